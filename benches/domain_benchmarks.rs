@@ -13,6 +13,14 @@ fn bench_validation(c: &mut Criterion) {
         token_mint: None,
         signature: "dummy_sig".to_string(),
         nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string(),
+        memo: None,
+        priority: 0,
+        callback_url: None,
+        signature_version: None,
+        valid_until: None,
+        jito_region: None,
+        skip_preflight: None,
+        amount_sol: None,
     };
 
     c.bench_function("validate_transfer_request", |b| {
@@ -0,0 +1,9 @@
+-- Optional expiry for blocklist entries.
+-- Entries with a NULL expires_at block indefinitely; otherwise they stop
+-- blocking once expires_at has passed and are purged by the expiry sweep.
+
+ALTER TABLE blocklist ADD COLUMN IF NOT EXISTS expires_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_blocklist_expires_at
+    ON blocklist (expires_at)
+    WHERE expires_at IS NOT NULL;
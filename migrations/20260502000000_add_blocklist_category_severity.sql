@@ -0,0 +1,16 @@
+-- Category and severity metadata for blocklist entries (compliance reporting).
+
+ALTER TABLE blocklist
+    ADD COLUMN IF NOT EXISTS category TEXT NOT NULL DEFAULT 'manual' CHECK (
+        category IN ('ofac_sanction', 'internal_fraud', 'range_auto_block', 'manual')
+    ),
+    ADD COLUMN IF NOT EXISTS severity SMALLINT NOT NULL DEFAULT 5 CHECK (
+        severity BETWEEN 0 AND 10
+    );
+
+-- Backfill entries created by the Range Protocol auto-block path
+UPDATE blocklist
+SET category = 'range_auto_block', severity = 9
+WHERE reason LIKE 'Auto-blocked: Range Protocol%';
+
+CREATE INDEX IF NOT EXISTS idx_blocklist_category ON blocklist (category);
@@ -0,0 +1,14 @@
+-- Create allowlist table for known-good wallets (treasury, exchange hot wallets)
+-- Transfers where both sender and recipient are allowlisted skip the blocklist
+-- and external compliance checks. The blocklist always takes precedence.
+
+CREATE TABLE IF NOT EXISTS allowlist (
+    address TEXT PRIMARY KEY,
+    reason TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_allowlist_created_at ON allowlist(created_at);
+
+COMMENT ON TABLE allowlist IS 'Known-good wallet addresses that bypass compliance screening';
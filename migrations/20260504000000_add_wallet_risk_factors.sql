@@ -0,0 +1,5 @@
+-- Persist the contributing risk factors alongside cached wallet risk profiles
+-- so cached risk-check responses can explain why a wallet was flagged.
+
+ALTER TABLE wallet_risk_profiles
+    ADD COLUMN IF NOT EXISTS risk_factors TEXT[] NOT NULL DEFAULT '{}';
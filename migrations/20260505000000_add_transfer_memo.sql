@@ -0,0 +1,5 @@
+-- Optional memo attached to outgoing transfers via the SPL Memo program.
+-- Signed by the client as part of the v2 signing message.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS memo VARCHAR(256);
@@ -0,0 +1,9 @@
+-- Processing priority for pending submissions (higher is dequeued first).
+-- Signed by the client as part of the v3 signing message.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS priority SMALLINT NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_transfer_requests_pending_priority
+    ON transfer_requests (priority DESC, created_at ASC)
+    WHERE blockchain_status = 'pending_submission';
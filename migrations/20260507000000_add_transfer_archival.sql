@@ -0,0 +1,11 @@
+-- Soft-delete for old terminal transfers. Archived rows are hidden from
+-- listings and worker queues but stay retrievable by id for audit.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS archived_at TIMESTAMPTZ;
+
+-- Archival candidates: terminal rows not yet archived, oldest update first
+CREATE INDEX IF NOT EXISTS idx_transfer_requests_archivable
+    ON transfer_requests (updated_at ASC)
+    WHERE archived_at IS NULL
+      AND blockchain_status IN ('confirmed', 'failed', 'expired', 'cancelled');
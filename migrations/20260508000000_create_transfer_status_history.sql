@@ -0,0 +1,15 @@
+-- Status transition history for transfer audits.
+-- One row per compliance or blockchain status update, in the order applied.
+
+CREATE TABLE IF NOT EXISTS transfer_status_history (
+    id BIGSERIAL PRIMARY KEY,
+    transfer_id VARCHAR(255) NOT NULL REFERENCES transfer_requests(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (kind IN ('compliance', 'blockchain')),
+    old_status TEXT,
+    new_status TEXT NOT NULL,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_transfer_status_history_transfer
+    ON transfer_status_history (transfer_id, id);
@@ -0,0 +1,5 @@
+-- Correlation id of the HTTP request that created the transfer, reused by
+-- the worker and crank so their logs can be joined with the API logs.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS correlation_id VARCHAR(255);
@@ -0,0 +1,25 @@
+-- Outbound webhook notifications of transfer status changes.
+-- One row per notified event; `event_key` keeps a status change from being
+-- queued twice, and delivered rows are never sent again.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS callback_url TEXT;
+
+CREATE TABLE IF NOT EXISTS webhook_deliveries (
+    id VARCHAR(255) PRIMARY KEY,
+    transfer_id VARCHAR(255) NOT NULL REFERENCES transfer_requests(id) ON DELETE CASCADE,
+    event_key TEXT NOT NULL UNIQUE,
+    url TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'delivered', 'failed')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Dispatcher queue: pending deliveries by due time
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_due
+    ON webhook_deliveries (next_attempt_at)
+    WHERE status = 'pending';
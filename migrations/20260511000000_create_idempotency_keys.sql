@@ -0,0 +1,12 @@
+-- HTTP Idempotency-Key support for POST /transfer-requests.
+-- Stores the first response for each key so retries replay it verbatim;
+-- `request_hash` detects a key reused with a different body.
+
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    key VARCHAR(255) PRIMARY KEY,
+    request_hash CHAR(64) NOT NULL,
+    status_code INTEGER NOT NULL,
+    response_body TEXT NOT NULL,
+    transfer_id VARCHAR(255),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
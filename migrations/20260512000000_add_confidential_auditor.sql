@@ -0,0 +1,5 @@
+-- Auditor ElGamal pubkey of confidential transfers to mints with an auditor.
+-- NULL for public transfers and for mints without an auditor.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS auditor_elgamal_pubkey TEXT;
@@ -0,0 +1,16 @@
+-- Compliance rejections get their own terminal blockchain status instead of
+-- 'failed', so rejection and failure rates can be reported separately.
+-- Earlier rejections are moved over; they are the failed rows whose
+-- compliance check rejected them.
+
+UPDATE transfer_requests
+SET blockchain_status = 'rejected'
+WHERE blockchain_status = 'failed'
+  AND compliance_status = 'rejected';
+
+-- Archival candidates now include rejected rows
+DROP INDEX IF EXISTS idx_transfer_requests_archivable;
+CREATE INDEX IF NOT EXISTS idx_transfer_requests_archivable
+    ON transfer_requests (updated_at ASC)
+    WHERE archived_at IS NULL
+      AND blockchain_status IN ('confirmed', 'failed', 'rejected', 'expired', 'cancelled');
@@ -0,0 +1,5 @@
+-- Jito bundle ID of the last submission, for correlating with Jito's
+-- bundle explorer. NULL when the transfer was not submitted as a bundle.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS bundle_id TEXT;
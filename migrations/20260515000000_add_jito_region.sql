@@ -0,0 +1,4 @@
+-- Per-transfer Jito region override. NULL uses the configured JITO_REGION.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS jito_region TEXT;
@@ -0,0 +1,5 @@
+-- Blocklist hit statistics, flushed periodically from the in-memory counters.
+
+ALTER TABLE blocklist
+    ADD COLUMN IF NOT EXISTS hit_count BIGINT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS last_hit_at TIMESTAMPTZ;
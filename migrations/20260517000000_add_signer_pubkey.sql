@@ -0,0 +1,5 @@
+-- Issuer key pinned to a transfer on its first submission.
+-- NULL until then; every later attempt is signed by this key.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS signer_pubkey TEXT;
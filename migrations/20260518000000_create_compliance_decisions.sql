@@ -0,0 +1,19 @@
+-- Compliance decision audit trail.
+-- One row per approval or rejection taken while screening a submitted transfer.
+
+CREATE TABLE IF NOT EXISTS compliance_decisions (
+    id VARCHAR(255) PRIMARY KEY,
+    transfer_id VARCHAR(255) NOT NULL REFERENCES transfer_requests(id) ON DELETE CASCADE,
+    address VARCHAR(255) NOT NULL,
+    decision TEXT NOT NULL CHECK (decision IN ('approved', 'rejected')),
+    provider TEXT NOT NULL,
+    risk_score INTEGER,
+    reason TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_compliance_decisions_created
+    ON compliance_decisions (created_at DESC, id DESC);
+
+CREATE INDEX IF NOT EXISTS idx_compliance_decisions_decision_created
+    ON compliance_decisions (decision, created_at DESC);
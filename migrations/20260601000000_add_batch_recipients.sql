@@ -0,0 +1,5 @@
+-- Recipients of a public batch transfer as [[address, amount], ...].
+-- NULL for every other transfer type; `amount` holds the batch total.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS batch_recipients JSONB;
@@ -0,0 +1,5 @@
+-- Per-transfer override of SKIP_PREFLIGHT.
+-- NULL uses the relayer's configured default.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS skip_preflight BOOLEAN;
@@ -0,0 +1,10 @@
+-- valid_until of the signed request, kept so the nonce sweep knows when a
+-- nonce can no longer be replayed. NULL for requests signed without one.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS valid_until TIMESTAMPTZ;
+
+-- Rows the nonce sweep can still clear
+CREATE INDEX IF NOT EXISTS idx_transfer_requests_nonce_sweep
+    ON transfer_requests (updated_at)
+    WHERE nonce IS NOT NULL AND valid_until IS NOT NULL;
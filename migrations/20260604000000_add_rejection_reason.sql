@@ -0,0 +1,8 @@
+-- Structured compliance rejection reason (source, category, risk score, message).
+-- blockchain_last_error keeps the display message.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS rejection_reason JSONB;
+
+ALTER TABLE transfer_status_history
+    ADD COLUMN IF NOT EXISTS rejection_reason JSONB;
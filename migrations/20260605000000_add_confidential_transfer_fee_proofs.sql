@@ -0,0 +1,6 @@
+-- Fee proofs of confidential transfers to mints with the confidential
+-- transfer fee extension. NULL for all other transfers.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS fee_sigma_proof TEXT,
+    ADD COLUMN IF NOT EXISTS fee_ciphertext_validity_proof TEXT;
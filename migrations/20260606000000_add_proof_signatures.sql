@@ -0,0 +1,10 @@
+-- Signatures of the proof verification transactions of a confidential
+-- transfer, sent and confirmed before the final transfer. Webhooks for them
+-- are recognized without confirming the transfer. Empty for all other
+-- transfers.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS proof_signatures TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX IF NOT EXISTS idx_transfer_requests_proof_signatures
+    ON transfer_requests USING GIN (proof_signatures);
@@ -0,0 +1,49 @@
+-- Canonicalize blocklist and allowlist addresses stored before writes were
+-- normalized. A 32-byte key has a single base58 spelling, so the canonical
+-- form is the address without surrounding whitespace. Padded entries would
+-- otherwise never match a transfer.
+--
+-- When several spellings of the same address exist, the most recently
+-- updated row is kept; on the blocklist it takes over the hit counts of the
+-- others.
+
+CREATE TEMPORARY TABLE blocklist_canonical ON COMMIT DROP AS
+SELECT address,
+       regexp_replace(address, '^\s+|\s+$', '', 'g') AS canonical,
+       ROW_NUMBER() OVER w AS rank,
+       SUM(hit_count) OVER (PARTITION BY regexp_replace(address, '^\s+|\s+$', '', 'g'))
+           AS total_hits,
+       MAX(last_hit_at) OVER (PARTITION BY regexp_replace(address, '^\s+|\s+$', '', 'g'))
+           AS last_hit
+FROM blocklist
+WINDOW w AS (
+    PARTITION BY regexp_replace(address, '^\s+|\s+$', '', 'g')
+    ORDER BY updated_at DESC, address DESC
+);
+
+DELETE FROM blocklist AS b
+USING blocklist_canonical AS c
+WHERE b.address = c.address AND c.rank > 1;
+
+UPDATE blocklist AS b
+SET address = c.canonical,
+    hit_count = c.total_hits,
+    last_hit_at = c.last_hit
+FROM blocklist_canonical AS c
+WHERE b.address = c.address
+  AND (b.address <> c.canonical OR b.hit_count <> c.total_hits);
+
+DELETE FROM allowlist AS a
+USING (
+    SELECT address,
+           ROW_NUMBER() OVER (
+               PARTITION BY regexp_replace(address, '^\s+|\s+$', '', 'g')
+               ORDER BY updated_at DESC, address DESC
+           ) AS rank
+    FROM allowlist
+) AS c
+WHERE a.address = c.address AND c.rank > 1;
+
+UPDATE allowlist
+SET address = regexp_replace(address, '^\s+|\s+$', '', 'g')
+WHERE address <> regexp_replace(address, '^\s+|\s+$', '', 'g');
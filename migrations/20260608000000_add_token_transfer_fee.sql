@@ -0,0 +1,8 @@
+-- What a transfer of a Token-2022 mint with a transfer fee actually moved:
+-- the raw units debited from the source (grossed up for the fee when
+-- configured) and the fee withheld from the recipients. Recorded when the
+-- transfer is submitted; NULL for mints without a transfer fee.
+
+ALTER TABLE transfer_requests
+    ADD COLUMN IF NOT EXISTS token_sent_amount BIGINT,
+    ADD COLUMN IF NOT EXISTS token_transfer_fee BIGINT;
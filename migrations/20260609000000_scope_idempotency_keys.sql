@@ -0,0 +1,23 @@
+-- Scope idempotency keys to the API key that used them and let them expire.
+--
+-- `scope` is the SHA-256 (hex) of the API key presented with the request,
+-- or empty when none was, so one client cannot replay another client's
+-- response by guessing its key. Existing records keep the empty scope.
+-- Records are replayed until `expires_at` and then purged by the
+-- idempotency sweep.
+
+ALTER TABLE idempotency_keys
+    ADD COLUMN IF NOT EXISTS scope VARCHAR(64) NOT NULL DEFAULT '',
+    ADD COLUMN IF NOT EXISTS expires_at TIMESTAMPTZ;
+
+UPDATE idempotency_keys
+SET expires_at = created_at + INTERVAL '24 hours'
+WHERE expires_at IS NULL;
+
+ALTER TABLE idempotency_keys
+    ALTER COLUMN expires_at SET NOT NULL,
+    DROP CONSTRAINT IF EXISTS idempotency_keys_pkey,
+    ADD PRIMARY KEY (scope, key);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_expires_at
+    ON idempotency_keys (expires_at);
@@ -0,0 +1,17 @@
+-- Transfers that landed on-chain but did not match the request get their own
+-- terminal blockchain status instead of 'failed' with a marker in the last
+-- error. Earlier mismatches are moved over; they are the failed rows whose
+-- error carries the old 'Transfer mismatch:' prefix.
+
+UPDATE transfer_requests
+SET blockchain_status = 'unreconciled',
+    blockchain_last_error = btrim(substr(blockchain_last_error, length('Transfer mismatch:') + 1))
+WHERE blockchain_status = 'failed'
+  AND blockchain_last_error LIKE 'Transfer mismatch:%';
+
+-- Archival candidates now include unreconciled rows
+DROP INDEX IF EXISTS idx_transfer_requests_archivable;
+CREATE INDEX IF NOT EXISTS idx_transfer_requests_archivable
+    ON transfer_requests (updated_at ASC)
+    WHERE archived_at IS NULL
+      AND blockchain_status IN ('confirmed', 'failed', 'rejected', 'unreconciled', 'expired', 'cancelled');
@@ -1,19 +1,28 @@
-//! Admin API handlers for blocklist management.
+//! Admin API handlers for blocklist and allowlist management.
 //!
-//! Provides HTTP endpoints for real-time management of the internal blocklist.
+//! Provides HTTP endpoints for real-time management of the internal blocklist
+//! and the allowlist of known-good addresses, for reconciling stuck
+//! transfers with their on-chain status, and for pausing the background
+//! worker and crank during incidents.
 
 use std::sync::Arc;
 
 use axum::{
     Json,
     extract::{Path, State},
+    http::{HeaderMap, header},
 };
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use tracing::warn;
+use tracing::{error, info, warn};
 use utoipa::ToSchema;
 
 use crate::app::AppState;
-use crate::domain::{AppError, DatabaseError, ValidationError};
+use crate::domain::{
+    AppError, BlocklistCategory, DatabaseError, MAX_BLOCKLIST_SEVERITY, TransferRequest,
+    ValidationError, validate_solana_address,
+};
+use crate::infra::BlocklistEntry;
 
 /// Request body for adding an address to the blocklist
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
@@ -22,6 +31,12 @@ pub struct AddBlocklistRequest {
     pub address: String,
     /// The reason for blocking this address
     pub reason: String,
+    /// Why the address is blocked (defaults to `manual`)
+    #[serde(default)]
+    pub category: BlocklistCategory,
+    /// Severity from 0 to 10 (defaults to the category's default severity)
+    #[serde(default)]
+    pub severity: Option<u8>,
 }
 
 /// Response for blocklist operations
@@ -40,6 +55,69 @@ pub struct BlocklistEntryResponse {
     pub address: String,
     /// The reason for blocking
     pub reason: String,
+    /// Why the address is blocked
+    pub category: BlocklistCategory,
+    /// Severity from 0 to 10
+    pub severity: u8,
+    /// Number of screened addresses this entry has matched
+    pub hit_count: u64,
+    /// When this entry last matched (`null` if it never has)
+    pub last_hit_at: Option<DateTime<Utc>>,
+}
+
+/// Request body for adding an address to the allowlist
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+pub struct AddAllowlistRequest {
+    /// The known-good wallet address
+    pub address: String,
+    /// Why this address is trusted (e.g. "Treasury hot wallet")
+    pub reason: String,
+}
+
+/// Allowlist entry for listing
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AllowlistEntryResponse {
+    /// The allowlisted wallet address
+    pub address: String,
+    /// Why the address is trusted
+    pub reason: String,
+}
+
+/// Response for listing all allowlist entries
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ListAllowlistResponse {
+    /// Total count of allowlisted addresses
+    pub count: usize,
+    /// List of allowlist entries
+    pub entries: Vec<AllowlistEntryResponse>,
+}
+
+/// Entry rejected during a bulk import
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct InvalidBlocklistEntry {
+    /// Zero-based position of the entry in the submitted batch
+    pub index: usize,
+    /// The submitted address (may be empty)
+    pub address: String,
+    /// Why the entry was skipped
+    pub error: String,
+}
+
+/// Response for a bulk blocklist import
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BulkBlocklistResponse {
+    /// Success indicator (true even when some entries were skipped)
+    pub success: bool,
+    /// Number of newly blocklisted addresses
+    pub inserted: usize,
+    /// Number of existing entries that were overwritten
+    pub updated: usize,
+    /// Number of entries dropped because the address repeated later in the batch
+    pub duplicates: usize,
+    /// Number of entries skipped due to validation errors
+    pub invalid: usize,
+    /// Details for each skipped entry
+    pub invalid_entries: Vec<InvalidBlocklistEntry>,
 }
 
 /// Response for listing all blocklist entries
@@ -69,17 +147,34 @@ pub async fn add_blocklist_handler(
     State(state): State<Arc<AppState>>,
     Json(payload): Json<AddBlocklistRequest>,
 ) -> Result<Json<BlocklistResponse>, AppError> {
-    // Validate input
+    // Validate input, reporting every problem at once
+    let severity = payload
+        .severity
+        .unwrap_or_else(|| payload.category.default_severity());
+    let mut errors = Vec::new();
     if payload.address.trim().is_empty() {
-        return Err(AppError::Validation(ValidationError::MissingField(
-            "address".to_string(),
-        )));
+        errors.push(ValidationError::MissingField("address".to_string()));
+    } else if validate_solana_address(payload.address.trim()).is_err() {
+        errors.push(ValidationError::InvalidAddress(payload.address.clone()));
     }
     if payload.reason.trim().is_empty() {
-        return Err(AppError::Validation(ValidationError::MissingField(
-            "reason".to_string(),
+        errors.push(ValidationError::MissingField("reason".to_string()));
+    }
+    if severity > MAX_BLOCKLIST_SEVERITY {
+        errors.push(ValidationError::InvalidField {
+            field: "severity".to_string(),
+            message: format!("must be between 0 and {}", MAX_BLOCKLIST_SEVERITY),
+        });
+    }
+    if errors.len() > 1 {
+        let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        return Err(AppError::Validation(ValidationError::Multiple(
+            messages.join("; "),
         )));
     }
+    if let Some(error) = errors.pop() {
+        return Err(AppError::Validation(error));
+    }
 
     // Get blocklist or return error if not configured
     let blocklist = state
@@ -89,12 +184,19 @@ pub async fn add_blocklist_handler(
 
     // Add to blocklist (persisted to database)
     blocklist
-        .add_address(payload.address.clone(), payload.reason.clone())
+        .add_address(
+            payload.address.clone(),
+            payload.reason.clone(),
+            payload.category,
+            severity,
+        )
         .await?;
 
     warn!(
         address = %payload.address,
         reason = %payload.reason,
+        category = %payload.category,
+        severity,
         "Admin added address to blocklist"
     );
 
@@ -104,6 +206,152 @@ pub async fn add_blocklist_handler(
     }))
 }
 
+/// Bulk-import addresses into the internal blocklist
+///
+/// POST /admin/blocklist/bulk
+///
+/// Accepts a JSON array of `AddBlocklistRequest` objects, or a CSV body
+/// (`Content-Type: text/csv`) with `address,reason` rows and an optional
+/// header line. Invalid entries are reported and skipped; valid entries are
+/// written in a single transaction.
+#[utoipa::path(
+    post,
+    path = "/admin/blocklist/bulk",
+    tag = "admin",
+    request_body(content = Vec<AddBlocklistRequest>, description = "JSON array of entries, or CSV `address,reason` rows"),
+    responses(
+        (status = 200, description = "Batch imported", body = BulkBlocklistResponse),
+        (status = 400, description = "Malformed request body", body = crate::domain::ErrorResponse),
+        (status = 503, description = "Blocklist not configured", body = crate::domain::ErrorResponse),
+    )
+)]
+pub async fn bulk_add_blocklist_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: String,
+) -> Result<Json<BulkBlocklistResponse>, AppError> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok());
+    let items = parse_bulk_blocklist_body(content_type, &body)?;
+    let (entries, invalid_entries) = validate_bulk_entries(items);
+
+    // Get blocklist or return error if not configured
+    let blocklist = state
+        .blocklist
+        .as_ref()
+        .ok_or_else(|| AppError::NotSupported("Blocklist not configured".to_string()))?;
+
+    let summary = if entries.is_empty() {
+        Default::default()
+    } else {
+        blocklist.add_addresses_batch(entries).await?
+    };
+
+    warn!(
+        inserted = summary.inserted,
+        updated = summary.updated,
+        invalid = invalid_entries.len(),
+        "Admin bulk-imported addresses into blocklist"
+    );
+
+    Ok(Json(BulkBlocklistResponse {
+        success: true,
+        inserted: summary.inserted,
+        updated: summary.updated,
+        duplicates: summary.duplicates,
+        invalid: invalid_entries.len(),
+        invalid_entries,
+    }))
+}
+
+/// Parse a bulk import body as CSV (`text/csv`) or a JSON array.
+fn parse_bulk_blocklist_body(
+    content_type: Option<&str>,
+    body: &str,
+) -> Result<Vec<AddBlocklistRequest>, AppError> {
+    let is_csv = content_type.is_some_and(|ct| ct.starts_with("text/csv"));
+    if !is_csv {
+        return serde_json::from_str(body).map_err(|e| {
+            AppError::Validation(ValidationError::InvalidFormat(format!(
+                "Expected a JSON array of blocklist entries: {}",
+                e
+            )))
+        });
+    }
+
+    let mut items = Vec::new();
+    for (line_no, line) in body.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        // Reasons may contain commas, so only split on the first one
+        let (address, reason) = line.split_once(',').unwrap_or((line, ""));
+        let (address, reason) = (address.trim(), reason.trim());
+        if line_no == 0 && address.eq_ignore_ascii_case("address") {
+            continue;
+        }
+        items.push(AddBlocklistRequest {
+            address: address.to_string(),
+            reason: reason.to_string(),
+            category: BlocklistCategory::default(),
+            severity: None,
+        });
+    }
+    Ok(items)
+}
+
+/// Split bulk import items into valid entries and per-entry validation failures.
+fn validate_bulk_entries(
+    items: Vec<AddBlocklistRequest>,
+) -> (Vec<BlocklistEntry>, Vec<InvalidBlocklistEntry>) {
+    let mut entries = Vec::with_capacity(items.len());
+    let mut invalid = Vec::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        let address = item.address.trim().to_string();
+        let severity = item
+            .severity
+            .unwrap_or_else(|| item.category.default_severity());
+
+        // Every problem of the entry is reported, not just the first
+        let mut errors = Vec::new();
+        if address.is_empty() {
+            errors.push("address is required".to_string());
+        } else if validate_solana_address(&address).is_err() {
+            errors.push("address is not a valid base58 Solana public key".to_string());
+        }
+        if item.reason.trim().is_empty() {
+            errors.push("reason is required".to_string());
+        }
+        if severity > MAX_BLOCKLIST_SEVERITY {
+            errors.push(format!(
+                "severity must be between 0 and {}",
+                MAX_BLOCKLIST_SEVERITY
+            ));
+        }
+
+        if errors.is_empty() {
+            entries.push(BlocklistEntry {
+                address,
+                reason: item.reason,
+                category: item.category,
+                severity,
+                expires_at: None,
+            });
+        } else {
+            invalid.push(InvalidBlocklistEntry {
+                index,
+                address,
+                error: errors.join("; "),
+            });
+        }
+    }
+
+    (entries, invalid)
+}
+
 /// Remove an address from the internal blocklist
 ///
 /// DELETE /admin/blocklist/{address}
@@ -172,14 +420,362 @@ pub async fn list_blocklist_handler(
     let entries: Vec<BlocklistEntryResponse> = blocklist
         .list_all()
         .into_iter()
-        .map(|e| BlocklistEntryResponse {
+        .map(|e| {
+            let hits = blocklist.hits(&e.address);
+            BlocklistEntryResponse {
+                address: e.address,
+                reason: e.reason,
+                category: e.category,
+                severity: e.severity,
+                hit_count: hits.hit_count,
+                last_hit_at: hits.last_hit_at,
+            }
+        })
+        .collect();
+
+    Ok(Json(ListBlocklistResponse {
+        count: entries.len(),
+        entries,
+    }))
+}
+
+/// Add an address to the allowlist
+///
+/// POST /admin/allowlist
+///
+/// Transfers where both parties are allowlisted skip the blocklist and
+/// external compliance checks. An address that is also blocklisted stays blocked.
+#[utoipa::path(
+    post,
+    path = "/admin/allowlist",
+    tag = "admin",
+    request_body = AddAllowlistRequest,
+    responses(
+        (status = 200, description = "Address added to allowlist", body = BlocklistResponse),
+        (status = 400, description = "Invalid request", body = crate::domain::ErrorResponse),
+        (status = 503, description = "Allowlist not configured", body = crate::domain::ErrorResponse),
+    )
+)]
+pub async fn add_allowlist_handler(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<AddAllowlistRequest>,
+) -> Result<Json<BlocklistResponse>, AppError> {
+    if payload.address.trim().is_empty() {
+        return Err(AppError::Validation(ValidationError::MissingField(
+            "address".to_string(),
+        )));
+    }
+    if payload.reason.trim().is_empty() {
+        return Err(AppError::Validation(ValidationError::MissingField(
+            "reason".to_string(),
+        )));
+    }
+
+    let allowlist = state
+        .allowlist
+        .as_ref()
+        .ok_or_else(|| AppError::NotSupported("Allowlist not configured".to_string()))?;
+
+    allowlist
+        .add_address(payload.address.clone(), payload.reason.clone())
+        .await?;
+
+    // The blocklist always wins, so make the conflict visible to the operator
+    if let Some(reason) = state
+        .blocklist
+        .as_ref()
+        .and_then(|blocklist| blocklist.peek_address(&payload.address))
+        .map(|entry| entry.reason)
+    {
+        error!(
+            address = %payload.address,
+            blocklist_reason = %reason,
+            "Allowlisted address is also blocklisted - it will remain blocked"
+        );
+        return Ok(Json(BlocklistResponse {
+            success: true,
+            message: format!(
+                "Address {} added to allowlist, but it is blocklisted and will remain blocked",
+                payload.address
+            ),
+        }));
+    }
+
+    warn!(
+        address = %payload.address,
+        reason = %payload.reason,
+        "Admin added address to allowlist"
+    );
+
+    Ok(Json(BlocklistResponse {
+        success: true,
+        message: format!("Address {} added to allowlist", payload.address),
+    }))
+}
+
+/// Remove an address from the allowlist
+///
+/// DELETE /admin/allowlist/{address}
+#[utoipa::path(
+    delete,
+    path = "/admin/allowlist/{address}",
+    tag = "admin",
+    params(
+        ("address" = String, Path, description = "Wallet address to remove from allowlist")
+    ),
+    responses(
+        (status = 200, description = "Address removed from allowlist", body = BlocklistResponse),
+        (status = 404, description = "Address not found in allowlist", body = crate::domain::ErrorResponse),
+        (status = 503, description = "Allowlist not configured", body = crate::domain::ErrorResponse),
+    )
+)]
+pub async fn remove_allowlist_handler(
+    State(state): State<Arc<AppState>>,
+    Path(address): Path<String>,
+) -> Result<Json<BlocklistResponse>, AppError> {
+    let allowlist = state
+        .allowlist
+        .as_ref()
+        .ok_or_else(|| AppError::NotSupported("Allowlist not configured".to_string()))?;
+
+    if allowlist.remove_address(&address).await? {
+        warn!(address = %address, "Admin removed address from allowlist");
+        Ok(Json(BlocklistResponse {
+            success: true,
+            message: format!("Address {} removed from allowlist", address),
+        }))
+    } else {
+        Err(AppError::Database(DatabaseError::NotFound(format!(
+            "Address {} not found in allowlist",
+            address
+        ))))
+    }
+}
+
+/// List all addresses in the allowlist
+///
+/// GET /admin/allowlist
+#[utoipa::path(
+    get,
+    path = "/admin/allowlist",
+    tag = "admin",
+    responses(
+        (status = 200, description = "List of all allowlisted addresses", body = ListAllowlistResponse),
+        (status = 503, description = "Allowlist not configured", body = crate::domain::ErrorResponse),
+    )
+)]
+pub async fn list_allowlist_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<ListAllowlistResponse>, AppError> {
+    let allowlist = state
+        .allowlist
+        .as_ref()
+        .ok_or_else(|| AppError::NotSupported("Allowlist not configured".to_string()))?;
+
+    let entries: Vec<AllowlistEntryResponse> = allowlist
+        .list_all()
+        .into_iter()
+        .map(|e| AllowlistEntryResponse {
             address: e.address,
             reason: e.reason,
         })
         .collect();
 
-    Ok(Json(ListBlocklistResponse {
+    Ok(Json(ListAllowlistResponse {
         count: entries.len(),
         entries,
     }))
 }
+
+/// Re-check a submitted transfer's on-chain status now, as the crank would
+///
+/// POST /admin/transfers/{id}/reconcile
+#[utoipa::path(
+    post,
+    path = "/admin/transfers/{id}/reconcile",
+    tag = "admin",
+    params(
+        ("id" = String, Path, description = "Transfer Request ID")
+    ),
+    responses(
+        (status = 200, description = "Transfer after reconciliation", body = TransferRequest),
+        (status = 404, description = "Request not found", body = crate::domain::ErrorResponse),
+        (status = 409, description = "Transfer is not in submitted status", body = crate::domain::ErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::domain::ErrorResponse),
+    )
+)]
+pub async fn reconcile_transfer_handler(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<TransferRequest>, AppError> {
+    let mut transfer = state.service.reconcile_transfer(&id).await?;
+    state.link_explorer(&mut transfer);
+    Ok(Json(transfer))
+}
+
+/// Pause state of the background worker and crank
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ProcessingPauseResponse {
+    /// Whether the worker and crank are paused
+    pub paused: bool,
+    /// Whether this request changed the state
+    pub changed: bool,
+}
+
+/// Pause the background worker and crank. Both keep running but claim
+/// nothing until resumed; submissions are still accepted and queued.
+///
+/// POST /admin/worker/pause
+#[utoipa::path(
+    post,
+    path = "/admin/worker/pause",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Worker and crank paused", body = ProcessingPauseResponse),
+    )
+)]
+pub async fn pause_worker_handler(
+    State(state): State<Arc<AppState>>,
+) -> Json<ProcessingPauseResponse> {
+    let changed = state.service.processing_pause().pause();
+    if changed {
+        warn!("Background worker and crank paused by admin");
+    }
+    Json(ProcessingPauseResponse {
+        paused: true,
+        changed,
+    })
+}
+
+/// Resume the background worker and crank
+///
+/// POST /admin/worker/resume
+#[utoipa::path(
+    post,
+    path = "/admin/worker/resume",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Worker and crank resumed", body = ProcessingPauseResponse),
+    )
+)]
+pub async fn resume_worker_handler(
+    State(state): State<Arc<AppState>>,
+) -> Json<ProcessingPauseResponse> {
+    let changed = state.service.processing_pause().resume();
+    if changed {
+        info!("Background worker and crank resumed by admin");
+    }
+    Json(ProcessingPauseResponse {
+        paused: false,
+        changed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_address(seed: u8) -> String {
+        bs58::encode([seed; 32]).into_string()
+    }
+
+    #[test]
+    fn test_parse_bulk_json() {
+        let body = format!(
+            r#"[{{"address":"{}","reason":"Phishing"}},{{"address":"{}","reason":"Sanctions","category":"ofac_sanction","severity":10}}]"#,
+            valid_address(1),
+            valid_address(2)
+        );
+        let items = parse_bulk_blocklist_body(Some("application/json"), &body).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].category, BlocklistCategory::Manual);
+        assert_eq!(items[1].category, BlocklistCategory::OfacSanction);
+        assert_eq!(items[1].severity, Some(10));
+    }
+
+    #[test]
+    fn test_parse_bulk_json_rejects_non_array() {
+        let result = parse_bulk_blocklist_body(None, r#"{"address":"x","reason":"y"}"#);
+        assert!(matches!(
+            result,
+            Err(AppError::Validation(ValidationError::InvalidFormat(_)))
+        ));
+    }
+
+    #[test]
+    fn test_parse_bulk_csv_with_header() {
+        let body = format!(
+            "address,reason\n{},Phishing, wallet drainer\n\n{},Sanctions\n",
+            valid_address(1),
+            valid_address(2)
+        );
+        let items = parse_bulk_blocklist_body(Some("text/csv; charset=utf-8"), &body).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].reason, "Phishing, wallet drainer");
+        assert_eq!(items[1].address, valid_address(2));
+    }
+
+    #[test]
+    fn test_validate_bulk_entries_partial_invalid() {
+        let item = |address: String, reason: &str, severity: Option<u8>| AddBlocklistRequest {
+            address,
+            reason: reason.to_string(),
+            category: BlocklistCategory::Manual,
+            severity,
+        };
+        let items = vec![
+            item(valid_address(1), "Phishing", None),
+            item("not-base58-0OIl".to_string(), "Bad", None),
+            item(String::new(), "Empty", None),
+            item(valid_address(2), " ", None),
+            item(valid_address(3), "Too severe", Some(11)),
+            item(valid_address(4), "Fraud", Some(7)),
+            item("bad".to_string(), "", Some(11)),
+        ];
+
+        let (entries, invalid) = validate_bulk_entries(items);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries[0].severity,
+            BlocklistCategory::Manual.default_severity()
+        );
+        assert_eq!(entries[1].severity, 7);
+        assert_eq!(
+            invalid.iter().map(|e| e.index).collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 6]
+        );
+        // All problems of an entry are reported together
+        assert_eq!(
+            invalid[4].error,
+            "address is not a valid base58 Solana public key; reason is required; \
+             severity must be between 0 and 10"
+        );
+    }
+
+    #[test]
+    fn test_validate_bulk_entries_keeps_duplicates_for_batch_dedup() {
+        let address = valid_address(9);
+        let items = vec![
+            AddBlocklistRequest {
+                address: address.clone(),
+                reason: "first".to_string(),
+                category: BlocklistCategory::Manual,
+                severity: None,
+            },
+            AddBlocklistRequest {
+                address: format!(" {} ", address),
+                reason: "second".to_string(),
+                category: BlocklistCategory::InternalFraud,
+                severity: None,
+            },
+        ];
+
+        let (entries, invalid) = validate_bulk_entries(items);
+
+        // Duplicates are collapsed by BlocklistManager::add_addresses_batch
+        assert!(invalid.is_empty());
+        assert_eq!(entries.len(), 2);
+        assert!(entries.iter().all(|e| e.address == address));
+    }
+}
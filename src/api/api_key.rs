@@ -0,0 +1,179 @@
+//! API key authentication for submit and admin routes.
+//!
+//! [`ApiKeyLayer`] checks the key presented in `Authorization: Bearer <key>`
+//! or `X-API-Key: <key>` against an [`ApiKeyStore`] and rejects the request
+//! with 401 (missing or unknown key) or 403 (key lacks the route's scope)
+//! before it reaches the handler.
+
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, Request, Response},
+    response::IntoResponse,
+};
+use futures_util::future::{Either, Ready, ready};
+use tower::{Layer, Service};
+
+use crate::domain::{ApiKeyScope, ApiKeyStore};
+
+/// Header carrying an API key when `Authorization` is not used
+pub const API_KEY_HEADER: &str = "X-API-Key";
+
+/// Legacy header accepted for the admin API key
+pub const ADMIN_API_KEY_HEADER: &str = "X-Admin-Api-Key";
+
+/// API key presented with the request: `Authorization: Bearer`, then
+/// `X-API-Key`, then `X-Admin-Api-Key`
+pub(crate) fn presented_api_key(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get("Authorization")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .or_else(|| {
+            [API_KEY_HEADER, ADMIN_API_KEY_HEADER]
+                .into_iter()
+                .find_map(|name| headers.get(name).and_then(|value| value.to_str().ok()))
+        })
+}
+
+/// Layer requiring an API key with `scope` on the wrapped routes
+#[derive(Debug, Clone)]
+pub struct ApiKeyLayer {
+    store: Arc<ApiKeyStore>,
+    scope: ApiKeyScope,
+}
+
+impl ApiKeyLayer {
+    #[must_use]
+    pub fn new(store: Arc<ApiKeyStore>, scope: ApiKeyScope) -> Self {
+        Self { store, scope }
+    }
+}
+
+impl<S> Layer<S> for ApiKeyLayer {
+    type Service = ApiKeyService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ApiKeyService {
+            inner,
+            store: Arc::clone(&self.store),
+            scope: self.scope,
+        }
+    }
+}
+
+/// Service produced by [`ApiKeyLayer`]
+#[derive(Debug, Clone)]
+pub struct ApiKeyService<S> {
+    inner: S,
+    store: Arc<ApiKeyStore>,
+    scope: ApiKeyScope,
+}
+
+impl<S> Service<Request<Body>> for ApiKeyService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>>,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = Either<Ready<Result<Response<Body>, S::Error>>, S::Future>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        match self
+            .store
+            .authorize(presented_api_key(request.headers()), self.scope)
+        {
+            Ok(()) => Either::Right(self.inner.call(request)),
+            Err(e) => Either::Left(ready(Ok(e.into_response()))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{Router, http::StatusCode, routing::post};
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn app(store: ApiKeyStore) -> Router {
+        Router::new().route(
+            "/",
+            post(|| async { "ok" }).layer(ApiKeyLayer::new(Arc::new(store), ApiKeyScope::Submit)),
+        )
+    }
+
+    fn request(header: Option<(&str, &str)>) -> Request<Body> {
+        let mut builder = Request::builder().method("POST").uri("/");
+        if let Some((name, value)) = header {
+            builder = builder.header(name, value);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    fn store() -> ApiKeyStore {
+        ApiKeyStore::new()
+            .with_key("submit_key", [ApiKeyScope::Submit])
+            .with_key("admin_key", [ApiKeyScope::Admin])
+    }
+
+    #[test]
+    fn test_presented_api_key_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(presented_api_key(&headers), None);
+
+        headers.insert(ADMIN_API_KEY_HEADER, "legacy".parse().unwrap());
+        assert_eq!(presented_api_key(&headers), Some("legacy"));
+        headers.insert(API_KEY_HEADER, "header".parse().unwrap());
+        assert_eq!(presented_api_key(&headers), Some("header"));
+        headers.insert("Authorization", "Bearer bearer".parse().unwrap());
+        assert_eq!(presented_api_key(&headers), Some("bearer"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_key_unauthorized() {
+        let response = app(store()).oneshot(request(None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app(store())
+            .oneshot(request(Some((API_KEY_HEADER, "unknown"))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_wrong_scope_forbidden() {
+        let response = app(store())
+            .oneshot(request(Some(("Authorization", "Bearer admin_key"))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_valid_key_allowed() {
+        for header in [
+            ("Authorization", "Bearer submit_key"),
+            (API_KEY_HEADER, "submit_key"),
+        ] {
+            let response = app(store()).oneshot(request(Some(header))).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_empty_store_open() {
+        let response = app(ApiKeyStore::new())
+            .oneshot(request(None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
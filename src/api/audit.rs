@@ -1,14 +1,17 @@
-//! Compliance audit report API handlers.
+//! Compliance audit report and status history API handlers.
 
 use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
 
 use crate::app::AppState;
-use crate::domain::{AppError, DatabaseError, TransferAuditReport};
+use crate::domain::{
+    AppError, ComplianceDecision, ComplianceDecisionQuery, DatabaseError, PaginatedResponse,
+    StatusTransition, TransferAuditReport,
+};
 
 /// Get a concise compliance and settlement audit report for a transfer.
 #[utoipa::path(
@@ -35,3 +38,62 @@ pub async fn get_transfer_audit_report_handler(
         .ok_or(AppError::Database(DatabaseError::NotFound(id)))?;
     Ok(Json(report))
 }
+
+/// Get the status transition history of a transfer, oldest first.
+#[utoipa::path(
+    get,
+    path = "/transfer-requests/{id}/history",
+    tag = "transfers",
+    params(
+        ("id" = String, Path, description = "Transfer Request ID")
+    ),
+    responses(
+        (status = 200, description = "Ordered status transitions", body = Vec<StatusTransition>),
+        (status = 404, description = "Transfer request not found", body = crate::domain::ErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::domain::ErrorResponse)
+    )
+)]
+pub async fn get_transfer_history_handler(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<Vec<StatusTransition>>, AppError> {
+    let history = state
+        .service
+        .get_transfer_history(&id)
+        .await?
+        .ok_or(AppError::Database(DatabaseError::NotFound(id)))?;
+    Ok(Json(history))
+}
+
+/// List compliance approvals and rejections, newest first.
+#[utoipa::path(
+    get,
+    path = "/admin/compliance-decisions",
+    tag = "admin",
+    params(
+        ("limit" = Option<i64>, Query, description = "Maximum number of decisions to return (1-100, default: 20)"),
+        ("cursor" = Option<String>, Query, description = "Cursor for pagination (decision ID to start after)"),
+        ("decision" = Option<crate::domain::ComplianceStatus>, Query, description = "Only `approved` or only `rejected` decisions"),
+        ("created_after" = Option<String>, Query, description = "Only decisions taken at or after this RFC 3339 time"),
+        ("created_before" = Option<String>, Query, description = "Only decisions taken before this RFC 3339 time")
+    ),
+    responses(
+        (status = 200, description = "Compliance decisions", body = PaginatedResponse<ComplianceDecision>),
+        (status = 400, description = "Invalid query parameters", body = crate::domain::ErrorResponse),
+        (status = 500, description = "Internal server error", body = crate::domain::ErrorResponse)
+    )
+)]
+pub async fn list_compliance_decisions_handler(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<ComplianceDecisionQuery>,
+) -> Result<Json<PaginatedResponse<ComplianceDecision>>, AppError> {
+    let decisions = state
+        .service
+        .list_compliance_decisions(
+            &params.filter(),
+            params.limit.clamp(1, 100),
+            params.cursor.as_deref(),
+        )
+        .await?;
+    Ok(Json(decisions))
+}
@@ -4,19 +4,34 @@ use std::sync::Arc;
 
 use axum::{
     Json,
-    extract::{Path, Query, State},
-    http::{HeaderMap, StatusCode},
-    response::IntoResponse,
+    body::{Body, Bytes},
+    extract::{FromRequest, Path, Query, Request, State, rejection::JsonRejection},
+    http::{HeaderMap, StatusCode, header},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, KeepAlive, Sse},
+    },
 };
-use tracing::{error, info};
+use futures_util::{Stream, stream};
+use serde::de::DeserializeOwned;
+use tokio::sync::broadcast::error::RecvError;
+use tracing::{error, info, warn};
 use utoipa::OpenApi;
 
+use super::idempotency::{
+    idempotency_scope, presented_idempotency_key, record_response, replay_response,
+    request_fingerprint,
+};
+use super::webhooks::{HeliusWebhookProvider, QuickNodeWebhookProvider, handle_webhook};
 use crate::app::AppState;
+use crate::app::risk_service::MAX_BULK_RISK_CHECK_ADDRESSES;
 use crate::domain::{
-    AppError, BlockchainError, DatabaseError, ErrorDetail, ErrorResponse, ExternalServiceError,
-    HealthResponse, HealthStatus, HeliusTransaction, PaginatedResponse, PaginationParams,
-    QuickNodeWebhookEvent, RateLimitResponse, RiskCheckRequest, RiskCheckResult,
-    SubmitTransferRequest, TransferRequest, ValidationError,
+    AppError, BlockchainError, BulkRiskCheckRequest, BulkRiskCheckResponse, BulkTransferResponse,
+    BulkTransferResult, DatabaseError, DependencyHealth, ErrorCode, ErrorDetail, ErrorResponse,
+    ExternalServiceError, FeeEstimate, FeeEstimateRequest, HealthQuery, HealthResponse,
+    HealthStatus, PaginatedResponse, PaginationParams, RateLimitResponse, RiskCheckRequest,
+    RiskCheckResult, ServerTimeResponse, SubmitTransferQuery, SubmitTransferRequest,
+    TransferRequest, TransferStatusEvent, ValidationError,
 };
 
 /// OpenAPI documentation structure
@@ -36,30 +51,55 @@ use crate::domain::{
     ),
     paths(
         submit_transfer_handler,
+        submit_bulk_transfers_handler,
+        estimate_fee_handler,
         list_transfer_requests_handler,
         get_transfer_request_handler,
         crate::api::audit::get_transfer_audit_report_handler,
+        crate::api::audit::get_transfer_history_handler,
         retry_blockchain_handler,
+        cancel_transfer_handler,
+        transfer_events_handler,
         crate::api::checkout::create_checkout_session_handler,
         crate::api::checkout::get_checkout_session_handler,
         crate::api::checkout::submit_checkout_transfer_handler,
         crate::api::admin::add_blocklist_handler,
+        crate::api::admin::bulk_add_blocklist_handler,
         crate::api::admin::list_blocklist_handler,
         crate::api::admin::remove_blocklist_handler,
+        crate::api::admin::add_allowlist_handler,
+        crate::api::admin::list_allowlist_handler,
+        crate::api::admin::remove_allowlist_handler,
+        crate::api::admin::reconcile_transfer_handler,
+        crate::api::admin::pause_worker_handler,
+        crate::api::admin::resume_worker_handler,
+        crate::api::audit::list_compliance_decisions_handler,
         health_check_handler,
         liveness_handler,
         readiness_handler,
+        server_time_handler,
+        metrics_handler,
         risk_check_handler,
+        bulk_risk_check_handler,
     ),
     components(
         schemas(
             TransferRequest,
+            TransferStatusEvent,
             SubmitTransferRequest,
+            crate::domain::BulkTransferResult,
+            crate::domain::BulkTransferResponse,
+            crate::domain::DryRunResult,
+            crate::domain::FeeEstimateRequest,
+            crate::domain::FeeEstimate,
             crate::domain::CheckoutSession,
             crate::domain::CreateCheckoutSessionRequest,
             crate::domain::CheckoutTransferSubmissionResponse,
             crate::domain::CheckoutSessionStatus,
             crate::domain::TransferAuditReport,
+            crate::domain::StatusTransition,
+            crate::domain::StatusKind,
+            crate::domain::RejectionReason,
             crate::domain::AuditAssetType,
             crate::domain::AuditAmount,
             crate::domain::AuditFinalDecision,
@@ -68,19 +108,35 @@ use crate::domain::{
             crate::domain::ComplianceStatus,
             crate::domain::BlockchainStatus,
             crate::domain::LastErrorType,
+            crate::domain::BlocklistCategory,
             PaginationParams,
             PaginatedResponse<TransferRequest>,
+            crate::domain::ComplianceDecision,
+            PaginatedResponse<crate::domain::ComplianceDecision>,
             HealthResponse,
             HealthStatus,
+            DependencyHealth,
+            ServerTimeResponse,
+            crate::domain::BlockchainNodeHealth,
+            crate::domain::FeePayerBalance,
             ErrorResponse,
             ErrorDetail,
+            ErrorCode,
             RateLimitResponse,
             RiskCheckRequest,
             RiskCheckResult,
+            BulkRiskCheckRequest,
+            BulkRiskCheckResponse,
             crate::api::admin::AddBlocklistRequest,
             crate::api::admin::BlocklistResponse,
             crate::api::admin::BlocklistEntryResponse,
             crate::api::admin::ListBlocklistResponse,
+            crate::api::admin::BulkBlocklistResponse,
+            crate::api::admin::InvalidBlocklistEntry,
+            crate::api::admin::AddAllowlistRequest,
+            crate::api::admin::AllowlistEntryResponse,
+            crate::api::admin::ListAllowlistResponse,
+            crate::api::admin::ProcessingPauseResponse,
         )
     ),
     tags(
@@ -101,9 +157,19 @@ pub struct ApiDoc;
 /// **Replay Protection:** The `nonce` field is required and must be included in
 /// the signature message. Format: `{from}:{to}:{amount|confidential}:{mint|SOL}:{nonce}`
 ///
-/// **Idempotency:** If the optional `Idempotency-Key` header is provided, it must
-/// match the body `nonce`. Duplicate requests with the same nonce return the
-/// existing request (HTTP 200) rather than creating a new one.
+/// **Idempotency:** Duplicate requests with the same nonce return the existing
+/// request (HTTP 200) rather than creating a new one. Independently of the
+/// nonce, an optional `Idempotency-Key` header makes retries safe: the first
+/// response for a key is replayed verbatim (same status code and body, with
+/// `Idempotent-Replayed: true`) for retries with the same body, and reusing
+/// the key with a different body returns 409. 5xx and 429 responses are not
+/// recorded.
+///
+/// **Dry run:** With `?dry_run=true` the request goes through validation,
+/// signature verification and the compliance checks only. The response is a
+/// `DryRunResult` (`"dry_run": true`) with the compliance status the transfer
+/// would get; nothing is persisted, queued or sent, and `Idempotency-Key` is
+/// ignored.
 ///
 /// **Response indicates acceptance, not blockchain confirmation.**
 /// Poll `GET /transfer-requests/{id}` to track `blockchain_status` progression:
@@ -117,11 +183,13 @@ pub struct ApiDoc;
     tag = "transfers",
     request_body = SubmitTransferRequest,
     params(
-        ("Idempotency-Key" = Option<String>, Header, description = "Optional idempotency key (must match body nonce if provided)")
+        ("dry_run" = Option<bool>, Query, description = "Only validate and screen the transfer; returns a DryRunResult and never persists or submits"),
+        ("Idempotency-Key" = Option<String>, Header, description = "Optional idempotency key (1-255 visible ASCII characters); retries with the same key and body replay the original response")
     ),
     responses(
-        (status = 200, description = "Transfer accepted for processing (blockchain_status will be 'pending_submission')", body = TransferRequest),
-        (status = 400, description = "Validation error - invalid request format or nonce mismatch", body = ErrorResponse),
+        (status = 200, description = "Transfer accepted for processing (blockchain_status will be 'pending_submission'); a DryRunResult for dry runs", body = TransferRequest),
+        (status = 400, description = "Validation error - invalid request format or Idempotency-Key", body = ErrorResponse),
+        (status = 409, description = "Idempotency-Key already used with a different request body", body = ErrorResponse),
         (status = 429, description = "Rate limit exceeded", body = RateLimitResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse),
         (status = 503, description = "Service unavailable", body = ErrorResponse)
@@ -129,27 +197,83 @@ pub struct ApiDoc;
 )]
 pub async fn submit_transfer_handler(
     State(state): State<Arc<AppState>>,
+    Query(query): Query<SubmitTransferQuery>,
     headers: HeaderMap,
-    Json(payload): Json<SubmitTransferRequest>,
-) -> Result<Json<TransferRequest>, AppError> {
-    // Extract optional Idempotency-Key header
-    let idempotency_key = headers
-        .get("Idempotency-Key")
-        .and_then(|v| v.to_str().ok())
-        .map(String::from);
-
-    // If Idempotency-Key header is provided, it must match the body nonce
-    if let Some(ref key) = idempotency_key
-        && key != &payload.nonce
+    body: Bytes,
+) -> Result<Response, AppError> {
+    if query.dry_run {
+        let payload: SubmitTransferRequest = match parse_json_body(&headers, body).await {
+            Ok(payload) => payload,
+            Err(rejection) => return Ok(rejection.into_response()),
+        };
+        return Ok(Json(state.service.dry_run_transfer(&payload).await?).into_response());
+    }
+
+    let Some(key) = presented_idempotency_key(&headers)? else {
+        let payload = match parse_json_body(&headers, body).await {
+            Ok(payload) => payload,
+            Err(rejection) => return Ok(rejection.into_response()),
+        };
+        return Ok(Json(accept_transfer(&state, &payload).await?).into_response());
+    };
+
+    let scope = idempotency_scope(&headers);
+    let request_hash = request_fingerprint(&body);
+    if let Some(record) = state.service.find_idempotency_record(&scope, key).await? {
+        if record.request_hash != request_hash {
+            return Err(AppError::Conflict(format!(
+                "Idempotency-Key '{}' was already used with a different request body",
+                key
+            )));
+        }
+        info!(
+            idempotency_key = %key,
+            transfer_id = ?record.transfer_id,
+            "Idempotent replay: returning recorded response"
+        );
+        return Ok(replay_response(&record));
+    }
+
+    let (response, transfer_id) = match parse_json_body(&headers, body).await {
+        Err(rejection) => (rejection.into_response(), None),
+        Ok(payload) => match accept_transfer(&state, &payload).await {
+            Ok(request) => {
+                let id = request.id.clone();
+                (Json(request).into_response(), Some(id))
+            }
+            Err(e) => (e.into_response(), None),
+        },
+    };
+
+    let (response, record) =
+        record_response(scope, key, request_hash, transfer_id, response).await?;
+    if let Some(record) = record
+        && let Err(e) = state.service.save_idempotency_record(&record).await
     {
-        return Err(AppError::Validation(ValidationError::InvalidField {
-            field: "Idempotency-Key".to_string(),
-            message: "Header must match body nonce".to_string(),
-        }));
+        warn!(idempotency_key = %key, error = ?e, "Failed to record idempotent response");
     }
+    Ok(response)
+}
 
+/// Parse a JSON body the way the `Json` extractor does, so content-type and
+/// syntax errors are reported the same way
+async fn parse_json_body<T: DeserializeOwned>(
+    headers: &HeaderMap,
+    body: Bytes,
+) -> Result<T, JsonRejection> {
+    let mut request = Request::new(Body::from(body));
+    *request.headers_mut() = headers.clone();
+    let Json(payload) = Json::<T>::from_request(request, &()).await?;
+    Ok(payload)
+}
+
+/// Return the existing transfer for a reused nonce, or submit a new one
+async fn accept_transfer(
+    state: &AppState,
+    payload: &SubmitTransferRequest,
+) -> Result<TransferRequest, AppError> {
     // Check for existing request with same nonce (idempotent return)
-    if let Some(existing) = state
+    if let Some(mut existing) = state
         .service
         .find_by_nonce(&payload.from_address, &payload.nonce)
         .await?
@@ -159,12 +283,99 @@ pub async fn submit_transfer_handler(
             existing_id = %existing.id,
             "Idempotent return: existing request found for nonce"
         );
-        return Ok(Json(existing));
+        state.link_explorer(&mut existing);
+        return Ok(existing);
     }
 
     // Proceed with normal submission
-    let request = state.service.submit_transfer(&payload).await?;
-    Ok(Json(request))
+    let mut request = state.service.submit_transfer(payload).await?;
+    state.link_explorer(&mut request);
+    Ok(request)
+}
+
+/// Estimate the network fee of a transfer
+///
+/// Builds the instructions the transfer would use (creating the recipient's
+/// token account if needed), prices the message with `getFeeForMessage`, and
+/// adds the current priority fee and the Jito tip if Jito submission is
+/// enabled. Nothing is signed or sent, and no signature is required.
+#[utoipa::path(
+    post,
+    path = "/transfer-requests/estimate-fee",
+    tag = "transfers",
+    request_body = FeeEstimateRequest,
+    responses(
+        (status = 200, description = "Estimated fee, by component", body = FeeEstimate),
+        (status = 400, description = "Validation error - missing address or zero amount", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitResponse),
+        (status = 501, description = "Fee estimation not supported by the blockchain client", body = ErrorResponse),
+        (status = 503, description = "Blockchain RPC unavailable", body = ErrorResponse)
+    )
+)]
+pub async fn estimate_fee_handler(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<FeeEstimateRequest>,
+) -> Result<Json<FeeEstimate>, AppError> {
+    let estimate = state.service.estimate_fee(&payload).await?;
+    Ok(Json(estimate))
+}
+
+/// Submit several transfers in one request
+///
+/// Each item is handled like `POST /transfer-requests` (validation, signature
+/// and nonce checks, compliance screening). Items succeed or fail
+/// independently: the response holds one result per item, in request order,
+/// with the transfer ID and statuses or the error for that item. A
+/// compliance rejection is a recorded transfer, not an error.
+#[utoipa::path(
+    post,
+    path = "/transfer-requests/bulk",
+    tag = "transfers",
+    request_body = Vec<SubmitTransferRequest>,
+    responses(
+        (status = 200, description = "Per-item results", body = BulkTransferResponse),
+        (status = 400, description = "Empty batch or batch larger than the configured maximum", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitResponse)
+    )
+)]
+pub async fn submit_bulk_transfers_handler(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<Vec<SubmitTransferRequest>>,
+) -> Result<Json<BulkTransferResponse>, AppError> {
+    if payload.is_empty() || payload.len() > state.max_bulk_transfers {
+        return Err(AppError::Validation(ValidationError::InvalidField {
+            field: "transfers".to_string(),
+            message: format!(
+                "Batch must contain between 1 and {} transfers, got {}",
+                state.max_bulk_transfers,
+                payload.len()
+            ),
+        }));
+    }
+
+    let results: Vec<BulkTransferResult> = state
+        .service
+        .submit_transfers_bulk(&payload)
+        .await
+        .into_iter()
+        .enumerate()
+        .map(|(index, result)| match result {
+            Ok(request) => BulkTransferResult::accepted(index, &request),
+            Err(e) => BulkTransferResult::failed(index, error_parts(&e).1),
+        })
+        .collect();
+
+    let failed = results.iter().filter(|r| r.error.is_some()).count();
+    info!(
+        total = results.len(),
+        failed, "Bulk transfer submission processed"
+    );
+
+    Ok(Json(BulkTransferResponse {
+        accepted: results.len() - failed,
+        failed,
+        results,
+    }))
 }
 
 /// List transfer requests with pagination
@@ -174,7 +385,13 @@ pub async fn submit_transfer_handler(
     tag = "transfers",
     params(
         ("limit" = Option<i64>, Query, description = "Maximum number of requests to return (1-100, default: 20)"),
-        ("cursor" = Option<String>, Query, description = "Cursor for pagination (request ID to start after)")
+        ("cursor" = Option<String>, Query, description = "Cursor for pagination (request ID to start after)"),
+        ("from_address" = Option<String>, Query, description = "Only transfers sent from this address"),
+        ("to_address" = Option<String>, Query, description = "Only transfers sent to this address"),
+        ("blockchain_status" = Option<crate::domain::BlockchainStatus>, Query, description = "Only transfers in this blockchain status"),
+        ("compliance_status" = Option<crate::domain::ComplianceStatus>, Query, description = "Only transfers in this compliance status"),
+        ("created_after" = Option<String>, Query, description = "Only transfers created at or after this RFC 3339 time"),
+        ("created_before" = Option<String>, Query, description = "Only transfers created before this RFC 3339 time")
     ),
     responses(
         (status = 200, description = "List of transfer requests", body = PaginatedResponse<TransferRequest>),
@@ -189,10 +406,13 @@ pub async fn list_transfer_requests_handler(
 ) -> Result<Json<PaginatedResponse<TransferRequest>>, AppError> {
     // Validate limit
     let limit = params.limit.clamp(1, 100);
-    let requests = state
+    let mut requests = state
         .service
-        .list_transfer_requests(limit, params.cursor.as_deref())
+        .list_transfer_requests(&params.transfer_filter(), limit, params.cursor.as_deref())
         .await?;
+    for request in &mut requests.items {
+        state.link_explorer(request);
+    }
     Ok(Json(requests))
 }
 
@@ -215,11 +435,12 @@ pub async fn get_transfer_request_handler(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
 ) -> Result<Json<TransferRequest>, AppError> {
-    let request = state
+    let mut request = state
         .service
         .get_transfer_request(&id)
         .await?
         .ok_or(AppError::Database(DatabaseError::NotFound(id)))?;
+    state.link_explorer(&mut request);
     Ok(Json(request))
 }
 
@@ -244,21 +465,134 @@ pub async fn retry_blockchain_handler(
     State(state): State<Arc<AppState>>,
     Path(id): Path<String>,
 ) -> Result<Json<TransferRequest>, AppError> {
-    let request = state.service.retry_blockchain_submission(&id).await?;
+    let mut request = state.service.retry_blockchain_submission(&id).await?;
+    state.link_explorer(&mut request);
     Ok(Json(request))
 }
 
+/// Cancel a transfer request before it is submitted to the blockchain
+#[utoipa::path(
+    post,
+    path = "/transfer-requests/{id}/cancel",
+    tag = "transfers",
+    params(
+        ("id" = String, Path, description = "Transfer Request ID")
+    ),
+    responses(
+        (status = 200, description = "Transfer cancelled", body = TransferRequest),
+        (status = 404, description = "Request not found", body = ErrorResponse),
+        (status = 409, description = "Transfer already picked up for submission", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn cancel_transfer_handler(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Json<TransferRequest>, AppError> {
+    let mut request = state.service.cancel_transfer(&id).await?;
+    state.link_explorer(&mut request);
+    Ok(Json(request))
+}
+
+/// Stream blockchain status updates for a transfer request (Server-Sent Events)
+///
+/// Emits a `status` event with the current status on connect, then one per
+/// status update. The stream closes after a terminal status
+/// (`confirmed`, `failed`, `expired` or `cancelled`).
+#[utoipa::path(
+    get,
+    path = "/transfer-requests/{id}/events",
+    tag = "transfers",
+    params(
+        ("id" = String, Path, description = "Transfer Request ID")
+    ),
+    responses(
+        (status = 200, description = "Event stream of status updates", body = TransferStatusEvent, content_type = "text/event-stream"),
+        (status = 404, description = "Request not found", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+pub async fn transfer_events_handler(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, AppError> {
+    // Subscribe before reading the current status so no update is missed
+    let receiver = state.service.subscribe_status_events();
+    let request = state
+        .service
+        .get_transfer_request(&id)
+        .await?
+        .ok_or_else(|| AppError::Database(DatabaseError::NotFound(id.clone())))?;
+    let initial = TransferStatusEvent::from(&request);
+
+    let events = stream::unfold(Some((receiver, Some(initial))), move |stream_state| {
+        let state = Arc::clone(&state);
+        let id = id.clone();
+        async move {
+            let (mut receiver, pending) = stream_state?;
+            let event = match pending {
+                Some(event) => event,
+                None => next_status_event(&state, &mut receiver, &id).await?,
+            };
+
+            let sse = Event::default().event("status").json_data(&event);
+            let next = (!event.blockchain_status.is_terminal()).then_some((receiver, None));
+            Some((sse, next))
+        }
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// Wait for the next status update of transfer `id`.
+/// Returns `None` when the status channel closes.
+async fn next_status_event(
+    state: &AppState,
+    receiver: &mut tokio::sync::broadcast::Receiver<TransferStatusEvent>,
+    id: &str,
+) -> Option<TransferStatusEvent> {
+    loop {
+        match receiver.recv().await {
+            Ok(event) if event.id == id => return Some(event),
+            Ok(_) => {}
+            Err(RecvError::Lagged(skipped)) => {
+                // Updates were dropped; resync from the database
+                warn!(transfer_id = %id, skipped, "Status subscriber lagged, reloading transfer");
+                if let Ok(Some(request)) = state.service.get_transfer_request(id).await {
+                    return Some(TransferStatusEvent::from(&request));
+                }
+            }
+            Err(RecvError::Closed) => return None,
+        }
+    }
+}
+
 /// Detailed health check
+///
+/// With `verbose=true`, the response also lists every probed dependency
+/// with its latency, the service uptime and the pending queue depth.
 #[utoipa::path(
     get,
     path = "/health",
     tag = "health",
+    params(
+        ("verbose" = Option<bool>, Query, description = "Include per-dependency health, uptime and queue depth")
+    ),
     responses(
         (status = 200, description = "Health status", body = HealthResponse)
     )
 )]
-pub async fn health_check_handler(State(state): State<Arc<AppState>>) -> Json<HealthResponse> {
-    let health = state.service.health_check().await;
+pub async fn health_check_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<HealthQuery>,
+) -> Json<HealthResponse> {
+    let health = if query.verbose {
+        state.service.health_check_verbose().await
+    } else {
+        state.service.health_check().await.terse()
+    };
     Json(health)
 }
 
@@ -293,222 +627,78 @@ pub async fn readiness_handler(State(state): State<Arc<AppState>>) -> StatusCode
     }
 }
 
+/// Server clock and clock skew tolerance
+///
+/// Clients can compare `server_time` with their own clock before choosing a
+/// `valid_until`. Deadlines (`valid_until`, checkout expiry) are only enforced
+/// once they are more than `clock_skew_tolerance_secs` in the past.
+#[utoipa::path(
+    get,
+    path = "/time",
+    tag = "health",
+    responses(
+        (status = 200, description = "Server time and clock skew tolerance", body = ServerTimeResponse)
+    )
+)]
+pub async fn server_time_handler(State(state): State<Arc<AppState>>) -> Json<ServerTimeResponse> {
+    Json(ServerTimeResponse {
+        server_time: chrono::Utc::now(),
+        clock_skew_tolerance_secs: state.service.clock_skew_tolerance().num_seconds(),
+    })
+}
+
+/// Prometheus metrics in the text exposition format
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "health",
+    responses(
+        (status = 200, description = "Metrics in Prometheus text format", content_type = "text/plain"),
+        (status = 501, description = "Metrics not configured", body = ErrorResponse)
+    )
+)]
+pub async fn metrics_handler(
+    State(state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    let handle = state
+        .metrics_handle
+        .as_ref()
+        .ok_or_else(|| AppError::NotSupported("Metrics not configured".to_string()))?;
+    Ok((
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        handle.render(),
+    ))
+}
+
 /// Handle Helius webhook for transaction confirmation
 ///
 /// Receives Enhanced Transaction events from Helius and updates transaction status.
-/// Validates the Authorization header against the configured HELIUS_WEBHOOK_SECRET.
+/// When HELIUS_WEBHOOK_SECRET is configured, the Authorization header must hold
+/// that secret (the auth header set on the Helius webhook).
 pub async fn helius_webhook_handler(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(payload): Json<Vec<HeliusTransaction>>,
+    body: Bytes,
 ) -> Result<StatusCode, AppError> {
-    // Validate webhook secret if configured
-    if let Some(expected_secret) = &state.helius_webhook_secret {
-        let auth_header = headers
-            .get("Authorization")
-            .and_then(|v| v.to_str().ok())
-            .ok_or_else(|| AppError::Authentication("Missing Authorization header".to_string()))?;
-
-        if auth_header != expected_secret {
-            return Err(AppError::Authentication(
-                "Invalid webhook secret".to_string(),
-            ));
-        }
-    }
-
-    // Process the webhook payload
-    let tx_count = payload.len();
-    let processed = state.service.process_helius_webhook(payload).await?;
-
-    info!(
-        received = %tx_count,
-        processed = %processed,
-        "Helius webhook processed"
-    );
-
-    Ok(StatusCode::OK)
+    let provider = HeliusWebhookProvider::new(state.helius_webhook_secret.clone());
+    handle_webhook(&state, &provider, &headers, &body).await
 }
 
 /// Handle QuickNode webhook for transaction confirmation
 ///
 /// Receives transaction events from QuickNode Streams/Webhooks and updates transaction status.
-/// Validates the x-qn-signature header against the configured QUICKNODE_WEBHOOK_SECRET.
-///
-/// **IMPORTANT**: This handler accepts ANY valid JSON to avoid 422 errors.
-/// QuickNode Streams can send various payload formats depending on the template/filter configured.
-/// The handler will attempt to extract transaction signatures from the payload.
+/// When QUICKNODE_WEBHOOK_SECRET is configured, the delivery must carry QuickNode's
+/// signature (`x-qn-signature` over `x-qn-nonce`, `x-qn-timestamp` and the raw body)
+/// or the secret itself in `x-qn-signature` or `Authorization`.
 ///
-/// Reference: <https://www.quicknode.com/docs/webhooks>
+/// Accepts any valid JSON; see [`QuickNodeWebhookProvider`] for the payload formats.
 pub async fn quicknode_webhook_handler(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Json(payload): Json<serde_json::Value>,
+    body: Bytes,
 ) -> Result<StatusCode, AppError> {
-    // Log the raw payload for debugging (truncate if too large)
-    let payload_str = serde_json::to_string(&payload).unwrap_or_else(|_| "null".to_string());
-    let truncated_payload = if payload_str.len() > 2000 {
-        format!(
-            "{}... (truncated, {} bytes total)",
-            &payload_str[..2000],
-            payload_str.len()
-        )
-    } else {
-        payload_str.clone()
-    };
-
-    info!(
-        payload = %truncated_payload,
-        "QuickNode webhook received - raw payload"
-    );
-
-    // Validate webhook secret if configured.
-    if let Some(expected_secret) = &state.quicknode_webhook_secret {
-        let qn_signature = headers.get("x-qn-signature").and_then(|v| v.to_str().ok());
-        let authorization = headers.get("Authorization").and_then(|v| v.to_str().ok());
-
-        info!(
-            has_qn_signature = qn_signature.is_some(),
-            has_authorization = authorization.is_some(),
-            "QuickNode webhook headers"
-        );
-
-        let has_matching_secret = qn_signature.is_some_and(|value| value == expected_secret)
-            || authorization.is_some_and(|value| value == expected_secret);
-
-        if !has_matching_secret {
-            if qn_signature.is_some() || authorization.is_some() {
-                return Err(AppError::Authentication(
-                    "Invalid QuickNode webhook secret".to_string(),
-                ));
-            } else {
-                return Err(AppError::Authentication(
-                    "Missing QuickNode webhook secret".to_string(),
-                ));
-            }
-        }
-    }
-
-    // Try to parse the payload into events
-    let events = parse_quicknode_payload(&payload);
-    let event_count = events.len();
-
-    if event_count == 0 {
-        info!(
-            "QuickNode webhook: No extractable signatures found in payload. \
-             This may be a verification ping or unrecognized format."
-        );
-        // Still return OK to satisfy QuickNode verification
-        return Ok(StatusCode::OK);
-    }
-
-    info!(
-        event_count = %event_count,
-        signatures = ?events.iter().map(|e| &e.signature).collect::<Vec<_>>(),
-        "Processing QuickNode webhook batch"
-    );
-
-    // Process ALL events in the batch
-    let processed = state.service.process_quicknode_webhook(events).await?;
-
-    info!(
-        received = %event_count,
-        processed = %processed,
-        "QuickNode webhook processed"
-    );
-
-    Ok(StatusCode::OK)
-}
-
-/// Parse QuickNode webhook payload into events
-///
-/// Attempts to extract transaction signatures from various QuickNode payload formats:
-/// 1. Array of objects with "signature" field
-/// 2. Single object with "signature" field
-/// 3. Array of objects with "transaction" -> "signatures" array
-/// 4. Nested "data" or "transactions" arrays
-fn parse_quicknode_payload(payload: &serde_json::Value) -> Vec<QuickNodeWebhookEvent> {
-    let mut events = Vec::new();
-
-    // Helper to create event from signature and optional fields
-    let create_event = |sig: &str, obj: Option<&serde_json::Value>| -> QuickNodeWebhookEvent {
-        let (slot, block_time, err) = if let Some(o) = obj {
-            (
-                o.get("slot").and_then(|v| v.as_u64()),
-                o.get("blockTime")
-                    .or_else(|| o.get("block_time"))
-                    .and_then(|v| v.as_i64()),
-                o.get("err").cloned().filter(|v| !v.is_null()),
-            )
-        } else {
-            (None, None, None)
-        };
-        QuickNodeWebhookEvent {
-            signature: sig.to_string(),
-            slot,
-            block_time,
-            err,
-            meta: None,
-        }
-    };
-
-    // Try parsing as array
-    if let Some(arr) = payload.as_array() {
-        for item in arr {
-            // Direct signature field
-            if let Some(sig) = item.get("signature").and_then(|v| v.as_str()) {
-                events.push(create_event(sig, Some(item)));
-            }
-            // Nested transaction.signatures array (common in Solana RPC format)
-            else if let Some(tx) = item.get("transaction")
-                && let Some(sigs) = tx.get("signatures").and_then(|v| v.as_array())
-                && let Some(first_sig) = sigs.first().and_then(|v| v.as_str())
-            {
-                events.push(create_event(first_sig, Some(item)));
-            }
-            // Try "data" wrapper
-            else if let Some(data) = item.get("data")
-                && let Some(sig) = data.get("signature").and_then(|v| v.as_str())
-            {
-                events.push(create_event(sig, Some(data)));
-            }
-        }
-    }
-    // Try parsing as single object
-    else if let Some(obj) = payload.as_object() {
-        // Direct signature field
-        if let Some(sig) = obj.get("signature").and_then(|v| v.as_str()) {
-            events.push(create_event(sig, Some(payload)));
-        }
-        // Nested transaction.signatures
-        else if let Some(tx) = obj.get("transaction")
-            && let Some(sigs) = tx.get("signatures").and_then(|v| v.as_array())
-            && let Some(first_sig) = sigs.first().and_then(|v| v.as_str())
-        {
-            events.push(create_event(first_sig, Some(payload)));
-        }
-        // Check for "data" array wrapper
-        else if let Some(data_arr) = obj.get("data").and_then(|v| v.as_array()) {
-            for item in data_arr {
-                if let Some(sig) = item.get("signature").and_then(|v| v.as_str()) {
-                    events.push(create_event(sig, Some(item)));
-                }
-            }
-        }
-        // Check for "transactions" array
-        else if let Some(txs) = obj.get("transactions").and_then(|v| v.as_array()) {
-            for tx in txs {
-                if let Some(sig) = tx.get("signature").and_then(|v| v.as_str()) {
-                    events.push(create_event(sig, Some(tx)));
-                } else if let Some(sigs) = tx.get("signatures").and_then(|v| v.as_array())
-                    && let Some(first_sig) = sigs.first().and_then(|v| v.as_str())
-                {
-                    events.push(create_event(first_sig, Some(tx)));
-                }
-            }
-        }
-    }
-
-    events
+    let provider = QuickNodeWebhookProvider::new(state.quicknode_webhook_secret.clone());
+    handle_webhook(&state, &provider, &headers, &body).await
 }
 
 /// Check wallet risk status (pre-flight compliance check)
@@ -553,125 +743,180 @@ pub async fn risk_check_handler(
     Ok(Json(result))
 }
 
-impl IntoResponse for AppError {
-    fn into_response(self) -> axum::response::Response {
-        let (status, error_type, message) = match &self {
-            AppError::Database(db_err) => match db_err {
-                DatabaseError::Connection(_) => (
-                    StatusCode::SERVICE_UNAVAILABLE,
-                    "database_error",
-                    self.to_string(),
-                ),
-                DatabaseError::NotFound(_) => {
-                    (StatusCode::NOT_FOUND, "not_found", self.to_string())
-                }
-                DatabaseError::Duplicate(_) => {
-                    (StatusCode::CONFLICT, "duplicate", self.to_string())
-                }
-                _ => (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "database_error",
-                    self.to_string(),
-                ),
-            },
-            AppError::Blockchain(bc_err) => match bc_err {
-                BlockchainError::Connection(_) => (
-                    StatusCode::SERVICE_UNAVAILABLE,
-                    "blockchain_error",
-                    self.to_string(),
-                ),
-                BlockchainError::InsufficientFunds => (
-                    StatusCode::PAYMENT_REQUIRED,
-                    "insufficient_funds",
-                    self.to_string(),
-                ),
-                BlockchainError::Timeout(_) => {
-                    (StatusCode::GATEWAY_TIMEOUT, "timeout", self.to_string())
-                }
-                _ => (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "blockchain_error",
-                    self.to_string(),
-                ),
-            },
-            AppError::ExternalService(ext_err) => match ext_err {
-                ExternalServiceError::Unavailable(_) => (
-                    StatusCode::BAD_GATEWAY,
-                    "external_service_error",
-                    self.to_string(),
-                ),
-                ExternalServiceError::Timeout(_) => {
-                    (StatusCode::GATEWAY_TIMEOUT, "timeout", self.to_string())
-                }
-                ExternalServiceError::RateLimited(_) => (
-                    StatusCode::TOO_MANY_REQUESTS,
-                    "rate_limited",
-                    self.to_string(),
-                ),
-                _ => (
-                    StatusCode::BAD_GATEWAY,
-                    "external_service_error",
-                    self.to_string(),
-                ),
-            },
-            AppError::Config(_) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "configuration_error",
-                self.to_string(),
-            ),
-            AppError::Validation(_) => (
-                StatusCode::BAD_REQUEST,
-                "validation_error",
-                self.to_string(),
+/// Check the risk status of several wallets at once
+///
+/// Each address is checked like `POST /risk-check`. Repeated addresses are
+/// looked up once, cached profiles are reused, and the remaining lookups run
+/// concurrently. The response holds one result per requested address, in
+/// request order.
+#[utoipa::path(
+    post,
+    path = "/risk/bulk",
+    tag = "compliance",
+    request_body = BulkRiskCheckRequest,
+    responses(
+        (status = 200, description = "Per-address results", body = BulkRiskCheckResponse),
+        (status = 400, description = "Empty list, blank address or more addresses than the maximum", body = ErrorResponse),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+        (status = 501, description = "Risk service not configured", body = ErrorResponse)
+    )
+)]
+pub async fn bulk_risk_check_handler(
+    State(state): State<Arc<AppState>>,
+    Json(payload): Json<BulkRiskCheckRequest>,
+) -> Result<Json<BulkRiskCheckResponse>, AppError> {
+    let count = payload.addresses.len();
+    if count == 0 || count > MAX_BULK_RISK_CHECK_ADDRESSES {
+        return Err(AppError::Validation(ValidationError::InvalidField {
+            field: "addresses".to_string(),
+            message: format!(
+                "Request must contain between 1 and {} addresses, got {}",
+                MAX_BULK_RISK_CHECK_ADDRESSES, count
             ),
-            AppError::Authentication(_) => (
-                StatusCode::UNAUTHORIZED,
-                "authentication_error",
-                self.to_string(),
+        }));
+    }
+    if payload.addresses.iter().any(|a| a.trim().is_empty()) {
+        return Err(AppError::Validation(ValidationError::InvalidField {
+            field: "addresses".to_string(),
+            message: "Addresses must not be blank".to_string(),
+        }));
+    }
+
+    let risk_service = state
+        .risk_service
+        .as_ref()
+        .ok_or_else(|| AppError::NotSupported("Risk check service not configured".to_string()))?;
+
+    let results = risk_service.check_wallets_risk(&payload.addresses).await?;
+    Ok(Json(BulkRiskCheckResponse { results }))
+}
+
+/// HTTP status and error detail (type, code and message) for an error
+fn error_parts(error: &AppError) -> (StatusCode, ErrorDetail) {
+    let (status, error_type, code) = match error {
+        AppError::Database(db_err) => match db_err {
+            DatabaseError::Connection(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "database_error",
+                ErrorCode::DatabaseUnavailable,
             ),
-            AppError::Authorization(_) => (
-                StatusCode::FORBIDDEN,
-                "authorization_error",
-                self.to_string(),
+            DatabaseError::NotFound(_) => (StatusCode::NOT_FOUND, "not_found", ErrorCode::NotFound),
+            DatabaseError::Duplicate(_) => (
+                StatusCode::CONFLICT,
+                "duplicate",
+                ErrorCode::DuplicateRecord,
             ),
-            AppError::Serialization(_) => (
+            _ => (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                "serialization_error",
-                self.to_string(),
+                "database_error",
+                ErrorCode::DatabaseError,
             ),
-            AppError::Deserialization(_) => (
-                StatusCode::BAD_REQUEST,
-                "deserialization_error",
-                self.to_string(),
+        },
+        AppError::Blockchain(bc_err) => match bc_err {
+            BlockchainError::Connection(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "blockchain_error",
+                ErrorCode::BlockchainUnavailable,
             ),
-            AppError::Internal(_) => (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "internal_error",
-                self.to_string(),
+            BlockchainError::InsufficientFunds => (
+                StatusCode::PAYMENT_REQUIRED,
+                "insufficient_funds",
+                ErrorCode::InsufficientFunds,
             ),
-            AppError::NotSupported(_) => (
-                StatusCode::NOT_IMPLEMENTED,
-                "not_supported",
-                self.to_string(),
+            BlockchainError::Timeout(_) => {
+                (StatusCode::GATEWAY_TIMEOUT, "timeout", ErrorCode::Timeout)
+            }
+            _ => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "blockchain_error",
+                ErrorCode::BlockchainError,
             ),
-            AppError::RateLimited => (
+        },
+        AppError::ExternalService(ext_err) => match ext_err {
+            ExternalServiceError::Timeout(_) => {
+                (StatusCode::GATEWAY_TIMEOUT, "timeout", ErrorCode::Timeout)
+            }
+            ExternalServiceError::RateLimited(_) => (
                 StatusCode::TOO_MANY_REQUESTS,
                 "rate_limited",
-                "Rate limit exceeded".to_string(),
+                ErrorCode::RateLimited,
             ),
-        };
+            _ => (
+                StatusCode::BAD_GATEWAY,
+                "external_service_error",
+                ErrorCode::ExternalServiceError,
+            ),
+        },
+        AppError::Config(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "configuration_error",
+            ErrorCode::InternalError,
+        ),
+        AppError::Validation(val_err) => {
+            let code = match val_err {
+                ValidationError::Blocklisted(_) => ErrorCode::Blocklisted,
+                ValidationError::ComplianceRejected(_) => ErrorCode::ComplianceRejected,
+                ValidationError::DuplicateRequest { .. } => ErrorCode::DuplicateNonce,
+                ValidationError::TransferLimitExceeded(_) => ErrorCode::TransferLimitExceeded,
+                _ => ErrorCode::ValidationFailed,
+            };
+            (StatusCode::BAD_REQUEST, "validation_error", code)
+        }
+        AppError::Authentication(_) => (
+            StatusCode::UNAUTHORIZED,
+            "authentication_error",
+            ErrorCode::AuthenticationFailed,
+        ),
+        AppError::Authorization(_) => (
+            StatusCode::FORBIDDEN,
+            "authorization_error",
+            ErrorCode::AuthorizationFailed,
+        ),
+        AppError::Serialization(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "serialization_error",
+            ErrorCode::InternalError,
+        ),
+        AppError::Deserialization(_) => (
+            StatusCode::BAD_REQUEST,
+            "deserialization_error",
+            ErrorCode::MalformedRequest,
+        ),
+        AppError::Internal(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "internal_error",
+            ErrorCode::InternalError,
+        ),
+        AppError::NotSupported(_) => (
+            StatusCode::NOT_IMPLEMENTED,
+            "not_supported",
+            ErrorCode::NotSupported,
+        ),
+        AppError::Conflict(_) => (StatusCode::CONFLICT, "conflict", ErrorCode::Conflict),
+        AppError::RateLimited => (
+            StatusCode::TOO_MANY_REQUESTS,
+            "rate_limited",
+            ErrorCode::RateLimited,
+        ),
+    };
+
+    let detail = ErrorDetail {
+        r#type: error_type.to_string(),
+        code,
+        message: error.to_string(),
+    };
+    (status, detail)
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> axum::response::Response {
+        let (status, detail) = error_parts(&self);
 
         if status.is_server_error() {
-            error!(error_type = %error_type, message = %message, "Server error");
+            error!(error_type = %detail.r#type, message = %detail.message, "Server error");
         }
 
-        let body = Json(ErrorResponse {
-            error: ErrorDetail {
-                r#type: error_type.to_string(),
-                message,
-            },
-        });
-
-        (status, body).into_response()
+        (status, Json(ErrorResponse { error: detail })).into_response()
     }
 }
@@ -0,0 +1,201 @@
+//! `Idempotency-Key` support for transfer submission.
+//!
+//! The first response produced for a key is recorded together with a SHA-256
+//! fingerprint of the request body. Retrying with the same key and body gets
+//! that response back verbatim (status code and body) without submitting
+//! again; reusing the key with a different body is rejected with 409.
+//! Server errors and rate-limit responses are not recorded, so such requests
+//! can be retried under the same key.
+//!
+//! Keys are scoped to the API key presented with the request, so a client
+//! cannot replay another client's response by reusing its key. Records are
+//! replayed for [`IDEMPOTENCY_KEY_TTL`] and then purged by the idempotency
+//! sweep.
+
+use std::time::Duration;
+
+use axum::{
+    body::{Body, to_bytes},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::Response,
+};
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+
+use super::api_key::presented_api_key;
+use crate::domain::{AppError, IdempotencyRecord, ValidationError};
+
+/// Header carrying the client-chosen idempotency key
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Header set on responses replayed from an earlier request
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "Idempotent-Replayed";
+
+/// Longest idempotency key that is accepted
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// How long a recorded response is replayed (24 hours)
+pub const IDEMPOTENCY_KEY_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Idempotency key presented by the caller, if any.
+/// A present key must be non-empty visible ASCII of at most
+/// `MAX_IDEMPOTENCY_KEY_LEN` bytes.
+pub(crate) fn presented_idempotency_key(headers: &HeaderMap) -> Result<Option<&str>, AppError> {
+    let Some(value) = headers.get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(None);
+    };
+    value
+        .to_str()
+        .ok()
+        .map(str::trim)
+        .filter(|key| {
+            !key.is_empty()
+                && key.len() <= MAX_IDEMPOTENCY_KEY_LEN
+                && key.bytes().all(|b| b.is_ascii_graphic())
+        })
+        .map(Some)
+        .ok_or_else(|| {
+            AppError::Validation(ValidationError::InvalidField {
+                field: IDEMPOTENCY_KEY_HEADER.to_string(),
+                message: format!(
+                    "Must be 1-{} visible ASCII characters",
+                    MAX_IDEMPOTENCY_KEY_LEN
+                ),
+            })
+        })
+}
+
+/// SHA-256 (hex) of a request body
+pub(crate) fn request_fingerprint(body: &[u8]) -> String {
+    hex::encode(Sha256::digest(body))
+}
+
+/// Scope of the request's idempotency key: the SHA-256 (hex) of the API key
+/// presented with it, or empty without one. The API key itself is not stored.
+pub(crate) fn idempotency_scope(headers: &HeaderMap) -> String {
+    presented_api_key(headers)
+        .map(|api_key| hex::encode(Sha256::digest(api_key.as_bytes())))
+        .unwrap_or_default()
+}
+
+/// Whether a response is recorded for replay.
+/// Server errors and rate limiting are transient, so they are not.
+fn is_recorded(status: StatusCode) -> bool {
+    !status.is_server_error() && status != StatusCode::TOO_MANY_REQUESTS
+}
+
+/// Record `response` under `key` in `scope` if it is replayable.
+/// Returns the response to send, with its body buffered when it was recorded.
+pub(crate) async fn record_response(
+    scope: String,
+    key: &str,
+    request_hash: String,
+    transfer_id: Option<String>,
+    response: Response,
+) -> Result<(Response, Option<IdempotencyRecord>), AppError> {
+    if !is_recorded(response.status()) {
+        return Ok((response, None));
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = to_bytes(body, usize::MAX)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to buffer response body: {}", e)))?;
+    let created_at = Utc::now();
+    let record = IdempotencyRecord {
+        scope,
+        key: key.to_string(),
+        request_hash,
+        status_code: parts.status.as_u16(),
+        response_body: String::from_utf8_lossy(&bytes).into_owned(),
+        transfer_id,
+        created_at,
+        expires_at: created_at + IDEMPOTENCY_KEY_TTL,
+    };
+    Ok((Response::from_parts(parts, Body::from(bytes)), Some(record)))
+}
+
+/// Rebuild the recorded response, marked as a replay
+pub(crate) fn replay_response(record: &IdempotencyRecord) -> Response {
+    let mut response = Response::new(Body::from(record.response_body.clone()));
+    *response.status_mut() = StatusCode::from_u16(record.status_code).unwrap_or(StatusCode::OK);
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("application/json"),
+    );
+    headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presented_idempotency_key_validation() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(presented_idempotency_key(&headers).unwrap(), None);
+
+        headers.insert(IDEMPOTENCY_KEY_HEADER, "  order-42  ".parse().unwrap());
+        assert_eq!(
+            presented_idempotency_key(&headers).unwrap(),
+            Some("order-42")
+        );
+
+        let too_long = "x".repeat(MAX_IDEMPOTENCY_KEY_LEN + 1);
+        for invalid in ["", "has space", too_long.as_str()] {
+            headers.insert(IDEMPOTENCY_KEY_HEADER, invalid.parse().unwrap());
+            assert!(
+                presented_idempotency_key(&headers).is_err(),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_idempotency_scope_hashes_presented_api_key() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(idempotency_scope(&headers), "");
+
+        headers.insert("X-API-Key", "key-a".parse().unwrap());
+        let scope = idempotency_scope(&headers);
+        assert_eq!(scope, request_fingerprint(b"key-a"));
+
+        headers.insert("X-API-Key", "key-b".parse().unwrap());
+        assert_ne!(idempotency_scope(&headers), scope);
+    }
+
+    #[tokio::test]
+    async fn test_records_only_replayable_responses() {
+        let ok = Response::new(Body::from(r#"{"id":"tr_1"}"#));
+        let (response, record) =
+            record_response(String::new(), "k", request_fingerprint(b"{}"), None, ok)
+                .await
+                .unwrap();
+        let record = record.unwrap();
+        assert_eq!(record.status_code, 200);
+        assert_eq!(record.created_at + IDEMPOTENCY_KEY_TTL, record.expires_at);
+        assert_eq!(record.response_body, r#"{"id":"tr_1"}"#);
+        assert_eq!(record.request_hash, request_fingerprint(b"{}"));
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&body[..], record.response_body.as_bytes());
+
+        let replayed = replay_response(&record);
+        assert_eq!(replayed.status(), StatusCode::OK);
+        assert_eq!(replayed.headers()[IDEMPOTENT_REPLAYED_HEADER], "true");
+
+        for status in [
+            StatusCode::SERVICE_UNAVAILABLE,
+            StatusCode::TOO_MANY_REQUESTS,
+        ] {
+            let mut response = Response::new(Body::empty());
+            *response.status_mut() = status;
+            let (_, record) = record_response(String::new(), "k", String::new(), None, response)
+                .await
+                .unwrap();
+            assert!(record.is_none(), "{}", status);
+        }
+    }
+}
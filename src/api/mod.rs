@@ -1,18 +1,43 @@
 //! The API layer, containing web handlers and routing.
 
 pub mod admin;
+pub mod api_key;
 pub mod audit;
 pub mod checkout;
 pub mod handlers;
+pub mod idempotency;
+pub mod request_id;
 pub mod router;
+pub mod webhook_auth;
+pub mod webhooks;
 
 pub use admin::{
-    AddBlocklistRequest, BlocklistEntryResponse, BlocklistResponse, ListBlocklistResponse,
-    add_blocklist_handler, list_blocklist_handler, remove_blocklist_handler,
+    AddAllowlistRequest, AddBlocklistRequest, AllowlistEntryResponse, BlocklistEntryResponse,
+    BlocklistResponse, BulkBlocklistResponse, InvalidBlocklistEntry, ListAllowlistResponse,
+    ListBlocklistResponse, ProcessingPauseResponse, add_allowlist_handler, add_blocklist_handler,
+    bulk_add_blocklist_handler, list_allowlist_handler, list_blocklist_handler,
+    pause_worker_handler, reconcile_transfer_handler, remove_allowlist_handler,
+    remove_blocklist_handler, resume_worker_handler,
+};
+pub use api_key::{ApiKeyLayer, ApiKeyService};
+pub use audit::{
+    get_transfer_audit_report_handler, get_transfer_history_handler,
+    list_compliance_decisions_handler,
 };
-pub use audit::get_transfer_audit_report_handler;
 pub use checkout::{
     create_checkout_session_handler, get_checkout_session_handler, submit_checkout_transfer_handler,
 };
 pub use handlers::ApiDoc;
-pub use router::{RateLimitConfig, create_router, create_router_with_rate_limit};
+pub use idempotency::{IDEMPOTENCY_KEY_HEADER, IDEMPOTENCY_KEY_TTL, IDEMPOTENT_REPLAYED_HEADER};
+pub use request_id::{REQUEST_ID_HEADER, request_id_middleware};
+pub use router::{
+    RateLimitConfig, RateLimitRoute, RouteRateLimit, create_router, create_router_with_rate_limit,
+    parse_route_limits,
+};
+pub use webhook_auth::{
+    QuickNodeSignature, verify_helius_auth_header, verify_quicknode_signature,
+    verify_webhook_signature,
+};
+pub use webhooks::{
+    HeliusWebhookProvider, QuickNodeWebhookProvider, WebhookProvider, handle_webhook,
+};
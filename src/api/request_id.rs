@@ -0,0 +1,125 @@
+//! Per-request correlation ids.
+//!
+//! Every request gets an id: the caller's `X-Request-Id` when it is usable,
+//! otherwise a fresh UUID. The id is recorded on a `correlation` tracing span,
+//! made available to the service layer through [`crate::app::correlation`]
+//! (and from there persisted on new transfers), and echoed back in the
+//! `X-Request-Id` response header.
+//!
+//! When traces are exported over OTLP, the `correlation` span continues the
+//! caller's trace given by its W3C `traceparent` header.
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, Request, Response},
+    middleware::Next,
+};
+use opentelemetry::propagation::Extractor;
+use tracing::{Instrument, info_span};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+use crate::app::correlation::with_correlation_id;
+
+/// Header carrying the correlation id of a request
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Longest caller-provided request id that is accepted
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Request id presented by the caller, if it is non-empty, at most
+/// `MAX_REQUEST_ID_LEN` bytes and printable ASCII without spaces
+fn presented_request_id(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|id| {
+            !id.is_empty()
+                && id.len() <= MAX_REQUEST_ID_LEN
+                && id.bytes().all(|b| b.is_ascii_graphic())
+        })
+}
+
+/// Reads propagation headers (`traceparent`, `tracestate`) from a request
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|name| name.as_str()).collect()
+    }
+}
+
+/// Trace context presented by the caller. Empty unless OTLP export installed
+/// the W3C propagator.
+fn remote_trace_context(headers: &HeaderMap) -> opentelemetry::Context {
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(headers))
+    })
+}
+
+/// Assign a correlation id to the request and echo it in the response
+pub async fn request_id_middleware(request: Request<Body>, next: Next) -> Response<Body> {
+    let request_id = presented_request_id(request.headers())
+        .map(String::from)
+        .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+    let span = info_span!("correlation", request_id = %request_id);
+    span.set_parent(remote_trace_context(request.headers()));
+    let mut response = with_correlation_id(request_id.clone(), next.run(request))
+        .instrument(span)
+        .await;
+
+    if let Ok(value) = request_id.parse() {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presented_request_id_validation() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(presented_request_id(&headers), None);
+
+        headers.insert(REQUEST_ID_HEADER, "  abc-123  ".parse().unwrap());
+        assert_eq!(presented_request_id(&headers), Some("abc-123"));
+
+        let too_long = "x".repeat(MAX_REQUEST_ID_LEN + 1);
+        for invalid in ["", "has space", too_long.as_str()] {
+            headers.insert(REQUEST_ID_HEADER, invalid.parse().unwrap());
+            assert_eq!(presented_request_id(&headers), None, "{:?}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_remote_trace_context_from_traceparent() {
+        use opentelemetry::trace::TraceContextExt;
+        use opentelemetry_sdk::propagation::TraceContextPropagator;
+
+        opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+        let mut headers = HeaderMap::new();
+        assert!(!remote_trace_context(&headers).has_active_span());
+
+        headers.insert(
+            "traceparent",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+                .parse()
+                .unwrap(),
+        );
+        let context = remote_trace_context(&headers);
+        let span_context = context.span().span_context().clone();
+        assert!(span_context.is_remote());
+        assert_eq!(
+            span_context.trace_id().to_string(),
+            "4bf92f3577b34da6a3ce929d0e0e4736"
+        );
+        assert_eq!(span_context.span_id().to_string(), "00f067aa0ba902b7");
+    }
+}
@@ -1,5 +1,6 @@
 //! HTTP routing configuration with rate limiting and OpenAPI documentation.
 
+use std::collections::{HashMap, HashSet};
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::num::NonZeroU32;
 use std::sync::Arc;
@@ -28,18 +29,102 @@ use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::app::AppState;
-use crate::domain::{ErrorDetail, ErrorResponse, RateLimitResponse};
+use crate::app::risk_service::MAX_BULK_RISK_CHECK_ADDRESSES;
+use crate::domain::{
+    ApiKeyScope, BulkRiskCheckRequest, ConfigError, ErrorCode, ErrorDetail, RateLimitResponse,
+};
 
-use super::admin::{add_blocklist_handler, list_blocklist_handler, remove_blocklist_handler};
-use super::audit::get_transfer_audit_report_handler;
+use super::admin::{
+    add_allowlist_handler, add_blocklist_handler, bulk_add_blocklist_handler,
+    list_allowlist_handler, list_blocklist_handler, pause_worker_handler,
+    reconcile_transfer_handler, remove_allowlist_handler, remove_blocklist_handler,
+    resume_worker_handler,
+};
+use super::api_key::{ApiKeyLayer, presented_api_key};
+use super::audit::{
+    get_transfer_audit_report_handler, get_transfer_history_handler,
+    list_compliance_decisions_handler,
+};
 use super::checkout::{
     create_checkout_session_handler, get_checkout_session_handler, submit_checkout_transfer_handler,
 };
 use super::handlers::{
-    ApiDoc, get_transfer_request_handler, health_check_handler, helius_webhook_handler,
-    list_transfer_requests_handler, liveness_handler, quicknode_webhook_handler, readiness_handler,
-    retry_blockchain_handler, risk_check_handler, submit_transfer_handler,
+    ApiDoc, bulk_risk_check_handler, cancel_transfer_handler, estimate_fee_handler,
+    get_transfer_request_handler, health_check_handler, helius_webhook_handler,
+    list_transfer_requests_handler, liveness_handler, metrics_handler, quicknode_webhook_handler,
+    readiness_handler, retry_blockchain_handler, risk_check_handler, server_time_handler,
+    submit_bulk_transfers_handler, submit_transfer_handler, transfer_events_handler,
 };
+use super::request_id::{REQUEST_ID_HEADER, request_id_middleware};
+
+/// Largest `POST /risk/bulk` body the rate limiter reads to weigh a request
+/// (axum's default JSON body limit)
+const MAX_BULK_RISK_CHECK_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Route groups with their own rate limit budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitRoute {
+    /// `POST /transfer-requests` and `/transfer-requests/bulk` (compliance screening and proof handling)
+    Submit,
+    /// All other `/transfer-requests` routes
+    Transfers,
+    /// `/checkout` routes
+    Checkout,
+    /// `/admin` routes
+    Admin,
+    /// `POST /risk-check`
+    RiskCheck,
+    /// `POST /risk/bulk`, charged one unit per requested address
+    RiskBulk,
+    /// `/health` routes
+    Health,
+}
+
+impl RateLimitRoute {
+    /// All route groups
+    pub const ALL: [Self; 7] = [
+        Self::Submit,
+        Self::Transfers,
+        Self::Checkout,
+        Self::Admin,
+        Self::RiskCheck,
+        Self::RiskBulk,
+        Self::Health,
+    ];
+
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Submit => "submit",
+            Self::Transfers => "transfers",
+            Self::Checkout => "checkout",
+            Self::Admin => "admin",
+            Self::RiskCheck => "risk_check",
+            Self::RiskBulk => "risk_bulk",
+            Self::Health => "health",
+        }
+    }
+}
+
+impl std::str::FromStr for RateLimitRoute {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|route| route.as_str() == s)
+            .ok_or_else(|| format!("Unknown rate limit route: {}", s))
+    }
+}
+
+/// Rate limit budget for a single route group
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteRateLimit {
+    /// Requests per second
+    pub rps: u32,
+    /// Burst size
+    pub burst: u32,
+}
 
 /// Rate limiter configuration
 #[derive(Debug, Clone)]
@@ -52,6 +137,8 @@ pub struct RateLimitConfig {
     pub health_rps: u32,
     /// Burst size for health endpoints
     pub health_burst: u32,
+    /// Per-route overrides of the general/health budgets
+    pub route_limits: HashMap<RateLimitRoute, RouteRateLimit>,
 }
 
 impl Default for RateLimitConfig {
@@ -61,13 +148,14 @@ impl Default for RateLimitConfig {
             general_burst: 20,
             health_rps: 100,
             health_burst: 100,
+            route_limits: HashMap::new(),
         }
     }
 }
 
 impl RateLimitConfig {
     /// Create config from environment variables
-    pub fn from_env() -> Self {
+    pub fn from_env() -> Result<Self, ConfigError> {
         let general_rps = std::env::var("RATE_LIMIT_RPS")
             .ok()
             .and_then(|v| v.parse().ok())
@@ -77,77 +165,215 @@ impl RateLimitConfig {
             .and_then(|v| v.parse().ok())
             .unwrap_or(20);
 
-        Self {
+        // Per-route overrides, e.g. "submit=2:5,admin=5:10"
+        let route_limits = match std::env::var("RATE_LIMIT_ROUTES") {
+            Ok(v) => parse_route_limits(&v).map_err(|message| ConfigError::InvalidValue {
+                key: "RATE_LIMIT_ROUTES".to_string(),
+                message,
+            })?,
+            Err(_) => HashMap::new(),
+        };
+
+        Ok(Self {
             general_rps,
             general_burst,
             health_rps: 100,
             health_burst: 100,
+            route_limits,
+        })
+    }
+
+    /// Budget for a route group: its override, or the health/general default.
+    /// The bulk risk check defaults to the general rate with a burst that
+    /// fits one full-size request.
+    #[must_use]
+    pub fn limit_for(&self, route: RateLimitRoute) -> RouteRateLimit {
+        if let Some(limit) = self.route_limits.get(&route) {
+            return *limit;
         }
+        match route {
+            RateLimitRoute::Health => RouteRateLimit {
+                rps: self.health_rps,
+                burst: self.health_burst,
+            },
+            RateLimitRoute::RiskBulk => RouteRateLimit {
+                rps: self.general_rps,
+                burst: self.general_burst.max(MAX_BULK_RISK_CHECK_ADDRESSES as u32),
+            },
+            _ => RouteRateLimit {
+                rps: self.general_rps,
+                burst: self.general_burst,
+            },
+        }
+    }
+}
+
+/// Parse per-route overrides in the form `submit=2:5,admin=5:10` (`route=rps:burst`).
+/// Whitespace around entries is ignored; rps and burst must be positive.
+pub fn parse_route_limits(raw: &str) -> Result<HashMap<RateLimitRoute, RouteRateLimit>, String> {
+    let mut limits = HashMap::new();
+    for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let invalid = || {
+            format!(
+                "Invalid route rate limit '{}': expected route=rps:burst",
+                entry
+            )
+        };
+        let (route, budget) = entry.split_once('=').ok_or_else(invalid)?;
+        let route = route.trim().parse::<RateLimitRoute>()?;
+        let (rps, burst) = budget.split_once(':').ok_or_else(invalid)?;
+        let parse_positive = |value: &str| {
+            value
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|v| *v > 0)
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid route rate limit '{}': rps and burst must be positive integers",
+                        entry
+                    )
+                })
+        };
+        limits.insert(
+            route,
+            RouteRateLimit {
+                rps: parse_positive(rps)?,
+                burst: parse_positive(burst)?,
+            },
+        );
     }
+    Ok(limits)
+}
+
+/// Bucket key: a recognized API key, or the client IP
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum RateLimitKey {
+    ApiKey(String),
+    Ip(IpAddr),
 }
 
-/// Shared rate limiter state (keyed by client IP to prevent single-user DoS)
+type KeyedRateLimiter = RateLimiter<RateLimitKey, DashMapStateStore<RateLimitKey>, DefaultClock>;
+
+/// Shared rate limiter state, one keyed limiter per route group.
+/// Buckets are keyed by API key for recognized keys and by client IP otherwise.
 pub struct RateLimitState {
-    transfers_limiter: RateLimiter<IpAddr, DashMapStateStore<IpAddr>, DefaultClock>,
-    health_limiter: RateLimiter<IpAddr, DashMapStateStore<IpAddr>, DefaultClock>,
-    config: RateLimitConfig,
+    limiters: HashMap<RateLimitRoute, (KeyedRateLimiter, RouteRateLimit)>,
+    /// Keys that get their own bucket. Unknown keys fall back to the client IP,
+    /// so rotating made-up keys cannot be used to escape the limit.
+    api_keys: HashSet<String>,
 }
 
 impl RateLimitState {
     pub fn new(config: RateLimitConfig) -> Self {
-        let transfers_quota = Quota::per_second(
-            NonZeroU32::new(config.general_rps)
-                .expect("Invalid configuration: general_rps rate limit cannot be 0"),
-        )
-        .allow_burst(
-            NonZeroU32::new(config.general_burst)
-                .expect("Invalid configuration: general_burst rate limit cannot be 0"),
-        );
-        let health_quota = Quota::per_second(
-            NonZeroU32::new(config.health_rps)
-                .expect("Invalid configuration: health_rps rate limit cannot be 0"),
-        )
-        .allow_burst(
-            NonZeroU32::new(config.health_burst)
-                .expect("Invalid configuration: health_burst rate limit cannot be 0"),
-        );
+        let limiters = RateLimitRoute::ALL
+            .into_iter()
+            .map(|route| {
+                let limit = config.limit_for(route);
+                let quota = Quota::per_second(NonZeroU32::new(limit.rps).unwrap_or_else(|| {
+                    panic!(
+                        "Invalid configuration: {} rate limit rps cannot be 0",
+                        route.as_str()
+                    )
+                }))
+                .allow_burst(NonZeroU32::new(limit.burst).unwrap_or_else(|| {
+                    panic!(
+                        "Invalid configuration: {} rate limit burst cannot be 0",
+                        route.as_str()
+                    )
+                }));
+                (route, (RateLimiter::keyed(quota), limit))
+            })
+            .collect();
 
         Self {
-            transfers_limiter: RateLimiter::keyed(transfers_quota),
-            health_limiter: RateLimiter::keyed(health_quota),
-            config,
+            limiters,
+            api_keys: HashSet::new(),
+        }
+    }
+
+    /// Give these API keys their own buckets instead of per-IP ones (builder pattern)
+    #[must_use]
+    pub fn with_api_keys(mut self, api_keys: impl IntoIterator<Item = String>) -> Self {
+        self.api_keys.extend(api_keys);
+        self
+    }
+
+    fn key_for(&self, request: &Request<Body>) -> RateLimitKey {
+        match presented_api_key(request.headers()) {
+            Some(key) if self.api_keys.contains(key) => RateLimitKey::ApiKey(key.to_string()),
+            _ => RateLimitKey::Ip(extract_client_ip(request)),
         }
     }
 }
 
-/// Rate limit middleware for transfers endpoints (keyed by client IP)
-async fn rate_limit_transfers_middleware(
-    State(rate_limit): State<Arc<RateLimitState>>,
+/// Rate limit middleware for a route group (state: limiter and the group's route)
+async fn rate_limit_middleware(
+    State((rate_limit, route)): State<(Arc<RateLimitState>, RateLimitRoute)>,
     request: Request<Body>,
     next: Next,
 ) -> Response<Body> {
-    let client_ip = extract_client_ip(&request);
+    check_rate_limit(&rate_limit, route, 1, request, next).await
+}
+
+/// Rate limit middleware for `POST /risk/bulk`: each requested address costs
+/// one unit, so a bulk check draws as much budget as the single checks it
+/// replaces. Bodies that don't parse cost one unit and are rejected by the
+/// handler.
+async fn bulk_risk_rate_limit_middleware(
+    State((rate_limit, route)): State<(Arc<RateLimitState>, RateLimitRoute)>,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let (parts, body) = request.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, MAX_BULK_RISK_CHECK_BODY_BYTES).await else {
+        return StatusCode::PAYLOAD_TOO_LARGE.into_response();
+    };
+    let weight = serde_json::from_slice::<BulkRiskCheckRequest>(&bytes)
+        .map_or(1, |payload| payload.addresses.len());
+    let request = Request::from_parts(parts, Body::from(bytes));
+    check_rate_limit(&rate_limit, route, weight, request, next).await
+}
 
-    match rate_limit.transfers_limiter.check_key(&client_ip) {
+/// Charge `weight` units of `route`'s budget (capped at its burst, so an
+/// oversized request can still pass once the bucket is full) and run the
+/// request, or answer 429
+async fn check_rate_limit(
+    rate_limit: &RateLimitState,
+    route: RateLimitRoute,
+    weight: usize,
+    request: Request<Body>,
+    next: Next,
+) -> Response<Body> {
+    let (limiter, limit) = &rate_limit.limiters[&route];
+    let key = rate_limit.key_for(&request);
+    let cells = u32::try_from(weight)
+        .unwrap_or(u32::MAX)
+        .clamp(1, limit.burst);
+    let cells = NonZeroU32::new(cells).unwrap_or(NonZeroU32::MIN);
+
+    match limiter
+        .check_key_n(&key, cells)
+        .unwrap_or_else(|_| limiter.check_key(&key))
+    {
         Ok(_) => {
             let mut response = next.run(request).await;
             // Add rate limit headers
             let headers = response.headers_mut();
-            headers.insert(
-                "X-RateLimit-Limit",
-                rate_limit.config.general_rps.to_string().parse().unwrap(),
-            );
+            headers.insert("X-RateLimit-Limit", limit.rps.to_string().parse().unwrap());
             response
         }
         Err(not_until) => {
             let wait_time = not_until.wait_time_from(governor::clock::Clock::now(
                 &governor::clock::DefaultClock::default(),
             ));
-            let retry_after = wait_time.as_secs();
+            // Round up so a sub-second wait is not reported as "retry after 0"
+            let retry_after = wait_time.as_secs() + u64::from(wait_time.subsec_nanos() > 0);
 
             let body = RateLimitResponse {
                 error: ErrorDetail {
                     r#type: "rate_limited".to_string(),
+                    code: ErrorCode::RateLimited,
                     message: "Rate limit exceeded. Please slow down your requests.".to_string(),
                 },
                 retry_after,
@@ -155,10 +381,7 @@ async fn rate_limit_transfers_middleware(
 
             let mut response = (StatusCode::TOO_MANY_REQUESTS, Json(body)).into_response();
             let headers = response.headers_mut();
-            headers.insert(
-                "X-RateLimit-Limit",
-                rate_limit.config.general_rps.to_string().parse().unwrap(),
-            );
+            headers.insert("X-RateLimit-Limit", limit.rps.to_string().parse().unwrap());
             headers.insert("X-RateLimit-Remaining", "0".parse().unwrap());
             headers.insert("Retry-After", retry_after.to_string().parse().unwrap());
             response
@@ -166,38 +389,6 @@ async fn rate_limit_transfers_middleware(
     }
 }
 
-/// Rate limit middleware for health endpoints (keyed by client IP)
-async fn rate_limit_health_middleware(
-    State(rate_limit): State<Arc<RateLimitState>>,
-    request: Request<Body>,
-    next: Next,
-) -> Response<Body> {
-    let client_ip = extract_client_ip(&request);
-
-    match rate_limit.health_limiter.check_key(&client_ip) {
-        Ok(_) => next.run(request).await,
-        Err(not_until) => {
-            let wait_time = not_until.wait_time_from(governor::clock::Clock::now(
-                &governor::clock::DefaultClock::default(),
-            ));
-            let retry_after = wait_time.as_secs();
-
-            let body = ErrorResponse {
-                error: ErrorDetail {
-                    r#type: "rate_limited".to_string(),
-                    message: "Rate limit exceeded".to_string(),
-                },
-            };
-
-            let mut response = (StatusCode::TOO_MANY_REQUESTS, Json(body)).into_response();
-            response
-                .headers_mut()
-                .insert("Retry-After", retry_after.to_string().parse().unwrap());
-            response
-        }
-    }
-}
-
 /// Extract the client IP address from the request.
 /// Priority: X-Forwarded-For header > ConnectInfo > fallback to 127.0.0.1
 fn extract_client_ip(request: &Request<Body>) -> IpAddr {
@@ -223,48 +414,6 @@ fn extract_client_ip(request: &Request<Body>) -> IpAddr {
     IpAddr::V4(Ipv4Addr::LOCALHOST)
 }
 
-/// Admin authentication middleware.
-///
-/// Local development remains usable when ADMIN_API_KEY is not configured. When it is
-/// configured, /admin routes require either `Authorization: Bearer <key>` or
-/// `X-Admin-Api-Key: <key>`.
-async fn admin_auth_middleware(
-    State(state): State<Arc<AppState>>,
-    request: Request<Body>,
-    next: Next,
-) -> Response<Body> {
-    let Some(expected_key) = state.admin_api_key.as_deref() else {
-        return next.run(request).await;
-    };
-
-    if admin_credentials_valid(request.headers(), expected_key) {
-        return next.run(request).await;
-    }
-
-    let body = ErrorResponse {
-        error: ErrorDetail {
-            r#type: "authentication_error".to_string(),
-            message: "Missing or invalid admin credentials".to_string(),
-        },
-    };
-    (StatusCode::UNAUTHORIZED, Json(body)).into_response()
-}
-
-fn admin_credentials_valid(headers: &axum::http::HeaderMap, expected_key: &str) -> bool {
-    let bearer_valid = headers
-        .get("Authorization")
-        .and_then(|value| value.to_str().ok())
-        .and_then(|value| value.strip_prefix("Bearer "))
-        .is_some_and(|token| token == expected_key);
-
-    let api_key_valid = headers
-        .get("X-Admin-Api-Key")
-        .and_then(|value| value.to_str().ok())
-        .is_some_and(|token| token == expected_key);
-
-    bearer_valid || api_key_valid
-}
-
 /// Create CORS layer for cross-origin requests
 fn create_cors_layer() -> CorsLayer {
     let allowed_origins = std::env::var("CORS_ALLOWED_ORIGINS").unwrap_or_else(|_| {
@@ -299,12 +448,16 @@ fn create_cors_layer() -> CorsLayer {
             "X-RateLimit-Limit".parse().unwrap(),
             "X-RateLimit-Remaining".parse().unwrap(),
             "Retry-After".parse().unwrap(),
+            REQUEST_ID_HEADER.parse().unwrap(),
         ])
         .max_age(Duration::from_secs(86400)) // 24 hours
 }
 
 /// Create router without rate limiting
 pub fn create_router(app_state: Arc<AppState>) -> Router {
+    let api_keys = Arc::new(app_state.effective_api_keys());
+    let submit_auth = ApiKeyLayer::new(Arc::clone(&api_keys), ApiKeyScope::Submit);
+
     let middleware = ServiceBuilder::new()
         .layer(
             TraceLayer::new_for_http()
@@ -316,15 +469,29 @@ pub fn create_router(app_state: Arc<AppState>) -> Router {
             Duration::from_secs(30),
         ));
 
+    // Submission requires the `submit` scope; listing stays open
+    let submit_routes = Router::new()
+        .route("/", post(submit_transfer_handler))
+        .route("/bulk", post(submit_bulk_transfers_handler))
+        .route_layer(submit_auth.clone());
+
     // Transfer routes
     let transfer_routes = Router::new()
-        .route(
-            "/",
-            post(submit_transfer_handler).get(list_transfer_requests_handler),
-        )
+        .route("/", get(list_transfer_requests_handler))
+        .route("/estimate-fee", post(estimate_fee_handler))
         .route("/{id}", get(get_transfer_request_handler))
         .route("/{id}/audit-report", get(get_transfer_audit_report_handler))
-        .route("/{id}/retry", post(retry_blockchain_handler));
+        .route("/{id}/history", get(get_transfer_history_handler))
+        .route(
+            "/{id}/retry",
+            post(retry_blockchain_handler).layer(submit_auth.clone()),
+        )
+        .route(
+            "/{id}/cancel",
+            post(cancel_transfer_handler).layer(submit_auth),
+        )
+        .route("/{id}/events", get(transfer_events_handler))
+        .merge(submit_routes);
 
     let checkout_routes = Router::new()
         .route("/sessions", post(create_checkout_session_handler))
@@ -345,20 +512,34 @@ pub fn create_router(app_state: Arc<AppState>) -> Router {
         .route("/helius", post(helius_webhook_handler))
         .route("/quicknode", post(quicknode_webhook_handler));
 
-    // Admin routes for blocklist management
+    // Admin routes for blocklist and allowlist management, compliance audits and reconciliation
     let admin_routes = Router::new()
         .route(
             "/blocklist",
             post(add_blocklist_handler).get(list_blocklist_handler),
         )
+        .route("/blocklist/bulk", post(bulk_add_blocklist_handler))
         .route("/blocklist/{address}", delete(remove_blocklist_handler))
-        .layer(middleware::from_fn_with_state(
-            Arc::clone(&app_state),
-            admin_auth_middleware,
-        ));
+        .route(
+            "/allowlist",
+            post(add_allowlist_handler).get(list_allowlist_handler),
+        )
+        .route("/allowlist/{address}", delete(remove_allowlist_handler))
+        .route(
+            "/compliance-decisions",
+            get(list_compliance_decisions_handler),
+        )
+        .route(
+            "/transfers/{id}/reconcile",
+            post(reconcile_transfer_handler),
+        )
+        .route("/worker/pause", post(pause_worker_handler))
+        .route("/worker/resume", post(resume_worker_handler))
+        .route_layer(ApiKeyLayer::new(api_keys, ApiKeyScope::Admin));
 
     // Compliance routes
     let compliance_routes = Router::new().route("/", post(risk_check_handler));
+    let risk_routes = Router::new().route("/bulk", post(bulk_risk_check_handler));
 
     Router::new()
         .nest("/transfer-requests", transfer_routes)
@@ -367,15 +548,28 @@ pub fn create_router(app_state: Arc<AppState>) -> Router {
         .nest("/health", health_routes)
         .nest("/admin", admin_routes)
         .nest("/risk-check", compliance_routes)
+        .nest("/risk", risk_routes)
+        // Prometheus scrape endpoint (not rate limited)
+        .route("/metrics", get(metrics_handler))
+        // Kubernetes probe aliases of /health/live and /health/ready (not rate limited)
+        .route("/livez", get(liveness_handler))
+        .route("/readyz", get(readiness_handler))
+        // Server clock for clients computing valid_until (not rate limited)
+        .route("/time", get(server_time_handler))
         .layer(create_cors_layer())
         .layer(middleware)
+        .layer(middleware::from_fn(request_id_middleware))
         .with_state(app_state)
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
 }
 
 /// Create router with rate limiting enabled
 pub fn create_router_with_rate_limit(app_state: Arc<AppState>, config: RateLimitConfig) -> Router {
-    let rate_limit_state = Arc::new(RateLimitState::new(config));
+    let api_keys = Arc::new(app_state.effective_api_keys());
+    let submit_auth = ApiKeyLayer::new(Arc::clone(&api_keys), ApiKeyScope::Submit);
+    let rate_limit_state =
+        Arc::new(RateLimitState::new(config).with_api_keys(api_keys.keys().map(String::from)));
+    let limit = |route| (Arc::clone(&rate_limit_state), route);
 
     let middleware = ServiceBuilder::new()
         .layer(
@@ -388,19 +582,37 @@ pub fn create_router_with_rate_limit(app_state: Arc<AppState>, config: RateLimit
             Duration::from_secs(30),
         ));
 
+    // Submission is the expensive path and has its own budget
+    let submit_routes = Router::new()
+        .route("/", post(submit_transfer_handler))
+        .route("/bulk", post(submit_bulk_transfers_handler))
+        .route_layer(submit_auth.clone())
+        .layer(middleware::from_fn_with_state(
+            limit(RateLimitRoute::Submit),
+            rate_limit_middleware,
+        ));
+
     // Transfer routes with rate limiting
     let transfer_routes = Router::new()
-        .route(
-            "/",
-            post(submit_transfer_handler).get(list_transfer_requests_handler),
-        )
+        .route("/", get(list_transfer_requests_handler))
+        .route("/estimate-fee", post(estimate_fee_handler))
         .route("/{id}", get(get_transfer_request_handler))
         .route("/{id}/audit-report", get(get_transfer_audit_report_handler))
-        .route("/{id}/retry", post(retry_blockchain_handler))
+        .route("/{id}/history", get(get_transfer_history_handler))
+        .route(
+            "/{id}/retry",
+            post(retry_blockchain_handler).layer(submit_auth.clone()),
+        )
+        .route(
+            "/{id}/cancel",
+            post(cancel_transfer_handler).layer(submit_auth),
+        )
+        .route("/{id}/events", get(transfer_events_handler))
         .layer(middleware::from_fn_with_state(
-            Arc::clone(&rate_limit_state),
-            rate_limit_transfers_middleware,
-        ));
+            limit(RateLimitRoute::Transfers),
+            rate_limit_middleware,
+        ))
+        .merge(submit_routes);
 
     let checkout_routes = Router::new()
         .route("/sessions", post(create_checkout_session_handler))
@@ -410,8 +622,8 @@ pub fn create_router_with_rate_limit(app_state: Arc<AppState>, config: RateLimit
             post(submit_checkout_transfer_handler),
         )
         .layer(middleware::from_fn_with_state(
-            Arc::clone(&rate_limit_state),
-            rate_limit_transfers_middleware,
+            limit(RateLimitRoute::Checkout),
+            rate_limit_middleware,
         ));
 
     // Health routes with separate rate limiting
@@ -420,8 +632,8 @@ pub fn create_router_with_rate_limit(app_state: Arc<AppState>, config: RateLimit
         .route("/live", get(liveness_handler))
         .route("/ready", get(readiness_handler))
         .layer(middleware::from_fn_with_state(
-            Arc::clone(&rate_limit_state),
-            rate_limit_health_middleware,
+            limit(RateLimitRoute::Health),
+            rate_limit_middleware,
         ));
 
     // Webhook routes (no rate limiting - webhooks need immediate delivery)
@@ -429,20 +641,33 @@ pub fn create_router_with_rate_limit(app_state: Arc<AppState>, config: RateLimit
         .route("/helius", post(helius_webhook_handler))
         .route("/quicknode", post(quicknode_webhook_handler));
 
-    // Admin routes for blocklist management (with rate limiting)
+    // Admin routes for blocklist and allowlist management, compliance audits and reconciliation (with rate limiting)
     let admin_routes = Router::new()
         .route(
             "/blocklist",
             post(add_blocklist_handler).get(list_blocklist_handler),
         )
+        .route("/blocklist/bulk", post(bulk_add_blocklist_handler))
         .route("/blocklist/{address}", delete(remove_blocklist_handler))
+        .route(
+            "/allowlist",
+            post(add_allowlist_handler).get(list_allowlist_handler),
+        )
+        .route("/allowlist/{address}", delete(remove_allowlist_handler))
+        .route(
+            "/compliance-decisions",
+            get(list_compliance_decisions_handler),
+        )
+        .route(
+            "/transfers/{id}/reconcile",
+            post(reconcile_transfer_handler),
+        )
+        .route("/worker/pause", post(pause_worker_handler))
+        .route("/worker/resume", post(resume_worker_handler))
+        .route_layer(ApiKeyLayer::new(api_keys, ApiKeyScope::Admin))
         .layer(middleware::from_fn_with_state(
-            Arc::clone(&app_state),
-            admin_auth_middleware,
-        ))
-        .layer(middleware::from_fn_with_state(
-            Arc::clone(&rate_limit_state),
-            rate_limit_transfers_middleware,
+            limit(RateLimitRoute::Admin),
+            rate_limit_middleware,
         ));
 
     // Compliance routes (with rate limiting)
@@ -450,10 +675,18 @@ pub fn create_router_with_rate_limit(app_state: Arc<AppState>, config: RateLimit
         Router::new()
             .route("/", post(risk_check_handler))
             .layer(middleware::from_fn_with_state(
-                Arc::clone(&rate_limit_state),
-                rate_limit_transfers_middleware,
+                limit(RateLimitRoute::RiskCheck),
+                rate_limit_middleware,
             ));
 
+    // Bulk risk checks are weighted by the number of addresses
+    let risk_routes = Router::new()
+        .route("/bulk", post(bulk_risk_check_handler))
+        .layer(middleware::from_fn_with_state(
+            limit(RateLimitRoute::RiskBulk),
+            bulk_risk_rate_limit_middleware,
+        ));
+
     Router::new()
         .nest("/transfer-requests", transfer_routes)
         .nest("/checkout", checkout_routes)
@@ -461,8 +694,17 @@ pub fn create_router_with_rate_limit(app_state: Arc<AppState>, config: RateLimit
         .nest("/health", health_routes)
         .nest("/admin", admin_routes)
         .nest("/risk-check", compliance_routes)
+        .nest("/risk", risk_routes)
+        // Prometheus scrape endpoint (not rate limited)
+        .route("/metrics", get(metrics_handler))
+        // Kubernetes probe aliases of /health/live and /health/ready (not rate limited)
+        .route("/livez", get(liveness_handler))
+        .route("/readyz", get(readiness_handler))
+        // Server clock for clients computing valid_until (not rate limited)
+        .route("/time", get(server_time_handler))
         .layer(create_cors_layer())
         .layer(middleware)
+        .layer(middleware::from_fn(request_id_middleware))
         .with_state(app_state)
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
 }
@@ -515,6 +757,7 @@ mod tests {
                 general_burst: 100,
                 health_rps: 200,
                 health_burst: 200,
+                ..Default::default()
             };
             assert_eq!(config.general_rps, 50);
             assert_eq!(config.general_burst, 100);
@@ -537,11 +780,73 @@ mod tests {
                 general_burst: 84,
                 health_rps: 100,
                 health_burst: 100,
+                ..Default::default()
             };
             let config2 = config1.clone();
             assert_eq!(config1.general_rps, config2.general_rps);
             assert_eq!(config1.general_burst, config2.general_burst);
         }
+
+        #[test]
+        fn test_parse_route_limits() {
+            let limits = parse_route_limits(" submit=2:5, health = 50:60 ,").unwrap();
+            assert_eq!(limits.len(), 2);
+            assert_eq!(
+                limits[&RateLimitRoute::Submit],
+                RouteRateLimit { rps: 2, burst: 5 }
+            );
+            assert_eq!(
+                limits[&RateLimitRoute::Health],
+                RouteRateLimit { rps: 50, burst: 60 }
+            );
+            assert!(parse_route_limits("").unwrap().is_empty());
+
+            for invalid in [
+                "submit",
+                "submit=2",
+                "unknown=1:1",
+                "admin=0:5",
+                "admin=5:x",
+            ] {
+                assert!(
+                    parse_route_limits(invalid).is_err(),
+                    "{:?} should be rejected",
+                    invalid
+                );
+            }
+        }
+
+        #[test]
+        fn test_limit_for_falls_back_to_defaults() {
+            let mut config = RateLimitConfig::default();
+            config
+                .route_limits
+                .insert(RateLimitRoute::Admin, RouteRateLimit { rps: 1, burst: 2 });
+
+            assert_eq!(
+                config.limit_for(RateLimitRoute::Admin),
+                RouteRateLimit { rps: 1, burst: 2 }
+            );
+            assert_eq!(
+                config.limit_for(RateLimitRoute::Submit),
+                RouteRateLimit { rps: 10, burst: 20 }
+            );
+            assert_eq!(
+                config.limit_for(RateLimitRoute::Health),
+                RouteRateLimit {
+                    rps: 100,
+                    burst: 100
+                }
+            );
+            // A full-size bulk risk check fits in the default burst
+            assert_eq!(
+                config.limit_for(RateLimitRoute::RiskBulk),
+                RouteRateLimit {
+                    rps: 10,
+                    burst: MAX_BULK_RISK_CHECK_ADDRESSES as u32
+                }
+            );
+        }
     }
 
     mod middleware_tests {
@@ -566,8 +871,8 @@ mod tests {
                 Router::new()
                     .route("/", get(dummy_handler))
                     .layer(middleware::from_fn_with_state(
-                        state,
-                        rate_limit_transfers_middleware,
+                        (state, RateLimitRoute::Transfers),
+                        rate_limit_middleware,
                     ));
 
             app.clone()
@@ -597,8 +902,8 @@ mod tests {
                 Router::new()
                     .route("/", get(dummy_handler))
                     .layer(middleware::from_fn_with_state(
-                        state,
-                        rate_limit_transfers_middleware,
+                        (state, RateLimitRoute::Transfers),
+                        rate_limit_middleware,
                     ));
 
             let response = app
@@ -625,8 +930,8 @@ mod tests {
                 Router::new()
                     .route("/", get(dummy_handler))
                     .layer(middleware::from_fn_with_state(
-                        state,
-                        rate_limit_transfers_middleware,
+                        (state, RateLimitRoute::Transfers),
+                        rate_limit_middleware,
                     ));
 
             // Exhaust the limit
@@ -665,8 +970,8 @@ mod tests {
                 Router::new()
                     .route("/", get(dummy_handler))
                     .layer(middleware::from_fn_with_state(
-                        state,
-                        rate_limit_transfers_middleware,
+                        (state, RateLimitRoute::Transfers),
+                        rate_limit_middleware,
                     ));
 
             // Exhaust the limit
@@ -693,6 +998,7 @@ mod tests {
                 general_burst: 1,
                 health_rps: 100,
                 health_burst: 100,
+                ..Default::default()
             };
 
             let state = Arc::new(RateLimitState::new(config));
@@ -701,8 +1007,8 @@ mod tests {
                 Router::new()
                     .route("/", get(dummy_handler))
                     .layer(middleware::from_fn_with_state(
-                        state,
-                        rate_limit_health_middleware,
+                        (state, RateLimitRoute::Health),
+                        rate_limit_middleware,
                     ));
 
             // Should allow multiple requests
@@ -715,6 +1021,120 @@ mod tests {
                 assert_eq!(response.status(), StatusCode::OK);
             }
         }
+
+        fn bulk_risk_request(count: usize) -> Request<Body> {
+            let addresses: Vec<String> = (0..count).map(|i| format!("Wallet{}", i)).collect();
+            Request::builder()
+                .method("POST")
+                .uri("/")
+                .header("Content-Type", "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "addresses": addresses }).to_string(),
+                ))
+                .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_bulk_risk_rate_limit_charges_per_address() {
+            let mut config = RateLimitConfig::default();
+            config.route_limits.insert(
+                RateLimitRoute::RiskBulk,
+                RouteRateLimit { rps: 1, burst: 5 },
+            );
+            let state = Arc::new(RateLimitState::new(config));
+
+            async fn echo_count(body: String) -> String {
+                let payload: BulkRiskCheckRequest = serde_json::from_str(&body).unwrap();
+                payload.addresses.len().to_string()
+            }
+            let app = Router::new()
+                .route("/", axum::routing::post(echo_count))
+                .layer(middleware::from_fn_with_state(
+                    (state, RateLimitRoute::RiskBulk),
+                    bulk_risk_rate_limit_middleware,
+                ));
+
+            // Three addresses leave two units of the burst
+            let response = app.clone().oneshot(bulk_risk_request(3)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            assert_eq!(&body[..], b"3");
+
+            let response = app.clone().oneshot(bulk_risk_request(3)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+            let response = app.oneshot(bulk_risk_request(2)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        fn keyed_request(ip: &str, api_key: Option<&str>) -> Request<Body> {
+            let mut builder = Request::builder().uri("/").header("X-Forwarded-For", ip);
+            if let Some(key) = api_key {
+                builder = builder.header("X-Admin-Api-Key", key);
+            }
+            builder.body(Body::empty()).unwrap()
+        }
+
+        fn single_request_app(api_keys: &[&str]) -> Router {
+            let config = RateLimitConfig {
+                general_rps: 1,
+                general_burst: 1,
+                ..Default::default()
+            };
+            let state = Arc::new(
+                RateLimitState::new(config)
+                    .with_api_keys(api_keys.iter().map(|key| key.to_string())),
+            );
+            Router::new()
+                .route("/", get(dummy_handler))
+                .layer(middleware::from_fn_with_state(
+                    (state, RateLimitRoute::Transfers),
+                    rate_limit_middleware,
+                ))
+        }
+
+        #[tokio::test]
+        async fn test_known_api_key_shares_bucket_across_ips() {
+            let app = single_request_app(&["key-1"]);
+
+            let response = app
+                .clone()
+                .oneshot(keyed_request("10.0.0.1", Some("key-1")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            // Same key from another IP draws from the same bucket
+            let response = app
+                .clone()
+                .oneshot(keyed_request("10.0.0.2", Some("key-1")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+            // The IP bucket is untouched by keyed requests
+            let response = app.oneshot(keyed_request("10.0.0.1", None)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+
+        #[tokio::test]
+        async fn test_unknown_api_key_uses_ip_bucket() {
+            let app = single_request_app(&["key-1"]);
+
+            let response = app
+                .clone()
+                .oneshot(keyed_request("10.0.0.1", Some("made-up-1")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+
+            // A fresh made-up key does not buy a fresh bucket
+            let response = app
+                .oneshot(keyed_request("10.0.0.1", Some("made-up-2")))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        }
     }
 
     mod router_tests {
@@ -777,5 +1197,72 @@ mod tests {
             // Should return 404 (not found), not forbidden or error
             assert_eq!(res.status(), StatusCode::NOT_FOUND);
         }
+
+        fn single_request_budgets() -> RateLimitConfig {
+            let mut config = RateLimitConfig::default();
+            for route in [RateLimitRoute::Submit, RateLimitRoute::Health] {
+                config
+                    .route_limits
+                    .insert(route, RouteRateLimit { rps: 1, burst: 1 });
+            }
+            config
+        }
+
+        fn submit_request() -> Request<Body> {
+            Request::builder()
+                .method("POST")
+                .uri("/transfer-requests")
+                .header("Content-Type", "application/json")
+                .body(Body::from("{}"))
+                .unwrap()
+        }
+
+        fn health_request() -> Request<Body> {
+            Request::builder()
+                .uri("/health/live")
+                .body(Body::empty())
+                .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_exhausted_submit_budget_does_not_block_health() {
+            let router =
+                create_router_with_rate_limit(create_test_state(), single_request_budgets());
+
+            let first = router.clone().oneshot(submit_request()).await.unwrap();
+            assert_ne!(first.status(), StatusCode::TOO_MANY_REQUESTS);
+            let second = router.clone().oneshot(submit_request()).await.unwrap();
+            assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+            assert!(second.headers().contains_key("Retry-After"));
+
+            let health = router.clone().oneshot(health_request()).await.unwrap();
+            assert_eq!(health.status(), StatusCode::OK);
+
+            // Other transfer routes keep the general budget
+            let list = router
+                .oneshot(
+                    Request::builder()
+                        .uri("/transfer-requests")
+                        .body(Body::empty())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(list.status(), StatusCode::OK);
+        }
+
+        #[tokio::test]
+        async fn test_exhausted_health_budget_does_not_block_submit() {
+            let router =
+                create_router_with_rate_limit(create_test_state(), single_request_budgets());
+
+            let first = router.clone().oneshot(health_request()).await.unwrap();
+            assert_eq!(first.status(), StatusCode::OK);
+            let second = router.clone().oneshot(health_request()).await.unwrap();
+            assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
+
+            let submit = router.oneshot(submit_request()).await.unwrap();
+            assert_ne!(submit.status(), StatusCode::TOO_MANY_REQUESTS);
+        }
     }
 }
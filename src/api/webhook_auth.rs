@@ -0,0 +1,296 @@
+//! Authentication of incoming provider webhooks.
+//!
+//! - **Helius** sends the auth header configured on the webhook verbatim in
+//!   `Authorization`; it must equal `HELIUS_WEBHOOK_SECRET`.
+//! - **QuickNode** signs every delivery: `x-qn-signature` is the hex
+//!   HMAC-SHA256, keyed with the stream's security token, of `x-qn-nonce`,
+//!   `x-qn-timestamp` and the raw body concatenated. Deliveries whose
+//!   timestamp is more than [`QUICKNODE_SIGNATURE_TOLERANCE_SECS`] away from
+//!   now are rejected as replays. Deployments set up before signatures were
+//!   verified send the secret itself in `x-qn-signature` or `Authorization`,
+//!   which is still accepted.
+//!
+//! Secrets and digests are compared in constant time.
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::Sha256;
+
+use crate::domain::AppError;
+
+/// Header carrying the Helius auth header value
+pub const HELIUS_AUTH_HEADER: &str = "Authorization";
+
+/// Header carrying the QuickNode webhook signature
+pub const QUICKNODE_SIGNATURE_HEADER: &str = "x-qn-signature";
+
+/// Header carrying the nonce QuickNode signs with each delivery
+pub const QUICKNODE_NONCE_HEADER: &str = "x-qn-nonce";
+
+/// Header carrying the Unix time (seconds) QuickNode signs with each delivery
+pub const QUICKNODE_TIMESTAMP_HEADER: &str = "x-qn-timestamp";
+
+/// Header in which QuickNode deployments may send the raw secret instead
+pub const QUICKNODE_LEGACY_AUTH_HEADER: &str = "Authorization";
+
+/// How far a QuickNode delivery's timestamp may be from now, in seconds
+pub const QUICKNODE_SIGNATURE_TOLERANCE_SECS: i64 = 300;
+
+/// Optional prefix some senders put in front of the hex digest
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signature headers of a QuickNode delivery
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuickNodeSignature<'a> {
+    pub signature: Option<&'a str>,
+    pub nonce: Option<&'a str>,
+    pub timestamp: Option<&'a str>,
+}
+
+fn hmac(secret: &str) -> Result<HmacSha256, AppError> {
+    HmacSha256::new_from_slice(secret.as_bytes())
+        .map_err(|e| AppError::Internal(format!("Invalid webhook secret: {}", e)))
+}
+
+/// Verify `signature` as the hex HMAC-SHA256 of `message` under `secret`.
+///
+/// Accepts an optional `sha256=` prefix. Returns
+/// [`AppError::Authentication`] (401) if the signature is missing, malformed
+/// or does not match.
+pub fn verify_webhook_signature(
+    secret: &str,
+    message: &[u8],
+    signature: Option<&str>,
+) -> Result<(), AppError> {
+    let signature = signature
+        .ok_or_else(|| AppError::Authentication("Missing webhook signature".to_string()))?;
+    let signature = signature.trim();
+    let signature = signature
+        .strip_prefix(SIGNATURE_PREFIX)
+        .unwrap_or(signature);
+
+    let expected = hex::decode(signature)
+        .map_err(|_| AppError::Authentication("Malformed webhook signature".to_string()))?;
+
+    let mut mac = hmac(secret)?;
+    mac.update(message);
+
+    // verify_slice compares in constant time
+    mac.verify_slice(&expected)
+        .map_err(|_| AppError::Authentication("Invalid webhook signature".to_string()))
+}
+
+/// Whether `presented` equals `secret`, compared in constant time
+pub(crate) fn secret_matches(secret: &str, presented: &str) -> Result<bool, AppError> {
+    // Comparing keyed digests keeps the time independent of where they differ
+    let digest = |value: &str| {
+        hmac(secret).map(|mut mac| {
+            mac.update(value.as_bytes());
+            mac
+        })
+    };
+    let expected = digest(secret)?.finalize().into_bytes();
+    Ok(digest(presented)?.verify_slice(&expected).is_ok())
+}
+
+/// Verify a Helius delivery: `authorization` must be the configured secret
+pub fn verify_helius_auth_header(
+    secret: &str,
+    authorization: Option<&str>,
+) -> Result<(), AppError> {
+    let authorization = authorization
+        .ok_or_else(|| AppError::Authentication("Missing Authorization header".to_string()))?;
+    if secret_matches(secret, authorization)? {
+        Ok(())
+    } else {
+        Err(AppError::Authentication(
+            "Invalid webhook secret".to_string(),
+        ))
+    }
+}
+
+/// Verify a signed QuickNode delivery of `body` received at `now`.
+///
+/// Returns [`AppError::Authentication`] (401) if a header is missing, the
+/// timestamp is outside [`QUICKNODE_SIGNATURE_TOLERANCE_SECS`] or the
+/// signature does not match.
+pub fn verify_quicknode_signature(
+    secret: &str,
+    body: &[u8],
+    headers: QuickNodeSignature<'_>,
+    now: DateTime<Utc>,
+) -> Result<(), AppError> {
+    let (Some(nonce), Some(timestamp)) = (headers.nonce, headers.timestamp) else {
+        return Err(AppError::Authentication(
+            "Missing QuickNode signature nonce or timestamp".to_string(),
+        ));
+    };
+    let signed_at: i64 = timestamp.trim().parse().map_err(|_| {
+        AppError::Authentication("Malformed QuickNode signature timestamp".to_string())
+    })?;
+    // abs_diff cannot overflow on an extreme header value
+    if now.timestamp().abs_diff(signed_at) > QUICKNODE_SIGNATURE_TOLERANCE_SECS.unsigned_abs() {
+        return Err(AppError::Authentication(
+            "QuickNode signature timestamp outside the allowed window".to_string(),
+        ));
+    }
+
+    let mut message = Vec::with_capacity(nonce.len() + timestamp.len() + body.len());
+    message.extend_from_slice(nonce.as_bytes());
+    message.extend_from_slice(timestamp.as_bytes());
+    message.extend_from_slice(body);
+    verify_webhook_signature(secret, &message, headers.signature)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test_secret";
+    const BODY: &[u8] = br#"{"signature":"sig_123"}"#;
+    /// HMAC-SHA256 of `BODY` under `SECRET`
+    const SIGNATURE: &str = "397fe083fdac422d50c084d544d78fa848b7ff7f5d67d287e45eb6efb4a0c8b4";
+
+    const QN_NONCE: &str = "6f1c1f5e-5d7d-4f8c-9a43-0d0b1e3b7c21";
+    const QN_TIMESTAMP: &str = "1767225600";
+
+    /// Hex HMAC-SHA256 of `message` under `SECRET`
+    fn sign(message: &[u8]) -> String {
+        let mut mac = hmac(SECRET).unwrap();
+        mac.update(message);
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    fn signed_at() -> DateTime<Utc> {
+        DateTime::from_timestamp(QN_TIMESTAMP.parse().unwrap(), 0).unwrap()
+    }
+
+    #[test]
+    fn test_known_signature_accepted() {
+        assert!(verify_webhook_signature(SECRET, BODY, Some(SIGNATURE)).is_ok());
+        let prefixed = format!("sha256={}", SIGNATURE);
+        assert!(verify_webhook_signature(SECRET, BODY, Some(&prefixed)).is_ok());
+        let upper = SIGNATURE.to_uppercase();
+        assert!(verify_webhook_signature(SECRET, BODY, Some(&upper)).is_ok());
+    }
+
+    #[test]
+    fn test_tampered_body_rejected() {
+        let tampered = br#"{"signature":"sig_124"}"#;
+        let result = verify_webhook_signature(SECRET, tampered, Some(SIGNATURE));
+        assert!(matches!(result, Err(AppError::Authentication(_))));
+    }
+
+    #[test]
+    fn test_wrong_secret_rejected() {
+        let result = verify_webhook_signature("other_secret", BODY, Some(SIGNATURE));
+        assert!(matches!(result, Err(AppError::Authentication(_))));
+    }
+
+    #[test]
+    fn test_missing_or_malformed_signature_rejected() {
+        for signature in [None, Some(""), Some("not-hex"), Some(SECRET), Some("abcd")] {
+            let result = verify_webhook_signature(SECRET, BODY, signature);
+            assert!(
+                matches!(result, Err(AppError::Authentication(_))),
+                "signature {:?} should be rejected",
+                signature
+            );
+        }
+    }
+
+    #[test]
+    fn test_helius_auth_header_must_equal_secret() {
+        assert!(verify_helius_auth_header(SECRET, Some(SECRET)).is_ok());
+        for authorization in [
+            None,
+            Some(""),
+            Some("whsec_test_secreT"),
+            Some("Bearer whsec_test_secret"),
+            Some(SIGNATURE),
+        ] {
+            assert!(
+                matches!(
+                    verify_helius_auth_header(SECRET, authorization),
+                    Err(AppError::Authentication(_))
+                ),
+                "authorization {:?} should be rejected",
+                authorization
+            );
+        }
+    }
+
+    #[test]
+    fn test_quicknode_signature_covers_nonce_timestamp_and_body() {
+        let without_body = sign(format!("{}{}", QN_NONCE, QN_TIMESTAMP).as_bytes());
+        let mut message = format!("{}{}", QN_NONCE, QN_TIMESTAMP).into_bytes();
+        message.extend_from_slice(BODY);
+        let valid = sign(&message);
+        let headers = |signature| QuickNodeSignature {
+            signature: Some(signature),
+            nonce: Some(QN_NONCE),
+            timestamp: Some(QN_TIMESTAMP),
+        };
+
+        assert!(verify_quicknode_signature(SECRET, BODY, headers(&valid), signed_at()).is_ok());
+
+        // A digest of the body alone, or one leaving the body out, is rejected
+        for signature in [SIGNATURE, without_body.as_str()] {
+            let result = verify_quicknode_signature(SECRET, BODY, headers(signature), signed_at());
+            assert!(matches!(result, Err(AppError::Authentication(_))));
+        }
+
+        // Each signed header is covered
+        let other_nonce = QuickNodeSignature {
+            nonce: Some("other-nonce"),
+            ..headers(&valid)
+        };
+        let result = verify_quicknode_signature(SECRET, BODY, other_nonce, signed_at());
+        assert!(matches!(result, Err(AppError::Authentication(_))));
+        let missing_timestamp = QuickNodeSignature {
+            timestamp: None,
+            ..headers(&valid)
+        };
+        let result = verify_quicknode_signature(SECRET, BODY, missing_timestamp, signed_at());
+        assert!(matches!(result, Err(AppError::Authentication(_))));
+    }
+
+    #[test]
+    fn test_quicknode_signature_outside_tolerance_rejected() {
+        let mut message = format!("{}{}", QN_NONCE, QN_TIMESTAMP).into_bytes();
+        message.extend_from_slice(BODY);
+        let signature = sign(&message);
+        let headers = QuickNodeSignature {
+            signature: Some(&signature),
+            nonce: Some(QN_NONCE),
+            timestamp: Some(QN_TIMESTAMP),
+        };
+
+        let within = signed_at() + chrono::Duration::seconds(QUICKNODE_SIGNATURE_TOLERANCE_SECS);
+        assert!(verify_quicknode_signature(SECRET, BODY, headers, within).is_ok());
+
+        let late = within + chrono::Duration::seconds(1);
+        let result = verify_quicknode_signature(SECRET, BODY, headers, late);
+        assert!(matches!(result, Err(AppError::Authentication(_))));
+    }
+
+    #[test]
+    fn test_quicknode_extreme_timestamp_rejected() {
+        for timestamp in [i64::MIN, i64::MAX] {
+            let timestamp = timestamp.to_string();
+            let mut message = format!("{}{}", QN_NONCE, timestamp).into_bytes();
+            message.extend_from_slice(BODY);
+            let signature = sign(&message);
+            let headers = QuickNodeSignature {
+                signature: Some(&signature),
+                nonce: Some(QN_NONCE),
+                timestamp: Some(&timestamp),
+            };
+
+            let result = verify_quicknode_signature(SECRET, BODY, headers, signed_at());
+            assert!(matches!(result, Err(AppError::Authentication(_))));
+        }
+    }
+}
@@ -0,0 +1,513 @@
+//! Webhook providers reporting transaction status.
+//!
+//! Each provider authenticates its deliveries and normalizes its payload into
+//! [`ConfirmationEvent`]s, which [`handle_webhook`] funnels into
+//! [`crate::app::AppService::process_webhook`]. Supporting another indexer
+//! (Triton, a self-hosted Geyser webhook, ...) only takes a new
+//! [`WebhookProvider`] implementation and a route.
+
+use axum::http::{HeaderMap, StatusCode};
+use chrono::Utc;
+use tracing::info;
+
+use crate::app::AppState;
+use crate::domain::{
+    AppError, ConfirmationEvent, HeliusTransaction, QuickNodeWebhookEvent, ValidationError,
+};
+
+use super::webhook_auth::{
+    HELIUS_AUTH_HEADER, QUICKNODE_LEGACY_AUTH_HEADER, QUICKNODE_NONCE_HEADER,
+    QUICKNODE_SIGNATURE_HEADER, QUICKNODE_TIMESTAMP_HEADER, QuickNodeSignature, secret_matches,
+    verify_helius_auth_header, verify_quicknode_signature,
+};
+
+/// Source of transaction confirmation webhooks
+pub trait WebhookProvider: Send + Sync {
+    /// Provider name for logs
+    fn name(&self) -> &'static str;
+
+    /// Authenticate a delivery and normalize its payload.
+    /// Returns [`AppError::Authentication`] for unsigned or forged requests.
+    fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<Vec<ConfirmationEvent>, AppError>;
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+/// Authenticate a QuickNode delivery: the secret itself in `x-qn-signature`
+/// or `Authorization` (deployments predating signed deliveries), else a
+/// valid signature over the nonce, timestamp and body
+fn verify_quicknode_delivery(
+    secret: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<(), AppError> {
+    let signature = header(headers, QUICKNODE_SIGNATURE_HEADER);
+    let authorization = header(headers, QUICKNODE_LEGACY_AUTH_HEADER);
+    if signature.is_none() && authorization.is_none() {
+        return Err(AppError::Authentication(
+            "Missing QuickNode webhook signature".to_string(),
+        ));
+    }
+    for presented in [signature, authorization].into_iter().flatten() {
+        if secret_matches(secret, presented)? {
+            return Ok(());
+        }
+    }
+
+    let delivery = QuickNodeSignature {
+        signature,
+        nonce: header(headers, QUICKNODE_NONCE_HEADER),
+        timestamp: header(headers, QUICKNODE_TIMESTAMP_HEADER),
+    };
+    verify_quicknode_signature(secret, body, delivery, Utc::now())
+}
+
+fn invalid_payload(provider: &str, error: serde_json::Error) -> AppError {
+    AppError::Validation(ValidationError::InvalidFormat(format!(
+        "Invalid {} webhook payload: {}",
+        provider, error
+    )))
+}
+
+/// Helius Enhanced Transaction webhooks.
+/// Reference: <https://docs.helius.dev/webhooks-and-websockets/webhooks>
+#[derive(Debug, Clone, Default)]
+pub struct HeliusWebhookProvider {
+    /// Expected Authorization header value (`None` disables verification)
+    secret: Option<String>,
+}
+
+impl HeliusWebhookProvider {
+    /// Create a provider; `None` disables signature verification
+    #[must_use]
+    pub fn new(secret: Option<String>) -> Self {
+        Self { secret }
+    }
+}
+
+impl WebhookProvider for HeliusWebhookProvider {
+    fn name(&self) -> &'static str {
+        "helius"
+    }
+
+    fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<Vec<ConfirmationEvent>, AppError> {
+        if let Some(secret) = &self.secret {
+            verify_helius_auth_header(secret, header(headers, HELIUS_AUTH_HEADER))?;
+        }
+
+        let transactions: Vec<HeliusTransaction> =
+            serde_json::from_slice(body).map_err(|e| invalid_payload("Helius", e))?;
+        Ok(transactions.into_iter().map(Into::into).collect())
+    }
+}
+
+/// QuickNode Streams/Webhooks.
+///
+/// **IMPORTANT**: Accepts ANY valid JSON to avoid 422 errors. QuickNode
+/// Streams can send various payload formats depending on the template/filter
+/// configured, so signatures are extracted from several known shapes.
+///
+/// Reference: <https://www.quicknode.com/docs/webhooks>
+#[derive(Debug, Clone, Default)]
+pub struct QuickNodeWebhookProvider {
+    /// Security token signing deliveries (`None` disables verification)
+    secret: Option<String>,
+}
+
+impl QuickNodeWebhookProvider {
+    /// Create a provider; `None` disables signature verification
+    #[must_use]
+    pub fn new(secret: Option<String>) -> Self {
+        Self { secret }
+    }
+}
+
+impl WebhookProvider for QuickNodeWebhookProvider {
+    fn name(&self) -> &'static str {
+        "quicknode"
+    }
+
+    fn verify(&self, headers: &HeaderMap, body: &[u8]) -> Result<Vec<ConfirmationEvent>, AppError> {
+        if let Some(secret) = &self.secret {
+            verify_quicknode_delivery(secret, headers, body)?;
+        }
+
+        let payload: serde_json::Value =
+            serde_json::from_slice(body).map_err(|e| invalid_payload("QuickNode", e))?;
+
+        // Log the raw payload for debugging (truncate if too large)
+        let payload_str = payload.to_string();
+        let truncated_payload = if payload_str.len() > 2000 {
+            format!(
+                "{}... (truncated, {} bytes total)",
+                &payload_str[..2000],
+                payload_str.len()
+            )
+        } else {
+            payload_str
+        };
+        info!(
+            payload = %truncated_payload,
+            "QuickNode webhook received - raw payload"
+        );
+
+        Ok(parse_quicknode_payload(&payload)
+            .into_iter()
+            .map(Into::into)
+            .collect())
+    }
+}
+
+/// Authenticate and process a webhook delivery from `provider`
+pub async fn handle_webhook(
+    state: &AppState,
+    provider: &dyn WebhookProvider,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<StatusCode, AppError> {
+    let events = provider.verify(headers, body)?;
+    let event_count = events.len();
+
+    if event_count == 0 {
+        info!(
+            provider = provider.name(),
+            "Webhook: No extractable signatures found in payload. \
+             This may be a verification ping or unrecognized format."
+        );
+        // Still return OK to satisfy provider verification
+        return Ok(StatusCode::OK);
+    }
+
+    info!(
+        provider = provider.name(),
+        event_count = %event_count,
+        signatures = ?events.iter().map(|e| &e.signature).collect::<Vec<_>>(),
+        "Processing webhook batch"
+    );
+
+    let processed = state.service.process_webhook(events).await?;
+
+    info!(
+        provider = provider.name(),
+        received = %event_count,
+        processed = %processed,
+        "Webhook processed"
+    );
+
+    Ok(StatusCode::OK)
+}
+
+/// Parse QuickNode webhook payload into events
+///
+/// Attempts to extract transaction signatures from various QuickNode payload formats:
+/// 1. Array of objects with "signature" field
+/// 2. Single object with "signature" field
+/// 3. Array of objects with "transaction" -> "signatures" array
+/// 4. Nested "data" or "transactions" arrays
+fn parse_quicknode_payload(payload: &serde_json::Value) -> Vec<QuickNodeWebhookEvent> {
+    let mut events = Vec::new();
+
+    // Helper to create event from signature and optional fields
+    let create_event = |sig: &str, obj: Option<&serde_json::Value>| -> QuickNodeWebhookEvent {
+        let (slot, block_time, err) = if let Some(o) = obj {
+            (
+                o.get("slot").and_then(|v| v.as_u64()),
+                o.get("blockTime")
+                    .or_else(|| o.get("block_time"))
+                    .and_then(|v| v.as_i64()),
+                o.get("err").cloned().filter(|v| !v.is_null()),
+            )
+        } else {
+            (None, None, None)
+        };
+        QuickNodeWebhookEvent {
+            signature: sig.to_string(),
+            slot,
+            block_time,
+            err,
+            meta: None,
+        }
+    };
+
+    // Try parsing as array
+    if let Some(arr) = payload.as_array() {
+        for item in arr {
+            // Direct signature field
+            if let Some(sig) = item.get("signature").and_then(|v| v.as_str()) {
+                events.push(create_event(sig, Some(item)));
+            }
+            // Nested transaction.signatures array (common in Solana RPC format)
+            else if let Some(tx) = item.get("transaction")
+                && let Some(sigs) = tx.get("signatures").and_then(|v| v.as_array())
+                && let Some(first_sig) = sigs.first().and_then(|v| v.as_str())
+            {
+                events.push(create_event(first_sig, Some(item)));
+            }
+            // Try "data" wrapper
+            else if let Some(data) = item.get("data")
+                && let Some(sig) = data.get("signature").and_then(|v| v.as_str())
+            {
+                events.push(create_event(sig, Some(data)));
+            }
+        }
+    }
+    // Try parsing as single object
+    else if let Some(obj) = payload.as_object() {
+        // Direct signature field
+        if let Some(sig) = obj.get("signature").and_then(|v| v.as_str()) {
+            events.push(create_event(sig, Some(payload)));
+        }
+        // Nested transaction.signatures
+        else if let Some(tx) = obj.get("transaction")
+            && let Some(sigs) = tx.get("signatures").and_then(|v| v.as_array())
+            && let Some(first_sig) = sigs.first().and_then(|v| v.as_str())
+        {
+            events.push(create_event(first_sig, Some(payload)));
+        }
+        // Check for "data" array wrapper
+        else if let Some(data_arr) = obj.get("data").and_then(|v| v.as_array()) {
+            for item in data_arr {
+                if let Some(sig) = item.get("signature").and_then(|v| v.as_str()) {
+                    events.push(create_event(sig, Some(item)));
+                }
+            }
+        }
+        // Check for "transactions" array
+        else if let Some(txs) = obj.get("transactions").and_then(|v| v.as_array()) {
+            for tx in txs {
+                if let Some(sig) = tx.get("signature").and_then(|v| v.as_str()) {
+                    events.push(create_event(sig, Some(tx)));
+                } else if let Some(sigs) = tx.get("signatures").and_then(|v| v.as_array())
+                    && let Some(first_sig) = sigs.first().and_then(|v| v.as_str())
+                {
+                    events.push(create_event(first_sig, Some(tx)));
+                }
+            }
+        }
+    }
+
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ObservedTransfer;
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
+    fn headers(name: &'static str, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(name, value.parse().unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_helius_payload_normalized() {
+        let body = br#"[
+            {"type": "TRANSFER", "signature": "sig_ok", "transactionError": null, "source": "SYSTEM_PROGRAM"},
+            {"type": "TRANSFER", "signature": "sig_err", "transactionError": {"InstructionError": [0, "Custom"]}}
+        ]"#;
+        let events = HeliusWebhookProvider::default()
+            .verify(&HeaderMap::new(), body)
+            .unwrap();
+
+        assert_eq!(
+            events[0],
+            ConfirmationEvent {
+                signature: "sig_ok".to_string(),
+                success: true,
+                error: None,
+                slot: None,
+                transfers: None,
+            }
+        );
+        assert_eq!(events[1].signature, "sig_err");
+        assert!(!events[1].success);
+        assert!(
+            events[1]
+                .error
+                .as_deref()
+                .unwrap()
+                .contains("InstructionError")
+        );
+    }
+
+    #[test]
+    fn test_helius_enhanced_payload_parsed() {
+        let body = br#"[{
+            "type": "TRANSFER",
+            "source": "SOLANA_PROGRAM_LIBRARY",
+            "signature": "sig_enhanced",
+            "transactionError": null,
+            "fee": 5000,
+            "feePayer": "Sender111",
+            "nativeTransfers": [
+                {"fromUserAccount": "Sender111", "toUserAccount": "Tip111", "amount": 10000}
+            ],
+            "tokenTransfers": [{
+                "fromUserAccount": "Sender111",
+                "toUserAccount": "Recipient111",
+                "fromTokenAccount": "SenderAta111",
+                "toTokenAccount": "RecipientAta111",
+                "tokenAmount": 2.5,
+                "mint": "Mint111",
+                "tokenStandard": "Fungible"
+            }],
+            "accountData": [
+                {"account": "Sender111", "nativeBalanceChange": -15000, "tokenBalanceChanges": []},
+                {"account": "RecipientAta111", "nativeBalanceChange": 0, "tokenBalanceChanges": [{
+                    "userAccount": "Recipient111",
+                    "tokenAccount": "RecipientAta111",
+                    "mint": "Mint111",
+                    "rawTokenAmount": {"tokenAmount": "2500000", "decimals": 6}
+                }]}
+            ]
+        }]"#;
+        let events = HeliusWebhookProvider::default()
+            .verify(&HeaderMap::new(), body)
+            .unwrap();
+
+        assert!(events[0].success);
+        assert_eq!(
+            events[0].transfers,
+            Some(vec![
+                ObservedTransfer {
+                    recipient: "Tip111".to_string(),
+                    mint: None,
+                    amount: Some(10_000),
+                },
+                ObservedTransfer {
+                    recipient: "Recipient111".to_string(),
+                    mint: Some("Mint111".to_string()),
+                    amount: Some(2_500_000),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_helius_credit_reported_once_per_recipient_and_mint() {
+        let token_transfer = |amount: f64| {
+            serde_json::json!({
+                "fromUserAccount": "Sender111",
+                "toUserAccount": "Recipient111",
+                "fromTokenAccount": "SenderAta111",
+                "toTokenAccount": "RecipientAta111",
+                "tokenAmount": amount,
+                "mint": "Mint111",
+                "tokenStandard": "Fungible"
+            })
+        };
+        let body = serde_json::json!([{
+            "type": "TRANSFER",
+            "signature": "sig_batch",
+            "transactionError": null,
+            "nativeTransfers": [],
+            "tokenTransfers": [token_transfer(1.0), token_transfer(1.5)],
+            "accountData": [{
+                "account": "RecipientAta111",
+                "nativeBalanceChange": 0,
+                "tokenBalanceChanges": [{
+                    "userAccount": "Recipient111",
+                    "tokenAccount": "RecipientAta111",
+                    "mint": "Mint111",
+                    "rawTokenAmount": {"tokenAmount": "2500000", "decimals": 6}
+                }]
+            }]
+        }]);
+        let events = HeliusWebhookProvider::default()
+            .verify(&HeaderMap::new(), body.to_string().as_bytes())
+            .unwrap();
+
+        // Both transfers land in one balance change, which is the whole credit
+        assert_eq!(
+            events[0].transfers,
+            Some(vec![ObservedTransfer {
+                recipient: "Recipient111".to_string(),
+                mint: Some("Mint111".to_string()),
+                amount: Some(2_500_000),
+            }])
+        );
+    }
+
+    #[test]
+    fn test_helius_rejects_malformed_payload() {
+        let result = HeliusWebhookProvider::default().verify(&HeaderMap::new(), b"{}");
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn test_quicknode_payload_formats_normalized() {
+        let provider = QuickNodeWebhookProvider::default();
+
+        let direct = br#"[{"signature": "sig_a", "slot": 42, "err": null}]"#;
+        assert_eq!(
+            provider.verify(&HeaderMap::new(), direct).unwrap(),
+            vec![ConfirmationEvent {
+                signature: "sig_a".to_string(),
+                success: true,
+                error: None,
+                slot: Some(42),
+                transfers: None,
+            }]
+        );
+
+        let nested =
+            br#"{"transaction": {"signatures": ["sig_b"]}, "slot": 7, "err": "Custom(1)"}"#;
+        let events = provider.verify(&HeaderMap::new(), nested).unwrap();
+        assert_eq!(events[0].signature, "sig_b");
+        assert_eq!(events[0].slot, Some(7));
+        assert!(!events[0].success);
+        assert_eq!(events[0].error.as_deref(), Some("\"Custom(1)\""));
+
+        let wrapped = br#"{"transactions": [{"signature": "sig_c"}, {"signatures": ["sig_d"]}]}"#;
+        let signatures: Vec<_> = provider
+            .verify(&HeaderMap::new(), wrapped)
+            .unwrap()
+            .into_iter()
+            .map(|e| e.signature)
+            .collect();
+        assert_eq!(signatures, vec!["sig_c", "sig_d"]);
+
+        let ping = br#"{"message": "hello"}"#;
+        assert!(provider.verify(&HeaderMap::new(), ping).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_provider_enforces_configured_secret() {
+        let provider = QuickNodeWebhookProvider::new(Some("qn_test_secret".to_string()));
+        let body = br#"{"signature":"sig_123"}"#;
+
+        let unsigned = provider.verify(&HeaderMap::new(), body);
+        assert!(matches!(unsigned, Err(AppError::Authentication(_))));
+
+        // A digest of the body alone no longer verifies
+        let body_only = headers(
+            QUICKNODE_SIGNATURE_HEADER,
+            "8d2b036e5003a7c93c14fb9d219c01088583f66dc74737e3b5809b06e3599042",
+        );
+        let result = provider.verify(&body_only, body);
+        assert!(matches!(result, Err(AppError::Authentication(_))));
+
+        let legacy = headers(QUICKNODE_SIGNATURE_HEADER, "qn_test_secret");
+        assert_eq!(
+            provider.verify(&legacy, body).unwrap()[0].signature,
+            "sig_123"
+        );
+
+        let nonce = "nonce-1";
+        let timestamp = Utc::now().timestamp().to_string();
+        let mut mac = Hmac::<Sha256>::new_from_slice(b"qn_test_secret").unwrap();
+        mac.update(format!("{}{}", nonce, timestamp).as_bytes());
+        mac.update(body);
+        let signature = hex::encode(mac.finalize().into_bytes());
+        let mut signed = headers(QUICKNODE_SIGNATURE_HEADER, &signature);
+        signed.insert(QUICKNODE_NONCE_HEADER, nonce.parse().unwrap());
+        signed.insert(QUICKNODE_TIMESTAMP_HEADER, timestamp.parse().unwrap());
+        let events = provider.verify(&signed, body).unwrap();
+        assert_eq!(events[0].signature, "sig_123");
+    }
+}
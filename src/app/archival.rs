@@ -0,0 +1,230 @@
+//! Periodic archival of old terminal transfers.
+//!
+//! Transfers that reached a terminal blockchain status (`confirmed`,
+//! `failed`, `rejected`, `expired`, `cancelled`) and have not been updated for the
+//! retention period are soft-deleted in batches. Archived transfers are
+//! excluded from listings and worker queues but stay retrievable by id,
+//! so audit lookups keep working.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::watch;
+use tracing::{debug, error, info};
+
+use crate::domain::{AppError, DatabaseClient};
+
+/// Default interval between archival runs
+pub const DEFAULT_ARCHIVAL_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Default age after which terminal transfers are archived (90 days)
+pub const DEFAULT_ARCHIVAL_RETENTION: Duration = Duration::from_secs(90 * 24 * 60 * 60);
+
+/// Default number of transfers archived per batch
+pub const DEFAULT_ARCHIVAL_BATCH_SIZE: i64 = 500;
+
+/// Configuration for the archival service
+#[derive(Debug, Clone)]
+pub struct ArchivalConfig {
+    /// Interval between archival runs
+    pub poll_interval: Duration,
+    /// Archive terminal transfers not updated for this long
+    pub retention: Duration,
+    /// Number of transfers archived per batch
+    pub batch_size: i64,
+    /// Whether archival is enabled
+    pub enabled: bool,
+}
+
+impl Default for ArchivalConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_ARCHIVAL_INTERVAL,
+            retention: DEFAULT_ARCHIVAL_RETENTION,
+            batch_size: DEFAULT_ARCHIVAL_BATCH_SIZE,
+            enabled: true,
+        }
+    }
+}
+
+/// Background service soft-deleting old terminal transfers
+pub struct ArchivalService {
+    db_client: Arc<dyn DatabaseClient>,
+    config: ArchivalConfig,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl ArchivalService {
+    /// Create a new archival service
+    pub fn new(
+        db_client: Arc<dyn DatabaseClient>,
+        config: ArchivalConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            db_client,
+            config,
+            shutdown_rx,
+        }
+    }
+
+    /// Run the archival loop
+    pub async fn run(mut self) {
+        if !self.config.enabled {
+            info!("Transfer archival is disabled");
+            return;
+        }
+
+        info!(
+            poll_interval = ?self.config.poll_interval,
+            retention = ?self.config.retention,
+            batch_size = self.config.batch_size,
+            "Starting transfer archival"
+        );
+
+        loop {
+            match self.run_once().await {
+                Ok(0) => debug!("No transfers to archive"),
+                Ok(count) => info!(count, "Archived terminal transfers"),
+                Err(e) => error!(error = ?e, "Error archiving terminal transfers"),
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(self.config.poll_interval) => {}
+                result = self.shutdown_rx.changed() => {
+                    if result.is_ok() && *self.shutdown_rx.borrow() {
+                        info!("Transfer archival shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Archive every eligible transfer, one batch at a time.
+    /// Stops early on shutdown. Returns the number of transfers archived.
+    pub async fn run_once(&self) -> Result<u64, AppError> {
+        if !self.config.enabled {
+            return Ok(0);
+        }
+
+        let retention = chrono::Duration::from_std(self.config.retention)
+            .map_err(|e| AppError::Internal(format!("Invalid archival retention: {}", e)))?;
+        let older_than = Utc::now() - retention;
+        let batch_size = self.config.batch_size.max(1);
+
+        let mut total = 0;
+        loop {
+            let archived = self
+                .db_client
+                .archive_terminal_transfers(older_than, batch_size)
+                .await?;
+            total += archived;
+            if archived < batch_size as u64 || *self.shutdown_rx.borrow() {
+                return Ok(total);
+            }
+        }
+    }
+}
+
+/// Spawn the archival service as a tokio task.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_archival(
+    db_client: Arc<dyn DatabaseClient>,
+    config: ArchivalConfig,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let archival = ArchivalService::new(db_client, config, shutdown_rx);
+    let handle = tokio::spawn(archival.run());
+    (handle, shutdown_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::BlockchainStatus;
+    use crate::test_utils::{MockDatabaseClient, transfer_request};
+
+    async fn insert(db: &MockDatabaseClient, i: usize, status: BlockchainStatus) -> String {
+        let tr = db.submit_transfer(&transfer_request(i)).await.unwrap();
+        db.update_blockchain_status(&tr.id, status, None, None, None, None)
+            .await
+            .unwrap();
+        tr.id
+    }
+
+    fn service(
+        db: Arc<MockDatabaseClient>,
+        retention: Duration,
+        batch_size: i64,
+    ) -> ArchivalService {
+        let config = ArchivalConfig {
+            retention,
+            batch_size,
+            ..ArchivalConfig::default()
+        };
+        ArchivalService::new(db, config, watch::channel(false).1)
+    }
+
+    #[tokio::test]
+    async fn test_archives_only_old_terminal_transfers() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let confirmed = insert(&db, 0, BlockchainStatus::Confirmed).await;
+        let failed = insert(&db, 1, BlockchainStatus::Failed).await;
+        let pending = insert(&db, 2, BlockchainStatus::PendingSubmission).await;
+        let submitted = insert(&db, 3, BlockchainStatus::Submitted).await;
+
+        // Nothing is older than the default retention yet
+        let archival = service(Arc::clone(&db), DEFAULT_ARCHIVAL_RETENTION, 10);
+        assert_eq!(archival.run_once().await.unwrap(), 0);
+
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        let archival = service(Arc::clone(&db), Duration::ZERO, 10);
+        assert_eq!(archival.run_once().await.unwrap(), 2);
+
+        for (id, archived) in [
+            (&confirmed, true),
+            (&failed, true),
+            (&pending, false),
+            (&submitted, false),
+        ] {
+            // Archived transfers stay retrievable by id
+            let transfer = db.get_transfer_request(id).await.unwrap().unwrap();
+            assert_eq!(transfer.archived_at.is_some(), archived, "transfer {}", id);
+        }
+
+        // Already archived transfers are not archived again
+        assert_eq!(archival.run_once().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_archives_in_batches() {
+        let db = Arc::new(MockDatabaseClient::new());
+        for i in 0..5 {
+            insert(&db, i, BlockchainStatus::Confirmed).await;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let archival = service(Arc::clone(&db), Duration::ZERO, 2);
+        assert_eq!(archival.run_once().await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_archived_transfers_excluded_from_listing_and_queue() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let confirmed = insert(&db, 0, BlockchainStatus::Confirmed).await;
+        let pending = insert(&db, 1, BlockchainStatus::PendingSubmission).await;
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        service(Arc::clone(&db), Duration::ZERO, 10)
+            .run_once()
+            .await
+            .unwrap();
+
+        let listed = db.list_transfer_requests(100, None).await.unwrap();
+        let ids: Vec<&str> = listed.items.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, [pending.as_str()]);
+        assert!(!ids.contains(&confirmed.as_str()));
+        assert_eq!(db.count_pending_blockchain_requests().await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,345 @@
+//! Fee payer balance monitoring.
+//!
+//! The relayer's keypair pays every fee and Jito tip, so a drained fee payer
+//! fails every transfer. [`BalanceMonitor`] polls its balance, keeps the last
+//! reading for the verbose health check, and logs a warning on every check
+//! while it is below `MIN_FEE_PAYER_LAMPORTS`. With outbound webhooks and a
+//! global callback URL configured, it also POSTs a signed alert when the
+//! balance first drops below the threshold, and with chat alerting
+//! configured it posts the same event to Slack or Discord.
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+use crate::domain::{Alert, AlertKind, AppError, BlockchainClient, FeePayerBalance};
+use crate::infra::alerting::AlertDispatcher;
+use crate::infra::outbound_webhooks::{OutboundWebhookConfig, send_alert};
+
+/// Default interval between balance checks
+pub const DEFAULT_BALANCE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Default low-balance threshold: 0.1 SOL
+pub const DEFAULT_MIN_FEE_PAYER_LAMPORTS: u64 = 100_000_000;
+
+/// Event name of the low-balance alert
+pub const FEE_PAYER_LOW_BALANCE_EVENT: &str = "fee_payer_low_balance";
+
+/// Timeout of the alert request
+const ALERT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Configuration for the balance monitor
+#[derive(Clone)]
+pub struct BalanceMonitorConfig {
+    /// Interval between balance checks
+    pub poll_interval: Duration,
+    /// Balance below which the fee payer is reported as low
+    pub min_lamports: u64,
+    /// Outbound webhook configuration used to send the low-balance alert
+    pub alert: Option<OutboundWebhookConfig>,
+    /// Chat alerting used to report the low balance to operators
+    pub alert_dispatcher: Option<Arc<AlertDispatcher>>,
+}
+
+impl Default for BalanceMonitorConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_BALANCE_CHECK_INTERVAL,
+            min_lamports: DEFAULT_MIN_FEE_PAYER_LAMPORTS,
+            alert: None,
+            alert_dispatcher: None,
+        }
+    }
+}
+
+/// JSON body of the low-balance alert
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct FeePayerAlertPayload {
+    /// Always `fee_payer_low_balance`
+    pub event: String,
+    /// The reading that crossed the threshold
+    #[serde(flatten)]
+    pub balance: FeePayerBalance,
+}
+
+/// Latest fee payer balance, shared between the monitor and the health check
+#[derive(Debug, Clone, Default)]
+pub struct FeePayerBalanceTracker(Arc<RwLock<Option<FeePayerBalance>>>);
+
+impl FeePayerBalanceTracker {
+    /// Last recorded reading, if a check has succeeded yet
+    #[must_use]
+    pub fn latest(&self) -> Option<FeePayerBalance> {
+        *self.0.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn record(&self, balance: FeePayerBalance) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = Some(balance);
+    }
+}
+
+/// Background service watching the fee payer balance
+pub struct BalanceMonitor {
+    blockchain_client: Arc<dyn BlockchainClient>,
+    config: BalanceMonitorConfig,
+    tracker: FeePayerBalanceTracker,
+    http_client: Client,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl BalanceMonitor {
+    /// Create a new balance monitor recording readings into `tracker`
+    pub fn new(
+        blockchain_client: Arc<dyn BlockchainClient>,
+        config: BalanceMonitorConfig,
+        tracker: FeePayerBalanceTracker,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        let http_client = Client::builder()
+            .timeout(ALERT_TIMEOUT)
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            blockchain_client,
+            config,
+            tracker,
+            http_client,
+            shutdown_rx,
+        }
+    }
+
+    /// Run the monitoring loop
+    pub async fn run(mut self) {
+        info!(
+            poll_interval = ?self.config.poll_interval,
+            min_lamports = self.config.min_lamports,
+            "Starting fee payer balance monitor"
+        );
+
+        loop {
+            if let Err(e) = self.check_once().await {
+                error!(error = ?e, "Failed to check fee payer balance");
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(self.config.poll_interval) => {}
+                result = self.shutdown_rx.changed() => {
+                    if result.is_ok() && *self.shutdown_rx.borrow() {
+                        info!("Fee payer balance monitor shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Read the balance once, record it and warn if it is low.
+    /// A failed read keeps the previous reading.
+    pub async fn check_once(&self) -> Result<FeePayerBalance, AppError> {
+        let lamports = self.blockchain_client.get_fee_payer_balance().await?;
+        let balance = FeePayerBalance::new(lamports, self.config.min_lamports);
+        let was_low = self.tracker.latest().is_some_and(|previous| previous.low);
+        self.tracker.record(balance);
+
+        if balance.low {
+            warn!(
+                balance_lamports = lamports,
+                min_lamports = self.config.min_lamports,
+                "FEE PAYER BALANCE LOW - top up the relayer keypair; transfers fail once it cannot cover fees and tips"
+            );
+            if !was_low {
+                self.alert(balance).await;
+            }
+        } else if was_low {
+            info!(
+                balance_lamports = lamports,
+                min_lamports = self.config.min_lamports,
+                "Fee payer balance back above threshold"
+            );
+        } else {
+            debug!(balance_lamports = lamports, "Fee payer balance checked");
+        }
+
+        Ok(balance)
+    }
+
+    /// Send the low-balance alert, if alerting is configured
+    async fn alert(&self, balance: FeePayerBalance) {
+        if let Some(dispatcher) = &self.config.alert_dispatcher {
+            dispatcher
+                .dispatch(Alert::new(
+                    AlertKind::FeePayerLowBalance,
+                    format!(
+                        "Balance is {} lamports, below the {} lamport threshold. Top up the relayer keypair.",
+                        balance.lamports, balance.min_lamports
+                    ),
+                ))
+                .await;
+        }
+        let Some(config) = &self.config.alert else {
+            return;
+        };
+        let payload = FeePayerAlertPayload {
+            event: FEE_PAYER_LOW_BALANCE_EVENT.to_string(),
+            balance,
+        };
+        match send_alert(&self.http_client, config, &payload).await {
+            Ok(()) => info!("Fee payer low-balance alert sent"),
+            Err(reason) => warn!(error = %reason, "Failed to send fee payer low-balance alert"),
+        }
+    }
+}
+
+/// Spawn the balance monitor as a tokio task.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_balance_monitor(
+    blockchain_client: Arc<dyn BlockchainClient>,
+    config: BalanceMonitorConfig,
+    tracker: FeePayerBalanceTracker,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let monitor = BalanceMonitor::new(blockchain_client, config, tracker, shutdown_rx);
+    let handle = tokio::spawn(monitor.run());
+    (handle, shutdown_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{header_exists, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::infra::alerting::{DEFAULT_ALERT_COOLDOWN, SlackWebhookSink};
+    use crate::infra::outbound_webhooks::{SIGNATURE_HEADER, sign_payload};
+    use crate::test_utils::MockBlockchainClient;
+
+    const MIN_LAMPORTS: u64 = 1_000_000_000;
+
+    fn monitor(
+        blockchain: &Arc<MockBlockchainClient>,
+        alert: Option<OutboundWebhookConfig>,
+    ) -> (BalanceMonitor, FeePayerBalanceTracker) {
+        let config = BalanceMonitorConfig {
+            min_lamports: MIN_LAMPORTS,
+            alert,
+            ..BalanceMonitorConfig::default()
+        };
+        let tracker = FeePayerBalanceTracker::default();
+        let monitor = BalanceMonitor::new(
+            Arc::clone(blockchain) as _,
+            config,
+            tracker.clone(),
+            watch::channel(false).1,
+        );
+        (monitor, tracker)
+    }
+
+    #[tokio::test]
+    async fn test_flags_balance_crossing_threshold() {
+        let blockchain = Arc::new(MockBlockchainClient::new());
+        let (monitor, tracker) = monitor(&blockchain, None);
+        assert!(tracker.latest().is_none());
+
+        let balance = monitor.check_once().await.unwrap();
+        assert!(!balance.low);
+        assert_eq!(tracker.latest(), Some(balance));
+
+        blockchain.set_fee_payer_balance(MIN_LAMPORTS - 1);
+        let balance = monitor.check_once().await.unwrap();
+        assert!(balance.low);
+        assert_eq!(balance.lamports, MIN_LAMPORTS - 1);
+        assert_eq!(balance.min_lamports, MIN_LAMPORTS);
+        assert!(tracker.latest().unwrap().low);
+
+        blockchain.set_fee_payer_balance(MIN_LAMPORTS);
+        assert!(!monitor.check_once().await.unwrap().low);
+        assert!(!tracker.latest().unwrap().low);
+    }
+
+    #[tokio::test]
+    async fn test_failed_check_keeps_last_reading() {
+        let blockchain = Arc::new(MockBlockchainClient::new());
+        let (monitor, tracker) = monitor(&blockchain, None);
+        let balance = monitor.check_once().await.unwrap();
+
+        blockchain.set_unreachable(true);
+        assert!(monitor.check_once().await.is_err());
+        assert_eq!(tracker.latest(), Some(balance));
+    }
+
+    #[tokio::test]
+    async fn test_alerts_once_per_low_period() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/alerts"))
+            .and(header_exists(SIGNATURE_HEADER))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let alert = OutboundWebhookConfig::new("whsec_test_secret")
+            .with_default_url(Some(format!("{}/alerts", server.uri())));
+        let blockchain = Arc::new(MockBlockchainClient::new());
+        let (monitor, _tracker) = monitor(&blockchain, Some(alert));
+
+        // Alerted when the balance first drops, not on every low reading
+        blockchain.set_fee_payer_balance(1_000);
+        monitor.check_once().await.unwrap();
+        monitor.check_once().await.unwrap();
+
+        // Recovering and dropping again alerts again
+        blockchain.set_fee_payer_balance(MIN_LAMPORTS);
+        monitor.check_once().await.unwrap();
+        blockchain.set_fee_payer_balance(1_000);
+        monitor.check_once().await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let request = &requests[0];
+        let payload: FeePayerAlertPayload = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(payload.event, FEE_PAYER_LOW_BALANCE_EVENT);
+        assert_eq!(payload.balance.lamports, 1_000);
+        assert!(payload.balance.low);
+        let signature = request.headers[SIGNATURE_HEADER].to_str().unwrap();
+        assert_eq!(
+            signature,
+            format!(
+                "sha256={}",
+                sign_payload("whsec_test_secret", &request.body)
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_posts_chat_alert_when_balance_drops() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/slack"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let sink = Arc::new(SlackWebhookSink::new(format!("{}/slack", server.uri())));
+        let blockchain = Arc::new(MockBlockchainClient::new());
+        let (mut monitor, _tracker) = monitor(&blockchain, None);
+        monitor.config.alert_dispatcher = Some(Arc::new(AlertDispatcher::new(
+            vec![sink],
+            DEFAULT_ALERT_COOLDOWN,
+        )));
+
+        blockchain.set_fee_payer_balance(1_000);
+        monitor.check_once().await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        let text = body["text"].as_str().unwrap();
+        assert!(text.contains("Fee payer balance low"));
+        assert!(text.contains("1000 lamports"));
+    }
+}
@@ -0,0 +1,39 @@
+//! Correlation id of the API request being handled.
+//!
+//! The request id middleware runs every request inside
+//! [`with_correlation_id`]. Code called from the handler reads the id back
+//! with [`current_correlation_id`] instead of threading it through every
+//! service signature.
+
+use std::future::Future;
+
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+/// Run `future` with `id` as the current correlation id
+pub async fn with_correlation_id<F: Future>(id: String, future: F) -> F::Output {
+    CORRELATION_ID.scope(id, future).await
+}
+
+/// Correlation id of the request being handled, if any
+#[must_use]
+pub fn current_correlation_id() -> Option<String> {
+    CORRELATION_ID.try_with(Clone::clone).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_correlation_id_scoped_to_future() {
+        assert_eq!(current_correlation_id(), None);
+
+        let inner =
+            with_correlation_id("req-1".to_string(), async { current_correlation_id() }).await;
+        assert_eq!(inner.as_deref(), Some("req-1"));
+
+        assert_eq!(current_correlation_id(), None);
+    }
+}
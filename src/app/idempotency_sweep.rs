@@ -0,0 +1,183 @@
+//! Periodic cleanup of expired `Idempotency-Key` records.
+//!
+//! A recorded response is only replayed until its `expires_at`; afterwards
+//! the record is dead weight. The sweep deletes expired records in batches
+//! so the table does not grow with every keyed request.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::watch;
+use tracing::{debug, error, info};
+
+use crate::domain::{AppError, DatabaseClient};
+
+/// Default interval between idempotency sweeps
+pub const DEFAULT_IDEMPOTENCY_SWEEP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Default number of records deleted per batch
+pub const DEFAULT_IDEMPOTENCY_SWEEP_BATCH_SIZE: i64 = 500;
+
+/// Configuration for the idempotency sweep
+#[derive(Debug, Clone)]
+pub struct IdempotencySweepConfig {
+    /// Interval between sweeps
+    pub poll_interval: Duration,
+    /// Number of records deleted per batch
+    pub batch_size: i64,
+    /// Whether the sweep is enabled (on by default, like `ENABLE_IDEMPOTENCY_SWEEP`)
+    pub enabled: bool,
+}
+
+impl Default for IdempotencySweepConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_IDEMPOTENCY_SWEEP_INTERVAL,
+            batch_size: DEFAULT_IDEMPOTENCY_SWEEP_BATCH_SIZE,
+            enabled: true,
+        }
+    }
+}
+
+/// Background service deleting expired idempotency records
+pub struct IdempotencySweepService {
+    db_client: Arc<dyn DatabaseClient>,
+    config: IdempotencySweepConfig,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl IdempotencySweepService {
+    /// Create a new idempotency sweep service
+    pub fn new(
+        db_client: Arc<dyn DatabaseClient>,
+        config: IdempotencySweepConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            db_client,
+            config,
+            shutdown_rx,
+        }
+    }
+
+    /// Run the sweep loop
+    pub async fn run(mut self) {
+        if !self.config.enabled {
+            info!("Idempotency sweep is disabled");
+            return;
+        }
+
+        info!(
+            poll_interval = ?self.config.poll_interval,
+            batch_size = self.config.batch_size,
+            "Starting idempotency sweep"
+        );
+
+        loop {
+            match self.run_once().await {
+                Ok(0) => debug!("No idempotency records to purge"),
+                Ok(count) => info!(count, "Purged expired idempotency records"),
+                Err(e) => error!(error = ?e, "Error purging expired idempotency records"),
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(self.config.poll_interval) => {}
+                result = self.shutdown_rx.changed() => {
+                    if result.is_ok() && *self.shutdown_rx.borrow() {
+                        info!("Idempotency sweep shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Delete every expired record, one batch at a time.
+    /// Stops early on shutdown. Returns the number of records deleted.
+    pub async fn run_once(&self) -> Result<u64, AppError> {
+        if !self.config.enabled {
+            return Ok(0);
+        }
+
+        let now = Utc::now();
+        let batch_size = self.config.batch_size.max(1);
+
+        let mut total = 0;
+        loop {
+            let purged = self
+                .db_client
+                .purge_expired_idempotency_records(now, batch_size)
+                .await?;
+            total += purged;
+            if purged < batch_size as u64 || *self.shutdown_rx.borrow() {
+                return Ok(total);
+            }
+        }
+    }
+}
+
+/// Spawn the idempotency sweep as a tokio task.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_idempotency_sweep(
+    db_client: Arc<dyn DatabaseClient>,
+    config: IdempotencySweepConfig,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let sweep = IdempotencySweepService::new(db_client, config, shutdown_rx);
+    let handle = tokio::spawn(sweep.run());
+    (handle, shutdown_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::IdempotencyRecord;
+    use crate::test_utils::MockDatabaseClient;
+
+    async fn record(db: &MockDatabaseClient, key: &str, expires_in: chrono::Duration) {
+        let created_at = Utc::now() - chrono::Duration::days(1);
+        let record = IdempotencyRecord {
+            scope: String::new(),
+            key: key.to_string(),
+            request_hash: "a".repeat(64),
+            status_code: 200,
+            response_body: "{}".to_string(),
+            transfer_id: None,
+            created_at,
+            expires_at: Utc::now() + expires_in,
+        };
+        db.save_idempotency_record(&record).await.unwrap();
+    }
+
+    fn service(db: Arc<MockDatabaseClient>, batch_size: i64) -> IdempotencySweepService {
+        let config = IdempotencySweepConfig {
+            batch_size,
+            ..IdempotencySweepConfig::default()
+        };
+        IdempotencySweepService::new(db, config, watch::channel(false).1)
+    }
+
+    #[tokio::test]
+    async fn test_purges_expired_records_in_batches() {
+        let db = Arc::new(MockDatabaseClient::new());
+        for i in 0..5 {
+            record(
+                &db,
+                &format!("expired-{}", i),
+                chrono::Duration::minutes(-1),
+            )
+            .await;
+        }
+        record(&db, "live", chrono::Duration::hours(1)).await;
+
+        assert_eq!(service(Arc::clone(&db), 2).run_once().await.unwrap(), 5);
+        assert_eq!(service(Arc::clone(&db), 2).run_once().await.unwrap(), 0);
+        assert!(
+            db.find_idempotency_record("", "live")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
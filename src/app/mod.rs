@@ -1,14 +1,46 @@
 //! Application layer containing business logic and shared state.
 
+pub mod archival;
+pub mod balance_monitor;
+pub mod correlation;
+pub mod idempotency_sweep;
+pub mod nonce_sweep;
 pub mod risk_service;
+pub mod rpc_limiter;
 pub mod service;
+pub mod shutdown;
 pub mod state;
+mod webhook_dedup;
 pub mod worker;
 
+pub use archival::{
+    ArchivalConfig, ArchivalService, DEFAULT_ARCHIVAL_BATCH_SIZE, DEFAULT_ARCHIVAL_INTERVAL,
+    DEFAULT_ARCHIVAL_RETENTION, spawn_archival,
+};
+pub use balance_monitor::{
+    BalanceMonitor, BalanceMonitorConfig, DEFAULT_BALANCE_CHECK_INTERVAL,
+    DEFAULT_MIN_FEE_PAYER_LAMPORTS, FeePayerBalanceTracker, spawn_balance_monitor,
+};
+pub use idempotency_sweep::{
+    DEFAULT_IDEMPOTENCY_SWEEP_BATCH_SIZE, DEFAULT_IDEMPOTENCY_SWEEP_INTERVAL,
+    IdempotencySweepConfig, IdempotencySweepService, spawn_idempotency_sweep,
+};
+pub use nonce_sweep::{
+    DEFAULT_NONCE_RETENTION, DEFAULT_NONCE_SWEEP_BATCH_SIZE, DEFAULT_NONCE_SWEEP_INTERVAL,
+    NonceSweepConfig, NonceSweepService, spawn_nonce_sweep,
+};
 pub use risk_service::RiskService;
-pub use service::AppService;
-pub use state::AppState;
+pub use rpc_limiter::{DEFAULT_WORKER_MAX_RPS, RpcRateLimiter};
+pub use service::{
+    AppService, BlockchainRetryConfig, DEFAULT_CLOCK_SKEW_TOLERANCE_SECS, DEFAULT_MAX_BACKOFF_SECS,
+    DEFAULT_MAX_RETRY_ATTEMPTS, MAX_CLOCK_SKEW_TOLERANCE_SECS, ScreeningOrder, ScreeningPolicy,
+    TransferLimits, parse_screening_order_overrides,
+};
+pub use shutdown::{DrainOutcome, ShutdownSummary};
+pub use state::{AppState, DEFAULT_MAX_BULK_TRANSFERS};
 pub use worker::{
-    BlockchainRetryWorker, CrankConfig, StaleTransactionCrank, WorkerConfig, spawn_crank,
-    spawn_worker, spawn_worker_with_privacy,
+    BlockchainRetryWorker, CrankConfig, DEFAULT_PROCESSING_LEASE_SECS,
+    DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF, DEFAULT_WORKER_CONCURRENCY, DEFAULT_WORKER_DRAIN_TIMEOUT,
+    ProcessingPause, StaleTransactionCrank, WorkerConfig, spawn_crank, spawn_worker,
+    spawn_worker_with_privacy,
 };
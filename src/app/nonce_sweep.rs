@@ -0,0 +1,234 @@
+//! Periodic cleanup of nonces that no longer protect against replay.
+//!
+//! A nonce only has to stay unique while its signed request could still be
+//! accepted. Once a transfer is terminal, its `valid_until` has passed and
+//! it has not been updated for the retention period, the nonce is cleared so
+//! the uniqueness index stops growing. The transfer row itself is kept.
+//!
+//! Transfers signed without a `valid_until` keep their nonce forever: the
+//! signed payload never expires, so clearing the nonce would let it be
+//! replayed.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::watch;
+use tracing::{debug, error, info};
+
+use crate::domain::{AppError, DatabaseClient};
+
+/// Default interval between nonce sweeps
+pub const DEFAULT_NONCE_SWEEP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Default age after which nonces of expired terminal transfers are cleared (30 days)
+pub const DEFAULT_NONCE_RETENTION: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+
+/// Default number of nonces cleared per batch
+pub const DEFAULT_NONCE_SWEEP_BATCH_SIZE: i64 = 500;
+
+/// Configuration for the nonce sweep
+#[derive(Debug, Clone)]
+pub struct NonceSweepConfig {
+    /// Interval between sweeps
+    pub poll_interval: Duration,
+    /// Clear nonces of transfers not updated (and expired) for this long
+    pub retention: Duration,
+    /// Number of nonces cleared per batch
+    pub batch_size: i64,
+    /// Whether the sweep is enabled (off by default, like `ENABLE_NONCE_SWEEP`)
+    pub enabled: bool,
+}
+
+impl Default for NonceSweepConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: DEFAULT_NONCE_SWEEP_INTERVAL,
+            retention: DEFAULT_NONCE_RETENTION,
+            batch_size: DEFAULT_NONCE_SWEEP_BATCH_SIZE,
+            enabled: false,
+        }
+    }
+}
+
+/// Background service clearing nonces of old, expired terminal transfers
+pub struct NonceSweepService {
+    db_client: Arc<dyn DatabaseClient>,
+    config: NonceSweepConfig,
+    shutdown_rx: watch::Receiver<bool>,
+}
+
+impl NonceSweepService {
+    /// Create a new nonce sweep service
+    pub fn new(
+        db_client: Arc<dyn DatabaseClient>,
+        config: NonceSweepConfig,
+        shutdown_rx: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            db_client,
+            config,
+            shutdown_rx,
+        }
+    }
+
+    /// Run the sweep loop
+    pub async fn run(mut self) {
+        if !self.config.enabled {
+            info!("Nonce sweep is disabled");
+            return;
+        }
+
+        info!(
+            poll_interval = ?self.config.poll_interval,
+            retention = ?self.config.retention,
+            batch_size = self.config.batch_size,
+            "Starting nonce sweep"
+        );
+
+        loop {
+            match self.run_once().await {
+                Ok(0) => debug!("No nonces to purge"),
+                Ok(count) => info!(count, "Purged expired nonces"),
+                Err(e) => error!(error = ?e, "Error purging expired nonces"),
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(self.config.poll_interval) => {}
+                result = self.shutdown_rx.changed() => {
+                    if result.is_ok() && *self.shutdown_rx.borrow() {
+                        info!("Nonce sweep shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Clear every eligible nonce, one batch at a time.
+    /// Stops early on shutdown. Returns the number of nonces cleared.
+    pub async fn run_once(&self) -> Result<u64, AppError> {
+        if !self.config.enabled {
+            return Ok(0);
+        }
+
+        let retention = chrono::Duration::from_std(self.config.retention)
+            .map_err(|e| AppError::Internal(format!("Invalid nonce retention: {}", e)))?;
+        let older_than = Utc::now() - retention;
+        let batch_size = self.config.batch_size.max(1);
+
+        let mut total = 0;
+        loop {
+            let purged = self
+                .db_client
+                .purge_expired_nonces(older_than, batch_size)
+                .await?;
+            total += purged;
+            if purged < batch_size as u64 || *self.shutdown_rx.borrow() {
+                return Ok(total);
+            }
+        }
+    }
+}
+
+/// Spawn the nonce sweep as a tokio task.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_nonce_sweep(
+    db_client: Arc<dyn DatabaseClient>,
+    config: NonceSweepConfig,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let sweep = NonceSweepService::new(db_client, config, shutdown_rx);
+    let handle = tokio::spawn(sweep.run());
+    (handle, shutdown_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{BlockchainStatus, SubmitTransferRequest};
+    use crate::test_utils::{MockDatabaseClient, transfer_request};
+
+    const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+    async fn insert(
+        db: &MockDatabaseClient,
+        i: usize,
+        status: BlockchainStatus,
+        valid_until: Option<chrono::DateTime<Utc>>,
+        age: Duration,
+    ) -> String {
+        let request = SubmitTransferRequest {
+            valid_until,
+            ..transfer_request(i)
+        };
+        let tr = db.submit_transfer(&request).await.unwrap();
+        db.update_blockchain_status(&tr.id, status, None, None, None, None)
+            .await
+            .unwrap();
+        db.set_updated_at(
+            &tr.id,
+            Utc::now() - chrono::Duration::from_std(age).unwrap(),
+        );
+        tr.id
+    }
+
+    fn service(db: Arc<MockDatabaseClient>, batch_size: i64) -> NonceSweepService {
+        let config = NonceSweepConfig {
+            retention: 7 * DAY,
+            batch_size,
+            enabled: true,
+            ..NonceSweepConfig::default()
+        };
+        NonceSweepService::new(db, config, watch::channel(false).1)
+    }
+
+    #[tokio::test]
+    async fn test_purges_old_terminal_nonce_and_keeps_recent() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let expired = Some(Utc::now() - chrono::Duration::days(30));
+        let old = insert(&db, 0, BlockchainStatus::Confirmed, expired, 10 * DAY).await;
+        let recent = insert(&db, 1, BlockchainStatus::Confirmed, expired, DAY).await;
+
+        assert_eq!(service(Arc::clone(&db), 10).run_once().await.unwrap(), 1);
+
+        // The transfer itself is kept, only its nonce is cleared
+        let old = db.get_transfer_request(&old).await.unwrap().unwrap();
+        assert!(old.nonce.is_none());
+        let recent = db.get_transfer_request(&recent).await.unwrap().unwrap();
+        assert_eq!(recent.nonce, Some(transfer_request(1).nonce));
+    }
+
+    #[tokio::test]
+    async fn test_never_purges_in_flight_or_unexpiring_nonces() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let expired = Some(Utc::now() - chrono::Duration::days(30));
+        let in_flight = [
+            BlockchainStatus::Received,
+            BlockchainStatus::PendingSubmission,
+            BlockchainStatus::Submitted,
+        ];
+        for (i, status) in in_flight.into_iter().enumerate() {
+            insert(&db, i, status, expired, 10 * DAY).await;
+        }
+        // Terminal but still valid, or signed without an expiry
+        let future = Some(Utc::now() + chrono::Duration::days(1));
+        insert(&db, 3, BlockchainStatus::Failed, future, 10 * DAY).await;
+        insert(&db, 4, BlockchainStatus::Confirmed, None, 10 * DAY).await;
+
+        assert_eq!(service(Arc::clone(&db), 10).run_once().await.unwrap(), 0);
+        assert!(db.get_all_items().iter().all(|item| item.nonce.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_purges_in_batches() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let expired = Some(Utc::now() - chrono::Duration::days(30));
+        for i in 0..5 {
+            insert(&db, i, BlockchainStatus::Expired, expired, 10 * DAY).await;
+        }
+
+        assert_eq!(service(Arc::clone(&db), 2).run_once().await.unwrap(), 5);
+        assert_eq!(service(Arc::clone(&db), 2).run_once().await.unwrap(), 0);
+    }
+}
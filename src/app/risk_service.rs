@@ -7,13 +7,16 @@
 //!
 //! Results are cached to reduce API costs.
 
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use chrono::Utc;
+use futures_util::{StreamExt, TryStreamExt, stream};
 use tracing::{debug, info, instrument, warn};
 
 use crate::domain::{
-    AppError, BlockchainClient, DatabaseClient, RiskCheckResult, WalletRiskProfile,
+    AppError, BlockchainClient, BlocklistCategory, DatabaseClient, RiskCheckResult,
+    WalletRiskProfile,
 };
 use crate::infra::BlocklistManager;
 use crate::infra::compliance::RangeComplianceProvider;
@@ -21,6 +24,12 @@ use crate::infra::compliance::RangeComplianceProvider;
 /// Default cache TTL: 1 hour (3600 seconds)
 pub const DEFAULT_CACHE_TTL_SECS: i64 = 3600;
 
+/// Maximum number of addresses in one bulk risk check
+pub const MAX_BULK_RISK_CHECK_ADDRESSES: usize = 100;
+
+/// Default number of addresses a bulk risk check looks up concurrently
+pub const DEFAULT_RISK_CHECK_CONCURRENCY: usize = 8;
+
 /// Service for pre-flight wallet risk checking.
 ///
 /// Implements a "Fast Fail / Cache First" hierarchy:
@@ -35,6 +44,7 @@ pub struct RiskService {
     range_provider: Arc<RangeComplianceProvider>,
     blocklist: Option<Arc<BlocklistManager>>,
     cache_ttl_secs: i64,
+    concurrency: usize,
 }
 
 impl RiskService {
@@ -57,6 +67,7 @@ impl RiskService {
             range_provider,
             blocklist,
             cache_ttl_secs: DEFAULT_CACHE_TTL_SECS,
+            concurrency: DEFAULT_RISK_CHECK_CONCURRENCY,
         }
     }
 
@@ -67,6 +78,50 @@ impl RiskService {
         self
     }
 
+    /// Set how many addresses a bulk risk check looks up concurrently.
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Check the risk status of several wallet addresses.
+    ///
+    /// Repeated addresses are checked once, and at most `concurrency` checks
+    /// run at a time. Each check follows [`Self::check_wallet_risk`], so cached
+    /// profiles avoid provider calls. Returns one result per address, in
+    /// request order.
+    #[instrument(skip(self, addresses), fields(count = addresses.len()))]
+    pub async fn check_wallets_risk(
+        &self,
+        addresses: &[String],
+    ) -> Result<Vec<RiskCheckResult>, AppError> {
+        let mut seen = HashSet::new();
+        // Owned addresses keep the futures free of a borrow of the slice,
+        // which the handler's future could not otherwise prove `Send`
+        let unique: Vec<String> = addresses
+            .iter()
+            .filter(|address| seen.insert(address.as_str()))
+            .cloned()
+            .collect();
+        debug!(unique = unique.len(), "Checking wallet risk in bulk");
+
+        let results: HashMap<String, RiskCheckResult> = stream::iter(unique)
+            .map(|address| async move {
+                self.check_wallet_risk(&address)
+                    .await
+                    .map(|result| (address, result))
+            })
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+
+        Ok(addresses
+            .iter()
+            .map(|address| results[address.as_str()].clone())
+            .collect())
+    }
+
     /// Check the risk status of a wallet address.
     ///
     /// This method implements the "Fast Fail / Cache First" hierarchy:
@@ -100,6 +155,7 @@ impl RiskService {
                 risk_score: profile.risk_score.unwrap_or(0),
                 risk_level: profile.risk_level.unwrap_or_else(|| "Unknown".to_string()),
                 reasoning: profile.reasoning.unwrap_or_default(),
+                risk_factors: profile.risk_factors,
                 has_sanctioned_assets: profile.has_sanctioned_assets,
                 helius_assets_checked: profile.helius_assets_checked,
                 from_cache: true,
@@ -112,20 +168,25 @@ impl RiskService {
 
         // Call Range Protocol API
         let range_result = self.range_provider.check_address_risk(address).await;
-        let (risk_score, risk_level, reasoning) = match range_result {
-            Ok(response) => (
-                Some(response.risk_score),
-                Some(response.risk_level),
-                Some(response.reasoning),
-            ),
+        let (risk_score, risk_level, reasoning, risk_factors) = match range_result {
+            Ok(response) => {
+                let risk_factors = response.risk_factors();
+                (
+                    Some(response.risk_score),
+                    Some(response.risk_level),
+                    Some(response.reasoning),
+                    risk_factors,
+                )
+            }
             Err(e) => {
                 warn!(error = ?e, "Range Protocol API call failed, continuing with partial data");
-                (None, None, None)
+                (None, None, None, Vec::new())
             }
         };
 
-        // Call Helius DAS (check_wallet_assets returns false if sanctioned assets found)
-        // The method returns true for non-Helius providers (skip check / assume compliant)
+        // Call Helius DAS or the token account fallback (check_wallet_assets returns false
+        // if sanctioned assets found). It returns true when the provider can't screen
+        // assets (skip check / assume compliant)
         let (has_sanctioned_assets, helius_assets_checked) =
             match self.blockchain_client.check_wallet_assets(address).await {
                 Ok(is_compliant) => {
@@ -160,6 +221,7 @@ impl RiskService {
             risk_score,
             risk_level: risk_level.clone(),
             reasoning: reasoning.clone(),
+            risk_factors: risk_factors.clone(),
             has_sanctioned_assets,
             helius_assets_checked,
             created_at: now,
@@ -176,7 +238,7 @@ impl RiskService {
             use crate::infra::compliance::range::DEFAULT_RISK_THRESHOLD;
             if score >= DEFAULT_RISK_THRESHOLD
                 && let Some(blocklist) = &self.blocklist
-                && blocklist.check_address(address).is_none()
+                && blocklist.peek_address(address).is_none()
             {
                 let reason = format!(
                     "Auto-blocked: Range Protocol {} (score: {})",
@@ -188,7 +250,15 @@ impl RiskService {
                     risk_score = %score,
                     "Auto-adding high-risk address to internal blocklist"
                 );
-                if let Err(e) = blocklist.add_address(address.to_string(), reason).await {
+                if let Err(e) = blocklist
+                    .add_address(
+                        address.to_string(),
+                        reason,
+                        BlocklistCategory::RangeAutoBlock,
+                        BlocklistCategory::RangeAutoBlock.default_severity(),
+                    )
+                    .await
+                {
                     warn!(error = ?e, "Failed to add address to blocklist");
                 }
             }
@@ -200,6 +270,7 @@ impl RiskService {
             risk_score: risk_score.unwrap_or(0),
             risk_level: risk_level.unwrap_or_else(|| "Unknown".to_string()),
             reasoning: reasoning.unwrap_or_default(),
+            risk_factors,
             has_sanctioned_assets,
             helius_assets_checked,
             from_cache: false,
@@ -240,4 +311,171 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_flagged_wallet_reports_risk_factors() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/risk/address"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "riskScore": 8,
+                "riskLevel": "Extremely high risk",
+                "numHops": 1,
+                "maliciousAddressesFound": [{
+                    "address": "MixerAddr",
+                    "distance": 1,
+                    "name_tag": "Tornado Mixer",
+                    "category": "Mixer"
+                }],
+                "reasoning": "1 hop from a mixer"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let range_provider = Arc::new(RangeComplianceProvider::new(
+            Some("test_key".to_string()),
+            Some(mock_server.uri()),
+            None,
+        ));
+        let service = RiskService::new(
+            Arc::new(MockDatabaseClient::default()),
+            Arc::new(MockBlockchainClient::default()),
+            range_provider,
+            None,
+        );
+
+        let result = service
+            .check_wallet_risk("FlaggedWallet111111111111111111111111111111")
+            .await
+            .unwrap();
+
+        match result {
+            RiskCheckResult::Analyzed {
+                risk_score,
+                risk_factors,
+                ..
+            } => {
+                assert_eq!(risk_score, 8);
+                assert!(!risk_factors.is_empty(), "Flagged wallet must explain why");
+                assert_eq!(risk_factors[0], "Mixer (Tornado Mixer) at 1 hop(s)");
+            }
+            RiskCheckResult::Blocked { .. } => {
+                panic!("Expected Analyzed, got Blocked");
+            }
+        }
+    }
+
+    /// Service whose Range lookups go to `server`
+    fn service_with_range(server: &wiremock::MockServer) -> RiskService {
+        let range_provider = Arc::new(RangeComplianceProvider::new(
+            Some("test_key".to_string()),
+            Some(server.uri()),
+            None,
+        ));
+        RiskService::new(
+            Arc::new(MockDatabaseClient::default()),
+            Arc::new(MockBlockchainClient::default()),
+            range_provider,
+            None,
+        )
+    }
+
+    /// Mount a Range response for `address`, expected to be requested `calls` times
+    async fn mount_range(server: &wiremock::MockServer, address: &str, score: i32, calls: u64) {
+        use wiremock::matchers::{method, path, query_param};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("GET"))
+            .and(path("/risk/address"))
+            .and(query_param("address", address))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "riskScore": score,
+                "riskLevel": "Low risk",
+                "numHops": 3,
+                "maliciousAddressesFound": [],
+                "reasoning": "No flagged counterparties"
+            })))
+            .expect(calls)
+            .mount(server)
+            .await;
+    }
+
+    fn risk_score(result: &RiskCheckResult) -> (&str, i32) {
+        match result {
+            RiskCheckResult::Analyzed {
+                address,
+                risk_score,
+                ..
+            } => (address, *risk_score),
+            RiskCheckResult::Blocked { .. } => panic!("Expected Analyzed, got Blocked"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_check_dedups_and_preserves_order() {
+        let server = wiremock::MockServer::start().await;
+        mount_range(&server, "WalletA", 1, 1).await;
+        mount_range(&server, "WalletB", 2, 1).await;
+        mount_range(&server, "WalletC", 3, 1).await;
+        let service = service_with_range(&server).with_concurrency(2);
+
+        let addresses: Vec<String> = ["WalletB", "WalletA", "WalletB", "WalletC", "WalletA"]
+            .iter()
+            .map(|a| a.to_string())
+            .collect();
+        let results = service.check_wallets_risk(&addresses).await.unwrap();
+
+        // Repeated addresses are looked up once but answered in every position
+        let scores: Vec<(&str, i32)> = results.iter().map(risk_score).collect();
+        assert_eq!(
+            scores,
+            [
+                ("WalletB", 2),
+                ("WalletA", 1),
+                ("WalletB", 2),
+                ("WalletC", 3),
+                ("WalletA", 1)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bulk_check_reuses_cached_profiles() {
+        let server = wiremock::MockServer::start().await;
+        mount_range(&server, "WalletA", 4, 1).await;
+        let service = service_with_range(&server);
+        let addresses = vec!["WalletA".to_string()];
+
+        let first = service.check_wallets_risk(&addresses).await.unwrap();
+        let second = service.check_wallets_risk(&addresses).await.unwrap();
+
+        // The second check is served from the cache without calling Range again
+        assert_eq!(risk_score(&second[0]), ("WalletA", 4));
+        match (&first[0], &second[0]) {
+            (
+                RiskCheckResult::Analyzed {
+                    from_cache: false, ..
+                },
+                RiskCheckResult::Analyzed {
+                    from_cache: true, ..
+                },
+            ) => {}
+            other => panic!("Expected a fresh then a cached result, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_cache_queries_provider_again() {
+        let server = wiremock::MockServer::start().await;
+        mount_range(&server, "WalletA", 4, 2).await;
+        let service = service_with_range(&server).with_cache_ttl(0);
+        let addresses = vec!["WalletA".to_string()];
+
+        service.check_wallets_risk(&addresses).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        service.check_wallets_risk(&addresses).await.unwrap();
+    }
 }
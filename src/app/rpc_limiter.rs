@@ -0,0 +1,108 @@
+//! Rate limiting of the background RPC load.
+//!
+//! Draining a large backlog can make the worker burst hundreds of RPC calls
+//! and trip the provider's rate limit. [`RpcRateLimiter`] is a token bucket
+//! holding a single token: permits are handed out evenly spaced at
+//! `WORKER_MAX_RPS`, in the order they were requested. The worker takes one
+//! before each submission and the crank before each status check, so one
+//! shared limiter keeps their combined load under the cap.
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+/// Default permits per second shared by the worker and crank
+pub const DEFAULT_WORKER_MAX_RPS: u32 = 20;
+
+/// Token-bucket limiter of background RPC calls
+#[derive(Debug)]
+pub struct RpcRateLimiter {
+    /// Time between two permits
+    interval: Duration,
+    /// When the next permit becomes available
+    next_permit: Mutex<Instant>,
+}
+
+impl RpcRateLimiter {
+    /// Create a limiter handing out `max_rps` permits per second (at least one)
+    #[must_use]
+    pub fn new(max_rps: u32) -> Self {
+        Self {
+            interval: Duration::from_secs(1) / max_rps.max(1),
+            next_permit: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Wait for a permit.
+    /// Each call reserves the next free slot, so waiters are served in order.
+    pub async fn acquire(&self) {
+        let permit_at = {
+            let mut next_permit = self.next_permit.lock().unwrap_or_else(|e| e.into_inner());
+            // Unused slots are not saved up: an idle limiter grants one permit at once
+            let permit_at = (*next_permit).max(Instant::now());
+            *next_permit = permit_at + self.interval;
+            permit_at
+        };
+        tokio::time::sleep_until(permit_at).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_caps_call_rate_over_window() {
+        tokio::time::pause();
+        let limiter = Arc::new(RpcRateLimiter::new(10));
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        // Far more callers than the limit allows in the window
+        for _ in 0..50 {
+            let limiter = Arc::clone(&limiter);
+            let calls = Arc::clone(&calls);
+            tokio::spawn(async move {
+                limiter.acquire().await;
+                calls.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+
+        // One permit at once, then one every 100ms
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        tokio::time::sleep(Duration::from_millis(1_000)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 11);
+        tokio::time::sleep(Duration::from_millis(2_000)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 31);
+    }
+
+    #[tokio::test]
+    async fn test_idle_limiter_does_not_burst() {
+        tokio::time::pause();
+        let limiter = RpcRateLimiter::new(5);
+
+        // A long idle period does not build up a burst of permits
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        let start = Instant::now();
+        for _ in 0..3 {
+            limiter.acquire().await;
+        }
+        // The timer rounds each sleep up to the next millisecond
+        let elapsed = start.elapsed();
+        assert!(
+            elapsed >= Duration::from_millis(400) && elapsed < Duration::from_millis(410),
+            "{:?}",
+            elapsed
+        );
+    }
+
+    #[test]
+    fn test_zero_rps_treated_as_one() {
+        assert_eq!(RpcRateLimiter::new(0).interval, Duration::from_secs(1));
+        assert_eq!(RpcRateLimiter::new(20).interval, Duration::from_millis(50));
+    }
+}
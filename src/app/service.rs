@@ -1,25 +1,66 @@
 //! Application service layer with graceful degradation.
 
 use chrono::{DateTime, Duration, Utc};
+use futures_util::{StreamExt, stream};
+use std::borrow::Cow;
+use std::collections::HashMap;
 use std::sync::Arc;
-use tracing::{error, info, instrument, warn};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+use tokio::sync::broadcast;
+use tracing::{debug, error, info, instrument, warn};
 use validator::Validate;
 
 use crate::domain::{
     AppError, AuditAmount, AuditAssetType, AuditFinalDecision, BlockchainClient, BlockchainStatus,
-    CheckoutSession, CheckoutSessionStatus, CheckoutTransferSubmissionResponse, ComplianceStatus,
-    CreateCheckoutSessionRequest, DatabaseClient, HealthResponse, HealthStatus, HeliusTransaction,
-    InternalBlocklistHit, LastErrorType, PaginatedResponse, PrivateSubmissionAuditMetadata,
-    QuickNodeWebhookEvent, SubmitTransferRequest, TransactionStatus, TransferAuditReport,
-    TransferRequest, TransferType, ValidationError,
+    BlocklistCategory, CheckoutSession, CheckoutSessionStatus, CheckoutTransferSubmissionResponse,
+    ComplianceDecision, ComplianceDecisionFilter, ComplianceStatus, ConfirmationEvent,
+    CreateCheckoutSessionRequest, DatabaseClient, DependencyHealth, DryRunResult,
+    ExternalServiceError, FeeEstimate, FeeEstimateRequest, HealthResponse, HeliusTransaction,
+    IdempotencyRecord, InternalBlocklistHit, LastErrorType, ObservedTransfer, PaginatedResponse,
+    PrivateSubmissionAuditMetadata, QuickNodeWebhookEvent, RejectionReason, SenderLimit,
+    StatusTransition, SubmissionOutcome, SubmitTransferRequest, TransactionStatus,
+    TransferAuditReport, TransferFilter, TransferRequest, TransferStatusEvent, TransferType,
+    ValidationError, WebhookDelivery,
 };
-use crate::infra::BlocklistManager;
+use crate::infra::alerting::RejectionRateDetector;
+use crate::infra::blockchain::is_circuit_open;
+use crate::infra::metrics;
+use crate::infra::outbound_webhooks::{
+    OutboundWebhookConfig, is_notified_status, validate_callback_url,
+};
+use crate::infra::privacy::PrivacyHealthCheckService;
+use crate::infra::{AllowlistManager, BlocklistManager};
+
+use super::balance_monitor::FeePayerBalanceTracker;
+use super::correlation::current_correlation_id;
+use super::risk_service::DEFAULT_CACHE_TTL_SECS;
+use super::rpc_limiter::RpcRateLimiter;
+use super::webhook_dedup::WebhookDeduplicator;
+use super::worker::ProcessingPause;
+
+/// Default maximum number of retry attempts for blockchain submission
+pub const DEFAULT_MAX_RETRY_ATTEMPTS: i32 = 10;
+
+/// Default maximum backoff duration in seconds (5 minutes)
+pub const DEFAULT_MAX_BACKOFF_SECS: i64 = 300;
 
-/// Maximum number of retry attempts for blockchain submission
-const MAX_RETRY_ATTEMPTS: i32 = 10;
+/// Default tolerance, in seconds, for clocks disagreeing with ours when
+/// checking client timestamps and crank staleness
+pub const DEFAULT_CLOCK_SKEW_TOLERANCE_SECS: i64 = 30;
 
-/// Maximum backoff duration in seconds (5 minutes)
-const MAX_BACKOFF_SECS: i64 = 300;
+/// Largest accepted clock skew tolerance, in seconds (one day). Anything
+/// larger is a configuration mistake and would overflow deadline arithmetic.
+pub const MAX_CLOCK_SKEW_TOLERANCE_SECS: i64 = 24 * 60 * 60;
+
+/// Length of the rolling window of the per-sender transfer limit (24 hours)
+const TRANSFER_LIMIT_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+/// Status events buffered per subscriber before it starts lagging
+const STATUS_EVENT_CAPACITY: usize = 1024;
+
+/// Bulk submission items screened concurrently
+const BULK_SUBMIT_CONCURRENCY: usize = 8;
 
 /// Default checkout session lifetime: 30 minutes.
 const DEFAULT_CHECKOUT_SESSION_TTL_SECS: i64 = 30 * 60;
@@ -28,6 +69,227 @@ const DEFAULT_CHECKOUT_SESSION_TTL_SECS: i64 = 30 * 60;
 /// profiles as evidence when available.
 const AUDIT_RISK_PROFILE_MAX_AGE_SECS: i64 = 10 * 365 * 24 * 60 * 60;
 
+/// Failure reason recorded when the compliance provider rejects a transfer
+const PROVIDER_REJECTION_REASON: &str =
+    "Range Protocol: High-risk address detected (CRITICAL RISK)";
+
+/// Rejection category recorded when the compliance provider rejects a
+/// transfer without reporting a category of its own
+const PROVIDER_REJECTION_CATEGORY: &str = "provider_rejected";
+
+/// Retry policy for failed blockchain submissions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockchainRetryConfig {
+    /// Retries after which a transfer is marked failed
+    pub max_attempts: i32,
+    /// Upper bound of the delay before a retry, in seconds
+    pub max_backoff_secs: i64,
+}
+
+impl Default for BlockchainRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_RETRY_ATTEMPTS,
+            max_backoff_secs: DEFAULT_MAX_BACKOFF_SECS,
+        }
+    }
+}
+
+impl BlockchainRetryConfig {
+    /// Exponential backoff cap in seconds: `2^retry_count`, capped at
+    /// `max_backoff_secs`
+    fn backoff_cap(&self, retry_count: i32) -> i64 {
+        let backoff = 2_i64.saturating_pow(retry_count.max(0) as u32);
+        backoff.min(self.max_backoff_secs.max(0))
+    }
+
+    /// Backoff in seconds with full jitter: uniformly random between 0 and the
+    /// cap, so transfers that failed in the same outage spread their retries
+    fn backoff(&self, retry_count: i32) -> i64 {
+        rand::random_range(0..=self.backoff_cap(retry_count))
+    }
+}
+
+/// Server-side caps on native SOL transfer amounts, limiting the damage a
+/// compromised signing key can do. `None` disables a cap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferLimits {
+    /// Largest amount of a single transfer (a batch counts as its total), in lamports
+    pub max_transfer_lamports: Option<u64>,
+    /// Largest total a sender may transfer within a rolling 24 hours, in lamports
+    pub daily_limit_lamports: Option<u64>,
+}
+
+/// Order in which a transfer is screened against the internal blocklist and
+/// the compliance provider
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScreeningOrder {
+    /// Allowlist, blocklist, then the provider: a local match never costs an
+    /// external call
+    #[default]
+    BlocklistFirst,
+    /// The provider's authoritative verdict first, then the allowlist and
+    /// blocklist. The allowlist does not bypass the provider, whose rejection
+    /// always stands; a transfer it approves gets the same local decision as
+    /// in blocklist-first order.
+    ProviderFirst,
+}
+
+impl ScreeningOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::BlocklistFirst => "blocklist_first",
+            Self::ProviderFirst => "provider_first",
+        }
+    }
+}
+
+impl std::str::FromStr for ScreeningOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "blocklist_first" => Ok(Self::BlocklistFirst),
+            "provider_first" => Ok(Self::ProviderFirst),
+            _ => Err(format!("Invalid compliance check order: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for ScreeningOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Which [`ScreeningOrder`] a transfer is screened in: a default, overridden
+/// per token mint (e.g. provider first for high-value mints)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScreeningPolicy {
+    /// Order for native SOL and mints without an override
+    pub default_order: ScreeningOrder,
+    /// Token mint address -> order
+    pub mint_orders: HashMap<String, ScreeningOrder>,
+}
+
+impl ScreeningPolicy {
+    /// Order for a transfer of `token_mint` (`None` for native SOL)
+    #[must_use]
+    pub fn order_for(&self, token_mint: Option<&str>) -> ScreeningOrder {
+        token_mint
+            .and_then(|mint| self.mint_orders.get(mint))
+            .copied()
+            .unwrap_or(self.default_order)
+    }
+}
+
+/// Parse per-mint check order overrides in the form
+/// `mint1:provider_first,mint2:blocklist_first`.
+/// Whitespace around entries is ignored.
+pub fn parse_screening_order_overrides(
+    raw: &str,
+) -> Result<HashMap<String, ScreeningOrder>, String> {
+    let mut overrides = HashMap::new();
+    for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (mint, order) = entry.rsplit_once(':').ok_or_else(|| {
+            format!(
+                "Invalid check order override '{}': expected mint:order",
+                entry
+            )
+        })?;
+        let mint = mint.trim();
+        if mint.is_empty() {
+            return Err(format!(
+                "Invalid check order override '{}': missing mint",
+                entry
+            ));
+        }
+        overrides.insert(mint.to_string(), order.trim().parse()?);
+    }
+    Ok(overrides)
+}
+
+/// Compliance decision for a transfer
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Screening {
+    /// Sender and recipient are allowlisted; screening was skipped
+    Allowlisted,
+    /// Passed the blocklist and the compliance provider
+    Approved,
+    /// Sender or recipient is on the internal blocklist
+    Blocklisted {
+        address: String,
+        reason: RejectionReason,
+    },
+    /// Rejected by the compliance provider, in the risk category it reported
+    ProviderRejected { address: String, category: String },
+}
+
+/// What the crank does with a submitted transaction that is not found on-chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotFoundAction {
+    /// The transaction may still land; check again next cycle
+    Wait,
+    /// The blockhash expired; terminal, the user must re-sign
+    Expire,
+    /// The durable nonce moved on without this transaction; build a new one
+    Resubmit,
+}
+
+impl NotFoundAction {
+    fn decide(blockhash_valid: bool, durable_nonce: bool) -> Self {
+        match (blockhash_valid, durable_nonce) {
+            (true, _) => Self::Wait,
+            (false, true) => Self::Resubmit,
+            (false, false) => Self::Expire,
+        }
+    }
+}
+
+/// Time allowed for a single dependency health probe
+const HEALTH_PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+/// Run a health probe with a timeout, measuring its latency
+async fn probe_dependency(
+    name: &str,
+    check: impl Future<Output = Result<(), AppError>>,
+) -> DependencyHealth {
+    let started = Instant::now();
+    let result = tokio::time::timeout(HEALTH_PROBE_TIMEOUT, check)
+        .await
+        .unwrap_or_else(|_| {
+            Err(AppError::ExternalService(ExternalServiceError::Timeout(
+                format!("{} health probe timed out", name),
+            )))
+        });
+    if let Err(e) = &result {
+        warn!(dependency = name, error = %e, "Dependency health probe failed");
+    }
+    DependencyHealth::from_result(name, &result, started.elapsed())
+}
+
+/// Like [`probe_dependency`], for a dependency that may not be configured
+async fn probe_optional_dependency(
+    name: &str,
+    check: impl Future<Output = Option<Result<(), AppError>>>,
+) -> Option<DependencyHealth> {
+    let started = Instant::now();
+    let result = match tokio::time::timeout(HEALTH_PROBE_TIMEOUT, check).await {
+        Ok(result) => result?,
+        Err(_) => Err(AppError::ExternalService(ExternalServiceError::Timeout(
+            format!("{} health probe timed out", name),
+        ))),
+    };
+    if let Err(e) = &result {
+        warn!(dependency = name, error = %e, "Dependency health probe failed");
+    }
+    Some(DependencyHealth::from_result(
+        name,
+        &result,
+        started.elapsed(),
+    ))
+}
+
 /// Application service containing business logic
 pub struct AppService {
     db_client: Arc<dyn DatabaseClient>,
@@ -35,6 +297,37 @@ pub struct AppService {
     compliance_provider: Arc<dyn crate::domain::ComplianceProvider>,
     /// Optional internal blocklist for fast local screening
     blocklist: Option<Arc<BlocklistManager>>,
+    /// Optional allowlist of known-good addresses that bypass screening
+    allowlist: Option<Arc<AllowlistManager>>,
+    /// Recently processed webhook events, to skip re-deliveries
+    webhook_dedup: WebhookDeduplicator,
+    /// Publishes every blockchain status update to live subscribers
+    status_events: broadcast::Sender<TransferStatusEvent>,
+    /// Optional privacy service, probed by the health check
+    privacy_service: Option<Arc<PrivacyHealthCheckService>>,
+    /// Outbound webhooks; when set, notified status changes are queued for delivery
+    outbound_webhooks: Option<OutboundWebhookConfig>,
+    /// Latest fee payer balance from the balance monitor, reported by the verbose health check
+    fee_payer_balance: Option<FeePayerBalanceTracker>,
+    /// Retry limit and backoff of failed blockchain submissions
+    retry_config: BlockchainRetryConfig,
+    /// Limiter shared by worker submissions and crank status checks
+    rpc_limiter: Option<Arc<RpcRateLimiter>>,
+    /// Grace period after a client timestamp (`valid_until`, a checkout
+    /// `expires_at`) has passed before it counts as expired
+    clock_skew_tolerance: Duration,
+    /// Per-transfer and rolling per-sender amount caps
+    transfer_limits: TransferLimits,
+    /// Order of the blocklist and compliance provider checks
+    screening_policy: ScreeningPolicy,
+    /// Mint of public transfers that name none, instead of native SOL
+    default_token_mint: Option<String>,
+    /// Alerts operators when compliance rejections spike
+    rejection_alerts: Option<Arc<RejectionRateDetector>>,
+    /// Pauses the worker and crank at runtime
+    processing_pause: ProcessingPause,
+    /// When the service was created, for the reported uptime
+    started_at: Instant,
 }
 
 impl AppService {
@@ -49,6 +342,21 @@ impl AppService {
             blockchain_client,
             compliance_provider,
             blocklist: None,
+            allowlist: None,
+            webhook_dedup: WebhookDeduplicator::default(),
+            status_events: broadcast::channel(STATUS_EVENT_CAPACITY).0,
+            privacy_service: None,
+            outbound_webhooks: None,
+            fee_payer_balance: None,
+            retry_config: BlockchainRetryConfig::default(),
+            rpc_limiter: None,
+            clock_skew_tolerance: Duration::seconds(DEFAULT_CLOCK_SKEW_TOLERANCE_SECS),
+            transfer_limits: TransferLimits::default(),
+            screening_policy: ScreeningPolicy::default(),
+            default_token_mint: None,
+            rejection_alerts: None,
+            processing_pause: ProcessingPause::default(),
+            started_at: Instant::now(),
         }
     }
 
@@ -65,7 +373,333 @@ impl AppService {
             blockchain_client,
             compliance_provider,
             blocklist: Some(blocklist),
+            allowlist: None,
+            webhook_dedup: WebhookDeduplicator::default(),
+            status_events: broadcast::channel(STATUS_EVENT_CAPACITY).0,
+            privacy_service: None,
+            outbound_webhooks: None,
+            fee_payer_balance: None,
+            retry_config: BlockchainRetryConfig::default(),
+            rpc_limiter: None,
+            clock_skew_tolerance: Duration::seconds(DEFAULT_CLOCK_SKEW_TOLERANCE_SECS),
+            transfer_limits: TransferLimits::default(),
+            screening_policy: ScreeningPolicy::default(),
+            default_token_mint: None,
+            rejection_alerts: None,
+            processing_pause: ProcessingPause::default(),
+            started_at: Instant::now(),
+        }
+    }
+
+    /// Attach an allowlist of known-good addresses (builder pattern)
+    #[must_use]
+    pub fn with_allowlist(mut self, allowlist: Arc<AllowlistManager>) -> Self {
+        self.allowlist = Some(allowlist);
+        self
+    }
+
+    /// Attach the privacy service so the health check probes it (builder pattern)
+    #[must_use]
+    pub fn with_privacy_service(mut self, privacy_service: Arc<PrivacyHealthCheckService>) -> Self {
+        self.privacy_service = Some(privacy_service);
+        self
+    }
+
+    /// Queue outbound webhooks for notified status changes (builder pattern)
+    #[must_use]
+    pub fn with_outbound_webhooks(mut self, config: OutboundWebhookConfig) -> Self {
+        self.outbound_webhooks = Some(config);
+        self
+    }
+
+    /// Report the balance monitor's latest reading in the verbose health check (builder pattern)
+    #[must_use]
+    pub fn with_fee_payer_balance(mut self, tracker: FeePayerBalanceTracker) -> Self {
+        self.fee_payer_balance = Some(tracker);
+        self
+    }
+
+    /// Set the retry limit and backoff of failed blockchain submissions (builder pattern)
+    #[must_use]
+    pub fn with_retry_config(mut self, retry_config: BlockchainRetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// Set the tolerance for clocks disagreeing with ours (builder pattern)
+    #[must_use]
+    pub fn with_clock_skew_tolerance(mut self, skew: Duration) -> Self {
+        self.clock_skew_tolerance = skew;
+        self
+    }
+
+    /// Rate-limit worker submissions and crank status checks (builder pattern)
+    #[must_use]
+    pub fn with_rpc_limiter(mut self, limiter: Arc<RpcRateLimiter>) -> Self {
+        self.rpc_limiter = Some(limiter);
+        self
+    }
+
+    /// Cap single transfers and each sender's rolling daily total (builder pattern)
+    #[must_use]
+    pub fn with_transfer_limits(mut self, limits: TransferLimits) -> Self {
+        self.transfer_limits = limits;
+        self
+    }
+
+    /// Set the order of the blocklist and compliance provider checks (builder pattern)
+    #[must_use]
+    pub fn with_screening_policy(mut self, policy: ScreeningPolicy) -> Self {
+        self.screening_policy = policy;
+        self
+    }
+
+    /// Send public transfers that omit `token_mint` as this token instead of SOL (builder pattern)
+    #[must_use]
+    pub fn with_default_token_mint(mut self, mint: String) -> Self {
+        self.default_token_mint = Some(mint);
+        self
+    }
+
+    /// Count compliance rejections towards the rejection-spike alert (builder pattern)
+    #[must_use]
+    pub fn with_rejection_alerts(mut self, detector: Arc<RejectionRateDetector>) -> Self {
+        self.rejection_alerts = Some(detector);
+        self
+    }
+
+    /// Share the worker and crank pause switch (builder pattern)
+    #[must_use]
+    pub fn with_processing_pause(mut self, pause: ProcessingPause) -> Self {
+        self.processing_pause = pause;
+        self
+    }
+
+    /// Switch pausing the worker and crank
+    pub fn processing_pause(&self) -> &ProcessingPause {
+        &self.processing_pause
+    }
+
+    /// Tolerance for clocks disagreeing with ours
+    pub fn clock_skew_tolerance(&self) -> Duration {
+        self.clock_skew_tolerance
+    }
+
+    /// Whether `at` has passed, allowing for the clock skew tolerance
+    fn is_past(&self, at: DateTime<Utc>) -> bool {
+        Utc::now() > at + self.clock_skew_tolerance
+    }
+
+    /// Wait for a permit of the RPC limiter, if one is configured
+    async fn acquire_rpc_permit(&self) {
+        if let Some(limiter) = &self.rpc_limiter {
+            limiter.acquire().await;
+        }
+    }
+
+    /// Pin the transfer to an issuer key before it is first submitted, so
+    /// every attempt, including retries, is signed by the same key.
+    async fn pin_signer(&self, mut request: TransferRequest) -> Result<TransferRequest, AppError> {
+        if request.signer_pubkey.is_some() {
+            return Ok(request);
+        }
+        if let Some(signer) = self.blockchain_client.select_signer(&request.id) {
+            let pinned = self
+                .db_client
+                .pin_transfer_signer(&request.id, &signer)
+                .await?;
+            debug!(transfer_id = %request.id, signer = %pinned, "Pinned transfer signing key");
+            request.signer_pubkey = Some(pinned);
+        }
+        Ok(request)
+    }
+
+    /// Subscribe to blockchain status updates of all transfers
+    pub fn subscribe_status_events(&self) -> broadcast::Receiver<TransferStatusEvent> {
+        self.status_events.subscribe()
+    }
+
+    /// Persist a blockchain status update and publish it to status subscribers
+    async fn set_blockchain_status(
+        &self,
+        id: &str,
+        status: BlockchainStatus,
+        signature: Option<&str>,
+        error: Option<&str>,
+        next_retry_at: Option<DateTime<Utc>>,
+        blockhash_used: Option<&str>,
+    ) -> Result<(), AppError> {
+        self.db_client
+            .update_blockchain_status(id, status, signature, error, next_retry_at, blockhash_used)
+            .await?;
+        self.publish_blockchain_status(id, status, signature, error)
+            .await;
+        Ok(())
+    }
+
+    /// Record a successful submission and publish its status like
+    /// [`Self::set_blockchain_status`]. The signature, bundle ID and proof
+    /// transactions are written in the same update.
+    async fn set_submitted(
+        &self,
+        id: &str,
+        outcome: &SubmissionOutcome,
+        blockhash_used: &str,
+    ) -> Result<(), AppError> {
+        self.db_client
+            .record_submission(id, outcome, Some(blockhash_used))
+            .await?;
+        self.publish_blockchain_status(
+            id,
+            outcome.submitted_status(),
+            Some(&outcome.signature),
+            None,
+        )
+        .await;
+        Ok(())
+    }
+
+    /// Metrics, outbound webhooks and status events of a stored status change
+    async fn publish_blockchain_status(
+        &self,
+        id: &str,
+        status: BlockchainStatus,
+        signature: Option<&str>,
+        error: Option<&str>,
+    ) {
+        metrics::record_transfer_status(status);
+
+        let event = TransferStatusEvent {
+            id: id.to_string(),
+            blockchain_status: status,
+            blockchain_signature: signature.map(ToString::to_string),
+            blockchain_last_error: error.map(ToString::to_string),
+            updated_at: Utc::now(),
+        };
+        if is_notified_status(status)
+            && let Some(config) = &self.outbound_webhooks
+        {
+            self.queue_outbound_webhook(config, event.clone()).await;
+        }
+
+        if status == BlockchainStatus::Confirmed
+            && self.blockchain_client.closes_empty_token_accounts()
+        {
+            self.spawn_token_account_close(id);
+        }
+
+        // Sending only fails when nobody is subscribed
+        let _ = self.status_events.send(event);
+    }
+
+    /// Close the relayer's token account if a confirmed public token transfer
+    /// left it empty. Runs in the background so the status update is not
+    /// held up by the close transaction; failures are only logged.
+    fn spawn_token_account_close(&self, id: &str) {
+        let db_client = Arc::clone(&self.db_client);
+        let blockchain_client = Arc::clone(&self.blockchain_client);
+        let id = id.to_string();
+
+        tokio::spawn(async move {
+            let transfer = match db_client.get_transfer_request(&id).await {
+                Ok(Some(transfer)) => transfer,
+                Ok(None) => return,
+                Err(e) => {
+                    warn!(transfer_id = %id, error = %e, "Failed to load transfer for token account close");
+                    return;
+                }
+            };
+            // Confidential balances can't be checked for emptiness here
+            let (Some(token_mint), TransferType::Public { .. } | TransferType::PublicBatch { .. }) =
+                (transfer.token_mint.as_deref(), &transfer.transfer_details)
+            else {
+                return;
+            };
+
+            // The account belongs to the key that signed the transfer
+            match blockchain_client
+                .close_empty_token_account(token_mint, transfer.signer_pubkey.as_deref())
+                .await
+            {
+                Ok(Some(signature)) => info!(
+                    transfer_id = %id,
+                    token_mint = %token_mint,
+                    signature = %signature,
+                    "Closed empty relayer token account and reclaimed rent"
+                ),
+                Ok(None) => {}
+                Err(e) => warn!(
+                    transfer_id = %id,
+                    token_mint = %token_mint,
+                    error = %e,
+                    "Failed to close empty relayer token account"
+                ),
+            }
+        });
+    }
+
+    /// Queue an outbound webhook for `event` to the transfer's callback URL,
+    /// or the global one. Failures are logged, not returned: the status
+    /// update itself has already been persisted.
+    async fn queue_outbound_webhook(
+        &self,
+        config: &OutboundWebhookConfig,
+        event: TransferStatusEvent,
+    ) {
+        let callback_url = match self.db_client.get_transfer_request(&event.id).await {
+            Ok(transfer) => transfer.and_then(|t| t.callback_url),
+            Err(e) => {
+                warn!(transfer_id = %event.id, error = %e, "Failed to load transfer for outbound webhook");
+                return;
+            }
+        };
+        let Some(url) = callback_url.or_else(|| config.default_url.clone()) else {
+            return;
+        };
+
+        let delivery = WebhookDelivery::new(url, event);
+        match self.db_client.enqueue_webhook_delivery(&delivery).await {
+            Ok(true) => debug!(
+                transfer_id = %delivery.transfer_id,
+                delivery_id = %delivery.id,
+                status = %delivery.event.blockchain_status,
+                "Queued outbound webhook"
+            ),
+            Ok(false) => {}
+            Err(e) => warn!(
+                transfer_id = %delivery.transfer_id,
+                error = %e,
+                "Failed to queue outbound webhook"
+            ),
+        }
+    }
+
+    /// Whether both parties of a transfer are allowlisted and may skip screening.
+    /// An address that is also blocklisted is never treated as allowlisted.
+    fn is_allowlisted_transfer(&self, request: &SubmitTransferRequest) -> bool {
+        let Some(allowlist) = &self.allowlist else {
+            return false;
+        };
+        if !allowlist.is_allowed(&request.from_address)
+            || !allowlist.is_allowed(&request.to_address)
+        {
+            return false;
+        }
+
+        if let Some(blocklist) = &self.blocklist {
+            for address in [&request.from_address, &request.to_address] {
+                if let Some(reason) = blocklist.check_address(address) {
+                    error!(
+                        address = %address,
+                        reason = %reason,
+                        "Address is on both the allowlist and the blocklist - blocklist wins"
+                    );
+                    return false;
+                }
+            }
         }
+
+        true
     }
 
     // =========================================================================
@@ -91,6 +725,25 @@ impl AppService {
         self.db_client.find_by_nonce(from_address, nonce).await
     }
 
+    /// Look up the recorded response for an `Idempotency-Key` under `scope`
+    #[instrument(skip(self, scope))]
+    pub async fn find_idempotency_record(
+        &self,
+        scope: &str,
+        key: &str,
+    ) -> Result<Option<IdempotencyRecord>, AppError> {
+        self.db_client.find_idempotency_record(scope, key).await
+    }
+
+    /// Record the response for an `Idempotency-Key`
+    #[instrument(skip(self, record), fields(key = %record.key))]
+    pub async fn save_idempotency_record(
+        &self,
+        record: &IdempotencyRecord,
+    ) -> Result<(), AppError> {
+        self.db_client.save_idempotency_record(record).await
+    }
+
     /// Submit a new transfer request for background processing.
     /// Implements the **Receive → Persist → Process** pattern for 100% auditability.
     ///
@@ -115,17 +768,8 @@ impl AppService {
         // =====================================================================
         // STEP 1: Validation (before any persistence)
         // =====================================================================
-        request.validate().map_err(|e| {
-            warn!(error = %e, "Validation failed");
-            AppError::Validation(ValidationError::Multiple(e.to_string()))
-        })?;
-
-        // Cryptographic signature verification (includes nonce in message)
-        // Format: "{from}:{to}:{amount|confidential}:{mint|SOL}:{nonce}"
-        request.verify_signature().map_err(|e| {
-            warn!(from = %request.from_address, nonce = %request.nonce, error = %e, "Signature verification failed");
-            e
-        })?;
+        let normalized = self.validate_submission(request)?;
+        let request = normalized.as_ref();
 
         // Check for existing request with same nonce (idempotency)
         if let Some(existing) = self
@@ -140,98 +784,107 @@ impl AppService {
             return Ok(existing);
         }
 
+        // Amount caps are checked before anything is queued; the daily cap
+        // is checked atomically with the insert
+        let sender_limit = self.check_transfer_limits(request)?;
+
         // =====================================================================
         // STEP 2: PERSIST IMMEDIATELY (Audit Trail - before compliance check!)
         // =====================================================================
         // This ensures 100% auditability: if the service crashes during compliance
         // check, the record still exists in the database with status `Received`.
         info!("Persisting transfer request with status 'received'");
-        let mut transfer_request = self.db_client.submit_transfer(request).await?;
+        let correlation_id = current_correlation_id();
+        let mut transfer_request = match sender_limit {
+            Some(limit) => self
+                .db_client
+                .submit_transfer_within_limit(request, correlation_id.as_deref(), limit)
+                .await
+                .inspect_err(|e| {
+                    if matches!(
+                        e,
+                        AppError::Validation(ValidationError::TransferLimitExceeded(_))
+                    ) {
+                        warn!(
+                            amount = limit.amount_lamports,
+                            limit = limit.limit_lamports,
+                            "Transfer exceeds the sender's daily limit"
+                        );
+                    }
+                })?,
+            None => {
+                self.db_client
+                    .submit_transfer_with_correlation(request, correlation_id.as_deref())
+                    .await?
+            }
+        };
         let request_id = transfer_request.id.clone();
 
         // =====================================================================
-        // STEP 3: Compliance Checks (blocklist + Range Protocol)
+        // STEP 3: Compliance Checks (allowlist + blocklist + Range Protocol)
         // =====================================================================
-
-        // Internal blocklist check (fast O(1) lookup)
-        if let Some(ref blocklist) = self.blocklist {
-            // Check recipient
-            if let Some(reason) = blocklist.check_address(&request.to_address) {
-                warn!(
-                    address = %request.to_address,
-                    reason = %reason,
-                    "Transfer blocked: recipient in internal blocklist"
-                );
-                return self
-                    .reject_transfer(&request_id, &format!("Blocklist: {}", reason))
-                    .await;
-            }
-
-            // Check sender
-            if let Some(reason) = blocklist.check_address(&request.from_address) {
-                warn!(
-                    address = %request.from_address,
-                    reason = %reason,
-                    "Transfer blocked: sender in internal blocklist"
-                );
-                return self
-                    .reject_transfer(&request_id, &format!("Blocklist: {}", reason))
-                    .await;
+        let screening = self.screen_transfer(request).await?;
+        self.record_compliance_decision(&request_id, request, &screening)
+            .await;
+        match screening {
+            Screening::Allowlisted | Screening::Approved => {}
+            Screening::Blocklisted { reason, .. } => {
+                return self.reject_transfer(&request_id, &reason).await;
             }
-        }
-
-        // External compliance check (Range Protocol - slower, external API)
-        let compliance_status = self.compliance_provider.check_compliance(request).await?;
-
-        if compliance_status == crate::domain::ComplianceStatus::Rejected {
-            warn!(from = %request.from_address, to = %request.to_address, "Transfer rejected by compliance provider");
-
-            let rejection_reason = "Range Protocol: High-risk address detected (CRITICAL RISK)";
+            Screening::ProviderRejected { address, category } => {
+                let risk_score = self.cached_risk_score(&address).await;
+                // Auto-add to internal blocklist to avoid future API calls
+                if let Some(ref blocklist) = self.blocklist
+                    && blocklist.peek_address(&address).is_none()
+                {
+                    info!(
+                        address = %address,
+                        "Auto-adding high-risk address to internal blocklist"
+                    );
+                    let _ = blocklist
+                        .add_address(
+                            address,
+                            "Auto-blocked: Range Protocol CRITICAL RISK".to_string(),
+                            BlocklistCategory::RangeAutoBlock,
+                            BlocklistCategory::RangeAutoBlock.default_severity(),
+                        )
+                        .await;
+                }
 
-            // Auto-add to internal blocklist to avoid future API calls
-            if let Some(ref blocklist) = self.blocklist
-                && blocklist.check_address(&request.to_address).is_none()
-            {
-                info!(
-                    address = %request.to_address,
-                    "Auto-adding high-risk address to internal blocklist"
-                );
-                let _ = blocklist
-                    .add_address(
-                        request.to_address.clone(),
-                        "Auto-blocked: Range Protocol CRITICAL RISK".to_string(),
-                    )
-                    .await;
+                let reason = RejectionReason::provider(
+                    self.compliance_provider.name(),
+                    &category,
+                    PROVIDER_REJECTION_REASON,
+                )
+                .with_risk_score(risk_score);
+                return self.reject_transfer(&request_id, &reason).await;
             }
-
-            return self.reject_transfer(&request_id, rejection_reason).await;
         }
 
         // =====================================================================
         // STEP 4: Approve and Queue for Background Processing
         // =====================================================================
-        info!(id = %request_id, "Updating compliance_status to 'approved'");
+        info!(transfer_id = %request_id, "Updating compliance_status to 'approved'");
         self.db_client
             .update_compliance_status(&request_id, ComplianceStatus::Approved)
             .await?;
         transfer_request.compliance_status = ComplianceStatus::Approved;
 
         // Queue for background worker (Outbox Pattern: no blockchain call here!)
-        info!(id = %request_id, "Updating blockchain_status to 'pending_submission'");
-        self.db_client
-            .update_blockchain_status(
-                &request_id,
-                BlockchainStatus::PendingSubmission,
-                None,
-                None,
-                None,
-                None,
-            )
-            .await?;
+        info!(transfer_id = %request_id, "Updating blockchain_status to 'pending_submission'");
+        self.set_blockchain_status(
+            &request_id,
+            BlockchainStatus::PendingSubmission,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await?;
         transfer_request.blockchain_status = BlockchainStatus::PendingSubmission;
 
         info!(
-            id = %transfer_request.id,
+            transfer_id = %transfer_request.id,
             compliance_status = %transfer_request.compliance_status.as_str(),
             blockchain_status = %transfer_request.blockchain_status.as_str(),
             "Transfer approved and queued for background processing (worker should pick up within 10s)"
@@ -240,25 +893,398 @@ impl AppService {
         Ok(transfer_request)
     }
 
-    /// Internal helper to reject a transfer request (used after persist)
-    async fn reject_transfer(&self, id: &str, reason: &str) -> Result<TransferRequest, AppError> {
-        self.db_client
-            .update_compliance_status(id, ComplianceStatus::Rejected)
-            .await?;
-        self.db_client
-            .update_blockchain_status(id, BlockchainStatus::Failed, None, Some(reason), None, None)
-            .await?;
-
-        // Fetch and return the updated request
-        self.db_client
-            .get_transfer_request(id)
-            .await?
-            .ok_or_else(|| {
-                AppError::Database(crate::domain::DatabaseError::NotFound(id.to_string()))
-            })
+    /// Run a transfer through validation, signature verification and the
+    /// compliance checks without acting on it.
+    ///
+    /// Nothing is persisted, queued or sent to the blockchain, and a
+    /// compliance rejection does not auto-block the recipient. The nonce is
+    /// not checked for reuse, so a dry run can precede the real submission
+    /// with the same signed request.
+    #[instrument(skip(self, request), fields(from = %request.from_address, to = %request.to_address, nonce = %request.nonce))]
+    pub async fn dry_run_transfer(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<DryRunResult, AppError> {
+        let normalized = self.validate_submission(request)?;
+        let request = normalized.as_ref();
+
+        let result = match self.screen_transfer(request).await? {
+            Screening::Allowlisted | Screening::Approved => DryRunResult::approved(),
+            Screening::Blocklisted { reason, .. } => DryRunResult::rejected(reason.message),
+            Screening::ProviderRejected { .. } => DryRunResult::rejected(PROVIDER_REJECTION_REASON),
+        };
+        info!(
+            compliance_status = %result.compliance_status.as_str(),
+            "Dry run completed"
+        );
+        Ok(result)
     }
 
-    /// Get a transfer request by ID
+    /// Estimate the network fee of a transfer without building a signed
+    /// transaction or submitting anything
+    #[instrument(skip(self, request), fields(to = %request.to_address, token_mint = ?request.token_mint))]
+    pub async fn estimate_fee(
+        &self,
+        request: &FeeEstimateRequest,
+    ) -> Result<FeeEstimate, AppError> {
+        request
+            .validate()
+            .map_err(|e| AppError::Validation(ValidationError::Multiple(e.to_string())))?;
+
+        // Estimated for the mint a submission without one would send
+        let token_mint = request
+            .token_mint
+            .as_deref()
+            .or(self.default_token_mint.as_deref());
+        self.blockchain_client
+            .estimate_fee(
+                &request.to_address,
+                request.amount,
+                token_mint,
+                request.memo.as_deref(),
+            )
+            .await
+    }
+
+    /// Validate the request fields, verify its signature and check that it
+    /// has not expired. Returns the request with the default token mint
+    /// filled in and `amount_sol` converted to lamports, which is what the
+    /// client signs.
+    fn validate_submission<'a>(
+        &self,
+        request: &'a SubmitTransferRequest,
+    ) -> Result<Cow<'a, SubmitTransferRequest>, AppError> {
+        request.validate().map_err(|e| {
+            warn!(error = %e, "Validation failed");
+            AppError::from(e)
+        })?;
+        self.validate_callback_url(request)?;
+        // Only requests without `amount_sol` take the default mint, so a
+        // resolved request has nothing left to convert
+        let request = match request.resolve_token_mint(self.default_token_mint.as_deref()) {
+            Cow::Borrowed(request) => request.normalize_amount()?,
+            resolved => resolved,
+        };
+
+        // Cryptographic signature verification (includes nonce in message)
+        // Format: "{from}:{to}:{amount|confidential}:{mint|SOL}:{nonce}"
+        request.verify_signature().map_err(|e| {
+            warn!(from = %request.from_address, nonce = %request.nonce, error = %e, "Signature verification failed");
+            e
+        })?;
+
+        // Checked after the signature, so the expiry itself is authentic
+        if let Some(valid_until) = request.valid_until
+            && self.is_past(valid_until)
+        {
+            warn!(nonce = %request.nonce, valid_until = %valid_until, "Request expired");
+            return Err(AppError::Validation(ValidationError::InvalidField {
+                field: "valid_until".to_string(),
+                message: format!("Request expired at {}", valid_until.to_rfc3339()),
+            }));
+        }
+        Ok(request)
+    }
+
+    /// Refuse a callback URL on a loopback, private or link-local host, so
+    /// submitters cannot make the relayer POST to internal services
+    fn validate_callback_url(&self, request: &SubmitTransferRequest) -> Result<(), AppError> {
+        let allow_private = self
+            .outbound_webhooks
+            .as_ref()
+            .is_some_and(|config| config.allow_private_callback_urls);
+        match &request.callback_url {
+            Some(url) if !allow_private => validate_callback_url(url).map_err(|message| {
+                warn!(callback_url = %url, error = %message, "Callback URL refused");
+                AppError::Validation(ValidationError::InvalidField {
+                    field: "callback_url".to_string(),
+                    message,
+                })
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Enforce the per-transfer cap on native SOL transfers and return the
+    /// rolling per-sender cap the transfer must be persisted under, if any.
+    /// Token and confidential transfers are not capped: their amounts are
+    /// not in lamports, or not visible at all.
+    fn check_transfer_limits(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<Option<SenderLimit>, AppError> {
+        let TransferLimits {
+            max_transfer_lamports,
+            daily_limit_lamports,
+        } = self.transfer_limits;
+        if request.token_mint.is_some() {
+            return Ok(None);
+        }
+        let amount = match &request.transfer_details {
+            TransferType::Public { amount } => *amount,
+            TransferType::PublicBatch { .. } => request.batch_total().unwrap_or(u64::MAX),
+            TransferType::Confidential { .. } => return Ok(None),
+        };
+
+        if let Some(max) = max_transfer_lamports
+            && amount > max
+        {
+            warn!(amount, max, "Transfer exceeds the per-transfer limit");
+            return Err(AppError::Validation(
+                ValidationError::TransferLimitExceeded(format!(
+                    "Transfer of {} lamports exceeds the per-transfer maximum of {} lamports",
+                    amount, max
+                )),
+            ));
+        }
+
+        Ok(daily_limit_lamports.map(|limit| SenderLimit {
+            amount_lamports: amount,
+            limit_lamports: limit,
+            since: Utc::now() - Duration::seconds(TRANSFER_LIMIT_WINDOW_SECS),
+        }))
+    }
+
+    /// Screen a transfer against the allowlist, the internal blocklist and
+    /// the compliance provider.
+    ///
+    /// Every recipient of a batch transfer is screened as its own transfer;
+    /// the batch is rejected with the first recipient that fails.
+    async fn screen_transfer(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<Screening, AppError> {
+        if !matches!(request.transfer_details, TransferType::PublicBatch { .. }) {
+            return self.screen_single_transfer(request).await;
+        }
+
+        let mut screening = Screening::Allowlisted;
+        for sub_transfer in request.batch_sub_transfers() {
+            match self.screen_single_transfer(&sub_transfer).await? {
+                Screening::Allowlisted => {}
+                Screening::Approved => screening = Screening::Approved,
+                rejected => return Ok(rejected),
+            }
+        }
+        Ok(screening)
+    }
+
+    /// Screen a single-recipient transfer in the order its mint's policy sets
+    async fn screen_single_transfer(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<Screening, AppError> {
+        let order = self
+            .screening_policy
+            .order_for(request.token_mint.as_deref());
+        if order == ScreeningOrder::ProviderFirst {
+            // A provider rejection short-circuits, even for allowlisted parties
+            let verdict = self.check_compliance_provider(request).await?;
+            if verdict != Screening::Approved {
+                return Ok(verdict);
+            }
+            return Ok(self.check_local_lists(request).unwrap_or(verdict));
+        }
+
+        if let Some(screening) = self.check_local_lists(request) {
+            return Ok(screening);
+        }
+        self.check_compliance_provider(request).await
+    }
+
+    /// Allowlist, then blocklist: known-good counterparties are approved
+    /// unless also blocklisted. `None` when neither list decides.
+    fn check_local_lists(&self, request: &SubmitTransferRequest) -> Option<Screening> {
+        if self.is_allowlisted_transfer(request) {
+            info!("Sender and recipient allowlisted");
+            return Some(Screening::Allowlisted);
+        }
+        self.check_blocklist(request)
+    }
+
+    /// Internal blocklist check (fast O(1) lookup) of the recipient, then the sender
+    fn check_blocklist(&self, request: &SubmitTransferRequest) -> Option<Screening> {
+        let blocklist = self.blocklist.as_ref()?;
+
+        // Check recipient
+        if let Some(entry) = blocklist.check_address_detailed(&request.to_address) {
+            warn!(
+                address = %request.to_address,
+                reason = %entry.reason,
+                category = %entry.category,
+                severity = entry.severity,
+                "Transfer blocked: recipient in internal blocklist"
+            );
+            metrics::record_blocklist_hit("recipient");
+            return Some(Screening::Blocklisted {
+                address: request.to_address.clone(),
+                reason: RejectionReason::blocklist(entry.category, &entry.reason),
+            });
+        }
+
+        // Check sender
+        if let Some(entry) = blocklist.check_address_detailed(&request.from_address) {
+            warn!(
+                address = %request.from_address,
+                reason = %entry.reason,
+                category = %entry.category,
+                severity = entry.severity,
+                "Transfer blocked: sender in internal blocklist"
+            );
+            metrics::record_blocklist_hit("sender");
+            return Some(Screening::Blocklisted {
+                address: request.from_address.clone(),
+                reason: RejectionReason::blocklist(entry.category, &entry.reason),
+            });
+        }
+        None
+    }
+
+    /// External compliance check (Range Protocol - slower, external API)
+    async fn check_compliance_provider(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<Screening, AppError> {
+        let started = std::time::Instant::now();
+        let result = self.compliance_provider.screen(request).await;
+        metrics::record_compliance_check(self.compliance_provider.name(), started.elapsed());
+
+        let verdict = result?;
+        if verdict.status == ComplianceStatus::Rejected {
+            let category = verdict
+                .category
+                .unwrap_or_else(|| PROVIDER_REJECTION_CATEGORY.to_string());
+            warn!(from = %request.from_address, to = %request.to_address, category = %category, "Transfer rejected by compliance provider");
+            return Ok(Screening::ProviderRejected {
+                address: request.to_address.clone(),
+                category,
+            });
+        }
+        Ok(Screening::Approved)
+    }
+
+    /// Append the outcome of screening a transfer to the compliance decision
+    /// audit trail. A failed write is logged and never fails the submission.
+    async fn record_compliance_decision(
+        &self,
+        transfer_id: &str,
+        request: &SubmitTransferRequest,
+        screening: &Screening,
+    ) {
+        let decision = match screening {
+            Screening::Allowlisted => ComplianceDecision::new(
+                transfer_id,
+                &request.to_address,
+                ComplianceStatus::Approved,
+                "allowlist",
+            )
+            .with_reason("Sender and recipient allowlisted"),
+            Screening::Blocklisted { address, reason } => ComplianceDecision::new(
+                transfer_id,
+                address,
+                ComplianceStatus::Rejected,
+                "blocklist",
+            )
+            .with_reason(reason.message.clone()),
+            Screening::Approved | Screening::ProviderRejected { .. } => {
+                let (status, address) = match screening {
+                    Screening::ProviderRejected { address, .. } => {
+                        (ComplianceStatus::Rejected, address.as_str())
+                    }
+                    _ => (ComplianceStatus::Approved, request.to_address.as_str()),
+                };
+                let risk_score = self.cached_risk_score(address).await;
+                let decision = ComplianceDecision::new(
+                    transfer_id,
+                    address,
+                    status,
+                    self.compliance_provider.name(),
+                )
+                .with_risk_score(risk_score);
+                if status == ComplianceStatus::Rejected {
+                    decision.with_reason(PROVIDER_REJECTION_REASON)
+                } else {
+                    decision
+                }
+            }
+        };
+
+        if let Err(e) = self.db_client.record_compliance_decision(&decision).await {
+            warn!(
+                transfer_id = %transfer_id,
+                error = %e,
+                "Failed to record compliance decision"
+            );
+        }
+    }
+
+    /// Risk score of `address` for the decision trail. The provider only
+    /// returns a verdict; the score comes from a risk profile fresh enough to
+    /// be served by the risk check API.
+    async fn cached_risk_score(&self, address: &str) -> Option<i32> {
+        self.db_client
+            .get_risk_profile(address, DEFAULT_CACHE_TTL_SECS)
+            .await
+            .ok()
+            .flatten()
+            .and_then(|profile| profile.risk_score)
+    }
+
+    /// Submit several transfers, each through [`Self::submit_transfer`].
+    ///
+    /// Items succeed or fail independently; up to `BULK_SUBMIT_CONCURRENCY`
+    /// are in flight at once. Results are returned in request order.
+    #[instrument(skip(self, requests), fields(count = requests.len()))]
+    pub async fn submit_transfers_bulk(
+        &self,
+        requests: &[SubmitTransferRequest],
+    ) -> Vec<Result<TransferRequest, AppError>> {
+        // Owned requests keep the futures free of a borrow of the slice,
+        // which the handler's future could not otherwise prove `Send`
+        stream::iter(requests.iter().cloned())
+            .map(|request| async move { self.submit_transfer(&request).await })
+            .buffered(BULK_SUBMIT_CONCURRENCY)
+            .collect()
+            .await
+    }
+
+    /// Internal helper to reject a transfer request (used after persist).
+    /// The structured reason is stored first so the compliance transition
+    /// carries it; its message becomes the displayed error.
+    async fn reject_transfer(
+        &self,
+        id: &str,
+        reason: &RejectionReason,
+    ) -> Result<TransferRequest, AppError> {
+        self.db_client
+            .update_rejection_reason(id, Some(reason))
+            .await?;
+        self.db_client
+            .update_compliance_status(id, ComplianceStatus::Rejected)
+            .await?;
+        self.set_blockchain_status(
+            id,
+            BlockchainStatus::Rejected,
+            None,
+            Some(&reason.message),
+            None,
+            None,
+        )
+        .await?;
+        if let Some(detector) = &self.rejection_alerts {
+            detector.record_rejection();
+        }
+
+        // Fetch and return the updated request
+        self.db_client
+            .get_transfer_request(id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Database(crate::domain::DatabaseError::NotFound(id.to_string()))
+            })
+    }
+
+    /// Get a transfer request by ID
     #[instrument(skip(self))]
     pub async fn get_transfer_request(
         &self,
@@ -267,14 +1293,65 @@ impl AppService {
         self.db_client.get_transfer_request(id).await
     }
 
-    /// List transfer requests with pagination
+    /// List transfer requests matching `filter` with pagination
     #[instrument(skip(self))]
     pub async fn list_transfer_requests(
         &self,
+        filter: &TransferFilter,
         limit: i64,
         cursor: Option<&str>,
     ) -> Result<PaginatedResponse<TransferRequest>, AppError> {
-        self.db_client.list_transfer_requests(limit, cursor).await
+        self.db_client
+            .list_transfer_requests_filtered(filter, limit, cursor)
+            .await
+    }
+
+    /// List compliance decisions, newest first
+    #[instrument(skip(self, filter))]
+    pub async fn list_compliance_decisions(
+        &self,
+        filter: &ComplianceDecisionFilter,
+        limit: i64,
+        cursor: Option<&str>,
+    ) -> Result<PaginatedResponse<ComplianceDecision>, AppError> {
+        self.db_client
+            .list_compliance_decisions(filter, limit, cursor)
+            .await
+    }
+
+    /// Cancel a transfer that has not been picked up for submission yet.
+    /// Fails with [`AppError::Conflict`] once the worker has claimed the request.
+    #[instrument(skip(self))]
+    pub async fn cancel_transfer(&self, id: &str) -> Result<TransferRequest, AppError> {
+        let cancelled = self.db_client.cancel_transfer_request(id).await?;
+
+        let transfer_request = self
+            .db_client
+            .get_transfer_request(id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Database(crate::domain::DatabaseError::NotFound(id.to_string()))
+            })?;
+
+        if !cancelled {
+            warn!(
+                transfer_id = %id,
+                status = %transfer_request.blockchain_status,
+                "Cancel rejected: transfer already picked up for submission"
+            );
+            return Err(AppError::Conflict(format!(
+                "Transfer {} cannot be cancelled in status '{}'",
+                id, transfer_request.blockchain_status
+            )));
+        }
+
+        info!(transfer_id = %id, "Transfer cancelled");
+        metrics::record_transfer_status(BlockchainStatus::Cancelled);
+        // Sending only fails when nobody is subscribed
+        let _ = self
+            .status_events
+            .send(TransferStatusEvent::from(&transfer_request));
+        Ok(transfer_request)
     }
 
     // =========================================================================
@@ -293,7 +1370,7 @@ impl AppService {
             .expires_at
             .unwrap_or_else(|| Utc::now() + Duration::seconds(DEFAULT_CHECKOUT_SESSION_TTL_SECS));
 
-        if expires_at <= Utc::now() {
+        if self.is_past(expires_at) {
             return Err(AppError::Validation(ValidationError::InvalidField {
                 field: "expires_at".to_string(),
                 message: "Expiration must be in the future".to_string(),
@@ -380,6 +1457,18 @@ impl AppService {
         })
     }
 
+    /// Ordered status transitions of a transfer, or `None` if it does not exist.
+    #[instrument(skip(self))]
+    pub async fn get_transfer_history(
+        &self,
+        id: &str,
+    ) -> Result<Option<Vec<StatusTransition>>, AppError> {
+        if self.db_client.get_transfer_request(id).await?.is_none() {
+            return Ok(None);
+        }
+        self.db_client.get_status_history(id).await.map(Some)
+    }
+
     /// Build a compliance and settlement audit report for a transfer.
     #[instrument(skip(self))]
     pub async fn get_transfer_audit_report(
@@ -427,6 +1516,14 @@ impl AppService {
                 };
                 (asset_type, AuditAmount::Public { amount: *amount })
             }
+            TransferType::PublicBatch { recipients } => (
+                AuditAssetType::SplToken,
+                AuditAmount::Public {
+                    amount: recipients
+                        .iter()
+                        .fold(0, |total: u64, (_, amount)| total.saturating_add(*amount)),
+                },
+            ),
             TransferType::Confidential { .. } => (
                 AuditAssetType::Token2022Confidential,
                 AuditAmount::Confidential {
@@ -442,6 +1539,8 @@ impl AppService {
             asset_type,
             token_mint: transfer.token_mint,
             amount,
+            token_sent_amount: transfer.token_sent_amount,
+            token_transfer_fee: transfer.token_transfer_fee,
             nonce: transfer.nonce,
             compliance_status: transfer.compliance_status,
             blockchain_status: transfer.blockchain_status,
@@ -468,7 +1567,7 @@ impl AppService {
             return Ok(());
         }
 
-        if session.status == CheckoutSessionStatus::Open && session.expires_at <= Utc::now() {
+        if session.status == CheckoutSessionStatus::Open && self.is_past(session.expires_at) {
             session.status = CheckoutSessionStatus::Expired;
         }
 
@@ -552,33 +1651,32 @@ impl AppService {
 
                 if is_still_blocked {
                     warn!(
-                        id = %id,
+                        transfer_id = %id,
                         "Retry blocked: address still in blocklist"
                     );
-                    return Err(AppError::Validation(ValidationError::InvalidField {
-                        field: "compliance_status".to_string(),
-                        message: "Address is still blocklisted".to_string(),
-                    }));
+                    return Err(AppError::Validation(ValidationError::Blocklisted(
+                        "Address is still blocklisted".to_string(),
+                    )));
                 }
 
                 // Address is now clear - update compliance status to approved
                 info!(
-                    id = %id,
+                    transfer_id = %id,
                     "Blocklist cleared: updating compliance status to approved for retry"
                 );
+                self.db_client.update_rejection_reason(id, None).await?;
                 self.db_client
                     .update_compliance_status(id, ComplianceStatus::Approved)
                     .await?;
             } else {
                 // Non-blocklist rejection - cannot retry
                 warn!(
-                    id = %id,
+                    transfer_id = %id,
                     "Retry blocked: compliance status is rejected (not blocklist)"
                 );
-                return Err(AppError::Validation(ValidationError::InvalidField {
-                    field: "compliance_status".to_string(),
-                    message: "Cannot retry a rejected transfer".to_string(),
-                }));
+                return Err(AppError::Validation(ValidationError::ComplianceRejected(
+                    "Cannot retry a rejected transfer".to_string(),
+                )));
             }
         }
 
@@ -586,7 +1684,7 @@ impl AppService {
         if let Some(ref blocklist) = self.blocklist {
             if let Some(reason) = blocklist.check_address(&transfer_request.to_address) {
                 warn!(
-                    id = %id,
+                    transfer_id = %id,
                     address = %transfer_request.to_address,
                     reason = %reason,
                     "Retry blocked: recipient in blocklist"
@@ -598,7 +1696,7 @@ impl AppService {
             }
             if let Some(reason) = blocklist.check_address(&transfer_request.from_address) {
                 warn!(
-                    id = %id,
+                    transfer_id = %id,
                     address = %transfer_request.from_address,
                     reason = %reason,
                     "Retry blocked: sender in blocklist"
@@ -610,12 +1708,26 @@ impl AppService {
             }
         }
 
-        if transfer_request.blockchain_status != BlockchainStatus::PendingSubmission
-            && transfer_request.blockchain_status != BlockchainStatus::Failed
-        {
+        // An unreconciled transfer already landed, so resubmitting would pay twice
+        if transfer_request.blockchain_status == BlockchainStatus::Unreconciled {
+            warn!(transfer_id = %id, "Retry blocked: transfer landed with a mismatch");
+            return Err(AppError::Validation(ValidationError::InvalidField {
+                field: "blockchain_status".to_string(),
+                message: "Transfer landed on-chain but could not be reconciled with the request"
+                    .to_string(),
+            }));
+        }
+
+        // Rejected transfers only get here once their blocklist hit has cleared
+        if !matches!(
+            transfer_request.blockchain_status,
+            BlockchainStatus::PendingSubmission
+                | BlockchainStatus::Failed
+                | BlockchainStatus::Rejected
+        ) {
             return Err(AppError::Validation(ValidationError::InvalidField {
                 field: "blockchain_status".to_string(),
-                message: "Request is not pending submission or failed".to_string(),
+                message: "Request is not pending submission, failed or rejected".to_string(),
             }));
         }
 
@@ -630,8 +1742,8 @@ impl AppService {
             && let Some(original_sig) = transfer_request.original_tx_signature.clone()
         {
             info!(
-                id = %transfer_request.id,
-                original_sig = %original_sig,
+                transfer_id = %transfer_request.id,
+                original_signature = %original_sig,
                 "Checking original transaction status before manual retry (JitoStateUnknown)"
             );
 
@@ -643,20 +1755,19 @@ impl AppService {
                 Ok(Some(TransactionStatus::Confirmed | TransactionStatus::Finalized)) => {
                     // Original transaction landed! Update as success, no retry needed
                     info!(
-                        id = %transfer_request.id,
-                        original_sig = %original_sig,
+                        transfer_id = %transfer_request.id,
+                        original_signature = %original_sig,
                         "Original transaction confirmed - marking as success (prevented double-spend)"
                     );
-                    self.db_client
-                        .update_blockchain_status(
-                            id,
-                            BlockchainStatus::Submitted,
-                            Some(&original_sig),
-                            None,
-                            None,
-                            transfer_request.blockhash_used.as_deref(),
-                        )
-                        .await?;
+                    self.set_blockchain_status(
+                        id,
+                        BlockchainStatus::Submitted,
+                        Some(&original_sig),
+                        None,
+                        None,
+                        transfer_request.blockhash_used.as_deref(),
+                    )
+                    .await?;
                     self.db_client
                         .update_jito_tracking(id, None, LastErrorType::None, None)
                         .await?;
@@ -671,7 +1782,7 @@ impl AppService {
                 Ok(Some(TransactionStatus::Failed(_))) | Ok(None) => {
                     // Failed or not found - safe to retry with new blockhash
                     info!(
-                        id = %transfer_request.id,
+                        transfer_id = %transfer_request.id,
                         "Original tx not confirmed - proceeding with retry"
                     );
                     self.db_client
@@ -680,7 +1791,7 @@ impl AppService {
                 }
                 Err(e) => {
                     warn!(
-                        id = %transfer_request.id,
+                        transfer_id = %transfer_request.id,
                         error = %e,
                         "Failed to check original tx status, proceeding with retry"
                     );
@@ -688,29 +1799,29 @@ impl AppService {
             }
         }
 
+        let transfer_request = self.pin_signer(transfer_request).await?;
         match self
             .blockchain_client
             .submit_transaction(&transfer_request)
             .await
         {
-            Ok((signature, blockhash)) => {
-                info!(id = %transfer_request.id, signature = %signature, "Retry submission successful");
-                self.db_client
-                    .update_blockchain_status(
-                        id,
-                        BlockchainStatus::Submitted,
-                        Some(&signature),
-                        None,
-                        None,
-                        Some(&blockhash),
-                    )
-                    .await?;
+            Ok((outcome, blockhash)) => {
+                info!(
+                    transfer_id = %transfer_request.id,
+                    signature = %outcome.signature,
+                    bundle_id = ?outcome.bundle_id,
+                    "Retry submission successful"
+                );
+                let status = outcome.submitted_status();
+                self.set_submitted(id, &outcome, &blockhash).await?;
                 self.db_client
                     .update_jito_tracking(id, None, LastErrorType::None, Some(&blockhash))
                     .await?;
                 let mut updated_request = transfer_request;
-                updated_request.blockchain_status = BlockchainStatus::Submitted;
-                updated_request.blockchain_signature = Some(signature.clone());
+                updated_request.blockchain_status = status;
+                updated_request.blockchain_signature = Some(outcome.signature);
+                updated_request.bundle_id = outcome.bundle_id;
+                updated_request.proof_signatures = outcome.proof_signatures;
                 updated_request.blockhash_used = Some(blockhash);
                 updated_request.blockchain_last_error = None;
                 updated_request.blockchain_next_retry_at = None;
@@ -719,32 +1830,31 @@ impl AppService {
             }
             Err(e) => {
                 let error_type = self.blockchain_client.classify_error(&e);
-                warn!(id = %transfer_request.id, error = ?e, error_type = %error_type, "Retry submission failed");
+                warn!(transfer_id = %transfer_request.id, error = ?e, error_type = %error_type, "Retry submission failed");
 
                 // SECURITY: Extract blockhash from error (sticky blockhash to prevent double-spend)
                 let attempt_blockhash = extract_blockhash_from_error(&e);
 
                 let retry_count = self.db_client.increment_retry_count(id).await?;
-                let (status, next_retry) = if retry_count >= MAX_RETRY_ATTEMPTS {
+                let (status, next_retry) = if retry_count >= self.retry_config.max_attempts {
                     (BlockchainStatus::Failed, None)
                 } else {
-                    let backoff = calculate_backoff(retry_count);
+                    let backoff = self.retry_config.backoff(retry_count);
                     (
                         BlockchainStatus::PendingSubmission,
                         Some(Utc::now() + Duration::seconds(backoff)),
                     )
                 };
 
-                self.db_client
-                    .update_blockchain_status(
-                        id,
-                        status,
-                        None,
-                        Some(&e.to_string()),
-                        next_retry,
-                        attempt_blockhash.as_deref(),
-                    )
-                    .await?;
+                self.set_blockchain_status(
+                    id,
+                    status,
+                    None,
+                    Some(&e.to_string()),
+                    next_retry,
+                    attempt_blockhash.as_deref(),
+                )
+                .await?;
 
                 // Store Jito tracking info
                 let original_sig = transfer_request.blockchain_signature.as_deref();
@@ -762,40 +1872,125 @@ impl AppService {
         }
     }
 
-    /// Process pending blockchain submissions (called by background worker)
+    /// Process pending blockchain submissions (called by background worker).
+    ///
+    /// Up to `concurrency` submissions are in flight at once; each one
+    /// succeeds or fails independently.
     #[instrument(skip(self))]
-    pub async fn process_pending_submissions(&self, batch_size: i64) -> Result<usize, AppError> {
-        let pending_requests = self
-            .db_client
-            .get_pending_blockchain_requests(batch_size)
-            .await?;
+    pub async fn process_pending_submissions(
+        &self,
+        batch_size: i64,
+        concurrency: usize,
+    ) -> Result<usize, AppError> {
+        let pending_requests = self.get_pending_submissions(batch_size).await?;
         let count = pending_requests.len();
 
         if count == 0 {
             return Ok(0);
         }
 
-        info!(count = count, "Processing pending blockchain submissions");
+        let concurrency = self.submission_concurrency(concurrency);
+        info!(
+            count = count,
+            concurrency = concurrency,
+            "Processing pending blockchain submissions"
+        );
 
-        for request in pending_requests {
-            if let Err(e) = self.process_single_submission(&request).await {
-                error!(id = %request.id, error = ?e, "Failed to process pending submission");
-            }
-        }
+        stream::iter(pending_requests)
+            .for_each_concurrent(concurrency, |request| async move {
+                if let Err(e) = self.process_single_submission(&request).await {
+                    error!(transfer_id = %request.id, error = ?e, "Failed to process pending submission");
+                }
+            })
+            .await;
 
         Ok(count)
     }
 
+    /// Number of submissions that may run in parallel for a requested limit.
+    ///
+    /// Signing only borrows the relayer keypair, so parallel submissions are
+    /// safe, except with a durable nonce: every transaction advances the same
+    /// nonce account, so they must be submitted one at a time.
+    pub(crate) fn submission_concurrency(&self, requested: usize) -> usize {
+        if self.blockchain_client.uses_durable_nonce() {
+            1
+        } else {
+            requested.max(1)
+        }
+    }
+
+    /// Fetch approved requests that are due for blockchain submission
+    pub async fn get_pending_submissions(
+        &self,
+        batch_size: i64,
+    ) -> Result<Vec<TransferRequest>, AppError> {
+        self.db_client
+            .get_pending_blockchain_requests(batch_size)
+            .await
+    }
+
+    /// Mark a pending submission as failed without submitting it
+    /// (e.g. rejected by the privacy health check)
+    pub async fn fail_submission(&self, id: &str, reason: &str) -> Result<(), AppError> {
+        warn!(transfer_id = %id, reason = %reason, "Failing pending submission");
+        self.set_blockchain_status(id, BlockchainStatus::Failed, None, Some(reason), None, None)
+            .await
+    }
+
+    /// Return a claimed submission to the queue without counting a retry
+    /// (e.g. skipped while the blockchain circuit is open)
+    pub async fn release_submission(&self, id: &str, reason: &str) -> Result<(), AppError> {
+        debug!(transfer_id = %id, reason = %reason, "Releasing pending submission");
+        self.set_blockchain_status(
+            id,
+            BlockchainStatus::PendingSubmission,
+            None,
+            Some(reason),
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Return a claimed submission to the queue until `retry_at` without
+    /// counting a retry (e.g. held back by the privacy health check)
+    pub async fn defer_submission(
+        &self,
+        id: &str,
+        retry_at: DateTime<Utc>,
+        reason: &str,
+    ) -> Result<(), AppError> {
+        debug!(transfer_id = %id, retry_at = %retry_at, reason = %reason, "Deferring pending submission");
+        self.set_blockchain_status(
+            id,
+            BlockchainStatus::PendingSubmission,
+            None,
+            Some(reason),
+            Some(retry_at),
+            None,
+        )
+        .await
+    }
+
     /// Process a single pending submission with Jito Double Spend Protection.
     ///
     /// This method implements the Jito Double Spend Protection:
     /// - Before retrying after a JitoStateUnknown error, check if the original
     ///   transaction was processed to prevent double-spend.
     /// - Track the error type to enable smart retry logic.
-    async fn process_single_submission(&self, request: &TransferRequest) -> Result<(), AppError> {
+    ///
+    /// If the blockchain circuit is open the submission is released back to
+    /// the queue without counting a retry, and the circuit-open error is
+    /// returned so the caller can back off.
+    #[instrument(skip_all, fields(transfer_id = %request.id, correlation_id = request.correlation_id.as_deref()))]
+    pub async fn process_single_submission(
+        &self,
+        request: &TransferRequest,
+    ) -> Result<(), AppError> {
         // Defense in depth: Skip non-approved requests (should be filtered at DB level already)
         if request.compliance_status != ComplianceStatus::Approved {
-            warn!(id = %request.id, status = ?request.compliance_status, "Skipping non-approved request");
+            warn!(transfer_id = %request.id, status = ?request.compliance_status, "Skipping non-approved request");
             return Ok(());
         }
 
@@ -811,8 +2006,8 @@ impl AppService {
             && let Some(ref original_sig) = request.original_tx_signature
         {
             info!(
-                id = %request.id,
-                original_sig = %original_sig,
+                transfer_id = %request.id,
+                original_signature = %original_sig,
                 "Checking original transaction status before retry (JitoStateUnknown)"
             );
 
@@ -825,20 +2020,19 @@ impl AppService {
                 Ok(Some(TransactionStatus::Confirmed | TransactionStatus::Finalized)) => {
                     // Original transaction landed! Update as success, no retry needed
                     info!(
-                        id = %request.id,
-                        original_sig = %original_sig,
+                        transfer_id = %request.id,
+                        original_signature = %original_sig,
                         "Original transaction confirmed - marking as success (prevented double-spend)"
                     );
-                    self.db_client
-                        .update_blockchain_status(
-                            &request.id,
-                            BlockchainStatus::Submitted,
-                            Some(original_sig),
-                            None,
-                            None,
-                            request.blockhash_used.as_deref(),
-                        )
-                        .await?;
+                    self.set_blockchain_status(
+                        &request.id,
+                        BlockchainStatus::Submitted,
+                        Some(original_sig),
+                        None,
+                        None,
+                        request.blockhash_used.as_deref(),
+                    )
+                    .await?;
                     // Clear error type since tx succeeded
                     self.db_client
                         .update_jito_tracking(&request.id, None, LastErrorType::None, None)
@@ -848,8 +2042,8 @@ impl AppService {
                 Ok(Some(TransactionStatus::Failed(err))) => {
                     // Definite failure, safe to retry with new blockhash
                     info!(
-                        id = %request.id,
-                        original_sig = %original_sig,
+                        transfer_id = %request.id,
+                        original_signature = %original_sig,
                         error = %err,
                         "Original tx failed on-chain - safe to retry with new blockhash"
                     );
@@ -875,7 +2069,7 @@ impl AppService {
                         if blockhash_valid {
                             // Blockhash still valid, tx might still land - wait longer
                             info!(
-                                id = %request.id,
+                                transfer_id = %request.id,
                                 blockhash = %blockhash,
                                 "Blockhash still valid, waiting longer before retry"
                             );
@@ -883,54 +2077,56 @@ impl AppService {
                             // Schedule a retry with backoff
                             let retry_count =
                                 self.db_client.increment_retry_count(&request.id).await?;
-                            let backoff = calculate_backoff(retry_count);
-                            self.db_client
-                                .update_blockchain_status(
-                                    &request.id,
-                                    BlockchainStatus::PendingSubmission,
-                                    None,
-                                    Some("JitoStateUnknown: waiting for blockhash expiry"),
-                                    Some(Utc::now() + Duration::seconds(backoff)),
-                                    None,
-                                )
-                                .await?;
+                            let backoff = self.retry_config.backoff(retry_count);
+                            self.set_blockchain_status(
+                                &request.id,
+                                BlockchainStatus::PendingSubmission,
+                                None,
+                                Some("JitoStateUnknown: waiting for blockhash expiry"),
+                                Some(Utc::now() + Duration::seconds(backoff)),
+                                None,
+                            )
+                            .await?;
                             return Ok(());
                         }
                     }
                     // Blockhash expired and tx not found = safe to retry with new blockhash
                     info!(
-                        id = %request.id,
+                        transfer_id = %request.id,
                         "Blockhash expired and tx not found - safe to retry with new blockhash"
                     );
                     self.db_client
                         .update_jito_tracking(&request.id, None, LastErrorType::NetworkError, None)
                         .await?;
                 }
+                Err(e) if is_circuit_open(&e) => {
+                    self.release_submission(&request.id, &e.to_string()).await?;
+                    return Err(e);
+                }
                 Err(e) => {
                     // SAFETY: Cannot verify original tx status due to RPC/network error.
                     // We MUST NOT submit a new transaction - the original might have landed.
                     // Reschedule for later retry of the status check instead.
                     error!(
-                        id = %request.id,
+                        transfer_id = %request.id,
                         error = %e,
                         "Failed to check original tx status - rescheduling (cannot safely retry)"
                     );
 
                     let retry_count = self.db_client.increment_retry_count(&request.id).await?;
-                    let backoff = calculate_backoff(retry_count);
-                    self.db_client
-                        .update_blockchain_status(
-                            &request.id,
-                            BlockchainStatus::PendingSubmission,
-                            None,
-                            Some(&format!(
-                                "JitoStateUnknown: RPC error checking status - {}",
-                                e
-                            )),
-                            Some(Utc::now() + Duration::seconds(backoff)),
-                            None,
-                        )
-                        .await?;
+                    let backoff = self.retry_config.backoff(retry_count);
+                    self.set_blockchain_status(
+                        &request.id,
+                        BlockchainStatus::PendingSubmission,
+                        None,
+                        Some(&format!(
+                            "JitoStateUnknown: RPC error checking status - {}",
+                            e
+                        )),
+                        Some(Utc::now() + Duration::seconds(backoff)),
+                        None,
+                    )
+                    .await?;
 
                     // Return Ok to indicate we handled this request (rescheduled, not failed)
                     // The request stays in PendingSubmission and will be retried later
@@ -940,31 +2136,42 @@ impl AppService {
         }
 
         // Delegate dispatch to blockchain client
+        let pinned = self.pin_signer(request.clone()).await?;
+        let request = &pinned;
+        self.acquire_rpc_permit().await;
         let result = self.blockchain_client.submit_transaction(request).await;
 
         match result {
-            Ok((signature, blockhash)) => {
+            Ok((outcome, blockhash)) => {
                 let transfer_type = if request.token_mint.is_some() {
                     "Token"
                 } else {
                     "SOL"
                 };
-                info!(id = %request.id, signature = %signature, r#type = %transfer_type, "Transfer successful");
-                self.db_client
-                    .update_blockchain_status(
-                        &request.id,
-                        BlockchainStatus::Submitted,
-                        Some(&signature),
-                        None,
-                        None,
-                        Some(&blockhash),
-                    )
+                info!(
+                    transfer_id = %request.id,
+                    signature = %outcome.signature,
+                    bundle_id = ?outcome.bundle_id,
+                    r#type = %transfer_type,
+                    awaiting_approval = outcome.awaiting_approval,
+                    "Transfer successful"
+                );
+                // Keep the bundle ID for correlation with Jito's explorer and
+                // the proof transactions, by which webhooks recognize those of
+                // a confidential transfer; a resubmission replaces both
+                self.set_submitted(&request.id, &outcome, &blockhash)
                     .await?;
                 // Clear Jito tracking on success (persist blockhash for future retry logic)
                 self.db_client
                     .update_jito_tracking(&request.id, None, LastErrorType::None, Some(&blockhash))
                     .await?;
             }
+            Err(e) if is_circuit_open(&e) => {
+                // The RPC was never called: no retry is counted and the
+                // sticky blockhash and Jito tracking stay as they were
+                self.release_submission(&request.id, &e.to_string()).await?;
+                return Err(e);
+            }
             Err(e) => {
                 let transfer_type = if request.token_mint.is_some() {
                     "Token"
@@ -975,7 +2182,7 @@ impl AppService {
                 // Classify the error for smart retry logic
                 let error_type = self.blockchain_client.classify_error(&e);
                 warn!(
-                    id = %request.id,
+                    transfer_id = %request.id,
                     error = ?e,
                     error_type = %error_type,
                     r#type = %transfer_type,
@@ -986,26 +2193,25 @@ impl AppService {
                 let attempt_blockhash = extract_blockhash_from_error(&e);
 
                 let retry_count = self.db_client.increment_retry_count(&request.id).await?;
-                let (status, next_retry) = if retry_count >= MAX_RETRY_ATTEMPTS {
+                let (status, next_retry) = if retry_count >= self.retry_config.max_attempts {
                     (BlockchainStatus::Failed, None)
                 } else {
-                    let backoff = calculate_backoff(retry_count);
+                    let backoff = self.retry_config.backoff(retry_count);
                     (
                         BlockchainStatus::PendingSubmission,
                         Some(Utc::now() + Duration::seconds(backoff)),
                     )
                 };
 
-                self.db_client
-                    .update_blockchain_status(
-                        &request.id,
-                        status,
-                        None,
-                        Some(&e.to_string()),
-                        next_retry,
-                        attempt_blockhash.as_deref(),
-                    )
-                    .await?;
+                self.set_blockchain_status(
+                    &request.id,
+                    status,
+                    None,
+                    Some(&e.to_string()),
+                    next_retry,
+                    attempt_blockhash.as_deref(),
+                )
+                .await?;
 
                 // Store Jito tracking info for JitoStateUnknown errors
                 // This enables status check on next retry attempt
@@ -1049,13 +2255,31 @@ impl AppService {
     ///    - Confirmed/Finalized → `Confirmed`
     ///    - Not Found + Blockhash Expired → `Expired` (terminal, user must re-sign)
     ///    - Not Found + Blockhash Valid → Wait (next crank cycle)
+    ///    - Not Found + Durable Nonce Advanced → `PendingSubmission` (resubmitted, never expires)
     ///
     /// This is a self-healing mechanism that handles webhook failures.
-    #[instrument(skip(self))]
     pub async fn process_stale_submitted_transactions(
         &self,
         older_than_secs: i64,
         batch_size: i64,
+    ) -> Result<usize, AppError> {
+        self.process_stale_submitted_transactions_tracked(
+            older_than_secs,
+            batch_size,
+            &AtomicUsize::new(0),
+        )
+        .await
+    }
+
+    /// [`Self::process_stale_submitted_transactions`], setting `in_flight` to
+    /// the size of the batch and decrementing it as each status check
+    /// finishes, so the crank can report progress on shutdown.
+    #[instrument(skip(self, in_flight))]
+    pub(crate) async fn process_stale_submitted_transactions_tracked(
+        &self,
+        older_than_secs: i64,
+        batch_size: i64,
+        in_flight: &AtomicUsize,
     ) -> Result<usize, AppError> {
         let stale_transactions = self
             .db_client
@@ -1073,80 +2297,362 @@ impl AppService {
             "Processing stale submitted transactions (crank)"
         );
 
+        in_flight.store(count, Ordering::SeqCst);
         for tx in stale_transactions {
             if let Err(e) = self.check_stale_transaction_status(&tx).await {
-                error!(id = %tx.id, error = ?e, "Failed to check stale transaction status");
+                error!(transfer_id = %tx.id, error = ?e, "Failed to check stale transaction status");
+            }
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        }
+
+        Ok(count)
+    }
+
+    /// Settle transfers submitted as multisig proposals once their members
+    /// have decided.
+    ///
+    /// For each claimed `awaiting_approval` transfer, the proposal stored as
+    /// its signature is read on-chain:
+    /// - Executed → `Confirmed`
+    /// - Rejected/Cancelled → `Failed`
+    /// - Still open, or not found yet → left awaiting approval
+    #[instrument(skip(self))]
+    pub async fn process_awaiting_approvals(&self, batch_size: i64) -> Result<usize, AppError> {
+        let pending = self
+            .db_client
+            .claim_awaiting_approval_transfers(batch_size)
+            .await?;
+
+        let count = pending.len();
+        if count == 0 {
+            return Ok(0);
+        }
+
+        debug!(count = count, "Checking multisig proposals (crank)");
+
+        for tx in pending {
+            if let Err(e) = self.check_proposal_status(&tx).await {
+                error!(transfer_id = %tx.id, error = ?e, "Failed to check multisig proposal status");
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// Move one transfer awaiting approval on if its proposal was decided.
+    #[instrument(skip_all, fields(transfer_id = %tx.id, correlation_id = tx.correlation_id.as_deref()))]
+    async fn check_proposal_status(&self, tx: &TransferRequest) -> Result<(), AppError> {
+        let Some(proposal) = tx.blockchain_signature.as_deref() else {
+            warn!(transfer_id = %tx.id, "Transfer awaiting approval has no proposal address");
+            return Ok(());
+        };
+
+        self.acquire_rpc_permit().await;
+        let status = self.blockchain_client.get_proposal_status(proposal).await?;
+        let decided = status.and_then(|status| status.blockchain_status());
+        if decided.is_none()
+            && tx
+                .blockchain_next_retry_at
+                .is_some_and(|expires_at| expires_at <= Utc::now())
+        {
+            warn!(
+                transfer_id = %tx.id,
+                proposal = %proposal,
+                "Multisig proposal not decided within the approval window"
+            );
+            let error = format!(
+                "Multisig proposal {} not decided within the approval window; members should reject it",
+                proposal
+            );
+            self.set_blockchain_status(
+                &tx.id,
+                BlockchainStatus::Expired,
+                None,
+                Some(&error),
+                None,
+                None,
+            )
+            .await?;
+            return Ok(());
+        }
+        let Some(status) = status else {
+            debug!(transfer_id = %tx.id, proposal = %proposal, "Multisig proposal not found yet");
+            return Ok(());
+        };
+
+        match decided {
+            Some(BlockchainStatus::Confirmed) => {
+                info!(transfer_id = %tx.id, proposal = %proposal, "Multisig proposal executed");
+                self.set_blockchain_status(
+                    &tx.id,
+                    BlockchainStatus::Confirmed,
+                    Some(proposal),
+                    None,
+                    None,
+                    None,
+                )
+                .await?;
+            }
+            Some(next) => {
+                info!(
+                    transfer_id = %tx.id,
+                    proposal = %proposal,
+                    proposal_status = status.as_str(),
+                    "Multisig proposal will not execute"
+                );
+                let error = format!("Multisig proposal {}", status.as_str());
+                self.set_blockchain_status(&tx.id, next, None, Some(&error), None, None)
+                    .await?;
+            }
+            None => {
+                debug!(
+                    transfer_id = %tx.id,
+                    proposal = %proposal,
+                    proposal_status = status.as_str(),
+                    "Multisig proposal still awaiting approval"
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Return transfers abandoned in `processing` by a crashed worker to the queue.
+    ///
+    /// A worker that dies between claiming a transfer and recording the
+    /// outcome leaves it in `processing`, which no worker claims again. Once
+    /// the claim is older than `lease_secs`, the last signature of the
+    /// transfer (if any) is checked on-chain first, so a transaction that
+    /// landed is never submitted twice:
+    /// - Confirmed/Finalized → `Confirmed`
+    /// - Failed on-chain → `PendingSubmission` (safe to retry)
+    /// - Not found → `PendingSubmission` as `JitoStateUnknown`, so the worker
+    ///   waits for the blockhash to expire before resubmitting
+    /// - No signature → `PendingSubmission`
+    /// - Status lookup error → left in `processing` for the next cycle
+    #[instrument(skip(self))]
+    pub async fn reclaim_stale_processing_transactions(
+        &self,
+        lease_secs: i64,
+        batch_size: i64,
+    ) -> Result<usize, AppError> {
+        let stale_transactions = self
+            .db_client
+            .claim_stale_processing_transactions(lease_secs, batch_size)
+            .await?;
+
+        let count = stale_transactions.len();
+        if count == 0 {
+            return Ok(0);
+        }
+
+        warn!(
+            count = count,
+            lease_secs = lease_secs,
+            "Reclaiming transfers abandoned in processing (crank)"
+        );
+
+        for tx in stale_transactions {
+            if let Err(e) = self.reclaim_processing_transaction(&tx).await {
+                error!(transfer_id = %tx.id, error = ?e, "Failed to reclaim abandoned transfer");
             }
         }
 
         Ok(count)
     }
 
+    /// Check the last signature of an abandoned transfer and requeue it if it
+    /// did not land.
+    #[instrument(skip_all, fields(transfer_id = %tx.id, correlation_id = tx.correlation_id.as_deref()))]
+    async fn reclaim_processing_transaction(&self, tx: &TransferRequest) -> Result<(), AppError> {
+        const REQUEUE_REASON: &str = "Processing lease expired - requeued after worker crash";
+
+        let Some(signature) = tx
+            .blockchain_signature
+            .as_deref()
+            .or(tx.original_tx_signature.as_deref())
+        else {
+            info!(transfer_id = %tx.id, "Abandoned transfer has no signature - requeueing");
+            return self
+                .set_blockchain_status(
+                    &tx.id,
+                    BlockchainStatus::PendingSubmission,
+                    None,
+                    Some(REQUEUE_REASON),
+                    None,
+                    None,
+                )
+                .await;
+        };
+
+        self.acquire_rpc_permit().await;
+        match self.blockchain_client.get_signature_status(signature).await {
+            Ok(Some(TransactionStatus::Confirmed | TransactionStatus::Finalized)) => {
+                info!(
+                    transfer_id = %tx.id,
+                    signature = %signature,
+                    "Abandoned transfer confirmed on-chain (prevented double-spend)"
+                );
+                self.set_blockchain_status(
+                    &tx.id,
+                    BlockchainStatus::Confirmed,
+                    Some(signature),
+                    None,
+                    None,
+                    tx.blockhash_used.as_deref(),
+                )
+                .await?;
+            }
+            Ok(Some(TransactionStatus::Failed(err))) => {
+                info!(
+                    transfer_id = %tx.id,
+                    error = %err,
+                    "Abandoned transfer failed on-chain - safe to requeue"
+                );
+                self.set_blockchain_status(
+                    &tx.id,
+                    BlockchainStatus::PendingSubmission,
+                    None,
+                    Some(REQUEUE_REASON),
+                    None,
+                    None,
+                )
+                .await?;
+                self.db_client
+                    .update_jito_tracking(&tx.id, None, LastErrorType::TransactionFailed, None)
+                    .await?;
+            }
+            Ok(None) => {
+                // The transaction may still land: let the worker's double-spend
+                // protection wait for its blockhash to expire before resubmitting
+                info!(
+                    transfer_id = %tx.id,
+                    signature = %signature,
+                    "Abandoned transfer not found on-chain - requeueing as state unknown"
+                );
+                self.set_blockchain_status(
+                    &tx.id,
+                    BlockchainStatus::PendingSubmission,
+                    None,
+                    Some(REQUEUE_REASON),
+                    None,
+                    None,
+                )
+                .await?;
+                self.db_client
+                    .update_jito_tracking(
+                        &tx.id,
+                        Some(signature),
+                        LastErrorType::JitoStateUnknown,
+                        tx.blockhash_used.as_deref(),
+                    )
+                    .await?;
+            }
+            Err(e) => {
+                warn!(transfer_id = %tx.id, error = ?e, "Failed to query abandoned transfer status - will retry next cycle");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reconcile a submitted transfer with its on-chain status now, instead
+    /// of waiting for the crank cycle.
+    ///
+    /// Runs exactly the crank's check for one transfer, so it can confirm,
+    /// fail, expire or requeue it, or leave it submitted while the
+    /// transaction may still land. Returns the transfer as it stands after.
+    #[instrument(skip(self))]
+    pub async fn reconcile_transfer(&self, id: &str) -> Result<TransferRequest, AppError> {
+        let transfer = self
+            .db_client
+            .get_transfer_request(id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Database(crate::domain::DatabaseError::NotFound(id.to_string()))
+            })?;
+        if transfer.blockchain_status != BlockchainStatus::Submitted {
+            return Err(AppError::Conflict(format!(
+                "Transfer {} cannot be reconciled in status '{}'",
+                id, transfer.blockchain_status
+            )));
+        }
+
+        info!(transfer_id = %id, "Reconciling transfer on demand");
+        self.check_stale_transaction_status(&transfer).await?;
+
+        self.db_client
+            .get_transfer_request(id)
+            .await?
+            .ok_or_else(|| {
+                AppError::Database(crate::domain::DatabaseError::NotFound(id.to_string()))
+            })
+    }
+
     /// Check the on-chain status of a single stale submitted transaction.
+    #[instrument(skip_all, fields(transfer_id = %tx.id, correlation_id = tx.correlation_id.as_deref()))]
     async fn check_stale_transaction_status(&self, tx: &TransferRequest) -> Result<(), AppError> {
         let signature = match &tx.blockchain_signature {
             Some(sig) => sig,
             None => {
                 // No signature stored - shouldn't happen for submitted status
-                warn!(id = %tx.id, "Stale transaction has no signature - marking as failed");
-                self.db_client
-                    .update_blockchain_status(
-                        &tx.id,
-                        BlockchainStatus::Failed,
-                        None,
-                        Some("No blockchain signature found for submitted transaction"),
-                        None,
-                        None,
-                    )
-                    .await?;
+                warn!(transfer_id = %tx.id, "Stale transaction has no signature - marking as failed");
+                self.set_blockchain_status(
+                    &tx.id,
+                    BlockchainStatus::Failed,
+                    None,
+                    Some("No blockchain signature found for submitted transaction"),
+                    None,
+                    None,
+                )
+                .await?;
                 return Ok(());
             }
         };
 
-        info!(id = %tx.id, signature = %signature, "Checking stale transaction status on-chain");
+        info!(transfer_id = %tx.id, signature = %signature, "Checking stale transaction status on-chain");
 
         // Query blockchain for transaction status
+        self.acquire_rpc_permit().await;
         match self.blockchain_client.get_signature_status(signature).await {
             Ok(Some(TransactionStatus::Confirmed | TransactionStatus::Finalized)) => {
                 // Transaction confirmed! Webhook missed it.
                 info!(
-                    id = %tx.id,
+                    transfer_id = %tx.id,
                     signature = %signature,
                     "Stale transaction confirmed on-chain (webhook missed)"
                 );
-                self.db_client
-                    .update_blockchain_status(
-                        &tx.id,
-                        BlockchainStatus::Confirmed,
-                        Some(signature),
-                        None,
-                        None,
-                        tx.blockhash_used.as_deref(),
-                    )
-                    .await?;
+                self.set_blockchain_status(
+                    &tx.id,
+                    BlockchainStatus::Confirmed,
+                    Some(signature),
+                    None,
+                    None,
+                    tx.blockhash_used.as_deref(),
+                )
+                .await?;
             }
             Ok(Some(TransactionStatus::Failed(err))) => {
                 // Transaction failed on-chain
-                warn!(id = %tx.id, error = %err, "Stale transaction failed on-chain");
-                self.db_client
-                    .update_blockchain_status(
-                        &tx.id,
-                        BlockchainStatus::Failed,
-                        Some(signature),
-                        Some(&format!("Transaction failed on-chain: {}", err)),
-                        None,
-                        tx.blockhash_used.as_deref(),
-                    )
-                    .await?;
+                warn!(transfer_id = %tx.id, error = %err, "Stale transaction failed on-chain");
+                self.set_blockchain_status(
+                    &tx.id,
+                    BlockchainStatus::Failed,
+                    Some(signature),
+                    Some(&format!("Transaction failed on-chain: {}", err)),
+                    None,
+                    tx.blockhash_used.as_deref(),
+                )
+                .await?;
             }
             Ok(None) => {
                 // Transaction not found - check if blockhash expired
-                info!(id = %tx.id, "Transaction not found on-chain - checking blockhash validity");
+                info!(transfer_id = %tx.id, "Transaction not found on-chain - checking blockhash validity");
                 self.handle_not_found_transaction(tx, signature).await?;
             }
             Err(e) => {
                 // RPC error - log and leave for next crank cycle
-                warn!(id = %tx.id, error = ?e, "Failed to query transaction status - will retry next cycle");
+                warn!(transfer_id = %tx.id, error = ?e, "Failed to query transaction status - will retry next cycle");
             }
         }
 
@@ -1154,7 +2660,8 @@ impl AppService {
     }
 
     /// Handle a transaction that was not found on-chain.
-    /// If blockhash is expired, mark as `Expired` (terminal state).
+    /// If blockhash is expired, mark as `Expired` (terminal state), unless the
+    /// transaction was built on a durable nonce, in which case it is resubmitted.
     async fn handle_not_found_transaction(
         &self,
         tx: &TransferRequest,
@@ -1164,29 +2671,45 @@ impl AppService {
             Some(bh) => bh,
             None => {
                 // No blockhash stored - can't determine expiry, leave for next cycle
-                warn!(id = %tx.id, "No blockhash stored - cannot determine expiry");
+                warn!(transfer_id = %tx.id, "No blockhash stored - cannot determine expiry");
                 return Ok(());
             }
         };
 
         // Check if blockhash is still valid
-        match self.blockchain_client.is_blockhash_valid(blockhash).await {
-            Ok(true) => {
-                // Blockhash still valid - transaction might still land
-                info!(id = %tx.id, "Blockhash still valid - transaction may still land");
-                // Leave in submitted state, will check again next cycle
+        let blockhash_valid = match self.blockchain_client.is_blockhash_valid(blockhash).await {
+            Ok(valid) => valid,
+            Err(e) => {
+                // RPC error - log and leave for next cycle
+                warn!(transfer_id = %tx.id, error = ?e, "Failed to check blockhash validity - will retry next cycle");
+                return Ok(());
+            }
+        };
+
+        match NotFoundAction::decide(blockhash_valid, self.blockchain_client.uses_durable_nonce()) {
+            NotFoundAction::Wait => {
+                if self.bundle_dropped(tx, signature).await {
+                    self.retry_dropped_bundle(tx, signature, blockhash).await?;
+                } else {
+                    // Blockhash still valid - transaction might still land
+                    info!(transfer_id = %tx.id, "Blockhash still valid - transaction may still land");
+                    // Leave in submitted state, will check again next cycle
+                }
+            }
+            NotFoundAction::Resubmit => {
+                self.resubmit_after_nonce_advance(tx, signature).await?;
             }
-            Ok(false) => {
+            NotFoundAction::Expire => {
                 // Blockhash expired + transaction not found = transaction will never land
                 // This is a TERMINAL state - user must re-sign with fresh nonce
                 warn!(
-                    id = %tx.id,
+                    transfer_id = %tx.id,
                     signature = %signature,
                     blockhash = %blockhash,
                     "Blockhash expired and transaction not found - marking as EXPIRED"
                 );
-                self.db_client
-                    .update_blockchain_status(
+                self
+                    .set_blockchain_status(
                         &tx.id,
                         BlockchainStatus::Expired,
                         Some(signature),
@@ -1196,151 +2719,326 @@ impl AppService {
                     )
                     .await?;
             }
-            Err(e) => {
-                // RPC error - log and leave for next cycle
-                warn!(id = %tx.id, error = ?e, "Failed to check blockhash validity - will retry next cycle");
-            }
         }
 
         Ok(())
     }
 
-    /// Perform health check on all dependencies
-    #[instrument(skip(self))]
-    pub async fn health_check(&self) -> HealthResponse {
-        let db_health = match self.db_client.health_check().await {
-            Ok(()) => HealthStatus::Healthy,
-            Err(_) => HealthStatus::Unhealthy,
-        };
-        let blockchain_health = match self.blockchain_client.health_check().await {
-            Ok(()) => HealthStatus::Healthy,
-            Err(_) => HealthStatus::Unhealthy,
+    /// Whether the Jito bundle carrying `signature` was dropped by the block engine.
+    /// Untracked transactions and status lookup errors count as not dropped.
+    async fn bundle_dropped(&self, tx: &TransferRequest, signature: &str) -> bool {
+        match self.blockchain_client.get_bundle_status(signature).await {
+            Ok(Some(status)) => {
+                info!(transfer_id = %tx.id, bundle_status = ?status, "Checked Jito bundle status");
+                status.is_dropped()
+            }
+            Ok(None) => false,
+            Err(e) => {
+                warn!(transfer_id = %tx.id, error = ?e, "Failed to check Jito bundle status");
+                false
+            }
+        }
+    }
+
+    /// Reschedule a transaction whose Jito bundle was dropped.
+    ///
+    /// A dropped bundle never executes, so this is a definite failure and a
+    /// new transaction is safe to submit, following the regular retry limits.
+    async fn retry_dropped_bundle(
+        &self,
+        tx: &TransferRequest,
+        signature: &str,
+        blockhash: &str,
+    ) -> Result<(), AppError> {
+        warn!(
+            transfer_id = %tx.id,
+            signature = %signature,
+            "Jito bundle dropped before landing - scheduling retry"
+        );
+
+        let retry_count = self.db_client.increment_retry_count(&tx.id).await?;
+        let (status, next_retry) = if retry_count >= self.retry_config.max_attempts {
+            (BlockchainStatus::Failed, None)
+        } else {
+            let backoff = self.retry_config.backoff(retry_count);
+            (
+                BlockchainStatus::PendingSubmission,
+                Some(Utc::now() + Duration::seconds(backoff)),
+            )
         };
-        HealthResponse::new(db_health, blockchain_health)
+
+        self.set_blockchain_status(
+            &tx.id,
+            status,
+            None,
+            Some("Jito bundle dropped before landing"),
+            next_retry,
+            Some(blockhash),
+        )
+        .await?;
+        self.db_client
+            .update_jito_tracking(
+                &tx.id,
+                None,
+                LastErrorType::JitoBundleFailed,
+                Some(blockhash),
+            )
+            .await?;
+
+        Ok(())
     }
 
-    /// Process incoming Helius webhook transactions.
-    /// Updates blockchain status for transactions we have initiated.
-    /// Returns the number of transactions actually processed.
-    #[instrument(skip(self, transactions), fields(tx_count = %transactions.len()))]
-    pub async fn process_helius_webhook(
+    /// Reschedule a durable nonce transaction whose nonce was advanced without it landing.
+    ///
+    /// The original can no longer be processed once the nonce moves on, so a
+    /// new transaction is safe to submit. The signature is checked once more
+    /// first, in case the original itself advanced the nonce after the
+    /// previous status lookup.
+    async fn resubmit_after_nonce_advance(
         &self,
-        transactions: Vec<HeliusTransaction>,
-    ) -> Result<usize, AppError> {
-        let mut processed = 0;
+        tx: &TransferRequest,
+        signature: &str,
+    ) -> Result<(), AppError> {
+        match self.blockchain_client.get_signature_status(signature).await {
+            Ok(None) => {}
+            Ok(Some(_)) => {
+                info!(transfer_id = %tx.id, "Transaction landed while checking nonce - will settle next cycle");
+                return Ok(());
+            }
+            Err(e) => {
+                warn!(transfer_id = %tx.id, error = ?e, "Failed to re-check transaction status - will retry next cycle");
+                return Ok(());
+            }
+        }
 
-        for tx in transactions {
-            // Look up by signature to see if this is one of our transactions
-            if let Some(request) = self
-                .db_client
-                .get_transfer_by_signature(&tx.signature)
-                .await?
-            {
-                // Only update if currently in Submitted status (waiting for confirmation)
-                if request.blockchain_status == BlockchainStatus::Submitted {
-                    let (new_status, error_msg) = if tx.transaction_error.is_none() {
-                        info!(id = %request.id, signature = %tx.signature, "Transaction confirmed via Helius webhook");
-                        (BlockchainStatus::Confirmed, None)
-                    } else {
-                        let err = tx
-                            .transaction_error
-                            .as_ref()
-                            .map(|e| e.to_string())
-                            .unwrap_or_else(|| "Unknown transaction error".to_string());
-                        warn!(id = %request.id, signature = %tx.signature, error = %err, "Transaction failed via Helius webhook");
-                        (BlockchainStatus::Failed, Some(err))
-                    };
+        warn!(
+            transfer_id = %tx.id,
+            signature = %signature,
+            "Durable nonce advanced and transaction not found - resubmitting"
+        );
+        let retry_count = self.db_client.increment_retry_count(&tx.id).await?;
+        let backoff = self.retry_config.backoff(retry_count);
+        self.set_blockchain_status(
+            &tx.id,
+            BlockchainStatus::PendingSubmission,
+            None,
+            Some("Durable nonce advanced before transaction landed - resubmitting"),
+            Some(Utc::now() + Duration::seconds(backoff)),
+            None,
+        )
+        .await?;
 
-                    self.db_client
-                        .update_blockchain_status(
-                            &request.id,
-                            new_status,
-                            None,
-                            error_msg.as_deref(),
-                            None,
-                            None,
-                        )
-                        .await?;
+        Ok(())
+    }
 
-                    processed += 1;
+    /// Perform health check on all dependencies.
+    ///
+    /// The database and blockchain are critical and make the service
+    /// unhealthy; a failing compliance provider, privacy service or read
+    /// replica only degrades it. Probes run concurrently.
+    #[instrument(skip(self))]
+    pub async fn health_check(&self) -> HealthResponse {
+        let privacy = async {
+            match &self.privacy_service {
+                Some(privacy_service) => {
+                    probe_optional_dependency("privacy", privacy_service.ping()).await
                 }
+                None => None,
             }
-        }
+        };
+        let (database, blockchain, compliance, read_replica, privacy) = tokio::join!(
+            probe_dependency("database", self.db_client.health_check()),
+            probe_dependency("blockchain", self.blockchain_client.health_check()),
+            probe_dependency("compliance", self.compliance_provider.health_check()),
+            probe_optional_dependency("read_replica", self.db_client.read_replica_health_check()),
+            privacy,
+        );
 
-        info!(processed = %processed, "Helius webhook processing complete");
-        Ok(processed)
+        let mut health = HealthResponse::new(database.status, blockchain.status);
+        health.processing_paused = self.processing_pause.is_paused();
+        let mut dependencies = vec![database, blockchain, compliance];
+        dependencies.extend(read_replica);
+        dependencies.extend(privacy);
+        health.with_dependencies(dependencies)
     }
 
-    /// Process incoming QuickNode webhook events.
+    /// Health check including uptime, the pending queue depth, the fee
+    /// payer balance and the RPC node's slot lag and version
+    pub async fn health_check_verbose(&self) -> HealthResponse {
+        let node = async {
+            match tokio::time::timeout(
+                HEALTH_PROBE_TIMEOUT,
+                self.blockchain_client.detailed_health(),
+            )
+            .await
+            {
+                Ok(Ok(node)) => Some(node),
+                Ok(Err(AppError::NotSupported(_))) => None,
+                Ok(Err(e)) => {
+                    warn!(error = %e, "Blockchain node diagnostics failed");
+                    None
+                }
+                Err(_) => {
+                    warn!("Blockchain node diagnostics timed out");
+                    None
+                }
+            }
+        };
+        let (health, pending, node) = tokio::join!(
+            self.health_check(),
+            self.db_client.count_pending_blockchain_requests(),
+            node,
+        );
+        let mut health = match node {
+            Some(node) => health.with_blockchain_node(node),
+            None => health,
+        };
+        health.uptime_secs = Some(self.started_at.elapsed().as_secs());
+        health.pending_queue_depth = pending
+            .inspect_err(
+                |e| warn!(error = %e, "Failed to count pending transfers for health check"),
+            )
+            .ok();
+        health.fee_payer_balance = self
+            .fee_payer_balance
+            .as_ref()
+            .and_then(FeePayerBalanceTracker::latest);
+        health
+    }
+
+    /// Process confirmation events from any webhook provider.
     /// Updates blockchain status for transactions we have initiated.
     ///
-    /// **IMPORTANT**: QuickNode webhooks can deliver an array of events in a single POST.
-    /// This method processes ALL events in the batch, not just a single event.
+    /// Providers can deliver several events in a single POST; ALL of them are
+    /// processed. Re-delivered events are skipped.
     ///
-    /// Returns the number of transactions actually processed (status updated).
+    /// Returns the number of genuinely new transactions processed (status updated).
     #[instrument(skip(self, events), fields(event_count = %events.len()))]
-    pub async fn process_quicknode_webhook(
-        &self,
-        events: Vec<QuickNodeWebhookEvent>,
-    ) -> Result<usize, AppError> {
+    pub async fn process_webhook(&self, events: Vec<ConfirmationEvent>) -> Result<usize, AppError> {
         let mut processed = 0;
 
-        // Process ALL events in the batch (not 1:1 mapping of request to event)
         for event in events {
-            // Look up by signature to see if this is one of our transactions
-            if let Some(request) = self
-                .db_client
-                .get_transfer_by_signature(&event.signature)
-                .await?
-            {
-                // Only update if currently in Submitted status (waiting for confirmation)
-                if request.blockchain_status == BlockchainStatus::Submitted {
-                    let (new_status, error_msg) = if event.is_success() {
-                        info!(
-                            id = %request.id,
-                            signature = %event.signature,
-                            slot = ?event.slot,
-                            "Transaction confirmed via QuickNode webhook"
-                        );
-                        (BlockchainStatus::Confirmed, None)
-                    } else {
-                        let err = event
-                            .error_message()
-                            .unwrap_or_else(|| "Unknown transaction error".to_string());
-                        warn!(
-                            id = %request.id,
-                            signature = %event.signature,
-                            error = %err,
-                            "Transaction failed via QuickNode webhook"
-                        );
-                        (BlockchainStatus::Failed, Some(err))
-                    };
-
-                    self.db_client
-                        .update_blockchain_status(
-                            &request.id,
-                            new_status,
-                            None,
-                            error_msg.as_deref(),
-                            None,
-                            None,
-                        )
-                        .await?;
+            let outcome = if event.success {
+                BlockchainStatus::Confirmed
+            } else {
+                BlockchainStatus::Failed
+            };
+            if !self.webhook_dedup.claim(&event.signature, outcome) {
+                debug!(signature = %event.signature, "Skipping re-delivered webhook event");
+                continue;
+            }
 
-                    processed += 1;
+            match self.apply_confirmation_event(&event).await {
+                Ok(true) => processed += 1,
+                Ok(false) => self.webhook_dedup.release(&event.signature, outcome),
+                Err(e) => {
+                    self.webhook_dedup.release(&event.signature, outcome);
+                    return Err(e);
                 }
             }
         }
 
-        info!(processed = %processed, "QuickNode webhook processing complete");
+        info!(processed = %processed, "Webhook processing complete");
         Ok(processed)
     }
-}
 
-/// Calculate exponential backoff with maximum cap
-fn calculate_backoff(retry_count: i32) -> i64 {
-    let backoff = 2_i64.pow(retry_count.min(8) as u32);
-    backoff.min(MAX_BACKOFF_SECS)
+    /// Apply a single confirmation event.
+    /// Returns whether a transfer's status was updated.
+    async fn apply_confirmation_event(&self, event: &ConfirmationEvent) -> Result<bool, AppError> {
+        // Look up by signature to see if this is one of our transactions
+        let Some(request) = self
+            .db_client
+            .get_transfer_by_signature(&event.signature)
+            .await?
+        else {
+            return Ok(false);
+        };
+
+        // Only update if currently in Submitted status (waiting for confirmation)
+        if request.blockchain_status != BlockchainStatus::Submitted {
+            return Ok(false);
+        }
+
+        // Proof verification transactions of a confidential transfer land
+        // before it; only the final transfer decides the request
+        if request.blockchain_signature.as_deref() != Some(event.signature.as_str()) {
+            debug!(
+                transfer_id = %request.id,
+                signature = %event.signature,
+                success = event.success,
+                "Proof transaction webhook, waiting for the final transfer"
+            );
+            return Ok(false);
+        }
+
+        let mismatch = event
+            .transfers
+            .as_deref()
+            .filter(|_| event.success)
+            .and_then(|observed| reconcile_transfers(&request, observed));
+
+        let (new_status, error_msg) = if let Some(mismatch) = mismatch {
+            error!(
+                transfer_id = %request.id,
+                signature = %event.signature,
+                mismatch = %mismatch,
+                "On-chain transfer does not match the stored request"
+            );
+            (BlockchainStatus::Unreconciled, Some(mismatch))
+        } else if event.success {
+            info!(
+                transfer_id = %request.id,
+                signature = %event.signature,
+                slot = ?event.slot,
+                "Transaction confirmed via webhook"
+            );
+            (BlockchainStatus::Confirmed, None)
+        } else {
+            let err = event
+                .error
+                .clone()
+                .unwrap_or_else(|| "Unknown transaction error".to_string());
+            warn!(
+                transfer_id = %request.id,
+                signature = %event.signature,
+                error = %err,
+                "Transaction failed via webhook"
+            );
+            (BlockchainStatus::Failed, Some(err))
+        };
+
+        self.set_blockchain_status(
+            &request.id,
+            new_status,
+            None,
+            error_msg.as_deref(),
+            None,
+            None,
+        )
+        .await?;
+
+        Ok(true)
+    }
+
+    /// Process incoming Helius webhook transactions.
+    /// Returns the number of genuinely new transactions processed.
+    pub async fn process_helius_webhook(
+        &self,
+        transactions: Vec<HeliusTransaction>,
+    ) -> Result<usize, AppError> {
+        self.process_webhook(transactions.into_iter().map(Into::into).collect())
+            .await
+    }
+
+    /// Process incoming QuickNode webhook events.
+    /// Returns the number of genuinely new transactions processed.
+    pub async fn process_quicknode_webhook(
+        &self,
+        events: Vec<QuickNodeWebhookEvent>,
+    ) -> Result<usize, AppError> {
+        self.process_webhook(events.into_iter().map(Into::into).collect())
+            .await
+    }
 }
 
 /// Extract the attempt blockhash from a blockchain error, if present.
@@ -1360,6 +3058,82 @@ fn extract_blockhash_from_error(error: &AppError) -> Option<String> {
     }
 }
 
+/// Compare the transfers observed on-chain with what the request asked for.
+/// Returns a description of the first discrepancy, if any.
+///
+/// Every recipient must be credited exactly what was requested. When the
+/// mint withheld a Token-2022 transfer fee, a credit may fall short of the
+/// request by that fee, but the credits together must equal what was sent
+/// less the recorded fee. A credit whose amount was not reported cannot be
+/// verified and counts as a discrepancy. Confidential amounts are encrypted
+/// and cannot be checked.
+fn reconcile_transfers(request: &TransferRequest, observed: &[ObservedTransfer]) -> Option<String> {
+    let expected: Vec<(&str, u64)> = match &request.transfer_details {
+        TransferType::Public { amount } => vec![(request.to_address.as_str(), *amount)],
+        TransferType::PublicBatch { recipients } => recipients
+            .iter()
+            .map(|(recipient, amount)| (recipient.as_str(), *amount))
+            .collect(),
+        TransferType::Confidential { .. } => return None,
+    };
+    let mint = request.token_mint.as_deref();
+    let fee = match (mint, request.token_sent_amount, request.token_transfer_fee) {
+        (Some(_), Some(sent), Some(fee)) => Some((sent, fee)),
+        _ => None,
+    };
+
+    let mut totals: HashMap<&str, u64> = HashMap::new();
+    for (recipient, amount) in expected {
+        *totals.entry(recipient).or_default() += amount;
+    }
+
+    let mut total_received: u128 = 0;
+    for (recipient, requested) in totals {
+        let credits: Vec<Option<u64>> = observed
+            .iter()
+            .filter(|t| t.recipient == recipient && t.mint.as_deref() == mint)
+            .map(|t| t.amount)
+            .collect();
+        if credits.is_empty() {
+            return Some(format!("recipient {} was not paid", recipient));
+        }
+        let Some(received) = credits
+            .into_iter()
+            .map(|c| c.map(u128::from))
+            .sum::<Option<u128>>()
+        else {
+            return Some(format!(
+                "amount credited to recipient {} was not reported",
+                recipient
+            ));
+        };
+        let requested = u128::from(requested);
+        let matches = match fee {
+            None => received == requested,
+            Some(_) => received <= requested,
+        };
+        if !matches {
+            return Some(format!(
+                "recipient {} received {}, expected {}",
+                recipient, received, requested
+            ));
+        }
+        total_received += received;
+    }
+
+    if let Some((sent, fee)) = fee {
+        let expected = u128::from(sent).saturating_sub(u128::from(fee));
+        if total_received != expected {
+            return Some(format!(
+                "recipients received {} in total, expected {} after a {} transfer fee",
+                total_received, expected, fee
+            ));
+        }
+    }
+
+    None
+}
+
 fn validate_checkout_session_request(
     request: &CreateCheckoutSessionRequest,
 ) -> Result<(), AppError> {
@@ -1446,12 +3220,12 @@ fn validate_checkout_transfer_matches_session(
     }
 
     match &payload.transfer_details {
-        TransferType::Public { amount } if *amount == session.amount => Ok(()),
+        TransferType::Public { .. } if payload.public_amount() == Some(session.amount) => Ok(()),
         TransferType::Public { .. } => Err(AppError::Validation(ValidationError::InvalidField {
             field: "amount".to_string(),
             message: "Transfer amount must match checkout session amount".to_string(),
         })),
-        TransferType::Confidential { .. } => {
+        TransferType::PublicBatch { .. } | TransferType::Confidential { .. } => {
             Err(AppError::Validation(ValidationError::InvalidField {
                 field: "transfer_details".to_string(),
                 message: "Checkout sessions require public SOL or SPL token transfer payloads"
@@ -1471,12 +3245,17 @@ fn checkout_status_from_transfer(
 
     match transfer.blockchain_status {
         BlockchainStatus::Confirmed => CheckoutSessionStatus::Settled,
-        BlockchainStatus::Failed | BlockchainStatus::Expired => CheckoutSessionStatus::Failed,
+        BlockchainStatus::Rejected => CheckoutSessionStatus::Rejected,
+        BlockchainStatus::Failed
+        | BlockchainStatus::Unreconciled
+        | BlockchainStatus::Expired
+        | BlockchainStatus::Cancelled => CheckoutSessionStatus::Failed,
         BlockchainStatus::Pending
         | BlockchainStatus::Received
         | BlockchainStatus::PendingSubmission
         | BlockchainStatus::Processing
-        | BlockchainStatus::Submitted => CheckoutSessionStatus::TransferSubmitted,
+        | BlockchainStatus::Submitted
+        | BlockchainStatus::AwaitingApproval => CheckoutSessionStatus::TransferSubmitted,
     }
     .or_expired(expires_at)
 }
@@ -1504,7 +3283,10 @@ fn audit_final_decision(transfer: &TransferRequest) -> AuditFinalDecision {
     }
     if matches!(
         transfer.blockchain_status,
-        BlockchainStatus::Failed | BlockchainStatus::Expired
+        BlockchainStatus::Failed
+            | BlockchainStatus::Unreconciled
+            | BlockchainStatus::Expired
+            | BlockchainStatus::Cancelled
     ) {
         return AuditFinalDecision::FailedOrExpired;
     }
@@ -1563,16 +3345,1150 @@ mod tests {
 
     #[test]
     fn test_calculate_backoff() {
-        assert_eq!(calculate_backoff(0), 1);
-        assert_eq!(calculate_backoff(1), 2);
-        assert_eq!(calculate_backoff(2), 4);
-        assert_eq!(calculate_backoff(3), 8);
-        assert_eq!(calculate_backoff(4), 16);
-        assert_eq!(calculate_backoff(5), 32);
-        assert_eq!(calculate_backoff(6), 64);
-        assert_eq!(calculate_backoff(7), 128);
-        assert_eq!(calculate_backoff(8), 256);
-        assert_eq!(calculate_backoff(9), 256); // Capped at 2^8
-        assert_eq!(calculate_backoff(10), 256);
+        let config = BlockchainRetryConfig::default();
+        assert_eq!(config.backoff_cap(0), 1);
+        assert_eq!(config.backoff_cap(1), 2);
+        assert_eq!(config.backoff_cap(2), 4);
+        assert_eq!(config.backoff_cap(3), 8);
+        assert_eq!(config.backoff_cap(4), 16);
+        assert_eq!(config.backoff_cap(5), 32);
+        assert_eq!(config.backoff_cap(6), 64);
+        assert_eq!(config.backoff_cap(7), 128);
+        assert_eq!(config.backoff_cap(8), 256);
+        assert_eq!(config.backoff_cap(9), DEFAULT_MAX_BACKOFF_SECS); // Capped at 300s
+        assert_eq!(config.backoff_cap(10), DEFAULT_MAX_BACKOFF_SECS);
+        assert_eq!(config.backoff_cap(i32::MAX), DEFAULT_MAX_BACKOFF_SECS);
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_within_cap() {
+        let config = BlockchainRetryConfig::default();
+        for retry_count in 0..=10 {
+            let cap = config.backoff_cap(retry_count);
+            let samples: Vec<i64> = (0..200).map(|_| config.backoff(retry_count)).collect();
+            assert!(samples.iter().all(|backoff| (0..=cap).contains(backoff)));
+            if cap >= 16 {
+                // Jittered, not the deterministic cap every time
+                assert!(samples.iter().any(|backoff| *backoff != cap));
+            }
+        }
+    }
+
+    #[test]
+    fn test_retry_config_overrides_defaults() {
+        let config = BlockchainRetryConfig {
+            max_attempts: 3,
+            max_backoff_secs: 20,
+        };
+        assert_ne!(config, BlockchainRetryConfig::default());
+        assert_eq!(config.backoff_cap(3), 8);
+        assert_eq!(config.backoff_cap(5), 20);
+        assert_eq!(config.backoff_cap(10), 20);
+        assert_eq!(config.backoff_cap(100), 20);
+        assert!((0..100).all(|_| config.backoff(10) <= 20));
+    }
+
+    #[test]
+    fn test_not_found_action_blockhash_mode() {
+        assert_eq!(NotFoundAction::decide(true, false), NotFoundAction::Wait);
+        assert_eq!(NotFoundAction::decide(false, false), NotFoundAction::Expire);
+    }
+
+    #[test]
+    fn test_not_found_action_durable_nonce_never_expires() {
+        assert_eq!(NotFoundAction::decide(true, true), NotFoundAction::Wait);
+        assert_eq!(
+            NotFoundAction::decide(false, true),
+            NotFoundAction::Resubmit
+        );
+    }
+
+    mod webhook_dedup_tests {
+        use super::*;
+        use crate::domain::HeliusNativeTransfer;
+        use crate::test_utils::{
+            MockBlockchainClient, MockComplianceProvider, MockDatabaseClient, queue_transfer,
+            transfer_request,
+        };
+
+        fn service(db: Arc<MockDatabaseClient>) -> AppService {
+            AppService::new(
+                db as _,
+                Arc::new(MockBlockchainClient::new()) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            )
+        }
+
+        /// Create a transfer awaiting confirmation under `signature`
+        async fn submitted_transfer(db: &MockDatabaseClient, signature: &str) -> String {
+            let tr = db.submit_transfer(&transfer_request(10)).await.unwrap();
+            mark_submitted(db, &tr.id, signature).await;
+            tr.id
+        }
+
+        async fn mark_submitted(db: &MockDatabaseClient, id: &str, signature: &str) {
+            db.update_blockchain_status(
+                id,
+                BlockchainStatus::Submitted,
+                Some(signature),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        fn helius_tx(signature: &str) -> HeliusTransaction {
+            HeliusTransaction {
+                transaction_type: "TRANSFER".to_string(),
+                signature: signature.to_string(),
+                transaction_error: None,
+                source: "SYSTEM_PROGRAM".to_string(),
+                native_transfers: None,
+                token_transfers: None,
+                account_data: Vec::new(),
+            }
+        }
+
+        /// Enhanced payload paying `lamports` to `recipient`
+        fn enhanced_tx(signature: &str, recipient: &str, lamports: u64) -> HeliusTransaction {
+            HeliusTransaction {
+                native_transfers: Some(vec![HeliusNativeTransfer {
+                    from_user_account: "AddressA".to_string(),
+                    to_user_account: recipient.to_string(),
+                    amount: lamports,
+                }]),
+                ..helius_tx(signature)
+            }
+        }
+
+        fn quicknode_event(signature: &str) -> QuickNodeWebhookEvent {
+            QuickNodeWebhookEvent {
+                signature: signature.to_string(),
+                slot: Some(1),
+                block_time: None,
+                err: None,
+                meta: None,
+            }
+        }
+
+        #[tokio::test]
+        async fn test_duplicate_helius_batch_not_reprocessed() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = submitted_transfer(&db, "sig_helius").await;
+            let service = service(db.clone());
+
+            let batch = vec![helius_tx("sig_helius"), helius_tx("sig_helius")];
+            assert_eq!(
+                service.process_helius_webhook(batch.clone()).await.unwrap(),
+                1
+            );
+
+            // Re-delivery racing a status update must not be counted again
+            mark_submitted(&db, &id, "sig_helius").await;
+            assert_eq!(service.process_helius_webhook(batch).await.unwrap(), 0);
+        }
+
+        #[tokio::test]
+        async fn test_duplicate_quicknode_batch_not_reprocessed() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = submitted_transfer(&db, "sig_qn").await;
+            let service = service(db.clone());
+
+            let batch = vec![quicknode_event("sig_qn")];
+            assert_eq!(
+                service
+                    .process_quicknode_webhook(batch.clone())
+                    .await
+                    .unwrap(),
+                1
+            );
+
+            mark_submitted(&db, &id, "sig_qn").await;
+            assert_eq!(service.process_quicknode_webhook(batch).await.unwrap(), 0);
+        }
+
+        #[tokio::test]
+        async fn test_unmatched_event_not_remembered() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let service = service(db.clone());
+
+            // Arrives before the transfer is known, so nothing is processed
+            let batch = vec![helius_tx("sig_early")];
+            assert_eq!(
+                service.process_helius_webhook(batch.clone()).await.unwrap(),
+                0
+            );
+
+            submitted_transfer(&db, "sig_early").await;
+            assert_eq!(service.process_helius_webhook(batch).await.unwrap(), 1);
+        }
+
+        #[tokio::test]
+        async fn test_only_final_confidential_transaction_confirms() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let request = SubmitTransferRequest {
+                transfer_details: TransferType::Confidential {
+                    new_decryptable_available_balance: "balance".to_string(),
+                    equality_proof: "equality".to_string(),
+                    ciphertext_validity_proof: "validity".to_string(),
+                    range_proof: "range".to_string(),
+                    auditor_elgamal_pubkey: None,
+                    fee_sigma_proof: None,
+                    fee_ciphertext_validity_proof: None,
+                },
+                token_mint: Some("MintAddress".to_string()),
+                ..transfer_request(11)
+            };
+            let id = queue_transfer(db.as_ref(), &request).await;
+            let service = service(db.clone());
+            assert_eq!(service.process_pending_submissions(10, 1).await.unwrap(), 1);
+
+            // Every transaction of the flow is stored
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.proof_signatures.len(), 3);
+            let final_signature = transfer.blockchain_signature.unwrap();
+            assert!(!transfer.proof_signatures.contains(&final_signature));
+
+            // Confirming proof transactions leaves the transfer submitted
+            let proofs = &transfer.proof_signatures;
+            assert_eq!(
+                service
+                    .process_helius_webhook(vec![helius_tx(&proofs[0])])
+                    .await
+                    .unwrap(),
+                0
+            );
+            assert_eq!(
+                service
+                    .process_quicknode_webhook(vec![quicknode_event(&proofs[1])])
+                    .await
+                    .unwrap(),
+                0
+            );
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Submitted);
+
+            // The final transfer confirms it
+            assert_eq!(
+                service
+                    .process_quicknode_webhook(vec![quicknode_event(&final_signature)])
+                    .await
+                    .unwrap(),
+                1
+            );
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Confirmed);
+        }
+
+        #[tokio::test]
+        async fn test_enhanced_payload_matching_request_confirms() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = submitted_transfer(&db, "sig_match").await;
+            let service = service(db.clone());
+
+            let batch = vec![enhanced_tx("sig_match", "AddressB", 1_000)];
+            assert_eq!(service.process_helius_webhook(batch).await.unwrap(), 1);
+
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Confirmed);
+        }
+
+        #[tokio::test]
+        async fn test_enhanced_payload_recipient_mismatch_unreconciles_transfer() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = submitted_transfer(&db, "sig_tampered").await;
+            let service = service(db.clone());
+
+            let batch = vec![enhanced_tx("sig_tampered", "AddressEvil", 1_000)];
+            assert_eq!(service.process_helius_webhook(batch).await.unwrap(), 1);
+
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Unreconciled);
+            let error = transfer.blockchain_last_error.unwrap();
+            assert!(error.contains("AddressB"));
+
+            // The funds already moved, so the transfer cannot be resubmitted
+            assert!(matches!(
+                service.retry_blockchain_submission(&id).await,
+                Err(AppError::Validation(ValidationError::InvalidField { message, .. }))
+                    if message.contains("could not be reconciled")
+            ));
+        }
+
+        #[test]
+        fn test_reconcile_token_amounts() {
+            let request = TransferRequest {
+                to_address: "AddressB".to_string(),
+                transfer_details: TransferType::Public { amount: 1_000 },
+                token_mint: Some("MintX".to_string()),
+                ..Default::default()
+            };
+            let credit = |mint: &str, amount: Option<u64>| {
+                vec![ObservedTransfer {
+                    recipient: "AddressB".to_string(),
+                    mint: Some(mint.to_string()),
+                    amount,
+                }]
+            };
+
+            // Without a transfer fee the credit must match exactly
+            assert!(reconcile_transfers(&request, &credit("MintX", Some(1_000))).is_none());
+            assert!(reconcile_transfers(&request, &credit("MintX", Some(990))).is_some());
+            assert!(reconcile_transfers(&request, &credit("MintX", Some(1_001))).is_some());
+            assert!(reconcile_transfers(&request, &credit("MintY", Some(1_000))).is_some());
+
+            // An unreported amount cannot be verified
+            assert!(reconcile_transfers(&request, &credit("MintX", None)).is_some());
+
+            // A withheld fee may only reduce the credit by the recorded fee
+            let with_fee = TransferRequest {
+                token_sent_amount: Some(1_000),
+                token_transfer_fee: Some(10),
+                ..request.clone()
+            };
+            assert!(reconcile_transfers(&with_fee, &credit("MintX", Some(990))).is_none());
+            assert!(reconcile_transfers(&with_fee, &credit("MintX", Some(985))).is_some());
+            assert!(reconcile_transfers(&with_fee, &credit("MintX", Some(1_000))).is_some());
+        }
+
+        #[test]
+        fn test_reconcile_batch_paying_recipient_twice() {
+            let request = TransferRequest {
+                to_address: "AddressB".to_string(),
+                transfer_details: TransferType::PublicBatch {
+                    recipients: vec![
+                        ("AddressB".to_string(), 400),
+                        ("AddressC".to_string(), 100),
+                        ("AddressB".to_string(), 600),
+                    ],
+                },
+                token_mint: Some("MintX".to_string()),
+                ..Default::default()
+            };
+            let credit = |recipient: &str, amount: u64| ObservedTransfer {
+                recipient: recipient.to_string(),
+                mint: Some("MintX".to_string()),
+                amount: Some(amount),
+            };
+
+            // The owner's credit is reported once and covers both payments
+            let observed = vec![credit("AddressB", 1_000), credit("AddressC", 100)];
+            assert!(reconcile_transfers(&request, &observed).is_none());
+
+            let short = vec![credit("AddressB", 600), credit("AddressC", 100)];
+            assert!(reconcile_transfers(&request, &short).is_some());
+        }
+    }
+
+    mod cancel_tests {
+        use super::*;
+        use crate::test_utils::{
+            MockBlockchainClient, MockComplianceProvider, MockDatabaseClient, queue_transfer,
+            transfer_request,
+        };
+
+        fn service(db: Arc<MockDatabaseClient>) -> AppService {
+            AppService::new(
+                db as _,
+                Arc::new(MockBlockchainClient::new()) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            )
+        }
+
+        async fn set_status(db: &MockDatabaseClient, id: &str, status: BlockchainStatus) {
+            db.update_blockchain_status(id, status, None, None, None, None)
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_cancel_pending_submission() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue_transfer(db.as_ref(), &transfer_request(0)).await;
+            let service = service(db.clone());
+            let mut events = service.subscribe_status_events();
+
+            let cancelled = service.cancel_transfer(&id).await.unwrap();
+            assert_eq!(cancelled.blockchain_status, BlockchainStatus::Cancelled);
+            assert_eq!(
+                events.try_recv().unwrap().blockchain_status,
+                BlockchainStatus::Cancelled
+            );
+
+            // A cancelled transfer is never handed to the worker
+            assert!(
+                db.get_pending_blockchain_requests(10)
+                    .await
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+
+        #[tokio::test]
+        async fn test_cancel_loses_race_with_worker() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue_transfer(db.as_ref(), &transfer_request(0)).await;
+            let service = service(db.clone());
+
+            // The worker claims the row between the user's read and the cancel
+            let claimed = db.get_pending_blockchain_requests(10).await.unwrap();
+            assert_eq!(claimed.len(), 1);
+
+            let result = service.cancel_transfer(&id).await;
+            assert!(matches!(result, Err(AppError::Conflict(_))));
+
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Processing);
+        }
+
+        #[tokio::test]
+        async fn test_cancel_rejected_after_submission() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue_transfer(db.as_ref(), &transfer_request(0)).await;
+            let service = service(db.clone());
+
+            for status in [BlockchainStatus::Submitted, BlockchainStatus::Confirmed] {
+                set_status(&db, &id, status).await;
+                let result = service.cancel_transfer(&id).await;
+                assert!(matches!(result, Err(AppError::Conflict(_))));
+            }
+
+            let missing = service.cancel_transfer("missing").await;
+            assert!(matches!(
+                missing,
+                Err(AppError::Database(crate::domain::DatabaseError::NotFound(
+                    _
+                )))
+            ));
+        }
+    }
+
+    mod outbound_webhook_tests {
+        use super::*;
+        use crate::test_utils::{
+            MockBlockchainClient, MockComplianceProvider, MockDatabaseClient, transfer_request,
+        };
+
+        fn service(db: Arc<MockDatabaseClient>, default_url: Option<&str>) -> AppService {
+            let config = OutboundWebhookConfig::new("whsec_test")
+                .with_default_url(default_url.map(String::from));
+            AppService::new(
+                db as _,
+                Arc::new(MockBlockchainClient::new()) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            )
+            .with_outbound_webhooks(config)
+        }
+
+        async fn transfer(db: &MockDatabaseClient, callback_url: Option<&str>) -> String {
+            let request = SubmitTransferRequest {
+                callback_url: callback_url.map(String::from),
+                ..transfer_request(30)
+            };
+            db.submit_transfer(&request).await.unwrap().id
+        }
+
+        async fn set_status(service: &AppService, id: &str, status: BlockchainStatus) {
+            service
+                .set_blockchain_status(id, status, Some("sig_1"), None, None, None)
+                .await
+                .unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_notified_statuses_queued_once() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = transfer(&db, Some("https://merchant.example/hook")).await;
+            let service = service(Arc::clone(&db), Some("https://global.example/hook"));
+
+            set_status(&service, &id, BlockchainStatus::Processing).await;
+            set_status(&service, &id, BlockchainStatus::Submitted).await;
+            set_status(&service, &id, BlockchainStatus::Confirmed).await;
+            // Crank and provider webhook can both report the confirmation
+            set_status(&service, &id, BlockchainStatus::Confirmed).await;
+
+            let deliveries = db.webhook_deliveries();
+            let statuses: Vec<BlockchainStatus> = deliveries
+                .iter()
+                .map(|d| d.event.blockchain_status)
+                .collect();
+            assert_eq!(
+                statuses,
+                [BlockchainStatus::Submitted, BlockchainStatus::Confirmed]
+            );
+            // The transfer's own callback URL wins over the global one
+            assert!(
+                deliveries
+                    .iter()
+                    .all(|d| d.url == "https://merchant.example/hook" && d.transfer_id == id)
+            );
+        }
+
+        #[tokio::test]
+        async fn test_global_callback_url_fallback() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = transfer(&db, None).await;
+
+            set_status(
+                &service(Arc::clone(&db), None),
+                &id,
+                BlockchainStatus::Failed,
+            )
+            .await;
+            assert!(db.webhook_deliveries().is_empty());
+
+            let service = service(Arc::clone(&db), Some("https://global.example/hook"));
+            set_status(&service, &id, BlockchainStatus::Failed).await;
+            assert_eq!(
+                db.webhook_deliveries()[0].url,
+                "https://global.example/hook"
+            );
+        }
+    }
+
+    mod submission_tests {
+        use super::*;
+        use crate::app::DEFAULT_PROCESSING_LEASE_SECS;
+        use crate::domain::ProposalStatus;
+        use crate::test_utils::{
+            MockBlockchainClient, MockComplianceProvider, MockDatabaseClient, queue_transfer,
+            transfer_request,
+        };
+
+        /// Submit one approved transfer through `blockchain` and return it
+        async fn submit(blockchain: MockBlockchainClient) -> TransferRequest {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue_transfer(db.as_ref(), &transfer_request(40)).await;
+
+            let service = AppService::new(
+                Arc::clone(&db) as _,
+                Arc::new(blockchain) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            );
+            assert_eq!(service.process_pending_submissions(10, 1).await.unwrap(), 1);
+            db.get_transfer_request(&id).await.unwrap().unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_bundle_id_persisted_on_submission() {
+            let transfer = submit(MockBlockchainClient::new().with_bundle_id("bundle_abc")).await;
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Submitted);
+            assert_eq!(transfer.bundle_id.as_deref(), Some("bundle_abc"));
+            assert_eq!(
+                transfer.blockchain_signature,
+                Some(format!("sig_{}", transfer.id))
+            );
+        }
+
+        #[tokio::test]
+        async fn test_no_bundle_id_without_jito() {
+            let transfer = submit(MockBlockchainClient::new()).await;
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Submitted);
+            assert!(transfer.bundle_id.is_none());
+        }
+
+        #[tokio::test]
+        async fn test_rpc_limiter_spaces_worker_submissions() {
+            tokio::time::pause();
+            let db = Arc::new(MockDatabaseClient::new());
+            for i in 0..3 {
+                queue_transfer(db.as_ref(), &transfer_request(i)).await;
+            }
+
+            let service = AppService::new(
+                Arc::clone(&db) as _,
+                Arc::new(MockBlockchainClient::new()) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            )
+            .with_rpc_limiter(Arc::new(RpcRateLimiter::new(10)));
+
+            // Parallel submissions still wait for their permits, 100ms apart
+            let start = tokio::time::Instant::now();
+            assert_eq!(service.process_pending_submissions(10, 3).await.unwrap(), 3);
+            assert!(start.elapsed() >= std::time::Duration::from_millis(200));
+        }
+
+        /// Queue one approved transfer for submission and return its id
+        async fn queue(db: &MockDatabaseClient, i: usize) -> String {
+            queue_transfer(db, &transfer_request(i)).await
+        }
+
+        fn service(db: &Arc<MockDatabaseClient>) -> AppService {
+            AppService::new(
+                Arc::clone(db) as _,
+                Arc::new(MockBlockchainClient::new()) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            )
+        }
+
+        #[tokio::test]
+        async fn test_concurrent_claims_never_share_a_transfer() {
+            let db = Arc::new(MockDatabaseClient::new());
+            for i in 0..6 {
+                queue(&db, i).await;
+            }
+            let (worker_a, worker_b) = (service(&db), service(&db));
+
+            let (claimed_a, claimed_b) = tokio::join!(
+                worker_a.get_pending_submissions(4),
+                worker_b.get_pending_submissions(4)
+            );
+            let mut ids: Vec<String> = claimed_a
+                .unwrap()
+                .into_iter()
+                .chain(claimed_b.unwrap())
+                .map(|transfer| transfer.id)
+                .collect();
+            assert_eq!(ids.len(), 6);
+            ids.sort();
+            ids.dedup();
+            assert_eq!(ids.len(), 6);
+        }
+
+        /// Claim a queued transfer and leave it behind as a crashed worker
+        /// would, with an optional signature from an earlier attempt
+        async fn abandon(db: &MockDatabaseClient, i: usize, signature: Option<&str>) -> String {
+            let id = queue(db, i).await;
+            db.update_blockchain_status(
+                &id,
+                BlockchainStatus::Processing,
+                signature,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+            let expired = Utc::now() - Duration::seconds(DEFAULT_PROCESSING_LEASE_SECS + 1);
+            db.set_updated_at(&id, expired);
+            id
+        }
+
+        async fn status(db: &MockDatabaseClient, id: &str) -> BlockchainStatus {
+            let transfer = db.get_transfer_request(id).await.unwrap().unwrap();
+            transfer.blockchain_status
+        }
+
+        #[tokio::test]
+        async fn test_abandoned_claim_not_claimed_by_worker() {
+            let db = Arc::new(MockDatabaseClient::new());
+            abandon(&db, 0, None).await;
+            let claimed = service(&db).get_pending_submissions(10).await.unwrap();
+            assert!(claimed.is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_abandoned_transfer_with_confirmed_signature_marked_confirmed() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = abandon(&db, 0, Some("sig_landed")).await;
+            let blockchain = MockBlockchainClient::new()
+                .with_signature_status(TransactionStatus::Confirmed, std::time::Duration::ZERO);
+            let service = AppService::new(
+                Arc::clone(&db) as _,
+                Arc::new(blockchain) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            );
+
+            let reclaimed = service
+                .reclaim_stale_processing_transactions(DEFAULT_PROCESSING_LEASE_SECS, 10)
+                .await
+                .unwrap();
+            assert_eq!(reclaimed, 1);
+            assert_eq!(status(&db, &id).await, BlockchainStatus::Confirmed);
+            // Never handed back to the worker, so it is not submitted again
+            assert!(
+                service
+                    .get_pending_submissions(10)
+                    .await
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+
+        #[tokio::test]
+        async fn test_abandoned_transfer_without_signature_requeued() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = abandon(&db, 0, None).await;
+            let service = service(&db);
+
+            let reclaimed = service
+                .reclaim_stale_processing_transactions(DEFAULT_PROCESSING_LEASE_SECS, 10)
+                .await
+                .unwrap();
+            assert_eq!(reclaimed, 1);
+            assert_eq!(status(&db, &id).await, BlockchainStatus::PendingSubmission);
+
+            let claimed = service.get_pending_submissions(10).await.unwrap();
+            assert_eq!(claimed.len(), 1);
+            assert_eq!(claimed[0].id, id);
+        }
+
+        #[tokio::test]
+        async fn test_claim_within_lease_left_alone() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue(&db, 0).await;
+            let service = service(&db);
+            assert_eq!(service.get_pending_submissions(10).await.unwrap().len(), 1);
+
+            // The worker that claimed it is still within its lease
+            let reclaimed = service
+                .reclaim_stale_processing_transactions(DEFAULT_PROCESSING_LEASE_SECS, 10)
+                .await
+                .unwrap();
+            assert_eq!(reclaimed, 0);
+            assert_eq!(status(&db, &id).await, BlockchainStatus::Processing);
+        }
+
+        #[tokio::test]
+        async fn test_multisig_proposal_awaits_approval_until_executed() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue(&db, 0).await;
+            let blockchain =
+                Arc::new(MockBlockchainClient::new().with_proposal_status(ProposalStatus::Active));
+            let service = AppService::new(
+                Arc::clone(&db) as _,
+                Arc::clone(&blockchain) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            );
+
+            service.process_pending_submissions(10, 1).await.unwrap();
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(
+                transfer.blockchain_status,
+                BlockchainStatus::AwaitingApproval
+            );
+            let proposal = format!("proposal_{}", id);
+            assert_eq!(transfer.blockchain_signature.as_deref(), Some(&*proposal));
+
+            // Approved but not executed yet
+            blockchain.set_proposal_status(ProposalStatus::Approved);
+            assert_eq!(service.process_awaiting_approvals(10).await.unwrap(), 1);
+            assert_eq!(status(&db, &id).await, BlockchainStatus::AwaitingApproval);
+
+            blockchain.set_proposal_status(ProposalStatus::Executed);
+            service.process_awaiting_approvals(10).await.unwrap();
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Confirmed);
+            assert_eq!(transfer.blockchain_signature, Some(proposal));
+            assert_eq!(service.process_awaiting_approvals(10).await.unwrap(), 0);
+        }
+
+        #[tokio::test]
+        async fn test_multisig_proposal_expires_when_undecided() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue(&db, 0).await;
+            let blockchain = Arc::new(
+                MockBlockchainClient::new()
+                    .with_proposal_status(ProposalStatus::Active)
+                    .with_approval_window(chrono::Duration::zero()),
+            );
+            let service = AppService::new(
+                Arc::clone(&db) as _,
+                Arc::clone(&blockchain) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            );
+            service.process_pending_submissions(10, 1).await.unwrap();
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(
+                transfer.blockchain_status,
+                BlockchainStatus::AwaitingApproval
+            );
+            assert!(transfer.blockchain_next_retry_at.is_some());
+
+            service.process_awaiting_approvals(10).await.unwrap();
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Expired);
+            assert!(
+                transfer
+                    .blockchain_last_error
+                    .unwrap()
+                    .contains("not decided within the approval window")
+            );
+            assert_eq!(service.process_awaiting_approvals(10).await.unwrap(), 0);
+        }
+
+        #[tokio::test]
+        async fn test_multisig_proposal_decided_after_window_still_settles() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue(&db, 0).await;
+            let blockchain = Arc::new(
+                MockBlockchainClient::new()
+                    .with_proposal_status(ProposalStatus::Active)
+                    .with_approval_window(chrono::Duration::zero()),
+            );
+            let service = AppService::new(
+                Arc::clone(&db) as _,
+                Arc::clone(&blockchain) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            );
+            service.process_pending_submissions(10, 1).await.unwrap();
+
+            blockchain.set_proposal_status(ProposalStatus::Executed);
+            service.process_awaiting_approvals(10).await.unwrap();
+            assert_eq!(status(&db, &id).await, BlockchainStatus::Confirmed);
+        }
+
+        #[tokio::test]
+        async fn test_rejected_multisig_proposal_fails_transfer() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue(&db, 0).await;
+            let blockchain =
+                Arc::new(MockBlockchainClient::new().with_proposal_status(ProposalStatus::Active));
+            let service = AppService::new(
+                Arc::clone(&db) as _,
+                Arc::clone(&blockchain) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            );
+            service.process_pending_submissions(10, 1).await.unwrap();
+
+            blockchain.set_proposal_status(ProposalStatus::Rejected);
+            service.process_awaiting_approvals(10).await.unwrap();
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Failed);
+            assert_eq!(
+                transfer.blockchain_last_error.as_deref(),
+                Some("Multisig proposal rejected")
+            );
+        }
+
+        fn service_with_signers(
+            db: &Arc<MockDatabaseClient>,
+            blockchain: &Arc<MockBlockchainClient>,
+        ) -> AppService {
+            AppService::new(
+                Arc::clone(db) as _,
+                Arc::clone(blockchain) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            )
+        }
+
+        #[tokio::test]
+        async fn test_retry_reuses_pinned_signer() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = queue(&db, 0).await;
+            let blockchain =
+                Arc::new(MockBlockchainClient::new().with_signers(&["KeyOne", "KeyTwo"]));
+            let service = service_with_signers(&db, &blockchain);
+
+            // The first attempt fails after the transfer was pinned to a key
+            blockchain.set_unreachable(true);
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            let _ = service.process_single_submission(&transfer).await;
+            let transfer = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(transfer.signer_pubkey.as_deref(), Some("KeyOne"));
+
+            // Round-robin would hand out KeyTwo next; the retry keeps KeyOne
+            blockchain.set_unreachable(false);
+            service.process_single_submission(&transfer).await.unwrap();
+            assert_eq!(
+                blockchain.submitted_signers(),
+                vec![Some("KeyOne".to_string())]
+            );
+            assert_eq!(status(&db, &id).await, BlockchainStatus::Submitted);
+        }
+
+        #[tokio::test]
+        async fn test_transfers_pinned_to_selected_signers() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let first = queue(&db, 0).await;
+            let second = queue(&db, 1).await;
+            let blockchain =
+                Arc::new(MockBlockchainClient::new().with_signers(&["KeyOne", "KeyTwo"]));
+            let service = service_with_signers(&db, &blockchain);
+
+            assert_eq!(service.process_pending_submissions(10, 1).await.unwrap(), 2);
+
+            let mut pinned = Vec::new();
+            for id in [&first, &second] {
+                let transfer = db.get_transfer_request(id).await.unwrap().unwrap();
+                pinned.push(transfer.signer_pubkey.unwrap());
+            }
+            pinned.sort();
+            assert_eq!(pinned, vec!["KeyOne", "KeyTwo"]);
+            assert_eq!(blockchain.submitted_signers().len(), 2);
+        }
+    }
+
+    mod reconcile_tests {
+        use super::*;
+        use crate::test_utils::{
+            MockBlockchainClient, MockComplianceProvider, MockDatabaseClient, transfer_request,
+        };
+
+        /// Create a transfer submitted as `sig_submitted` on blockhash `bh_submitted`
+        async fn submitted_transfer(db: &MockDatabaseClient) -> String {
+            let tr = db.submit_transfer(&transfer_request(30)).await.unwrap();
+            db.update_compliance_status(&tr.id, ComplianceStatus::Approved)
+                .await
+                .unwrap();
+            db.update_blockchain_status(
+                &tr.id,
+                BlockchainStatus::Submitted,
+                Some("sig_submitted"),
+                None,
+                None,
+                Some("bh_submitted"),
+            )
+            .await
+            .unwrap();
+            tr.id
+        }
+
+        async fn reconcile(blockchain: MockBlockchainClient) -> TransferRequest {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = submitted_transfer(&db).await;
+            let service = AppService::new(
+                db as _,
+                Arc::new(blockchain) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            );
+            service.reconcile_transfer(&id).await.unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_reconcile_confirmed() {
+            let blockchain = MockBlockchainClient::new()
+                .with_signature_status(TransactionStatus::Finalized, std::time::Duration::ZERO);
+            let transfer = reconcile(blockchain).await;
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Confirmed);
+            assert_eq!(
+                transfer.blockchain_signature.as_deref(),
+                Some("sig_submitted")
+            );
+        }
+
+        #[tokio::test]
+        async fn test_reconcile_failed() {
+            let blockchain = MockBlockchainClient::new().with_signature_status(
+                TransactionStatus::Failed("InsufficientFunds".to_string()),
+                std::time::Duration::ZERO,
+            );
+            let transfer = reconcile(blockchain).await;
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Failed);
+            assert!(
+                transfer
+                    .blockchain_last_error
+                    .unwrap()
+                    .contains("InsufficientFunds")
+            );
+        }
+
+        #[tokio::test]
+        async fn test_reconcile_not_found_with_valid_blockhash_stays_submitted() {
+            let blockchain = MockBlockchainClient::new()
+                .with_signature_not_found()
+                .with_blockhash_valid(true);
+            let transfer = reconcile(blockchain).await;
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Submitted);
+        }
+
+        #[tokio::test]
+        async fn test_reconcile_not_found_with_expired_blockhash_expires() {
+            let blockchain = MockBlockchainClient::new()
+                .with_signature_not_found()
+                .with_blockhash_valid(false);
+            let transfer = reconcile(blockchain).await;
+            assert_eq!(transfer.blockchain_status, BlockchainStatus::Expired);
+        }
+
+        #[tokio::test]
+        async fn test_reconcile_rejects_unknown_and_unsubmitted_transfers() {
+            let db = Arc::new(MockDatabaseClient::new());
+            let id = submitted_transfer(&db).await;
+            db.update_blockchain_status(&id, BlockchainStatus::Confirmed, None, None, None, None)
+                .await
+                .unwrap();
+            let service = AppService::new(
+                db as _,
+                Arc::new(MockBlockchainClient::new()) as _,
+                Arc::new(MockComplianceProvider::new()) as _,
+            );
+
+            assert!(matches!(
+                service.reconcile_transfer(&id).await,
+                Err(AppError::Conflict(_))
+            ));
+            assert!(matches!(
+                service.reconcile_transfer("missing").await,
+                Err(AppError::Database(crate::domain::DatabaseError::NotFound(
+                    _
+                )))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_screening_order_overrides() {
+        let overrides =
+            parse_screening_order_overrides(" mintA:provider_first, mintB:blocklist_first ,")
+                .unwrap();
+        assert_eq!(overrides["mintA"], ScreeningOrder::ProviderFirst);
+        assert_eq!(overrides["mintB"], ScreeningOrder::BlocklistFirst);
+        assert!(parse_screening_order_overrides("").unwrap().is_empty());
+
+        assert!(parse_screening_order_overrides("mintA").is_err());
+        assert!(parse_screening_order_overrides(":provider_first").is_err());
+        assert!(parse_screening_order_overrides("mintA:fastest").is_err());
+    }
+
+    mod screening_order_tests {
+        use super::*;
+        use crate::test_utils::{
+            MockBlockchainClient, MockComplianceProvider, MockDatabaseClient, transfer_request,
+        };
+
+        const HIGH_VALUE_MINT: &str = "HighValueMint111";
+
+        fn request(token_mint: Option<&str>) -> SubmitTransferRequest {
+            SubmitTransferRequest {
+                token_mint: token_mint.map(str::to_string),
+                ..transfer_request(60)
+            }
+        }
+
+        /// Service screening `HIGH_VALUE_MINT` provider first and everything
+        /// else blocklist first
+        fn service(
+            compliance: &Arc<MockComplianceProvider>,
+            blocklisted: &[&str],
+            allowlisted: &[&str],
+        ) -> AppService {
+            let policy = ScreeningPolicy {
+                default_order: ScreeningOrder::BlocklistFirst,
+                mint_orders: HashMap::from([(
+                    HIGH_VALUE_MINT.to_string(),
+                    ScreeningOrder::ProviderFirst,
+                )]),
+            };
+            AppService::with_blocklist(
+                Arc::new(MockDatabaseClient::new()) as _,
+                Arc::new(MockBlockchainClient::new()) as _,
+                Arc::clone(compliance) as _,
+                Arc::new(BlocklistManager::in_memory(blocklisted)),
+            )
+            .with_allowlist(Arc::new(AllowlistManager::in_memory(allowlisted)))
+            .with_screening_policy(policy)
+        }
+
+        #[test]
+        fn test_order_for_mint() {
+            let policy = ScreeningPolicy {
+                default_order: ScreeningOrder::ProviderFirst,
+                mint_orders: HashMap::from([("mintA".to_string(), ScreeningOrder::BlocklistFirst)]),
+            };
+            assert_eq!(policy.order_for(None), ScreeningOrder::ProviderFirst);
+            assert_eq!(
+                policy.order_for(Some("mintA")),
+                ScreeningOrder::BlocklistFirst
+            );
+            assert_eq!(
+                policy.order_for(Some("mintB")),
+                ScreeningOrder::ProviderFirst
+            );
+            assert_eq!(
+                ScreeningPolicy::default().order_for(Some("mintA")),
+                ScreeningOrder::BlocklistFirst
+            );
+        }
+
+        #[tokio::test]
+        async fn test_blocklisted_recipient_rejected_in_either_order() {
+            for (token_mint, external_calls) in [(None, 0), (Some(HIGH_VALUE_MINT), 1)] {
+                let compliance = Arc::new(MockComplianceProvider::new());
+                let service = service(&compliance, &["AddressB"], &[]);
+
+                let screening = service
+                    .screen_single_transfer(&request(token_mint))
+                    .await
+                    .unwrap();
+
+                // Same decision; only provider-first pays for the external check
+                assert!(matches!(
+                    screening,
+                    Screening::Blocklisted { ref address, .. } if address == "AddressB"
+                ));
+                assert_eq!(compliance.call_count(), external_calls, "{:?}", token_mint);
+            }
+        }
+
+        #[tokio::test]
+        async fn test_clean_transfer_approved_in_either_order() {
+            for token_mint in [None, Some(HIGH_VALUE_MINT)] {
+                let compliance = Arc::new(MockComplianceProvider::new());
+                let service = service(&compliance, &[], &[]);
+
+                let screening = service
+                    .screen_single_transfer(&request(token_mint))
+                    .await
+                    .unwrap();
+
+                assert_eq!(screening, Screening::Approved);
+                assert_eq!(compliance.call_count(), 1);
+            }
+        }
+
+        #[tokio::test]
+        async fn test_provider_first_rejection_overrides_allowlist() {
+            let allowlisted = ["AddressA", "AddressB"];
+
+            // Blocklist first: the allowlist skips the provider entirely
+            let compliance = Arc::new(MockComplianceProvider::rejecting());
+            let screening = service(&compliance, &[], &allowlisted)
+                .screen_single_transfer(&request(None))
+                .await
+                .unwrap();
+            assert_eq!(screening, Screening::Allowlisted);
+            assert_eq!(compliance.call_count(), 0);
+
+            // Provider first: its rejection stands for allowlisted parties too
+            let compliance = Arc::new(MockComplianceProvider::rejecting());
+            let screening = service(&compliance, &[], &allowlisted)
+                .screen_single_transfer(&request(Some(HIGH_VALUE_MINT)))
+                .await
+                .unwrap();
+            assert_eq!(
+                screening,
+                Screening::ProviderRejected {
+                    address: "AddressB".to_string(),
+                    category: PROVIDER_REJECTION_CATEGORY.to_string(),
+                }
+            );
+            assert_eq!(compliance.call_count(), 1);
+
+            // Provider first: an approved allowlisted pair is still allowlisted
+            let compliance = Arc::new(MockComplianceProvider::new());
+            let screening = service(&compliance, &[], &allowlisted)
+                .screen_single_transfer(&request(Some(HIGH_VALUE_MINT)))
+                .await
+                .unwrap();
+            assert_eq!(screening, Screening::Allowlisted);
+            assert_eq!(compliance.call_count(), 1);
+        }
+
+        #[tokio::test]
+        async fn test_allowlisted_and_blocklisted_party_same_decision_in_either_order() {
+            for (token_mint, external_calls) in [(None, 0), (Some(HIGH_VALUE_MINT), 1)] {
+                let compliance = Arc::new(MockComplianceProvider::new());
+                let service = service(&compliance, &["AddressB"], &["AddressA", "AddressB"]);
+
+                let screening = service
+                    .screen_single_transfer(&request(token_mint))
+                    .await
+                    .unwrap();
+
+                // The blocklist wins over the allowlist either way
+                assert!(
+                    matches!(
+                        screening,
+                        Screening::Blocklisted { ref address, .. } if address == "AddressB"
+                    ),
+                    "{:?}",
+                    token_mint
+                );
+                assert_eq!(compliance.call_count(), external_calls, "{:?}", token_mint);
+            }
+        }
     }
 }
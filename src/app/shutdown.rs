@@ -0,0 +1,167 @@
+//! Shutdown reporting.
+//!
+//! On shutdown the server collects how the background worker and crank
+//! drained into a [`ShutdownSummary`], logs it, and exits with
+//! [`ShutdownSummary::exit_code`] so orchestrators can tell a clean stop from
+//! a partial one.
+
+use tracing::{info, warn};
+
+/// Process exit code of a clean shutdown
+pub const EXIT_CLEAN: i32 = 0;
+
+/// Process exit code when a background task panicked or was cancelled
+pub const EXIT_TASK_FAILED: i32 = 70;
+
+/// Process exit code when the worker abandoned in-flight submissions
+pub const EXIT_DRAIN_TIMEOUT: i32 = 75;
+
+/// How a background loop stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrainOutcome {
+    /// Stopped between batches (or was disabled), nothing was in flight
+    Idle,
+    /// Finished the batch that was running when shutdown was signalled
+    Drained,
+    /// The drain timeout elapsed with `abandoned` items still in flight
+    TimedOut { abandoned: usize },
+}
+
+impl DrainOutcome {
+    /// Label used in the shutdown summary
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Drained => "drained",
+            Self::TimedOut { .. } => "timed_out",
+        }
+    }
+}
+
+/// Outcome of a shutdown, reported when the server stops
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShutdownSummary {
+    /// How the worker drained (`None` when it was not started)
+    pub worker: Option<DrainOutcome>,
+    /// How the crank drained (`None` when it was not started)
+    pub crank: Option<DrainOutcome>,
+    /// Background tasks that panicked or were cancelled
+    pub failed_tasks: Vec<&'static str>,
+    /// Whether startup skipped the database migrations. Logged only: a
+    /// server started with `SKIP_MIGRATIONS` still stops cleanly.
+    pub migrations_skipped: bool,
+}
+
+impl ShutdownSummary {
+    /// Whether every task stopped cleanly
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.exit_code() == EXIT_CLEAN
+    }
+
+    /// Process exit code for this shutdown. When both occurred, a failed
+    /// task takes precedence over a drain timeout.
+    #[must_use]
+    pub fn exit_code(&self) -> i32 {
+        let timed_out = [self.worker, self.crank]
+            .into_iter()
+            .flatten()
+            .any(|outcome| matches!(outcome, DrainOutcome::TimedOut { .. }));
+        if !self.failed_tasks.is_empty() {
+            EXIT_TASK_FAILED
+        } else if timed_out {
+            EXIT_DRAIN_TIMEOUT
+        } else {
+            EXIT_CLEAN
+        }
+    }
+
+    /// Log the summary as one structured event
+    pub fn log(&self) {
+        let abandoned = match self.worker {
+            Some(DrainOutcome::TimedOut { abandoned }) => abandoned,
+            _ => 0,
+        };
+        let worker = self
+            .worker
+            .map_or("not_started", |outcome| outcome.as_str());
+        let crank = self.crank.map_or("not_started", |outcome| outcome.as_str());
+        let exit_code = self.exit_code();
+        if exit_code == EXIT_CLEAN {
+            info!(
+                worker,
+                crank,
+                migrations_skipped = self.migrations_skipped,
+                exit_code,
+                "Shutdown summary: clean"
+            );
+        } else {
+            warn!(
+                worker,
+                crank,
+                abandoned,
+                failed_tasks = ?self.failed_tasks,
+                migrations_skipped = self.migrations_skipped,
+                exit_code,
+                "Shutdown summary: incomplete"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_shutdown_exits_zero() {
+        let summary = ShutdownSummary {
+            worker: Some(DrainOutcome::Drained),
+            crank: Some(DrainOutcome::Idle),
+            ..ShutdownSummary::default()
+        };
+        assert_eq!(summary.exit_code(), EXIT_CLEAN);
+        assert!(summary.is_clean());
+
+        // Nothing started is still clean
+        assert!(ShutdownSummary::default().is_clean());
+    }
+
+    #[test]
+    fn test_drain_timeout_exit_code() {
+        let summary = ShutdownSummary {
+            worker: Some(DrainOutcome::TimedOut { abandoned: 3 }),
+            crank: Some(DrainOutcome::Drained),
+            ..ShutdownSummary::default()
+        };
+        assert_eq!(summary.exit_code(), EXIT_DRAIN_TIMEOUT);
+        assert!(!summary.is_clean());
+    }
+
+    #[test]
+    fn test_migrations_skipped_clean_shutdown_exits_zero() {
+        let mut summary = ShutdownSummary {
+            worker: Some(DrainOutcome::Drained),
+            migrations_skipped: true,
+            ..ShutdownSummary::default()
+        };
+        assert_eq!(summary.exit_code(), EXIT_CLEAN);
+        assert!(summary.is_clean());
+
+        summary.worker = Some(DrainOutcome::TimedOut { abandoned: 2 });
+        assert_eq!(summary.exit_code(), EXIT_DRAIN_TIMEOUT);
+    }
+
+    #[test]
+    fn test_exit_code_precedence() {
+        let mut summary = ShutdownSummary {
+            worker: Some(DrainOutcome::TimedOut { abandoned: 1 }),
+            migrations_skipped: true,
+            ..ShutdownSummary::default()
+        };
+        assert_eq!(summary.exit_code(), EXIT_DRAIN_TIMEOUT);
+
+        summary.failed_tasks.push("crank");
+        assert_eq!(summary.exit_code(), EXIT_TASK_FAILED);
+    }
+}
@@ -2,12 +2,28 @@
 
 use std::sync::Arc;
 
-use crate::domain::{BlockchainClient, ComplianceProvider, DatabaseClient};
-use crate::infra::BlocklistManager;
+use metrics_exporter_prometheus::PrometheusHandle;
+
+use crate::domain::{
+    ApiKeyScope, ApiKeyStore, BlockchainClient, ComplianceProvider, DatabaseClient, TransferRequest,
+};
+use crate::infra::alerting::RejectionRateDetector;
+use crate::infra::blockchain::ExplorerLinks;
+use crate::infra::outbound_webhooks::OutboundWebhookConfig;
 use crate::infra::privacy::PrivacyHealthCheckService;
+use crate::infra::{AllowlistManager, BlocklistManager};
 
+use super::balance_monitor::FeePayerBalanceTracker;
 use super::risk_service::RiskService;
-use super::service::AppService;
+use super::rpc_limiter::RpcRateLimiter;
+use super::service::{
+    AppService, BlockchainRetryConfig, DEFAULT_CLOCK_SKEW_TOLERANCE_SECS, ScreeningPolicy,
+    TransferLimits,
+};
+use super::worker::ProcessingPause;
+
+/// Default maximum number of transfers in one bulk submission
+pub const DEFAULT_MAX_BULK_TRANSFERS: usize = 100;
 
 /// Shared application state
 #[derive(Clone)]
@@ -23,12 +39,43 @@ pub struct AppState {
     pub quicknode_webhook_secret: Option<String>,
     /// Admin API key for application-level authentication on /admin routes (optional for local dev)
     pub admin_api_key: Option<String>,
+    /// Scoped API keys for submit and admin routes (empty = open for local dev)
+    pub api_keys: ApiKeyStore,
     /// Privacy health check service for confidential transfers
     pub privacy_service: Option<Arc<PrivacyHealthCheckService>>,
     /// Internal blocklist manager for local address screening
     pub blocklist: Option<Arc<BlocklistManager>>,
+    /// Allowlist of known-good addresses that bypass compliance screening
+    pub allowlist: Option<Arc<AllowlistManager>>,
     /// Risk check service for pre-flight compliance screening
     pub risk_service: Option<Arc<RiskService>>,
+    /// Prometheus recorder handle used to render `/metrics` (optional)
+    pub metrics_handle: Option<PrometheusHandle>,
+    /// Maximum number of transfers accepted by one bulk submission
+    pub max_bulk_transfers: usize,
+    /// Outbound webhook notifications of status changes (optional)
+    pub outbound_webhooks: Option<OutboundWebhookConfig>,
+    /// Latest fee payer balance from the balance monitor (optional)
+    pub fee_payer_balance: Option<FeePayerBalanceTracker>,
+    /// Retry limit and backoff of failed blockchain submissions
+    pub retry_config: BlockchainRetryConfig,
+    /// Limiter of worker and crank RPC calls (optional)
+    pub rpc_limiter: Option<Arc<RpcRateLimiter>>,
+    /// Tolerance for clocks disagreeing with ours, applied to client
+    /// timestamps and crank staleness
+    pub clock_skew_tolerance: chrono::Duration,
+    /// Per-transfer and rolling per-sender amount caps
+    pub transfer_limits: TransferLimits,
+    /// Order of the blocklist and compliance provider checks, per mint
+    pub screening_policy: ScreeningPolicy,
+    /// Mint of public transfers that omit `token_mint` (optional, SOL otherwise)
+    pub default_token_mint: Option<String>,
+    /// Detector alerting operators on compliance rejection spikes (optional)
+    pub rejection_alerts: Option<Arc<RejectionRateDetector>>,
+    /// Runtime pause of the worker and crank, toggled by the admin API
+    pub processing_pause: ProcessingPause,
+    /// Block explorer links added to transfer responses (optional)
+    pub explorer: Option<ExplorerLinks>,
 }
 
 impl AppState {
@@ -74,11 +121,15 @@ impl AppState {
         helius_webhook_secret: Option<String>,
         quicknode_webhook_secret: Option<String>,
     ) -> Self {
-        let service = Arc::new(AppService::new(
-            Arc::clone(&db_client),
-            Arc::clone(&blockchain_client),
-            Arc::clone(&compliance_provider),
-        ));
+        let processing_pause = ProcessingPause::default();
+        let service = Arc::new(
+            AppService::new(
+                Arc::clone(&db_client),
+                Arc::clone(&blockchain_client),
+                Arc::clone(&compliance_provider),
+            )
+            .with_processing_pause(processing_pause.clone()),
+        );
         Self {
             service,
             db_client,
@@ -87,9 +138,24 @@ impl AppState {
             helius_webhook_secret,
             quicknode_webhook_secret,
             admin_api_key: None,
+            api_keys: ApiKeyStore::default(),
             privacy_service: None,
             blocklist: None,
+            allowlist: None,
             risk_service: None,
+            metrics_handle: None,
+            max_bulk_transfers: DEFAULT_MAX_BULK_TRANSFERS,
+            outbound_webhooks: None,
+            fee_payer_balance: None,
+            retry_config: BlockchainRetryConfig::default(),
+            rpc_limiter: None,
+            clock_skew_tolerance: chrono::Duration::seconds(DEFAULT_CLOCK_SKEW_TOLERANCE_SECS),
+            transfer_limits: TransferLimits::default(),
+            screening_policy: ScreeningPolicy::default(),
+            default_token_mint: None,
+            rejection_alerts: None,
+            processing_pause,
+            explorer: None,
         }
     }
 
@@ -100,10 +166,32 @@ impl AppState {
         self
     }
 
+    /// Add scoped API keys to the application state (builder pattern)
+    #[must_use]
+    pub fn with_api_keys(mut self, api_keys: ApiKeyStore) -> Self {
+        self.api_keys = api_keys;
+        self
+    }
+
+    /// API keys enforced by the router: the scoped keys, plus the admin
+    /// API key granted the `admin` scope
+    #[must_use]
+    pub fn effective_api_keys(&self) -> ApiKeyStore {
+        match &self.admin_api_key {
+            Some(key) => self
+                .api_keys
+                .clone()
+                .with_key(key.clone(), [ApiKeyScope::Admin]),
+            None => self.api_keys.clone(),
+        }
+    }
+
     /// Add privacy service to the application state (builder pattern)
+    /// This rebuilds the service so the health check probes it
     #[must_use]
     pub fn with_privacy_service(mut self, privacy_service: Arc<PrivacyHealthCheckService>) -> Self {
         self.privacy_service = Some(privacy_service);
+        self.rebuild_service();
         self
     }
 
@@ -111,21 +199,190 @@ impl AppState {
     /// This rebuilds the service to include blocklist integration
     #[must_use]
     pub fn with_blocklist(mut self, blocklist: Arc<BlocklistManager>) -> Self {
-        // Rebuild the service with blocklist integration
-        self.service = Arc::new(AppService::with_blocklist(
-            Arc::clone(&self.db_client),
-            Arc::clone(&self.blockchain_client),
-            Arc::clone(&self.compliance_provider),
-            Arc::clone(&blocklist),
-        ));
         self.blocklist = Some(blocklist);
+        self.rebuild_service();
+        self
+    }
+
+    /// Add allowlist manager to the application state (builder pattern)
+    /// This rebuilds the service to include allowlist integration
+    #[must_use]
+    pub fn with_allowlist(mut self, allowlist: Arc<AllowlistManager>) -> Self {
+        self.allowlist = Some(allowlist);
+        self.rebuild_service();
+        self
+    }
+
+    /// Enable outbound webhook notifications (builder pattern)
+    /// This rebuilds the service so it queues notified status changes
+    #[must_use]
+    pub fn with_outbound_webhooks(mut self, config: OutboundWebhookConfig) -> Self {
+        self.outbound_webhooks = Some(config);
+        self.rebuild_service();
+        self
+    }
+
+    /// Report the fee payer balance in the verbose health check (builder pattern)
+    /// This rebuilds the service so the health check reads the tracker
+    #[must_use]
+    pub fn with_fee_payer_balance(mut self, tracker: FeePayerBalanceTracker) -> Self {
+        self.fee_payer_balance = Some(tracker);
+        self.rebuild_service();
+        self
+    }
+
+    /// Set the retry limit and backoff of failed blockchain submissions (builder pattern)
+    /// This rebuilds the service so the worker and crank use them
+    #[must_use]
+    pub fn with_retry_config(mut self, retry_config: BlockchainRetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self.rebuild_service();
+        self
+    }
+
+    /// Rate-limit worker submissions and crank status checks (builder pattern)
+    /// This rebuilds the service so the worker and crank share the limiter
+    #[must_use]
+    pub fn with_rpc_limiter(mut self, limiter: Arc<RpcRateLimiter>) -> Self {
+        self.rpc_limiter = Some(limiter);
+        self.rebuild_service();
+        self
+    }
+
+    /// Set the tolerance for clocks disagreeing with ours (builder pattern)
+    /// This rebuilds the service so submissions and the crank use it
+    #[must_use]
+    pub fn with_clock_skew_tolerance(mut self, skew: chrono::Duration) -> Self {
+        self.clock_skew_tolerance = skew;
+        self.rebuild_service();
         self
     }
 
+    /// Cap single transfers and each sender's rolling daily total (builder pattern)
+    /// This rebuilds the service so submissions use them
+    #[must_use]
+    pub fn with_transfer_limits(mut self, limits: TransferLimits) -> Self {
+        self.transfer_limits = limits;
+        self.rebuild_service();
+        self
+    }
+
+    /// Set the order of the blocklist and compliance provider checks (builder pattern)
+    /// This rebuilds the service so submissions are screened in that order
+    #[must_use]
+    pub fn with_screening_policy(mut self, policy: ScreeningPolicy) -> Self {
+        self.screening_policy = policy;
+        self.rebuild_service();
+        self
+    }
+
+    /// Send public transfers that omit `token_mint` as this token (builder pattern)
+    /// This rebuilds the service so submissions resolve to it
+    #[must_use]
+    pub fn with_default_token_mint(mut self, mint: String) -> Self {
+        self.default_token_mint = Some(mint);
+        self.rebuild_service();
+        self
+    }
+
+    /// Alert operators when compliance rejections spike (builder pattern)
+    /// This rebuilds the service so rejections are counted
+    #[must_use]
+    pub fn with_rejection_alerts(mut self, detector: Arc<RejectionRateDetector>) -> Self {
+        self.rejection_alerts = Some(detector);
+        self.rebuild_service();
+        self
+    }
+
+    /// Rebuild the service so it sees the currently configured blocklist,
+    /// allowlist, privacy service, outbound webhooks, fee payer balance,
+    /// retry policy, RPC limiter, expiry tolerance, transfer limits,
+    /// screening policy, default token mint, rejection alerts and processing
+    /// pause
+    fn rebuild_service(&mut self) {
+        let service = match &self.blocklist {
+            Some(blocklist) => AppService::with_blocklist(
+                Arc::clone(&self.db_client),
+                Arc::clone(&self.blockchain_client),
+                Arc::clone(&self.compliance_provider),
+                Arc::clone(blocklist),
+            ),
+            None => AppService::new(
+                Arc::clone(&self.db_client),
+                Arc::clone(&self.blockchain_client),
+                Arc::clone(&self.compliance_provider),
+            ),
+        };
+        let service = match &self.allowlist {
+            Some(allowlist) => service.with_allowlist(Arc::clone(allowlist)),
+            None => service,
+        };
+        let service = match &self.privacy_service {
+            Some(privacy_service) => service.with_privacy_service(Arc::clone(privacy_service)),
+            None => service,
+        };
+        let service = match &self.outbound_webhooks {
+            Some(config) => service.with_outbound_webhooks(config.clone()),
+            None => service,
+        };
+        let service = match &self.fee_payer_balance {
+            Some(tracker) => service.with_fee_payer_balance(tracker.clone()),
+            None => service,
+        };
+        let service = match &self.rpc_limiter {
+            Some(limiter) => service.with_rpc_limiter(Arc::clone(limiter)),
+            None => service,
+        };
+        let service = match &self.rejection_alerts {
+            Some(detector) => service.with_rejection_alerts(Arc::clone(detector)),
+            None => service,
+        };
+        let service = match &self.default_token_mint {
+            Some(mint) => service.with_default_token_mint(mint.clone()),
+            None => service,
+        };
+        self.service = Arc::new(
+            service
+                .with_retry_config(self.retry_config)
+                .with_clock_skew_tolerance(self.clock_skew_tolerance)
+                .with_transfer_limits(self.transfer_limits)
+                .with_screening_policy(self.screening_policy.clone())
+                .with_processing_pause(self.processing_pause.clone()),
+        );
+    }
+
     /// Add risk service to the application state (builder pattern)
     #[must_use]
     pub fn with_risk_service(mut self, risk_service: Arc<RiskService>) -> Self {
         self.risk_service = Some(risk_service);
         self
     }
+
+    /// Add Prometheus recorder handle to the application state (builder pattern)
+    #[must_use]
+    pub fn with_metrics_handle(mut self, metrics_handle: PrometheusHandle) -> Self {
+        self.metrics_handle = Some(metrics_handle);
+        self
+    }
+
+    /// Set the maximum bulk submission size (builder pattern)
+    #[must_use]
+    pub fn with_max_bulk_transfers(mut self, max_bulk_transfers: usize) -> Self {
+        self.max_bulk_transfers = max_bulk_transfers;
+        self
+    }
+
+    /// Add explorer links to transfer responses (builder pattern)
+    #[must_use]
+    pub fn with_explorer(mut self, explorer: ExplorerLinks) -> Self {
+        self.explorer = Some(explorer);
+        self
+    }
+
+    /// Fill in the explorer link of a transfer about to be returned
+    pub fn link_explorer(&self, request: &mut TransferRequest) {
+        if let (Some(explorer), Some(signature)) = (&self.explorer, &request.blockchain_signature) {
+            request.explorer_url = Some(explorer.blockchain_signature_url(signature));
+        }
+    }
 }
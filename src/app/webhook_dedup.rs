@@ -0,0 +1,103 @@
+//! Short-lived record of processed webhook events.
+//!
+//! Helius and QuickNode retry deliveries, so the same event can arrive more
+//! than once, possibly while the first delivery is still being applied.
+//! Events are keyed by `(signature, status)` and claimed before processing,
+//! so a re-delivery inside the TTL is recognized and skipped.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+
+use crate::domain::BlockchainStatus;
+
+/// Default time a processed webhook event is remembered (seconds)
+const DEFAULT_WEBHOOK_DEDUP_TTL_SECS: u64 = 600;
+
+/// Expired entries are swept once the record grows past this size
+const DEDUP_SWEEP_THRESHOLD: usize = 10_000;
+
+type EventKey = (String, BlockchainStatus);
+
+/// TTL-bound set of claimed webhook events, shared between clones
+#[derive(Debug, Clone)]
+pub(crate) struct WebhookDeduplicator {
+    /// Event key -> when the claim expires
+    seen: Arc<DashMap<EventKey, Instant>>,
+    ttl: Duration,
+}
+
+impl Default for WebhookDeduplicator {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_WEBHOOK_DEDUP_TTL_SECS))
+    }
+}
+
+impl WebhookDeduplicator {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            seen: Arc::new(DashMap::new()),
+            ttl,
+        }
+    }
+
+    /// Claim an event for processing.
+    /// Returns `false` if the same event was already claimed within the TTL.
+    pub(crate) fn claim(&self, signature: &str, status: BlockchainStatus) -> bool {
+        let now = Instant::now();
+        if self.seen.len() >= DEDUP_SWEEP_THRESHOLD {
+            self.seen.retain(|_, expires_at| *expires_at > now);
+        }
+
+        match self.seen.entry((signature.to_string(), status)) {
+            Entry::Occupied(entry) if *entry.get() > now => false,
+            Entry::Occupied(mut entry) => {
+                entry.insert(now + self.ttl);
+                true
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(now + self.ttl);
+                true
+            }
+        }
+    }
+
+    /// Release a claim for an event that was not processed, so a later
+    /// delivery of it is not mistaken for a duplicate
+    pub(crate) fn release(&self, signature: &str, status: BlockchainStatus) {
+        self.seen.remove(&(signature.to_string(), status));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_claim_rejected() {
+        let dedup = WebhookDeduplicator::default();
+        assert!(dedup.claim("sig", BlockchainStatus::Confirmed));
+        assert!(!dedup.claim("sig", BlockchainStatus::Confirmed));
+
+        // A different outcome for the same signature is a distinct event
+        assert!(dedup.claim("sig", BlockchainStatus::Failed));
+        assert!(!dedup.claim("sig", BlockchainStatus::Failed));
+    }
+
+    #[test]
+    fn test_released_claim_can_be_reclaimed() {
+        let dedup = WebhookDeduplicator::default();
+        assert!(dedup.claim("sig", BlockchainStatus::Confirmed));
+        dedup.release("sig", BlockchainStatus::Confirmed);
+        assert!(dedup.claim("sig", BlockchainStatus::Confirmed));
+    }
+
+    #[test]
+    fn test_expired_claim_can_be_reclaimed() {
+        let dedup = WebhookDeduplicator::new(Duration::ZERO);
+        assert!(dedup.claim("sig", BlockchainStatus::Confirmed));
+        assert!(dedup.claim("sig", BlockchainStatus::Confirmed));
+    }
+}
@@ -1,14 +1,55 @@
 //! Background worker for processing pending blockchain submissions.
 
+use chrono::Utc;
+use futures_util::{StreamExt, stream};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::time::Duration;
 use tokio::sync::watch;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
 
-use crate::domain::types::TransferType;
-use crate::infra::privacy::PrivacyHealthCheckService;
+use crate::domain::AppError;
+use crate::domain::types::{TransferRequest, TransferType};
+use crate::infra::blockchain::{DEFAULT_CIRCUIT_COOLDOWN, is_circuit_open};
+use crate::infra::privacy::{AnonymitySetHealth, PrivacyAction, PrivacyHealthCheckService};
 
 use super::service::AppService;
+use super::shutdown::DrainOutcome;
+
+/// Default number of submissions processed in parallel per batch
+pub const DEFAULT_WORKER_CONCURRENCY: usize = 4;
+
+/// Default time shutdown waits for in-flight submissions
+pub const DEFAULT_WORKER_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default pause after the blockchain circuit opens, matching the breaker cooldown
+pub const DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF: Duration = DEFAULT_CIRCUIT_COOLDOWN;
+
+/// Default seconds a worker owns a claimed (`processing`) transfer before the
+/// crank treats it as abandoned
+pub const DEFAULT_PROCESSING_LEASE_SECS: i64 = 300;
+
+/// Runtime switch pausing the worker and crank, toggled from the admin API.
+/// While paused both loops keep running but claim and process nothing.
+#[derive(Debug, Clone, Default)]
+pub struct ProcessingPause(Arc<AtomicBool>);
+
+impl ProcessingPause {
+    /// Pause processing. Returns whether this changed the state.
+    pub fn pause(&self) -> bool {
+        !self.0.swap(true, Ordering::SeqCst)
+    }
+
+    /// Resume processing. Returns whether this changed the state.
+    pub fn resume(&self) -> bool {
+        self.0.swap(false, Ordering::SeqCst)
+    }
+
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
 
 /// Configuration for the background worker
 #[derive(Debug, Clone)]
@@ -21,6 +62,12 @@ pub struct WorkerConfig {
     pub enabled: bool,
     /// Whether to apply privacy health checks for confidential transfers
     pub enable_privacy_checks: bool,
+    /// Maximum number of submissions from a batch processed in parallel
+    pub concurrency: usize,
+    /// How long shutdown waits for in-flight submissions to finish
+    pub drain_timeout: Duration,
+    /// How long the worker pauses after the blockchain circuit opens mid-batch
+    pub circuit_open_backoff: Duration,
 }
 
 impl Default for WorkerConfig {
@@ -30,6 +77,9 @@ impl Default for WorkerConfig {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: true,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         }
     }
 }
@@ -40,8 +90,9 @@ pub struct BlockchainRetryWorker {
     config: WorkerConfig,
     shutdown_rx: watch::Receiver<bool>,
     /// Privacy service for anonymity set health checks (used for confidential transfers)
-    #[allow(dead_code)] // Scaffolded for future integration
     privacy_service: Option<Arc<PrivacyHealthCheckService>>,
+    /// Submissions currently being sent to the blockchain
+    in_flight: AtomicUsize,
 }
 
 impl BlockchainRetryWorker {
@@ -56,6 +107,7 @@ impl BlockchainRetryWorker {
             config,
             shutdown_rx,
             privacy_service: None,
+            in_flight: AtomicUsize::new(0),
         }
     }
 
@@ -71,6 +123,7 @@ impl BlockchainRetryWorker {
             config,
             shutdown_rx,
             privacy_service: Some(privacy_service),
+            in_flight: AtomicUsize::new(0),
         }
     }
 
@@ -80,35 +133,75 @@ impl BlockchainRetryWorker {
         self.config.batch_size
     }
 
-    /// Run the worker loop
-    pub async fn run(mut self) {
+    /// Run the worker loop.
+    ///
+    /// On shutdown the worker stops claiming new transfers, but a batch that
+    /// is already running is given up to `drain_timeout` to finish its
+    /// in-flight submissions before the loop returns. Returns how the
+    /// drain went.
+    pub async fn run(mut self) -> DrainOutcome {
         if !self.config.enabled {
             info!("Blockchain retry worker is disabled");
-            return;
+            return DrainOutcome::Idle;
         }
 
         info!(
             poll_interval = ?self.config.poll_interval,
             batch_size = self.config.batch_size,
-            "Starting blockchain retry worker (first poll in {:?})",
-            self.config.poll_interval
+            concurrency = self.config.concurrency,
+            "Starting blockchain retry worker"
         );
 
         loop {
             tokio::select! {
                 _ = tokio::time::sleep(self.config.poll_interval) => {
-                    self.process_batch().await;
+                    if let Some(outcome) = self.process_batch_until_shutdown().await {
+                        return outcome;
+                    }
                 }
                 result = self.shutdown_rx.changed() => {
                     if result.is_ok() && *self.shutdown_rx.borrow() {
                         info!("Blockchain retry worker shutting down");
-                        break;
+                        return DrainOutcome::Idle;
                     }
                 }
             }
         }
     }
 
+    /// Process one batch. If shutdown is signalled meanwhile, wait up to
+    /// `drain_timeout` for in-flight submissions and return the outcome.
+    async fn process_batch_until_shutdown(&self) -> Option<DrainOutcome> {
+        let batch = self.process_batch();
+        tokio::pin!(batch);
+        let mut shutdown_rx = self.shutdown_rx.clone();
+        tokio::select! {
+            _ = &mut batch => return None,
+            Ok(_) = shutdown_rx.wait_for(|shutdown| *shutdown) => {}
+        }
+
+        let in_flight = self.in_flight.load(Ordering::SeqCst);
+        info!(
+            in_flight = in_flight,
+            drain_timeout = ?self.config.drain_timeout,
+            "Blockchain retry worker shutting down - draining in-flight submissions"
+        );
+        match tokio::time::timeout(self.config.drain_timeout, batch).await {
+            Ok(()) => {
+                info!(drained = in_flight, "Drained in-flight submissions");
+                Some(DrainOutcome::Drained)
+            }
+            Err(_) => {
+                let abandoned = self.in_flight.load(Ordering::SeqCst);
+                warn!(
+                    abandoned = abandoned,
+                    "Drain timeout elapsed - abandoning in-flight submissions"
+                );
+                Some(DrainOutcome::TimedOut { abandoned })
+            }
+        }
+    }
+
     /// Execute a single tick of the worker loop (for testing)
     /// This processes one batch without the full loop infrastructure
     pub async fn run_once(&self) {
@@ -118,17 +211,19 @@ impl BlockchainRetryWorker {
         self.process_batch().await;
     }
 
-    /// Process a batch of pending submissions
+    /// Process a batch of pending submissions. Does nothing while
+    /// processing is paused.
     pub async fn process_batch(&self) {
+        if self.service.processing_pause().is_paused() {
+            debug!("Worker paused - skipping batch");
+            return;
+        }
         debug!(
-            "Worker polling for pending submissions (batch_size: {})",
-            self.config.batch_size
+            batch_size = self.config.batch_size,
+            "Worker polling for pending submissions"
         );
-        match self
-            .service
-            .process_pending_submissions(self.config.batch_size)
-            .await
-        {
+
+        match self.process_pending().await {
             Ok(0) => {
                 // No pending items - debug log for troubleshooting
                 debug!("No pending blockchain submissions found");
@@ -142,51 +237,208 @@ impl BlockchainRetryWorker {
         }
     }
 
+    /// Whether confidential transfers in a batch need a privacy health check
+    fn privacy_checks_active(&self) -> bool {
+        self.config.enable_privacy_checks
+            && self
+                .privacy_service
+                .as_ref()
+                .is_some_and(|s| s.config().enabled)
+    }
+
+    /// Sleep for `duration`. Returns `false` if shutdown was signalled first.
+    async fn sleep_unless_shutdown(&self, duration: Duration) -> bool {
+        if duration.is_zero() {
+            return true;
+        }
+        let mut shutdown_rx = self.shutdown_rx.clone();
+        tokio::select! {
+            _ = tokio::time::sleep(duration) => true,
+            Ok(_) = shutdown_rx.wait_for(|shutdown| *shutdown) => false,
+        }
+    }
+
+    /// Process a batch with up to `concurrency` transfers handled at once,
+    /// so one delayed confidential transfer does not hold back the rest of
+    /// the batch. Transfers not yet started when shutdown is signalled are
+    /// released back to the queue.
+    ///
+    /// Once a submission hits the open blockchain circuit, the transfers not
+    /// yet started are released back to the queue and the worker pauses for
+    /// `circuit_open_backoff` instead of retrying each one.
+    async fn process_pending(&self) -> Result<usize, AppError> {
+        let pending_requests = self
+            .service
+            .get_pending_submissions(self.config.batch_size)
+            .await?;
+        let count = pending_requests.len();
+        let concurrency = self.service.submission_concurrency(self.config.concurrency);
+        let privacy_checks = self.privacy_checks_active();
+        let circuit_open = AtomicBool::new(false);
+
+        stream::iter(pending_requests)
+            .for_each_concurrent(concurrency, |request| {
+                let circuit_open = &circuit_open;
+                async move {
+                    if circuit_open.load(Ordering::SeqCst) {
+                        self.release_request(&request, "Skipped: blockchain circuit open")
+                            .await;
+                    } else if self.process_request(&request, privacy_checks).await {
+                        circuit_open.store(true, Ordering::SeqCst);
+                    }
+                }
+            })
+            .await;
+
+        if circuit_open.into_inner() {
+            warn!(
+                backoff = ?self.config.circuit_open_backoff,
+                "Blockchain circuit open - backing off the batch"
+            );
+            self.sleep_unless_shutdown(self.config.circuit_open_backoff)
+                .await;
+        }
+
+        Ok(count)
+    }
+
+    /// Return a claimed transfer to the queue without submitting it
+    async fn release_request(&self, request: &TransferRequest, reason: &str) {
+        if let Err(e) = self.service.release_submission(&request.id, reason).await {
+            error!(transfer_id = %request.id, error = ?e, "Failed to release pending submission");
+        }
+    }
+
+    /// Submit a single pending transfer. With `privacy_checks`, confidential
+    /// transfers are privacy-checked and jittered first; a transfer the check
+    /// delays is put back in the queue until the delay has passed.
+    /// Returns `true` if the submission was short-circuited by the open
+    /// blockchain circuit.
+    #[instrument(skip_all, fields(transfer_id = %request.id, correlation_id = request.correlation_id.as_deref()))]
+    async fn process_request(&self, request: &TransferRequest, privacy_checks: bool) -> bool {
+        if *self.shutdown_rx.borrow() {
+            debug!(transfer_id = %request.id, "Shutdown signalled - leaving submission pending");
+            self.release_request(request, "Skipped: shutting down")
+                .await;
+            return false;
+        }
+
+        if privacy_checks && let Some(health) = self.check_privacy_health(request).await {
+            match health.action {
+                PrivacyAction::Proceed => {}
+                // A transfer that already waited out a scheduled delay or
+                // retry is submitted rather than delayed again
+                PrivacyAction::Delay if request.blockchain_next_retry_at.is_some() => {
+                    debug!(
+                        transfer_id = %request.id,
+                        "Privacy health check: delay already served - submitting"
+                    );
+                }
+                PrivacyAction::Delay => {
+                    let delay = health.recommended_delay_secs.unwrap_or(0);
+                    warn!(
+                        transfer_id = %request.id,
+                        delay_secs = delay,
+                        recent_tx_count = health.recent_tx_count,
+                        holder_count = ?health.holder_count,
+                        anonymity_score = health.anonymity_score,
+                        "Privacy health check: delaying submission for anonymity"
+                    );
+                    // Scheduled rather than slept, so the worker slot is free meanwhile
+                    let retry_at = Utc::now() + chrono::Duration::seconds(delay as i64);
+                    if let Err(e) = self
+                        .service
+                        .defer_submission(
+                            &request.id,
+                            retry_at,
+                            "Privacy delay: anonymity set thin",
+                        )
+                        .await
+                    {
+                        error!(transfer_id = %request.id, error = ?e, "Failed to defer pending submission");
+                    }
+                    return false;
+                }
+                PrivacyAction::Reject => {
+                    let reason = self.privacy_rejection_reason(&health);
+                    if let Err(e) = self.service.fail_submission(&request.id, &reason).await {
+                        error!(transfer_id = %request.id, error = ?e, "Failed to reject submission");
+                    }
+                    return false;
+                }
+            }
+
+            // Jitter every confidential submission so its timing does not
+            // reveal when it was dequeued
+            if let Some(privacy_service) = &self.privacy_service {
+                let jitter = privacy_service.submission_jitter();
+                debug!(
+                    transfer_id = %request.id,
+                    jitter_ms = jitter.as_millis() as u64,
+                    "Applying confidential submission jitter"
+                );
+                if !self.sleep_unless_shutdown(jitter).await {
+                    info!(
+                        transfer_id = %request.id,
+                        "Shutdown during submission jitter - leaving submission pending"
+                    );
+                    self.release_request(request, "Skipped: shutting down")
+                        .await;
+                    return false;
+                }
+            }
+        }
+
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        let result = self.service.process_single_submission(request).await;
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+        match result {
+            Ok(()) => false,
+            Err(e) if is_circuit_open(&e) => {
+                debug!(transfer_id = %request.id, "Blockchain circuit open - submission released");
+                true
+            }
+            Err(e) => {
+                error!(transfer_id = %request.id, error = ?e, "Failed to process pending submission");
+                false
+            }
+        }
+    }
+
     /// Check privacy health for confidential transfers
     ///
-    /// Returns the recommended delay in seconds, or 0 for immediate processing.
-    /// Only applies to confidential transfers when privacy checks are enabled.
-    #[allow(dead_code)] // Scaffolded for future integration
-    async fn check_privacy_health(&self, request: &crate::domain::TransferRequest) -> u64 {
+    /// Returns `None` when the check does not apply: public transfers,
+    /// privacy checks disabled, or no token mint.
+    async fn check_privacy_health(&self, request: &TransferRequest) -> Option<AnonymitySetHealth> {
         // Only check confidential transfers
         let is_confidential = matches!(request.transfer_details, TransferType::Confidential { .. });
 
         if !is_confidential || !self.config.enable_privacy_checks {
-            return 0;
+            return None;
         }
 
-        let privacy_service = match &self.privacy_service {
-            Some(s) => s,
-            None => return 0,
-        };
-
-        let token_mint = match &request.token_mint {
-            Some(mint) => mint,
-            None => return 0,
-        };
+        let privacy_service = self.privacy_service.as_ref()?;
+        let token_mint = request.token_mint.as_ref()?;
 
         debug!(
-            request_id = %request.id,
+            transfer_id = %request.id,
             token_mint = %token_mint,
             "Checking privacy health for confidential transfer"
         );
 
-        let health = privacy_service.check_health(token_mint).await;
+        Some(privacy_service.check_health(token_mint).await)
+    }
 
-        if health.is_healthy {
-            0
-        } else {
-            let delay = health.recommended_delay_secs.unwrap_or(0);
-            if delay > 0 {
-                warn!(
-                    request_id = %request.id,
-                    delay_secs = delay,
-                    recent_tx_count = health.recent_tx_count,
-                    "Privacy health check: delaying submission for anonymity"
-                );
-            }
-            delay
-        }
+    /// Failure reason recorded for a transfer rejected by the privacy check
+    fn privacy_rejection_reason(&self, health: &AnonymitySetHealth) -> String {
+        let min_acceptable = self
+            .privacy_service
+            .as_ref()
+            .map_or(0, |s| s.config().min_acceptable_tx_count);
+        format!(
+            "Privacy check rejected transfer: anonymity set too thin for mint {} ({} recent transactions, minimum {})",
+            health.token_mint, health.recent_tx_count, min_acceptable
+        )
     }
 }
 
@@ -194,7 +446,7 @@ impl BlockchainRetryWorker {
 pub fn spawn_worker(
     service: Arc<AppService>,
     config: WorkerConfig,
-) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+) -> (tokio::task::JoinHandle<DrainOutcome>, watch::Sender<bool>) {
     let (shutdown_tx, shutdown_rx) = watch::channel(false);
     let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
     let handle = tokio::spawn(worker.run());
@@ -206,7 +458,7 @@ pub fn spawn_worker_with_privacy(
     service: Arc<AppService>,
     config: WorkerConfig,
     privacy_service: Arc<PrivacyHealthCheckService>,
-) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+) -> (tokio::task::JoinHandle<DrainOutcome>, watch::Sender<bool>) {
     let (shutdown_tx, shutdown_rx) = watch::channel(false);
     let worker =
         BlockchainRetryWorker::with_privacy_service(service, config, shutdown_rx, privacy_service);
@@ -223,10 +475,17 @@ pub fn spawn_worker_with_privacy(
 pub struct CrankConfig {
     /// Interval between crank cycles (default: 60 seconds)
     pub poll_interval: Duration,
-    /// Consider transactions stale after this many seconds (default: 90 seconds)
+    /// Consider transactions stale after this many seconds, plus the
+    /// service's clock skew tolerance (default: 90 seconds)
     pub stale_after_secs: i64,
     /// Number of stale transactions to process per cycle
     pub batch_size: i64,
+    /// Reclaim transfers left in `processing` for this many seconds, plus
+    /// the service's clock skew tolerance (default: 300 seconds)
+    pub processing_lease_secs: i64,
+    /// On shutdown, how long to wait for the running batch's status checks
+    /// (default: 30 seconds)
+    pub drain_timeout: Duration,
     /// Whether the crank is enabled
     pub enabled: bool,
 }
@@ -237,6 +496,8 @@ impl Default for CrankConfig {
             poll_interval: Duration::from_secs(60),
             stale_after_secs: 90,
             batch_size: 20,
+            processing_lease_secs: DEFAULT_PROCESSING_LEASE_SECS,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
             enabled: true,
         }
     }
@@ -246,7 +507,9 @@ impl Default for CrankConfig {
 ///
 /// This worker runs independently of the main blockchain retry worker and polls
 /// for transactions stuck in `submitted` state. It checks their on-chain status
-/// and updates them to `Confirmed` or `Expired` as appropriate.
+/// and updates them to `Confirmed` or `Expired` as appropriate. It also returns
+/// transfers abandoned in `processing` by a crashed worker to the queue once
+/// their lease has expired.
 ///
 /// Per ARCHITECTURE.md:
 /// - Polls every 60 seconds
@@ -256,6 +519,8 @@ pub struct StaleTransactionCrank {
     service: Arc<AppService>,
     config: CrankConfig,
     shutdown_rx: watch::Receiver<bool>,
+    /// Transactions of the current batch whose status check hasn't finished
+    in_flight: AtomicUsize,
 }
 
 impl StaleTransactionCrank {
@@ -269,14 +534,20 @@ impl StaleTransactionCrank {
             service,
             config,
             shutdown_rx,
+            in_flight: AtomicUsize::new(0),
         }
     }
 
-    /// Run the crank loop
-    pub async fn run(mut self) {
+    /// Run the crank loop.
+    ///
+    /// On shutdown the crank stops starting new batches. A batch that is
+    /// already running is given up to `drain_timeout` to finish; status
+    /// checks still running after that are abandoned and picked up again by
+    /// the next crank to run.
+    pub async fn run(mut self) -> DrainOutcome {
         if !self.config.enabled {
             info!("Stale transaction crank is disabled");
-            return;
+            return DrainOutcome::Idle;
         }
 
         info!(
@@ -289,18 +560,56 @@ impl StaleTransactionCrank {
         loop {
             tokio::select! {
                 _ = tokio::time::sleep(self.config.poll_interval) => {
-                    self.process_stale().await;
+                    if let Some(outcome) = self.process_stale_until_shutdown().await {
+                        return outcome;
+                    }
                 }
                 result = self.shutdown_rx.changed() => {
                     if result.is_ok() && *self.shutdown_rx.borrow() {
                         info!("Stale transaction crank shutting down");
-                        break;
+                        return DrainOutcome::Idle;
                     }
                 }
             }
         }
     }
 
+    /// Process one batch. If shutdown is signalled meanwhile, wait up to
+    /// `drain_timeout` for the batch and return the outcome.
+    async fn process_stale_until_shutdown(&self) -> Option<DrainOutcome> {
+        let batch = self.process_stale();
+        tokio::pin!(batch);
+        let mut shutdown_rx = self.shutdown_rx.clone();
+        tokio::select! {
+            _ = &mut batch => return None,
+            Ok(_) = shutdown_rx.wait_for(|shutdown| *shutdown) => {}
+        }
+
+        let in_flight = self.in_flight.load(Ordering::SeqCst);
+        info!(
+            in_flight = in_flight,
+            drain_timeout = ?self.config.drain_timeout,
+            "Stale transaction crank shutting down - finishing in-flight status checks"
+        );
+        match tokio::time::timeout(self.config.drain_timeout, batch).await {
+            Ok(()) => {
+                info!(
+                    drained = in_flight,
+                    "Finished in-flight stale transaction checks"
+                );
+                Some(DrainOutcome::Drained)
+            }
+            Err(_) => {
+                let abandoned = self.in_flight.load(Ordering::SeqCst);
+                warn!(
+                    abandoned = abandoned,
+                    "Drain timeout elapsed - abandoning in-flight stale transaction checks"
+                );
+                Some(DrainOutcome::TimedOut { abandoned })
+            }
+        }
+    }
+
     /// Execute a single tick of the crank loop (for testing)
     pub async fn run_once(&self) {
         if !self.config.enabled {
@@ -309,13 +618,35 @@ impl StaleTransactionCrank {
         self.process_stale().await;
     }
 
-    /// Process stale submitted transactions
+    /// Reclaim abandoned `processing` transfers, process stale submitted
+    /// transactions, then check multisig proposals awaiting approval.
+    /// Does nothing while processing is paused.
     async fn process_stale(&self) {
+        if self.service.processing_pause().is_paused() {
+            debug!("Crank paused - skipping cycle");
+            return;
+        }
+        // `updated_at` may have been written by an instance whose clock runs ahead
+        let skew_secs = self.service.clock_skew_tolerance().num_seconds();
+        match self
+            .service
+            .reclaim_stale_processing_transactions(
+                self.config.processing_lease_secs + skew_secs,
+                self.config.batch_size,
+            )
+            .await
+        {
+            Ok(0) => debug!("No abandoned processing transfers to reclaim"),
+            Ok(count) => info!(count = count, "Reclaimed abandoned processing transfers"),
+            Err(e) => error!(error = ?e, "Error reclaiming abandoned processing transfers"),
+        }
+
         match self
             .service
-            .process_stale_submitted_transactions(
-                self.config.stale_after_secs,
+            .process_stale_submitted_transactions_tracked(
+                self.config.stale_after_secs + skew_secs,
                 self.config.batch_size,
+                &self.in_flight,
             )
             .await
         {
@@ -329,6 +660,16 @@ impl StaleTransactionCrank {
                 error!(error = ?e, "Error processing stale submitted transactions");
             }
         }
+
+        match self
+            .service
+            .process_awaiting_approvals(self.config.batch_size)
+            .await
+        {
+            Ok(0) => debug!("No transfers awaiting multisig approval"),
+            Ok(count) => debug!(count = count, "Checked multisig proposals"),
+            Err(e) => error!(error = ?e, "Error checking multisig proposals"),
+        }
     }
 }
 
@@ -336,7 +677,7 @@ impl StaleTransactionCrank {
 pub fn spawn_crank(
     service: Arc<AppService>,
     config: CrankConfig,
-) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+) -> (tokio::task::JoinHandle<DrainOutcome>, watch::Sender<bool>) {
     let (shutdown_tx, shutdown_rx) = watch::channel(false);
     let crank = StaleTransactionCrank::new(service, config, shutdown_rx);
     let handle = tokio::spawn(crank.run());
@@ -347,10 +688,12 @@ pub fn spawn_crank(
 mod tests {
     use super::*;
     use crate::domain::{
-        BlockchainStatus, ComplianceStatus, DatabaseClient, SubmitTransferRequest, TransferType,
+        BlockchainStatus, DatabaseClient, SubmitTransferRequest, TransactionStatus, TransferType,
     };
+    use crate::infra::blockchain::{CircuitBreakerClient, CircuitBreakerConfig};
     use crate::test_utils::{
         MockBlockchainClient, MockComplianceProvider, MockConfig, MockDatabaseClient,
+        queue_transfer, transfer_request,
     };
 
     fn create_test_service() -> Arc<AppService> {
@@ -366,6 +709,7 @@ mod tests {
         assert_eq!(config.poll_interval, Duration::from_secs(10));
         assert_eq!(config.batch_size, 10);
         assert!(config.enabled);
+        assert_eq!(config.concurrency, DEFAULT_WORKER_CONCURRENCY);
     }
 
     #[test]
@@ -375,10 +719,14 @@ mod tests {
             batch_size: 20,
             enabled: false,
             enable_privacy_checks: false,
+            concurrency: 8,
+            drain_timeout: Duration::from_secs(5),
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         assert_eq!(config.poll_interval, Duration::from_secs(5));
         assert_eq!(config.batch_size, 20);
         assert!(!config.enabled);
+        assert_eq!(config.concurrency, 8);
     }
 
     #[test]
@@ -397,6 +745,9 @@ mod tests {
             batch_size: 50,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let config2 = config1.clone();
         assert_eq!(config1.poll_interval, config2.poll_interval);
@@ -412,6 +763,9 @@ mod tests {
             batch_size: 10,
             enabled: false, // Disabled
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (_, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -433,6 +787,9 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -459,6 +816,9 @@ mod tests {
             batch_size: 10,
             enabled: false, // Disabled so it returns immediately
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
 
         let (handle, shutdown_tx) = spawn_worker(service, config);
@@ -497,6 +857,9 @@ mod tests {
             batch_size: 10,
             enabled: false,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (_, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -517,6 +880,9 @@ mod tests {
             batch_size: 5,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (_, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -526,6 +892,344 @@ mod tests {
         assert!(result.is_ok(), "run_once should complete within 1 second");
     }
 
+    #[tokio::test]
+    async fn test_batch_submissions_run_up_to_concurrency() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let mut ids = Vec::new();
+        for i in 0..6 {
+            ids.push(queue_transfer(db.as_ref(), &transfer_request(i)).await);
+        }
+
+        let bc = Arc::new(MockBlockchainClient::new().with_submit_delay(Duration::from_millis(50)));
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(AppService::new(db.clone() as _, bc.clone() as _, cp as _));
+        let config = WorkerConfig {
+            poll_interval: Duration::from_secs(60),
+            batch_size: 10,
+            enabled: true,
+            enable_privacy_checks: false,
+            concurrency: 3,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
+        };
+        let (_, shutdown_rx) = watch::channel(false);
+        let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
+
+        worker.run_once().await;
+
+        assert_eq!(bc.max_in_flight(), 3);
+        assert_eq!(bc.get_transactions().len(), 6);
+        for id in ids {
+            let tr = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(tr.blockchain_status, BlockchainStatus::Submitted);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_circuit_backs_off_whole_batch() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let mut ids = Vec::new();
+        for i in 0..4 {
+            ids.push(queue_transfer(db.as_ref(), &transfer_request(i)).await);
+        }
+
+        let rpc = Arc::new(MockBlockchainClient::new());
+        rpc.set_unreachable(true);
+        let bc = Arc::new(CircuitBreakerClient::new(
+            rpc.clone() as _,
+            CircuitBreakerConfig {
+                failure_threshold: 1,
+                cooldown: Duration::from_secs(60),
+            },
+        ));
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(AppService::new(db.clone() as _, bc as _, cp as _));
+        let config = WorkerConfig {
+            poll_interval: Duration::from_secs(60),
+            batch_size: 10,
+            enabled: true,
+            enable_privacy_checks: false,
+            concurrency: 1,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: Duration::from_millis(50),
+        };
+        let (_, shutdown_rx) = watch::channel(false);
+        let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
+
+        let started = std::time::Instant::now();
+        worker.run_once().await;
+        assert!(started.elapsed() >= Duration::from_millis(50));
+
+        // Only the submission that opened the circuit reached the RPC
+        assert_eq!(rpc.rpc_calls(), 1);
+        let mut retry_counts = Vec::new();
+        for id in &ids {
+            let tr = db.get_transfer_request(id).await.unwrap().unwrap();
+            assert_eq!(tr.blockchain_status, BlockchainStatus::PendingSubmission);
+            retry_counts.push(tr.blockchain_retry_count);
+        }
+        // The rest were released without a retry being counted
+        retry_counts.sort_unstable();
+        assert_eq!(retry_counts, [0, 0, 0, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_higher_priority_transfers_dequeued_first() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let mut ids = Vec::new();
+        // Oldest first, so only priority can move the later ones ahead
+        for (i, priority) in [0i16, -1, 5].into_iter().enumerate() {
+            let request = SubmitTransferRequest {
+                priority,
+                ..transfer_request(i)
+            };
+            ids.push(queue_transfer(db.as_ref(), &request).await);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        let (normal, low, high) = (ids[0].clone(), ids[1].clone(), ids[2].clone());
+
+        let bc = Arc::new(MockBlockchainClient::new());
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(AppService::new(db.clone() as _, bc.clone() as _, cp as _));
+        let config = WorkerConfig {
+            poll_interval: Duration::from_secs(60),
+            batch_size: 1,
+            enabled: true,
+            enable_privacy_checks: false,
+            concurrency: 1,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
+        };
+        let (_, shutdown_rx) = watch::channel(false);
+        let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
+
+        worker.run_once().await;
+        assert_eq!(bc.get_transactions(), vec![high.clone()]);
+        let pending = db.get_transfer_request(&normal).await.unwrap().unwrap();
+        assert_eq!(
+            pending.blockchain_status,
+            BlockchainStatus::PendingSubmission
+        );
+
+        worker.run_once().await;
+        worker.run_once().await;
+        assert_eq!(bc.get_transactions(), vec![high, normal, low]);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_in_flight_submissions() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let mut ids = Vec::new();
+        for i in 0..4 {
+            ids.push(queue_transfer(db.as_ref(), &transfer_request(i)).await);
+        }
+
+        let bc =
+            Arc::new(MockBlockchainClient::new().with_submit_delay(Duration::from_millis(300)));
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(AppService::new(db.clone() as _, bc.clone() as _, cp as _));
+        let config = WorkerConfig {
+            poll_interval: Duration::from_millis(10),
+            batch_size: 10,
+            enabled: true,
+            enable_privacy_checks: false,
+            concurrency: 2,
+            drain_timeout: Duration::from_secs(5),
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
+        };
+        let (handle, shutdown_tx) = spawn_worker(service, config);
+
+        // Wait until the first two submissions are mid-flight, then shut down
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while bc.in_flight() < 2 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("submissions should start");
+        shutdown_tx.send(true).unwrap();
+
+        let outcome = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("worker should finish draining")
+            .unwrap();
+        assert_eq!(outcome, DrainOutcome::Drained);
+
+        // Started submissions completed; the rest were never claimed
+        assert_eq!(bc.get_transactions().len(), 2);
+        let mut statuses = Vec::new();
+        for id in ids {
+            let tr = db.get_transfer_request(&id).await.unwrap().unwrap();
+            statuses.push(tr.blockchain_status);
+        }
+        let submitted = statuses
+            .iter()
+            .filter(|s| **s == BlockchainStatus::Submitted)
+            .count();
+        let pending = statuses
+            .iter()
+            .filter(|s| **s == BlockchainStatus::PendingSubmission)
+            .count();
+        assert_eq!((submitted, pending), (2, 2));
+    }
+
+    #[tokio::test]
+    async fn test_crank_shutdown_completes_started_status_checks() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            let tr = db.submit_transfer(&transfer_request(i)).await.unwrap();
+            db.update_blockchain_status(
+                &tr.id,
+                BlockchainStatus::Submitted,
+                Some(&format!("sig_{}", i)),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+            ids.push(tr.id);
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let bc = Arc::new(
+            MockBlockchainClient::new()
+                .with_signature_status(TransactionStatus::Confirmed, Duration::from_millis(200)),
+        );
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(
+            AppService::new(db.clone() as _, bc.clone() as _, cp as _)
+                .with_clock_skew_tolerance(chrono::Duration::zero()),
+        );
+        let config = CrankConfig {
+            poll_interval: Duration::from_millis(10),
+            stale_after_secs: 0,
+            batch_size: 10,
+            processing_lease_secs: DEFAULT_PROCESSING_LEASE_SECS,
+            drain_timeout: Duration::from_secs(5),
+            enabled: true,
+        };
+        let (handle, shutdown_tx) = spawn_crank(service, config);
+
+        // Shut down once the first status check is underway
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while bc.rpc_calls() == 0 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("status checks should start");
+        shutdown_tx.send(true).unwrap();
+
+        let outcome = tokio::time::timeout(Duration::from_secs(5), handle)
+            .await
+            .expect("crank should finish its batch")
+            .unwrap();
+        assert_eq!(outcome, DrainOutcome::Drained);
+
+        // The whole batch was checked and applied, none left half-done
+        for id in ids {
+            let tr = db.get_transfer_request(&id).await.unwrap().unwrap();
+            assert_eq!(tr.blockchain_status, BlockchainStatus::Confirmed);
+        }
+        assert_eq!(bc.rpc_calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_crank_shutdown_abandons_checks_after_drain_timeout() {
+        let db = Arc::new(MockDatabaseClient::new());
+        for i in 0..3 {
+            let tr = db.submit_transfer(&transfer_request(i)).await.unwrap();
+            db.update_blockchain_status(
+                &tr.id,
+                BlockchainStatus::Submitted,
+                Some(&format!("sig_{}", i)),
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+
+        let bc = Arc::new(
+            MockBlockchainClient::new()
+                .with_signature_status(TransactionStatus::Confirmed, Duration::from_secs(10)),
+        );
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(
+            AppService::new(db.clone() as _, bc.clone() as _, cp as _)
+                .with_clock_skew_tolerance(chrono::Duration::zero()),
+        );
+        let config = CrankConfig {
+            poll_interval: Duration::from_millis(10),
+            stale_after_secs: 0,
+            drain_timeout: Duration::from_millis(50),
+            ..CrankConfig::default()
+        };
+        let (handle, shutdown_tx) = spawn_crank(service, config);
+
+        tokio::time::timeout(Duration::from_secs(2), async {
+            while bc.rpc_calls() == 0 {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .expect("status checks should start");
+        shutdown_tx.send(true).unwrap();
+
+        // The slow check does not hold up shutdown past the drain timeout
+        let outcome = tokio::time::timeout(Duration::from_secs(2), handle)
+            .await
+            .expect("crank should stop after its drain timeout")
+            .unwrap();
+        assert_eq!(outcome, DrainOutcome::TimedOut { abandoned: 3 });
+    }
+
+    #[tokio::test]
+    async fn test_crank_staleness_includes_clock_skew_tolerance() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let tr = db.submit_transfer(&transfer_request(0)).await.unwrap();
+        db.update_blockchain_status(
+            &tr.id,
+            BlockchainStatus::Submitted,
+            Some("sig_skew"),
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let bc = Arc::new(
+            MockBlockchainClient::new()
+                .with_signature_status(TransactionStatus::Confirmed, Duration::ZERO),
+        );
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(
+            AppService::new(db.clone() as _, bc.clone() as _, cp as _)
+                .with_clock_skew_tolerance(chrono::Duration::seconds(30)),
+        );
+        let config = CrankConfig {
+            stale_after_secs: 90,
+            ..CrankConfig::default()
+        };
+        let crank = StaleTransactionCrank::new(service, config, watch::channel(false).1);
+
+        // Past stale_after but within the tolerance: not checked yet
+        db.set_updated_at(&tr.id, chrono::Utc::now() - chrono::Duration::seconds(100));
+        crank.run_once().await;
+        assert_eq!(bc.rpc_calls(), 0);
+
+        // Beyond stale_after plus the tolerance
+        db.set_updated_at(&tr.id, chrono::Utc::now() - chrono::Duration::seconds(130));
+        crank.run_once().await;
+        let tr = db.get_transfer_request(&tr.id).await.unwrap().unwrap();
+        assert_eq!(tr.blockchain_status, BlockchainStatus::Confirmed);
+    }
+
     #[tokio::test]
     async fn test_batch_size_accessor() {
         let service = create_test_service();
@@ -534,6 +1238,9 @@ mod tests {
             batch_size: 42,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (_, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -549,6 +1256,9 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (_, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -572,6 +1282,9 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (_, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -590,6 +1303,9 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -617,6 +1333,9 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -643,6 +1362,9 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
 
         let (handle, shutdown_tx) = spawn_worker(service, config);
@@ -666,6 +1388,9 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (shutdown_tx, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -688,35 +1413,8 @@ mod tests {
         let db = Arc::new(MockDatabaseClient::new());
         let bc = Arc::new(MockBlockchainClient::new());
 
-        // Create a request that needs processing
-        let request = SubmitTransferRequest {
-            from_address: "AddressA".to_string(),
-            to_address: "AddressB".to_string(),
-            transfer_details: TransferType::Public {
-                amount: 1_500_000_000,
-            },
-            token_mint: None,
-            signature: "dummy_sig".to_string(),
-            nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f00".to_string(),
-        };
-        let tr = db.submit_transfer(&request).await.unwrap();
-
-        // Update to pending submission status
-        db.update_blockchain_status(
-            &tr.id,
-            BlockchainStatus::PendingSubmission,
-            None,
-            None,
-            None,
-            None,
-        )
-        .await
-        .unwrap();
-
-        // Set compliance status to Approved (required for worker to process)
-        db.update_compliance_status(&tr.id, ComplianceStatus::Approved)
-            .await
-            .unwrap();
+        // Queue an approved request that needs processing
+        let id = queue_transfer(db.as_ref(), &transfer_request(0)).await;
 
         let cp = Arc::new(MockComplianceProvider::new());
         let service = Arc::new(AppService::new(db.clone() as _, bc as _, cp as _));
@@ -726,6 +1424,9 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         let (_, shutdown_rx) = watch::channel(false);
         let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
@@ -734,10 +1435,40 @@ mod tests {
         worker.run_once().await;
 
         // Verify the item was processed
-        let updated = db.get_transfer_request(&tr.id).await.unwrap().unwrap();
+        let updated = db.get_transfer_request(&id).await.unwrap().unwrap();
         assert_eq!(updated.blockchain_status, BlockchainStatus::Submitted);
     }
 
+    #[tokio::test]
+    async fn test_paused_worker_skips_pending_items_until_resumed() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let bc = Arc::new(MockBlockchainClient::new());
+        let id = queue_transfer(db.as_ref(), &transfer_request(0)).await;
+
+        let cp = Arc::new(MockComplianceProvider::new());
+        let pause = ProcessingPause::default();
+        let service = Arc::new(
+            AppService::new(db.clone() as _, bc as _, cp as _).with_processing_pause(pause.clone()),
+        );
+        let (_, shutdown_rx) = watch::channel(false);
+        let config = WorkerConfig {
+            enable_privacy_checks: false,
+            ..WorkerConfig::default()
+        };
+        let worker = BlockchainRetryWorker::new(service, config, shutdown_rx);
+
+        assert!(pause.pause());
+        assert!(!pause.pause());
+        worker.run_once().await;
+        let item = db.get_transfer_request(&id).await.unwrap().unwrap();
+        assert_eq!(item.blockchain_status, BlockchainStatus::PendingSubmission);
+
+        assert!(pause.resume());
+        worker.run_once().await;
+        let item = db.get_transfer_request(&id).await.unwrap().unwrap();
+        assert_eq!(item.blockchain_status, BlockchainStatus::Submitted);
+    }
+
     #[test]
     fn test_worker_config_zero_batch_size() {
         let config = WorkerConfig {
@@ -745,6 +1476,9 @@ mod tests {
             batch_size: 0,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         assert_eq!(config.batch_size, 0);
     }
@@ -756,7 +1490,194 @@ mod tests {
             batch_size: 10,
             enabled: true,
             enable_privacy_checks: false,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
         };
         assert_eq!(config.poll_interval, Duration::from_millis(1));
     }
+
+    // --- PRIVACY HEALTH CHECK ACTIONS ---
+
+    use crate::infra::blockchain::QuickNodeTokenApiClient;
+    use crate::infra::privacy::PrivacyHealthCheckConfig;
+    use wiremock::matchers::{body_partial_json, method};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    /// Token API mock reporting `recent_tx_count` recent signatures for the mint
+    async fn token_api_mock(recent_tx_count: usize) -> MockServer {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({"method": "qn_getTokenMetadata"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "error": {"code": -32601, "message": "Method not found"}
+            })))
+            .mount(&mock_server)
+            .await;
+        let signatures: Vec<_> = (0..recent_tx_count)
+            .map(|i| serde_json::json!({"signature": format!("sig_{i}")}))
+            .collect();
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({"method": "getSignaturesForAddress"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": signatures
+            })))
+            .mount(&mock_server)
+            .await;
+        mock_server
+    }
+
+    /// Queue an approved confidential transfer for submission
+    async fn pending_confidential_transfer(db: &MockDatabaseClient) -> String {
+        let request = SubmitTransferRequest {
+            transfer_details: TransferType::Confidential {
+                new_decryptable_available_balance: "balance".to_string(),
+                equality_proof: "equality".to_string(),
+                ciphertext_validity_proof: "validity".to_string(),
+                range_proof: "range".to_string(),
+                auditor_elgamal_pubkey: None,
+                fee_sigma_proof: None,
+                fee_ciphertext_validity_proof: None,
+            },
+            token_mint: Some("ConfidentialMint".to_string()),
+            ..transfer_request(1)
+        };
+        queue_transfer(db, &request).await
+    }
+
+    fn privacy_worker_config() -> WorkerConfig {
+        WorkerConfig {
+            poll_interval: Duration::from_secs(10),
+            batch_size: 10,
+            enabled: true,
+            enable_privacy_checks: true,
+            concurrency: DEFAULT_WORKER_CONCURRENCY,
+            drain_timeout: DEFAULT_WORKER_DRAIN_TIMEOUT,
+            circuit_open_backoff: DEFAULT_WORKER_CIRCUIT_OPEN_BACKOFF,
+        }
+    }
+
+    /// Run one privacy-checked batch over a pending confidential transfer and
+    /// return its resulting status and error
+    async fn run_confidential_batch(recent_tx_count: usize) -> (BlockchainStatus, Option<String>) {
+        let updated = run_confidential_batches(recent_tx_count, 1).await;
+        (updated.blockchain_status, updated.blockchain_last_error)
+    }
+
+    /// Run `runs` privacy-checked batches over a pending confidential
+    /// transfer and return the transfer afterwards
+    async fn run_confidential_batches(recent_tx_count: usize, runs: usize) -> TransferRequest {
+        let mock_server = token_api_mock(recent_tx_count).await;
+        let db = Arc::new(MockDatabaseClient::new());
+        let id = pending_confidential_transfer(&db).await;
+
+        let bc = Arc::new(MockBlockchainClient::new());
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(AppService::new(db.clone() as _, bc as _, cp as _));
+        let privacy_service = Arc::new(PrivacyHealthCheckService::new(
+            PrivacyHealthCheckConfig {
+                min_tx_threshold: 5,
+                min_acceptable_tx_count: 2,
+                min_delay_secs: 0,
+                max_delay_secs: 0,
+                jitter_min_ms: 0,
+                jitter_max_ms: 0,
+                ..Default::default()
+            },
+            Some(Arc::new(QuickNodeTokenApiClient::new(&mock_server.uri()))),
+        ));
+        let (_, shutdown_rx) = watch::channel(false);
+        let worker = BlockchainRetryWorker::with_privacy_service(
+            service,
+            privacy_worker_config(),
+            shutdown_rx,
+            privacy_service,
+        );
+
+        for _ in 0..runs {
+            worker.run_once().await;
+        }
+
+        db.get_transfer_request(&id).await.unwrap().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_privacy_proceed_submits_confidential_transfer() {
+        let (status, error) = run_confidential_batch(8).await;
+        assert_eq!(status, BlockchainStatus::Submitted);
+        assert!(error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_privacy_delay_requeues_then_submits() {
+        // The delay is scheduled, not slept: the transfer goes back to the queue
+        let deferred = run_confidential_batches(3, 1).await;
+        assert_eq!(
+            deferred.blockchain_status,
+            BlockchainStatus::PendingSubmission
+        );
+        assert!(deferred.blockchain_next_retry_at.is_some());
+        assert_eq!(deferred.blockchain_retry_count, 0);
+
+        // Once the (zero-length) delay has passed it is submitted, not delayed again
+        let submitted = run_confidential_batches(3, 2).await;
+        assert_eq!(submitted.blockchain_status, BlockchainStatus::Submitted);
+    }
+
+    #[tokio::test]
+    async fn test_privacy_reject_fails_confidential_transfer() {
+        let (status, error) = run_confidential_batch(1).await;
+        assert_eq!(status, BlockchainStatus::Failed);
+        let error = error.unwrap();
+        assert!(error.contains("anonymity set too thin"));
+        assert!(error.contains("1 recent transactions, minimum 2"));
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_interrupts_submission_jitter() {
+        let db = Arc::new(MockDatabaseClient::new());
+        let id = pending_confidential_transfer(&db).await;
+
+        let bc = Arc::new(MockBlockchainClient::new());
+        let cp = Arc::new(MockComplianceProvider::new());
+        let service = Arc::new(AppService::new(db.clone() as _, bc as _, cp as _));
+        // No Token API client: the health check is skipped, but jitter still applies
+        let privacy_service = Arc::new(PrivacyHealthCheckService::new(
+            PrivacyHealthCheckConfig {
+                jitter_min_ms: 60_000,
+                jitter_max_ms: 60_000,
+                ..Default::default()
+            },
+            None,
+        ));
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let worker = BlockchainRetryWorker::with_privacy_service(
+            service,
+            privacy_worker_config(),
+            shutdown_rx,
+            privacy_service,
+        );
+
+        let handle = tokio::spawn(async move { worker.run_once().await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        shutdown_tx.send(true).unwrap();
+
+        let result = tokio::time::timeout(Duration::from_secs(1), handle).await;
+        assert!(result.is_ok(), "Shutdown should interrupt the jitter sleep");
+
+        // The transfer was not submitted and stays queued
+        let updated = db.get_transfer_request(&id).await.unwrap().unwrap();
+        assert_eq!(
+            updated.blockchain_status,
+            BlockchainStatus::PendingSubmission
+        );
+    }
 }
@@ -75,6 +75,14 @@ fn main() -> Result<()> {
         token_mint,
         signature: signature_bs58.clone(),
         nonce: nonce.clone(),
+        memo: None,
+        priority: 0,
+        callback_url: None,
+        signature_version: None,
+        valid_until: None,
+        jito_region: None,
+        skip_preflight: None,
+        amount_sol: None,
     };
 
     // 5. Generate the CURL command (with optional Idempotency-Key header)
@@ -253,6 +261,9 @@ fn generate_confidential_transfer() -> Result<(TransferType, Option<String>, Str
         equality_proof: equality_proof_base64,
         ciphertext_validity_proof: ciphertext_validity_proof_base64,
         range_proof: range_proof_base64,
+        auditor_elgamal_pubkey: None,
+        fee_sigma_proof: None,
+        fee_ciphertext_validity_proof: None,
     };
 
     Ok((
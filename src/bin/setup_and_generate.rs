@@ -646,6 +646,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         equality_proof: equality_proof_base64,
         ciphertext_validity_proof: ciphertext_validity_proof_base64,
         range_proof: range_proof_base64,
+        auditor_elgamal_pubkey: None,
+        fee_sigma_proof: None,
+        fee_ciphertext_validity_proof: None,
     };
 
     let request = SubmitTransferRequest {
@@ -655,6 +658,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         token_mint: Some(mint_pubkey.to_string()),
         signature: signature_bs58,
         nonce: nonce.clone(),
+        memo: None,
+        priority: 0,
+        callback_url: None,
+        signature_version: None,
+        valid_until: None,
+        jito_region: None,
+        skip_preflight: None,
+        amount_sol: None,
     };
 
     let json_body = serde_json::to_string_pretty(&request)?;
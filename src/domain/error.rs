@@ -2,6 +2,8 @@
 
 use thiserror::Error;
 
+use super::types::INVALID_ADDRESS_CODE;
+
 #[derive(Error, Debug, Clone)]
 pub enum DatabaseError {
     #[error("Connection failed: {0}")]
@@ -32,6 +34,11 @@ pub enum BlockchainError {
     WalletError(String),
     #[error("Insufficient funds for transaction")]
     InsufficientFunds,
+    /// The transaction tried to create an account that already exists (the
+    /// system program's `AccountAlreadyInUse`); `instruction` is the index of
+    /// the instruction that failed.
+    #[error("Account already in use (instruction {instruction}): {message}")]
+    AccountAlreadyInUse { instruction: u8, message: String },
     #[error("Timeout waiting for confirmation: {0}")]
     Timeout(String),
     #[error("Helius API error: {0}")]
@@ -99,6 +106,15 @@ pub enum ValidationError {
     /// Returns the existing request to support idempotent behavior.
     #[error("Duplicate request: nonce '{nonce}' has already been used")]
     DuplicateRequest { nonce: String },
+    /// An address involved in the request is on the internal blocklist
+    #[error("Blocklisted: {0}")]
+    Blocklisted(String),
+    /// Compliance screening rejected the transfer
+    #[error("Compliance rejected: {0}")]
+    ComplianceRejected(String),
+    /// The transfer exceeds the per-transfer or rolling per-sender amount limit
+    #[error("Transfer limit exceeded: {0}")]
+    TransferLimitExceeded(String),
 }
 
 impl From<&str> for ValidationError {
@@ -125,6 +141,9 @@ pub enum ExternalServiceError {
     ApiError { status_code: u16, message: String },
     #[error("Parse error: {0}")]
     ParseError(String),
+    /// Transient failures persisted through every retry attempt
+    #[error("Retries exhausted after {attempts} attempts: {message}")]
+    RetriesExhausted { attempts: u32, message: String },
 }
 
 #[derive(Error, Debug)]
@@ -151,6 +170,8 @@ pub enum AppError {
     Internal(String),
     #[error("Operation not supported: {0}")]
     NotSupported(String),
+    #[error("Conflict: {0}")]
+    Conflict(String),
     #[error("Rate limit exceeded")]
     RateLimited,
 }
@@ -163,6 +184,24 @@ impl From<serde_json::Error> for AppError {
 
 impl From<validator::ValidationErrors> for AppError {
     fn from(err: validator::ValidationErrors) -> Self {
+        // Malformed addresses get their own error when nothing else failed;
+        // otherwise every failure, addresses included, is reported together
+        let field_errors = err.field_errors();
+        let mut invalid_addresses: Vec<String> = field_errors
+            .iter()
+            .filter(|(_, errors)| errors.iter().any(|e| e.code == INVALID_ADDRESS_CODE))
+            .map(|(field, _)| field.to_string())
+            .collect();
+        let only_addresses = field_errors
+            .values()
+            .all(|errors| errors.iter().all(|e| e.code == INVALID_ADDRESS_CODE));
+        if !invalid_addresses.is_empty() && only_addresses {
+            invalid_addresses.sort();
+            return AppError::Validation(ValidationError::InvalidAddress(format!(
+                "{} must be a base58-encoded 32-byte public key",
+                invalid_addresses.join(", ")
+            )));
+        }
         AppError::Validation(ValidationError::Multiple(err.to_string()))
     }
 }
@@ -324,6 +363,12 @@ mod tests {
 
         let err = ValidationError::Multiple("many errors".to_string());
         assert_eq!(err.to_string(), "Validation failed: many errors");
+
+        let err = ValidationError::Blocklisted("address on blocklist".to_string());
+        assert_eq!(err.to_string(), "Blocklisted: address on blocklist");
+
+        let err = ValidationError::ComplianceRejected("high risk".to_string());
+        assert_eq!(err.to_string(), "Compliance rejected: high risk");
     }
 
     #[test]
@@ -339,6 +384,15 @@ mod tests {
 
         let err = ExternalServiceError::RateLimited("api".to_string());
         assert_eq!(err.to_string(), "Rate limited: api");
+
+        let err = ExternalServiceError::RetriesExhausted {
+            attempts: 3,
+            message: "Timeout: 30s".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Retries exhausted after 3 attempts: Timeout: 30s"
+        );
     }
 
     #[test]
@@ -361,6 +415,9 @@ mod tests {
         let err = AppError::NotSupported("feature".to_string());
         assert_eq!(err.to_string(), "Operation not supported: feature");
 
+        let err = AppError::Conflict("already submitted".to_string());
+        assert_eq!(err.to_string(), "Conflict: already submitted");
+
         let err = AppError::RateLimited;
         assert_eq!(err.to_string(), "Rate limit exceeded");
     }
@@ -7,14 +7,24 @@ pub mod types;
 pub use error::{
     AppError, BlockchainError, ConfigError, DatabaseError, ExternalServiceError, ValidationError,
 };
-pub use traits::{BlockchainClient, ComplianceProvider, DatabaseClient};
+pub use traits::{AlertSink, BlockchainClient, ComplianceProvider, DatabaseClient};
 pub use types::{
-    AuditAmount, AuditAssetType, AuditFinalDecision, BlockchainStatus, CheckoutSession,
-    CheckoutSessionStatus, CheckoutTransferSubmissionResponse, ComplianceStatus,
-    CreateCheckoutSessionRequest, ErrorDetail, ErrorResponse, HealthResponse, HealthStatus,
-    HeliusTransaction, InternalBlocklistHit, LastErrorType, PaginatedResponse, PaginationParams,
-    PrivateSubmissionAuditMetadata, QuickNodeTransactionMeta, QuickNodeWebhookEvent,
-    QuickNodeWebhookPayload, RateLimitResponse, RiskCheckRequest, RiskCheckResult,
-    SubmitTransferRequest, TransactionStatus, TransferAuditReport, TransferRequest, TransferType,
-    WalletRiskProfile,
+    Alert, AlertKind, ApiKeyScope, ApiKeyStore, AuditAmount, AuditAssetType, AuditFinalDecision,
+    BlockchainNodeHealth, BlockchainStatus, BlocklistCategory, BulkRiskCheckRequest,
+    BulkRiskCheckResponse, BulkTransferResponse, BulkTransferResult, BundleStatus, CheckoutSession,
+    CheckoutSessionStatus, CheckoutTransferSubmissionResponse, ComplianceDecision,
+    ComplianceDecisionFilter, ComplianceDecisionQuery, ComplianceStatus, ComplianceVerdict,
+    ConfirmationEvent, CreateCheckoutSessionRequest, DependencyHealth, DryRunResult, ErrorCode,
+    ErrorDetail, ErrorResponse, FeeEstimate, FeeEstimateRequest, FeePayerBalance, HealthQuery,
+    HealthResponse, HealthStatus, HeliusAccountData, HeliusNativeTransfer, HeliusRawTokenAmount,
+    HeliusTokenBalanceChange, HeliusTokenTransfer, HeliusTransaction, IdempotencyRecord,
+    InternalBlocklistHit, LAMPORTS_PER_SOL, LastErrorType, MAX_BATCH_RECIPIENTS,
+    MAX_BLOCKLIST_SEVERITY, ObservedTransfer, PaginatedResponse, PaginationParams,
+    PrivateSubmissionAuditMetadata, ProposalStatus, QuickNodeTransactionMeta,
+    QuickNodeWebhookEvent, QuickNodeWebhookPayload, RateLimitResponse, RejectionReason,
+    RiskCheckRequest, RiskCheckResult, SenderLimit, ServerTimeResponse, StatusKind,
+    StatusTransition, SubmissionOutcome, SubmitOptions, SubmitTransferQuery, SubmitTransferRequest,
+    TransactionStatus, TransferAuditReport, TransferFilter, TransferRequest, TransferStatusEvent,
+    TransferType, WalletRiskProfile, WebhookDelivery, WebhookDeliveryStatus,
+    normalize_solana_address, parse_sol_amount, validate_solana_address,
 };
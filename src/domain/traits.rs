@@ -4,9 +4,12 @@ use async_trait::async_trait;
 
 use super::error::AppError;
 use super::types::{
-    BlockchainStatus, CheckoutSession, CheckoutSessionStatus, ComplianceStatus,
-    CreateCheckoutSessionRequest, LastErrorType, PaginatedResponse, SubmitTransferRequest,
-    TransactionStatus, TransferRequest, WalletRiskProfile,
+    Alert, BlockchainNodeHealth, BlockchainStatus, BundleStatus, CheckoutSession,
+    CheckoutSessionStatus, ComplianceDecision, ComplianceDecisionFilter, ComplianceStatus,
+    ComplianceVerdict, CreateCheckoutSessionRequest, FeeEstimate, IdempotencyRecord, LastErrorType,
+    PaginatedResponse, ProposalStatus, RejectionReason, SenderLimit, StatusTransition,
+    SubmissionOutcome, SubmitOptions, SubmitTransferRequest, TransactionStatus, TransferFilter,
+    TransferRequest, WalletRiskProfile, WebhookDelivery,
 };
 use chrono::{DateTime, Utc};
 
@@ -18,6 +21,44 @@ pub trait ComplianceProvider: Send + Sync {
         &self,
         request: &SubmitTransferRequest,
     ) -> Result<ComplianceStatus, AppError>;
+
+    /// Screen a transfer, reporting the risk category behind a rejection.
+    /// Providers that don't categorize their verdicts keep the default, which
+    /// wraps [`Self::check_compliance`].
+    async fn screen(&self, request: &SubmitTransferRequest) -> Result<ComplianceVerdict, AppError> {
+        self.check_compliance(request)
+            .await
+            .map(ComplianceVerdict::from)
+    }
+
+    /// Short provider name used in logs
+    fn name(&self) -> &'static str {
+        "compliance_provider"
+    }
+
+    /// Lightweight reachability check used by the health endpoint.
+    /// Must not perform a billable screening request.
+    async fn health_check(&self) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    /// Drop any cached decisions involving `address` so the next check hits
+    /// the provider again. Returns the number of cached entries removed.
+    fn invalidate_address(&self, _address: &str) -> usize {
+        0
+    }
+}
+
+/// Destination of operator alerts, e.g. a chat webhook
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    /// Deliver one alert
+    async fn send_alert(&self, alert: &Alert) -> Result<(), AppError>;
+
+    /// Short sink name used in logs
+    fn name(&self) -> &'static str {
+        "alert_sink"
+    }
 }
 
 /// Database client trait for persistence operations
@@ -26,6 +67,12 @@ pub trait DatabaseClient: Send + Sync {
     /// Check database connectivity
     async fn health_check(&self) -> Result<(), AppError>;
 
+    /// Check read replica connectivity.
+    /// Returns `None` when no replica is configured.
+    async fn read_replica_health_check(&self) -> Option<Result<(), AppError>> {
+        None
+    }
+
     /// Get a single transfer request by ID
     async fn get_transfer_request(&self, id: &str) -> Result<Option<TransferRequest>, AppError>;
 
@@ -35,6 +82,40 @@ pub trait DatabaseClient: Send + Sync {
         data: &SubmitTransferRequest,
     ) -> Result<TransferRequest, AppError>;
 
+    /// Submit a new transfer request, recording the correlation id of the
+    /// API request that created it. Clients that cannot store the id fall
+    /// back to [`Self::submit_transfer`].
+    async fn submit_transfer_with_correlation(
+        &self,
+        data: &SubmitTransferRequest,
+        correlation_id: Option<&str>,
+    ) -> Result<TransferRequest, AppError> {
+        let _ = correlation_id;
+        self.submit_transfer(data).await
+    }
+
+    /// Like [`Self::submit_transfer_with_correlation`], but only persist the
+    /// transfer if it keeps the sender within `limit`.
+    ///
+    /// The sender's native SOL transfers (single and batch) created since
+    /// `limit.since` count toward the limit unless compliance rejected them or
+    /// they expired or were cancelled; transfers still being screened count.
+    /// The check and the insert are serialized per sender, so concurrent
+    /// submissions cannot overshoot the limit. Returns
+    /// [`ValidationError::TransferLimitExceeded`](crate::domain::ValidationError::TransferLimitExceeded)
+    /// without persisting anything when it would be exceeded.
+    async fn submit_transfer_within_limit(
+        &self,
+        data: &SubmitTransferRequest,
+        correlation_id: Option<&str>,
+        limit: SenderLimit,
+    ) -> Result<TransferRequest, AppError> {
+        let _ = (data, correlation_id, limit);
+        Err(AppError::NotSupported(
+            "submit_transfer_within_limit not implemented".to_string(),
+        ))
+    }
+
     /// List transfer requests with cursor-based pagination
     async fn list_transfer_requests(
         &self,
@@ -42,6 +123,22 @@ pub trait DatabaseClient: Send + Sync {
         cursor: Option<&str>,
     ) -> Result<PaginatedResponse<TransferRequest>, AppError>;
 
+    /// List transfer requests matching `filter` with cursor-based pagination.
+    /// Without filters this is the same as [`Self::list_transfer_requests`].
+    async fn list_transfer_requests_filtered(
+        &self,
+        filter: &TransferFilter,
+        limit: i64,
+        cursor: Option<&str>,
+    ) -> Result<PaginatedResponse<TransferRequest>, AppError> {
+        if filter.is_empty() {
+            return self.list_transfer_requests(limit, cursor).await;
+        }
+        Err(AppError::NotSupported(
+            "list_transfer_requests_filtered not implemented".to_string(),
+        ))
+    }
+
     /// Update blockchain status for a transfer request.
     /// When signature is set, blockhash_used can be set for Jito double-spend protection (expiry checks).
     async fn update_blockchain_status(
@@ -61,21 +158,103 @@ pub trait DatabaseClient: Send + Sync {
         status: crate::domain::ComplianceStatus,
     ) -> Result<(), AppError>;
 
-    /// Get requests pending blockchain submission
+    /// Store (or clear) the structured compliance rejection reason of a transfer.
+    /// Set it before the compliance status moves to rejected so the recorded
+    /// transition carries it.
+    async fn update_rejection_reason(
+        &self,
+        id: &str,
+        reason: Option<&RejectionReason>,
+    ) -> Result<(), AppError> {
+        let _ = (id, reason);
+        Err(AppError::NotSupported(
+            "update_rejection_reason not implemented".to_string(),
+        ))
+    }
+
+    /// Append a status transition to the history of a transfer.
+    /// Implementations call this from every status update.
+    async fn record_status_transition(
+        &self,
+        transition: &StatusTransition,
+    ) -> Result<(), AppError> {
+        let _ = transition;
+        Err(AppError::NotSupported(
+            "record_status_transition not implemented".to_string(),
+        ))
+    }
+
+    /// Status history of a transfer, oldest first
+    async fn get_status_history(&self, id: &str) -> Result<Vec<StatusTransition>, AppError> {
+        let _ = id;
+        Err(AppError::NotSupported(
+            "get_status_history not implemented".to_string(),
+        ))
+    }
+
+    /// Append a compliance decision to the audit trail
+    async fn record_compliance_decision(
+        &self,
+        decision: &ComplianceDecision,
+    ) -> Result<(), AppError> {
+        let _ = decision;
+        Err(AppError::NotSupported(
+            "record_compliance_decision not implemented".to_string(),
+        ))
+    }
+
+    /// List compliance decisions matching `filter`, newest first, with
+    /// cursor-based pagination
+    async fn list_compliance_decisions(
+        &self,
+        filter: &ComplianceDecisionFilter,
+        limit: i64,
+        cursor: Option<&str>,
+    ) -> Result<PaginatedResponse<ComplianceDecision>, AppError> {
+        let _ = (filter, limit, cursor);
+        Err(AppError::NotSupported(
+            "list_compliance_decisions not implemented".to_string(),
+        ))
+    }
+
+    /// Claim requests pending blockchain submission.
+    ///
+    /// Claimed requests are moved to `processing` in the same atomic step, so
+    /// concurrent workers never receive the same request. Requests abandoned
+    /// in `processing` by a crashed worker are returned to the queue by the
+    /// crank (see [`Self::claim_stale_processing_transactions`]).
     async fn get_pending_blockchain_requests(
         &self,
         limit: i64,
     ) -> Result<Vec<TransferRequest>, AppError>;
 
+    /// Count requests queued for blockchain submission (`pending_submission`)
+    async fn count_pending_blockchain_requests(&self) -> Result<i64, AppError> {
+        Err(AppError::NotSupported(
+            "count_pending_blockchain_requests not implemented".to_string(),
+        ))
+    }
+
     /// Increment retry count for a request
     async fn increment_retry_count(&self, id: &str) -> Result<i32, AppError>;
 
-    /// Get a transfer request by blockchain signature
+    /// Get a transfer request by blockchain signature, or by the signature of
+    /// one of its proof verification transactions
     async fn get_transfer_by_signature(
         &self,
         signature: &str,
     ) -> Result<Option<TransferRequest>, AppError>;
 
+    /// Cancel a transfer request that has not been picked up for submission.
+    /// The transition must be atomic with respect to the worker claiming the row.
+    /// Returns `false` if the request does not exist or is no longer cancellable.
+    async fn cancel_transfer_request(&self, id: &str) -> Result<bool, AppError> {
+        let _ = id;
+        Err(AppError::NotSupported(
+            "cancel_transfer_request not implemented".to_string(),
+        ))
+    }
+
     // =========================================================================
     // Merchant Checkout Session Methods
     // =========================================================================
@@ -134,6 +313,46 @@ pub trait DatabaseClient: Send + Sync {
         Ok(None)
     }
 
+    /// Find the unexpired response recorded for an `Idempotency-Key` under
+    /// `scope` (see [`IdempotencyRecord::scope`]).
+    ///
+    /// # Returns
+    /// - `Ok(Some(IdempotencyRecord))` - The key was used before
+    /// - `Ok(None)` - First use of the key, or its record has expired
+    async fn find_idempotency_record(
+        &self,
+        scope: &str,
+        key: &str,
+    ) -> Result<Option<IdempotencyRecord>, AppError> {
+        let _ = (scope, key);
+        Err(AppError::NotSupported(
+            "find_idempotency_record not implemented".to_string(),
+        ))
+    }
+
+    /// Record the response for an `Idempotency-Key`.
+    /// Keeps the existing record if the key was already recorded under the
+    /// same scope and has not expired.
+    async fn save_idempotency_record(&self, record: &IdempotencyRecord) -> Result<(), AppError> {
+        let _ = record;
+        Err(AppError::NotSupported(
+            "save_idempotency_record not implemented".to_string(),
+        ))
+    }
+
+    /// Delete up to `limit` idempotency records that expired before `now`.
+    /// Returns the number of records deleted.
+    async fn purge_expired_idempotency_records(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        let _ = (now, limit);
+        Err(AppError::NotSupported(
+            "purge_expired_idempotency_records not implemented".to_string(),
+        ))
+    }
+
     // =========================================================================
     // Jito Double Spend Protection Methods
     // =========================================================================
@@ -151,6 +370,30 @@ pub trait DatabaseClient: Send + Sync {
         Ok(())
     }
 
+    /// Pin a transfer to `signer_pubkey` unless it already has a signer.
+    /// Returns the signer in effect, so a concurrent pin never switches keys.
+    async fn pin_transfer_signer(&self, id: &str, signer_pubkey: &str) -> Result<String, AppError> {
+        let _ = id;
+        Ok(signer_pubkey.to_string())
+    }
+
+    /// Record a successful submission: the outcome's submitted status,
+    /// signature, Jito bundle ID, proof transactions and Token-2022 fee, and
+    /// the blockhash used, in one update so none of them ever belongs to
+    /// another attempt than the signature. A `None` bundle ID or fee, or an
+    /// empty proof list, clears those of an earlier attempt.
+    async fn record_submission(
+        &self,
+        id: &str,
+        outcome: &SubmissionOutcome,
+        blockhash_used: Option<&str>,
+    ) -> Result<(), AppError> {
+        let _ = (id, outcome, blockhash_used);
+        Err(AppError::NotSupported(
+            "record_submission not implemented".to_string(),
+        ))
+    }
+
     // =========================================================================
     // Active Polling Fallback (Crank) Methods
     // =========================================================================
@@ -175,6 +418,105 @@ pub trait DatabaseClient: Send + Sync {
         Ok(vec![])
     }
 
+    /// Claim transactions left in `processing` for longer than the lease,
+    /// i.e. abandoned by a crashed worker.
+    ///
+    /// Claiming renews their `updated_at` in the same atomic step, so
+    /// concurrent cranks never reclaim the same transaction. The crank checks
+    /// their last signature on-chain before returning them to the queue.
+    async fn claim_stale_processing_transactions(
+        &self,
+        older_than_secs: i64,
+        limit: i64,
+    ) -> Result<Vec<TransferRequest>, AppError> {
+        let _ = (older_than_secs, limit);
+        Ok(vec![])
+    }
+
+    /// Claim up to `limit` transfers in `awaiting_approval`, least recently
+    /// checked first. Claiming renews their `updated_at`, so successive
+    /// batches cycle through every pending proposal. The crank checks each
+    /// proposal on-chain.
+    async fn claim_awaiting_approval_transfers(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<TransferRequest>, AppError> {
+        let _ = limit;
+        Err(AppError::NotSupported(
+            "claim_awaiting_approval_transfers not implemented".to_string(),
+        ))
+    }
+
+    // =========================================================================
+    // Archival Methods
+    // =========================================================================
+
+    /// Soft-delete up to `limit` transfers in a terminal blockchain status
+    /// whose last update is before `older_than`, oldest first.
+    /// Archived transfers are excluded from listings and worker queues but
+    /// remain retrievable by id. Returns the number of transfers archived.
+    async fn archive_terminal_transfers(
+        &self,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        let _ = (older_than, limit);
+        Err(AppError::NotSupported(
+            "archive_terminal_transfers not implemented".to_string(),
+        ))
+    }
+
+    /// Clear the nonce of up to `limit` transfers in a terminal blockchain
+    /// status whose last update and `valid_until` are both before
+    /// `older_than`, oldest first. Nonces of transfers without a
+    /// `valid_until` are never cleared, since their signed request stays
+    /// valid forever. Returns the number of nonces cleared.
+    async fn purge_expired_nonces(
+        &self,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        let _ = (older_than, limit);
+        Err(AppError::NotSupported(
+            "purge_expired_nonces not implemented".to_string(),
+        ))
+    }
+
+    // =========================================================================
+    // Outbound Webhook Methods
+    // =========================================================================
+
+    /// Queue an outbound webhook delivery.
+    /// Returns `false` if a delivery with the same `event_key` already exists.
+    async fn enqueue_webhook_delivery(&self, delivery: &WebhookDelivery) -> Result<bool, AppError> {
+        let _ = delivery;
+        Err(AppError::NotSupported(
+            "enqueue_webhook_delivery not implemented".to_string(),
+        ))
+    }
+
+    /// Claim up to `limit` pending deliveries that are due, oldest first.
+    /// Claimed deliveries are not due again for `lease_secs`, so concurrent
+    /// dispatchers do not send them twice.
+    async fn claim_due_webhook_deliveries(
+        &self,
+        limit: i64,
+        lease_secs: i64,
+    ) -> Result<Vec<WebhookDelivery>, AppError> {
+        let _ = (limit, lease_secs);
+        Err(AppError::NotSupported(
+            "claim_due_webhook_deliveries not implemented".to_string(),
+        ))
+    }
+
+    /// Persist the state of a delivery after an attempt
+    async fn update_webhook_delivery(&self, delivery: &WebhookDelivery) -> Result<(), AppError> {
+        let _ = delivery;
+        Err(AppError::NotSupported(
+            "update_webhook_delivery not implemented".to_string(),
+        ))
+    }
+
     // =========================================================================
     // Risk Profile Methods (for pre-flight compliance screening cache)
     // =========================================================================
@@ -203,12 +545,21 @@ pub trait BlockchainClient: Send + Sync {
     /// Check blockchain RPC connectivity
     async fn health_check(&self) -> Result<(), AppError>;
 
+    /// Diagnose the RPC node beyond connectivity: its own health report,
+    /// software version and how far its slot lags behind expectations.
+    /// Reported by the verbose health check.
+    async fn detailed_health(&self) -> Result<BlockchainNodeHealth, AppError> {
+        Err(AppError::NotSupported(
+            "detailed_health not implemented".to_string(),
+        ))
+    }
+
     /// Submit a transaction using the transfer request details.
-    /// Returns (signature, blockhash) for Jito double-spend protection (blockhash used for expiry checks).
+    /// Returns (outcome, blockhash) for Jito double-spend protection (blockhash used for expiry checks).
     async fn submit_transaction(
         &self,
         request: &TransferRequest,
-    ) -> Result<(String, String), AppError>;
+    ) -> Result<(SubmissionOutcome, String), AppError>;
 
     /// Get transaction confirmation status
     async fn get_transaction_status(&self, signature: &str) -> Result<bool, AppError> {
@@ -246,13 +597,17 @@ pub trait BlockchainClient: Send + Sync {
 
     /// Transfer SOL from the issuer wallet to a destination address
     /// Amount is in lamports (1 SOL = 1_000_000_000 lamports)
-    /// Returns (signature, blockhash) on success for Jito double-spend protection
+    /// An optional memo is attached via the SPL Memo program
+    /// `options` carries the transfer's own submission overrides
+    /// Returns (outcome, blockhash) on success for Jito double-spend protection
     async fn transfer_sol(
         &self,
         to_address: &str,
         amount_lamports: u64,
-    ) -> Result<(String, String), AppError> {
-        let _ = (to_address, amount_lamports);
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        let _ = (to_address, amount_lamports, memo, options);
         Err(AppError::NotSupported(
             "transfer_sol not implemented".to_string(),
         ))
@@ -262,23 +617,52 @@ pub trait BlockchainClient: Send + Sync {
     /// Creates the destination ATA if it doesn't exist
     /// Amount is in raw token units (caller must pre-convert using token decimals)
     /// Example: 1 USDC (6 decimals) = 1_000_000 raw units
-    /// Returns (signature, blockhash) on success for Jito double-spend protection
+    /// An optional memo is attached via the SPL Memo program
+    /// `options` carries the transfer's own submission overrides
+    /// Returns (outcome, blockhash) on success for Jito double-spend protection
     async fn transfer_token(
         &self,
         to_address: &str,
         token_mint: &str,
         amount: u64,
-    ) -> Result<(String, String), AppError> {
-        let _ = (to_address, token_mint, amount);
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        let _ = (to_address, token_mint, amount, memo, options);
         Err(AppError::NotSupported(
             "transfer_token not implemented".to_string(),
         ))
     }
 
+    /// Transfer SPL Tokens from the issuer wallet to several recipients,
+    /// given as `(address, raw amount)` pairs
+    /// Every recipient is paid by one atomic transaction; a batch that does not
+    /// fit the packet size is rejected
+    /// An optional memo is attached to the transaction
+    /// `options` carries the transfer's own submission overrides
+    /// Returns (outcome, blockhash) on success
+    async fn transfer_token_batch(
+        &self,
+        token_mint: &str,
+        recipients: &[(String, u64)],
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        let _ = (token_mint, recipients, memo, options);
+        Err(AppError::NotSupported(
+            "transfer_token_batch not implemented".to_string(),
+        ))
+    }
+
     /// Transfer Token-2022 Confidential tokens
     /// The server constructs the instruction from structured proof components,
     /// ensuring full control over what it signs (mitigates Confused Deputy).
-    /// Returns (signature, blockhash) on success for Jito double-spend protection
+    /// The auditor pubkey is required for mints with an auditor, and the fee
+    /// proofs for mints with the confidential transfer fee extension.
+    /// An optional memo is attached to the final transfer transaction
+    /// `options` carries the transfer's own submission overrides
+    /// Returns (outcome, blockhash) on success for Jito double-spend protection
+    #[allow(clippy::too_many_arguments)]
     async fn transfer_confidential(
         &self,
         to_address: &str,
@@ -287,7 +671,12 @@ pub trait BlockchainClient: Send + Sync {
         equality_proof: &str,
         ciphertext_validity_proof: &str,
         range_proof: &str,
-    ) -> Result<(String, String), AppError> {
+        auditor_elgamal_pubkey: Option<&str>,
+        fee_sigma_proof: Option<&str>,
+        fee_ciphertext_validity_proof: Option<&str>,
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
         let _ = (
             to_address,
             token_mint,
@@ -295,17 +684,23 @@ pub trait BlockchainClient: Send + Sync {
             equality_proof,
             ciphertext_validity_proof,
             range_proof,
+            auditor_elgamal_pubkey,
+            fee_sigma_proof,
+            fee_ciphertext_validity_proof,
+            memo,
+            options,
         );
         Err(AppError::NotSupported(
             "transfer_confidential not implemented".to_string(),
         ))
     }
 
-    /// Check if a wallet holds compliant assets using DAS (Digital Asset Standard).
-    /// This is a Helius-specific feature for compliance screening.
+    /// Check if a wallet holds compliant assets, preferably using Helius DAS
+    /// (Digital Asset Standard). Other providers may fall back to scanning the
+    /// wallet's token accounts against configured sanctioned mints.
     ///
-    /// Returns `false` if the wallet holds assets from sanctioned collections.
-    /// For non-Helius providers, returns `true` (skip check / assume compliant).
+    /// Returns `false` if the wallet holds assets from sanctioned collections or mints.
+    /// Without any way to screen assets, returns `true` (skip check / assume compliant).
     ///
     /// # Arguments
     /// * `owner` - The wallet address (Base58) to check
@@ -355,6 +750,114 @@ pub trait BlockchainClient: Send + Sync {
         ))
     }
 
+    /// Query the Jito bundle that carried a transaction, if it was submitted as one.
+    ///
+    /// Lets the crank tell a dropped bundle (safe to retry) apart from one that
+    /// may still land while the blockhash is valid.
+    ///
+    /// # Returns
+    /// - `Ok(Some(status))` - Bundle status from the block engine
+    /// - `Ok(None)` - Transaction was not submitted as a tracked bundle
+    async fn get_bundle_status(&self, signature: &str) -> Result<Option<BundleStatus>, AppError> {
+        let _ = signature;
+        Ok(None)
+    }
+
+    /// Query the multisig proposal a transfer was submitted as.
+    ///
+    /// # Returns
+    /// - `Ok(Some(status))` - Status stored in the proposal account
+    /// - `Ok(None)` - Proposal account not found (its creation may not have landed yet)
+    async fn get_proposal_status(
+        &self,
+        proposal: &str,
+    ) -> Result<Option<ProposalStatus>, AppError> {
+        let _ = proposal;
+        Err(AppError::NotSupported(
+            "get_proposal_status not implemented".to_string(),
+        ))
+    }
+
+    /// Public keys (base58) of the active issuer keys new transfers are assigned to.
+    fn signer_pubkeys(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Pick the issuer key for a transfer's first submission.
+    ///
+    /// The choice is pinned to the transfer and passed back through
+    /// [`TransferRequest::signer_pubkey`] on every later attempt.
+    /// Returns `None` when the client has no keys to choose from.
+    fn select_signer(&self, transfer_id: &str) -> Option<String> {
+        let _ = transfer_id;
+        None
+    }
+
+    /// Lamport balance of the relayer's fee payer (the signing keypair).
+    /// With several issuer keys, the lowest balance among them.
+    ///
+    /// The fee payer covers every fee and Jito tip, so once it runs dry all
+    /// transfers fail; the balance monitor polls this to warn before that.
+    async fn get_fee_payer_balance(&self) -> Result<u64, AppError> {
+        Err(AppError::NotSupported(
+            "get_fee_payer_balance not implemented".to_string(),
+        ))
+    }
+
+    /// Estimate the network fee of a transfer without signing or sending it.
+    ///
+    /// Builds the transfer's instructions as `transfer_sol` / `transfer_token`
+    /// would, prices the message with `getFeeForMessage`, and adds the current
+    /// priority fee and the Jito tip, if enabled.
+    ///
+    /// # Arguments
+    /// * `to_address` - Recipient wallet address (Base58)
+    /// * `amount` - Lamports, or raw token units when `token_mint` is set
+    /// * `token_mint` - SPL Token mint, or `None` for native SOL
+    /// * `memo` - Memo the transfer would carry
+    async fn estimate_fee(
+        &self,
+        to_address: &str,
+        amount: u64,
+        token_mint: Option<&str>,
+        memo: Option<&str>,
+    ) -> Result<FeeEstimate, AppError> {
+        let _ = (to_address, amount, token_mint, memo);
+        Err(AppError::NotSupported(
+            "estimate_fee not implemented".to_string(),
+        ))
+    }
+
+    /// Whether [`Self::close_empty_token_account`] is enabled, so callers can
+    /// skip loading the transfer when it would do nothing.
+    fn closes_empty_token_accounts(&self) -> bool {
+        false
+    }
+
+    /// Close the token account for `token_mint` of `signer` (the base58
+    /// public key the transfer was pinned to, or the primary key) if it is
+    /// empty, returning its rent to that fee payer.
+    ///
+    /// Only accounts the relayer owns are ever closed. Returns the signature
+    /// of the close transaction, or `None` if there was nothing to close.
+    async fn close_empty_token_account(
+        &self,
+        token_mint: &str,
+        signer: Option<&str>,
+    ) -> Result<Option<String>, AppError> {
+        let _ = (token_mint, signer);
+        Ok(None)
+    }
+
+    /// Whether transactions are built on a durable nonce instead of a recent blockhash.
+    ///
+    /// Durable nonce transactions never expire on their own; they only become
+    /// unprocessable once the nonce is advanced, so they are resubmitted
+    /// rather than marked `Expired`.
+    fn uses_durable_nonce(&self) -> bool {
+        false
+    }
+
     /// Classify a blockchain error into LastErrorType for retry logic.
     /// This helper method categorizes errors to determine safe retry strategies.
     fn classify_error(&self, error: &AppError) -> LastErrorType {
@@ -467,8 +970,11 @@ mod tests {
         async fn submit_transaction(
             &self,
             _request: &TransferRequest,
-        ) -> Result<(String, String), AppError> {
-            Ok(("sig_123".to_string(), "blockhash_default".to_string()))
+        ) -> Result<(SubmissionOutcome, String), AppError> {
+            Ok((
+                SubmissionOutcome::new("sig_123"),
+                "blockhash_default".to_string(),
+            ))
         }
     }
 
@@ -1,15 +1,19 @@
 //! Domain types with validation support.
 
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+
+use base64::{Engine as _, prelude::BASE64_STANDARD};
 use chrono::{DateTime, Utc};
 use ed25519_dalek::{Signature, VerifyingKey};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use validator::Validate;
 
-use crate::domain::AppError;
+use crate::domain::{AppError, ValidationError};
 
 /// Status of blockchain submission for a transfer
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, ToSchema)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BlockchainStatus {
     /// Initial state - request received and persisted, awaiting compliance check
@@ -24,13 +28,25 @@ pub enum BlockchainStatus {
     Processing,
     /// Transaction submitted, awaiting confirmation
     Submitted,
+    /// Submitted as a multisig proposal, waiting for the members' approvals.
+    /// The proposal address is tracked as the signature.
+    AwaitingApproval,
     /// Transaction confirmed on blockchain (finalized commitment)
     Confirmed,
     /// Submission failed after max retries
     Failed,
+    /// Rejected by compliance screening (blocklist or provider) and never submitted.
+    /// Terminal state.
+    Rejected,
+    /// Landed on-chain, but the observed transfers did not match the request
+    /// or could not be verified. Terminal state - the funds have moved, so it
+    /// is never resubmitted and needs manual review.
+    Unreconciled,
     /// Blockhash expired and transaction was not found on-chain.
     /// Terminal state - user must re-sign with a fresh nonce.
     Expired,
+    /// Cancelled by the user before submission. Terminal state.
+    Cancelled,
 }
 
 impl BlockchainStatus {
@@ -41,15 +57,27 @@ impl BlockchainStatus {
             Self::PendingSubmission => "pending_submission",
             Self::Processing => "processing",
             Self::Submitted => "submitted",
+            Self::AwaitingApproval => "awaiting_approval",
             Self::Confirmed => "confirmed",
             Self::Failed => "failed",
+            Self::Rejected => "rejected",
+            Self::Unreconciled => "unreconciled",
             Self::Expired => "expired",
+            Self::Cancelled => "cancelled",
         }
     }
 
     /// Check if this is a terminal state (no further transitions possible)
     pub fn is_terminal(&self) -> bool {
-        matches!(self, Self::Confirmed | Self::Failed | Self::Expired)
+        matches!(
+            self,
+            Self::Confirmed
+                | Self::Failed
+                | Self::Rejected
+                | Self::Unreconciled
+                | Self::Expired
+                | Self::Cancelled
+        )
     }
 }
 
@@ -63,9 +91,13 @@ impl std::str::FromStr for BlockchainStatus {
             "pending_submission" => Ok(Self::PendingSubmission),
             "processing" => Ok(Self::Processing),
             "submitted" => Ok(Self::Submitted),
+            "awaiting_approval" => Ok(Self::AwaitingApproval),
             "confirmed" => Ok(Self::Confirmed),
             "failed" => Ok(Self::Failed),
+            "rejected" => Ok(Self::Rejected),
+            "unreconciled" => Ok(Self::Unreconciled),
             "expired" => Ok(Self::Expired),
+            "cancelled" => Ok(Self::Cancelled),
             _ => Err(format!("Invalid blockchain status: {}", s)),
         }
     }
@@ -119,6 +151,41 @@ impl std::fmt::Display for ComplianceStatus {
     }
 }
 
+/// Outcome of screening a transfer with a compliance provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplianceVerdict {
+    pub status: ComplianceStatus,
+    /// Provider's risk category behind a rejection (e.g. `sanctions`,
+    /// `high_risk`), when it reports one
+    pub category: Option<String>,
+}
+
+impl ComplianceVerdict {
+    /// Approval without a category
+    #[must_use]
+    pub fn approved() -> Self {
+        ComplianceStatus::Approved.into()
+    }
+
+    /// Rejection in `category`
+    #[must_use]
+    pub fn rejected(category: impl Into<String>) -> Self {
+        Self {
+            status: ComplianceStatus::Rejected,
+            category: Some(category.into()),
+        }
+    }
+}
+
+impl From<ComplianceStatus> for ComplianceVerdict {
+    fn from(status: ComplianceStatus) -> Self {
+        Self {
+            status,
+            category: None,
+        }
+    }
+}
+
 // ============================================================================
 // Jito Double Spend Protection Types
 // ============================================================================
@@ -206,16 +273,203 @@ pub enum TransactionStatus {
     Failed(String),
 }
 
+/// Status of a Squads multisig proposal, as stored in its account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProposalStatus {
+    /// Created but not yet open for voting
+    Draft,
+    /// Open for voting
+    Active,
+    /// Rejected by enough members that it can never pass
+    Rejected,
+    /// Reached the approval threshold, waiting to be executed
+    Approved,
+    /// Being executed (only seen mid-execution)
+    Executing,
+    /// Executed on-chain
+    Executed,
+    /// Cancelled by the members after approval
+    Cancelled,
+}
+
+impl ProposalStatus {
+    /// Blockchain status a transfer awaiting approval moves to once its
+    /// proposal reaches this status, or `None` while it is still undecided
+    #[must_use]
+    pub fn blockchain_status(self) -> Option<BlockchainStatus> {
+        match self {
+            Self::Executed => Some(BlockchainStatus::Confirmed),
+            Self::Rejected | Self::Cancelled => Some(BlockchainStatus::Failed),
+            Self::Draft | Self::Active | Self::Approved | Self::Executing => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Active => "active",
+            Self::Rejected => "rejected",
+            Self::Approved => "approved",
+            Self::Executing => "executing",
+            Self::Executed => "executed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// Jito bundle status reported by the block engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleStatus {
+    /// Bundle is still being processed
+    Pending,
+    /// Bundle landed on-chain
+    Landed { slot: Option<u64> },
+    /// Bundle failed or was dropped without landing
+    Failed,
+    /// Bundle is unknown to the block engine (never received, or outside its lookback window)
+    Invalid,
+}
+
+impl BundleStatus {
+    /// Whether the bundle will never land, making a retry with a new blockhash safe
+    #[must_use]
+    pub fn is_dropped(self) -> bool {
+        matches!(self, Self::Failed)
+    }
+}
+
+/// Result of handing a transaction to the network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionOutcome {
+    /// Transaction signature (Base58)
+    pub signature: String,
+    /// Jito bundle that carried the transaction, if it was sent as a bundle
+    pub bundle_id: Option<String>,
+    /// Whether `signature` is a multisig proposal address waiting for
+    /// approvals rather than a transaction signature
+    pub awaiting_approval: bool,
+    /// Proof verification transactions confirmed before `signature`, the
+    /// final transfer of a confidential transfer
+    pub proof_signatures: Vec<String>,
+    /// Raw token units debited from the source, when the mint charges a
+    /// Token-2022 transfer fee
+    pub token_sent_amount: Option<u64>,
+    /// Token-2022 transfer fee withheld from the recipients, in raw units
+    pub token_transfer_fee: Option<u64>,
+    /// When the relayer stops waiting for the multisig proposal to be decided
+    pub approval_expires_at: Option<DateTime<Utc>>,
+}
+
+impl SubmissionOutcome {
+    /// Outcome of a transaction sent outside a bundle
+    #[must_use]
+    pub fn new(signature: impl Into<String>) -> Self {
+        Self {
+            signature: signature.into(),
+            bundle_id: None,
+            awaiting_approval: false,
+            proof_signatures: Vec::new(),
+            token_sent_amount: None,
+            token_transfer_fee: None,
+            approval_expires_at: None,
+        }
+    }
+
+    /// Outcome of a transfer proposed to a multisig: `proposal` is the
+    /// proposal account address, tracked in place of a signature
+    #[must_use]
+    pub fn proposal(proposal: impl Into<String>) -> Self {
+        Self {
+            awaiting_approval: true,
+            ..Self::new(proposal)
+        }
+    }
+
+    /// Status the transfer is recorded in once this outcome is returned
+    #[must_use]
+    pub fn submitted_status(&self) -> BlockchainStatus {
+        if self.awaiting_approval {
+            BlockchainStatus::AwaitingApproval
+        } else {
+            BlockchainStatus::Submitted
+        }
+    }
+
+    /// Record the Jito bundle that carried the transaction
+    #[must_use]
+    pub fn with_bundle_id(mut self, bundle_id: impl Into<String>) -> Self {
+        self.bundle_id = Some(bundle_id.into());
+        self
+    }
+
+    /// Record the proof verification transactions sent before the transfer
+    #[must_use]
+    pub fn with_proof_signatures(mut self, proof_signatures: Vec<String>) -> Self {
+        self.proof_signatures = proof_signatures;
+        self
+    }
+
+    /// Record what a transfer of a Token-2022 fee mint debited and withheld
+    #[must_use]
+    pub fn with_token_transfer_fee(mut self, sent_amount: u64, fee: u64) -> Self {
+        self.token_sent_amount = Some(sent_amount);
+        self.token_transfer_fee = Some(fee);
+        self
+    }
+
+    /// Stop waiting for the proposal to be decided at `expires_at`
+    #[must_use]
+    pub fn with_approval_expiry(mut self, expires_at: DateTime<Utc>) -> Self {
+        self.approval_expires_at = Some(expires_at);
+        self
+    }
+}
+
+/// Per-transfer overrides of how a transfer is submitted. The default
+/// applies the client's configuration unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubmitOptions<'a> {
+    /// Base58 public key of the issuer key that signs and pays for the
+    /// transfer, instead of the primary key
+    pub signer: Option<&'a str>,
+    /// Jito region the transfer's bundles are sent to, instead of the
+    /// configured `JITO_REGION`
+    pub jito_region: Option<&'a str>,
+    /// Whether submissions skip preflight, instead of the configured
+    /// `SKIP_PREFLIGHT`
+    pub skip_preflight: Option<bool>,
+}
+
+impl<'a> SubmitOptions<'a> {
+    /// Overrides requested by `request`
+    #[must_use]
+    pub fn for_transfer(request: &'a TransferRequest) -> Self {
+        Self {
+            signer: request.signer_pubkey.as_deref(),
+            jito_region: request.jito_region.as_deref(),
+            skip_preflight: request.skip_preflight,
+        }
+    }
+}
+
 /// Type of transfer and associated data
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum TransferType {
     /// Standard public transfer with visible amount
     Public {
-        /// Amount in atomic units
+        /// Amount in atomic units. May be omitted when `amount_sol` is set.
         #[schema(example = 1_000_000_000)]
+        #[serde(default)]
         amount: u64,
     },
+    /// Public transfer of one SPL token to several recipients in a single
+    /// atomic transaction, which must fit the 1232-byte packet limit
+    PublicBatch {
+        /// `(recipient address, amount in atomic units)` pairs, paid in order.
+        /// The first recipient must match `to_address`.
+        recipients: Vec<(String, u64)>,
+    },
     /// Confidential transfer with zero-knowledge proofs (Token-2022)
     ///
     /// The client must generate all ZK proofs locally and submit the individual
@@ -244,6 +498,26 @@ pub enum TransferType {
         /// and within valid range
         #[schema(example = "SGVsbG8gUmFuZ2UgUHJvb2Y=")]
         range_proof: String,
+
+        /// Auditor ElGamal public key (Base64, 32 bytes)
+        /// Required when the mint has an auditor, and must match the mint's auditor
+        /// key and the third handle of the ciphertext validity proof
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        auditor_elgamal_pubkey: Option<String>,
+
+        /// Fee sigma proof (Base64 serialized PercentageWithCapProofData)
+        /// Required, together with the fee ciphertext validity proof, when the mint
+        /// has the confidential transfer fee extension. The range proof is then a
+        /// BatchedRangeProofU256Data covering the fee as well.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fee_sigma_proof: Option<String>,
+
+        /// Fee ciphertext validity proof (Base64 serialized
+        /// BatchedGroupedCiphertext2HandlesValidityProofData)
+        /// Proves that the fee ciphertexts are correctly constructed for the
+        /// destination and the withdraw withheld authority
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fee_ciphertext_validity_proof: Option<String>,
     },
 }
 
@@ -270,6 +544,10 @@ pub struct TransferRequest {
 
     /// Compliance check status
     pub compliance_status: ComplianceStatus,
+    /// Why compliance rejected the transfer. Cleared when a blocklist
+    /// rejection is lifted and the transfer retried.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub rejection_reason: Option<RejectionReason>,
     /// Blockchain submission status
     pub blockchain_status: BlockchainStatus,
     /// Blockchain transaction signature (if submitted)
@@ -279,7 +557,8 @@ pub struct TransferRequest {
     pub blockchain_retry_count: i32,
     /// Last error message from blockchain submission
     pub blockchain_last_error: Option<String>,
-    /// Next scheduled retry time
+    /// Next scheduled retry time. While awaiting approval, when the relayer
+    /// stops waiting for the multisig proposal to be decided
     pub blockchain_next_retry_at: Option<DateTime<Utc>>,
 
     // =========================================================================
@@ -300,6 +579,30 @@ pub struct TransferRequest {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub blockhash_used: Option<String>,
 
+    /// Jito bundle ID of the last submission, for correlating with Jito's
+    /// bundle explorer. None when the transaction was not sent as a bundle.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub bundle_id: Option<String>,
+
+    /// Signatures of a confidential transfer's proof verification
+    /// transactions. Only `blockchain_signature`, the final transfer,
+    /// confirms the request.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub proof_signatures: Vec<String>,
+
+    /// Raw token units debited from the source when the mint charges a
+    /// Token-2022 transfer fee: the requested amount, grossed up for the fee
+    /// when `GROSS_UP_TRANSFER_FEES` is set. Recorded on submission.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    #[schema(example = 1_010_101)]
+    pub token_sent_amount: Option<u64>,
+
+    /// Token-2022 transfer fee withheld from the recipients, in raw units.
+    /// Recorded on submission.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    #[schema(example = 10_101)]
+    pub token_transfer_fee: Option<u64>,
+
     // =========================================================================
     // Request Uniqueness Fields (Replay Protection & Idempotency)
     // =========================================================================
@@ -313,6 +616,54 @@ pub struct TransferRequest {
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub client_signature: Option<String>,
 
+    /// `valid_until` of the signed request. Once it has passed the nonce is
+    /// no longer needed for replay protection and may be purged.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub valid_until: Option<DateTime<Utc>>,
+
+    /// Memo attached on-chain via the SPL Memo program
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    #[schema(example = "INV-2026-0042")]
+    pub memo: Option<String>,
+
+    /// Processing priority; higher values are submitted first
+    #[serde(default)]
+    #[schema(example = 0)]
+    pub priority: i16,
+
+    /// When the transfer was archived. Archived transfers are hidden from
+    /// listings and worker queues but can still be fetched by id.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub archived_at: Option<DateTime<Utc>>,
+
+    /// `X-Request-Id` of the API request that created the transfer
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub correlation_id: Option<String>,
+
+    /// URL notified of status changes (overrides the global callback URL)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub callback_url: Option<String>,
+
+    /// Jito region the transfer's bundles are sent to (overrides `JITO_REGION`)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub jito_region: Option<String>,
+
+    /// Whether the transfer's submissions skip preflight (overrides `SKIP_PREFLIGHT`)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub skip_preflight: Option<bool>,
+
+    /// Issuer public key pinned on first submission; it signs and pays for every attempt
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub signer_pubkey: Option<String>,
+
+    /// Block explorer link of `blockchain_signature` on the relayer's cluster.
+    /// Derived when responding, never stored.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    #[schema(
+        example = "https://explorer.solana.com/tx/5wHu1qwD7q5ifaN5nwdcDqNFo53GJqa7nLp2BeeEpcHCusb4GzARz4GjgzsEHMkBMgCJMGa6GSQM3TYWMwnS1vKe?cluster=devnet"
+    )]
+    pub explorer_url: Option<String>,
+
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
     /// Last update timestamp
@@ -330,6 +681,7 @@ impl TransferRequest {
             transfer_details: TransferType::Public { amount },
             token_mint: None,
             compliance_status: ComplianceStatus::Pending,
+            rejection_reason: None,
             blockchain_status: BlockchainStatus::Pending,
             blockchain_signature: None,
             blockchain_retry_count: 0,
@@ -339,9 +691,23 @@ impl TransferRequest {
             original_tx_signature: None,
             last_error_type: LastErrorType::None,
             blockhash_used: None,
+            bundle_id: None,
+            proof_signatures: Vec::new(),
+            token_sent_amount: None,
+            token_transfer_fee: None,
             // Request Uniqueness fields
             nonce: None,
             client_signature: None,
+            valid_until: None,
+            memo: None,
+            priority: 0,
+            archived_at: None,
+            correlation_id: None,
+            callback_url: None,
+            jito_region: None,
+            skip_preflight: None,
+            signer_pubkey: None,
+            explorer_url: None,
             created_at: now,
             updated_at: now,
         }
@@ -395,94 +761,952 @@ impl Default for TransferRequest {
     }
 }
 
-/// Request to submit a new transfer
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
-pub struct SubmitTransferRequest {
-    /// Sender wallet address (Base58 Solana address)
-    #[schema(example = "HvwC9QSAzwEXkUkwqNNGhfNHoVqXJYfPvPZfQvJmHWcF")]
-    pub from_address: String,
-    /// Recipient wallet address (Base58 Solana address)
-    #[schema(example = "DRpbCBMxVnDK7maPM5tGv6MvB3v1sRMC86PZ8okm21hy")]
-    pub to_address: String,
+/// Blockchain status transition of a transfer, streamed to status subscribers
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct TransferStatusEvent {
+    /// Transfer request ID
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: String,
+    /// Blockchain submission status after the transition
+    pub blockchain_status: BlockchainStatus,
+    /// Blockchain transaction signature (if submitted)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub blockchain_signature: Option<String>,
+    /// Last blockchain error (if any)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub blockchain_last_error: Option<String>,
+    /// When the transition was observed
+    pub updated_at: DateTime<Utc>,
+}
 
-    /// Transfer details (Public or Confidential)
-    pub transfer_details: TransferType,
+impl From<&TransferRequest> for TransferStatusEvent {
+    fn from(request: &TransferRequest) -> Self {
+        Self {
+            id: request.id.clone(),
+            blockchain_status: request.blockchain_status,
+            blockchain_signature: request.blockchain_signature.clone(),
+            blockchain_last_error: request.blockchain_last_error.clone(),
+            updated_at: request.updated_at,
+        }
+    }
+}
 
-    /// Optional SPL Token mint address. If None, this is a native SOL transfer.
-    /// If Some, this is an SPL Token transfer for the specified mint.
-    #[schema(example = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")]
-    #[serde(skip_serializing_if = "Option::is_none", default)]
-    pub token_mint: Option<String>,
+/// Which status of a transfer a transition changed
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusKind {
+    /// Compliance screening status
+    Compliance,
+    /// Blockchain submission status
+    Blockchain,
+}
 
-    /// Base58-encoded Ed25519 signature proving ownership of from_address.
-    /// The message format is: "{from_address}:{to_address}:{amount|confidential}:{token_mint|SOL}:{nonce}"
-    #[schema(
-        example = "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d"
-    )]
-    pub signature: String,
+impl StatusKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Compliance => "compliance",
+            Self::Blockchain => "blockchain",
+        }
+    }
+}
 
-    /// Unique nonce for replay protection (UUID v7 recommended).
-    /// Must be included in the signature message to prevent replay attacks.
-    /// Format: "{from}:{to}:{amount|confidential}:{mint|SOL}:{nonce}"
-    #[schema(example = "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a")]
-    pub nonce: String,
+impl std::str::FromStr for StatusKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "compliance" => Ok(Self::Compliance),
+            "blockchain" => Ok(Self::Blockchain),
+            _ => Err(format!("Invalid status kind: {}", s)),
+        }
+    }
 }
 
-impl Validate for SubmitTransferRequest {
-    fn validate(&self) -> Result<(), validator::ValidationErrors> {
-        let mut errors = validator::ValidationErrors::new();
+/// One entry in the status history of a transfer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct StatusTransition {
+    /// Transfer request ID
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub transfer_id: String,
+    /// Which status changed
+    pub kind: StatusKind,
+    /// Status before the update (null if unknown)
+    #[schema(example = "pending_submission")]
+    pub old_status: Option<String>,
+    /// Status after the update
+    #[schema(example = "submitted")]
+    pub new_status: String,
+    /// Error recorded with the update (if any)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<String>,
+    /// Structured reason of a compliance rejection
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub rejection_reason: Option<RejectionReason>,
+    /// When the update was applied
+    pub created_at: DateTime<Utc>,
+}
 
-        if self.from_address.is_empty() {
-            errors.add(
-                "from_address",
-                validator::ValidationError::new("From address is required"),
-            );
+impl StatusTransition {
+    /// Blockchain status transition recorded now
+    #[must_use]
+    pub fn blockchain(
+        transfer_id: &str,
+        old_status: Option<BlockchainStatus>,
+        new_status: BlockchainStatus,
+        error: Option<&str>,
+    ) -> Self {
+        Self {
+            transfer_id: transfer_id.to_string(),
+            kind: StatusKind::Blockchain,
+            old_status: old_status.map(|status| status.as_str().to_string()),
+            new_status: new_status.as_str().to_string(),
+            error: error.map(ToString::to_string),
+            rejection_reason: None,
+            created_at: Utc::now(),
         }
-        if self.to_address.is_empty() {
-            errors.add(
-                "to_address",
-                validator::ValidationError::new("To address is required"),
-            );
+    }
+
+    /// Compliance status transition recorded now
+    #[must_use]
+    pub fn compliance(
+        transfer_id: &str,
+        old_status: Option<ComplianceStatus>,
+        new_status: ComplianceStatus,
+    ) -> Self {
+        Self {
+            transfer_id: transfer_id.to_string(),
+            kind: StatusKind::Compliance,
+            old_status: old_status.map(|status| status.as_str().to_string()),
+            new_status: new_status.as_str().to_string(),
+            error: None,
+            rejection_reason: None,
+            created_at: Utc::now(),
         }
+    }
 
-        // Nonce validation for replay protection
-        if self.nonce.is_empty() {
-            errors.add(
-                "nonce",
-                validator::ValidationError::new("Nonce is required for replay protection"),
-            );
-        } else if self.nonce.len() < 32 || self.nonce.len() > 64 {
-            errors.add(
-                "nonce",
-                validator::ValidationError::new("Nonce must be 32-64 characters (UUID format)"),
-            );
-        } else if !self
-            .nonce
-            .chars()
-            .all(|c| c.is_ascii_alphanumeric() || c == '-')
-        {
-            errors.add(
-                "nonce",
-                validator::ValidationError::new(
-                    "Nonce must be alphanumeric with optional hyphens (UUID format)",
-                ),
-            );
+    #[must_use]
+    pub fn with_rejection_reason(mut self, reason: Option<RejectionReason>) -> Self {
+        self.rejection_reason = reason;
+        self
+    }
+}
+
+/// Structured reason a transfer was rejected by compliance screening, for
+/// clients that branch on or localize rejections
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct RejectionReason {
+    /// What rejected the transfer: `blocklist` or the compliance provider name
+    #[schema(example = "blocklist")]
+    pub source: String,
+    /// Machine-readable category: the blocklist category, or the
+    /// provider's risk category
+    #[schema(example = "ofac_sanction")]
+    pub category: String,
+    /// Risk score of the screened address, when one is known
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub risk_score: Option<i32>,
+    /// Human-readable message, also stored as `blockchain_last_error`
+    #[schema(example = "Blocklist: OFAC SDN list")]
+    pub message: String,
+}
+
+impl RejectionReason {
+    /// Rejection by an internal blocklist entry
+    #[must_use]
+    pub fn blocklist(category: BlocklistCategory, reason: &str) -> Self {
+        Self {
+            source: "blocklist".to_string(),
+            category: category.as_str().to_string(),
+            risk_score: None,
+            message: format!("Blocklist: {}", reason),
         }
+    }
 
-        match &self.transfer_details {
-            TransferType::Public { amount } => {
-                if *amount == 0 {
-                    errors.add(
-                        "amount",
-                        validator::ValidationError::new("Amount must be greater than 0"),
-                    );
-                }
-            }
-            TransferType::Confidential {
-                new_decryptable_available_balance,
-                equality_proof,
-                ciphertext_validity_proof,
-                range_proof,
+    /// Rejection by an external compliance provider
+    #[must_use]
+    pub fn provider(provider: &str, category: &str, message: impl Into<String>) -> Self {
+        Self {
+            source: provider.to_string(),
+            category: category.to_string(),
+            risk_score: None,
+            message: message.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_risk_score(mut self, risk_score: Option<i32>) -> Self {
+        self.risk_score = risk_score;
+        self
+    }
+}
+
+/// Compliance decision taken while screening a submitted transfer
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ComplianceDecision {
+    /// Decision ID
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: String,
+    /// Transfer request ID
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub transfer_id: String,
+    /// Screened address: the recipient, or the sender when it is blocklisted
+    #[schema(example = "DRpbCBMxVnDK7maPM5tGv6MvB3v1sRMC86PZ8okm21hy")]
+    pub address: String,
+    /// `approved` or `rejected`
+    pub decision: ComplianceStatus,
+    /// What decided: `allowlist`, `blocklist` or the compliance provider name
+    #[schema(example = "range")]
+    pub provider: String,
+    /// Risk score of the screened address, when one is known
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub risk_score: Option<i32>,
+    /// Reason for the decision
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub reason: Option<String>,
+    /// When the decision was taken
+    pub created_at: DateTime<Utc>,
+}
+
+impl ComplianceDecision {
+    /// Decision about `address` taken now
+    #[must_use]
+    pub fn new(
+        transfer_id: &str,
+        address: &str,
+        decision: ComplianceStatus,
+        provider: &str,
+    ) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            transfer_id: transfer_id.to_string(),
+            address: address.to_string(),
+            decision,
+            provider: provider.to_string(),
+            risk_score: None,
+            reason: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_risk_score(mut self, risk_score: Option<i32>) -> Self {
+        self.risk_score = risk_score;
+        self
+    }
+}
+
+/// Filters for listing compliance decisions; unset fields match everything
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ComplianceDecisionFilter {
+    pub decision: Option<ComplianceStatus>,
+    /// Inclusive lower bound on `created_at`
+    pub created_after: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `created_at`
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl ComplianceDecisionFilter {
+    /// Whether `decision` passes every set filter
+    pub fn matches(&self, decision: &ComplianceDecision) -> bool {
+        self.decision
+            .is_none_or(|status| status == decision.decision)
+            && self
+                .created_after
+                .is_none_or(|after| decision.created_at >= after)
+            && self
+                .created_before
+                .is_none_or(|before| decision.created_at < before)
+    }
+}
+
+/// Query parameters of the compliance decision list
+#[derive(Debug, Clone, Deserialize)]
+pub struct ComplianceDecisionQuery {
+    /// Maximum number of decisions to return (1-100, default: 20)
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    /// Cursor for pagination (decision ID to start after)
+    pub cursor: Option<String>,
+    /// Only decisions with this outcome
+    pub decision: Option<ComplianceStatus>,
+    /// Only decisions taken at or after this time (RFC 3339)
+    pub created_after: Option<DateTime<Utc>>,
+    /// Only decisions taken before this time (RFC 3339)
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl ComplianceDecisionQuery {
+    /// Filters to apply to the decision list
+    #[must_use]
+    pub fn filter(&self) -> ComplianceDecisionFilter {
+        ComplianceDecisionFilter {
+            decision: self.decision,
+            created_after: self.created_after,
+            created_before: self.created_before,
+        }
+    }
+}
+
+/// Delivery state of an outbound webhook notification
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// Waiting for its next attempt
+    #[default]
+    Pending,
+    /// Acknowledged by the receiver with a 2xx response
+    Delivered,
+    /// Gave up after the maximum number of attempts
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookDeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "delivered" => Ok(Self::Delivered),
+            "failed" => Ok(Self::Failed),
+            _ => Err(format!("Invalid webhook delivery status: {}", s)),
+        }
+    }
+}
+
+/// Outbound webhook notification of one transfer status change
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDelivery {
+    /// Delivery ID, sent to the receiver for deduplication
+    pub id: String,
+    /// Transfer request ID
+    pub transfer_id: String,
+    /// Identifies the status change; only one delivery is queued per key
+    pub event_key: String,
+    /// Callback URL the notification is POSTed to
+    pub url: String,
+    /// Status change being notified
+    pub event: TransferStatusEvent,
+    /// Delivery state
+    pub status: WebhookDeliveryStatus,
+    /// Attempts made so far
+    pub attempts: i32,
+    /// When the next attempt is due
+    pub next_attempt_at: DateTime<Utc>,
+    /// Error of the last failed attempt
+    pub last_error: Option<String>,
+    /// Creation timestamp
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookDelivery {
+    /// Pending delivery of `event` to `url`, due now
+    #[must_use]
+    pub fn new(url: impl Into<String>, event: TransferStatusEvent) -> Self {
+        let now = Utc::now();
+        // A resubmission is a new `submitted` event, so the signature is part of the key
+        let event_key = format!(
+            "{}:{}:{}",
+            event.id,
+            event.blockchain_status.as_str(),
+            event.blockchain_signature.as_deref().unwrap_or_default()
+        );
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            transfer_id: event.id.clone(),
+            event_key,
+            url: url.into(),
+            event,
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// Response recorded for an `Idempotency-Key`, replayed on retries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IdempotencyRecord {
+    /// SHA-256 (hex) of the API key the key was used with, or empty without
+    /// one; the same key under another API key is a different record
+    pub scope: String,
+    /// Client-chosen idempotency key
+    pub key: String,
+    /// SHA-256 (hex) of the request body the key was first used with
+    pub request_hash: String,
+    /// HTTP status code of the original response
+    pub status_code: u16,
+    /// Original JSON response body, byte for byte
+    pub response_body: String,
+    /// Transfer created by the original request, if any
+    pub transfer_id: Option<String>,
+    /// When the key was first used
+    pub created_at: DateTime<Utc>,
+    /// When the record stops being replayed and may be purged
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Rolling cap on a sender's native SOL transfers, checked as a new transfer
+/// is persisted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SenderLimit {
+    /// Lamports moved by the transfer being submitted
+    pub amount_lamports: u64,
+    /// Largest total the sender may transfer within the window, in lamports
+    pub limit_lamports: u64,
+    /// Start of the window
+    pub since: DateTime<Utc>,
+}
+
+impl SenderLimit {
+    /// Reject the transfer if `sent` lamports already counted in the window
+    /// plus its own amount exceed the limit
+    pub fn check(&self, sent: u64) -> Result<(), AppError> {
+        if sent.saturating_add(self.amount_lamports) <= self.limit_lamports {
+            return Ok(());
+        }
+        Err(AppError::Validation(
+            ValidationError::TransferLimitExceeded(format!(
+                "Transfer of {} lamports would exceed the sender's daily limit of {} \
+                 lamports ({} lamports sent in the last 24 hours)",
+                self.amount_lamports, self.limit_lamports, sent
+            )),
+        ))
+    }
+}
+
+/// Outcome of one item of a bulk transfer submission
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkTransferResult {
+    /// Position of the item in the submitted array
+    pub index: usize,
+    /// Created (or, for a reused nonce, existing) transfer request ID
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Option<String>,
+    /// Compliance status of the transfer (rejected items are still recorded)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub compliance_status: Option<ComplianceStatus>,
+    /// Blockchain status of the transfer
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub blockchain_status: Option<BlockchainStatus>,
+    /// Why the item was not accepted
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<ErrorDetail>,
+}
+
+impl BulkTransferResult {
+    /// Result for an item that produced a transfer request
+    #[must_use]
+    pub fn accepted(index: usize, request: &TransferRequest) -> Self {
+        Self {
+            index,
+            id: Some(request.id.clone()),
+            compliance_status: Some(request.compliance_status),
+            blockchain_status: Some(request.blockchain_status),
+            error: None,
+        }
+    }
+
+    /// Result for an item that failed before a transfer request was returned
+    #[must_use]
+    pub fn failed(index: usize, error: ErrorDetail) -> Self {
+        Self {
+            index,
+            id: None,
+            compliance_status: None,
+            blockchain_status: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// Per-item results of a bulk transfer submission, in request order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkTransferResponse {
+    /// Items that produced a transfer request
+    pub accepted: usize,
+    /// Items that failed
+    pub failed: usize,
+    /// One result per submitted item
+    pub results: Vec<BulkTransferResult>,
+}
+
+/// Query parameters for transfer submission
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SubmitTransferQuery {
+    /// Run validation and compliance checks only; nothing is persisted or submitted
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Outcome of a dry-run submission
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DryRunResult {
+    /// Always `true`: no transfer was recorded, queued or sent
+    #[schema(example = true)]
+    pub dry_run: bool,
+    /// Compliance status the transfer would get
+    pub compliance_status: ComplianceStatus,
+    /// Why the transfer would be rejected
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    #[schema(example = "Blocklist: Known scam address")]
+    pub rejection_reason: Option<String>,
+}
+
+impl DryRunResult {
+    /// Result for a transfer that would be approved
+    #[must_use]
+    pub fn approved() -> Self {
+        Self {
+            dry_run: true,
+            compliance_status: ComplianceStatus::Approved,
+            rejection_reason: None,
+        }
+    }
+
+    /// Result for a transfer that would be rejected
+    #[must_use]
+    pub fn rejected(reason: impl Into<String>) -> Self {
+        Self {
+            dry_run: true,
+            compliance_status: ComplianceStatus::Rejected,
+            rejection_reason: Some(reason.into()),
+        }
+    }
+}
+
+/// Request to submit a new transfer
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SubmitTransferRequest {
+    /// Sender wallet address (Base58 Solana address)
+    #[schema(example = "HvwC9QSAzwEXkUkwqNNGhfNHoVqXJYfPvPZfQvJmHWcF")]
+    pub from_address: String,
+    /// Recipient wallet address (Base58 Solana address)
+    #[schema(example = "DRpbCBMxVnDK7maPM5tGv6MvB3v1sRMC86PZ8okm21hy")]
+    pub to_address: String,
+
+    /// Transfer details (Public or Confidential)
+    pub transfer_details: TransferType,
+
+    /// Amount of a native SOL transfer as a decimal string of SOL (at most
+    /// 9 decimal places), given instead of the lamport `amount`. Converted to
+    /// lamports before the signature is checked, so the client signs the
+    /// lamport amount.
+    #[schema(example = "1.5")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub amount_sol: Option<String>,
+
+    /// Optional SPL Token mint address. If None, this is a native SOL transfer.
+    /// If Some, this is an SPL Token transfer for the specified mint.
+    #[schema(example = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub token_mint: Option<String>,
+
+    /// Base58-encoded Ed25519 signature proving ownership of from_address.
+    /// The message format is: "{from_address}:{to_address}:{amount|confidential}:{token_mint|SOL}:{nonce}"
+    /// Requests with a memo sign the v2 format instead (see `create_signing_message`).
+    #[schema(
+        example = "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d"
+    )]
+    pub signature: String,
+
+    /// Unique nonce for replay protection (UUID v7 recommended).
+    /// Must be included in the signature message to prevent replay attacks.
+    /// Format: "{from}:{to}:{amount|confidential}:{mint|SOL}:{nonce}"
+    #[schema(example = "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a")]
+    pub nonce: String,
+
+    /// Optional memo recorded on-chain with the SPL Memo program
+    /// (at most `MAX_MEMO_LEN` bytes). Covered by the signature.
+    #[schema(example = "INV-2026-0042")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub memo: Option<String>,
+
+    /// Processing priority (default 0). Pending transfers with a higher
+    /// priority are submitted first. Covered by the signature when non-zero.
+    #[schema(example = 0)]
+    #[serde(skip_serializing_if = "is_default_priority", default)]
+    pub priority: i16,
+
+    /// Optional `http(s)` URL notified when the transfer is submitted,
+    /// confirmed, failed or expired. Not covered by the signature.
+    #[schema(example = "https://merchant.example.com/hooks/relayer")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub callback_url: Option<String>,
+
+    /// Version of the signed message format (1 to 6). When omitted, the
+    /// oldest version covering the memo, priority and expiry is used.
+    /// Unknown versions are rejected.
+    #[schema(example = 2)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub signature_version: Option<u8>,
+
+    /// Time after which the request is rejected, bounding how late a
+    /// captured request can be submitted. Covered by the signature (v4).
+    #[schema(example = "2026-01-30T10:35:00Z")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub valid_until: Option<DateTime<Utc>>,
+
+    /// Optional Jito region the bundle is sent to, overriding the configured
+    /// `JITO_REGION`. Must be one of `JITO_REGIONS`. Covered by the signature (v5).
+    #[schema(example = "frankfurt")]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub jito_region: Option<String>,
+
+    /// Optional override of the configured `SKIP_PREFLIGHT` for this transfer.
+    /// `false` preflights the transaction so a failing one is rejected before
+    /// it costs a fee or tip. Covered by the signature (v6).
+    #[schema(example = false)]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub skip_preflight: Option<bool>,
+}
+
+fn is_default_priority(priority: &i16) -> bool {
+    *priority == 0
+}
+
+/// Maximum memo length in bytes. Keeps the memo instruction well within the
+/// 1232-byte transaction packet alongside the transfer instructions.
+pub const MAX_MEMO_LEN: usize = 256;
+
+/// Maximum callback URL length in bytes
+pub const MAX_CALLBACK_URL_LEN: usize = 2048;
+
+/// Jito block-engine regions accepted as `sendBundle` region parameter
+pub const JITO_REGIONS: &[&str] = &[
+    "amsterdam",
+    "dublin",
+    "frankfurt",
+    "london",
+    "ny",
+    "slc",
+    "singapore",
+    "tokyo",
+];
+
+/// Maximum number of recipients in a batch transfer. A batch is paid by one
+/// transaction, which holds about this many transfers to existing token
+/// accounts; fewer fit when accounts must be created.
+pub const MAX_BATCH_RECIPIENTS: usize = 16;
+
+/// Length in bytes of an ElGamal public key
+pub const ELGAMAL_PUBKEY_LEN: usize = 32;
+
+/// Lamports in one SOL
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Decimal places of SOL (one lamport is 10^-9 SOL)
+const SOL_DECIMALS: usize = 9;
+
+/// Parse a decimal SOL amount such as `"1.5"` into lamports.
+///
+/// Exact: the digits are converted as integers, never through a float.
+/// Rejects signs, exponents, more than 9 decimal places and amounts above
+/// `u64::MAX` lamports.
+pub fn parse_sol_amount(amount: &str) -> Result<u64, &'static str> {
+    let (whole, fraction) = match amount.split_once('.') {
+        Some((whole, fraction)) if !fraction.is_empty() => (whole, fraction),
+        Some(_) => return Err("SOL amount must be a decimal number such as 1.5"),
+        None => (amount, ""),
+    };
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+        return Err("SOL amount must be a decimal number such as 1.5");
+    }
+    if fraction.len() > SOL_DECIMALS {
+        return Err("SOL amount must have at most 9 decimal places");
+    }
+
+    const TOO_LARGE: &str = "SOL amount is too large";
+    let whole: u64 = whole.parse().map_err(|_| TOO_LARGE)?;
+    let fraction: u64 = if fraction.is_empty() {
+        0
+    } else {
+        format!("{fraction:0<SOL_DECIMALS$}")
+            .parse()
+            .map_err(|_| TOO_LARGE)?
+    };
+    whole
+        .checked_mul(LAMPORTS_PER_SOL)
+        .and_then(|lamports| lamports.checked_add(fraction))
+        .ok_or(TOO_LARGE)
+}
+
+/// `validator` error code reported for a malformed Solana address
+pub const INVALID_ADDRESS_CODE: &str = "invalid_address";
+
+/// Check that `address` is a base58-encoded 32-byte Solana public key.
+///
+/// Has the signature of a `validator` custom function, so malformed
+/// addresses are rejected at the API boundary instead of failing deep in
+/// the blockchain layer.
+pub fn validate_solana_address(address: &str) -> Result<(), validator::ValidationError> {
+    if bs58::decode(address)
+        .into_vec()
+        .is_ok_and(|bytes| bytes.len() == 32)
+    {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new(INVALID_ADDRESS_CODE)
+            .with_message("Address must be a base58-encoded 32-byte public key".into()))
+    }
+}
+
+/// Canonical form of a Solana address: surrounding whitespace trimmed and
+/// the key bytes re-encoded as base58, so every accepted spelling of the
+/// same key maps to one string.
+///
+/// Returns `None` if the address is not a base58-encoded 32-byte public key.
+pub fn normalize_solana_address(address: &str) -> Option<String> {
+    let bytes = bs58::decode(address.trim()).into_vec().ok()?;
+    (bytes.len() == 32).then(|| bs58::encode(bytes).into_string())
+}
+
+/// Prefix of the v2 signing message
+const SIGNING_MESSAGE_V2_PREFIX: &str = "v2:";
+
+/// Prefix of the v3 signing message
+const SIGNING_MESSAGE_V3_PREFIX: &str = "v3:";
+
+/// Prefix of the v4 signing message
+const SIGNING_MESSAGE_V4_PREFIX: &str = "v4:";
+
+/// Prefix of the v5 signing message
+const SIGNING_MESSAGE_V5_PREFIX: &str = "v5:";
+
+/// Prefix of the v6 signing message
+const SIGNING_MESSAGE_V6_PREFIX: &str = "v6:";
+
+/// Latest signing message version
+pub const LATEST_SIGNATURE_VERSION: u8 = 6;
+
+impl Validate for SubmitTransferRequest {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+
+        if self.from_address.is_empty() {
+            errors.add(
+                "from_address",
+                validator::ValidationError::new("From address is required"),
+            );
+        } else if let Err(error) = validate_solana_address(&self.from_address) {
+            errors.add("from_address", error);
+        }
+        if self.to_address.is_empty() {
+            errors.add(
+                "to_address",
+                validator::ValidationError::new("To address is required"),
+            );
+        } else if let Err(error) = validate_solana_address(&self.to_address) {
+            errors.add("to_address", error);
+        }
+        if let Some(mint) = &self.token_mint
+            && let Err(error) = validate_solana_address(mint)
+        {
+            errors.add("token_mint", error);
+        }
+
+        // Nonce validation for replay protection
+        if self.nonce.is_empty() {
+            errors.add(
+                "nonce",
+                validator::ValidationError::new("Nonce is required for replay protection"),
+            );
+        } else if self.nonce.len() < 32 || self.nonce.len() > 64 {
+            errors.add(
+                "nonce",
+                validator::ValidationError::new("Nonce must be 32-64 characters (UUID format)"),
+            );
+        } else if !self
+            .nonce
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-')
+        {
+            errors.add(
+                "nonce",
+                validator::ValidationError::new(
+                    "Nonce must be alphanumeric with optional hyphens (UUID format)",
+                ),
+            );
+        }
+
+        if let Some(memo) = &self.memo {
+            if memo.is_empty() {
+                errors.add(
+                    "memo",
+                    validator::ValidationError::new("Memo must not be empty when provided"),
+                );
+            } else if memo.len() > MAX_MEMO_LEN {
+                errors.add(
+                    "memo",
+                    validator::ValidationError::new("Memo must be at most 256 bytes"),
+                );
+            }
+        }
+
+        if let Some(url) = &self.callback_url {
+            if !(url.starts_with("https://") || url.starts_with("http://")) {
+                errors.add(
+                    "callback_url",
+                    validator::ValidationError::new("Callback URL must be an http(s) URL"),
+                );
+            } else if url.len() > MAX_CALLBACK_URL_LEN {
+                errors.add(
+                    "callback_url",
+                    validator::ValidationError::new("Callback URL must be at most 2048 bytes"),
+                );
+            }
+        }
+
+        if let Some(region) = &self.jito_region
+            && !JITO_REGIONS.contains(&region.as_str())
+        {
+            errors.add(
+                "jito_region",
+                validator::ValidationError::new("Unknown Jito region"),
+            );
+        }
+
+        match &self.transfer_details {
+            TransferType::Public { amount } => match &self.amount_sol {
+                Some(_) if *amount != 0 => {
+                    errors.add(
+                        "amount_sol",
+                        validator::ValidationError::new(
+                            "Provide either amount or amount_sol, not both",
+                        ),
+                    );
+                }
+                Some(_) if self.token_mint.is_some() => {
+                    errors.add(
+                        "amount_sol",
+                        validator::ValidationError::new(
+                            "amount_sol is only supported for native SOL transfers",
+                        ),
+                    );
+                }
+                Some(amount_sol) => match parse_sol_amount(amount_sol) {
+                    Ok(0) => errors.add(
+                        "amount_sol",
+                        validator::ValidationError::new("Amount must be greater than 0"),
+                    ),
+                    Ok(_) => {}
+                    Err(message) => {
+                        errors.add("amount_sol", validator::ValidationError::new(message))
+                    }
+                },
+                None if *amount == 0 => {
+                    errors.add(
+                        "amount",
+                        validator::ValidationError::new(
+                            "Amount must be greater than 0 (or provide amount_sol)",
+                        ),
+                    );
+                }
+                None => {}
+            },
+            TransferType::PublicBatch { recipients } => {
+                if self.amount_sol.is_some() {
+                    errors.add(
+                        "amount_sol",
+                        validator::ValidationError::new(
+                            "amount_sol is only supported for native SOL transfers",
+                        ),
+                    );
+                }
+                if self.token_mint.is_none() {
+                    errors.add(
+                        "token_mint",
+                        validator::ValidationError::new(
+                            "Token mint is required for batch transfers",
+                        ),
+                    );
+                }
+                if recipients.is_empty() {
+                    errors.add(
+                        "recipients",
+                        validator::ValidationError::new(
+                            "Batch transfers need at least one recipient",
+                        ),
+                    );
+                } else if recipients.len() > MAX_BATCH_RECIPIENTS {
+                    errors.add(
+                        "recipients",
+                        validator::ValidationError::new(
+                            "Batch transfers are limited to 16 recipients",
+                        ),
+                    );
+                }
+                // Each sub-transfer is checked like a standalone transfer
+                if let Some(error) = recipients
+                    .iter()
+                    .find_map(|(address, _)| validate_solana_address(address).err())
+                {
+                    errors.add("recipients", error);
+                }
+                if recipients.iter().any(|(_, amount)| *amount == 0) {
+                    errors.add(
+                        "recipients",
+                        validator::ValidationError::new(
+                            "Every recipient amount must be greater than 0",
+                        ),
+                    );
+                }
+                if self.batch_total().is_none() {
+                    errors.add(
+                        "recipients",
+                        validator::ValidationError::new("Batch total amount is too large"),
+                    );
+                }
+                if recipients
+                    .first()
+                    .is_some_and(|(address, _)| *address != self.to_address)
+                {
+                    errors.add(
+                        "to_address",
+                        validator::ValidationError::new(
+                            "to_address must be the first batch recipient",
+                        ),
+                    );
+                }
+            }
+            TransferType::Confidential { .. } if self.amount_sol.is_some() => {
+                errors.add(
+                    "amount_sol",
+                    validator::ValidationError::new(
+                        "amount_sol is only supported for public transfers",
+                    ),
+                );
+            }
+            TransferType::Confidential {
+                new_decryptable_available_balance,
+                equality_proof,
+                ciphertext_validity_proof,
+                range_proof,
+                auditor_elgamal_pubkey,
+                fee_sigma_proof,
+                fee_ciphertext_validity_proof,
             } => {
                 if new_decryptable_available_balance.is_empty() {
                     errors.add(
@@ -516,6 +1740,41 @@ impl Validate for SubmitTransferRequest {
                         ),
                     );
                 }
+                if auditor_elgamal_pubkey.as_deref().is_some_and(|key| {
+                    !BASE64_STANDARD
+                        .decode(key)
+                        .is_ok_and(|bytes| bytes.len() == ELGAMAL_PUBKEY_LEN)
+                }) {
+                    errors.add(
+                        "auditor_elgamal_pubkey",
+                        validator::ValidationError::new(
+                            "Auditor ElGamal pubkey must be a Base64-encoded 32-byte key",
+                        ),
+                    );
+                }
+                // Fee transfers need both fee proofs; either one alone is useless
+                if fee_sigma_proof.is_some() != fee_ciphertext_validity_proof.is_some() {
+                    errors.add(
+                        "fee_sigma_proof",
+                        validator::ValidationError::new(
+                            "Fee sigma proof and fee ciphertext validity proof must be provided together",
+                        ),
+                    );
+                }
+                for (field, proof) in [
+                    ("fee_sigma_proof", fee_sigma_proof),
+                    (
+                        "fee_ciphertext_validity_proof",
+                        fee_ciphertext_validity_proof,
+                    ),
+                ] {
+                    if proof.as_deref().is_some_and(str::is_empty) {
+                        errors.add(
+                            field,
+                            validator::ValidationError::new("Fee proof must not be empty"),
+                        );
+                    }
+                }
             }
         }
 
@@ -532,7 +1791,7 @@ impl SubmitTransferRequest {
     /// Returns Ok(()) if valid, or AppError::Authorization if invalid.
     pub fn verify_signature(&self) -> Result<(), AppError> {
         // Construct the deterministic message to verify
-        let message = self.create_signing_message();
+        let message = self.create_signing_message()?;
 
         // Decode the from_address as a Solana public key (32 bytes)
         let pubkey_bytes = bs58::decode(&self.from_address).into_vec().map_err(|e| {
@@ -581,24 +1840,190 @@ impl SubmitTransferRequest {
         Ok(())
     }
 
+    /// Signing message version of the request: the declared
+    /// `signature_version`, or else the oldest version that covers the
+    /// request's fields (v1 without any, v2 with a memo, v3 with a priority,
+    /// v4 with a `valid_until`, v5 with a Jito region, v6 with a preflight
+    /// override).
+    #[must_use]
+    pub fn signing_message_version(&self) -> u8 {
+        self.signature_version
+            .unwrap_or(if self.skip_preflight.is_some() {
+                6
+            } else if self.jito_region.is_some() {
+                5
+            } else if self.valid_until.is_some() {
+                4
+            } else if self.priority != 0 {
+                3
+            } else if self.memo.is_some() {
+                2
+            } else {
+                1
+            })
+    }
+
     /// Create the deterministic message for signing.
-    /// Format: "{from_address}:{to_address}:{amount|confidential}:{token_mint|SOL}:{nonce}"
+    ///
+    /// v1: "{from_address}:{to_address}:{amount|confidential}:{token_mint|SOL}:{nonce}"
+    ///
+    /// v2: "v2:" followed by each field as "{byte_length}:{value}", in the
+    /// order from, to, amount, mint, nonce, memo (empty without a memo).
+    /// Length-prefixing keeps free-form fields such as the memo from being
+    /// confused with the delimiters around them.
+    ///
+    /// v3: "v3:" followed by the v2 fields and the priority. A request signed
+    /// at priority 0 therefore cannot be replayed at another priority.
+    ///
+    /// v4: "v4:" followed by the v3 fields and `valid_until` as Unix seconds
+    /// (empty without an expiry).
+    ///
+    /// v5: "v5:" followed by the v4 fields and the Jito region (empty
+    /// without one), so a relay cannot reroute the bundle.
+    ///
+    /// v6: "v6:" followed by the v5 fields and the preflight override as
+    /// `true` or `false` (empty without one).
+    ///
+    /// A v1 message starts with the Base58 sender address, which cannot
+    /// contain `:`, so it never matches a prefixed message and a signature
+    /// over one version does not verify under another.
     ///
     /// The nonce MUST be included in the message to prevent replay attacks.
     /// Same parameters without a unique nonce would produce the same message,
     /// allowing an attacker to replay the signed request indefinitely.
-    #[must_use]
-    pub fn create_signing_message(&self) -> Vec<u8> {
+    ///
+    /// Fails for unknown versions and for versions that cannot cover the
+    /// request's memo, priority, expiry, Jito region or preflight override.
+    pub fn create_signing_message(&self) -> Result<Vec<u8>, AppError> {
+        let version = self.signing_message_version();
+        let uncovered = match version {
+            1 if self.memo.is_some() => Some("memo"),
+            1 | 2 if self.priority != 0 => Some("priority"),
+            1..=3 if self.valid_until.is_some() => Some("valid_until"),
+            1..=4 if self.jito_region.is_some() => Some("jito_region"),
+            1..=5 if self.skip_preflight.is_some() => Some("skip_preflight"),
+            1..=LATEST_SIGNATURE_VERSION => None,
+            _ => {
+                return Err(AppError::Validation(ValidationError::InvalidField {
+                    field: "signature_version".to_string(),
+                    message: format!(
+                        "Unsupported signature version {}, expected 1 to {}",
+                        version, LATEST_SIGNATURE_VERSION
+                    ),
+                }));
+            }
+        };
+        if let Some(field) = uncovered {
+            return Err(AppError::Validation(ValidationError::InvalidField {
+                field: "signature_version".to_string(),
+                message: format!("Signature version {} does not cover the {}", version, field),
+            }));
+        }
+
         let amount_part = match &self.transfer_details {
             TransferType::Public { amount } => amount.to_string(),
+            TransferType::PublicBatch { recipients } => recipients
+                .iter()
+                .map(|(address, amount)| format!("{}={}", address, amount))
+                .collect::<Vec<_>>()
+                .join(","),
             TransferType::Confidential { .. } => "confidential".to_string(),
         };
         let mint_part = self.token_mint.as_deref().unwrap_or("SOL");
-        format!(
-            "{}:{}:{}:{}:{}",
-            self.from_address, self.to_address, amount_part, mint_part, self.nonce
-        )
-        .into_bytes()
+
+        if version == 1 {
+            return Ok(format!(
+                "{}:{}:{}:{}:{}",
+                self.from_address, self.to_address, amount_part, mint_part, self.nonce
+            )
+            .into_bytes());
+        }
+
+        let mut fields = vec![
+            self.from_address.clone(),
+            self.to_address.clone(),
+            amount_part,
+            mint_part.to_string(),
+            self.nonce.clone(),
+            self.memo.clone().unwrap_or_default(),
+        ];
+        let prefix = match version {
+            2 => SIGNING_MESSAGE_V2_PREFIX,
+            3 => {
+                fields.push(self.priority.to_string());
+                SIGNING_MESSAGE_V3_PREFIX
+            }
+            _ => {
+                fields.push(self.priority.to_string());
+                fields.push(
+                    self.valid_until
+                        .map(|t| t.timestamp().to_string())
+                        .unwrap_or_default(),
+                );
+                if version == 4 {
+                    SIGNING_MESSAGE_V4_PREFIX
+                } else {
+                    fields.push(self.jito_region.clone().unwrap_or_default());
+                    if version == 5 {
+                        SIGNING_MESSAGE_V5_PREFIX
+                    } else {
+                        fields.push(
+                            self.skip_preflight
+                                .map(|skip| skip.to_string())
+                                .unwrap_or_default(),
+                        );
+                        SIGNING_MESSAGE_V6_PREFIX
+                    }
+                }
+            }
+        };
+        let mut message = String::from(prefix);
+        for field in fields {
+            message.push_str(&format!("{}:{}", field.len(), field));
+        }
+        Ok(message.into_bytes())
+    }
+
+    /// Attach a memo to the request
+    #[must_use]
+    pub fn with_memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = Some(memo.into());
+        self
+    }
+
+    /// Set the processing priority
+    #[must_use]
+    pub fn with_priority(mut self, priority: i16) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set the time after which the request is rejected
+    #[must_use]
+    pub fn with_valid_until(mut self, valid_until: DateTime<Utc>) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// Send the transfer's bundle to a specific Jito region
+    #[must_use]
+    pub fn with_jito_region(mut self, region: impl Into<String>) -> Self {
+        self.jito_region = Some(region.into());
+        self
+    }
+
+    /// Override whether the transfer's submissions skip preflight
+    #[must_use]
+    pub fn with_skip_preflight(mut self, skip_preflight: bool) -> Self {
+        self.skip_preflight = Some(skip_preflight);
+        self
+    }
+
+    /// Declare the signing message version
+    #[must_use]
+    pub fn with_signature_version(mut self, version: u8) -> Self {
+        self.signature_version = Some(version);
+        self
     }
 
     #[must_use]
@@ -616,62 +2041,367 @@ impl SubmitTransferRequest {
             token_mint: None,
             signature,
             nonce,
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        }
+    }
+
+    /// Create a new SPL Token transfer request
+    #[must_use]
+    pub fn new_token_transfer(
+        from_address: String,
+        to_address: String,
+        amount: u64,
+        token_mint: String,
+        signature: String,
+        nonce: String,
+    ) -> Self {
+        Self {
+            from_address,
+            to_address,
+            transfer_details: TransferType::Public { amount },
+            token_mint: Some(token_mint),
+            signature,
+            nonce,
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        }
+    }
+
+    /// Create a new Confidential transfer request
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_confidential(
+        from_address: String,
+        to_address: String,
+        new_decryptable_available_balance: String,
+        equality_proof: String,
+        ciphertext_validity_proof: String,
+        range_proof: String,
+        token_mint: String,
+        signature: String,
+        nonce: String,
+    ) -> Self {
+        Self {
+            from_address,
+            to_address,
+            transfer_details: TransferType::Confidential {
+                new_decryptable_available_balance,
+                equality_proof,
+                ciphertext_validity_proof,
+                range_proof,
+                auditor_elgamal_pubkey: None,
+                fee_sigma_proof: None,
+                fee_ciphertext_validity_proof: None,
+            },
+            token_mint: Some(token_mint),
+            signature,
+            nonce,
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        }
+    }
+
+    /// Lamport amount of a public transfer, converting `amount_sol` when set.
+    /// `None` for batch and confidential transfers and an unparseable `amount_sol`.
+    #[must_use]
+    pub fn public_amount(&self) -> Option<u64> {
+        match (&self.transfer_details, &self.amount_sol) {
+            (TransferType::Public { .. }, Some(amount_sol)) => parse_sol_amount(amount_sol).ok(),
+            (TransferType::Public { amount }, None) => Some(*amount),
+            (TransferType::PublicBatch { .. } | TransferType::Confidential { .. }, _) => None,
+        }
+    }
+
+    /// Sum of the recipient amounts of a batch transfer.
+    /// `None` for other transfer types and when the sum overflows.
+    #[must_use]
+    pub fn batch_total(&self) -> Option<u64> {
+        let TransferType::PublicBatch { recipients } = &self.transfer_details else {
+            return None;
+        };
+        recipients
+            .iter()
+            .try_fold(0u64, |total, (_, amount)| total.checked_add(*amount))
+    }
+
+    /// A batch transfer split into one public transfer per recipient, so
+    /// each can be screened like a standalone transfer.
+    /// Empty for other transfer types.
+    #[must_use]
+    pub fn batch_sub_transfers(&self) -> Vec<Self> {
+        let TransferType::PublicBatch { recipients } = &self.transfer_details else {
+            return Vec::new();
+        };
+        recipients
+            .iter()
+            .map(|(address, amount)| Self {
+                to_address: address.clone(),
+                transfer_details: TransferType::Public { amount: *amount },
+                ..self.clone()
+            })
+            .collect()
+    }
+
+    /// This request with `amount_sol` converted into the lamport `amount`,
+    /// so signature checks, screening and storage only ever see lamports.
+    /// Borrowed unchanged when `amount_sol` is not set.
+    pub fn normalize_amount(&self) -> Result<Cow<'_, Self>, ValidationError> {
+        let Some(amount_sol) = &self.amount_sol else {
+            return Ok(Cow::Borrowed(self));
+        };
+        let amount =
+            parse_sol_amount(amount_sol).map_err(|message| ValidationError::InvalidField {
+                field: "amount_sol".to_string(),
+                message: message.to_string(),
+            })?;
+        Ok(Cow::Owned(Self {
+            transfer_details: TransferType::Public { amount },
+            amount_sol: None,
+            ..self.clone()
+        }))
+    }
+
+    /// This request with `default_mint` as its token mint, when it is a
+    /// single public transfer that names no mint. Borrowed unchanged
+    /// otherwise. Amounts given as `amount_sol` are native SOL by definition
+    /// and never take the default.
+    ///
+    /// Resolved before the signature is verified, so the client signs the
+    /// mint the transfer actually moves rather than an omitted one.
+    pub fn resolve_token_mint(&self, default_mint: Option<&str>) -> Cow<'_, Self> {
+        match default_mint {
+            Some(mint)
+                if self.token_mint.is_none()
+                    && self.amount_sol.is_none()
+                    && matches!(self.transfer_details, TransferType::Public { .. }) =>
+            {
+                Cow::Owned(Self {
+                    token_mint: Some(mint.to_string()),
+                    ..self.clone()
+                })
+            }
+            _ => Cow::Borrowed(self),
+        }
+    }
+
+    /// Check if this is an SPL Token transfer
+    #[must_use]
+    pub fn is_token_transfer(&self) -> bool {
+        self.token_mint.is_some()
+    }
+}
+
+// ============================================================================
+// Internal Blocklist Types
+// ============================================================================
+
+/// Why an address was added to the internal blocklist
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BlocklistCategory {
+    /// Sanctioned by OFAC (SDN list)
+    OfacSanction,
+    /// Flagged by internal fraud investigation
+    InternalFraud,
+    /// Auto-blocked after a high-risk Range Protocol assessment
+    RangeAutoBlock,
+    /// Added manually by an operator
+    #[default]
+    Manual,
+}
+
+impl BlocklistCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::OfacSanction => "ofac_sanction",
+            Self::InternalFraud => "internal_fraud",
+            Self::RangeAutoBlock => "range_auto_block",
+            Self::Manual => "manual",
+        }
+    }
+
+    /// Severity (0-10) assigned when the caller does not specify one
+    #[must_use]
+    pub fn default_severity(&self) -> u8 {
+        match self {
+            Self::OfacSanction => 10,
+            Self::RangeAutoBlock => 9,
+            Self::InternalFraud => 8,
+            Self::Manual => 5,
+        }
+    }
+}
+
+impl std::str::FromStr for BlocklistCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ofac_sanction" => Ok(Self::OfacSanction),
+            "internal_fraud" => Ok(Self::InternalFraud),
+            "range_auto_block" => Ok(Self::RangeAutoBlock),
+            "manual" => Ok(Self::Manual),
+            _ => Err(format!("Invalid blocklist category: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for BlocklistCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Maximum blocklist severity
+pub const MAX_BLOCKLIST_SEVERITY: u8 = 10;
+
+// ============================================================================
+// API Key Types
+// ============================================================================
+
+/// Permission granted to an API key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiKeyScope {
+    /// Submit, retry and cancel transfers
+    Submit,
+    /// Manage the blocklist and allowlist
+    Admin,
+}
+
+impl ApiKeyScope {
+    /// All scopes
+    pub const ALL: [Self; 2] = [Self::Submit, Self::Admin];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Submit => "submit",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+impl std::str::FromStr for ApiKeyScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "submit" => Ok(Self::Submit),
+            "admin" => Ok(Self::Admin),
+            _ => Err(format!("Invalid API key scope: {}", s)),
         }
     }
+}
+
+impl std::fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
 
-    /// Create a new SPL Token transfer request
+/// Configured API keys and the scopes each one grants.
+///
+/// A scope is only enforced once at least one key grants it, so a
+/// deployment without keys stays open for local development.
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeyStore {
+    keys: HashMap<String, HashSet<ApiKeyScope>>,
+}
+
+impl ApiKeyStore {
+    /// Create an empty store (no scope enforced)
     #[must_use]
-    pub fn new_token_transfer(
-        from_address: String,
-        to_address: String,
-        amount: u64,
-        token_mint: String,
-        signature: String,
-        nonce: String,
-    ) -> Self {
-        Self {
-            from_address,
-            to_address,
-            transfer_details: TransferType::Public { amount },
-            token_mint: Some(token_mint),
-            signature,
-            nonce,
-        }
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Create a new Confidential transfer request
+    /// Grant `scopes` to `key`, in addition to scopes it already has
     #[must_use]
-    #[allow(clippy::too_many_arguments)]
-    pub fn new_confidential(
-        from_address: String,
-        to_address: String,
-        new_decryptable_available_balance: String,
-        equality_proof: String,
-        ciphertext_validity_proof: String,
-        range_proof: String,
-        token_mint: String,
-        signature: String,
-        nonce: String,
+    pub fn with_key(
+        mut self,
+        key: impl Into<String>,
+        scopes: impl IntoIterator<Item = ApiKeyScope>,
     ) -> Self {
-        Self {
-            from_address,
-            to_address,
-            transfer_details: TransferType::Confidential {
-                new_decryptable_available_balance,
-                equality_proof,
-                ciphertext_validity_proof,
-                range_proof,
-            },
-            token_mint: Some(token_mint),
-            signature,
-            nonce,
+        self.keys.entry(key.into()).or_default().extend(scopes);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Configured keys
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.keys.keys().map(String::as_str)
+    }
+
+    /// Whether any key grants `scope`
+    pub fn enforces(&self, scope: ApiKeyScope) -> bool {
+        self.keys.values().any(|scopes| scopes.contains(&scope))
+    }
+
+    /// Check that the presented `key` grants `scope`.
+    ///
+    /// Returns [`AppError::Authentication`] (401) if the key is missing or
+    /// unknown, and [`AppError::Authorization`] (403) if it lacks the scope.
+    pub fn authorize(&self, key: Option<&str>, scope: ApiKeyScope) -> Result<(), AppError> {
+        if !self.enforces(scope) {
+            return Ok(());
+        }
+        let key = key.ok_or_else(|| AppError::Authentication("Missing API key".to_string()))?;
+        let scopes = self
+            .keys
+            .get(key)
+            .ok_or_else(|| AppError::Authentication("Invalid API key".to_string()))?;
+        if scopes.contains(&scope) {
+            Ok(())
+        } else {
+            Err(AppError::Authorization(format!(
+                "API key lacks the '{}' scope",
+                scope
+            )))
         }
     }
+}
 
-    /// Check if this is an SPL Token transfer
-    #[must_use]
-    pub fn is_token_transfer(&self) -> bool {
-        self.token_mint.is_some()
+/// Parses `key1:submit,key2:admin,key3`. A key without a scope is granted
+/// every scope; a key listed more than once gets the union of its scopes.
+impl std::str::FromStr for ApiKeyStore {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut store = Self::new();
+        for entry in s.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            store = match entry.split_once(':') {
+                Some((key, scope)) => {
+                    let key = key.trim();
+                    if key.is_empty() {
+                        return Err(format!("Invalid API key entry '{}': empty key", entry));
+                    }
+                    store.with_key(key, [scope.trim().parse::<ApiKeyScope>()?])
+                }
+                None => store.with_key(entry, ApiKeyScope::ALL),
+            };
+        }
+        Ok(store)
     }
 }
 
@@ -906,6 +2636,13 @@ pub struct TransferAuditReport {
     pub token_mint: Option<String>,
     /// Public amount or confidential marker.
     pub amount: AuditAmount,
+    /// Raw token units debited from the sender, when the mint charges a
+    /// Token-2022 transfer fee.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub token_sent_amount: Option<u64>,
+    /// Token-2022 transfer fee withheld from the recipient, in raw units.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub token_transfer_fee: Option<u64>,
     /// Replay-protection nonce.
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub nonce: Option<String>,
@@ -957,6 +2694,18 @@ pub struct PaginationParams {
     /// Cursor for pagination (ID to start after)
     #[schema(example = "uuid-string")]
     pub cursor: Option<String>,
+    /// Only transfers sent from this address
+    pub from_address: Option<String>,
+    /// Only transfers sent to this address
+    pub to_address: Option<String>,
+    /// Only transfers in this blockchain status
+    pub blockchain_status: Option<BlockchainStatus>,
+    /// Only transfers in this compliance status
+    pub compliance_status: Option<ComplianceStatus>,
+    /// Only transfers created at or after this time (RFC 3339)
+    pub created_after: Option<DateTime<Utc>>,
+    /// Only transfers created before this time (RFC 3339)
+    pub created_before: Option<DateTime<Utc>>,
 }
 
 fn default_limit() -> i64 {
@@ -968,10 +2717,74 @@ impl Default for PaginationParams {
         Self {
             limit: default_limit(),
             cursor: None,
+            from_address: None,
+            to_address: None,
+            blockchain_status: None,
+            compliance_status: None,
+            created_after: None,
+            created_before: None,
+        }
+    }
+}
+
+impl PaginationParams {
+    /// Filters to apply to the transfer list
+    #[must_use]
+    pub fn transfer_filter(&self) -> TransferFilter {
+        TransferFilter {
+            from_address: self.from_address.clone(),
+            to_address: self.to_address.clone(),
+            blockchain_status: self.blockchain_status,
+            compliance_status: self.compliance_status,
+            created_after: self.created_after,
+            created_before: self.created_before,
         }
     }
 }
 
+/// Filters for listing transfer requests; unset fields match everything
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferFilter {
+    pub from_address: Option<String>,
+    pub to_address: Option<String>,
+    pub blockchain_status: Option<BlockchainStatus>,
+    pub compliance_status: Option<ComplianceStatus>,
+    /// Inclusive lower bound on `created_at`
+    pub created_after: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `created_at`
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl TransferFilter {
+    /// Whether no filter is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether `request` passes every set filter
+    pub fn matches(&self, request: &TransferRequest) -> bool {
+        self.from_address
+            .as_ref()
+            .is_none_or(|from| *from == request.from_address)
+            && self
+                .to_address
+                .as_ref()
+                .is_none_or(|to| *to == request.to_address)
+            && self
+                .blockchain_status
+                .is_none_or(|status| status == request.blockchain_status)
+            && self
+                .compliance_status
+                .is_none_or(|status| status == request.compliance_status)
+            && self
+                .created_after
+                .is_none_or(|after| request.created_at >= after)
+            && self
+                .created_before
+                .is_none_or(|before| request.created_at < before)
+    }
+}
+
 /// Paginated response wrapper
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct PaginatedResponse<T: ToSchema> {
@@ -1014,6 +2827,16 @@ pub enum HealthStatus {
     Unhealthy,
 }
 
+/// Server clock, for clients computing `valid_until`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ServerTimeResponse {
+    /// Current server timestamp
+    pub server_time: DateTime<Utc>,
+    /// How far past a deadline a timestamp is still accepted, in seconds
+    #[schema(example = 30)]
+    pub clock_skew_tolerance_secs: i64,
+}
+
 /// Health check response
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct HealthResponse {
@@ -1028,6 +2851,26 @@ pub struct HealthResponse {
     /// Application version
     #[schema(example = "0.3.0")]
     pub version: String,
+    /// Per-dependency status and probe latency (verbose only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<DependencyHealth>,
+    /// Seconds since the service started (verbose only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = 3600)]
+    pub uptime_secs: Option<u64>,
+    /// Transfers waiting for the background worker (verbose only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = 12)]
+    pub pending_queue_depth: Option<i64>,
+    /// Last balance check of the relayer's fee payer (verbose only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_payer_balance: Option<FeePayerBalance>,
+    /// Slot, slot lag and version of the Solana RPC node (verbose only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blockchain_node: Option<BlockchainNodeHealth>,
+    /// Whether the worker and crank are paused by an admin (only present while paused)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub processing_paused: bool,
 }
 
 impl HealthResponse {
@@ -1044,10 +2887,302 @@ impl HealthResponse {
             blockchain,
             timestamp: Utc::now(),
             version: env!("CARGO_PKG_VERSION").to_string(),
+            dependencies: Vec::new(),
+            uptime_secs: None,
+            pending_queue_depth: None,
+            fee_payer_balance: None,
+            blockchain_node: None,
+            processing_paused: false,
+        }
+    }
+
+    /// Attach per-dependency results (builder pattern).
+    /// A failing non-critical dependency downgrades a healthy status to degraded.
+    #[must_use]
+    pub fn with_dependencies(mut self, dependencies: Vec<DependencyHealth>) -> Self {
+        let degraded = dependencies
+            .iter()
+            .any(|dependency| dependency.status != HealthStatus::Healthy);
+        if degraded && self.status == HealthStatus::Healthy {
+            self.status = HealthStatus::Degraded;
+        }
+        self.dependencies = dependencies;
+        self
+    }
+
+    /// Drop the verbose-only fields, leaving the original response shape
+    #[must_use]
+    pub fn terse(mut self) -> Self {
+        self.dependencies = Vec::new();
+        self.uptime_secs = None;
+        self.pending_queue_depth = None;
+        self.fee_payer_balance = None;
+        self.blockchain_node = None;
+        self
+    }
+
+    /// Attach the RPC node diagnostics (builder pattern).
+    /// A degraded node downgrades a healthy blockchain and overall status.
+    #[must_use]
+    pub fn with_blockchain_node(mut self, node: BlockchainNodeHealth) -> Self {
+        if node.status != HealthStatus::Healthy && self.blockchain == HealthStatus::Healthy {
+            self.blockchain = HealthStatus::Degraded;
+            if self.status == HealthStatus::Healthy {
+                self.status = HealthStatus::Degraded;
+            }
+        }
+        self.blockchain_node = Some(node);
+        self
+    }
+}
+
+/// Kind of an operator alert. Alerts of the same kind share a cooldown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertKind {
+    /// The fee payer balance dropped below `MIN_FEE_PAYER_LAMPORTS`
+    FeePayerLowBalance,
+    /// The blockchain RPC circuit breaker opened
+    CircuitOpen,
+    /// Compliance rejections exceeded the configured rate
+    RejectionSpike,
+}
+
+impl AlertKind {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertKind::FeePayerLowBalance => "fee_payer_low_balance",
+            AlertKind::CircuitOpen => "circuit_open",
+            AlertKind::RejectionSpike => "rejection_spike",
+        }
+    }
+
+    /// Human-readable headline of the alert
+    #[must_use]
+    pub fn title(&self) -> &'static str {
+        match self {
+            AlertKind::FeePayerLowBalance => "Fee payer balance low",
+            AlertKind::CircuitOpen => "Blockchain RPC circuit open",
+            AlertKind::RejectionSpike => "Compliance rejection spike",
+        }
+    }
+}
+
+/// Operator alert delivered to the configured chat sinks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Alert {
+    pub kind: AlertKind,
+    /// Details of the event, e.g. the balance or failure count
+    pub message: String,
+}
+
+impl Alert {
+    #[must_use]
+    pub fn new(kind: AlertKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+/// Balance of the relayer's fee payer, as seen by the last balance check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct FeePayerBalance {
+    /// Balance in lamports
+    #[schema(example = 2500000000_u64)]
+    pub lamports: u64,
+    /// Alert threshold in lamports (`MIN_FEE_PAYER_LAMPORTS`)
+    #[schema(example = 100000000_u64)]
+    pub min_lamports: u64,
+    /// Whether the balance is below the threshold
+    pub low: bool,
+    /// When the balance was read
+    pub checked_at: DateTime<Utc>,
+}
+
+impl FeePayerBalance {
+    #[must_use]
+    pub fn new(lamports: u64, min_lamports: u64) -> Self {
+        Self {
+            lamports,
+            min_lamports,
+            low: lamports < min_lamports,
+            checked_at: Utc::now(),
+        }
+    }
+}
+
+/// Request to estimate the network fee of a transfer
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FeeEstimateRequest {
+    /// Recipient wallet address (Base58 Solana address)
+    #[schema(example = "DRpbCBMxVnDK7maPM5tGv6MvB3v1sRMC86PZ8okm21hy")]
+    pub to_address: String,
+    /// Amount in lamports (SOL) or raw token units
+    #[schema(example = 1_000_000_000)]
+    pub amount: u64,
+    /// SPL Token mint address; omit for native SOL, or for
+    /// `DEFAULT_TOKEN_MINT` when the relayer sets one
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    #[schema(example = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v")]
+    pub token_mint: Option<String>,
+    /// Memo the transfer would carry
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub memo: Option<String>,
+}
+
+impl Validate for FeeEstimateRequest {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        let mut errors = validator::ValidationErrors::new();
+
+        if self.to_address.is_empty() {
+            errors.add(
+                "to_address",
+                validator::ValidationError::new("To address is required"),
+            );
+        }
+        if self.amount == 0 {
+            errors.add(
+                "amount",
+                validator::ValidationError::new("Amount must be greater than 0"),
+            );
+        }
+        if let Some(memo) = &self.memo
+            && (memo.is_empty() || memo.len() > MAX_MEMO_LEN)
+        {
+            errors.add(
+                "memo",
+                validator::ValidationError::new("Memo must be 1-256 bytes when provided"),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Estimated network cost of a transfer, by component
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct FeeEstimate {
+    /// Signature fee of the transaction message, as reported by `getFeeForMessage`
+    #[schema(example = 5000)]
+    pub base_fee_lamports: u64,
+    /// Compute unit price from the fee strategy, in micro-lamports per unit
+    #[schema(example = 100)]
+    pub compute_unit_price_micro_lamports: u64,
+    /// Compute units requested by the transaction
+    #[schema(example = 200000)]
+    pub compute_unit_limit: u32,
+    /// Priority fee: unit price times unit limit, rounded up to whole lamports
+    #[schema(example = 20)]
+    pub priority_fee_lamports: u64,
+    /// Jito tip, 0 when Jito submission is disabled
+    #[schema(example = 0)]
+    pub jito_tip_lamports: u64,
+    /// Sum of the base fee, priority fee and tip
+    #[schema(example = 5020)]
+    pub total_lamports: u64,
+}
+
+impl FeeEstimate {
+    /// Estimate for the given components; derives the priority fee and total
+    #[must_use]
+    pub fn new(
+        base_fee_lamports: u64,
+        compute_unit_price_micro_lamports: u64,
+        compute_unit_limit: u32,
+        jito_tip_lamports: u64,
+    ) -> Self {
+        let priority_fee_lamports = (u128::from(compute_unit_price_micro_lamports)
+            * u128::from(compute_unit_limit))
+        .div_ceil(1_000_000);
+        let priority_fee_lamports = u64::try_from(priority_fee_lamports).unwrap_or(u64::MAX);
+        Self {
+            base_fee_lamports,
+            compute_unit_price_micro_lamports,
+            compute_unit_limit,
+            priority_fee_lamports,
+            jito_tip_lamports,
+            total_lamports: base_fee_lamports
+                .saturating_add(priority_fee_lamports)
+                .saturating_add(jito_tip_lamports),
+        }
+    }
+}
+
+/// Query parameters for the health endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HealthQuery {
+    /// Include per-dependency results, uptime and queue depth
+    #[serde(default)]
+    pub verbose: bool,
+}
+
+/// Health of a single dependency, as measured by one probe
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DependencyHealth {
+    /// Dependency name (`database`, `blockchain`, `compliance`, `privacy`, `read_replica`)
+    #[schema(example = "compliance")]
+    pub name: String,
+    /// Probe outcome
+    pub status: HealthStatus,
+    /// Time the probe took, in milliseconds
+    #[schema(example = 42)]
+    pub latency_ms: u64,
+    /// Probe error, if it failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DependencyHealth {
+    /// Build from a probe result and its measured latency
+    #[must_use]
+    pub fn from_result(
+        name: impl Into<String>,
+        result: &Result<(), AppError>,
+        latency: std::time::Duration,
+    ) -> Self {
+        let (status, error) = match result {
+            Ok(()) => (HealthStatus::Healthy, None),
+            Err(e) => (HealthStatus::Unhealthy, Some(e.to_string())),
+        };
+        Self {
+            name: name.into(),
+            status,
+            latency_ms: u64::try_from(latency.as_millis()).unwrap_or(u64::MAX),
+            error,
         }
     }
 }
 
+/// Diagnostics of the Solana RPC node behind the blockchain client
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BlockchainNodeHealth {
+    /// `degraded` when the node reports itself unhealthy or its slot lags
+    /// behind the expected slot by more than the allowed lag
+    pub status: HealthStatus,
+    /// Slot reported by `getSlot`
+    #[schema(example = 287_654_321)]
+    pub slot: u64,
+    /// Slots the node is behind the expected slot, once a previous probe
+    /// gives an expectation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = 0)]
+    pub slot_lag: Option<u64>,
+    /// Node software version reported by `getVersion`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(example = "2.1.13")]
+    pub node_version: Option<String>,
+    /// Error returned by `getHealth`, if the node reports itself unhealthy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_error: Option<String>,
+}
+
 /// Error response structure
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ErrorResponse {
@@ -1055,12 +3190,66 @@ pub struct ErrorResponse {
     pub error: ErrorDetail,
 }
 
+/// Stable machine-readable error code.
+///
+/// Finer-grained than the error `type`; clients should branch on this rather
+/// than on the message. New codes may be added, so handle unknown values.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    /// The request failed validation
+    ValidationFailed,
+    /// The request body could not be parsed
+    MalformedRequest,
+    /// An address involved in the request is on the internal blocklist
+    Blocklisted,
+    /// Compliance screening rejected the transfer
+    ComplianceRejected,
+    /// The nonce has already been used
+    DuplicateNonce,
+    /// The transfer exceeds a configured amount limit
+    TransferLimitExceeded,
+    /// A record with the same unique key already exists
+    DuplicateRecord,
+    /// The request conflicts with the current state of the resource
+    Conflict,
+    /// The resource does not exist
+    NotFound,
+    /// Missing or invalid credentials
+    AuthenticationFailed,
+    /// Credentials valid but not allowed, or the request signature is invalid
+    AuthorizationFailed,
+    /// The fee payer cannot cover the transaction
+    InsufficientFunds,
+    /// Too many requests
+    RateLimited,
+    /// A dependency did not respond in time
+    Timeout,
+    /// The database is unreachable
+    DatabaseUnavailable,
+    /// A database operation failed
+    DatabaseError,
+    /// The Solana RPC node is unreachable
+    BlockchainUnavailable,
+    /// A blockchain operation failed
+    BlockchainError,
+    /// A compliance or other external provider failed
+    ExternalServiceError,
+    /// The operation is not supported by this deployment
+    NotSupported,
+    /// Unexpected server-side failure
+    InternalError,
+}
+
 /// Error detail structure
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ErrorDetail {
     /// Error type identifier
     #[schema(example = "validation_error")]
     pub r#type: String,
+    /// Stable machine-readable error code
+    #[schema(example = "VALIDATION_FAILED")]
+    pub code: ErrorCode,
     /// Human-readable error message
     #[schema(example = "Name must be between 1 and 255 characters")]
     pub message: String,
@@ -1088,6 +3277,22 @@ pub struct RiskCheckRequest {
     pub address: String,
 }
 
+/// Request to check the risk status of several wallets at once
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkRiskCheckRequest {
+    /// Wallet addresses to check (Base58 Solana addresses). Repeated
+    /// addresses are checked once.
+    #[schema(example = json!(["HvwC9QSAzwEXkUkwqNNGhfNHoVqXJYfPvPZfQvJmHWcF"]))]
+    pub addresses: Vec<String>,
+}
+
+/// Risk check results of a bulk request, in request order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkRiskCheckResponse {
+    /// One result per requested address, repeated addresses included
+    pub results: Vec<RiskCheckResult>,
+}
+
 /// Cached wallet risk profile from database
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletRiskProfile {
@@ -1095,6 +3300,9 @@ pub struct WalletRiskProfile {
     pub risk_score: Option<i32>,
     pub risk_level: Option<String>,
     pub reasoning: Option<String>,
+    /// Factors contributing to the risk score
+    #[serde(default)]
+    pub risk_factors: Vec<String>,
     pub has_sanctioned_assets: bool,
     pub helius_assets_checked: bool,
     pub created_at: DateTime<Utc>,
@@ -1134,6 +3342,11 @@ pub enum RiskCheckResult {
         /// Reasoning from Range Protocol
         #[schema(example = "3 hops from nearest flagged address")]
         reasoning: String,
+        /// Factors contributing to the risk score (flagged counterparties,
+        /// risk categories). Empty when nothing was flagged.
+        #[serde(default)]
+        #[schema(example = json!(["Mixer (Tornado Mixer) at 3 hop(s)"]))]
+        risk_factors: Vec<String>,
         /// Whether the wallet holds sanctioned NFTs/assets (from Helius DAS)
         #[schema(example = false)]
         has_sanctioned_assets: bool,
@@ -1149,22 +3362,149 @@ pub enum RiskCheckResult {
     },
 }
 
-/// Single transaction from Helius webhook (Enhanced Transaction format)
-/// Reference: <https://docs.helius.dev/webhooks-and-websockets/webhooks>
+/// Single transaction from Helius webhook (Enhanced Transaction format)
+/// Reference: <https://docs.helius.dev/webhooks-and-websockets/webhooks>
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeliusTransaction {
+    /// Transaction type (e.g., "TRANSFER", "NFT_SALE", "UNKNOWN")
+    #[serde(rename = "type")]
+    pub transaction_type: String,
+    /// Transaction signature (base58)
+    pub signature: String,
+    /// Transaction error (null if successful, object/string if failed)
+    #[serde(default)]
+    pub transaction_error: Option<serde_json::Value>,
+    /// Source program (e.g., "SYSTEM_PROGRAM")
+    #[serde(default)]
+    pub source: String,
+    /// Parsed native SOL transfers (absent on raw payloads)
+    #[serde(default)]
+    pub native_transfers: Option<Vec<HeliusNativeTransfer>>,
+    /// Parsed SPL token transfers (absent on raw payloads)
+    #[serde(default)]
+    pub token_transfers: Option<Vec<HeliusTokenTransfer>>,
+    /// Per-account balance changes, carrying raw token amounts
+    #[serde(default)]
+    pub account_data: Vec<HeliusAccountData>,
+}
+
+impl HeliusTransaction {
+    /// Whether Helius parsed the transfers of this transaction
+    pub fn is_enhanced(&self) -> bool {
+        self.native_transfers.is_some() || self.token_transfers.is_some()
+    }
+
+    /// Transfers parsed from the transaction.
+    ///
+    /// Token amounts are taken from the raw balance change of the recipient,
+    /// since `tokenAmount` is a lossy decimal. That change is the owner's
+    /// total credit of the mint, so it is reported once per recipient and
+    /// mint even when several token transfers pay the same owner.
+    pub fn observed_transfers(&self) -> Vec<ObservedTransfer> {
+        let native = self
+            .native_transfers
+            .iter()
+            .flatten()
+            .map(|t| ObservedTransfer {
+                recipient: t.to_user_account.clone(),
+                mint: None,
+                amount: Some(t.amount),
+            });
+        let mut credited = HashSet::new();
+        let tokens = self
+            .token_transfers
+            .iter()
+            .flatten()
+            .filter(|t| credited.insert((t.to_user_account.as_str(), t.mint.as_str())))
+            .map(|t| ObservedTransfer {
+                recipient: t.to_user_account.clone(),
+                mint: Some(t.mint.clone()),
+                amount: self.raw_token_credit(&t.to_user_account, &t.mint),
+            });
+        native.chain(tokens).collect()
+    }
+
+    /// Raw amount of `mint` credited to `owner`, if Helius reported it
+    fn raw_token_credit(&self, owner: &str, mint: &str) -> Option<u64> {
+        let credits: Vec<u64> = self
+            .account_data
+            .iter()
+            .flat_map(|data| &data.token_balance_changes)
+            .filter(|change| change.user_account == owner && change.mint == mint)
+            .filter_map(|change| change.raw_token_amount.token_amount.parse::<i128>().ok())
+            .filter(|amount| *amount > 0)
+            .filter_map(|amount| u64::try_from(amount).ok())
+            .collect();
+        (!credits.is_empty()).then(|| credits.iter().sum())
+    }
+}
+
+/// Native SOL transfer parsed by Helius
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeliusNativeTransfer {
+    /// Sender wallet
+    #[serde(default)]
+    pub from_user_account: String,
+    /// Recipient wallet
+    #[serde(default)]
+    pub to_user_account: String,
+    /// Amount in lamports
+    pub amount: u64,
+}
+
+/// SPL token transfer parsed by Helius
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeliusTokenTransfer {
+    /// Sender wallet (token account owner)
+    #[serde(default)]
+    pub from_user_account: String,
+    /// Recipient wallet (token account owner)
+    #[serde(default)]
+    pub to_user_account: String,
+    /// Token mint
+    pub mint: String,
+    /// Amount in UI units (decimals applied)
+    #[serde(default)]
+    pub token_amount: f64,
+}
+
+/// Balance changes of a single account in a Helius enhanced transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeliusAccountData {
+    /// Account address
+    pub account: String,
+    /// Lamport balance change
+    #[serde(default)]
+    pub native_balance_change: i64,
+    /// Token balance changes of token accounts owned by this account
+    #[serde(default)]
+    pub token_balance_changes: Vec<HeliusTokenBalanceChange>,
+}
+
+/// Token balance change reported by Helius
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeliusTokenBalanceChange {
+    /// Owner of the token account
+    pub user_account: String,
+    /// Token mint
+    pub mint: String,
+    /// Signed raw amount of the change
+    pub raw_token_amount: HeliusRawTokenAmount,
+}
+
+/// Raw token amount as reported by Helius
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct HeliusTransaction {
-    /// Transaction type (e.g., "TRANSFER", "NFT_SALE", "UNKNOWN")
-    #[serde(rename = "type")]
-    pub transaction_type: String,
-    /// Transaction signature (base58)
-    pub signature: String,
-    /// Transaction error (null if successful, object/string if failed)
-    #[serde(default)]
-    pub transaction_error: Option<serde_json::Value>,
-    /// Source program (e.g., "SYSTEM_PROGRAM")
-    #[serde(default)]
-    pub source: String,
+pub struct HeliusRawTokenAmount {
+    /// Signed amount in base units, as a decimal string
+    pub token_amount: String,
+    /// Mint decimals
+    pub decimals: u8,
 }
 
 // ============================================================================
@@ -1265,19 +3605,87 @@ pub struct QuickNodeTransactionMeta {
     pub post_balances: Vec<u64>,
 }
 
+// ============================================================================
+// Normalized Webhook Events
+// ============================================================================
+
+/// Transaction outcome reported by a webhook provider, normalized so every
+/// provider funnels into the same processing path
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConfirmationEvent {
+    /// Transaction signature (base58)
+    pub signature: String,
+    /// Whether the transaction executed successfully
+    pub success: bool,
+    /// Provider-reported error for failed transactions
+    pub error: Option<String>,
+    /// Slot the transaction landed in, if reported
+    pub slot: Option<u64>,
+    /// Transfers parsed from the transaction (`None` if the provider does not parse them)
+    #[serde(default)]
+    pub transfers: Option<Vec<ObservedTransfer>>,
+}
+
+/// Transfer observed on-chain, used to reconcile a landed transaction
+/// against the stored request
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObservedTransfer {
+    /// Recipient wallet
+    pub recipient: String,
+    /// Token mint (`None` for native SOL)
+    pub mint: Option<String>,
+    /// Amount credited in base units, if reported
+    pub amount: Option<u64>,
+}
+
+impl From<HeliusTransaction> for ConfirmationEvent {
+    fn from(tx: HeliusTransaction) -> Self {
+        let transfers = tx.is_enhanced().then(|| tx.observed_transfers());
+        Self {
+            success: tx.transaction_error.is_none(),
+            error: tx.transaction_error.map(|e| e.to_string()),
+            signature: tx.signature,
+            slot: None,
+            transfers,
+        }
+    }
+}
+
+impl From<QuickNodeWebhookEvent> for ConfirmationEvent {
+    fn from(event: QuickNodeWebhookEvent) -> Self {
+        Self {
+            success: event.is_success(),
+            error: event.error_message(),
+            signature: event.signature,
+            slot: event.slot,
+            transfers: None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::str::FromStr;
 
+    /// Base58 public key built from `seed`
+    fn test_address(seed: u8) -> String {
+        bs58::encode([seed; 32]).into_string()
+    }
+
     #[test]
     fn test_blockchain_status_display_and_parsing() {
         let statuses = vec![
             (BlockchainStatus::Pending, "pending"),
             (BlockchainStatus::PendingSubmission, "pending_submission"),
             (BlockchainStatus::Submitted, "submitted"),
+            (BlockchainStatus::AwaitingApproval, "awaiting_approval"),
             (BlockchainStatus::Confirmed, "confirmed"),
             (BlockchainStatus::Failed, "failed"),
+            (BlockchainStatus::Rejected, "rejected"),
+            (BlockchainStatus::Unreconciled, "unreconciled"),
+            (BlockchainStatus::Expired, "expired"),
+            (BlockchainStatus::Cancelled, "cancelled"),
         ];
 
         for (status, string) in statuses {
@@ -1289,6 +3697,46 @@ mod tests {
         assert!(BlockchainStatus::from_str("invalid").is_err());
     }
 
+    #[test]
+    fn test_proposal_status_transitions() {
+        assert!(!BlockchainStatus::AwaitingApproval.is_terminal());
+        assert_eq!(
+            ProposalStatus::Executed.blockchain_status(),
+            Some(BlockchainStatus::Confirmed)
+        );
+        assert_eq!(
+            ProposalStatus::Rejected.blockchain_status(),
+            Some(BlockchainStatus::Failed)
+        );
+        assert_eq!(
+            ProposalStatus::Cancelled.blockchain_status(),
+            Some(BlockchainStatus::Failed)
+        );
+        for undecided in [
+            ProposalStatus::Draft,
+            ProposalStatus::Active,
+            ProposalStatus::Approved,
+            ProposalStatus::Executing,
+        ] {
+            assert_eq!(undecided.blockchain_status(), None);
+        }
+    }
+
+    #[test]
+    fn test_proposal_outcome_awaits_approval() {
+        let outcome = SubmissionOutcome::proposal("Proposa1");
+        assert!(outcome.awaiting_approval);
+        assert_eq!(outcome.signature, "Proposa1");
+        assert_eq!(
+            outcome.submitted_status(),
+            BlockchainStatus::AwaitingApproval
+        );
+        assert_eq!(
+            SubmissionOutcome::new("sig").submitted_status(),
+            BlockchainStatus::Submitted
+        );
+    }
+
     #[test]
     fn test_compliance_status_display_and_parsing() {
         let statuses = vec![
@@ -1306,14 +3754,82 @@ mod tests {
         assert!(ComplianceStatus::from_str("invalid").is_err());
     }
 
+    #[test]
+    fn test_blocklist_category_display_and_parsing() {
+        let categories = vec![
+            (BlocklistCategory::OfacSanction, "ofac_sanction"),
+            (BlocklistCategory::InternalFraud, "internal_fraud"),
+            (BlocklistCategory::RangeAutoBlock, "range_auto_block"),
+            (BlocklistCategory::Manual, "manual"),
+        ];
+
+        for (category, string) in categories {
+            assert_eq!(category.as_str(), string);
+            assert_eq!(category.to_string(), string);
+            assert_eq!(BlocklistCategory::from_str(string).unwrap(), category);
+            assert!(category.default_severity() <= MAX_BLOCKLIST_SEVERITY);
+        }
+
+        assert!(BlocklistCategory::from_str("invalid").is_err());
+        assert_eq!(BlocklistCategory::default(), BlocklistCategory::Manual);
+    }
+
+    #[test]
+    fn test_api_key_store_parsing() {
+        let store = ApiKeyStore::from_str("k1:submit, k2:admin,k2:submit,k3").unwrap();
+        assert!(store.authorize(Some("k1"), ApiKeyScope::Submit).is_ok());
+        assert!(store.authorize(Some("k2"), ApiKeyScope::Submit).is_ok());
+        assert!(store.authorize(Some("k2"), ApiKeyScope::Admin).is_ok());
+        for scope in ApiKeyScope::ALL {
+            assert!(store.authorize(Some("k3"), scope).is_ok());
+        }
+
+        assert!(ApiKeyStore::from_str("").unwrap().is_empty());
+        assert!(ApiKeyStore::from_str("k1:superuser").is_err());
+        assert!(ApiKeyStore::from_str(":submit").is_err());
+    }
+
+    #[test]
+    fn test_api_key_store_authorize() {
+        let store = ApiKeyStore::new().with_key("submitter", [ApiKeyScope::Submit]);
+
+        assert!(matches!(
+            store.authorize(None, ApiKeyScope::Submit),
+            Err(AppError::Authentication(_))
+        ));
+        assert!(matches!(
+            store.authorize(Some("unknown"), ApiKeyScope::Submit),
+            Err(AppError::Authentication(_))
+        ));
+        assert!(
+            store
+                .authorize(Some("submitter"), ApiKeyScope::Submit)
+                .is_ok()
+        );
+
+        // No key grants admin yet, so the scope is not enforced
+        assert!(store.authorize(None, ApiKeyScope::Admin).is_ok());
+        let store = store.with_key("operator", [ApiKeyScope::Admin]);
+        assert!(matches!(
+            store.authorize(Some("submitter"), ApiKeyScope::Admin),
+            Err(AppError::Authorization(_))
+        ));
+        assert!(
+            store
+                .authorize(Some("operator"), ApiKeyScope::Admin)
+                .is_ok()
+        );
+    }
+
     #[test]
     fn test_submit_transfer_request_validation() {
         let valid_nonce = "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string();
+        let (from, to, mint) = (test_address(1), test_address(2), test_address(3));
 
         // Valid request (1 SOL in lamports)
         let req = SubmitTransferRequest::new(
-            "From".to_string(),
-            "To".to_string(),
+            from.clone(),
+            to.clone(),
             1_000_000_000,
             "sig".to_string(),
             valid_nonce.clone(),
@@ -1323,7 +3839,7 @@ mod tests {
         // Invalid From (empty)
         let req = SubmitTransferRequest::new(
             "".to_string(),
-            "To".to_string(),
+            to.clone(),
             1_000_000_000,
             "sig".to_string(),
             valid_nonce.clone(),
@@ -1332,7 +3848,7 @@ mod tests {
 
         // Invalid To (empty)
         let req = SubmitTransferRequest::new(
-            "From".to_string(),
+            from.clone(),
             "".to_string(),
             1_000_000_000,
             "sig".to_string(),
@@ -1342,8 +3858,8 @@ mod tests {
 
         // Invalid Amount (zero)
         let req = SubmitTransferRequest::new(
-            "From".to_string(),
-            "To".to_string(),
+            from.clone(),
+            to.clone(),
             0,
             "sig".to_string(),
             valid_nonce.clone(),
@@ -1352,8 +3868,8 @@ mod tests {
 
         // Invalid Nonce (empty)
         let req = SubmitTransferRequest::new(
-            "From".to_string(),
-            "To".to_string(),
+            from.clone(),
+            to.clone(),
             1_000_000_000,
             "sig".to_string(),
             "".to_string(),
@@ -1362,8 +3878,8 @@ mod tests {
 
         // Invalid Nonce (too short)
         let req = SubmitTransferRequest::new(
-            "From".to_string(),
-            "To".to_string(),
+            from.clone(),
+            to.clone(),
             1_000_000_000,
             "sig".to_string(),
             "short".to_string(),
@@ -1372,8 +3888,8 @@ mod tests {
 
         // Invalid Nonce (invalid characters)
         let req = SubmitTransferRequest::new(
-            "From".to_string(),
-            "To".to_string(),
+            from.clone(),
+            to.clone(),
             1_000_000_000,
             "sig".to_string(),
             "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7!".to_string(),
@@ -1382,13 +3898,13 @@ mod tests {
 
         // Valid Confidential Request
         let req = SubmitTransferRequest::new_confidential(
-            "From".to_string(),
-            "To".to_string(),
+            from.clone(),
+            to.clone(),
             "balance".to_string(),
             "equality".to_string(),
             "validity".to_string(),
             "range".to_string(),
-            "mint".to_string(),
+            mint.clone(),
             "sig".to_string(),
             valid_nonce.clone(),
         );
@@ -1396,17 +3912,342 @@ mod tests {
 
         // Invalid Confidential (empty equality proof)
         let req = SubmitTransferRequest::new_confidential(
-            "From".to_string(),
-            "To".to_string(),
+            from.clone(),
+            to.clone(),
             "balance".to_string(),
             "".to_string(),
             "validity".to_string(),
             "range".to_string(),
-            "mint".to_string(),
+            mint.clone(),
             "sig".to_string(),
             valid_nonce.clone(),
         );
         assert!(req.validate().is_err());
+
+        // Auditor ElGamal pubkey must decode to 32 bytes
+        let mut req = SubmitTransferRequest::new_confidential(
+            from.clone(),
+            to.clone(),
+            "balance".to_string(),
+            "equality".to_string(),
+            "validity".to_string(),
+            "range".to_string(),
+            mint.clone(),
+            "sig".to_string(),
+            valid_nonce.clone(),
+        );
+        for (auditor, valid) in [
+            (BASE64_STANDARD.encode([7u8; 32]), true),
+            (BASE64_STANDARD.encode([7u8; 31]), false),
+            ("not base64!".to_string(), false),
+        ] {
+            if let TransferType::Confidential {
+                auditor_elgamal_pubkey,
+                ..
+            } = &mut req.transfer_details
+            {
+                *auditor_elgamal_pubkey = Some(auditor);
+            }
+            assert_eq!(req.validate().is_ok(), valid);
+        }
+
+        // Fee proofs must be provided together and be non-empty
+        let mut req = SubmitTransferRequest::new_confidential(
+            from.clone(),
+            to.clone(),
+            "balance".to_string(),
+            "equality".to_string(),
+            "validity".to_string(),
+            "range".to_string(),
+            mint.clone(),
+            "sig".to_string(),
+            valid_nonce.clone(),
+        );
+        for (sigma, fee_validity, valid) in [
+            (Some("sigma"), Some("fee_validity"), true),
+            (Some("sigma"), None, false),
+            (None, Some("fee_validity"), false),
+            (Some(""), Some("fee_validity"), false),
+        ] {
+            if let TransferType::Confidential {
+                fee_sigma_proof,
+                fee_ciphertext_validity_proof,
+                ..
+            } = &mut req.transfer_details
+            {
+                *fee_sigma_proof = sigma.map(str::to_string);
+                *fee_ciphertext_validity_proof = fee_validity.map(str::to_string);
+            }
+            assert_eq!(req.validate().is_ok(), valid);
+        }
+    }
+
+    #[test]
+    fn test_validate_solana_address() {
+        assert!(validate_solana_address(&test_address(1)).is_ok());
+        assert!(validate_solana_address("DRpbCBMxVnDK7maPM5tGv6MvB3v1sRMC86PZ8okm21hy").is_ok());
+
+        // Too short, too long, not base58
+        let short = bs58::encode([1u8; 31]).into_string();
+        let long = bs58::encode([1u8; 33]).into_string();
+        for address in [short.as_str(), long.as_str(), "", "0OIl+/not-base58"] {
+            let err = validate_solana_address(address).unwrap_err();
+            assert_eq!(err.code, INVALID_ADDRESS_CODE, "{}", address);
+        }
+    }
+
+    #[test]
+    fn test_parse_sol_amount_is_exact() {
+        assert_eq!(parse_sol_amount("1"), Ok(LAMPORTS_PER_SOL));
+        assert_eq!(parse_sol_amount("1.5"), Ok(1_500_000_000));
+        assert_eq!(parse_sol_amount("0.000000001"), Ok(1));
+        assert_eq!(parse_sol_amount("123.456789012"), Ok(123_456_789_012));
+        // 0.1 + 0.2 style float errors cannot occur
+        assert_eq!(parse_sol_amount("0.3"), Ok(300_000_000));
+        assert_eq!(parse_sol_amount("007.10"), Ok(7_100_000_000));
+        assert_eq!(parse_sol_amount("0"), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_sol_amount_rejects_invalid_input() {
+        assert_eq!(
+            parse_sol_amount("0.0000000001"),
+            Err("SOL amount must have at most 9 decimal places")
+        );
+        for amount in ["", ".5", "1.", "-1", "+1", "1e9", " 1", "1,5", "1.2.3"] {
+            assert!(parse_sol_amount(amount).is_err(), "{:?}", amount);
+        }
+        // u64::MAX lamports is ~18.4 billion SOL
+        assert_eq!(parse_sol_amount("18446744073.709551615"), Ok(u64::MAX));
+        assert!(parse_sol_amount("18446744073.709551616").is_err());
+        assert!(parse_sol_amount("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn test_amount_sol_validation() {
+        let mut request = screenable_request();
+        request.transfer_details = TransferType::Public { amount: 0 };
+        request.amount_sol = Some("1.5".to_string());
+        assert!(request.validate().is_ok());
+        assert_eq!(request.public_amount(), Some(1_500_000_000));
+
+        // Both amounts
+        request.transfer_details = TransferType::Public { amount: 1_000 };
+        let errors = request.validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("amount_sol"));
+
+        // Neither amount
+        request.transfer_details = TransferType::Public { amount: 0 };
+        request.amount_sol = None;
+        let errors = request.validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("amount"));
+
+        // Excess precision and zero
+        for amount_sol in ["0.0000000001", "0", "0.000000000"] {
+            request.amount_sol = Some(amount_sol.to_string());
+            assert!(request.validate().is_err(), "{}", amount_sol);
+        }
+
+        // SPL token transfers are denominated in the mint's units
+        request.amount_sol = Some("1".to_string());
+        request.token_mint = Some(test_address(3));
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn test_amount_sol_is_deserialized_and_normalized() {
+        let request: SubmitTransferRequest = serde_json::from_value(serde_json::json!({
+            "from_address": test_address(1),
+            "to_address": test_address(2),
+            "transfer_details": { "type": "public" },
+            "amount_sol": "2.25",
+            "signature": "sig",
+            "nonce": "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a"
+        }))
+        .unwrap();
+        assert!(request.validate().is_ok());
+
+        let normalized = request.normalize_amount().unwrap();
+        assert_eq!(
+            normalized.transfer_details,
+            TransferType::Public {
+                amount: 2_250_000_000
+            }
+        );
+        assert_eq!(normalized.amount_sol, None);
+
+        // Requests in lamports are passed through as-is
+        let lamports = screenable_request();
+        assert!(matches!(
+            lamports.normalize_amount().unwrap(),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_omitted_mint_resolves_to_default() {
+        let default_mint = test_address(3);
+        let request = screenable_request();
+        let resolved = request.resolve_token_mint(Some(&default_mint));
+        assert_eq!(resolved.token_mint, Some(default_mint.clone()));
+
+        // The signed message names the resolved mint, not SOL
+        let message = String::from_utf8(resolved.create_signing_message().unwrap()).unwrap();
+        assert!(
+            message.contains(&format!(":{}:", default_mint)),
+            "{}",
+            message
+        );
+        assert!(!message.contains(":SOL:"), "{}", message);
+        assert!(resolved.verify_signature().is_err());
+        assert!(sign(resolved.into_owned()).verify_signature().is_ok());
+
+        // No default, an explicit mint, or an amount in SOL are left alone
+        assert!(matches!(request.resolve_token_mint(None), Cow::Borrowed(_)));
+        let mut explicit = screenable_request();
+        explicit.token_mint = Some(test_address(4));
+        assert_eq!(
+            explicit.resolve_token_mint(Some(&default_mint)).token_mint,
+            Some(test_address(4))
+        );
+        let mut in_sol = screenable_request();
+        in_sol.transfer_details = TransferType::Public { amount: 0 };
+        in_sol.amount_sol = Some("1.5".to_string());
+        assert!(matches!(
+            in_sol.resolve_token_mint(Some(&default_mint)),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    fn batch_request(recipients: Vec<(String, u64)>) -> SubmitTransferRequest {
+        let mut request = screenable_request();
+        request.to_address = recipients
+            .first()
+            .map(|(address, _)| address.clone())
+            .unwrap_or_default();
+        request.token_mint = Some(test_address(9));
+        request.transfer_details = TransferType::PublicBatch { recipients };
+        request
+    }
+
+    #[test]
+    fn test_batch_transfer_validation() {
+        let recipients = vec![(test_address(2), 1_000), (test_address(3), 2_000)];
+        let request = batch_request(recipients.clone());
+        assert!(request.validate().is_ok());
+        assert_eq!(request.batch_total(), Some(3_000));
+        assert_eq!(request.public_amount(), None);
+
+        let rejected_field = |request: SubmitTransferRequest| {
+            let errors = request.validate().unwrap_err();
+            let mut fields: Vec<_> = errors.field_errors().into_keys().collect();
+            fields.sort();
+            fields.join(",")
+        };
+
+        // Each sub-transfer is validated
+        let mut zero = recipients.clone();
+        zero[1].1 = 0;
+        assert_eq!(rejected_field(batch_request(zero)), "recipients");
+        let mut malformed = recipients.clone();
+        malformed[1].0 = "not-an-address".to_string();
+        assert_eq!(rejected_field(batch_request(malformed)), "recipients");
+        let overflow = vec![(test_address(2), u64::MAX), (test_address(3), 1)];
+        assert_eq!(rejected_field(batch_request(overflow)), "recipients");
+
+        assert_eq!(
+            rejected_field(batch_request(Vec::new())),
+            "recipients,to_address"
+        );
+        let too_many = (0..=MAX_BATCH_RECIPIENTS)
+            .map(|_| (test_address(2), 1))
+            .collect();
+        assert_eq!(rejected_field(batch_request(too_many)), "recipients");
+
+        let mut native = batch_request(recipients.clone());
+        native.token_mint = None;
+        assert_eq!(rejected_field(native), "token_mint");
+
+        let mut mismatched = batch_request(recipients.clone());
+        mismatched.to_address = test_address(3);
+        assert_eq!(rejected_field(mismatched), "to_address");
+
+        let mut in_sol = batch_request(recipients);
+        in_sol.amount_sol = Some("1".to_string());
+        assert_eq!(rejected_field(in_sol), "amount_sol");
+    }
+
+    #[test]
+    fn test_batch_transfer_signing_message_covers_every_recipient() {
+        let request = batch_request(vec![(test_address(2), 1_000), (test_address(3), 2_000)]);
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert_eq!(
+            message,
+            format!(
+                "{}:{}:{}=1000,{}=2000:{}:{}",
+                request.from_address,
+                test_address(2),
+                test_address(2),
+                test_address(3),
+                test_address(9),
+                request.nonce
+            )
+        );
+    }
+
+    #[test]
+    fn test_batch_sub_transfers() {
+        let request = batch_request(vec![(test_address(2), 1_000), (test_address(3), 2_000)]);
+        let sub_transfers = request.batch_sub_transfers();
+
+        assert_eq!(sub_transfers.len(), 2);
+        assert_eq!(sub_transfers[1].to_address, test_address(3));
+        assert_eq!(
+            sub_transfers[1].transfer_details,
+            TransferType::Public { amount: 2_000 }
+        );
+        assert_eq!(sub_transfers[1].from_address, request.from_address);
+        assert_eq!(sub_transfers[1].token_mint, request.token_mint);
+
+        assert!(screenable_request().batch_sub_transfers().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_addresses_map_to_invalid_address_error() {
+        let mut request = SubmitTransferRequest::new(
+            test_address(1),
+            "To".to_string(),
+            1_000,
+            "sig".to_string(),
+            "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string(),
+        );
+        request.token_mint = Some("mint".to_string());
+
+        let err = AppError::from(request.validate().unwrap_err());
+        match err {
+            AppError::Validation(ValidationError::InvalidAddress(message)) => {
+                assert!(message.starts_with("to_address, token_mint"), "{}", message);
+            }
+            other => panic!("expected InvalidAddress, got {:?}", other),
+        }
+
+        // With other failures, every one is reported together
+        request.nonce = "short".to_string();
+        match AppError::from(request.validate().unwrap_err()) {
+            AppError::Validation(ValidationError::Multiple(message)) => {
+                for field in ["to_address", "token_mint", "nonce"] {
+                    assert!(message.contains(field), "{}", message);
+                }
+            }
+            other => panic!("expected Multiple, got {:?}", other),
+        }
+
+        request.to_address = test_address(2);
+        request.token_mint = None;
+        assert!(matches!(
+            AppError::from(request.validate().unwrap_err()),
+            AppError::Validation(ValidationError::Multiple(_))
+        ));
     }
 
     #[test]
@@ -1535,4 +4376,340 @@ mod tests {
         assert!(!event.is_success());
         assert!(event.error_message().is_some());
     }
+
+    fn signed_request(memo: Option<&str>) -> SubmitTransferRequest {
+        signed_request_with_priority(memo, 0)
+    }
+
+    fn signed_request_with_priority(memo: Option<&str>, priority: i16) -> SubmitTransferRequest {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&[7u8; 32]);
+        let mut request = SubmitTransferRequest::new(
+            bs58::encode(signing_key.verifying_key().as_bytes()).into_string(),
+            "To".to_string(),
+            1_000_000,
+            String::new(),
+            "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string(),
+        )
+        .with_priority(priority);
+        request.memo = memo.map(str::to_string);
+        sign(request)
+    }
+
+    /// Signed request whose recipient passes address validation
+    fn screenable_request() -> SubmitTransferRequest {
+        let mut request = signed_request(None);
+        request.to_address = test_address(2);
+        sign(request)
+    }
+
+    /// Sign `request` with the test key over its current signing message
+    fn sign(mut request: SubmitTransferRequest) -> SubmitTransferRequest {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let signing_key = SigningKey::from_bytes(&[7u8; 32]);
+        let signature = signing_key.sign(&request.create_signing_message().unwrap());
+        request.signature = bs58::encode(signature.to_bytes()).into_string();
+        request
+    }
+
+    #[test]
+    fn test_signing_message_without_memo_is_v1() {
+        let request = signed_request(None);
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert_eq!(
+            message,
+            format!(
+                "{}:To:1000000:SOL:019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a",
+                request.from_address
+            )
+        );
+        assert!(request.verify_signature().is_ok());
+    }
+
+    #[test]
+    fn test_signing_message_with_memo_is_length_prefixed_v2() {
+        let request = signed_request(Some("INV:42"));
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert_eq!(
+            message,
+            format!(
+                "v2:{}:{}2:To7:10000003:SOL36:019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a6:INV:42",
+                request.from_address.len(),
+                request.from_address
+            )
+        );
+        assert!(request.verify_signature().is_ok());
+    }
+
+    #[test]
+    fn test_signing_message_with_priority_is_v3() {
+        let request = signed_request_with_priority(None, 10);
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert_eq!(
+            message,
+            format!(
+                "v3:{}:{}2:To7:10000003:SOL36:019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a0:2:10",
+                request.from_address.len(),
+                request.from_address
+            )
+        );
+        assert!(request.verify_signature().is_ok());
+
+        let with_memo = signed_request_with_priority(Some("INV:42"), -5);
+        let message = String::from_utf8(with_memo.create_signing_message().unwrap()).unwrap();
+        assert!(message.starts_with("v3:"));
+        assert!(message.ends_with("6:INV:422:-5"));
+        assert!(with_memo.verify_signature().is_ok());
+    }
+
+    #[test]
+    fn test_declared_v1_matches_legacy_format() {
+        let legacy = signed_request(None);
+        let declared = legacy.clone().with_signature_version(1);
+        assert_eq!(
+            declared.create_signing_message().unwrap(),
+            legacy.create_signing_message().unwrap()
+        );
+        assert!(declared.verify_signature().is_ok());
+    }
+
+    #[test]
+    fn test_declared_v2_carries_fields_without_memo() {
+        let request = sign(signed_request(None).with_signature_version(2));
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert_eq!(
+            message,
+            format!(
+                "v2:{}:{}2:To7:10000003:SOL36:019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a0:",
+                request.from_address.len(),
+                request.from_address
+            )
+        );
+        assert!(request.verify_signature().is_ok());
+
+        let request = sign(signed_request(Some("INV:42")).with_signature_version(3));
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert!(message.starts_with("v3:"));
+        assert!(message.ends_with("6:INV:421:0"));
+        assert!(request.verify_signature().is_ok());
+    }
+
+    #[test]
+    fn test_valid_until_is_covered_by_v4_signature() {
+        let valid_until = DateTime::parse_from_rfc3339("2026-01-30T10:35:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let request = sign(signed_request(None).with_valid_until(valid_until));
+        assert_eq!(request.signing_message_version(), 4);
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert!(message.starts_with("v4:"));
+        assert!(message.ends_with("0:1:010:1769769300"));
+        assert!(request.verify_signature().is_ok());
+
+        // Extending the expiry or stripping it invalidates the signature
+        let mut extended = request.clone();
+        extended.valid_until = Some(valid_until + chrono::Duration::hours(1));
+        assert!(extended.verify_signature().is_err());
+
+        let mut stripped = request.clone();
+        stripped.valid_until = None;
+        assert!(stripped.verify_signature().is_err());
+
+        // Older versions cannot carry an expiry
+        let v3 = request.with_signature_version(3);
+        assert!(v3.create_signing_message().is_err());
+    }
+
+    #[test]
+    fn test_unknown_signature_version_rejected() {
+        for version in [0, LATEST_SIGNATURE_VERSION + 1, u8::MAX] {
+            let request = signed_request(None).with_signature_version(version);
+            assert!(matches!(
+                request.create_signing_message(),
+                Err(AppError::Validation(ValidationError::InvalidField { ref field, .. }))
+                    if field == "signature_version"
+            ));
+            assert!(request.verify_signature().is_err());
+        }
+    }
+
+    #[test]
+    fn test_signature_version_must_cover_memo_and_priority() {
+        let memo = signed_request(Some("INV-2026-0042")).with_signature_version(1);
+        assert!(memo.create_signing_message().is_err());
+
+        let priority = signed_request_with_priority(None, 5).with_signature_version(2);
+        assert!(priority.create_signing_message().is_err());
+    }
+
+    #[test]
+    fn test_signature_does_not_verify_under_another_version() {
+        // A v1 signature relabeled as v2
+        let relabeled = signed_request(None).with_signature_version(2);
+        assert!(relabeled.verify_signature().is_err());
+
+        // A v2 signature stripped of its declared version falls back to v1
+        let mut stripped = sign(signed_request(None).with_signature_version(2));
+        stripped.signature_version = None;
+        assert!(stripped.verify_signature().is_err());
+
+        // A v3 signature downgraded to v2
+        let downgraded = sign(signed_request(Some("INV-2026-0042")).with_signature_version(3))
+            .with_signature_version(2);
+        assert!(downgraded.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_priority_is_covered_by_signature() {
+        let mut escalated = signed_request(None);
+        escalated.priority = 10;
+        assert!(escalated.verify_signature().is_err());
+
+        let mut escalated = signed_request_with_priority(Some("INV-2026-0042"), 1);
+        escalated.priority = 2;
+        assert!(escalated.verify_signature().is_err());
+
+        let mut reset = signed_request_with_priority(None, 10);
+        reset.priority = 0;
+        assert!(reset.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_priority_defaults_to_zero() {
+        let json = r#"{
+            "from_address": "From",
+            "to_address": "To",
+            "transfer_details": {"type": "public", "amount": 1},
+            "signature": "sig",
+            "nonce": "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a"
+        }"#;
+        let request: SubmitTransferRequest = serde_json::from_str(json).unwrap();
+        assert_eq!(request.priority, 0);
+        assert!(
+            !serde_json::to_string(&request)
+                .unwrap()
+                .contains("priority")
+        );
+
+        let request = request.with_priority(3);
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["priority"], 3);
+    }
+
+    #[test]
+    fn test_memo_is_covered_by_signature() {
+        let mut tampered = signed_request(Some("INV-2026-0042"));
+        tampered.memo = Some("INV-2026-0043".to_string());
+        assert!(tampered.verify_signature().is_err());
+
+        let mut stripped = signed_request(Some("INV-2026-0042"));
+        stripped.memo = None;
+        assert!(stripped.verify_signature().is_err());
+
+        let mut added = signed_request(None);
+        added.memo = Some("INV-2026-0042".to_string());
+        assert!(added.verify_signature().is_err());
+    }
+
+    #[test]
+    fn test_memo_length_validation() {
+        let request = screenable_request();
+        assert!(
+            request
+                .clone()
+                .with_memo("a".repeat(MAX_MEMO_LEN))
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            request
+                .clone()
+                .with_memo("a".repeat(MAX_MEMO_LEN + 1))
+                .validate()
+                .is_err()
+        );
+        assert!(request.with_memo("").validate().is_err());
+    }
+
+    #[test]
+    fn test_callback_url_validation() {
+        let mut request = screenable_request();
+        for (url, valid) in [
+            ("https://merchant.example/hook", true),
+            ("http://localhost:8080/hook", true),
+            ("ftp://merchant.example/hook", false),
+            ("merchant.example/hook", false),
+        ] {
+            request.callback_url = Some(url.to_string());
+            assert_eq!(request.validate().is_ok(), valid, "{}", url);
+        }
+
+        request.callback_url = Some(format!("https://{}", "a".repeat(MAX_CALLBACK_URL_LEN)));
+        assert!(request.validate().is_err());
+
+        // The callback URL is not part of the signed message
+        assert!(request.verify_signature().is_ok());
+    }
+
+    #[test]
+    fn test_jito_region_validation() {
+        let request = screenable_request();
+        for region in JITO_REGIONS {
+            assert!(request.clone().with_jito_region(*region).validate().is_ok());
+        }
+        assert!(request.clone().with_jito_region("mars").validate().is_err());
+        assert!(request.clone().with_jito_region("NY").validate().is_err());
+    }
+
+    #[test]
+    fn test_jito_region_is_covered_by_v5_signature() {
+        let request = sign(signed_request(None).with_jito_region("tokyo"));
+        assert_eq!(request.signing_message_version(), 5);
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert!(message.starts_with("v5:"));
+        assert!(message.ends_with("0:1:00:5:tokyo"));
+        assert!(request.verify_signature().is_ok());
+
+        // Rerouting the bundle or dropping the region invalidates the signature
+        let rerouted = request.clone().with_jito_region("ny");
+        assert!(rerouted.verify_signature().is_err());
+
+        let mut stripped = request.clone();
+        stripped.jito_region = None;
+        assert!(stripped.verify_signature().is_err());
+
+        // Adding a region to a request signed without one is rejected too
+        let added = screenable_request().with_jito_region("tokyo");
+        assert!(added.verify_signature().is_err());
+
+        // Older versions cannot carry a region
+        let v4 = request.with_signature_version(4);
+        assert!(v4.create_signing_message().is_err());
+    }
+
+    #[test]
+    fn test_skip_preflight_is_covered_by_v6_signature() {
+        let request = sign(signed_request(None).with_skip_preflight(true));
+        assert_eq!(request.signing_message_version(), 6);
+        let message = String::from_utf8(request.create_signing_message().unwrap()).unwrap();
+        assert!(message.starts_with("v6:"));
+        assert!(message.ends_with("0:1:00:0:4:true"));
+        assert!(request.verify_signature().is_ok());
+
+        // Flipping or dropping the override invalidates the signature
+        let flipped = request.clone().with_skip_preflight(false);
+        assert!(flipped.verify_signature().is_err());
+
+        let mut stripped = request.clone();
+        stripped.skip_preflight = None;
+        assert!(stripped.verify_signature().is_err());
+
+        // Adding an override to a request signed without one is rejected too
+        let added = screenable_request().with_skip_preflight(false);
+        assert!(added.verify_signature().is_err());
+
+        // Older versions cannot carry the override
+        let v5 = request.with_signature_version(5);
+        assert!(v5.create_signing_message().is_err());
+    }
 }
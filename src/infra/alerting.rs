@@ -0,0 +1,428 @@
+//! Operator alerts posted to Slack or Discord.
+//!
+//! Critical events (a low fee payer balance, an open RPC circuit, a spike of
+//! compliance rejections) are reported as [`Alert`]s. [`AlertDispatcher`]
+//! fans each alert out to every configured [`AlertSink`] and drops further
+//! alerts of the same kind until a cooldown has passed, so a flapping
+//! condition cannot flood the channel. [`RejectionRateDetector`] raises an
+//! alert when enough compliance rejections fall within a sliding window.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::{Value, json};
+use tokio::time::Instant;
+use tracing::{debug, info, warn};
+
+use crate::domain::{Alert, AlertKind, AlertSink, AppError, ExternalServiceError};
+
+/// Default time during which further alerts of the same kind are dropped
+pub const DEFAULT_ALERT_COOLDOWN: Duration = Duration::from_secs(600);
+
+/// Default number of rejections within the window that raises an alert
+pub const DEFAULT_REJECTION_ALERT_THRESHOLD: usize = 20;
+
+/// Default sliding window over which rejections are counted
+pub const DEFAULT_REJECTION_ALERT_WINDOW: Duration = Duration::from_secs(300);
+
+/// Timeout of one alert request
+const ALERT_TIMEOUT: Duration = Duration::from_secs(10);
+
+fn http_client() -> Client {
+    Client::builder()
+        .timeout(ALERT_TIMEOUT)
+        .build()
+        .expect("Failed to create HTTP client")
+}
+
+/// POST `body` to a chat webhook. The URL embeds the webhook token, so it is
+/// stripped from request errors before they are logged.
+async fn post_json(http_client: &Client, url: &str, body: &Value) -> Result<(), AppError> {
+    let response = http_client
+        .post(url)
+        .json(body)
+        .send()
+        .await
+        .map_err(|e| ExternalServiceError::HttpError(e.without_url().to_string()))?;
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let message = response.text().await.unwrap_or_default();
+    Err(ExternalServiceError::ApiError {
+        status_code: status.as_u16(),
+        message,
+    }
+    .into())
+}
+
+/// Posts alerts to a Slack incoming webhook
+pub struct SlackWebhookSink {
+    http_client: Client,
+    url: String,
+}
+
+impl SlackWebhookSink {
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            http_client: http_client(),
+            url: url.into(),
+        }
+    }
+
+    /// Message body posted for `alert`
+    #[must_use]
+    pub fn payload(alert: &Alert) -> Value {
+        json!({
+            "text": format!(":rotating_light: *{}*\n{}", alert.kind.title(), alert.message),
+        })
+    }
+}
+
+#[async_trait]
+impl AlertSink for SlackWebhookSink {
+    async fn send_alert(&self, alert: &Alert) -> Result<(), AppError> {
+        post_json(&self.http_client, &self.url, &Self::payload(alert)).await
+    }
+
+    fn name(&self) -> &'static str {
+        "slack"
+    }
+}
+
+/// Posts alerts to a Discord channel webhook
+pub struct DiscordWebhookSink {
+    http_client: Client,
+    url: String,
+}
+
+impl DiscordWebhookSink {
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            http_client: http_client(),
+            url: url.into(),
+        }
+    }
+
+    /// Message body posted for `alert`. Mentions are disabled so text from
+    /// the event cannot ping the channel.
+    #[must_use]
+    pub fn payload(alert: &Alert) -> Value {
+        json!({
+            "content": format!(":rotating_light: **{}**\n{}", alert.kind.title(), alert.message),
+            "allowed_mentions": { "parse": [] },
+        })
+    }
+}
+
+#[async_trait]
+impl AlertSink for DiscordWebhookSink {
+    async fn send_alert(&self, alert: &Alert) -> Result<(), AppError> {
+        post_json(&self.http_client, &self.url, &Self::payload(alert)).await
+    }
+
+    fn name(&self) -> &'static str {
+        "discord"
+    }
+}
+
+/// Rate-limited fan-out of alerts to every configured sink
+pub struct AlertDispatcher {
+    sinks: Vec<Arc<dyn AlertSink>>,
+    cooldown: Duration,
+    last_sent: Mutex<HashMap<AlertKind, Instant>>,
+}
+
+impl AlertDispatcher {
+    /// Dispatcher sending at most one alert of each kind per `cooldown`
+    #[must_use]
+    pub fn new(sinks: Vec<Arc<dyn AlertSink>>, cooldown: Duration) -> Self {
+        Self {
+            sinks,
+            cooldown,
+            last_sent: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Names of the configured sinks, for the startup log
+    pub fn sink_names(&self) -> Vec<&'static str> {
+        self.sinks.iter().map(|sink| sink.name()).collect()
+    }
+
+    /// Send `alert` to every sink, unless an alert of the same kind was sent
+    /// within the cooldown. Sink failures are logged, not returned. The
+    /// cooldown only starts once at least one sink accepted the alert, so a
+    /// failed send is retried by the next alert of that kind. Returns
+    /// whether the alert was sent.
+    pub async fn dispatch(&self, alert: Alert) -> bool {
+        let Some(previous) = self.claim(alert.kind) else {
+            debug!(
+                kind = alert.kind.as_str(),
+                "Alert suppressed during cooldown"
+            );
+            return false;
+        };
+        let mut sent = false;
+        for sink in &self.sinks {
+            match sink.send_alert(&alert).await {
+                Ok(()) => {
+                    sent = true;
+                    info!(sink = sink.name(), kind = alert.kind.as_str(), "Alert sent");
+                }
+                Err(e) => warn!(
+                    sink = sink.name(),
+                    kind = alert.kind.as_str(),
+                    error = %e,
+                    "Failed to send alert"
+                ),
+            }
+        }
+        if !sent {
+            self.release(alert.kind, previous);
+        }
+        sent
+    }
+
+    /// [`Self::dispatch`] on a background task, for callers that must not
+    /// wait on the webhook
+    pub fn dispatch_detached(self: &Arc<Self>, alert: Alert) {
+        let dispatcher = Arc::clone(self);
+        tokio::spawn(async move {
+            dispatcher.dispatch(alert).await;
+        });
+    }
+
+    /// Start the cooldown of `kind`, or return `None` if it is still running.
+    /// The claim also keeps concurrent alerts of the same kind from sending
+    /// while this one is in flight. Returns the previous send time, so a
+    /// failed send can restore it.
+    fn claim(&self, kind: AlertKind) -> Option<Option<Instant>> {
+        let mut last_sent = self.last_sent.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if last_sent
+            .get(&kind)
+            .is_some_and(|sent| now.duration_since(*sent) < self.cooldown)
+        {
+            return None;
+        }
+        Some(last_sent.insert(kind, now))
+    }
+
+    /// Undo a claim whose alert reached no sink
+    fn release(&self, kind: AlertKind, previous: Option<Instant>) {
+        let mut last_sent = self.last_sent.lock().unwrap_or_else(|e| e.into_inner());
+        match previous {
+            Some(sent) => last_sent.insert(kind, sent),
+            None => last_sent.remove(&kind),
+        };
+    }
+}
+
+/// Raises a [`AlertKind::RejectionSpike`] alert when compliance rejections
+/// within a sliding window reach a threshold
+pub struct RejectionRateDetector {
+    dispatcher: Arc<AlertDispatcher>,
+    threshold: usize,
+    window: Duration,
+    rejections: Mutex<VecDeque<Instant>>,
+}
+
+impl RejectionRateDetector {
+    #[must_use]
+    pub fn new(dispatcher: Arc<AlertDispatcher>, threshold: usize, window: Duration) -> Self {
+        Self {
+            dispatcher,
+            threshold: threshold.max(1),
+            window,
+            rejections: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Count one rejection and alert in the background once the threshold
+    /// is reached
+    pub fn record_rejection(&self) {
+        if let Some(count) = self.record(Instant::now()) {
+            self.dispatcher.dispatch_detached(Alert::new(
+                AlertKind::RejectionSpike,
+                format!(
+                    "{} compliance rejections in the last {}s",
+                    count,
+                    self.window.as_secs()
+                ),
+            ));
+        }
+    }
+
+    /// Add a rejection at `now` and drop those outside the window. When the
+    /// threshold is reached the window starts over and the count is returned.
+    fn record(&self, now: Instant) -> Option<usize> {
+        let mut rejections = self.rejections.lock().unwrap_or_else(|e| e.into_inner());
+        rejections.push_back(now);
+        while rejections
+            .front()
+            .is_some_and(|at| now.duration_since(*at) > self.window)
+        {
+            rejections.pop_front();
+        }
+        if rejections.len() < self.threshold {
+            return None;
+        }
+        let count = rejections.len();
+        rejections.clear();
+        Some(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    /// Sink keeping every alert it receives
+    #[derive(Default)]
+    struct RecordingSink(Mutex<Vec<Alert>>);
+
+    #[async_trait]
+    impl AlertSink for RecordingSink {
+        async fn send_alert(&self, alert: &Alert) -> Result<(), AppError> {
+            self.0.lock().unwrap().push(alert.clone());
+            Ok(())
+        }
+    }
+
+    fn low_balance() -> Alert {
+        Alert::new(
+            AlertKind::FeePayerLowBalance,
+            "Balance 1000 lamports, below 100000000",
+        )
+    }
+
+    #[tokio::test]
+    async fn test_slack_sink_posts_formatted_alert() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/slack"))
+            .and(body_json(json!({
+                "text": ":rotating_light: *Fee payer balance low*\nBalance 1000 lamports, below 100000000",
+            })))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let sink = SlackWebhookSink::new(format!("{}/slack", server.uri()));
+        sink.send_alert(&low_balance()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_discord_sink_posts_formatted_alert() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/discord"))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let sink = DiscordWebhookSink::new(format!("{}/discord", server.uri()));
+        sink.send_alert(&low_balance()).await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        let body: Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(
+            body["content"],
+            ":rotating_light: **Fee payer balance low**\nBalance 1000 lamports, below 100000000"
+        );
+        assert_eq!(body["allowed_mentions"]["parse"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_sink_reports_rejected_post() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(404).set_body_string("invalid_token"))
+            .mount(&server)
+            .await;
+
+        let sink = SlackWebhookSink::new(server.uri());
+        let result = sink.send_alert(&low_balance()).await;
+        assert!(matches!(
+            result,
+            Err(AppError::ExternalService(ExternalServiceError::ApiError {
+                status_code: 404,
+                ..
+            }))
+        ));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_dispatcher_suppresses_duplicates_within_cooldown() {
+        let sink = Arc::new(RecordingSink::default());
+        let dispatcher =
+            AlertDispatcher::new(vec![Arc::clone(&sink) as _], Duration::from_secs(600));
+
+        assert!(dispatcher.dispatch(low_balance()).await);
+        assert!(!dispatcher.dispatch(low_balance()).await);
+
+        // Other kinds have their own cooldown
+        let circuit = Alert::new(AlertKind::CircuitOpen, "5 consecutive failures");
+        assert!(dispatcher.dispatch(circuit).await);
+
+        tokio::time::advance(Duration::from_secs(601)).await;
+        assert!(dispatcher.dispatch(low_balance()).await);
+
+        let kinds: Vec<_> = sink.0.lock().unwrap().iter().map(|a| a.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                AlertKind::FeePayerLowBalance,
+                AlertKind::CircuitOpen,
+                AlertKind::FeePayerLowBalance
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatcher_failed_send_does_not_start_cooldown() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let sink = SlackWebhookSink::new(server.uri());
+        let dispatcher = AlertDispatcher::new(vec![Arc::new(sink) as _], DEFAULT_ALERT_COOLDOWN);
+
+        assert!(!dispatcher.dispatch(low_balance()).await);
+        // The failed alert left no cooldown behind
+        assert!(dispatcher.dispatch(low_balance()).await);
+        assert!(!dispatcher.dispatch(low_balance()).await);
+        assert_eq!(server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_rejection_detector_counts_within_window() {
+        let dispatcher = Arc::new(AlertDispatcher::new(Vec::new(), DEFAULT_ALERT_COOLDOWN));
+        let detector = RejectionRateDetector::new(dispatcher, 3, Duration::from_secs(60));
+        let start = Instant::now();
+
+        assert_eq!(detector.record(start), None);
+        assert_eq!(detector.record(start + Duration::from_secs(10)), None);
+        // The first rejection has left the window
+        assert_eq!(detector.record(start + Duration::from_secs(65)), None);
+        assert_eq!(detector.record(start + Duration::from_secs(66)), Some(3));
+        // Counting starts over after an alert
+        assert_eq!(detector.record(start + Duration::from_secs(67)), None);
+    }
+}
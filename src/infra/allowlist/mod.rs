@@ -0,0 +1,174 @@
+//! Internal allowlist manager for known-good addresses.
+//!
+//! Treasury and exchange hot wallets listed here bypass the blocklist and
+//! external compliance checks when both sides of a transfer are allowlisted.
+//! The allowlist is persisted to the database for durability across restarts.
+
+use dashmap::DashMap;
+use sqlx::PgPool;
+use tracing::{info, warn};
+
+use crate::domain::{AppError, DatabaseError};
+
+/// Allowlist entry with address and reason
+#[derive(Debug, Clone)]
+pub struct AllowlistEntry {
+    pub address: String,
+    pub reason: String,
+}
+
+/// Thread-safe internal allowlist manager using DashMap for high-concurrency access.
+///
+/// Maps wallet addresses (String) to the reason they are trusted (String).
+/// All changes are persisted to the database for durability.
+#[derive(Debug)]
+pub struct AllowlistManager {
+    /// In-memory cache for O(1) lookups
+    store: DashMap<String, String>,
+    /// Database pool for persistence
+    pool: PgPool,
+}
+
+impl AllowlistManager {
+    /// Create a new AllowlistManager and load existing entries from database.
+    pub async fn new(pool: PgPool) -> Result<Self, AppError> {
+        let manager = Self {
+            store: DashMap::new(),
+            pool,
+        };
+
+        manager.load_from_database().await?;
+
+        info!(
+            count = manager.store.len(),
+            "AllowlistManager initialized from database"
+        );
+
+        Ok(manager)
+    }
+
+    /// Load all allowlist entries from the database into memory.
+    async fn load_from_database(&self) -> Result<(), AppError> {
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT address, reason FROM allowlist ORDER BY created_at",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        for (address, reason) in rows {
+            self.store.insert(address, reason);
+        }
+
+        Ok(())
+    }
+
+    /// Check if an address is allowlisted.
+    #[must_use]
+    pub fn is_allowed(&self, address: &str) -> bool {
+        self.store.contains_key(address)
+    }
+
+    /// Get the reason an address is allowlisted, if it is.
+    #[must_use]
+    pub fn check_address(&self, address: &str) -> Option<String> {
+        self.store.get(address).map(|entry| entry.value().clone())
+    }
+
+    /// Add or update an address in the allowlist.
+    /// The change is persisted to the database.
+    pub async fn add_address(&self, address: String, reason: String) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            INSERT INTO allowlist (address, reason, created_at, updated_at)
+            VALUES ($1, $2, NOW(), NOW())
+            ON CONFLICT (address) DO UPDATE SET
+                reason = EXCLUDED.reason,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(&address)
+        .bind(&reason)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        warn!(
+            address = %address,
+            reason = %reason,
+            "Address added to allowlist"
+        );
+        self.store.insert(address, reason);
+
+        Ok(())
+    }
+
+    /// Remove an address from the allowlist.
+    /// Returns `true` if the address was present and removed.
+    pub async fn remove_address(&self, address: &str) -> Result<bool, AppError> {
+        let result = sqlx::query("DELETE FROM allowlist WHERE address = $1")
+            .bind(address)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        let removed = self.store.remove(address).is_some() || result.rows_affected() > 0;
+        if removed {
+            warn!(address = %address, "Address removed from allowlist");
+        }
+        Ok(removed)
+    }
+
+    /// Get the current number of allowlisted addresses.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    /// Check if the allowlist is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    /// List all allowlisted addresses (for admin purposes).
+    #[must_use]
+    pub fn list_all(&self) -> Vec<AllowlistEntry> {
+        self.store
+            .iter()
+            .map(|entry| AllowlistEntry {
+                address: entry.key().clone(),
+                reason: entry.value().clone(),
+            })
+            .collect()
+    }
+
+    /// Build an in-memory allowlist backed by a pool that is never connected.
+    /// Must be called from within a Tokio runtime.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn in_memory(entries: &[&str]) -> Self {
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .expect("lazy pool");
+        let store = entries
+            .iter()
+            .map(|address| (address.to_string(), "test".to_string()))
+            .collect();
+        Self { store, pool }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_in_memory_lookups() {
+        let allowlist = AllowlistManager::in_memory(&["treasury", "exchange"]);
+        assert_eq!(allowlist.len(), 2);
+        assert!(allowlist.is_allowed("treasury"));
+        assert!(!allowlist.is_allowed("stranger"));
+        assert_eq!(allowlist.check_address("exchange").as_deref(), Some("test"));
+        assert_eq!(allowlist.list_all().len(), 2);
+    }
+}
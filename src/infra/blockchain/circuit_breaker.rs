@@ -0,0 +1,478 @@
+//! Circuit breaker around a blockchain RPC client.
+//!
+//! [`CircuitBreakerClient`] counts consecutive network-level failures of the
+//! wrapped client. Once `failure_threshold` is reached the circuit opens and
+//! every call fails fast with a `Connection` error for `cooldown`, without
+//! touching the RPC. After the cooldown a single probe call is let through
+//! (half-open): success closes the circuit, failure opens it again. With an
+//! [`AlertDispatcher`] attached, the circuit opening also alerts operators.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use tracing::{info, warn};
+
+use crate::domain::{
+    Alert, AlertKind, AppError, BlockchainClient, BlockchainError, BlockchainNodeHealth,
+    BundleStatus, FeeEstimate, LastErrorType, ProposalStatus, SubmissionOutcome, SubmitOptions,
+    TransactionStatus, TransferRequest,
+};
+use crate::infra::alerting::AlertDispatcher;
+
+/// Default number of consecutive failures that opens the circuit
+pub const DEFAULT_CIRCUIT_FAILURE_THRESHOLD: u32 = 5;
+
+/// Default time the circuit stays open before a probe is allowed
+pub const DEFAULT_CIRCUIT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Message of the error returned while the circuit is open
+const CIRCUIT_OPEN_MESSAGE: &str = "Blockchain RPC circuit open - call skipped";
+
+/// Whether `error` was returned by an open circuit rather than the RPC
+pub fn is_circuit_open(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::Blockchain(BlockchainError::Connection(msg)) if msg == CIRCUIT_OPEN_MESSAGE
+    )
+}
+
+/// Configuration for [`CircuitBreakerClient`]
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the circuit
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a probe is allowed
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: DEFAULT_CIRCUIT_FAILURE_THRESHOLD,
+            cooldown: DEFAULT_CIRCUIT_COOLDOWN,
+        }
+    }
+}
+
+/// Externally visible state of the circuit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls pass through
+    Closed,
+    /// Calls fail fast until the cooldown ends
+    Open,
+    /// A probe call is in flight; other calls fail fast
+    HalfOpen,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { probe_started: Instant },
+}
+
+/// [`BlockchainClient`] wrapper that stops calling an RPC that keeps failing
+pub struct CircuitBreakerClient {
+    inner: Arc<dyn BlockchainClient>,
+    config: CircuitBreakerConfig,
+    state: Mutex<State>,
+    alerts: Option<Arc<AlertDispatcher>>,
+}
+
+impl CircuitBreakerClient {
+    #[must_use]
+    pub fn new(inner: Arc<dyn BlockchainClient>, config: CircuitBreakerConfig) -> Self {
+        Self {
+            inner,
+            config,
+            state: Mutex::new(State::Closed { failures: 0 }),
+            alerts: None,
+        }
+    }
+
+    /// Alert operators when the circuit opens (builder pattern)
+    #[must_use]
+    pub fn with_alerts(mut self, alerts: Arc<AlertDispatcher>) -> Self {
+        self.alerts = Some(alerts);
+        self
+    }
+
+    /// Current state of the circuit
+    pub fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap() {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if Instant::now() >= until => CircuitState::HalfOpen,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Admit a call, or fail fast while the circuit is open.
+    /// The first call after the cooldown becomes the half-open probe.
+    fn acquire(&self) -> Result<(), AppError> {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        match *state {
+            State::Closed { .. } => Ok(()),
+            State::Open { until } if now >= until => {
+                info!("Blockchain RPC circuit half-open - probing");
+                *state = State::HalfOpen { probe_started: now };
+                Ok(())
+            }
+            // A probe that never reported back (e.g. its caller was dropped)
+            // is replaced once another cooldown has passed
+            State::HalfOpen { probe_started }
+                if now.duration_since(probe_started) >= self.config.cooldown =>
+            {
+                *state = State::HalfOpen { probe_started: now };
+                Ok(())
+            }
+            State::Open { .. } | State::HalfOpen { .. } => Err(AppError::Blockchain(
+                BlockchainError::Connection(CIRCUIT_OPEN_MESSAGE.to_string()),
+            )),
+        }
+    }
+
+    /// Update the circuit with the outcome of an admitted call.
+    /// Only network-level errors count as failures: any other result
+    /// shows the RPC is reachable.
+    fn record<T>(&self, result: &Result<T, AppError>) {
+        let failed = matches!(
+            result,
+            Err(e) if self.inner.classify_error(e) == LastErrorType::NetworkError
+        );
+        let mut state = self.state.lock().unwrap();
+        match (*state, failed) {
+            (State::Closed { failures: 0 }, false) => {}
+            (_, false) => {
+                if !matches!(*state, State::Closed { .. }) {
+                    info!("Blockchain RPC recovered - circuit closed");
+                }
+                *state = State::Closed { failures: 0 };
+            }
+            (State::Closed { failures }, true) => {
+                let failures = failures + 1;
+                if failures >= self.config.failure_threshold {
+                    warn!(
+                        failures,
+                        cooldown = ?self.config.cooldown,
+                        "Blockchain RPC failing - circuit open"
+                    );
+                    if let Some(alerts) = &self.alerts {
+                        alerts.dispatch_detached(Alert::new(
+                            AlertKind::CircuitOpen,
+                            format!(
+                                "{} consecutive RPC failures; calls fail fast for {}s",
+                                failures,
+                                self.config.cooldown.as_secs()
+                            ),
+                        ));
+                    }
+                    *state = State::Open {
+                        until: Instant::now() + self.config.cooldown,
+                    };
+                } else {
+                    *state = State::Closed { failures };
+                }
+            }
+            (State::HalfOpen { .. }, true) => {
+                warn!(
+                    cooldown = ?self.config.cooldown,
+                    "Blockchain RPC probe failed - circuit open"
+                );
+                *state = State::Open {
+                    until: Instant::now() + self.config.cooldown,
+                };
+            }
+            // Late failure of a call admitted before the circuit opened
+            (State::Open { .. }, true) => {}
+        }
+    }
+
+    /// Run `call` against the inner client through the breaker
+    async fn call<T, F>(&self, call: F) -> Result<T, AppError>
+    where
+        F: Future<Output = Result<T, AppError>>,
+    {
+        self.acquire()?;
+        let result = call.await;
+        self.record(&result);
+        result
+    }
+}
+
+#[async_trait]
+impl BlockchainClient for CircuitBreakerClient {
+    async fn health_check(&self) -> Result<(), AppError> {
+        self.call(self.inner.health_check()).await
+    }
+
+    async fn detailed_health(&self) -> Result<BlockchainNodeHealth, AppError> {
+        self.call(self.inner.detailed_health()).await
+    }
+
+    async fn submit_transaction(
+        &self,
+        request: &TransferRequest,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        self.call(self.inner.submit_transaction(request)).await
+    }
+
+    async fn get_transaction_status(&self, signature: &str) -> Result<bool, AppError> {
+        self.call(self.inner.get_transaction_status(signature))
+            .await
+    }
+
+    async fn get_block_height(&self) -> Result<u64, AppError> {
+        self.call(self.inner.get_block_height()).await
+    }
+
+    async fn get_latest_blockhash(&self) -> Result<String, AppError> {
+        self.call(self.inner.get_latest_blockhash()).await
+    }
+
+    async fn wait_for_confirmation(
+        &self,
+        signature: &str,
+        timeout_secs: u64,
+    ) -> Result<bool, AppError> {
+        self.call(self.inner.wait_for_confirmation(signature, timeout_secs))
+            .await
+    }
+
+    async fn transfer_sol(
+        &self,
+        to_address: &str,
+        amount_lamports: u64,
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        self.call(
+            self.inner
+                .transfer_sol(to_address, amount_lamports, memo, options),
+        )
+        .await
+    }
+
+    async fn transfer_token(
+        &self,
+        to_address: &str,
+        token_mint: &str,
+        amount: u64,
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        self.call(
+            self.inner
+                .transfer_token(to_address, token_mint, amount, memo, options),
+        )
+        .await
+    }
+
+    async fn transfer_token_batch(
+        &self,
+        token_mint: &str,
+        recipients: &[(String, u64)],
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        self.call(
+            self.inner
+                .transfer_token_batch(token_mint, recipients, memo, options),
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn transfer_confidential(
+        &self,
+        to_address: &str,
+        token_mint: &str,
+        new_decryptable_available_balance: &str,
+        equality_proof: &str,
+        ciphertext_validity_proof: &str,
+        range_proof: &str,
+        auditor_elgamal_pubkey: Option<&str>,
+        fee_sigma_proof: Option<&str>,
+        fee_ciphertext_validity_proof: Option<&str>,
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        self.call(self.inner.transfer_confidential(
+            to_address,
+            token_mint,
+            new_decryptable_available_balance,
+            equality_proof,
+            ciphertext_validity_proof,
+            range_proof,
+            auditor_elgamal_pubkey,
+            fee_sigma_proof,
+            fee_ciphertext_validity_proof,
+            memo,
+            options,
+        ))
+        .await
+    }
+
+    async fn check_wallet_assets(&self, owner: &str) -> Result<bool, AppError> {
+        self.call(self.inner.check_wallet_assets(owner)).await
+    }
+
+    async fn get_signature_status(
+        &self,
+        signature: &str,
+    ) -> Result<Option<TransactionStatus>, AppError> {
+        self.call(self.inner.get_signature_status(signature)).await
+    }
+
+    async fn is_blockhash_valid(&self, blockhash: &str) -> Result<bool, AppError> {
+        self.call(self.inner.is_blockhash_valid(blockhash)).await
+    }
+
+    async fn get_bundle_status(&self, signature: &str) -> Result<Option<BundleStatus>, AppError> {
+        self.call(self.inner.get_bundle_status(signature)).await
+    }
+
+    async fn get_proposal_status(
+        &self,
+        proposal: &str,
+    ) -> Result<Option<ProposalStatus>, AppError> {
+        self.call(self.inner.get_proposal_status(proposal)).await
+    }
+
+    fn signer_pubkeys(&self) -> Vec<String> {
+        self.inner.signer_pubkeys()
+    }
+
+    fn select_signer(&self, transfer_id: &str) -> Option<String> {
+        self.inner.select_signer(transfer_id)
+    }
+
+    async fn get_fee_payer_balance(&self) -> Result<u64, AppError> {
+        self.call(self.inner.get_fee_payer_balance()).await
+    }
+
+    async fn estimate_fee(
+        &self,
+        to_address: &str,
+        amount: u64,
+        token_mint: Option<&str>,
+        memo: Option<&str>,
+    ) -> Result<FeeEstimate, AppError> {
+        self.call(
+            self.inner
+                .estimate_fee(to_address, amount, token_mint, memo),
+        )
+        .await
+    }
+
+    fn closes_empty_token_accounts(&self) -> bool {
+        self.inner.closes_empty_token_accounts()
+    }
+
+    async fn close_empty_token_account(
+        &self,
+        token_mint: &str,
+        signer: Option<&str>,
+    ) -> Result<Option<String>, AppError> {
+        self.call(self.inner.close_empty_token_account(token_mint, signer))
+            .await
+    }
+
+    fn uses_durable_nonce(&self) -> bool {
+        self.inner.uses_durable_nonce()
+    }
+
+    fn classify_error(&self, error: &AppError) -> LastErrorType {
+        self.inner.classify_error(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::MockBlockchainClient;
+
+    fn breaker(
+        threshold: u32,
+        cooldown: Duration,
+    ) -> (Arc<MockBlockchainClient>, CircuitBreakerClient) {
+        let mock = Arc::new(MockBlockchainClient::new());
+        let config = CircuitBreakerConfig {
+            failure_threshold: threshold,
+            cooldown,
+        };
+        let client = CircuitBreakerClient::new(Arc::clone(&mock) as _, config);
+        (mock, client)
+    }
+
+    #[tokio::test]
+    async fn test_opens_after_consecutive_failures() {
+        let (mock, client) = breaker(3, Duration::from_secs(60));
+        mock.set_unreachable(true);
+
+        for _ in 0..3 {
+            let result = client.get_latest_blockhash().await;
+            assert!(result.is_err_and(|e| !is_circuit_open(&e)));
+        }
+        assert_eq!(client.state(), CircuitState::Open);
+        assert_eq!(mock.rpc_calls(), 3);
+
+        // Open circuit fails fast without reaching the RPC
+        let result = client.get_latest_blockhash().await;
+        assert!(result.is_err_and(|e| is_circuit_open(&e)));
+        assert_eq!(mock.rpc_calls(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_success_resets_failure_count() {
+        let (mock, client) = breaker(2, Duration::from_secs(60));
+        mock.set_unreachable(true);
+        assert!(client.get_latest_blockhash().await.is_err());
+        mock.set_unreachable(false);
+        assert!(client.get_latest_blockhash().await.is_ok());
+        mock.set_unreachable(true);
+        assert!(client.get_latest_blockhash().await.is_err());
+        assert_eq!(client.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_non_network_errors_do_not_open() {
+        let mock = Arc::new(MockBlockchainClient::failing("Simulation failed"));
+        let config = CircuitBreakerConfig {
+            failure_threshold: 1,
+            cooldown: Duration::from_secs(60),
+        };
+        let client = CircuitBreakerClient::new(Arc::clone(&mock) as _, config);
+        assert!(client.get_latest_blockhash().await.is_err());
+        assert_eq!(client.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_half_open_probe_recovers() {
+        let (mock, client) = breaker(1, Duration::from_millis(20));
+        mock.set_unreachable(true);
+        assert!(client.get_latest_blockhash().await.is_err());
+        assert_eq!(client.state(), CircuitState::Open);
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(client.state(), CircuitState::HalfOpen);
+
+        // A failed probe reopens the circuit for another cooldown
+        assert!(client.get_latest_blockhash().await.is_err());
+        assert_eq!(mock.rpc_calls(), 2);
+        assert_eq!(client.state(), CircuitState::Open);
+        let result = client.get_latest_blockhash().await;
+        assert!(result.is_err_and(|e| is_circuit_open(&e)));
+
+        // A successful probe closes it
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        mock.set_unreachable(false);
+        assert!(client.get_latest_blockhash().await.is_ok());
+        assert_eq!(client.state(), CircuitState::Closed);
+        assert!(client.get_latest_blockhash().await.is_ok());
+        assert_eq!(mock.rpc_calls(), 4);
+    }
+}
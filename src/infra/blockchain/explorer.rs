@@ -0,0 +1,203 @@
+//! Block explorer links for submitted transactions.
+//!
+//! The cluster is inferred from the RPC URL, so links point at the network
+//! the relayer actually submits to. Solana Explorer and Solscan both select
+//! the cluster with a `?cluster=` query, which is omitted on mainnet.
+
+/// Default explorer used for transaction links
+pub const DEFAULT_EXPLORER_BASE_URL: &str = "https://explorer.solana.com";
+
+/// Solana cluster a transaction was submitted to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolanaCluster {
+    /// Mainnet beta, the explorer's default cluster
+    MainnetBeta,
+    Devnet,
+    Testnet,
+    /// A local validator, linked through the explorer's custom cluster
+    Custom(String),
+}
+
+impl SolanaCluster {
+    /// Infer the cluster from an RPC URL.
+    ///
+    /// URLs naming devnet or testnet (e.g. `api.devnet.solana.com`,
+    /// `devnet.helius-rpc.com`) map to those clusters and local validators
+    /// to a custom cluster. Anything else is assumed to be mainnet: hosted
+    /// mainnet endpoints often don't name the cluster. Remote URLs are never
+    /// put in links, since they may embed an API key.
+    pub fn detect(rpc_url: &str) -> Self {
+        let url_lower = rpc_url.to_lowercase();
+
+        if url_lower.contains("devnet") {
+            SolanaCluster::Devnet
+        } else if url_lower.contains("testnet") {
+            SolanaCluster::Testnet
+        } else if ["localhost", "127.0.0.1", "0.0.0.0"]
+            .iter()
+            .any(|host| url_lower.contains(host))
+        {
+            SolanaCluster::Custom(rpc_url.to_string())
+        } else {
+            SolanaCluster::MainnetBeta
+        }
+    }
+
+    /// Query string selecting this cluster in the explorer, empty on mainnet
+    fn query(&self) -> String {
+        match self {
+            SolanaCluster::MainnetBeta => String::new(),
+            SolanaCluster::Devnet => "?cluster=devnet".to_string(),
+            SolanaCluster::Testnet => "?cluster=testnet".to_string(),
+            // `:` and `/` are valid in a query, so a local URL needs no escaping
+            SolanaCluster::Custom(rpc_url) => format!("?cluster=custom&customUrl={}", rpc_url),
+        }
+    }
+}
+
+/// Builds explorer URLs for transaction signatures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExplorerLinks {
+    base_url: String,
+    cluster: SolanaCluster,
+}
+
+impl ExplorerLinks {
+    #[must_use]
+    pub fn new(base_url: impl Into<String>, cluster: SolanaCluster) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            cluster,
+        }
+    }
+
+    /// Links on `base_url` for the cluster `rpc_url` belongs to
+    #[must_use]
+    pub fn for_rpc_url(base_url: impl Into<String>, rpc_url: &str) -> Self {
+        Self::new(base_url, SolanaCluster::detect(rpc_url))
+    }
+
+    /// Cluster the links point at
+    pub fn cluster(&self) -> &SolanaCluster {
+        &self.cluster
+    }
+
+    /// Explorer URL of the transaction with `signature`
+    #[must_use]
+    pub fn transaction_url(&self, signature: &str) -> String {
+        format!("{}/tx/{}{}", self.base_url, signature, self.cluster.query())
+    }
+
+    /// Explorer URL of the account at `address`
+    #[must_use]
+    pub fn address_url(&self, address: &str) -> String {
+        format!(
+            "{}/address/{}{}",
+            self.base_url,
+            address,
+            self.cluster.query()
+        )
+    }
+
+    /// Explorer URL of a transfer's `blockchain_signature`. Multisig
+    /// transfers store their proposal address there, which is linked as an
+    /// account rather than a transaction.
+    #[must_use]
+    pub fn blockchain_signature_url(&self, blockchain_signature: &str) -> String {
+        let is_address = bs58::decode(blockchain_signature)
+            .into_vec()
+            .is_ok_and(|bytes| bytes.len() == 32);
+        if is_address {
+            self.address_url(blockchain_signature)
+        } else {
+            self.transaction_url(blockchain_signature)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNATURE: &str =
+        "5wHu1qwD7q5ifaN5nwdcDqNFo53GJqa7nLp2BeeEpcHCusb4GzARz4GjgzsEHMkBMgCJMGa6GSQM3TYWMwnS1vKe";
+
+    #[test]
+    fn test_detect_cluster_from_rpc_url() {
+        for (url, cluster) in [
+            (
+                "https://api.mainnet-beta.solana.com",
+                SolanaCluster::MainnetBeta,
+            ),
+            (
+                "https://mainnet.helius-rpc.com/?api-key=x",
+                SolanaCluster::MainnetBeta,
+            ),
+            ("https://rpc.example.com", SolanaCluster::MainnetBeta),
+            ("https://api.devnet.solana.com", SolanaCluster::Devnet),
+            (
+                "https://xxx.solana-devnet.quiknode.pro/yyy",
+                SolanaCluster::Devnet,
+            ),
+            ("https://api.testnet.solana.com", SolanaCluster::Testnet),
+        ] {
+            assert_eq!(SolanaCluster::detect(url), cluster, "{}", url);
+        }
+        assert_eq!(
+            SolanaCluster::detect("http://127.0.0.1:8899"),
+            SolanaCluster::Custom("http://127.0.0.1:8899".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mainnet_url_has_no_cluster_query() {
+        let links = ExplorerLinks::for_rpc_url(
+            DEFAULT_EXPLORER_BASE_URL,
+            "https://api.mainnet-beta.solana.com",
+        );
+        assert_eq!(
+            links.transaction_url(SIGNATURE),
+            format!("https://explorer.solana.com/tx/{}", SIGNATURE)
+        );
+    }
+
+    #[test]
+    fn test_devnet_url_selects_cluster() {
+        let links =
+            ExplorerLinks::for_rpc_url("https://solscan.io/", "https://api.devnet.solana.com");
+        assert_eq!(
+            links.transaction_url(SIGNATURE),
+            format!("https://solscan.io/tx/{}?cluster=devnet", SIGNATURE)
+        );
+    }
+
+    #[test]
+    fn test_multisig_proposal_linked_as_address() {
+        let links =
+            ExplorerLinks::for_rpc_url(DEFAULT_EXPLORER_BASE_URL, "https://api.devnet.solana.com");
+        let proposal = "DRpbCBMxVnDK7maPM5tGv6MvB3v1sRMC86PZ8okm21hy";
+        assert_eq!(
+            links.blockchain_signature_url(proposal),
+            format!(
+                "https://explorer.solana.com/address/{}?cluster=devnet",
+                proposal
+            )
+        );
+        assert_eq!(
+            links.blockchain_signature_url(SIGNATURE),
+            links.transaction_url(SIGNATURE)
+        );
+    }
+
+    #[test]
+    fn test_local_validator_uses_custom_cluster() {
+        let links = ExplorerLinks::for_rpc_url(DEFAULT_EXPLORER_BASE_URL, "http://localhost:8899");
+        assert_eq!(
+            links.transaction_url(SIGNATURE),
+            format!(
+                "https://explorer.solana.com/tx/{}?cluster=custom&customUrl=http://localhost:8899",
+                SIGNATURE
+            )
+        );
+    }
+}
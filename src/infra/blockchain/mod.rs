@@ -3,13 +3,44 @@
 //! This module provides blockchain interaction abstractions with provider-specific
 //! strategy implementations for Helius, QuickNode, and standard Solana RPC.
 
+pub mod circuit_breaker;
+pub mod explorer;
 pub mod helius;
 pub mod quicknode;
+pub mod signers;
 pub mod solana;
+pub mod squads;
 pub mod strategies;
+pub mod tip_accounts;
 
 // Re-export main types
-pub use solana::{RpcBlockchainClient, RpcClientConfig, signing_key_from_base58};
+pub use solana::{
+    DEFAULT_COMPUTE_UNIT_LIMIT, DEFAULT_CONFIRMATION_POLL_INTERVAL,
+    DEFAULT_CONFIRMATION_POLL_MAX_INTERVAL, DEFAULT_MAX_PRIORITY_FEE_MICROLAMPORTS,
+    DEFAULT_MINT_CACHE_TTL, DEFAULT_RENT_CACHE_TTL, DEFAULT_RESUBMIT_DEADLINE, RpcBlockchainClient,
+    RpcClientConfig, signing_key_from_base58,
+};
+
+// Re-export circuit breaker types
+pub use circuit_breaker::{
+    CircuitBreakerClient, CircuitBreakerConfig, CircuitState, DEFAULT_CIRCUIT_COOLDOWN,
+    DEFAULT_CIRCUIT_FAILURE_THRESHOLD, is_circuit_open,
+};
+
+// Re-export explorer link types
+pub use explorer::{DEFAULT_EXPLORER_BASE_URL, ExplorerLinks, SolanaCluster};
+
+// Re-export Squads multisig types
+pub use squads::{SQUADS_V4_PROGRAM_ID, SquadsConfig};
+
+// Re-export signing key selection types
+pub use signers::KeySelectionPolicy;
+
+// Re-export tip account refresh types
+pub use tip_accounts::{
+    DEFAULT_TIP_ACCOUNT_REFRESH_INTERVAL, TipAccountSet, refresh_tip_accounts,
+    spawn_tip_account_refresh,
+};
 
 // Re-export strategy types
 pub use strategies::{FeeStrategy, RpcProviderType, SubmissionStrategy};
@@ -19,8 +50,10 @@ pub use helius::{HeliusDasClient, HeliusFeeStrategy, SANCTIONED_COLLECTIONS};
 
 // Re-export QuickNode-specific types
 pub use quicknode::{
-    QuickNodePrivateSubmissionStrategy, QuickNodeSubmissionConfig, QuickNodeTokenApiClient,
-    StandardSubmissionStrategy, TokenActivityInfo,
+    DEFAULT_JITO_TIP_CEILING_LAMPORTS, DEFAULT_JITO_TIP_FLOOR_URL, DEFAULT_JITO_TIP_PERCENTILE,
+    DynamicTipConfig, DynamicTipStrategy, MAX_JITO_BUNDLE_SIZE, QuickNodePrivateSubmissionStrategy,
+    QuickNodeSubmissionConfig, QuickNodeTokenApiClient, StandardSubmissionStrategy, TipFloor,
+    TokenActivityInfo,
 };
 
 // ============================================================================
@@ -54,3 +87,10 @@ pub fn random_jito_tip_account() -> &'static str {
     let idx = rand::random_range(0..JITO_TIP_ACCOUNTS.len());
     JITO_TIP_ACCOUNTS[idx]
 }
+
+/// Select a random Jito tip account from the live set refreshed via
+/// `getTipAccounts`, falling back to [`JITO_TIP_ACCOUNTS`] until the first
+/// successful refresh.
+pub fn random_jito_tip_account_dynamic() -> solana_sdk::pubkey::Pubkey {
+    tip_accounts::LIVE_TIP_ACCOUNTS.random()
+}
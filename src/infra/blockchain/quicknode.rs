@@ -13,13 +13,19 @@
 //! QuickNode features are auto-activated when the RPC URL contains `quiknode.pro`
 //! or `quicknode.com`.
 
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use tracing::{debug, info, warn};
 
-use crate::domain::{AppError, BlockchainError};
+use crate::domain::{AppError, BlockchainError, BundleStatus, SubmissionOutcome};
+use crate::infra::metrics;
 
+use super::solana::rpc_account_already_in_use;
 use super::strategies::SubmissionStrategy;
 
 // ============================================================================
@@ -40,8 +46,14 @@ pub struct QuickNodeSubmissionConfig {
     /// Maximum retries for bundle submission
     pub max_bundle_retries: u32,
     /// Optional Jito region for lower latency (e.g., "ny", "amsterdam", "frankfurt", "tokyo")
-    /// If None, Jito will auto-select the optimal region.
+    /// If None, Jito will auto-select the optimal region. Transfers may
+    /// override it with their own `jito_region`.
     pub region: Option<String>,
+    /// Endpoint `sendBundle` is posted to instead of `rpc_url`, such as a
+    /// self-hosted Jito relay
+    pub block_engine_url: Option<String>,
+    /// Remember bundle IDs by transaction signature so bundle status can be polled
+    pub track_bundle_status: bool,
 }
 
 impl Default for QuickNodeSubmissionConfig {
@@ -52,6 +64,8 @@ impl Default for QuickNodeSubmissionConfig {
             tip_lamports: 10_000, // 0.00001 SOL (recommended minimum)
             max_bundle_retries: 2,
             region: None, // Let Jito choose optimal region
+            block_engine_url: None,
+            track_bundle_status: false,
         }
     }
 }
@@ -88,6 +102,79 @@ struct JitoError {
     message: String,
 }
 
+/// Response of `getInflightBundleStatuses` / `getBundleStatuses`
+#[derive(Debug, Deserialize)]
+struct BundleStatusesResponse<T> {
+    result: Option<BundleStatusesResult<T>>,
+    error: Option<JitoError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BundleStatusesResult<T> {
+    value: Vec<Option<T>>,
+}
+
+/// Entry of `getInflightBundleStatuses` (bundles from the last five minutes)
+#[derive(Debug, Deserialize)]
+struct InflightBundleStatus {
+    status: String,
+    landed_slot: Option<u64>,
+}
+
+/// Entry of `getBundleStatuses` (landed bundles only)
+#[derive(Debug, Deserialize)]
+struct LandedBundleStatus {
+    slot: Option<u64>,
+    #[serde(default)]
+    err: serde_json::Value,
+}
+
+impl From<&InflightBundleStatus> for BundleStatus {
+    fn from(entry: &InflightBundleStatus) -> Self {
+        match entry.status.as_str() {
+            "Pending" => BundleStatus::Pending,
+            "Landed" => BundleStatus::Landed {
+                slot: entry.landed_slot,
+            },
+            "Failed" => BundleStatus::Failed,
+            _ => BundleStatus::Invalid,
+        }
+    }
+}
+
+impl From<&LandedBundleStatus> for BundleStatus {
+    fn from(entry: &LandedBundleStatus) -> Self {
+        // Successful bundles report `"err": {"Ok": null}`
+        if entry.err.is_null() || entry.err.get("Ok").is_some() {
+            BundleStatus::Landed { slot: entry.slot }
+        } else {
+            BundleStatus::Failed
+        }
+    }
+}
+
+/// First status entry of a bundle status response (`None` if the bundle is unknown)
+fn first_bundle_status<T>(response: BundleStatusesResponse<T>) -> Result<Option<T>, AppError> {
+    if let Some(error) = response.error {
+        return Err(AppError::Blockchain(BlockchainError::RpcError(format!(
+            "Bundle status error {}: {}",
+            error.code, error.message
+        ))));
+    }
+    Ok(response
+        .result
+        .and_then(|result| result.value.into_iter().next().flatten()))
+}
+
+/// Tracked bundle IDs are kept this long (well past blockhash expiry)
+const BUNDLE_TRACKING_TTL: Duration = Duration::from_secs(600);
+
+/// Expired tracked bundle IDs are swept once the map grows past this size
+const BUNDLE_TRACKING_SWEEP_THRESHOLD: usize = 1_000;
+
+/// Maximum number of transactions Jito accepts in a single bundle
+pub const MAX_JITO_BUNDLE_SIZE: usize = 5;
+
 /// Standard sendTransaction request
 #[derive(Debug, Serialize)]
 struct SendTransactionRequest {
@@ -103,10 +190,44 @@ struct SendTransactionResponse {
     error: Option<RpcError>,
 }
 
+#[derive(Debug, Deserialize)]
+struct SimulateTransactionResponse {
+    result: Option<SimulateTransactionResult>,
+    error: Option<RpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SimulateTransactionResult {
+    value: SimulateTransactionValue,
+}
+
+#[derive(Debug, Deserialize)]
+struct SimulateTransactionValue {
+    err: Option<serde_json::Value>,
+}
+
 #[derive(Debug, Deserialize)]
 struct RpcError {
     code: i64,
     message: String,
+    /// Failed preflights report the transaction error as `data.err`
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+}
+
+impl RpcError {
+    /// The error of a rejected `sendTransaction`, typed when the preflight
+    /// failed because an account the transaction creates already exists
+    fn into_transaction_failed(self) -> AppError {
+        let message = format!("{}: {}", self.code, self.message);
+        self.data
+            .as_ref()
+            .and_then(|data| data.get("err"))
+            .and_then(|err| rpc_account_already_in_use(err, &message))
+            .unwrap_or(AppError::Blockchain(BlockchainError::TransactionFailed(
+                message,
+            )))
+    }
 }
 
 // ============================================================================
@@ -130,6 +251,10 @@ struct RpcError {
 pub struct QuickNodePrivateSubmissionStrategy {
     config: QuickNodeSubmissionConfig,
     http_client: reqwest::Client,
+    /// Bundle ID returned by the most recent successful `sendBundle`
+    last_bundle_id: Mutex<Option<String>>,
+    /// Bundle IDs by transaction signature (when `track_bundle_status` is enabled)
+    bundle_ids: DashMap<String, (String, Instant)>,
 }
 
 impl QuickNodePrivateSubmissionStrategy {
@@ -144,7 +269,98 @@ impl QuickNodePrivateSubmissionStrategy {
         Self {
             config,
             http_client: reqwest::Client::new(),
+            last_bundle_id: Mutex::new(None),
+            bundle_ids: DashMap::new(),
+        }
+    }
+
+    /// Bundle ID returned by the most recent successful bundle submission
+    pub fn last_bundle_id(&self) -> Option<String> {
+        self.last_bundle_id
+            .lock()
+            .expect("last bundle ID lock poisoned")
+            .clone()
+    }
+
+    /// Remember the bundle that carried `tx_signature`
+    fn record_bundle(&self, tx_signature: &str, bundle_id: &str) {
+        *self
+            .last_bundle_id
+            .lock()
+            .expect("last bundle ID lock poisoned") = Some(bundle_id.to_string());
+
+        if !self.config.track_bundle_status {
+            return;
         }
+        if self.bundle_ids.len() >= BUNDLE_TRACKING_SWEEP_THRESHOLD {
+            self.bundle_ids
+                .retain(|_, (_, recorded_at)| recorded_at.elapsed() < BUNDLE_TRACKING_TTL);
+        }
+        self.bundle_ids.insert(
+            tx_signature.to_string(),
+            (bundle_id.to_string(), Instant::now()),
+        );
+    }
+
+    /// Query the status of a Jito bundle
+    ///
+    /// Checks `getInflightBundleStatuses` first, which covers the last five
+    /// minutes, then `getBundleStatuses` for bundles that landed earlier.
+    ///
+    /// # API Reference
+    /// <https://www.quicknode.com/docs/solana/getInflightBundleStatuses>
+    pub async fn get_bundle_status(&self, bundle_id: &str) -> Result<BundleStatus, AppError> {
+        let inflight: Option<InflightBundleStatus> = self
+            .bundle_status_request("getInflightBundleStatuses", bundle_id)
+            .await?;
+        let status = inflight
+            .as_ref()
+            .map_or(BundleStatus::Invalid, BundleStatus::from);
+        if status != BundleStatus::Invalid {
+            return Ok(status);
+        }
+
+        let landed: Option<LandedBundleStatus> = self
+            .bundle_status_request("getBundleStatuses", bundle_id)
+            .await?;
+        Ok(landed
+            .as_ref()
+            .map_or(BundleStatus::Invalid, BundleStatus::from))
+    }
+
+    async fn bundle_status_request<T: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        bundle_id: &str,
+    ) -> Result<Option<T>, AppError> {
+        let request = JitoBundleRequest {
+            jsonrpc: "2.0",
+            id: 1,
+            method,
+            params: vec![serde_json::json!([bundle_id])],
+        };
+
+        let response = self
+            .http_client
+            .post(&self.config.rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::Blockchain(BlockchainError::RpcError(format!(
+                    "{} failed: {}",
+                    method, e
+                )))
+            })?;
+
+        let statuses: BundleStatusesResponse<T> = response.json().await.map_err(|e| {
+            AppError::Blockchain(BlockchainError::RpcError(format!(
+                "Failed to parse {} response: {}",
+                method, e
+            )))
+        })?;
+
+        first_bundle_status(statuses)
     }
 
     /// Extract the transaction signature from a Base58-encoded serialized transaction
@@ -276,9 +492,10 @@ impl QuickNodePrivateSubmissionStrategy {
         Ok((value, 3))
     }
 
-    /// Submit transaction as a Jito bundle for private submission
+    /// Submit transactions as a Jito bundle for private submission
     ///
     /// Uses QuickNode's `sendBundle` RPC method which routes to Jito block builders.
+    /// The transactions execute in the given order.
     ///
     /// # API Reference
     /// <https://www.quicknode.com/docs/solana/sendBundle>
@@ -289,20 +506,36 @@ impl QuickNodePrivateSubmissionStrategy {
     ///
     /// When `JitoStateUnknown` is returned, the caller should NOT immediately retry with a new blockhash
     /// to avoid potential double-spend risk if the original bundle was actually processed.
-    async fn submit_jito_bundle(&self, serialized_tx: &str) -> Result<String, AppError> {
+    async fn submit_jito_bundle(
+        &self,
+        serialized_txs: &[&str],
+        jito_region: Option<&str>,
+    ) -> Result<String, AppError> {
+        let result = self.send_bundle(serialized_txs, jito_region).await;
+        metrics::record_jito_bundle_outcome(&result);
+        result
+    }
+
+    /// Single `sendBundle` request, classified as described on [`Self::submit_jito_bundle`]
+    async fn send_bundle(
+        &self,
+        serialized_txs: &[&str],
+        jito_region: Option<&str>,
+    ) -> Result<String, AppError> {
+        // The transfer's own region takes precedence over the configured default
+        let region = jito_region.or(self.config.region.as_deref());
         debug!(
-            tx_len = serialized_tx.len(),
-            region = ?self.config.region,
+            tx_count = serialized_txs.len(),
+            region = ?region,
             "Attempting Jito bundle submission via sendBundle"
         );
 
-        // Build params: [[tx_base58], region?]
+        // Build params: [[tx_base58, ...], region?]
         // First param is always the array of transactions (even for single tx)
-        let mut params: Vec<serde_json::Value> =
-            vec![serde_json::json!([serialized_tx.to_string()])];
+        let mut params: Vec<serde_json::Value> = vec![serde_json::json!(serialized_txs)];
 
-        // Add region if configured (optional second parameter)
-        if let Some(ref region) = self.config.region {
+        // Add region if set (optional second parameter)
+        if let Some(region) = region {
             params.push(serde_json::json!(region));
         }
 
@@ -314,9 +547,14 @@ impl QuickNodePrivateSubmissionStrategy {
             params,
         };
 
+        let endpoint = self
+            .config
+            .block_engine_url
+            .as_deref()
+            .unwrap_or(&self.config.rpc_url);
         let response = self
             .http_client
-            .post(&self.config.rpc_url)
+            .post(endpoint)
             .json(&request)
             .timeout(std::time::Duration::from_secs(30))
             .send()
@@ -407,6 +645,64 @@ impl QuickNodePrivateSubmissionStrategy {
         })
     }
 
+    /// Preflight a transaction about to go out in a Jito bundle.
+    ///
+    /// `sendBundle` has no preflight of its own: the block engine simulates
+    /// the bundle and silently drops it if it fails. Simulating first returns
+    /// the failure to the caller instead. Nothing has been sent when this
+    /// fails, so errors are reported as `JitoBundleFailed` (safe to retry).
+    async fn simulate_transaction(&self, serialized_tx: &str) -> Result<(), AppError> {
+        debug!("Simulating transaction before Jito bundle submission");
+
+        let request = SendTransactionRequest {
+            jsonrpc: "2.0",
+            id: 1,
+            method: "simulateTransaction".to_string(),
+            params: vec![
+                serde_json::Value::String(serialized_tx.to_string()),
+                serde_json::json!({
+                    "commitment": "confirmed",
+                    "encoding": "base58"
+                }),
+            ],
+        };
+
+        let failed = |message: String| {
+            AppError::Blockchain(BlockchainError::JitoBundleFailed(format!(
+                "Preflight simulation failed: {}",
+                message
+            )))
+        };
+
+        let response: SimulateTransactionResponse = self
+            .http_client
+            .post(&self.config.rpc_url)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| failed(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| failed(e.to_string()))?;
+
+        if let Some(error) = response.error {
+            return Err(failed(format!("{}: {}", error.code, error.message)));
+        }
+        match response.result {
+            Some(SimulateTransactionResult {
+                value: SimulateTransactionValue { err: None },
+            }) => Ok(()),
+            Some(SimulateTransactionResult {
+                value: SimulateTransactionValue { err: Some(err) },
+            }) => {
+                let message = format!("Preflight simulation failed: {}", err);
+                Err(rpc_account_already_in_use(&err, &message)
+                    .unwrap_or_else(|| failed(err.to_string())))
+            }
+            None => Err(failed("empty simulateTransaction response".to_string())),
+        }
+    }
+
     /// Submit transaction via standard sendTransaction RPC
     async fn submit_standard(
         &self,
@@ -455,9 +751,7 @@ impl QuickNodePrivateSubmissionStrategy {
         })?;
 
         if let Some(error) = tx_response.error {
-            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
-                format!("{}: {}", error.code, error.message),
-            )));
+            return Err(error.into_transaction_failed());
         }
 
         tx_response.result.ok_or_else(|| {
@@ -474,7 +768,8 @@ impl SubmissionStrategy for QuickNodePrivateSubmissionStrategy {
         &self,
         serialized_tx: &str,
         skip_preflight: bool,
-    ) -> Result<String, AppError> {
+        jito_region: Option<&str>,
+    ) -> Result<SubmissionOutcome, AppError> {
         // CRITICAL: Extract the transaction signature BEFORE submission
         // qn_broadcastBundle returns a bundle ID, NOT the transaction signature.
         // We need the actual tx signature for status lookups and webhook correlation.
@@ -482,16 +777,20 @@ impl SubmissionStrategy for QuickNodePrivateSubmissionStrategy {
 
         // When Jito bundles are enabled, use ONLY Jito submission (no fallback to public mempool)
         if self.config.enable_jito_bundles {
-            match self.submit_jito_bundle(serialized_tx).await {
+            if !skip_preflight {
+                self.simulate_transaction(serialized_tx).await?;
+            }
+            match self.submit_jito_bundle(&[serialized_tx], jito_region).await {
                 Ok(bundle_id) => {
                     info!(
-                        tx_signature = %tx_signature,
+                        signature = %tx_signature,
                         bundle_id = %bundle_id,
                         "🔒 Ghost Mode: Transaction submitted privately via Jito bundle"
                     );
-                    // Return the TX SIGNATURE, not the bundle ID
+                    self.record_bundle(&tx_signature, &bundle_id);
+                    // The TX SIGNATURE identifies the transfer, not the bundle ID
                     // This ensures status lookups and webhook correlation work correctly
-                    return Ok(tx_signature);
+                    return Ok(SubmissionOutcome::new(tx_signature).with_bundle_id(bundle_id));
                 }
                 Err(e) => {
                     // SECURITY: No fallback to public mempool when Jito is enabled
@@ -500,28 +799,28 @@ impl SubmissionStrategy for QuickNodePrivateSubmissionStrategy {
                         AppError::Blockchain(BlockchainError::JitoBundleFailed(msg)) => {
                             warn!(
                                 error = %msg,
-                                tx_signature = %tx_signature,
+                                signature = %tx_signature,
                                 "🔒 Ghost Mode: Jito bundle rejected (definite failure, safe to retry)"
                             );
                         }
                         AppError::Blockchain(BlockchainError::JitoStateUnknown(msg)) => {
                             warn!(
                                 error = %msg,
-                                tx_signature = %tx_signature,
+                                signature = %tx_signature,
                                 "🔒 Ghost Mode: Jito bundle state unknown (DO NOT retry with new blockhash)"
                             );
                         }
                         AppError::Blockchain(BlockchainError::PrivateSubmissionFallback(msg)) => {
                             warn!(
                                 error = %msg,
-                                tx_signature = %tx_signature,
+                                signature = %tx_signature,
                                 "🔒 Ghost Mode: Jito not available on this endpoint"
                             );
                         }
                         _ => {
                             warn!(
                                 error = %e,
-                                tx_signature = %tx_signature,
+                                signature = %tx_signature,
                                 "🔒 Ghost Mode: Jito submission failed"
                             );
                         }
@@ -539,7 +838,60 @@ impl SubmissionStrategy for QuickNodePrivateSubmissionStrategy {
             signature = %signature,
             "Transaction submitted via standard sendTransaction (Jito disabled)"
         );
-        Ok(signature)
+        Ok(SubmissionOutcome::new(signature))
+    }
+
+    async fn submit_bundle(
+        &self,
+        serialized_txs: &[String],
+        jito_region: Option<&str>,
+    ) -> Result<Vec<String>, AppError> {
+        // Standard submission only when Jito is explicitly disabled
+        if !self.config.enable_jito_bundles {
+            let mut signatures = Vec::with_capacity(serialized_txs.len());
+            for serialized_tx in serialized_txs {
+                signatures.push(self.submit_standard(serialized_tx, true).await?);
+            }
+            return Ok(signatures);
+        }
+
+        if serialized_txs.is_empty() || serialized_txs.len() > MAX_JITO_BUNDLE_SIZE {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                format!(
+                    "Jito bundles hold 1-{} transactions, got {}",
+                    MAX_JITO_BUNDLE_SIZE,
+                    serialized_txs.len()
+                ),
+            )));
+        }
+
+        let signatures = serialized_txs
+            .iter()
+            .map(|tx| Self::extract_signature_from_serialized_tx(tx))
+            .collect::<Result<Vec<_>, _>>()?;
+        let txs: Vec<&str> = serialized_txs.iter().map(String::as_str).collect();
+
+        // SECURITY: No fallback to public mempool when Jito is enabled
+        let bundle_id = self
+            .submit_jito_bundle(&txs, jito_region)
+            .await
+            .inspect_err(|e| {
+                warn!(
+                    error = %e,
+                    tx_count = txs.len(),
+                    "🔒 Ghost Mode: Jito multi-transaction bundle submission failed"
+                );
+            })?;
+
+        info!(
+            bundle_id = %bundle_id,
+            tx_count = signatures.len(),
+            "🔒 Ghost Mode: Transactions submitted privately via Jito bundle"
+        );
+        for signature in &signatures {
+            self.record_bundle(signature, &bundle_id);
+        }
+        Ok(signatures)
     }
 
     fn name(&self) -> &'static str {
@@ -549,6 +901,14 @@ impl SubmissionStrategy for QuickNodePrivateSubmissionStrategy {
     fn supports_private_submission(&self) -> bool {
         self.config.enable_jito_bundles
     }
+
+    async fn bundle_status(&self, tx_signature: &str) -> Result<Option<BundleStatus>, AppError> {
+        let bundle_id = match self.bundle_ids.get(tx_signature) {
+            Some(entry) => entry.0.clone(),
+            None => return Ok(None),
+        };
+        self.get_bundle_status(&bundle_id).await.map(Some)
+    }
 }
 
 // ============================================================================
@@ -581,7 +941,8 @@ impl SubmissionStrategy for StandardSubmissionStrategy {
         &self,
         serialized_tx: &str,
         skip_preflight: bool,
-    ) -> Result<String, AppError> {
+        _jito_region: Option<&str>,
+    ) -> Result<SubmissionOutcome, AppError> {
         let params = vec![
             serde_json::Value::String(serialized_tx.to_string()),
             serde_json::json!({
@@ -619,9 +980,7 @@ impl SubmissionStrategy for StandardSubmissionStrategy {
         })?;
 
         if let Some(error) = tx_response.error {
-            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
-                format!("{}: {}", error.code, error.message),
-            )));
+            return Err(error.into_transaction_failed());
         }
 
         let signature = tx_response.result.ok_or_else(|| {
@@ -629,7 +988,7 @@ impl SubmissionStrategy for StandardSubmissionStrategy {
         })?;
 
         debug!(signature = %signature, "Transaction submitted via sendTransaction");
-        Ok(signature)
+        Ok(SubmissionOutcome::new(signature))
     }
 
     fn name(&self) -> &'static str {
@@ -641,6 +1000,193 @@ impl SubmissionStrategy for StandardSubmissionStrategy {
     }
 }
 
+// ============================================================================
+// DYNAMIC JITO TIP STRATEGY
+// ============================================================================
+
+/// Default Jito tip floor endpoint (recently landed tip percentiles, in SOL)
+pub const DEFAULT_JITO_TIP_FLOOR_URL: &str = "https://bundles.jito.wtf/api/v1/bundles/tip_floor";
+
+/// Default percentile of recently landed tips to pay
+pub const DEFAULT_JITO_TIP_PERCENTILE: u8 = 75;
+
+/// Default upper bound for a dynamic tip (0.001 SOL)
+pub const DEFAULT_JITO_TIP_CEILING_LAMPORTS: u64 = 1_000_000;
+
+/// How long a fetched tip floor is reused before querying again
+const TIP_FLOOR_CACHE_TTL: Duration = Duration::from_secs(5);
+
+const LAMPORTS_PER_SOL: f64 = 1_000_000_000.0;
+
+/// Configuration for dynamic Jito tip sizing
+#[derive(Debug, Clone)]
+pub struct DynamicTipConfig {
+    /// Tip floor endpoint returning landed tip percentiles
+    pub tip_floor_url: String,
+    /// Percentile of recently landed tips to pay (rounded up to a published percentile)
+    pub percentile: u8,
+    /// Lower bound for the tip, also used when no tip floor data is available
+    pub floor_lamports: u64,
+    /// Upper bound for the tip
+    pub ceiling_lamports: u64,
+}
+
+impl Default for DynamicTipConfig {
+    fn default() -> Self {
+        Self {
+            tip_floor_url: DEFAULT_JITO_TIP_FLOOR_URL.to_string(),
+            percentile: DEFAULT_JITO_TIP_PERCENTILE,
+            floor_lamports: 1_000,
+            ceiling_lamports: DEFAULT_JITO_TIP_CEILING_LAMPORTS,
+        }
+    }
+}
+
+/// Single entry of the tip floor response (values in SOL)
+#[derive(Debug, Deserialize)]
+struct TipFloorEntry {
+    landed_tips_25th_percentile: f64,
+    landed_tips_50th_percentile: f64,
+    landed_tips_75th_percentile: f64,
+    landed_tips_95th_percentile: f64,
+    landed_tips_99th_percentile: f64,
+}
+
+/// Recently landed Jito tips by percentile (in lamports)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TipFloor {
+    pub p25: u64,
+    pub p50: u64,
+    pub p75: u64,
+    pub p95: u64,
+    pub p99: u64,
+}
+
+impl TipFloor {
+    /// Tip at `percentile`, rounded up to the next published percentile
+    pub fn at_percentile(&self, percentile: u8) -> u64 {
+        match percentile {
+            0..=25 => self.p25,
+            26..=50 => self.p50,
+            51..=75 => self.p75,
+            76..=95 => self.p95,
+            _ => self.p99,
+        }
+    }
+}
+
+impl From<&TipFloorEntry> for TipFloor {
+    fn from(entry: &TipFloorEntry) -> Self {
+        // Negative and NaN values saturate to 0
+        let lamports = |sol: f64| (sol * LAMPORTS_PER_SOL).round() as u64;
+        Self {
+            p25: lamports(entry.landed_tips_25th_percentile),
+            p50: lamports(entry.landed_tips_50th_percentile),
+            p75: lamports(entry.landed_tips_75th_percentile),
+            p95: lamports(entry.landed_tips_95th_percentile),
+            p99: lamports(entry.landed_tips_99th_percentile),
+        }
+    }
+}
+
+/// Sizes Jito tips from recently landed tips instead of a flat amount
+///
+/// The tip floor is cached for a few seconds so bursts of transfers share a
+/// single lookup. When the endpoint is unavailable the last known tip floor
+/// is reused, and without any data the configured floor is paid.
+pub struct DynamicTipStrategy {
+    config: DynamicTipConfig,
+    http_client: reqwest::Client,
+    cached: Mutex<Option<(Instant, TipFloor)>>,
+}
+
+impl DynamicTipStrategy {
+    /// Create a new dynamic tip strategy
+    pub fn new(config: DynamicTipConfig) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(2))
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            config,
+            http_client,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Tip to attach to the next transaction (in lamports)
+    pub async fn tip_lamports(&self) -> u64 {
+        let tip = match self.tip_floor().await {
+            Some(tip_floor) => tip_floor.at_percentile(self.config.percentile),
+            None => self.config.floor_lamports,
+        };
+        self.clamp(tip)
+    }
+
+    /// Clamp a tip between the configured floor and ceiling
+    fn clamp(&self, tip: u64) -> u64 {
+        let floor = self.config.floor_lamports;
+        let ceiling = self.config.ceiling_lamports.max(floor);
+        tip.clamp(floor, ceiling)
+    }
+
+    /// Fresh cached tip floor, or a new one from the endpoint
+    async fn tip_floor(&self) -> Option<TipFloor> {
+        let cached = *self.cached.lock().expect("tip floor cache poisoned");
+        if let Some((fetched_at, tip_floor)) = cached
+            && fetched_at.elapsed() < TIP_FLOOR_CACHE_TTL
+        {
+            return Some(tip_floor);
+        }
+
+        match self.fetch_tip_floor().await {
+            Ok(tip_floor) => {
+                debug!(?tip_floor, "Fetched Jito tip floor");
+                *self.cached.lock().expect("tip floor cache poisoned") =
+                    Some((Instant::now(), tip_floor));
+                Some(tip_floor)
+            }
+            Err(e) => {
+                warn!(error = %e, "Failed to fetch Jito tip floor, using last known value");
+                cached.map(|(_, tip_floor)| tip_floor)
+            }
+        }
+    }
+
+    async fn fetch_tip_floor(&self) -> Result<TipFloor, AppError> {
+        let response = self
+            .http_client
+            .get(&self.config.tip_floor_url)
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::Blockchain(BlockchainError::RpcError(format!(
+                    "Tip floor request failed: {}",
+                    e
+                )))
+            })?;
+
+        let entries: Vec<TipFloorEntry> = response.json().await.map_err(|e| {
+            AppError::Blockchain(BlockchainError::RpcError(format!(
+                "Failed to parse tip floor response: {}",
+                e
+            )))
+        })?;
+
+        entries.first().map(TipFloor::from).ok_or_else(|| {
+            AppError::Blockchain(BlockchainError::RpcError(
+                "Empty tip floor response".to_string(),
+            ))
+        })
+    }
+
+    /// Human-readable strategy name for logging
+    pub fn name(&self) -> &'static str {
+        "Dynamic (Jito tip floor)"
+    }
+}
+
 // ============================================================================
 // QUICKNODE TOKEN API CLIENT
 // ============================================================================
@@ -652,6 +1198,8 @@ pub struct TokenActivityInfo {
     pub token_mint: String,
     /// Number of recent transactions
     pub recent_tx_count: u64,
+    /// Number of token holders (if reported by the provider)
+    pub holder_count: Option<u64>,
     /// Timestamp of last activity (if available)
     pub last_activity_timestamp: Option<DateTime<Utc>>,
     /// Whether this is an estimate (cached/approximated)
@@ -714,6 +1262,41 @@ impl QuickNodeTokenApiClient {
         }
     }
 
+    /// Check that the Token API endpoint is reachable with `getHealth`
+    pub async fn ping(&self) -> Result<(), AppError> {
+        #[derive(Debug, Deserialize)]
+        struct GetHealthResponse {
+            error: Option<RpcError>,
+        }
+
+        let response = self
+            .http_client
+            .post(&self.rpc_url)
+            .json(&serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "getHealth" }))
+            .send()
+            .await
+            .map_err(|e| {
+                AppError::Blockchain(BlockchainError::QuickNodeApiError(format!(
+                    "Token API health request failed: {}",
+                    e
+                )))
+            })?;
+
+        let health: GetHealthResponse = response.json().await.map_err(|e| {
+            AppError::Blockchain(BlockchainError::QuickNodeApiError(format!(
+                "Failed to parse health response: {}",
+                e
+            )))
+        })?;
+
+        match health.error {
+            Some(error) => Err(AppError::Blockchain(BlockchainError::QuickNodeApiError(
+                error.message,
+            ))),
+            None => Ok(()),
+        }
+    }
+
     /// Fetch token metadata using QuickNode's enhanced API
     async fn get_token_metadata(&self, token_mint: &str) -> Result<TokenActivityInfo, AppError> {
         #[derive(Debug, Serialize)]
@@ -733,7 +1316,6 @@ impl QuickNodeTokenApiClient {
         #[derive(Debug, Deserialize)]
         #[serde(rename_all = "camelCase")]
         struct TokenMetadata {
-            #[allow(dead_code)]
             #[serde(default)]
             holder_count: Option<u64>,
             #[serde(default)]
@@ -788,6 +1370,7 @@ impl QuickNodeTokenApiClient {
         Ok(TokenActivityInfo {
             token_mint: token_mint.to_string(),
             recent_tx_count: estimated_recent.max(1), // At least 1 if token exists
+            holder_count: metadata.holder_count,
             last_activity_timestamp: None,
             is_estimate: true,
         })
@@ -860,6 +1443,7 @@ impl QuickNodeTokenApiClient {
         Ok(TokenActivityInfo {
             token_mint: token_mint.to_string(),
             recent_tx_count: signatures.len() as u64,
+            holder_count: None,
             last_activity_timestamp: None,
             is_estimate: false,
         })
@@ -897,6 +1481,8 @@ mod tests {
             tip_lamports: 10_000,
             max_bundle_retries: 2,
             region: None,
+            block_engine_url: None,
+            track_bundle_status: false,
         };
         let strategy = QuickNodePrivateSubmissionStrategy::new(config);
         assert_eq!(strategy.name(), "QuickNode (Ghost Mode / Jito)");
@@ -908,6 +1494,7 @@ mod tests {
         let info = TokenActivityInfo {
             token_mint: "test_mint".to_string(),
             recent_tx_count: 10,
+            holder_count: None,
             last_activity_timestamp: Some(Utc::now()),
             is_estimate: false,
         };
@@ -931,12 +1518,33 @@ mod tests {
             tip_lamports: 10_000,
             max_bundle_retries: 2,
             region: Some("ny".to_string()), // Test with region
+            block_engine_url: None,
+            track_bundle_status: false,
         };
         let strategy = QuickNodePrivateSubmissionStrategy::new(config);
         assert_eq!(strategy.name(), "QuickNode (Ghost Mode / Jito)");
         assert!(!strategy.supports_private_submission()); // Should be false when Jito is disabled
     }
 
+    #[test]
+    fn test_send_transaction_preflight_error_typed() {
+        let in_use = r#"{"error": {"code": -32002, "message": "Transaction simulation failed",
+            "data": {"err": {"InstructionError": [2, {"Custom": 0}]}, "logs": []}}}"#;
+        let response: SendTransactionResponse = serde_json::from_str(in_use).unwrap();
+        assert!(matches!(
+            response.error.unwrap().into_transaction_failed(),
+            AppError::Blockchain(BlockchainError::AccountAlreadyInUse { instruction: 2, .. })
+        ));
+
+        let other = r#"{"error": {"code": -32002, "message": "Transaction simulation failed",
+            "data": {"err": "BlockhashNotFound"}}}"#;
+        let response: SendTransactionResponse = serde_json::from_str(other).unwrap();
+        assert!(matches!(
+            response.error.unwrap().into_transaction_failed(),
+            AppError::Blockchain(BlockchainError::TransactionFailed(_))
+        ));
+    }
+
     #[test]
     fn test_jito_bundle_response_error_parsing() {
         // Test that JitoBundleResponse correctly parses errors
@@ -1156,4 +1764,224 @@ mod tests {
         let expected = bs58::encode(&first_sig).into_string();
         assert_eq!(extracted, expected);
     }
+
+    fn tip_strategy(percentile: u8, floor: u64, ceiling: u64) -> DynamicTipStrategy {
+        DynamicTipStrategy::new(DynamicTipConfig {
+            // Unroutable, so tests never depend on the live endpoint
+            tip_floor_url: "http://127.0.0.1:9/tip_floor".to_string(),
+            percentile,
+            floor_lamports: floor,
+            ceiling_lamports: ceiling,
+        })
+    }
+
+    fn sample_tip_floor() -> TipFloor {
+        TipFloor {
+            p25: 1_000,
+            p50: 5_000,
+            p75: 20_000,
+            p95: 400_000,
+            p99: 5_000_000,
+        }
+    }
+
+    #[test]
+    fn test_tip_floor_percentile_selection() {
+        let tip_floor = sample_tip_floor();
+        assert_eq!(tip_floor.at_percentile(10), 1_000);
+        assert_eq!(tip_floor.at_percentile(25), 1_000);
+        assert_eq!(tip_floor.at_percentile(26), 5_000);
+        assert_eq!(tip_floor.at_percentile(50), 5_000);
+        assert_eq!(tip_floor.at_percentile(75), 20_000);
+        assert_eq!(tip_floor.at_percentile(90), 400_000);
+        assert_eq!(tip_floor.at_percentile(99), 5_000_000);
+        assert_eq!(tip_floor.at_percentile(100), 5_000_000);
+    }
+
+    #[test]
+    fn test_tip_floor_response_parsing() {
+        let body = r#"[{
+            "time": "2026-01-30T10:30:00Z",
+            "landed_tips_25th_percentile": 0.000001,
+            "landed_tips_50th_percentile": 0.00001,
+            "landed_tips_75th_percentile": 0.0000361,
+            "landed_tips_95th_percentile": 0.0014,
+            "landed_tips_99th_percentile": 0.01,
+            "ema_landed_tips_50th_percentile": 0.000013
+        }]"#;
+        let entries: Vec<TipFloorEntry> = serde_json::from_str(body).unwrap();
+        let tip_floor = TipFloor::from(&entries[0]);
+        assert_eq!(tip_floor.p25, 1_000);
+        assert_eq!(tip_floor.p50, 10_000);
+        assert_eq!(tip_floor.p75, 36_100);
+        assert_eq!(tip_floor.p95, 1_400_000);
+        assert_eq!(tip_floor.p99, 10_000_000);
+    }
+
+    #[test]
+    fn test_dynamic_tip_clamping() {
+        let strategy = tip_strategy(75, 10_000, 100_000);
+        assert_eq!(strategy.clamp(1_000), 10_000);
+        assert_eq!(strategy.clamp(50_000), 50_000);
+        assert_eq!(strategy.clamp(5_000_000), 100_000);
+
+        // A ceiling below the floor never lowers the tip under the floor
+        let inverted = tip_strategy(75, 10_000, 1_000);
+        assert_eq!(inverted.clamp(5_000_000), 10_000);
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_tip_uses_cached_tip_floor() {
+        let strategy = tip_strategy(95, 1_000, 1_000_000);
+        *strategy.cached.lock().unwrap() = Some((Instant::now(), sample_tip_floor()));
+        assert_eq!(strategy.tip_lamports().await, 400_000);
+
+        let strategy = tip_strategy(99, 1_000, 1_000_000);
+        *strategy.cached.lock().unwrap() = Some((Instant::now(), sample_tip_floor()));
+        assert_eq!(strategy.tip_lamports().await, 1_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_tip_falls_back_when_unavailable() {
+        // No cached data and an unreachable endpoint: pay the floor
+        let strategy = tip_strategy(75, 2_500, 1_000_000);
+        assert_eq!(strategy.tip_lamports().await, 2_500);
+
+        // Stale data is preferred over the floor when the endpoint fails
+        let stale = Instant::now() - TIP_FLOOR_CACHE_TTL * 2;
+        *strategy.cached.lock().unwrap() = Some((stale, sample_tip_floor()));
+        assert_eq!(strategy.tip_lamports().await, 20_000);
+    }
+
+    fn jito_strategy(track_bundle_status: bool) -> QuickNodePrivateSubmissionStrategy {
+        QuickNodePrivateSubmissionStrategy::new(QuickNodeSubmissionConfig {
+            rpc_url: "https://test.quiknode.pro/xxx".to_string(),
+            track_bundle_status,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_inflight_bundle_status_parsing() {
+        let body = r#"{
+            "jsonrpc": "2.0",
+            "result": {
+                "context": {"slot": 280999028},
+                "value": [{"bundle_id": "b31e5fae", "status": "Landed", "landed_slot": 280999027}]
+            },
+            "id": 1
+        }"#;
+        let response: BundleStatusesResponse<InflightBundleStatus> =
+            serde_json::from_str(body).unwrap();
+        let entry = first_bundle_status(response).unwrap().unwrap();
+        assert_eq!(
+            BundleStatus::from(&entry),
+            BundleStatus::Landed {
+                slot: Some(280999027)
+            }
+        );
+
+        for (status, expected) in [
+            ("Pending", BundleStatus::Pending),
+            ("Failed", BundleStatus::Failed),
+            ("Invalid", BundleStatus::Invalid),
+        ] {
+            let entry = InflightBundleStatus {
+                status: status.to_string(),
+                landed_slot: None,
+            };
+            assert_eq!(BundleStatus::from(&entry), expected);
+        }
+        assert!(BundleStatus::Failed.is_dropped());
+        assert!(!BundleStatus::Invalid.is_dropped());
+        assert!(!BundleStatus::Pending.is_dropped());
+    }
+
+    #[test]
+    fn test_landed_bundle_status_parsing() {
+        let body = r#"{
+            "jsonrpc": "2.0",
+            "result": {
+                "context": {"slot": 242806119},
+                "value": [{
+                    "bundle_id": "892b79ed",
+                    "transactions": ["3bC2M9fi"],
+                    "slot": 242804011,
+                    "confirmation_status": "finalized",
+                    "err": {"Ok": null}
+                }]
+            },
+            "id": 1
+        }"#;
+        let response: BundleStatusesResponse<LandedBundleStatus> =
+            serde_json::from_str(body).unwrap();
+        let entry = first_bundle_status(response).unwrap().unwrap();
+        assert_eq!(
+            BundleStatus::from(&entry),
+            BundleStatus::Landed {
+                slot: Some(242804011)
+            }
+        );
+
+        let failed = LandedBundleStatus {
+            slot: Some(1),
+            err: serde_json::json!({"Err": "BundleFailed"}),
+        };
+        assert_eq!(BundleStatus::from(&failed), BundleStatus::Failed);
+    }
+
+    #[test]
+    fn test_unknown_bundle_and_error_responses() {
+        let unknown = r#"{"jsonrpc":"2.0","result":{"context":{"slot":1},"value":[null]},"id":1}"#;
+        let response: BundleStatusesResponse<LandedBundleStatus> =
+            serde_json::from_str(unknown).unwrap();
+        assert!(first_bundle_status(response).unwrap().is_none());
+
+        let error =
+            r#"{"jsonrpc":"2.0","error":{"code":-32602,"message":"invalid bundle id"},"id":1}"#;
+        let response: BundleStatusesResponse<InflightBundleStatus> =
+            serde_json::from_str(error).unwrap();
+        assert!(first_bundle_status(response).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_bundle_id_tracking() {
+        let untracked = jito_strategy(false);
+        untracked.record_bundle("sig_a", "bundle_a");
+        assert_eq!(untracked.last_bundle_id().as_deref(), Some("bundle_a"));
+        assert!(untracked.bundle_status("sig_a").await.unwrap().is_none());
+
+        let tracked = jito_strategy(true);
+        assert!(tracked.last_bundle_id().is_none());
+        tracked.record_bundle("sig_a", "bundle_a");
+        tracked.record_bundle("sig_b", "bundle_b");
+        assert_eq!(tracked.last_bundle_id().as_deref(), Some("bundle_b"));
+        assert_eq!(tracked.bundle_ids.get("sig_a").unwrap().0, "bundle_a");
+        assert!(
+            tracked
+                .bundle_status("sig_unknown")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_submit_bundle_rejects_invalid_size() {
+        let strategy = jito_strategy(false);
+
+        let empty = strategy.submit_bundle(&[], None).await;
+        assert!(matches!(
+            empty,
+            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
+        ));
+
+        let oversized = vec!["tx".to_string(); MAX_JITO_BUNDLE_SIZE + 1];
+        let result = strategy.submit_bundle(&oversized, None).await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
+        ));
+        assert!(strategy.last_bundle_id().is_none());
+    }
 }
@@ -1,4 +1,4 @@
-﻿//! Blockchain RPC client implementation for Solana.
+//! Blockchain RPC client implementation for Solana.
 //!
 //! This module provides both mock and real blockchain interactions.
 //! Real blockchain functionality is enabled with the `real-blockchain` feature.
@@ -8,8 +8,9 @@ use ed25519_dalek::{Signer, SigningKey};
 use reqwest::Client;
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::collections::HashMap;
 use std::str::FromStr;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{debug, info, instrument, warn};
 
 // Solana SDK imports (v3.0)
@@ -18,19 +19,28 @@ use solana_client::nonblocking::rpc_client::RpcClient as SolanaRpcClient;
 use solana_commitment_config::CommitmentConfig;
 use solana_compute_budget_interface::ComputeBudgetInstruction;
 use solana_sdk::{
-    instruction::Instruction,
+    hash::Hash,
+    instruction::{Instruction, InstructionError},
+    message::{AddressLookupTableAccount, Message, VersionedMessage, v0},
     pubkey::Pubkey,
+    signature::Signature,
     signer::{Signer as SolanaSigner, keypair::Keypair},
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError, VersionedTransaction},
+};
+use solana_system_interface::{error::SystemError, instruction as system_instruction};
+use solana_zk_sdk::encryption::pod::{
+    elgamal::PodElGamalCiphertext, grouped_elgamal::PodGroupedElGamalCiphertext3Handles,
 };
-use solana_system_interface::instruction as system_instruction;
 use solana_zk_sdk::zk_elgamal_proof_program::{
     instruction::{ContextStateInfo, ProofInstruction, close_context_state},
     proof_data::{
+        BatchedGroupedCiphertext2HandlesValidityProofContext,
+        BatchedGroupedCiphertext2HandlesValidityProofData,
         BatchedGroupedCiphertext3HandlesValidityProofContext,
         BatchedGroupedCiphertext3HandlesValidityProofData, BatchedRangeProofContext,
-        BatchedRangeProofU128Data, CiphertextCommitmentEqualityProofContext,
-        CiphertextCommitmentEqualityProofData,
+        BatchedRangeProofU128Data, BatchedRangeProofU256Data,
+        CiphertextCommitmentEqualityProofContext, CiphertextCommitmentEqualityProofData,
+        PercentageWithCapProofContext, PercentageWithCapProofData,
     },
     state::ProofContextState,
 };
@@ -39,10 +49,29 @@ use spl_associated_token_account::{
     instruction::create_associated_token_account_idempotent,
 };
 
+use spl_token_2022_interface::{
+    extension::{
+        BaseStateWithExtensions, StateWithExtensions,
+        confidential_transfer::{
+            ConfidentialTransferAccount, ConfidentialTransferMint, DecryptableBalance,
+            instruction::{approve_account, inner_transfer, inner_transfer_with_fee},
+        },
+        confidential_transfer_fee::ConfidentialTransferFeeConfig,
+        transfer_fee::{
+            TransferFee, TransferFeeAmount, TransferFeeConfig,
+            instruction::transfer_checked_with_fee,
+        },
+    },
+    state::{Account as Token2022Account, Mint as Token2022Mint},
+};
+use spl_token_confidential_transfer_proof_extraction::instruction::ProofLocation;
 use spl_token_interface::instruction as token_instruction;
 
-use crate::domain::types::TransferType;
-use crate::domain::{AppError, BlockchainClient, BlockchainError, TransferRequest};
+use crate::domain::types::{ELGAMAL_PUBKEY_LEN, TransferType};
+use crate::domain::{
+    AppError, BlockchainClient, BlockchainError, BlockchainNodeHealth, BundleStatus, FeeEstimate,
+    HealthStatus, ProposalStatus, SubmissionOutcome, SubmitOptions, TransferRequest,
+};
 
 /// Configuration for the RPC client
 #[derive(Debug, Clone)]
@@ -51,6 +80,55 @@ pub struct RpcClientConfig {
     pub max_retries: u32,
     pub retry_delay: Duration,
     pub confirmation_timeout: Duration,
+    /// Build v0 (versioned) transactions instead of legacy ones
+    pub use_versioned_tx: bool,
+    /// Address lookup table resolved into v0 messages (only with `use_versioned_tx`)
+    pub address_lookup_table: Option<Pubkey>,
+    /// Durable nonce account used instead of a recent blockhash.
+    /// Its nonce authority must be the relayer keypair.
+    pub nonce_account: Option<Pubkey>,
+    /// Ceiling for the compute-unit price reported by the fee strategy (micro-lamports)
+    pub max_priority_fee_microlamports: u64,
+    /// Compute unit limit requested by SOL and token transfers
+    pub compute_unit_limit: u32,
+    /// For Token-2022 mints with a transfer fee, send enough to cover the fee so
+    /// the recipient nets the requested amount. When disabled, the fee is
+    /// deducted from the requested amount.
+    pub gross_up_transfer_fees: bool,
+    /// Check the fee payer's balance with `getBalance` before building a SOL
+    /// transfer and fail early with `InsufficientFunds` if it can't cover the
+    /// amount, fee and tip. Costs one extra RPC call per transfer.
+    pub preflight_balance_check: bool,
+    /// After a confirmed token transfer, close the relayer's token account
+    /// for the mint if it is empty and return its rent to the fee payer
+    pub close_empty_token_accounts: bool,
+    /// Mints flagged by the asset check on providers without Helius DAS,
+    /// which scans `getTokenAccountsByOwner`. Only held mints are matched;
+    /// NFT collections are not resolved on these providers.
+    pub sanctioned_mints: Vec<Pubkey>,
+    /// Slots the node may fall behind the expected slot before the detailed
+    /// health check reports it as degraded
+    pub max_slot_lag: u64,
+    /// Skip preflight simulation when submitting through the submission
+    /// strategy. Faster, but a doomed transaction still costs its fee.
+    /// Transfers may override it with their own `skip_preflight`.
+    pub skip_preflight: bool,
+    /// How long `getMinimumBalanceForRentExemption` results are cached per
+    /// account size. Zero disables the cache.
+    pub rent_cache_ttl: Duration,
+    /// How long a mint's token program and decimals are cached. Neither
+    /// changes after the mint is created; the entry expires so rarely used
+    /// mints are eventually refetched. Zero disables the cache.
+    pub mint_cache_ttl: Duration,
+    /// First delay between `getSignatureStatuses` polls while waiting for
+    /// confirmation. Doubles after every poll up to the maximum.
+    pub confirmation_poll_interval: Duration,
+    /// Longest delay between confirmation polls
+    pub confirmation_poll_max_interval: Duration,
+    /// Without a submission strategy, how long a transaction whose blockhash
+    /// expires before it confirms is re-signed with a fresh blockhash and
+    /// resent. Zero sends it once. Not used with a durable nonce.
+    pub resubmit_deadline: Duration,
 }
 
 impl Default for RpcClientConfig {
@@ -60,10 +138,109 @@ impl Default for RpcClientConfig {
             max_retries: 3,
             retry_delay: Duration::from_millis(500),
             confirmation_timeout: Duration::from_secs(60),
+            use_versioned_tx: false,
+            address_lookup_table: None,
+            nonce_account: None,
+            max_priority_fee_microlamports: DEFAULT_MAX_PRIORITY_FEE_MICROLAMPORTS,
+            compute_unit_limit: DEFAULT_COMPUTE_UNIT_LIMIT,
+            gross_up_transfer_fees: false,
+            preflight_balance_check: false,
+            close_empty_token_accounts: false,
+            sanctioned_mints: Vec::new(),
+            max_slot_lag: DEFAULT_MAX_SLOT_LAG,
+            skip_preflight: true,
+            rent_cache_ttl: DEFAULT_RENT_CACHE_TTL,
+            mint_cache_ttl: DEFAULT_MINT_CACHE_TTL,
+            confirmation_poll_interval: DEFAULT_CONFIRMATION_POLL_INTERVAL,
+            confirmation_poll_max_interval: DEFAULT_CONFIRMATION_POLL_MAX_INTERVAL,
+            resubmit_deadline: DEFAULT_RESUBMIT_DEADLINE,
         }
     }
 }
 
+/// Default priority fee ceiling: 1,000,000 micro-lamports per compute unit
+pub const DEFAULT_MAX_PRIORITY_FEE_MICROLAMPORTS: u64 = 1_000_000;
+
+/// Default allowed slot lag: about a minute of slots
+pub const DEFAULT_MAX_SLOT_LAG: u64 = 150;
+
+/// Default lifetime of cached rent-exempt minimums: 10 minutes
+pub const DEFAULT_RENT_CACHE_TTL: Duration = Duration::from_secs(600);
+
+/// Default lifetime of cached mint token programs and decimals: 24 hours
+pub const DEFAULT_MINT_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Default first delay between confirmation polls
+pub const DEFAULT_CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Default longest delay between confirmation polls
+pub const DEFAULT_CONFIRMATION_POLL_MAX_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Default time the standard path keeps resending with fresh blockhashes
+pub const DEFAULT_RESUBMIT_DEADLINE: Duration = Duration::from_secs(120);
+
+/// Slowest slot time expected of a live cluster. Projecting the expected
+/// slot with it keeps normal slot-time variance from counting as lag.
+const MAX_SLOT_DURATION: Duration = Duration::from_millis(500);
+
+/// Times a multisig proposal is created before giving up when other
+/// proposers keep taking the transaction index first
+const PROPOSAL_INDEX_ATTEMPTS: u32 = 3;
+
+/// Slots `slot` is behind the slot projected from `previous`, the first
+/// time the node reported the highest slot seen so far
+fn slot_lag(previous: Option<(u64, Instant)>, slot: u64, now: Instant) -> Option<u64> {
+    let (previous_slot, observed_at) = previous?;
+    let elapsed_slots =
+        now.saturating_duration_since(observed_at).as_millis() / MAX_SLOT_DURATION.as_millis();
+    let expected = previous_slot.saturating_add(u64::try_from(elapsed_slots).unwrap_or(u64::MAX));
+    Some(expected.saturating_sub(slot))
+}
+
+/// `getVersion` result
+#[derive(Debug, Deserialize)]
+struct NodeVersion {
+    #[serde(rename = "solana-core")]
+    solana_core: String,
+}
+
+/// Default compute unit limit for SOL and token transfers
+pub const DEFAULT_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
+/// Base fee charged per transaction signature
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Maximum serialized size of a transaction (the network packet limit)
+const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Transactions within this many bytes of `MAX_TRANSACTION_SIZE` are logged
+/// as near the limit
+const TRANSACTION_SIZE_WARNING_MARGIN: usize = 100;
+
+/// Compute units budgeted per recipient of a batch transfer, enough for an
+/// idempotent ATA creation plus the transfer
+const BATCH_RECIPIENT_COMPUTE_UNITS: u32 = 40_000;
+
+/// Most compute units a single transaction may request
+const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Byte offset of the amount in a token account (after the mint and owner)
+const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
+
+/// Size of the metadata header preceding the addresses in a lookup table account
+const LOOKUP_TABLE_META_SIZE: usize = 56;
+
+/// Byte range of the stored nonce value in a nonce account
+/// (version tag, state tag and authority precede it)
+const NONCE_VALUE_RANGE: std::ops::Range<usize> = 40..72;
+
+/// State tag of an initialized nonce account
+const NONCE_STATE_INITIALIZED: u32 = 1;
+
+/// Index of the auditor's decrypt handle in a transfer amount's grouped
+/// ciphertext (after the source's and the destination's)
+const AUDITOR_HANDLE_INDEX: usize = 2;
+
 /// Abstract provider for Solana RPC interactions to enable testing
 #[async_trait]
 pub trait SolanaRpcProvider: Send + Sync {
@@ -178,8 +355,13 @@ pub struct RpcBlockchainClient {
     config: RpcClientConfig,
     /// Solana SDK RPC client for SDK-based operations
     sdk_client: Option<SolanaRpcClient>,
-    /// Solana keypair for signing transactions
-    keypair: Option<Keypair>,
+    /// Issuer keypairs for signing transactions; the first is the primary key
+    keypairs: Vec<Keypair>,
+    /// Keys being rotated out: they still sign transfers already pinned to
+    /// them but are never selected for new ones
+    retired_keypairs: Vec<Keypair>,
+    /// Assigns new transfers to one of `keypairs`
+    key_selector: super::signers::KeySelector,
     /// Auto-detected provider type
     provider_type: super::strategies::RpcProviderType,
     /// Priority fee estimation strategy
@@ -196,6 +378,23 @@ pub struct RpcBlockchainClient {
     /// Jito tip amount in lamports (only used when submission_strategy supports private submission)
     /// This tip is added as a SOL transfer instruction to a Jito tip account.
     jito_tip_lamports: Option<u64>,
+    /// Sizes Jito tips from recent tip-floor data instead of `jito_tip_lamports`
+    dynamic_tip: Option<super::quicknode::DynamicTipStrategy>,
+    /// Resolved address lookup tables for v0 messages, fetched on first use
+    lookup_tables: tokio::sync::OnceCell<Vec<AddressLookupTableAccount>>,
+    /// Highest slot seen by the detailed health check and when it was first
+    /// seen, the baseline for the slot lag
+    slot_observation: std::sync::Mutex<Option<(u64, Instant)>>,
+    /// Rent-exempt minimums by account size and when they were fetched
+    rent_cache: std::sync::Mutex<HashMap<usize, (u64, Instant)>>,
+    /// Token program and decimals by mint, with the time they were fetched
+    mint_cache: std::sync::Mutex<HashMap<Pubkey, (Pubkey, u8, Instant)>>,
+    /// Squads multisig whose vault sends public transfers as proposals,
+    /// instead of the issuer key sending them directly
+    multisig: Option<super::squads::SquadsConfig>,
+    /// Held while a proposal is created, so proposals of this client take
+    /// the multisig's transaction indexes one at a time
+    proposal_lock: tokio::sync::Mutex<()>,
 }
 
 #[derive(Debug, Serialize)]
@@ -228,6 +427,98 @@ struct BlockhashResult {
     value: BlockhashResponse,
 }
 
+#[derive(Debug, Deserialize)]
+struct BalanceResult {
+    value: u64,
+}
+
+/// `getTokenAccountsByOwner` result with `jsonParsed` encoding
+#[derive(Debug, Deserialize)]
+struct TokenAccountsByOwnerResult {
+    value: Vec<KeyedTokenAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyedTokenAccount {
+    pubkey: String,
+    account: ParsedTokenAccount,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParsedTokenAccount {
+    data: ParsedTokenAccountData,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParsedTokenAccountData {
+    parsed: ParsedTokenAccountInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct ParsedTokenAccountInfo {
+    info: TokenAccountHolding,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TokenAccountHolding {
+    mint: String,
+    token_amount: TokenAmount,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenAmount {
+    /// Raw amount as a decimal string
+    amount: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct FeeForMessageResult {
+    value: Option<u64>,
+}
+
+/// Token program, decimals and transfer fee of a mint
+struct TokenMintInfo {
+    token_program_id: Pubkey,
+    decimals: u8,
+    /// Fee of the current epoch when the mint is a Token-2022 fee mint
+    transfer_fee: Option<TransferFee>,
+}
+
+impl TokenMintInfo {
+    /// Plan a transfer of `amount` raw units of this mint
+    fn plan(&self, amount: u64, gross_up: bool) -> Result<TokenTransferPlan, AppError> {
+        let (send_amount, expected_fee) = match &self.transfer_fee {
+            Some(fee) => {
+                let (send_amount, expected_fee) = plan_fee_transfer(fee, amount, gross_up)?;
+                info!(
+                    requested = amount,
+                    send_amount, expected_fee, gross_up, "Mint has Token-2022 transfer fee"
+                );
+                (send_amount, Some(expected_fee))
+            }
+            None => (amount, None),
+        };
+
+        Ok(TokenTransferPlan {
+            token_program_id: self.token_program_id,
+            decimals: self.decimals,
+            send_amount,
+            expected_fee,
+        })
+    }
+}
+
+/// How a token transfer is carried out, read from the mint
+struct TokenTransferPlan {
+    token_program_id: Pubkey,
+    decimals: u8,
+    /// Raw units sent, grossed up for the transfer fee if configured
+    send_amount: u64,
+    /// Transfer fee withheld by a Token-2022 mint
+    expected_fee: Option<u64>,
+}
+
 #[derive(Debug, Deserialize)]
 struct SignatureStatus {
     err: Option<serde_json::Value>,
@@ -311,6 +602,54 @@ impl RpcBlockchainClient {
         config: RpcClientConfig,
         submission_strategy: Option<Box<dyn super::strategies::SubmissionStrategy>>,
         jito_tip_lamports: Option<u64>,
+    ) -> Result<Self, AppError> {
+        Self::build(
+            rpc_url,
+            signing_key,
+            config,
+            None,
+            submission_strategy,
+            jito_tip_lamports,
+        )
+    }
+
+    /// Create a new RPC blockchain client with a caller-supplied priority fee
+    /// strategy, e.g. behind a proxy that masks the provider.
+    ///
+    /// # Fee Strategy Precedence
+    /// 1. `fee_strategy`, when given here, is always used
+    /// 2. Otherwise the strategy is chosen by provider detection on `rpc_url`
+    ///    (see [`Self::new_with_submission_strategy`])
+    ///
+    /// Only fee estimation bypasses detection: the Helius DAS checks still
+    /// follow the detected provider. Estimates from the injected strategy are
+    /// clamped to `max_priority_fee_microlamports` like any other.
+    pub fn new_with_fee_strategy(
+        rpc_url: &str,
+        signing_key: SigningKey,
+        config: RpcClientConfig,
+        fee_strategy: Box<dyn super::strategies::FeeStrategy>,
+        submission_strategy: Option<Box<dyn super::strategies::SubmissionStrategy>>,
+        jito_tip_lamports: Option<u64>,
+    ) -> Result<Self, AppError> {
+        Self::build(
+            rpc_url,
+            signing_key,
+            config,
+            Some(fee_strategy),
+            submission_strategy,
+            jito_tip_lamports,
+        )
+    }
+
+    /// Shared constructor; `fee_strategy` overrides the detected fee strategy
+    fn build(
+        rpc_url: &str,
+        signing_key: SigningKey,
+        config: RpcClientConfig,
+        fee_strategy: Option<Box<dyn super::strategies::FeeStrategy>>,
+        submission_strategy: Option<Box<dyn super::strategies::SubmissionStrategy>>,
+        jito_tip_lamports: Option<u64>,
     ) -> Result<Self, AppError> {
         use super::helius::{HeliusDasClient, HeliusFeeStrategy};
         use super::strategies::{FallbackFeeStrategy, QuickNodeFeeStrategy, RpcProviderType};
@@ -325,37 +664,42 @@ impl RpcBlockchainClient {
             "Detected RPC provider type"
         );
 
-        // Select fee strategy and DAS client based on provider type
-        let (fee_strategy, das_client): (
-            Box<dyn super::strategies::FeeStrategy>,
-            Option<HeliusDasClient>,
-        ) = match &provider_type {
+        // Select fee strategy based on provider type, unless one was injected
+        let fee_strategy: Box<dyn super::strategies::FeeStrategy> = match fee_strategy {
+            Some(fee_strategy) => {
+                info!(
+                    fee_strategy = %fee_strategy.name(),
+                    "Custom priority fee strategy overrides provider detection"
+                );
+                fee_strategy
+            }
+            None => match &provider_type {
+                RpcProviderType::Helius => {
+                    info!("Helius Priority Fee Strategy activated!");
+                    Box::new(HeliusFeeStrategy::new(rpc_url))
+                }
+                RpcProviderType::QuickNode => {
+                    info!("QuickNode Priority Fee Strategy activated");
+                    Box::new(QuickNodeFeeStrategy::new(rpc_url))
+                }
+                RpcProviderType::Standard => {
+                    info!("Standard RPC (fallback fee strategy)");
+                    Box::new(FallbackFeeStrategy::new())
+                }
+            },
+        };
+
+        // DAS compliance checks follow the detected provider
+        let das_client = match &provider_type {
             RpcProviderType::Helius => {
-                info!("Helius Priority Fee Strategy activated!");
                 info!("Helius DAS Check enabled");
-                (
-                    Box::new(HeliusFeeStrategy::new(rpc_url)),
-                    Some(HeliusDasClient::new(rpc_url)),
-                )
-            }
-            RpcProviderType::QuickNode => {
-                info!("QuickNode Priority Fee Strategy activated");
-                (Box::new(QuickNodeFeeStrategy::new(rpc_url)), None)
-            }
-            RpcProviderType::Standard => {
-                info!("Standard RPC (fallback fee strategy)");
-                (Box::new(FallbackFeeStrategy::new()), None)
+                Some(HeliusDasClient::new(rpc_url))
             }
+            RpcProviderType::QuickNode | RpcProviderType::Standard => None,
         };
 
         // Create Solana SDK keypair from ed25519-dalek signing key
-        let keypair_bytes = signing_key.to_keypair_bytes();
-        let keypair = Keypair::try_from(keypair_bytes.as_slice()).map_err(|e| {
-            AppError::Blockchain(BlockchainError::InvalidSignature(format!(
-                "Failed to create keypair: {}",
-                e
-            )))
-        })?;
+        let keypair = keypair_from_signing_key(&signing_key)?;
 
         // Create Solana SDK RPC client
         let sdk_client = SolanaRpcClient::new_with_timeout_and_commitment(
@@ -394,13 +738,22 @@ impl RpcBlockchainClient {
             provider: Box::new(provider),
             config,
             sdk_client: Some(sdk_client),
-            keypair: Some(keypair),
+            keypairs: vec![keypair],
+            retired_keypairs: Vec::new(),
+            key_selector: super::signers::KeySelector::default(),
             provider_type,
             fee_strategy,
             submission_strategy,
             das_client,
             rpc_url: rpc_url.to_string(),
             jito_tip_lamports,
+            dynamic_tip: None,
+            lookup_tables: tokio::sync::OnceCell::new(),
+            slot_observation: std::sync::Mutex::new(None),
+            rent_cache: std::sync::Mutex::default(),
+            mint_cache: std::sync::Mutex::default(),
+            multisig: None,
+            proposal_lock: tokio::sync::Mutex::new(()),
         })
     }
 
@@ -441,14 +794,105 @@ impl RpcBlockchainClient {
             provider,
             config,
             sdk_client: None,
-            keypair: None,
+            keypairs: Vec::new(),
+            retired_keypairs: Vec::new(),
+            key_selector: super::signers::KeySelector::default(),
             provider_type: RpcProviderType::Standard,
             fee_strategy: Box::new(FallbackFeeStrategy::new()),
             submission_strategy: None,
             das_client: None,
             rpc_url: String::new(),
             jito_tip_lamports: None,
+            dynamic_tip: None,
+            lookup_tables: tokio::sync::OnceCell::new(),
+            slot_observation: std::sync::Mutex::new(None),
+            rent_cache: std::sync::Mutex::default(),
+            mint_cache: std::sync::Mutex::default(),
+            multisig: None,
+            proposal_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Sign with `signing_keys` in addition to the primary key.
+    ///
+    /// Each transfer is pinned to one key chosen by `policy` on its first
+    /// submission (see [`BlockchainClient::select_signer`]). Not supported
+    /// with a durable nonce account, whose single authority signs every
+    /// transaction.
+    pub fn with_additional_signing_keys(
+        mut self,
+        signing_keys: &[SigningKey],
+        policy: super::signers::KeySelectionPolicy,
+    ) -> Result<Self, AppError> {
+        for signing_key in signing_keys {
+            let keypair = self.new_signer(signing_key)?;
+            self.keypairs.push(keypair);
+        }
+        self.key_selector = super::signers::KeySelector::new(policy);
+        info!(
+            keys = self.keypairs.len(),
+            policy = %policy,
+            "Multiple signing keys configured"
+        );
+        Ok(self)
+    }
+
+    /// Keep signing transfers already pinned to `signing_keys` without
+    /// selecting them for new ones, so they can be drained and removed.
+    pub fn with_retired_signing_keys(
+        mut self,
+        signing_keys: &[SigningKey],
+    ) -> Result<Self, AppError> {
+        for signing_key in signing_keys {
+            let keypair = self.new_signer(signing_key)?;
+            info!(pubkey = %keypair.pubkey(), "Retired signing key configured");
+            self.retired_keypairs.push(keypair);
+        }
+        Ok(self)
+    }
+
+    /// Keypair for an extra signing key, rejecting duplicates and durable nonce setups
+    fn new_signer(&self, signing_key: &SigningKey) -> Result<Keypair, AppError> {
+        if self.config.nonce_account.is_some() {
+            return Err(AppError::Blockchain(BlockchainError::WalletError(
+                "Multiple signing keys cannot share a durable nonce account".to_string(),
+            )));
         }
+        let keypair = keypair_from_signing_key(signing_key)?;
+        if self.find_keypair(&keypair.pubkey().to_string()).is_some() {
+            return Err(AppError::Blockchain(BlockchainError::WalletError(format!(
+                "Duplicate signing key {}",
+                keypair.pubkey()
+            ))));
+        }
+        Ok(keypair)
+    }
+
+    /// Size Jito tips dynamically instead of using the static tip amount
+    #[must_use]
+    pub fn with_dynamic_tip(mut self, strategy: super::quicknode::DynamicTipStrategy) -> Self {
+        info!(strategy = %strategy.name(), "Dynamic Jito tip sizing enabled");
+        self.dynamic_tip = Some(strategy);
+        self
+    }
+
+    /// Send public transfers from a Squads multisig vault instead of the
+    /// issuer wallet.
+    ///
+    /// Each transfer is opened as a proposal that the members approve and
+    /// execute (see [`super::squads`]); the issuer key only creates the
+    /// proposal and must be a member with the Initiate permission.
+    /// Confidential and batch transfers are not supported in this mode.
+    #[must_use]
+    pub fn with_multisig(mut self, multisig: super::squads::SquadsConfig) -> Self {
+        info!(
+            multisig = %multisig.multisig,
+            vault = %multisig.vault(),
+            vault_index = multisig.vault_index,
+            "Transfers will be proposed to a Squads multisig"
+        );
+        self.multisig = Some(multisig);
+        self
     }
 
     /// Check if this client has a submission strategy configured
@@ -463,36 +907,40 @@ impl RpcBlockchainClient {
             .is_some_and(|s| s.supports_private_submission())
     }
 
-    /// Creates a Jito tip instruction if Jito submission is enabled and configured.
-    ///
-    /// This method creates a SOL transfer instruction from the payer to a randomly
-    /// selected Jito tip account. The tip is REQUIRED for Jito bundle acceptance.
-    ///
-    /// # Returns
-    /// - `Some(Instruction)` - Tip instruction to append to the transaction
-    /// - `None` - If Jito is not enabled, not supported, or tip amount is 0
+    /// Tip to attach to the next transaction, if Jito submission is enabled.
     ///
-    /// # Best Practices
-    /// The tip instruction should be the LAST instruction in the transaction to avoid
-    /// potential issues with instruction ordering during bundle processing.
-    fn create_jito_tip_instruction(&self, payer: &Pubkey) -> Option<Instruction> {
+    /// Uses the dynamic tip strategy when configured, otherwise the static
+    /// `jito_tip_lamports`. Returns `None` when Jito is not enabled, not
+    /// supported, or the tip amount is 0.
+    async fn jito_tip_amount(&self) -> Option<u64> {
         // Only add tip if we have a Jito-enabled submission strategy
         if !self.supports_private_submission() {
             return None;
         }
 
-        let tip_lamports = self.jito_tip_lamports?;
+        let tip_lamports = match &self.dynamic_tip {
+            Some(strategy) => strategy.tip_lamports().await,
+            None => self.jito_tip_lamports?,
+        };
 
         if tip_lamports == 0 {
             debug!("Jito tip is 0, skipping tip instruction");
             return None;
         }
+        Some(tip_lamports)
+    }
 
-        // Select a random tip account to reduce contention
-        let tip_account_str = super::random_jito_tip_account();
-        let tip_account = tip_account_str
-            .parse::<Pubkey>()
-            .expect("Hardcoded Jito tip account should be valid");
+    /// Creates a Jito tip instruction for the amount from `jito_tip_amount`.
+    ///
+    /// This method creates a SOL transfer instruction from the payer to a randomly
+    /// selected Jito tip account. The tip is REQUIRED for Jito bundle acceptance.
+    ///
+    /// # Best Practices
+    /// The tip instruction should be the LAST instruction in the transaction to avoid
+    /// potential issues with instruction ordering during bundle processing.
+    fn create_jito_tip_instruction(&self, payer: &Pubkey, tip_lamports: u64) -> Instruction {
+        // Select a random tip account (from the live set, if refreshed) to reduce contention
+        let tip_account = super::random_jito_tip_account_dynamic();
 
         debug!(
             tip_lamports = tip_lamports,
@@ -500,11 +948,149 @@ impl RpcBlockchainClient {
             "Creating Jito tip instruction"
         );
 
-        Some(system_instruction::transfer(
-            payer,
-            &tip_account,
-            tip_lamports,
-        ))
+        system_instruction::transfer(payer, &tip_account, tip_lamports)
+    }
+
+    /// Build and sign a transaction for `instructions`.
+    ///
+    /// Produces a legacy transaction by default. With `use_versioned_tx`, a v0
+    /// message is compiled instead, resolving accounts through the configured
+    /// address lookup table so large instruction sets stay within size limits.
+    async fn build_signed_transaction(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        keypair: &Keypair,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> Result<VersionedTransaction, AppError> {
+        let transaction = if self.config.use_versioned_tx {
+            let lookup_tables = self
+                .lookup_tables
+                .get_or_try_init(|| self.load_lookup_tables(sdk_client))
+                .await?;
+            compile_v0_transaction(keypair, instructions, lookup_tables, recent_blockhash)?
+        } else {
+            Transaction::new_signed_with_payer(
+                instructions,
+                Some(&keypair.pubkey()),
+                &[keypair],
+                recent_blockhash,
+            )
+            .into()
+        };
+        Self::ensure_fits_packet(&transaction, "Transfer")?;
+        Ok(transaction)
+    }
+
+    /// Serialized size of a built transaction (legacy or versioned) in bytes,
+    /// as sent on the wire. Logs a warning when it is close to the 1232-byte
+    /// packet limit.
+    pub fn serialized_size<T: Serialize>(
+        transaction: &T,
+        description: &str,
+    ) -> Result<usize, AppError> {
+        let size = bincode::serde::encode_to_vec(transaction, bincode::config::legacy())
+            .map_err(|e| {
+                AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+                    "Failed to serialize transaction: {}",
+                    e
+                )))
+            })?
+            .len();
+        if size + TRANSACTION_SIZE_WARNING_MARGIN > MAX_TRANSACTION_SIZE {
+            warn!(
+                description,
+                size,
+                limit = MAX_TRANSACTION_SIZE,
+                "Transaction is near the size limit"
+            );
+        } else {
+            debug!(description, size, "Transaction size");
+        }
+        Ok(size)
+    }
+
+    /// Fail before sending if `transaction` exceeds the packet limit, which
+    /// the network would reject anyway
+    fn ensure_fits_packet<T: Serialize>(
+        transaction: &T,
+        description: &str,
+    ) -> Result<(), AppError> {
+        let size = Self::serialized_size(transaction, description)?;
+        if size > MAX_TRANSACTION_SIZE {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                format!(
+                    "{} transaction is {} bytes, over the {}-byte size limit",
+                    description, size, MAX_TRANSACTION_SIZE
+                ),
+            )));
+        }
+        Ok(())
+    }
+
+    /// Pick the blockhash for a new transaction.
+    ///
+    /// With a durable nonce account configured, an `advance_nonce_account`
+    /// instruction is prepended to `instructions` and the stored nonce value is
+    /// returned, so the transaction stays valid until the nonce is advanced.
+    /// Otherwise the latest blockhash is used.
+    async fn transaction_blockhash(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        authority: &Pubkey,
+        instructions: &mut Vec<Instruction>,
+    ) -> Result<Hash, AppError> {
+        let Some(nonce_account) = self.config.nonce_account else {
+            return sdk_client
+                .get_latest_blockhash()
+                .await
+                .map_err(map_solana_client_error);
+        };
+
+        let nonce = self.fetch_durable_nonce(sdk_client, &nonce_account).await?;
+        prepend_advance_nonce(instructions, &nonce_account, authority);
+        debug!(nonce_account = %nonce_account, nonce = %nonce, "Using durable nonce");
+        Ok(nonce)
+    }
+
+    /// Read the current nonce value stored in `nonce_account`
+    async fn fetch_durable_nonce(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        nonce_account: &Pubkey,
+    ) -> Result<Hash, AppError> {
+        let account = sdk_client
+            .get_account(nonce_account)
+            .await
+            .map_err(map_solana_client_error)?;
+        parse_durable_nonce(&account.data)
+    }
+
+    /// Fetch and decode the configured address lookup table
+    async fn load_lookup_tables(
+        &self,
+        sdk_client: &SolanaRpcClient,
+    ) -> Result<Vec<AddressLookupTableAccount>, AppError> {
+        let Some(table_key) = self.config.address_lookup_table else {
+            return Ok(Vec::new());
+        };
+
+        let account = sdk_client
+            .get_account(&table_key)
+            .await
+            .map_err(map_solana_client_error)?;
+        let addresses = parse_lookup_table_addresses(&account.data)?;
+
+        info!(
+            lookup_table = %table_key,
+            addresses = addresses.len(),
+            "Loaded address lookup table for versioned transactions"
+        );
+
+        Ok(vec![AddressLookupTableAccount {
+            key: table_key,
+            addresses,
+        }])
     }
 
     /// Get the detected provider type
@@ -523,6 +1109,31 @@ impl RpcBlockchainClient {
         self.provider.public_key()
     }
 
+    /// Keypair of `signer`, the base58 public key pinned to a transfer, or
+    /// the primary keypair without one. `None` if the pinned key is not
+    /// configured.
+    fn keypair(&self, signer: Option<&str>) -> Option<&Keypair> {
+        match signer {
+            Some(signer) => self.find_keypair(signer),
+            None => self.keypairs.first(),
+        }
+    }
+
+    /// Active or retired keypair with base58 public key `pubkey`
+    fn find_keypair(&self, pubkey: &str) -> Option<&Keypair> {
+        self.keypairs
+            .iter()
+            .chain(&self.retired_keypairs)
+            .find(|keypair| keypair.pubkey().to_string() == pubkey)
+    }
+
+    /// Base58 public key of the fee payer for transfers pinned to `signer`
+    fn payer_pubkey(&self, signer: Option<&str>) -> String {
+        self.keypair(signer)
+            .map(|keypair| keypair.pubkey().to_string())
+            .unwrap_or_else(|| self.provider.public_key())
+    }
+
     /// Sign a message and return the signature as base58
     #[must_use]
     pub fn sign(&self, message: &[u8]) -> String {
@@ -581,65 +1192,472 @@ impl RpcBlockchainClient {
     /// - QuickNode: Uses `qn_estimatePriorityFees` for global estimation
     /// - Standard: Returns a static fallback value
     ///
+    /// The estimate is clamped to `max_priority_fee_microlamports` so fee
+    /// spikes can't drive the price above the configured ceiling.
+    ///
     /// # Arguments
     /// * `serialized_tx` - Optional Base58-encoded serialized transaction
     ///   (used by Helius for per-account fee estimation)
     async fn get_priority_fee(&self, serialized_tx: Option<&str>) -> u64 {
-        self.fee_strategy.get_priority_fee(serialized_tx).await
+        let estimate = self.fee_strategy.get_priority_fee(serialized_tx).await;
+        let ceiling = self.config.max_priority_fee_microlamports;
+        if estimate > ceiling {
+            warn!(
+                strategy = %self.fee_strategy.name(),
+                estimate,
+                ceiling,
+                "Priority fee estimate above ceiling - clamping"
+            );
+            return ceiling;
+        }
+        estimate
     }
 
-    /// Legacy method for backward compatibility - calls the new strategy-based method
-    #[allow(dead_code)]
-    async fn get_quicknode_priority_fee(&self) -> u64 {
-        self.get_priority_fee(None).await
+    /// Lamport balance of `pubkey` via `getBalance`
+    async fn balance_of(&self, pubkey: &str) -> Result<u64, AppError> {
+        let params = serde_json::json!([pubkey, {"commitment": "confirmed"}]);
+        let balance: BalanceResult = self.rpc_call("getBalance", params).await?;
+        Ok(balance.value)
     }
 
-    /// Submit a transaction via the configured strategy, or fall back to SDK confirmation
-    ///
-    /// # Behavior
-    /// - **With submission strategy**: Serializes to Base58, submits via strategy, returns
-    ///   immediately. The transaction is "submitted" but not yet "confirmed". Confirmation
-    ///   should be handled via polling (`wait_for_confirmation`) or webhooks.
-    ///
-    /// - **Without submission strategy**: Uses SDK's `send_and_confirm_transaction` which
-    ///   blocks until the transaction is confirmed. Returns only after confirmation.
+    /// Rent-exempt minimum for an account holding `data_len` bytes.
     ///
-    /// # Important: "Submitted" State Resilience
-    /// When using the submission strategy path, the transaction is persisted as "Submitted"
-    /// immediately after this call returns successfully. However, the transaction may not
-    /// be indexed by RPC nodes yet. `get_transaction_status` handles this gracefully by
-    /// returning `false` (not confirmed) rather than an error for "not found" transactions.
-    async fn submit_or_confirm_transaction(
-        &self,
-        transaction: &Transaction,
-    ) -> Result<(String, String), AppError> {
-        let sdk_client = self.sdk_client.as_ref().ok_or_else(|| {
-            AppError::Blockchain(BlockchainError::TransactionFailed(
-                "SDK client not available".to_string(),
-            ))
-        })?;
+    /// Cached per size for `rent_cache_ttl`: the cluster's rent parameters
+    /// rarely change, and the same few account sizes are looked up on every
+    /// confidential transfer.
+    async fn minimum_balance_for_rent_exemption(&self, data_len: usize) -> Result<u64, AppError> {
+        let ttl = self.config.rent_cache_ttl;
+        let cached = self
+            .rent_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&data_len)
+            .copied();
+        if let Some((lamports, fetched_at)) = cached
+            && fetched_at.elapsed() < ttl
+        {
+            return Ok(lamports);
+        }
 
-        // Capture blockhash from the transaction for Jito double-spend protection (expiry checks)
-        let blockhash_str = transaction.message().recent_blockhash.to_string();
+        let lamports: u64 = self
+            .rpc_call("getMinimumBalanceForRentExemption", [data_len])
+            .await?;
+        if !ttl.is_zero() {
+            self.rent_cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(data_len, (lamports, Instant::now()));
+        }
+        Ok(lamports)
+    }
 
-        if let Some(ref strategy) = self.submission_strategy {
-            // Serialize transaction to Base58 for strategy submission
-            let serialized_tx = self.serialize_transaction_base58(transaction)?;
+    /// Lamport balance of the fee payer of `signer` via `getBalance`
+    async fn fee_payer_balance(&self, signer: Option<&str>) -> Result<u64, AppError> {
+        self.balance_of(&self.payer_pubkey(signer)).await
+    }
 
-            // Submit via strategy (Jito bundle, standard sendTransaction, etc.)
-            // The strategy handles signature extraction internally
-            let signature = strategy
-                .submit_transaction(&serialized_tx, true)
-                .await
-                .map_err(|e| wrap_error_with_blockhash(e, &blockhash_str))?;
+    /// Fail with `InsufficientFunds` if the fee payer of `signer` holds less
+    /// than `required` lamports
+    async fn ensure_payer_balance(
+        &self,
+        required: u64,
+        signer: Option<&str>,
+    ) -> Result<(), AppError> {
+        let balance = self.fee_payer_balance(signer).await?;
+        if balance < required {
+            warn!(
+                payer = %self.payer_pubkey(signer),
+                balance,
+                required,
+                "Fee payer balance too low - rejecting before submission"
+            );
+            return Err(AppError::Blockchain(BlockchainError::InsufficientFunds));
+        }
+        debug!(balance, required, "Fee payer balance sufficient");
+        Ok(())
+    }
+
+    /// Token program, decimals and amounts of a transfer of `amount` raw units of `mint_pubkey`
+    async fn plan_token_transfer(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        mint_pubkey: &Pubkey,
+        amount: u64,
+    ) -> Result<TokenTransferPlan, AppError> {
+        self.fetch_token_mint(sdk_client, mint_pubkey)
+            .await?
+            .plan(amount, self.config.gross_up_transfer_fees)
+    }
+
+    /// Read the token program, decimals and current transfer fee of `mint_pubkey`.
+    ///
+    /// Mints without a transfer fee are cached for `mint_cache_ttl`, so
+    /// repeated transfers of the same mint skip the account fetch. Fee mints
+    /// are always refetched: their fee depends on the epoch and can be updated
+    /// by the fee authority.
+    async fn fetch_token_mint(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        mint_pubkey: &Pubkey,
+    ) -> Result<TokenMintInfo, AppError> {
+        let ttl = self.config.mint_cache_ttl;
+        let cached = self
+            .mint_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(mint_pubkey)
+            .copied();
+        if let Some((token_program_id, decimals, fetched_at)) = cached
+            && fetched_at.elapsed() < ttl
+        {
+            debug!(mint = %mint_pubkey, token_program_id = %token_program_id, decimals, "Using cached mint");
+            return Ok(TokenMintInfo {
+                token_program_id,
+                decimals,
+                transfer_fee: None,
+            });
+        }
+
+        // Fetch the mint account to determine the correct token program ID and decimals
+        // This is required for transfer_checked instruction (validates decimals) and Token-2022 support
+        let mint_account = sdk_client.get_account(mint_pubkey).await.map_err(|e| {
+            AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+                "Failed to fetch mint account: {}",
+                e
+            )))
+        })?;
+
+        // The mint account's owner is the token program ID
+        let token_program_id = mint_account.owner;
+        debug!(token_program_id = %token_program_id, "Detected token program from mint");
+
+        // Extract decimals from mint account data (required for transfer_checked)
+        // Mint layout (both SPL Token and Token-2022):
+        // - bytes 0-35: mint_authority option (1 byte option flag + up to 32 bytes pubkey)
+        // - bytes 36-43: supply (u64)
+        // - byte 44: decimals (u8)
+        // - byte 45: is_initialized (bool)
+        // - bytes 46-78: freeze_authority option
+        const DECIMALS_OFFSET: usize = 44;
+        const MIN_MINT_SIZE: usize = 82;
+
+        if mint_account.data.len() < MIN_MINT_SIZE {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                format!(
+                    "Mint account data too small: {} bytes, expected at least {}",
+                    mint_account.data.len(),
+                    MIN_MINT_SIZE
+                ),
+            )));
+        }
+
+        let decimals = mint_account.data[DECIMALS_OFFSET];
+        debug!(decimals = %decimals, "Read decimals from mint account (needed for transfer_checked)");
+
+        // Token-2022 mints may withhold a transfer fee from every transfer
+        let transfer_fee = if token_program_id == spl_token_2022_interface::id() {
+            match parse_transfer_fee_config(&mint_account.data)? {
+                Some(config) => {
+                    let epoch = sdk_client
+                        .get_epoch_info()
+                        .await
+                        .map_err(map_solana_client_error)?
+                        .epoch;
+                    Some(*config.get_epoch_fee(epoch))
+                }
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        // Extensions are fixed at initialization, so a mint without a
+        // transfer fee never gains one
+        if transfer_fee.is_none() && !ttl.is_zero() {
+            self.mint_cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(*mint_pubkey, (token_program_id, decimals, Instant::now()));
+        }
+
+        Ok(TokenMintInfo {
+            token_program_id,
+            decimals,
+            transfer_fee,
+        })
+    }
+
+    /// Instructions moving the planned tokens from the `payer` to `to_pubkey`:
+    /// creating the destination ATA if it is missing, the memo, and the transfer
+    async fn token_transfer_instructions(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        payer: &Pubkey,
+        to_pubkey: &Pubkey,
+        mint_pubkey: &Pubkey,
+        plan: &TokenTransferPlan,
+        memo: Option<&str>,
+    ) -> Result<Vec<Instruction>, AppError> {
+        let token_program_id = plan.token_program_id;
+
+        // Derive Associated Token Accounts with the correct token program ID
+        let source_ata =
+            get_associated_token_address_with_program_id(payer, mint_pubkey, &token_program_id);
+        let destination_ata =
+            get_associated_token_address_with_program_id(to_pubkey, mint_pubkey, &token_program_id);
+
+        debug!(
+            source_ata = %source_ata,
+            destination_ata = %destination_ata,
+            token_program_id = %token_program_id,
+            "Derived ATAs for token transfer"
+        );
+
+        let mut instructions = Vec::new();
+
+        // Check if destination ATA exists
+        let dest_account_result = sdk_client.get_account(&destination_ata).await;
+
+        if dest_account_result.is_err() {
+            // ATA doesn't exist - create it using idempotent instruction
+            // This is safer as it won't fail if the ATA gets created between our check and execution
+            info!(destination_ata = %destination_ata, "Creating destination ATA");
+            let create_ata_ix = create_associated_token_account_idempotent(
+                payer,             // payer
+                to_pubkey,         // wallet owner
+                mint_pubkey,       // token mint
+                &token_program_id, // token program (dynamically detected)
+            );
+            instructions.push(create_ata_ix);
+        }
+
+        // Create SPL Token transfer_checked instruction for safer transfers
+        // transfer_checked validates the mint and decimals, providing better error messages
+        // Note: We pass the raw amount directly (already in token units), but still need
+        // `decimals` for the transfer_checked instruction validation. Fee-bearing mints use
+        // transfer_checked_with_fee, which also asserts the expected fee on-chain.
+        let transfer_ix = build_token_transfer_instruction(
+            &token_program_id,
+            &source_ata,
+            mint_pubkey,
+            &destination_ata,
+            payer, // authority (owner of source account)
+            plan.send_amount,
+            plan.decimals,
+            plan.expected_fee,
+        )?;
+
+        if let Some(memo) = memo {
+            instructions.push(memo_instruction(memo, payer));
+        }
+        instructions.push(transfer_ix);
+        Ok(instructions)
+    }
+
+    /// Verify that `owner`'s token account for `mint_pubkey` exists, belongs
+    /// to the token program and holds at least `required` raw units
+    async fn check_source_token_balance(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        owner: &Pubkey,
+        mint_pubkey: &Pubkey,
+        token_program_id: &Pubkey,
+        required: u64,
+    ) -> Result<(), AppError> {
+        // Derive the source ATA with the correct token program ID
+        let source_ata =
+            get_associated_token_address_with_program_id(owner, mint_pubkey, token_program_id);
+
+        let source_account = sdk_client.get_account(&source_ata).await.map_err(|e| {
+            AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+                "Source token account does not exist or cannot be fetched. \
+                 The sender ({}) does not have an associated token account for mint {}. \
+                 Error: {}",
+                owner, mint_pubkey, e
+            )))
+        })?;
+
+        // Verify the source account is owned by the token program
+        if source_account.owner != *token_program_id {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                format!(
+                    "Source token account is not owned by the token program. \
+                     Expected owner: {}, actual owner: {}",
+                    token_program_id, source_account.owner
+                ),
+            )));
+        }
+
+        if let Some(balance) = token_account_balance(&source_account.data) {
+            debug!(source_balance = %balance, required = %required, "Checking source token balance");
+            if balance < required {
+                return Err(AppError::Blockchain(BlockchainError::InsufficientFunds));
+            }
+        }
+        Ok(())
+    }
+
+    /// Signature fee of a transaction carrying `instructions` paid by `payer`,
+    /// via `getFeeForMessage` on a message built with the latest blockhash
+    async fn base_fee_for_message(
+        &self,
+        payer: &Pubkey,
+        instructions: &[Instruction],
+    ) -> Result<u64, AppError> {
+        let blockhash = self.get_latest_blockhash().await?;
+        let blockhash = Hash::from_str(&blockhash).map_err(|e| {
+            AppError::Blockchain(BlockchainError::RpcError(format!(
+                "Invalid blockhash {}: {}",
+                blockhash, e
+            )))
+        })?;
+        let message = Message::new_with_blockhash(instructions, Some(payer), &blockhash);
+        let serialized = bincode::serde::encode_to_vec(&message, bincode::config::legacy())
+            .map_err(|e| {
+                AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+                    "Failed to serialize message: {}",
+                    e
+                )))
+            })?;
+
+        let params = serde_json::json!([
+            BASE64_STANDARD.encode(serialized),
+            {"commitment": "confirmed"}
+        ]);
+        let fee: FeeForMessageResult = self.rpc_call("getFeeForMessage", params).await?;
+        fee.value.ok_or_else(|| {
+            AppError::Blockchain(BlockchainError::RpcError(
+                "getFeeForMessage returned no fee (blockhash expired)".to_string(),
+            ))
+        })
+    }
+
+    /// Asset check for providers without DAS: scan the wallet's SPL Token and
+    /// Token-2022 accounts and flag any nonzero balance of a sanctioned mint.
+    /// Returns `Ok(false)` if one is found.
+    async fn check_token_holdings(&self, owner: &str) -> Result<bool, AppError> {
+        let mut accounts_checked = 0;
+        for program_id in [spl_token_interface::id(), spl_token_2022_interface::id()] {
+            let params = serde_json::json!([
+                owner,
+                {"programId": program_id.to_string()},
+                {"encoding": "jsonParsed", "commitment": "confirmed"}
+            ]);
+            let result: TokenAccountsByOwnerResult =
+                self.rpc_call("getTokenAccountsByOwner", params).await?;
+            accounts_checked += result.value.len();
+
+            if let Some(holding) = result.value.iter().find(|keyed| {
+                let info = &keyed.account.data.parsed.info;
+                info.token_amount.amount != "0"
+                    && self
+                        .config
+                        .sanctioned_mints
+                        .iter()
+                        .any(|mint| mint.to_string() == info.mint)
+            }) {
+                warn!(
+                    wallet = %owner,
+                    sanctioned_mint = %holding.account.data.parsed.info.mint,
+                    token_account = %holding.pubkey,
+                    "Token account asset check FAILED: Wallet holds sanctioned asset"
+                );
+                return Ok(false);
+            }
+        }
+
+        info!(
+            wallet = %owner,
+            accounts_checked,
+            "Token account asset check PASSED: No sanctioned assets found"
+        );
+        Ok(true)
+    }
+
+    /// Legacy method for backward compatibility - calls the new strategy-based method
+    #[allow(dead_code)]
+    async fn get_quicknode_priority_fee(&self) -> u64 {
+        self.get_priority_fee(None).await
+    }
+
+    /// Submit a transaction via the configured strategy, or fall back to SDK confirmation
+    ///
+    /// # Behavior
+    /// - **With submission strategy**: Serializes to Base58, submits via strategy, returns
+    ///   immediately. The transaction is "submitted" but not yet "confirmed". Confirmation
+    ///   should be handled via polling (`wait_for_confirmation`) or webhooks.
+    ///
+    /// - **Without submission strategy**: Sends the transaction and blocks until it is
+    ///   confirmed. If its blockhash expires first, it is re-signed with a fresh blockhash
+    ///   and resent until `resubmit_deadline`. Transactions using a durable nonce, or
+    ///   signed by keys the client doesn't hold, are sent once with the SDK's
+    ///   `send_and_confirm_transaction`. Returns only after confirmation.
+    ///
+    /// # Important: "Submitted" State Resilience
+    /// When using the submission strategy path, the transaction is persisted as "Submitted"
+    /// immediately after this call returns successfully. However, the transaction may not
+    /// be indexed by RPC nodes yet. `get_transaction_status` handles this gracefully by
+    /// returning `false` (not confirmed) rather than an error for "not found" transactions.
+    async fn submit_or_confirm_transaction(
+        &self,
+        transaction: &VersionedTransaction,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        let sdk_client = self.sdk_client.as_ref().ok_or_else(|| {
+            AppError::Blockchain(BlockchainError::TransactionFailed(
+                "SDK client not available".to_string(),
+            ))
+        })?;
+
+        // Capture blockhash from the transaction for Jito double-spend protection (expiry checks)
+        let blockhash_str = transaction.message.recent_blockhash().to_string();
+
+        if let Some(ref strategy) = self.submission_strategy {
+            // Serialize transaction to Base58 for strategy submission
+            let serialized_tx = self.serialize_transaction_base58(transaction)?;
+
+            // Submit via strategy (Jito bundle, standard sendTransaction, etc.)
+            // The strategy handles signature extraction internally
+            let outcome = strategy
+                .submit_transaction(
+                    &serialized_tx,
+                    self.skip_preflight(options),
+                    options.jito_region,
+                )
+                .await
+                .map_err(|e| wrap_error_with_blockhash(e, &blockhash_str))?;
 
             info!(
-                signature = %signature,
+                signature = %outcome.signature,
+                bundle_id = ?outcome.bundle_id,
                 strategy = %strategy.name(),
                 "Transaction submitted via submission strategy (confirmation pending)"
             );
 
-            Ok((signature, blockhash_str))
+            Ok((outcome, blockhash_str))
+        } else if let Some(signers) = self.resubmit_signers(transaction) {
+            // No strategy - resend with a fresh blockhash until confirmed.
+            // Never done for strategies: a Jito bundle may still land after
+            // its blockhash looks expired to us.
+            let (signature, blockhash) = send_with_blockhash_refresh(
+                sdk_client,
+                transaction,
+                &signers,
+                self.config.resubmit_deadline,
+                self.config.confirmation_poll_interval,
+                self.config.confirmation_poll_max_interval,
+            )
+            .await?;
+
+            debug!(
+                signature = %signature,
+                "Transaction confirmed via standard send"
+            );
+
+            Ok((
+                SubmissionOutcome::new(signature.to_string()),
+                blockhash.to_string(),
+            ))
         } else {
             // No strategy - use SDK's blocking send_and_confirm
             let signature = sdk_client
@@ -655,14 +1673,178 @@ impl RpcBlockchainClient {
                 "Transaction confirmed via SDK send_and_confirm"
             );
 
-            Ok((signature.to_string(), blockhash_str))
+            Ok((SubmissionOutcome::new(signature.to_string()), blockhash_str))
+        }
+    }
+
+    /// Open a proposal on `multisig` to execute `instructions` from its vault.
+    ///
+    /// The vault transaction and its proposal are created together at the
+    /// multisig's next transaction index, signed and paid for by `keypair`.
+    /// Proposals of this client are created one at a time, each confirmed
+    /// before the next index is read. If another proposer takes the index
+    /// first, creation fails and is retried at the new next index, up to
+    /// [`PROPOSAL_INDEX_ATTEMPTS`] times. Returns the proposal address as the
+    /// outcome, for the crank to track until it is decided.
+    async fn propose_to_multisig(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        keypair: &Keypair,
+        multisig: &super::squads::SquadsConfig,
+        instructions: &[Instruction],
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        let message = super::squads::transaction_message_bytes(&multisig.vault(), instructions)?;
+        let _proposing = self.proposal_lock.lock().await;
+
+        let mut attempt = 1;
+        loop {
+            let transaction_index =
+                Self::multisig_transaction_index(sdk_client, multisig).await? + 1;
+            let error = match self
+                .create_proposal(
+                    sdk_client,
+                    keypair,
+                    multisig,
+                    &message,
+                    transaction_index,
+                    options,
+                )
+                .await
+            {
+                Ok(created) => return Ok(created),
+                Err(e) if is_definite_failure(&e) => e,
+                Err(e) => return Err(e),
+            };
+            let taken =
+                Self::multisig_transaction_index(sdk_client, multisig).await? >= transaction_index;
+            if !taken || attempt >= PROPOSAL_INDEX_ATTEMPTS {
+                return Err(error);
+            }
+            warn!(
+                transaction_index,
+                attempt,
+                error = %error,
+                "Multisig transaction index taken by another proposal, retrying"
+            );
+            attempt += 1;
+        }
+    }
+
+    /// Index of the multisig's latest vault transaction
+    async fn multisig_transaction_index(
+        sdk_client: &SolanaRpcClient,
+        multisig: &super::squads::SquadsConfig,
+    ) -> Result<u64, AppError> {
+        let account = sdk_client
+            .get_account(&multisig.multisig)
+            .await
+            .map_err(map_solana_client_error)?;
+        super::squads::parse_transaction_index(&account.data)
+    }
+
+    /// Create the vault transaction carrying `message` and its proposal at
+    /// `transaction_index`, and wait until the creation is confirmed
+    async fn create_proposal(
+        &self,
+        sdk_client: &SolanaRpcClient,
+        keypair: &Keypair,
+        multisig: &super::squads::SquadsConfig,
+        message: &[u8],
+        transaction_index: u64,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        let creator = keypair.pubkey();
+        let priority_fee = self.get_priority_fee(None).await;
+        let mut proposal_instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+            ComputeBudgetInstruction::set_compute_unit_limit(self.config.compute_unit_limit),
+            multisig.vault_transaction_create(&creator, transaction_index, message),
+            multisig.proposal_create(&creator, transaction_index),
+        ];
+        if let Some(tip_lamports) = self.jito_tip_amount().await {
+            proposal_instructions.push(self.create_jito_tip_instruction(&creator, tip_lamports));
+        }
+
+        let recent_blockhash = self
+            .transaction_blockhash(sdk_client, &creator, &mut proposal_instructions)
+            .await?;
+        let transaction = self
+            .build_signed_transaction(
+                sdk_client,
+                keypair,
+                &proposal_instructions,
+                recent_blockhash,
+            )
+            .await?;
+        let (created, blockhash) = self
+            .submit_or_confirm_transaction(&transaction, options)
+            .await?;
+        // A submission strategy returns before the creation lands; the next
+        // proposal may only read the index once it has
+        let timeout_secs = self.config.confirmation_timeout.as_secs();
+        self.wait_for_confirmation(&created.signature, timeout_secs)
+            .await
+            .and_then(|confirmed| {
+                confirmed.then_some(()).ok_or_else(|| {
+                    AppError::Blockchain(BlockchainError::Timeout(format!(
+                        "Multisig proposal creation {} not confirmed within {}s",
+                        created.signature, timeout_secs
+                    )))
+                })
+            })
+            .map_err(|e| wrap_error_with_blockhash(e, &blockhash))?;
+
+        let proposal = multisig.proposal(transaction_index);
+        info!(
+            proposal = %proposal,
+            transaction_index,
+            signature = %created.signature,
+            "Transfer proposed to multisig (awaiting approvals)"
+        );
+        let outcome = SubmissionOutcome::proposal(proposal.to_string())
+            .with_approval_expiry(chrono::Utc::now() + multisig.approval_timeout);
+        Ok((outcome, blockhash))
+    }
+
+    /// Keypairs to re-sign `transaction` with when its blockhash expires, in
+    /// the order of its required signers. `None` when it must not be
+    /// re-signed: resubmission is disabled, it uses a durable nonce, or a
+    /// signer's key is not held by this client.
+    fn resubmit_signers(&self, transaction: &VersionedTransaction) -> Option<Vec<&Keypair>> {
+        if self.config.resubmit_deadline.is_zero() || self.config.nonce_account.is_some() {
+            return None;
         }
+        let required = usize::from(transaction.message.header().num_required_signatures);
+        transaction
+            .message
+            .static_account_keys()
+            .iter()
+            .take(required)
+            .map(|pubkey| {
+                self.keypairs
+                    .iter()
+                    .chain(&self.retired_keypairs)
+                    .find(|keypair| keypair.pubkey() == *pubkey)
+            })
+            .collect()
+    }
+
+    /// Whether strategy submissions skip preflight: the transfer's override
+    /// in `options`, else the configured default
+    fn skip_preflight(&self, options: SubmitOptions<'_>) -> bool {
+        options.skip_preflight.unwrap_or(self.config.skip_preflight)
     }
 
     /// Serialize a signed transaction to Base58 encoding
     ///
-    /// Used for submitting transactions via the submission strategy.
-    fn serialize_transaction_base58(&self, transaction: &Transaction) -> Result<String, AppError> {
+    /// Used for submitting transactions via the submission strategy. Legacy
+    /// transactions wrapped in [`VersionedTransaction`] serialize to the same
+    /// bytes as the legacy wire format.
+    fn serialize_transaction_base58(
+        &self,
+        transaction: &VersionedTransaction,
+    ) -> Result<String, AppError> {
         let serialized = bincode::serde::encode_to_vec(transaction, bincode::config::legacy())
             .map_err(|e| {
                 AppError::Blockchain(BlockchainError::TransactionFailed(format!(
@@ -697,19 +1879,29 @@ impl RpcBlockchainClient {
         &self,
         transaction: &Transaction,
         description: &str,
+        options: SubmitOptions<'_>,
     ) -> Result<String, AppError> {
         let sdk_client = self.sdk_client.as_ref().ok_or_else(|| {
             AppError::Blockchain(BlockchainError::TransactionFailed(
                 "SDK client not available".to_string(),
             ))
         })?;
+        Self::ensure_fits_packet(transaction, description)?;
 
         if let Some(ref strategy) = self.submission_strategy {
             // Serialize transaction to Base58 for strategy submission
-            let serialized_tx = self.serialize_transaction_base58(transaction)?;
+            let serialized_tx = self
+                .serialize_transaction_base58(&VersionedTransaction::from(transaction.clone()))?;
 
             // Submit via strategy (Jito bundle, standard sendTransaction, etc.)
-            let signature = strategy.submit_transaction(&serialized_tx, true).await?;
+            let signature = strategy
+                .submit_transaction(
+                    &serialized_tx,
+                    self.skip_preflight(options),
+                    options.jito_region,
+                )
+                .await?
+                .signature;
 
             info!(
                 signature = %signature,
@@ -745,10 +1937,12 @@ impl RpcBlockchainClient {
                 .send_and_confirm_transaction(transaction)
                 .await
                 .map_err(|e| {
-                    AppError::Blockchain(BlockchainError::TransactionFailed(format!(
-                        "{}: {}",
-                        description, e
-                    )))
+                    let message = format!("{}: {}", description, e);
+                    e.get_transaction_error()
+                        .and_then(|error| account_already_in_use(&error, &message))
+                        .unwrap_or(AppError::Blockchain(BlockchainError::TransactionFailed(
+                            message,
+                        )))
                 })?;
 
             debug!(
@@ -760,48 +1954,187 @@ impl RpcBlockchainClient {
             Ok(signature.to_string())
         }
     }
-}
-
-#[async_trait]
-impl BlockchainClient for RpcBlockchainClient {
-    #[instrument(skip(self))]
-    async fn health_check(&self) -> Result<(), AppError> {
-        let _: u64 = self.rpc_call("getSlot", Vec::<()>::new()).await?;
-        Ok(())
-    }
 
-    #[instrument(skip(self))]
-    async fn submit_transaction(
+    /// Submit independent transactions as one bundle and wait for all of them
+    /// to confirm
+    ///
+    /// The submission strategy decides how the bundle is sent: a Jito bundle
+    /// when private submission is enabled, otherwise one transaction at a time.
+    /// Returns the transaction signatures in bundle order.
+    async fn submit_and_confirm_bundle(
         &self,
-        request: &TransferRequest,
-    ) -> Result<(String, String), AppError> {
-        info!(id = %request.id, "Submitting transaction for request");
-
-        // Check if we have SDK client (for real transactions)
-        if self.sdk_client.is_none() || self.keypair.is_none() {
-            // Mock implementation for testing (when SDK client not available)
+        transactions: &[Transaction],
+        description: &str,
+        options: SubmitOptions<'_>,
+    ) -> Result<Vec<String>, AppError> {
+        let strategy = self.submission_strategy.as_ref().ok_or_else(|| {
+            AppError::Blockchain(BlockchainError::TransactionFailed(
+                "Bundle submission requires a submission strategy".to_string(),
+            ))
+        })?;
+
+        let serialized_txs = transactions
+            .iter()
+            .map(|tx| self.serialize_transaction_base58(&VersionedTransaction::from(tx.clone())))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let signatures = strategy
+            .submit_bundle(&serialized_txs, options.jito_region)
+            .await?;
+
+        info!(
+            tx_count = signatures.len(),
+            strategy = %strategy.name(),
+            description = %description,
+            "Bundle submitted via strategy, waiting for confirmation..."
+        );
+
+        let confirmation_timeout_secs = self.config.confirmation_timeout.as_secs();
+        for signature in &signatures {
+            let confirmed = self
+                .wait_for_confirmation(signature, confirmation_timeout_secs)
+                .await?;
+
+            if !confirmed {
+                return Err(AppError::Blockchain(BlockchainError::Timeout(format!(
+                    "Transaction {} not confirmed within {}s: {}",
+                    signature, confirmation_timeout_secs, description
+                ))));
+            }
+        }
+
+        info!(
+            signatures = ?signatures,
+            description = %description,
+            "Bundle confirmed"
+        );
+
+        Ok(signatures)
+    }
+}
+
+#[async_trait]
+impl BlockchainClient for RpcBlockchainClient {
+    #[instrument(skip(self))]
+    async fn health_check(&self) -> Result<(), AppError> {
+        let _: u64 = self.rpc_call("getSlot", Vec::<()>::new()).await?;
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn detailed_health(&self) -> Result<BlockchainNodeHealth, AppError> {
+        let (slot, node_health, version) = tokio::join!(
+            self.rpc_call::<_, u64>("getSlot", Vec::<()>::new()),
+            self.rpc_call::<_, String>("getHealth", Vec::<()>::new()),
+            self.rpc_call::<_, NodeVersion>("getVersion", Vec::<()>::new()),
+        );
+        let slot = slot?;
+
+        let slot_lag = {
+            let now = Instant::now();
+            let mut observation = self
+                .slot_observation
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            let lag = slot_lag(*observation, slot, now);
+            // A stalled node keeps its old baseline, so its lag keeps growing
+            if observation.is_none_or(|(previous, _)| slot > previous) {
+                *observation = Some((slot, now));
+            }
+            lag
+        };
+        let node_error = node_health.err().map(|e| e.to_string());
+        let node_version = version
+            .inspect_err(|e| warn!(error = %e, "getVersion failed"))
+            .ok()
+            .map(|version| version.solana_core);
+
+        let stale = slot_lag.is_some_and(|lag| lag > self.config.max_slot_lag);
+        let status = if stale || node_error.is_some() {
+            warn!(slot, slot_lag = ?slot_lag, node_error = ?node_error, "RPC node degraded");
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        };
+        Ok(BlockchainNodeHealth {
+            status,
+            slot,
+            slot_lag,
+            node_version,
+            node_error,
+        })
+    }
+
+    #[instrument(skip(self))]
+    async fn submit_transaction(
+        &self,
+        request: &TransferRequest,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        info!(transfer_id = %request.id, "Submitting transaction for request");
+
+        // Check if we have SDK client (for real transactions)
+        if self.sdk_client.is_none() || self.keypairs.is_empty() {
+            // Mock implementation for testing (when SDK client not available)
             debug!("Using mock implementation for submit_transaction");
             let signature = self.sign(request.id.as_bytes());
             return Ok((
-                format!("tx_{}", &signature[..16]),
+                SubmissionOutcome::new(format!("tx_{}", &signature[..16])),
                 "mock_blockhash".to_string(),
             ));
         }
 
+        // Never fall back to another key: the transfer's earlier attempts were
+        // signed and paid for by the pinned one
+        if let Some(signer) = &request.signer_pubkey
+            && self.find_keypair(signer).is_none()
+        {
+            return Err(AppError::Blockchain(BlockchainError::WalletError(format!(
+                "Signing key {} pinned to transfer {} is not configured",
+                signer, request.id
+            ))));
+        }
+
+        if self.multisig.is_some()
+            && !matches!(request.transfer_details, TransferType::Public { .. })
+        {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                "Only public transfers can be proposed to a multisig".to_string(),
+            )));
+        }
+
+        let memo = request.memo.as_deref();
+        let options = SubmitOptions::for_transfer(request);
+
         // Dispatch based on TransferType
         match &request.transfer_details {
             TransferType::Public { amount } => match &request.token_mint {
                 Some(mint) => {
-                    self.transfer_token(&request.to_address, mint, *amount)
+                    self.transfer_token(&request.to_address, mint, *amount, memo, options)
+                        .await
+                }
+                None => {
+                    self.transfer_sol(&request.to_address, *amount, memo, options)
                         .await
                 }
-                None => self.transfer_sol(&request.to_address, *amount).await,
             },
+            TransferType::PublicBatch { recipients } => {
+                let mint = request.token_mint.as_ref().ok_or_else(|| {
+                    AppError::Validation(crate::domain::ValidationError::InvalidField {
+                        field: "token_mint".to_string(),
+                        message: "Token mint is required for batch transfers".to_string(),
+                    })
+                })?;
+                self.transfer_token_batch(mint, recipients, memo, options)
+                    .await
+            }
             TransferType::Confidential {
                 new_decryptable_available_balance,
                 equality_proof,
                 ciphertext_validity_proof,
                 range_proof,
+                auditor_elgamal_pubkey,
+                fee_sigma_proof,
+                fee_ciphertext_validity_proof,
             } => {
                 let mint = request.token_mint.as_ref().ok_or_else(|| {
                     AppError::Validation(crate::domain::ValidationError::InvalidField {
@@ -817,6 +2150,11 @@ impl BlockchainClient for RpcBlockchainClient {
                     equality_proof,
                     ciphertext_validity_proof,
                     range_proof,
+                    auditor_elgamal_pubkey.as_deref(),
+                    fee_sigma_proof.as_deref(),
+                    fee_ciphertext_validity_proof.as_deref(),
+                    memo,
+                    options,
                 )
                 .await
             }
@@ -833,13 +2171,34 @@ impl BlockchainClient for RpcBlockchainClient {
     /// 1. Transaction 1: Verify all ZK proofs via separate verification instructions
     /// 2. Transaction 2: Execute ConfidentialTransfer::TransferWithSplitProofs
     ///
+    /// With Jito enabled, the equality, validity and range-record transactions
+    /// are independent and go out as a single bundle; otherwise each one is
+    /// submitted and confirmed in turn.
+    ///
+    /// The recipient's token account must already be configured for
+    /// confidential transfers; this is checked before any proof is submitted.
+    ///
+    /// Mints with the confidential transfer fee extension take a
+    /// `TransferWithFee` instead: the fee sigma and fee ciphertext validity
+    /// proofs are verified into two more context accounts, and the range
+    /// proof is a U256 one covering the fee.
+    ///
     /// # Arguments
     /// * `to_address` - Destination wallet (Base58)
     /// * `token_mint` - Token-2022 mint with confidential extensions (Base58)
     /// * `new_decryptable_available_balance_base64` - AES-encrypted balance (Base64)
     /// * `equality_proof_base64` - CiphertextCommitmentEqualityProofData (Base64)
     /// * `ciphertext_validity_proof_base64` - BatchedGroupedCiphertext3HandlesValidityProofData (Base64)
-    /// * `range_proof_base64` - BatchedRangeProofU128Data (Base64)
+    /// * `range_proof_base64` - BatchedRangeProofU128Data, or BatchedRangeProofU256Data
+    ///   for fee mints (Base64)
+    /// * `auditor_elgamal_pubkey_base64` - Auditor ElGamal pubkey (Base64), required
+    ///   when the mint has an auditor
+    /// * `fee_sigma_proof_base64` - PercentageWithCapProofData (Base64), required
+    ///   when the mint has a confidential transfer fee
+    /// * `fee_ciphertext_validity_proof_base64` - BatchedGroupedCiphertext2HandlesValidityProofData
+    ///   (Base64), required along with the fee sigma proof
+    /// * `memo` - Optional memo attached to the transfer transaction
+    #[allow(clippy::too_many_arguments)]
     #[instrument(skip(self))]
     async fn transfer_confidential(
         &self,
@@ -849,14 +2208,19 @@ impl BlockchainClient for RpcBlockchainClient {
         equality_proof_base64: &str,
         ciphertext_validity_proof_base64: &str,
         range_proof_base64: &str,
-    ) -> Result<(String, String), AppError> {
+        auditor_elgamal_pubkey_base64: Option<&str>,
+        fee_sigma_proof_base64: Option<&str>,
+        fee_ciphertext_validity_proof_base64: Option<&str>,
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
         info!(
             to = %to_address,
             token_mint = %token_mint,
             "Processing confidential transfer with split proof verification"
         );
 
-        let keypair = self.keypair.as_ref().ok_or_else(|| {
+        let keypair = self.keypair(options.signer).ok_or_else(|| {
             AppError::Blockchain(BlockchainError::WalletError(
                 "No keypair available for signing".to_string(),
             ))
@@ -882,10 +2246,20 @@ impl BlockchainClient for RpcBlockchainClient {
         // Decode each proof component from Base64
         let new_decryptable_balance = BASE64_STANDARD
             .decode(new_decryptable_available_balance_base64)
-            .map_err(|e| {
+            .map_err(|e| format!("Invalid base64 encoding: {}", e))
+            .and_then(|bytes| {
+                bytemuck::try_pod_read_unaligned::<DecryptableBalance>(&bytes).map_err(|_| {
+                    format!(
+                        "Expected {} bytes, got {}",
+                        std::mem::size_of::<DecryptableBalance>(),
+                        bytes.len()
+                    )
+                })
+            })
+            .map_err(|message| {
                 AppError::Validation(crate::domain::ValidationError::InvalidField {
                     field: "new_decryptable_available_balance".to_string(),
-                    message: format!("Invalid base64 encoding: {}", e),
+                    message,
                 })
             })?;
 
@@ -912,11 +2286,49 @@ impl BlockchainClient for RpcBlockchainClient {
             })
         })?;
 
+        let requested_auditor = auditor_elgamal_pubkey_base64
+            .map(|key| {
+                BASE64_STANDARD
+                    .decode(key)
+                    .ok()
+                    .and_then(|bytes| <[u8; ELGAMAL_PUBKEY_LEN]>::try_from(bytes).ok())
+                    .ok_or_else(|| {
+                        AppError::Validation(crate::domain::ValidationError::InvalidField {
+                            field: "auditor_elgamal_pubkey".to_string(),
+                            message: "Expected a Base64-encoded 32-byte ElGamal pubkey".to_string(),
+                        })
+                    })
+            })
+            .transpose()?;
+
+        let fee_sigma_proof = fee_sigma_proof_base64
+            .map(|proof| {
+                BASE64_STANDARD.decode(proof).map_err(|e| {
+                    AppError::Validation(crate::domain::ValidationError::InvalidField {
+                        field: "fee_sigma_proof".to_string(),
+                        message: format!("Invalid base64 encoding: {}", e),
+                    })
+                })
+            })
+            .transpose()?;
+
+        let fee_ciphertext_validity_proof = fee_ciphertext_validity_proof_base64
+            .map(|proof| {
+                BASE64_STANDARD.decode(proof).map_err(|e| {
+                    AppError::Validation(crate::domain::ValidationError::InvalidField {
+                        field: "fee_ciphertext_validity_proof".to_string(),
+                        message: format!("Invalid base64 encoding: {}", e),
+                    })
+                })
+            })
+            .transpose()?;
+
         debug!(
-            balance_bytes = new_decryptable_balance.len(),
             equality_proof_bytes = equality_proof.len(),
             validity_proof_bytes = ciphertext_validity_proof.len(),
             range_proof_bytes = range_proof.len(),
+            fee_sigma_proof_bytes = fee_sigma_proof.as_ref().map(Vec::len),
+            fee_validity_proof_bytes = fee_ciphertext_validity_proof.as_ref().map(Vec::len),
             "Decoded confidential transfer proof components"
         );
 
@@ -1001,18 +2413,16 @@ impl BlockchainClient for RpcBlockchainClient {
             "Calculated proof context state sizes"
         );
 
-        let equality_rent = sdk_client
-            .get_minimum_balance_for_rent_exemption(equality_context_size)
-            .await
-            .map_err(map_solana_client_error)?;
-        let validity_rent = sdk_client
-            .get_minimum_balance_for_rent_exemption(validity_context_size)
-            .await
-            .map_err(map_solana_client_error)?;
-        let range_rent = sdk_client
-            .get_minimum_balance_for_rent_exemption(range_context_size)
-            .await
-            .map_err(map_solana_client_error)?;
+        // Cached by size, so repeated transfers don't re-query these fixed sizes
+        let equality_rent = self
+            .minimum_balance_for_rent_exemption(equality_context_size)
+            .await?;
+        let validity_rent = self
+            .minimum_balance_for_rent_exemption(validity_context_size)
+            .await?;
+        let range_rent = self
+            .minimum_balance_for_rent_exemption(range_context_size)
+            .await?;
 
         debug!(
             equality_ctx = %equality_context_pubkey,
@@ -1061,30 +2471,16 @@ impl BlockchainClient for RpcBlockchainClient {
         let equality_verify_ix = ProofInstruction::VerifyCiphertextCommitmentEquality
             .encode_verify_proof(Some(equality_context_info), equality_proof_data);
 
-        let equality_tx_instructions = vec![
-            ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
-            ComputeBudgetInstruction::set_compute_unit_limit(200_000),
-            create_equality_ctx_ix, // CREATE first
-            equality_verify_ix,     // VERIFY second
-        ];
-
-        let recent_blockhash = sdk_client
-            .get_latest_blockhash()
-            .await
-            .map_err(map_solana_client_error)?;
-
-        let equality_tx = Transaction::new_signed_with_payer(
-            &equality_tx_instructions,
-            Some(&keypair.pubkey()),
-            &[keypair, &equality_context_keypair], // Context keypair must sign create_account
-            recent_blockhash,
-        );
-
-        // Use submission strategy if available (MEV-protected) and wait for confirmation
-        self.submit_and_confirm_transaction(&equality_tx, "Equality proof verification")
-            .await?;
-
-        info!("Equality proof verified and context state created");
+        let equality_tx = ProofTransaction {
+            description: "Equality proof verification",
+            instructions: vec![
+                ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                create_equality_ctx_ix, // CREATE first
+                equality_verify_ix,     // VERIFY second
+            ],
+            signers: vec![&equality_context_keypair], // Context keypair must sign create_account
+        };
 
         // ====================================================================
         // TRANSACTION 2: Create Account + Verify Validity Proof
@@ -1109,6 +2505,50 @@ impl BlockchainClient for RpcBlockchainClient {
                 })
             })?;
 
+        // A mint with an auditor rejects transfers the auditor cannot decrypt:
+        // check the request and the proof against it before paying for any proof
+        let mint_account = sdk_client
+            .get_account(&mint_pubkey)
+            .await
+            .map_err(map_solana_client_error)?;
+        let mint_auditor = parse_confidential_auditor(&mint_account.data)?;
+        let validity_context = &validity_proof_data.context;
+        check_confidential_auditor(
+            mint_auditor,
+            requested_auditor,
+            bytemuck::cast(validity_context.third_pubkey),
+        )?;
+        let auditor_ciphertexts = auditor_ciphertexts(validity_context)?;
+
+        // A mint with a confidential transfer fee only accepts TransferWithFee,
+        // which needs the fee proofs; other mints only accept a plain Transfer
+        let fee_proofs = check_confidential_transfer_fee(
+            parse_confidential_transfer_fee(&mint_account.data)?,
+            fee_sigma_proof
+                .as_deref()
+                .zip(fee_ciphertext_validity_proof.as_deref()),
+        )?;
+
+        // Likewise, only a configured and approved destination account can
+        // receive the transfer: check it before paying for any proof
+        let destination_account = sdk_client
+            .get_account_with_commitment(&destination_ata, sdk_client.commitment())
+            .await
+            .map_err(map_solana_client_error)?
+            .value;
+        let destination_state = parse_confidential_destination(
+            destination_account
+                .as_ref()
+                .map(|account| account.data.as_slice()),
+        )?;
+        let approve_destination_ix = confidential_destination_approval(
+            destination_state,
+            parse_confidential_transfer_authority(&mint_account.data)?,
+            &keypair.pubkey(),
+            &destination_ata,
+            &mint_pubkey,
+        )?;
+
         let validity_ctx_address =
             solana_sdk::pubkey::Pubkey::from(validity_context_pubkey.to_bytes());
         let validity_context_info = ContextStateInfo {
@@ -1119,30 +2559,117 @@ impl BlockchainClient for RpcBlockchainClient {
         let validity_verify_ix = ProofInstruction::VerifyBatchedGroupedCiphertext3HandlesValidity
             .encode_verify_proof(Some(validity_context_info), validity_proof_data);
 
-        let validity_tx_instructions = vec![
-            ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
-            ComputeBudgetInstruction::set_compute_unit_limit(200_000),
-            create_validity_ctx_ix, // CREATE first
-            validity_verify_ix,     // VERIFY second
-        ];
+        let validity_tx = ProofTransaction {
+            description: "Ciphertext validity proof verification",
+            instructions: vec![
+                ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+                ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                create_validity_ctx_ix, // CREATE first
+                validity_verify_ix,     // VERIFY second
+            ],
+            signers: vec![&validity_context_keypair], // Context keypair must sign
+        };
 
-        let recent_blockhash = sdk_client
-            .get_latest_blockhash()
-            .await
-            .map_err(map_solana_client_error)?;
+        // ====================================================================
+        // TRANSACTIONS 2A & 2B: Fee Sigma and Fee Ciphertext Validity Proofs
+        // ====================================================================
+        // Only for mints with the confidential transfer fee extension. Both
+        // proofs are small enough to be verified inline, like the ones above.
+        let fee_sigma_context_keypair = Keypair::new();
+        let fee_validity_context_keypair = Keypair::new();
+        let fee_context_pubkeys = fee_proofs.map(|_| {
+            [
+                fee_sigma_context_keypair.pubkey(),
+                fee_validity_context_keypair.pubkey(),
+            ]
+        });
 
-        let validity_tx = Transaction::new_signed_with_payer(
-            &validity_tx_instructions,
-            Some(&keypair.pubkey()),
-            &[keypair, &validity_context_keypair], // Context keypair must sign
-            recent_blockhash,
-        );
+        let mut fee_proof_transactions = Vec::new();
+        if let Some((fee_sigma_proof, fee_ciphertext_validity_proof)) = fee_proofs {
+            info!("Transaction 2A/2B: Verifying fee sigma and fee ciphertext validity proofs");
 
-        // Use submission strategy if available (MEV-protected) and wait for confirmation
-        self.submit_and_confirm_transaction(&validity_tx, "Ciphertext validity proof verification")
-            .await?;
+            let fee_sigma_proof_data: &PercentageWithCapProofData =
+                bytemuck::try_from_bytes(fee_sigma_proof).map_err(|e| {
+                    AppError::Validation(crate::domain::ValidationError::InvalidField {
+                        field: "fee_sigma_proof".to_string(),
+                        message: format!("Invalid proof data format: {}", e),
+                    })
+                })?;
+            let fee_validity_proof_data: &BatchedGroupedCiphertext2HandlesValidityProofData =
+                bytemuck::try_from_bytes(fee_ciphertext_validity_proof).map_err(|e| {
+                    AppError::Validation(crate::domain::ValidationError::InvalidField {
+                        field: "fee_ciphertext_validity_proof".to_string(),
+                        message: format!("Invalid proof data format: {}", e),
+                    })
+                })?;
 
-        info!("Validity proof verified and context state created");
+            let fee_sigma_context_size =
+                std::mem::size_of::<ProofContextState<PercentageWithCapProofContext>>();
+            let fee_validity_context_size = std::mem::size_of::<
+                ProofContextState<BatchedGroupedCiphertext2HandlesValidityProofContext>,
+            >();
+            let fee_sigma_rent = self
+                .minimum_balance_for_rent_exemption(fee_sigma_context_size)
+                .await?;
+            let fee_validity_rent = self
+                .minimum_balance_for_rent_exemption(fee_validity_context_size)
+                .await?;
+
+            let fee_sigma_ctx_address =
+                solana_sdk::pubkey::Pubkey::from(fee_sigma_context_keypair.pubkey().to_bytes());
+            let fee_sigma_verify_ix = ProofInstruction::VerifyPercentageWithCap
+                .encode_verify_proof(
+                    Some(ContextStateInfo {
+                        context_state_account: &fee_sigma_ctx_address,
+                        context_state_authority: &authority_address,
+                    }),
+                    fee_sigma_proof_data,
+                );
+            fee_proof_transactions.push(ProofTransaction {
+                description: "Fee sigma proof verification",
+                instructions: vec![
+                    ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+                    ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                    system_instruction::create_account(
+                        &keypair.pubkey(),
+                        &fee_sigma_context_keypair.pubkey(),
+                        fee_sigma_rent,
+                        fee_sigma_context_size as u64,
+                        &zk_elgamal_proof_program,
+                    ),
+                    fee_sigma_verify_ix,
+                ],
+                signers: vec![&fee_sigma_context_keypair],
+            });
+
+            let fee_validity_ctx_address =
+                solana_sdk::pubkey::Pubkey::from(fee_validity_context_keypair.pubkey().to_bytes());
+            let fee_validity_verify_ix =
+                ProofInstruction::VerifyBatchedGroupedCiphertext2HandlesValidity
+                    .encode_verify_proof(
+                        Some(ContextStateInfo {
+                            context_state_account: &fee_validity_ctx_address,
+                            context_state_authority: &authority_address,
+                        }),
+                        fee_validity_proof_data,
+                    );
+            fee_proof_transactions.push(ProofTransaction {
+                description: "Fee ciphertext validity proof verification",
+                instructions: vec![
+                    ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+                    ComputeBudgetInstruction::set_compute_unit_limit(200_000),
+                    system_instruction::create_account(
+                        &keypair.pubkey(),
+                        &fee_validity_context_keypair.pubkey(),
+                        fee_validity_rent,
+                        fee_validity_context_size as u64,
+                        &zk_elgamal_proof_program,
+                    ),
+                    fee_validity_verify_ix,
+                ],
+                signers: vec![&fee_validity_context_keypair],
+            });
+        }
 
         // ====================================================================
         // TRANSACTION 3A & 3B: Record-Based Range Proof Verification
@@ -1154,14 +2681,21 @@ impl BlockchainClient for RpcBlockchainClient {
         // ====================================================================
         info!("Transaction 3: Verifying range proof (record-based for large proof)");
 
-        // Parse the range proof data first to validate it
-        let _range_proof_data: &BatchedRangeProofU128Data = bytemuck::try_from_bytes(&range_proof)
-            .map_err(|e| {
-                AppError::Validation(crate::domain::ValidationError::InvalidField {
-                    field: "range_proof".to_string(),
-                    message: format!("Invalid proof data format: {}", e),
-                })
-            })?;
+        // Parse the range proof data first to validate it. A fee transfer
+        // proves the fee ranges too, which takes the larger U256 proof.
+        let range_verify_instruction = if fee_proofs.is_some() {
+            bytemuck::try_from_bytes::<BatchedRangeProofU256Data>(&range_proof)
+                .map(|_| ProofInstruction::VerifyBatchedRangeProofU256)
+        } else {
+            bytemuck::try_from_bytes::<BatchedRangeProofU128Data>(&range_proof)
+                .map(|_| ProofInstruction::VerifyBatchedRangeProofU128)
+        }
+        .map_err(|e| {
+            AppError::Validation(crate::domain::ValidationError::InvalidField {
+                field: "range_proof".to_string(),
+                message: format!("Invalid proof data format: {}", e),
+            })
+        })?;
 
         // Create a keypair for the proof record account
         let range_proof_record_keypair = Keypair::new();
@@ -1172,10 +2706,9 @@ impl BlockchainClient for RpcBlockchainClient {
         const RECORD_HEADER_SIZE: usize = 32;
         let record_data_size = RECORD_HEADER_SIZE + range_proof.len();
 
-        let range_proof_record_rent = sdk_client
-            .get_minimum_balance_for_rent_exemption(record_data_size)
-            .await
-            .map_err(map_solana_client_error)?;
+        let range_proof_record_rent = self
+            .minimum_balance_for_rent_exemption(record_data_size)
+            .await?;
 
         debug!(
             range_proof_data_size = range_proof.len(),
@@ -1213,31 +2746,67 @@ impl BlockchainClient for RpcBlockchainClient {
         );
 
         // Combine create + initialize + write in one transaction
-        let recent_blockhash = sdk_client
-            .get_latest_blockhash()
-            .await
-            .map_err(map_solana_client_error)?;
-
-        let create_and_write_record_tx = Transaction::new_signed_with_payer(
-            &[
+        let create_and_write_record_tx = ProofTransaction {
+            description: "Create and write range proof record",
+            instructions: vec![
                 ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
                 create_record_account_ix,
                 initialize_record_ix,
                 write_record_ix,
             ],
-            Some(&keypair.pubkey()),
-            &[keypair, &range_proof_record_keypair],
-            recent_blockhash,
-        );
+            signers: vec![&range_proof_record_keypair],
+        };
 
-        // Use submission strategy if available (MEV-protected) and wait for confirmation
-        self.submit_and_confirm_transaction(
-            &create_and_write_record_tx,
-            "Create and write range proof record",
-        )
-        .await?;
+        // The equality, validity (and fee proof) and record transactions are
+        // independent of each other; only the range verification and the
+        // transfer need them. With the fee proofs that is five transactions,
+        // the most a Jito bundle takes.
+        let mut proof_transactions = vec![equality_tx, validity_tx];
+        proof_transactions.extend(fee_proof_transactions);
+        proof_transactions.push(create_and_write_record_tx);
+
+        // Every transaction before the transfer, so webhooks for them are
+        // recognized without confirming it
+        let mut proof_signatures = Vec::with_capacity(proof_transactions.len() + 1);
+        if self.supports_private_submission() {
+            // One Jito bundle: the proof transactions land together (or not at
+            // all) with a single confirmation wait, and the last one pays the tip
+            let tip = self.jito_tip_amount().await.map(|tip_lamports| {
+                self.create_jito_tip_instruction(&keypair.pubkey(), tip_lamports)
+            });
+            let recent_blockhash = sdk_client
+                .get_latest_blockhash()
+                .await
+                .map_err(map_solana_client_error)?;
+            let bundle = assemble_proof_bundle(&proof_transactions, keypair, recent_blockhash, tip);
+            proof_signatures.extend(
+                self.submit_and_confirm_bundle(&bundle, "Proof verification bundle", options)
+                    .await?,
+            );
+        } else {
+            for proof_tx in &proof_transactions {
+                let recent_blockhash = sdk_client
+                    .get_latest_blockhash()
+                    .await
+                    .map_err(map_solana_client_error)?;
+                let transaction = proof_tx.sign(keypair, recent_blockhash);
+
+                // Use submission strategy if available and wait for confirmation
+                proof_signatures.push(
+                    self.submit_and_confirm_transaction(
+                        &transaction,
+                        proof_tx.description,
+                        options,
+                    )
+                    .await?,
+                );
+            }
+        }
 
-        info!("Range proof record account created and data written");
+        info!(
+            fee_proofs = fee_proofs.is_some(),
+            "Equality and validity proofs verified, range proof record written"
+        );
 
         // TRANSACTION 3B: Create context account + verify from record
         let create_range_ctx_ix = system_instruction::create_account(
@@ -1258,7 +2827,7 @@ impl BlockchainClient for RpcBlockchainClient {
         // The spl-record account has a 32-byte authority header, so offset = 32
         let range_proof_record_address =
             solana_sdk::pubkey::Pubkey::from(range_proof_record_pubkey.to_bytes());
-        let range_verify_from_account_ix = ProofInstruction::VerifyBatchedRangeProofU128
+        let range_verify_from_account_ix = range_verify_instruction
             .encode_verify_proof_from_account(
                 Some(range_context_info),
                 &range_proof_record_address,
@@ -1285,8 +2854,10 @@ impl BlockchainClient for RpcBlockchainClient {
         );
 
         // Use submission strategy if available (MEV-protected) and wait for confirmation
-        self.submit_and_confirm_transaction(&range_tx, "Range proof verification")
-            .await?;
+        proof_signatures.push(
+            self.submit_and_confirm_transaction(&range_tx, "Range proof verification", options)
+                .await?,
+        );
 
         info!("Range proof verified and context state created");
 
@@ -1295,71 +2866,54 @@ impl BlockchainClient for RpcBlockchainClient {
         // ====================================================================
         info!("Transaction 4: Executing confidential transfer");
 
-        // Check if destination ATA exists, create if needed
-        let dest_account_result = sdk_client.get_account(&destination_ata).await;
         let mut transfer_instructions: Vec<Instruction> = vec![
             ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
             ComputeBudgetInstruction::set_compute_unit_limit(600_000), // Increased for close instructions
         ];
 
-        if dest_account_result.is_err() {
-            info!(destination_ata = %destination_ata, "Creating destination ATA");
-            let create_ata_ix = create_associated_token_account_idempotent(
-                &keypair.pubkey(),
-                &to_pubkey,
-                &mint_pubkey,
-                &token_program_id,
-            );
-            transfer_instructions.push(create_ata_ix);
+        // The destination was checked above; approve it if it still awaits
+        // approval and the relayer is the mint's confidential transfer authority
+        if let Some(approve_ix) = approve_destination_ix {
+            info!(destination_ata = %destination_ata, "Approving destination confidential account");
+            transfer_instructions.push(approve_ix);
         }
 
-        // ====================================================================
-        // BUILD CONFIDENTIAL TRANSFER INSTRUCTION
-        // ====================================================================
-        // Token-2022 extension instruction format:
-        //   Byte 0: TokenInstruction discriminator (we use the extension approach)
-        //   Remaining: Extension-specific data
-        //
-        // For confidential transfer with split proofs, we use:
-        //   [26] = ConfidentialTransferExtension (Token-2022 extension instruction)
-        //   [7]  = Transfer (sub-instruction within confidential transfer)
-        //
-        // Account order per SPL Token-2022 ConfidentialTransferInstruction::Transfer spec:
-        //   0. [writable] Source token account
-        //   1. []         Token mint
-        //   2. [writable] Destination token account
-        //   3. []         Equality proof context account
-        //   4. []         Ciphertext validity proof context account
-        //   5. []         Range proof context account
-        //   6. [signer]   Authority (owner of source account)
-
-        // Build instruction data: [extension_discriminator | sub_instruction | transfer_data]
-        let mut instruction_data = Vec::new();
-        instruction_data.push(26u8); // ConfidentialTransferExtension
-        instruction_data.push(7u8); // Transfer sub-instruction
-        instruction_data.extend_from_slice(&new_decryptable_balance);
-
-        let transfer_ix = Instruction {
-            program_id: token_program_id,
-            accounts: vec![
-                // 0. Source token account (writable)
-                solana_sdk::instruction::AccountMeta::new(source_ata, false),
-                // 1. Mint (readonly)
-                solana_sdk::instruction::AccountMeta::new_readonly(mint_pubkey, false),
-                // 2. Destination token account (writable)
-                solana_sdk::instruction::AccountMeta::new(destination_ata, false),
-                // 3. Equality proof context (readonly) - pre-verified
-                solana_sdk::instruction::AccountMeta::new_readonly(equality_context_pubkey, false),
-                // 4. Ciphertext validity proof context (readonly)
-                solana_sdk::instruction::AccountMeta::new_readonly(validity_context_pubkey, false),
-                // 5. Range proof context (readonly)
-                solana_sdk::instruction::AccountMeta::new_readonly(range_context_pubkey, false),
-                // 6. Authority (signer) - owner of source account
-                solana_sdk::instruction::AccountMeta::new_readonly(keypair.pubkey(), true),
-            ],
-            data: instruction_data,
+        let transfer_ix = match fee_context_pubkeys {
+            Some([fee_sigma_context_pubkey, fee_validity_context_pubkey]) => {
+                confidential_transfer_with_fee_instruction(
+                    &source_ata,
+                    &mint_pubkey,
+                    &destination_ata,
+                    [
+                        equality_context_pubkey,
+                        validity_context_pubkey,
+                        fee_sigma_context_pubkey,
+                        fee_validity_context_pubkey,
+                        range_context_pubkey,
+                    ],
+                    &keypair.pubkey(),
+                    &new_decryptable_balance,
+                    &auditor_ciphertexts,
+                )?
+            }
+            None => confidential_transfer_instruction(
+                &source_ata,
+                &mint_pubkey,
+                &destination_ata,
+                [
+                    equality_context_pubkey,
+                    validity_context_pubkey,
+                    range_context_pubkey,
+                ],
+                &keypair.pubkey(),
+                &new_decryptable_balance,
+                &auditor_ciphertexts,
+            )?,
         };
 
+        if let Some(memo) = memo {
+            transfer_instructions.push(memo_instruction(memo, &keypair.pubkey()));
+        }
         transfer_instructions.push(transfer_ix);
 
         // ====================================================================
@@ -1394,6 +2948,16 @@ impl BlockchainClient for RpcBlockchainClient {
         let close_range_ctx_ix = close_context_state(range_ctx_info, &destination_pubkey);
         transfer_instructions.push(close_range_ctx_ix);
 
+        // Close fee sigma and fee ciphertext validity proof contexts
+        for fee_context_pubkey in fee_context_pubkeys.into_iter().flatten() {
+            let fee_ctx_address = solana_sdk::pubkey::Pubkey::from(fee_context_pubkey.to_bytes());
+            let fee_ctx_info = ContextStateInfo {
+                context_state_account: &fee_ctx_address,
+                context_state_authority: &authority_address,
+            };
+            transfer_instructions.push(close_context_state(fee_ctx_info, &destination_pubkey));
+        }
+
         // Close range proof record account (spl_record)
         let close_record_ix = spl_record::instruction::close_account(
             &range_proof_record_pubkey,
@@ -1406,45 +2970,48 @@ impl BlockchainClient for RpcBlockchainClient {
             equality_ctx = %equality_context_pubkey,
             validity_ctx = %validity_context_pubkey,
             range_ctx = %range_context_pubkey,
+            fee_ctxs = ?fee_context_pubkeys,
             range_record = %range_proof_record_pubkey,
             "Added close instructions for all context accounts"
         );
 
-        // Append Jito tip instruction to FINAL transfer transaction only
-        // (not to the proof verification transactions)
-        if let Some(tip_ix) = self.create_jito_tip_instruction(&keypair.pubkey()) {
+        // The transfer goes out on its own after the proof bundle, so it pays
+        // its own Jito tip
+        let jito_tip = self.jito_tip_amount().await;
+        if let Some(tip_lamports) = jito_tip {
             info!(
-                tip_lamports = self.jito_tip_lamports.unwrap_or(0),
+                tip_lamports,
                 "Appending Jito tip instruction to confidential transfer"
             );
-            transfer_instructions.push(tip_ix);
+            transfer_instructions
+                .push(self.create_jito_tip_instruction(&keypair.pubkey(), tip_lamports));
         }
 
-        let recent_blockhash = sdk_client
-            .get_latest_blockhash()
-            .await
-            .map_err(map_solana_client_error)?;
+        let recent_blockhash = self
+            .transaction_blockhash(sdk_client, &keypair.pubkey(), &mut transfer_instructions)
+            .await?;
 
-        let transfer_tx = Transaction::new_signed_with_payer(
-            &transfer_instructions,
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
+        // The final transfer references the most accounts, so it benefits from
+        // a v0 message with lookup table compaction when enabled
+        let transfer_tx = self
+            .build_signed_transaction(
+                sdk_client,
+                keypair,
+                &transfer_instructions,
+                recent_blockhash,
+            )
+            .await?;
 
         info!(
             via_strategy = self.submission_strategy.is_some(),
-            jito_tip = self
-                .jito_tip_lamports
-                .filter(|_| self.supports_private_submission()),
-            "Sending confidential transfer transaction"
+            jito_tip, "Sending confidential transfer transaction"
         );
 
         // For the final transfer, we can use submit_or_confirm_transaction
         // (doesn't need to wait for subsequent transactions)
         // But for consistency with MEV protection, we use the strategy if available
-        let (signature, blockhash) = self
-            .submit_or_confirm_transaction(&transfer_tx)
+        let (outcome, blockhash) = self
+            .submit_or_confirm_transaction(&transfer_tx, options)
             .await
             .map_err(|e| {
                 let msg = e.to_string();
@@ -1464,14 +3031,14 @@ impl BlockchainClient for RpcBlockchainClient {
             })?;
 
         info!(
-            signature = %signature,
+            signature = %outcome.signature,
             to = %to_address,
             token_mint = %token_mint,
             via_strategy = self.submission_strategy.is_some(),
             "Confidential transfer with split proofs completed successfully"
         );
 
-        Ok((signature, blockhash))
+        Ok((outcome.with_proof_signatures(proof_signatures), blockhash))
     }
 
     #[instrument(skip(self))]
@@ -1515,11 +3082,16 @@ impl BlockchainClient for RpcBlockchainClient {
         signature: &str,
         timeout_secs: u64,
     ) -> Result<bool, AppError> {
-        let timeout = Duration::from_secs(timeout_secs);
-        let start = std::time::Instant::now();
-        let poll_interval = Duration::from_millis(500);
-
-        while start.elapsed() < timeout {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(timeout_secs);
+        let max_interval = self
+            .config
+            .confirmation_poll_max_interval
+            .max(self.config.confirmation_poll_interval);
+        let mut poll_interval = self.config.confirmation_poll_interval;
+
+        // Polls once more at the deadline, so a transaction that landed
+        // during the last sleep is not reported as timed out
+        loop {
             match self.get_transaction_status(signature).await {
                 Ok(true) => {
                     info!(signature = %signature, "Transaction confirmed");
@@ -1537,7 +3109,13 @@ impl BlockchainClient for RpcBlockchainClient {
                     warn!(signature = %signature, error = ?e, "Error checking transaction status");
                 }
             }
-            tokio::time::sleep(poll_interval).await;
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+            // Fast confirmations are caught early, slow ones don't hammer the RPC
+            tokio::time::sleep(poll_interval.min(remaining)).await;
+            poll_interval = poll_interval.saturating_mul(2).min(max_interval);
         }
 
         Err(AppError::Blockchain(BlockchainError::Timeout(format!(
@@ -1551,7 +3129,9 @@ impl BlockchainClient for RpcBlockchainClient {
         &self,
         to_address: &str,
         amount_lamports: u64,
-    ) -> Result<(String, String), AppError> {
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
         info!(to = %to_address, amount_lamports = %amount_lamports, "Transferring SOL");
 
         // Validate amount
@@ -1561,16 +3141,6 @@ impl BlockchainClient for RpcBlockchainClient {
             )));
         }
 
-        // Check if we have SDK client and keypair
-        let (sdk_client, keypair) = match (&self.sdk_client, &self.keypair) {
-            (Some(client), Some(kp)) => (client, kp),
-            _ => {
-                return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
-                    "SDK client not initialized for SOL transfers".to_string(),
-                )));
-            }
-        };
-
         // Parse destination address
         let to_pubkey = to_address.parse::<Pubkey>().map_err(|e| {
             AppError::Blockchain(BlockchainError::InvalidSignature(format!(
@@ -1579,55 +3149,105 @@ impl BlockchainClient for RpcBlockchainClient {
             )))
         })?;
 
+        // With a multisig, the vault sends the amount once the members approve
+        if let Some(multisig) = &self.multisig {
+            let (Some(sdk_client), Some(keypair)) =
+                (&self.sdk_client, self.keypair(options.signer))
+            else {
+                return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                    "SDK client not initialized for SOL transfers".to_string(),
+                )));
+            };
+            let vault = multisig.vault();
+            if self.config.preflight_balance_check {
+                let balance = self.balance_of(&vault.to_string()).await?;
+                if balance < amount_lamports {
+                    warn!(
+                        vault = %vault,
+                        balance,
+                        required = amount_lamports,
+                        "Multisig vault balance too low - rejecting before proposal"
+                    );
+                    return Err(AppError::Blockchain(BlockchainError::InsufficientFunds));
+                }
+            }
+            let instructions = sol_transfer_instructions(&vault, &to_pubkey, amount_lamports, memo);
+            return self
+                .propose_to_multisig(sdk_client, keypair, multisig, &instructions, options)
+                .await;
+        }
+
         // Get priority fee using provider-specific strategy
         let priority_fee = self.get_priority_fee(None).await;
+        let jito_tip = self.jito_tip_amount().await;
+
+        // Reject before building the transaction if the payer can't cover it
+        if self.config.preflight_balance_check {
+            let required = amount_lamports
+                .saturating_add(estimated_fee_lamports(
+                    priority_fee,
+                    self.config.compute_unit_limit,
+                ))
+                .saturating_add(jito_tip.unwrap_or(0));
+            self.ensure_payer_balance(required, options.signer).await?;
+        }
 
-        // Create transfer instruction using SDK
-        let transfer_ix =
-            system_instruction::transfer(&keypair.pubkey(), &to_pubkey, amount_lamports);
+        // Check if we have SDK client and keypair
+        let (sdk_client, keypair) = match (&self.sdk_client, self.keypair(options.signer)) {
+            (Some(client), Some(kp)) => (client, kp),
+            _ => {
+                return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                    "SDK client not initialized for SOL transfers".to_string(),
+                )));
+            }
+        };
 
-        // Build instructions with compute budget for priority fee
+        // Build instructions with compute budget for priority fee and limit
         let mut instructions = vec![
             ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
-            transfer_ix,
+            ComputeBudgetInstruction::set_compute_unit_limit(self.config.compute_unit_limit),
         ];
+        instructions.extend(sol_transfer_instructions(
+            &keypair.pubkey(),
+            &to_pubkey,
+            amount_lamports,
+            memo,
+        ));
 
         // Append Jito tip instruction if enabled (MUST be last instruction per Jito best practices)
-        if let Some(tip_ix) = self.create_jito_tip_instruction(&keypair.pubkey()) {
+        if let Some(tip_lamports) = jito_tip {
             info!(
-                tip_lamports = self.jito_tip_lamports.unwrap_or(0),
+                tip_lamports,
                 "Appending Jito tip instruction to SOL transfer"
             );
-            instructions.push(tip_ix);
+            instructions.push(self.create_jito_tip_instruction(&keypair.pubkey(), tip_lamports));
         }
 
-        // Get recent blockhash using SDK
-        let recent_blockhash = sdk_client
-            .get_latest_blockhash()
-            .await
-            .map_err(map_solana_client_error)?;
+        // Get recent blockhash (or durable nonce) using SDK
+        let recent_blockhash = self
+            .transaction_blockhash(sdk_client, &keypair.pubkey(), &mut instructions)
+            .await?;
 
         // Build and sign transaction
-        let transaction = Transaction::new_signed_with_payer(
-            &instructions,
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
+        let transaction = self
+            .build_signed_transaction(sdk_client, keypair, &instructions, recent_blockhash)
+            .await?;
 
         // Submit via strategy if available, otherwise use SDK
-        let (signature, blockhash) = self.submit_or_confirm_transaction(&transaction).await?;
+        let (outcome, blockhash) = self
+            .submit_or_confirm_transaction(&transaction, options)
+            .await?;
 
         info!(
-            signature = %signature,
+            signature = %outcome.signature,
             to = %to_address,
             amount_lamports = %amount_lamports,
             via_strategy = self.submission_strategy.is_some(),
-            jito_tip = self.jito_tip_lamports.filter(|_| self.supports_private_submission()),
+            jito_tip,
             "SOL transfer submitted"
         );
 
-        Ok((signature, blockhash))
+        Ok((outcome, blockhash))
     }
 
     #[instrument(skip(self))]
@@ -1636,7 +3256,9 @@ impl BlockchainClient for RpcBlockchainClient {
         to_address: &str,
         token_mint: &str,
         amount: u64,
-    ) -> Result<(String, String), AppError> {
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
         info!(to = %to_address, token_mint = %token_mint, amount = %amount, "Transferring SPL Token (raw units)");
 
         // Validate amount
@@ -1647,7 +3269,7 @@ impl BlockchainClient for RpcBlockchainClient {
         }
 
         // Check if we have SDK client and keypair
-        let (sdk_client, keypair) = match (&self.sdk_client, &self.keypair) {
+        let (sdk_client, keypair) = match (&self.sdk_client, self.keypair(options.signer)) {
             (Some(client), Some(kp)) => (client, kp),
             _ => {
                 return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
@@ -1671,196 +3293,328 @@ impl BlockchainClient for RpcBlockchainClient {
             )))
         })?;
 
-        // Fetch the mint account to determine the correct token program ID and decimals
-        // This is required for transfer_checked instruction (validates decimals) and Token-2022 support
-        let mint_account = sdk_client.get_account(&mint_pubkey).await.map_err(|e| {
-            AppError::Blockchain(BlockchainError::TransactionFailed(format!(
-                "Failed to fetch mint account: {}",
-                e
-            )))
-        })?;
+        let plan = self
+            .plan_token_transfer(sdk_client, &mint_pubkey, amount)
+            .await?;
 
-        // The mint account's owner is the token program ID
-        let token_program_id = mint_account.owner;
-        debug!(token_program_id = %token_program_id, "Detected token program from mint");
+        // With a multisig, tokens leave the vault's account instead
+        let owner = self
+            .multisig
+            .as_ref()
+            .map_or_else(|| keypair.pubkey(), |multisig| multisig.vault());
 
-        // Extract decimals from mint account data (required for transfer_checked)
-        // Mint layout (both SPL Token and Token-2022):
-        // - bytes 0-35: mint_authority option (1 byte option flag + up to 32 bytes pubkey)
-        // - bytes 36-43: supply (u64)
-        // - byte 44: decimals (u8)
-        // - byte 45: is_initialized (bool)
-        // - bytes 46-78: freeze_authority option
-        const DECIMALS_OFFSET: usize = 44;
-        const MIN_MINT_SIZE: usize = 82;
+        // CRITICAL: Verify source ATA exists and has sufficient balance
+        self.check_source_token_balance(
+            sdk_client,
+            &owner,
+            &mint_pubkey,
+            &plan.token_program_id,
+            amount,
+        )
+        .await?;
 
-        if mint_account.data.len() < MIN_MINT_SIZE {
-            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
-                format!(
-                    "Mint account data too small: {} bytes, expected at least {}",
-                    mint_account.data.len(),
-                    MIN_MINT_SIZE
-                ),
-            )));
+        if let Some(multisig) = &self.multisig {
+            // The vault also pays for the recipient's account if it is missing
+            let instructions = self
+                .token_transfer_instructions(
+                    sdk_client,
+                    &owner,
+                    &to_pubkey,
+                    &mint_pubkey,
+                    &plan,
+                    memo,
+                )
+                .await?;
+            return self
+                .propose_to_multisig(sdk_client, keypair, multisig, &instructions, options)
+                .await
+                .map(|(outcome, blockhash)| (with_token_fee(outcome, [&plan]), blockhash));
         }
 
-        let decimals = mint_account.data[DECIMALS_OFFSET];
-        debug!(decimals = %decimals, "Read decimals from mint account (needed for transfer_checked)");
-
-        // Derive Associated Token Accounts with the correct token program ID
-        let source_ata = get_associated_token_address_with_program_id(
-            &keypair.pubkey(),
-            &mint_pubkey,
-            &token_program_id,
-        );
-        let destination_ata = get_associated_token_address_with_program_id(
-            &to_pubkey,
-            &mint_pubkey,
-            &token_program_id,
-        );
-
-        debug!(
-            source_ata = %source_ata,
-            destination_ata = %destination_ata,
-            token_program_id = %token_program_id,
-            "Derived ATAs for token transfer"
-        );
+        // The destination ATA is created with the idempotent instruction, so
+        // an account created between our check and execution does not fail
+        // the transfer. Should the creation still be rejected as already in
+        // use, the account exists: the transfer is resent once without it.
+        let mut create_destination = true;
+        let (outcome, blockhash, jito_tip) = loop {
+            // Get priority fee using provider-specific strategy
+            let priority_fee = self.get_priority_fee(None).await;
+
+            let mut transfer_instructions = self
+                .token_transfer_instructions(
+                    sdk_client,
+                    &keypair.pubkey(),
+                    &to_pubkey,
+                    &mint_pubkey,
+                    &plan,
+                    memo,
+                )
+                .await?;
+            if !create_destination {
+                transfer_instructions
+                    .retain(|ix| ix.program_id != spl_associated_token_account::id());
+            }
 
-        // CRITICAL: Verify source ATA exists and has sufficient balance
-        let source_account = sdk_client.get_account(&source_ata).await.map_err(|e| {
-            AppError::Blockchain(BlockchainError::TransactionFailed(format!(
-                "Source token account does not exist or cannot be fetched. \
-                 The sender ({}) does not have an associated token account for mint {}. \
-                 Error: {}",
-                keypair.pubkey(),
-                token_mint,
-                e
-            )))
-        })?;
+            // Start with compute budget instructions for priority fee and limit
+            let mut instructions: Vec<Instruction> = vec![
+                ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+                ComputeBudgetInstruction::set_compute_unit_limit(self.config.compute_unit_limit),
+            ];
+            instructions.extend(transfer_instructions);
+
+            // Append Jito tip instruction if enabled (MUST be last instruction per Jito best practices)
+            let jito_tip = self.jito_tip_amount().await;
+            if let Some(tip_lamports) = jito_tip {
+                info!(
+                    tip_lamports,
+                    "Appending Jito tip instruction to token transfer"
+                );
+                instructions
+                    .push(self.create_jito_tip_instruction(&keypair.pubkey(), tip_lamports));
+            }
 
-        // Verify the source account is owned by the token program
-        if source_account.owner != token_program_id {
-            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
-                format!(
-                    "Source token account is not owned by the token program. \
-                     Expected owner: {}, actual owner: {}",
-                    token_program_id, source_account.owner
-                ),
-            )));
-        }
+            // Get recent blockhash (or durable nonce)
+            let recent_blockhash = self
+                .transaction_blockhash(sdk_client, &keypair.pubkey(), &mut instructions)
+                .await?;
 
-        // Extract balance from token account data to verify sufficient funds
-        // Token account layout: amount is at bytes 64-72 (u64 LE)
-        const TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = 64;
-        if source_account.data.len() >= TOKEN_ACCOUNT_AMOUNT_OFFSET + 8 {
-            let balance_bytes: [u8; 8] = source_account.data
-                [TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8]
-                .try_into()
-                .unwrap();
-            let balance = u64::from_le_bytes(balance_bytes);
-            debug!(source_balance = %balance, required = %amount, "Checking source token balance");
+            // Build and sign transaction
+            let transaction = self
+                .build_signed_transaction(sdk_client, keypair, &instructions, recent_blockhash)
+                .await?;
 
-            if balance < amount {
-                return Err(AppError::Blockchain(BlockchainError::InsufficientFunds));
+            // Submit via strategy if available, otherwise use SDK
+            match self
+                .submit_or_confirm_transaction(&transaction, options)
+                .await
+            {
+                Err(e) if failed_account_creation(&e, &instructions).is_some() => {
+                    warn!(
+                        to = %to_address,
+                        token_mint = %token_mint,
+                        error = %e,
+                        "Destination token account created concurrently, resending without creating it"
+                    );
+                    create_destination = false;
+                }
+                result => {
+                    let (outcome, blockhash) = result?;
+                    break (outcome, blockhash, jito_tip);
+                }
             }
-        }
+        };
 
-        // Get priority fee using provider-specific strategy
-        let priority_fee = self.get_priority_fee(None).await;
+        info!(
+            signature = %outcome.signature,
+            to = %to_address,
+            token_mint = %token_mint,
+            amount = %plan.send_amount,
+            transfer_fee = ?plan.expected_fee,
+            decimals = %plan.decimals,
+            via_strategy = self.submission_strategy.is_some(),
+            jito_tip,
+            "SPL Token transfer submitted (raw units)"
+        );
 
-        // Start with compute budget instruction for priority fee
-        let mut instructions: Vec<Instruction> =
-            vec![ComputeBudgetInstruction::set_compute_unit_price(
-                priority_fee,
-            )];
+        Ok((with_token_fee(outcome, [&plan]), blockhash))
+    }
 
-        // Check if destination ATA exists
-        let dest_account_result = sdk_client.get_account(&destination_ata).await;
+    /// Transfer SPL Tokens to several recipients in one transaction, which
+    /// must fit the 1232-byte packet limit.
+    ///
+    /// Each recipient gets its own `transfer_checked` (plus an idempotent ATA
+    /// creation when its account is missing), planned like a single transfer
+    /// so Token-2022 fees are grossed up per recipient. The source balance
+    /// must cover the sum of what every recipient is sent. A transaction
+    /// rejected because a recipient's account was created concurrently is
+    /// resent without that creation.
+    ///
+    /// A batch needing more than one transaction is rejected before anything
+    /// is sent: a later transaction failing after earlier ones paid their
+    /// recipients would have the retry pay them again.
+    async fn transfer_token_batch(
+        &self,
+        token_mint: &str,
+        recipients: &[(String, u64)],
+        memo: Option<&str>,
+        options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        info!(token_mint = %token_mint, recipients = recipients.len(), "Transferring SPL Token batch (raw units)");
 
-        if dest_account_result.is_err() {
-            // ATA doesn't exist - create it using idempotent instruction
-            // This is safer as it won't fail if the ATA gets created between our check and execution
-            info!(destination_ata = %destination_ata, "Creating destination ATA");
-            let create_ata_ix = create_associated_token_account_idempotent(
-                &keypair.pubkey(), // payer
-                &to_pubkey,        // wallet owner
-                &mint_pubkey,      // token mint
-                &token_program_id, // token program (dynamically detected)
-            );
-            instructions.push(create_ata_ix);
+        if recipients.is_empty() {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                "Batch transfer has no recipients".to_string(),
+            )));
+        }
+        if recipients.iter().any(|(_, amount)| *amount == 0) {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                "Transfer amount must be greater than 0".to_string(),
+            )));
         }
 
-        // Create SPL Token transfer_checked instruction for safer transfers
-        // transfer_checked validates the mint and decimals, providing better error messages
-        // Note: We pass the raw `amount` directly (already in token units), but still need
-        // `decimals` for the transfer_checked instruction validation
-        let transfer_ix = token_instruction::transfer_checked(
-            &token_program_id,
-            &source_ata,
-            &mint_pubkey,
-            &destination_ata,
-            &keypair.pubkey(), // authority (owner of source account)
-            &[],               // no multisig signers
-            amount,            // already in raw token units
-            decimals,          // required by transfer_checked for validation
-        )
-        .map_err(|e| {
-            AppError::Blockchain(BlockchainError::TransactionFailed(format!(
-                "Failed to create transfer_checked instruction: {}",
+        let (sdk_client, keypair) = match (&self.sdk_client, self.keypair(options.signer)) {
+            (Some(client), Some(kp)) => (client, kp),
+            _ => {
+                return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                    "SDK client not initialized for token transfers".to_string(),
+                )));
+            }
+        };
+        let payer = keypair.pubkey();
+
+        let mint_pubkey = token_mint.parse::<Pubkey>().map_err(|e| {
+            AppError::Blockchain(BlockchainError::InvalidSignature(format!(
+                "Invalid token mint address: {}",
                 e
             )))
         })?;
+        let mint = self.fetch_token_mint(sdk_client, &mint_pubkey).await?;
+
+        let mut plans = Vec::with_capacity(recipients.len());
+        for (address, amount) in recipients {
+            let to_pubkey = address.parse::<Pubkey>().map_err(|e| {
+                AppError::Blockchain(BlockchainError::InvalidSignature(format!(
+                    "Invalid destination address {}: {}",
+                    address, e
+                )))
+            })?;
+            plans.push((
+                to_pubkey,
+                mint.plan(*amount, self.config.gross_up_transfer_fees)?,
+            ));
+        }
 
-        instructions.push(transfer_ix);
+        let required = batch_send_total(plans.iter().map(|(_, plan)| plan))?;
+        self.check_source_token_balance(
+            sdk_client,
+            &payer,
+            &mint_pubkey,
+            &mint.token_program_id,
+            required,
+        )
+        .await?;
 
-        // Append Jito tip instruction if enabled (MUST be last instruction per Jito best practices)
-        if let Some(tip_ix) = self.create_jito_tip_instruction(&keypair.pubkey()) {
-            info!(
-                tip_lamports = self.jito_tip_lamports.unwrap_or(0),
-                "Appending Jito tip instruction to token transfer"
+        let mut groups = Vec::with_capacity(plans.len());
+        for (index, (to_pubkey, plan)) in plans.iter().enumerate() {
+            // The memo rides along with the first recipient only
+            let memo = if index == 0 { memo } else { None };
+            groups.push(
+                self.token_transfer_instructions(
+                    sdk_client,
+                    &payer,
+                    to_pubkey,
+                    &mint_pubkey,
+                    plan,
+                    memo,
+                )
+                .await?,
             );
-            instructions.push(tip_ix);
         }
 
-        // Get recent blockhash
-        let recent_blockhash = sdk_client
-            .get_latest_blockhash()
-            .await
-            .map_err(map_solana_client_error)?;
-
-        // Build and sign transaction
-        let transaction = Transaction::new_signed_with_payer(
-            &instructions,
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
-        );
+        // The transaction carries the compute budget, the Jito tip and, with a
+        // durable nonce, the advance instruction; they count towards its size
+        let priority_fee = self.get_priority_fee(None).await;
+        let jito_tip = self.jito_tip_amount().await;
+        let mut prefix = vec![
+            ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+            ComputeBudgetInstruction::set_compute_unit_limit(self.config.compute_unit_limit),
+        ];
+        if let Some(nonce_account) = self.config.nonce_account {
+            prepend_advance_nonce(&mut prefix, &nonce_account, &payer);
+        }
+        let suffix: Vec<Instruction> = jito_tip
+            .map(|tip_lamports| self.create_jito_tip_instruction(&payer, tip_lamports))
+            .into_iter()
+            .collect();
+        let mut transactions = pack_instruction_groups(&payer, &prefix, &suffix, groups)?;
+        if transactions.len() > 1 {
+            return Err(AppError::Validation(
+                crate::domain::ValidationError::InvalidField {
+                    field: "recipients".to_string(),
+                    message: format!(
+                        "Batch of {} recipients does not fit in one transaction; at most {} fit, \
+                         split it into smaller batches",
+                        recipients.len(),
+                        transactions[0].len()
+                    ),
+                },
+            ));
+        }
+        let Some(mut groups) = transactions.pop() else {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                "Batch transfer has no recipients".to_string(),
+            )));
+        };
+        let batch_instructions = |groups: &[Vec<Instruction>]| {
+            let compute_unit_limit =
+                batch_compute_unit_limit(self.config.compute_unit_limit, groups.len());
+            let mut instructions = vec![
+                ComputeBudgetInstruction::set_compute_unit_price(priority_fee),
+                ComputeBudgetInstruction::set_compute_unit_limit(compute_unit_limit),
+            ];
+            instructions.extend(groups.iter().flatten().cloned());
+            instructions.extend(suffix.iter().cloned());
+            instructions
+        };
+        // A recipient's account created between our check and execution is
+        // no longer created: the transaction is resent without it, as for a
+        // single transfer
+        let skip_creation = |groups: &mut Vec<Vec<Instruction>>, creation: &Instruction| {
+            warn!(
+                token_mint = %token_mint,
+                "Recipient token account created concurrently, resending without creating it"
+            );
+            for group in groups.iter_mut() {
+                group.retain(|ix| ix != creation);
+            }
+        };
 
-        // Submit via strategy if available, otherwise use SDK
-        let (signature, blockhash) = self.submit_or_confirm_transaction(&transaction).await?;
+        let (outcome, blockhash) = loop {
+            let mut instructions = batch_instructions(&groups);
+            let recent_blockhash = self
+                .transaction_blockhash(sdk_client, &payer, &mut instructions)
+                .await?;
+            let transaction = self
+                .build_signed_transaction(sdk_client, keypair, &instructions, recent_blockhash)
+                .await?;
+            match self
+                .submit_or_confirm_transaction(&transaction, options)
+                .await
+            {
+                Ok(submitted) => break submitted,
+                Err(e) => match failed_account_creation(&e, &instructions) {
+                    Some(creation) => skip_creation(&mut groups, creation),
+                    None => return Err(e),
+                },
+            }
+        };
 
         info!(
-            signature = %signature,
-            to = %to_address,
+            signature = %outcome.signature,
             token_mint = %token_mint,
-            amount = %amount,
-            decimals = %decimals,
+            recipients = recipients.len(),
+            total = required,
+            decimals = %mint.decimals,
             via_strategy = self.submission_strategy.is_some(),
-            jito_tip = self.jito_tip_lamports.filter(|_| self.supports_private_submission()),
-            "SPL Token transfer submitted (raw units)"
+            jito_tip,
+            "SPL Token batch transfer submitted (raw units)"
         );
 
-        Ok((signature, blockhash))
+        let outcome = with_token_fee(outcome, plans.iter().map(|(_, plan)| plan));
+        Ok((outcome, blockhash))
     }
 
-    /// Check if a wallet holds compliant assets using Helius DAS.
+    /// Check if a wallet holds compliant assets.
     ///
-    /// This method checks if the wallet holds any assets from sanctioned collections.
-    /// It is only available when using a Helius RPC provider.
+    /// This method checks if the wallet holds any assets from sanctioned collections
+    /// or mints. Helius DAS is preferred when available.
     ///
     /// # Behavior by Provider
     /// - **Helius**: Uses `getAssetsByOwner` DAS API to check asset collections
-    /// - **QuickNode/Standard**: Returns `true` (skip check, assume compliant)
+    /// - **QuickNode/Standard**: Uses `getTokenAccountsByOwner` to check the mints of
+    ///   the wallet's token holdings against `sanctioned_mints`. NFT collection
+    ///   membership is not checked. Returns `true` (skip check, assume compliant)
+    ///   when no sanctioned mints are configured.
     ///
     /// # Arguments
     /// * `owner` - The wallet address (Base58) to check
@@ -1876,11 +3630,15 @@ impl BlockchainClient for RpcBlockchainClient {
                 info!(wallet = %owner, "Helius DAS Check: Initiating asset scan");
                 das_client.check_wallet_compliance(owner).await
             }
+            None if !self.config.sanctioned_mints.is_empty() => {
+                info!(wallet = %owner, "Token account asset check: Initiating holdings scan");
+                self.check_token_holdings(owner).await
+            }
             None => {
                 debug!(
                     wallet = %owner,
                     provider = %self.provider_type.name(),
-                    "DAS not available for this provider, skipping asset check"
+                    "DAS not available and no sanctioned mints configured, skipping asset check"
                 );
                 Ok(true)
             }
@@ -1924,72 +3682,307 @@ impl BlockchainClient for RpcBlockchainClient {
         }
     }
 
-    /// Check if a blockhash is still valid (not expired).
-    /// Blockhashes typically expire after ~150 slots (~1-2 minutes).
-    #[instrument(skip(self))]
-    async fn is_blockhash_valid(&self, blockhash: &str) -> Result<bool, AppError> {
-        // Use the SDK client if available for accurate blockhash validation
-        if let Some(sdk_client) = &self.sdk_client {
-            let hash = solana_sdk::hash::Hash::from_str(blockhash).map_err(|e| {
-                AppError::Validation(crate::domain::ValidationError::InvalidField {
-                    field: "blockhash".to_string(),
-                    message: format!("Invalid blockhash format: {}", e),
-                })
-            })?;
-
-            let is_valid = sdk_client
-                .is_blockhash_valid(&hash, CommitmentConfig::confirmed())
-                .await
-                .map_err(map_solana_client_error)?;
-
-            debug!(
-                blockhash = %blockhash,
-                is_valid = %is_valid,
-                "Checked blockhash validity"
-            );
+    fn signer_pubkeys(&self) -> Vec<String> {
+        self.keypairs
+            .iter()
+            .map(|keypair| keypair.pubkey().to_string())
+            .collect()
+    }
 
-            return Ok(is_valid);
+    fn select_signer(&self, transfer_id: &str) -> Option<String> {
+        if self.keypairs.is_empty() {
+            return None;
         }
+        let index = self.key_selector.select(transfer_id, self.keypairs.len());
+        Some(self.keypairs[index].pubkey().to_string())
+    }
 
-        // Fallback: Use RPC method directly
-        // The `isBlockhashValid` RPC method returns whether the blockhash is still valid
-        let params = serde_json::json!([blockhash, {"commitment": "confirmed"}]);
-
-        #[derive(Debug, Deserialize)]
-        struct IsValidResult {
-            value: bool,
+    #[instrument(skip(self))]
+    async fn get_fee_payer_balance(&self) -> Result<u64, AppError> {
+        if self.keypairs.len() + self.retired_keypairs.len() <= 1 {
+            return self.fee_payer_balance(None).await;
         }
-
-        match self
-            .rpc_call::<serde_json::Value, IsValidResult>("isBlockhashValid", params)
-            .await
-        {
-            Ok(result) => {
-                debug!(
-                    blockhash = %blockhash,
-                    is_valid = %result.value,
-                    "Checked blockhash validity via RPC"
-                );
-                Ok(result.value)
-            }
-            Err(e) => {
-                warn!(
-                    blockhash = %blockhash,
-                    error = %e,
-                    "Failed to check blockhash validity, assuming expired"
-                );
-                // On error, assume expired (safe to retry with new blockhash)
-                Ok(false)
-            }
+        // Every key pays for its own transfers, so the emptiest one matters
+        let mut lowest = u64::MAX;
+        for keypair in self.keypairs.iter().chain(&self.retired_keypairs) {
+            let balance = self.balance_of(&keypair.pubkey().to_string()).await?;
+            lowest = lowest.min(balance);
         }
+        Ok(lowest)
     }
-}
 
-/// Map Solana client errors to our AppError types
+    /// The compute budget instructions are left out of the priced message, so
+    /// `getFeeForMessage` reports only the signature fee and the priority fee
+    /// is added once, from the fee strategy.
+    #[instrument(skip(self))]
+    async fn estimate_fee(
+        &self,
+        to_address: &str,
+        amount: u64,
+        token_mint: Option<&str>,
+        memo: Option<&str>,
+    ) -> Result<FeeEstimate, AppError> {
+        let to_pubkey = to_address.parse::<Pubkey>().map_err(|e| {
+            AppError::Blockchain(BlockchainError::InvalidSignature(format!(
+                "Invalid destination address: {}",
+                e
+            )))
+        })?;
+        let payer = self
+            .payer_pubkey(None)
+            .parse::<Pubkey>()
+            .map_err(|e| AppError::Internal(format!("Invalid fee payer public key: {}", e)))?;
+
+        let instructions = match token_mint {
+            None => sol_transfer_instructions(&payer, &to_pubkey, amount, memo),
+            Some(token_mint) => {
+                let sdk_client = self.sdk_client.as_ref().ok_or_else(|| {
+                    AppError::Blockchain(BlockchainError::TransactionFailed(
+                        "SDK client not initialized for token transfers".to_string(),
+                    ))
+                })?;
+                let mint_pubkey = token_mint.parse::<Pubkey>().map_err(|e| {
+                    AppError::Blockchain(BlockchainError::InvalidSignature(format!(
+                        "Invalid token mint address: {}",
+                        e
+                    )))
+                })?;
+                let plan = self
+                    .plan_token_transfer(sdk_client, &mint_pubkey, amount)
+                    .await?;
+                self.token_transfer_instructions(
+                    sdk_client,
+                    &payer,
+                    &to_pubkey,
+                    &mint_pubkey,
+                    &plan,
+                    memo,
+                )
+                .await?
+            }
+        };
+
+        let base_fee = self.base_fee_for_message(&payer, &instructions).await?;
+        let priority_fee = self.get_priority_fee(None).await;
+        let jito_tip = self.jito_tip_amount().await.unwrap_or(0);
+        let estimate = FeeEstimate::new(
+            base_fee,
+            priority_fee,
+            self.config.compute_unit_limit,
+            jito_tip,
+        );
+
+        debug!(
+            base_fee,
+            priority_fee_lamports = estimate.priority_fee_lamports,
+            jito_tip,
+            total_lamports = estimate.total_lamports,
+            "Estimated transfer fee"
+        );
+        Ok(estimate)
+    }
+
+    fn closes_empty_token_accounts(&self) -> bool {
+        self.config.close_empty_token_accounts
+    }
+
+    /// Closes the relayer's associated token account for `token_mint`. The
+    /// account is re-created by the next transfer that funds it.
+    #[instrument(skip(self))]
+    async fn close_empty_token_account(
+        &self,
+        token_mint: &str,
+        signer: Option<&str>,
+    ) -> Result<Option<String>, AppError> {
+        if !self.config.close_empty_token_accounts {
+            return Ok(None);
+        }
+        let options = SubmitOptions {
+            signer,
+            ..SubmitOptions::default()
+        };
+
+        let (sdk_client, keypair) = match (&self.sdk_client, self.keypair(options.signer)) {
+            (Some(client), Some(kp)) => (client, kp),
+            _ => {
+                return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                    "SDK client not initialized for closing token accounts".to_string(),
+                )));
+            }
+        };
+        let mint_pubkey = token_mint.parse::<Pubkey>().map_err(|e| {
+            AppError::Blockchain(BlockchainError::InvalidSignature(format!(
+                "Invalid token mint address: {}",
+                e
+            )))
+        })?;
+        let relayer = keypair.pubkey();
+
+        // The mint account's owner is the token program ID
+        let token_program_id = sdk_client
+            .get_account(&mint_pubkey)
+            .await
+            .map_err(map_solana_client_error)?
+            .owner;
+        let token_account =
+            get_associated_token_address_with_program_id(&relayer, &mint_pubkey, &token_program_id);
+
+        // Already closed (or never created) - nothing to reclaim
+        let Ok(account) = sdk_client.get_account(&token_account).await else {
+            return Ok(None);
+        };
+        let Some(close_ix) =
+            close_empty_token_account_instruction(&relayer, &token_account, &account)
+        else {
+            debug!(token_account = %token_account, "Relayer token account not closable, keeping it");
+            return Ok(None);
+        };
+
+        let mut instructions = vec![close_ix];
+        // Append Jito tip instruction if enabled (MUST be last instruction per Jito best practices)
+        if let Some(tip_lamports) = self.jito_tip_amount().await {
+            instructions.push(self.create_jito_tip_instruction(&relayer, tip_lamports));
+        }
+
+        let recent_blockhash = self
+            .transaction_blockhash(sdk_client, &relayer, &mut instructions)
+            .await?;
+        let transaction = self
+            .build_signed_transaction(sdk_client, keypair, &instructions, recent_blockhash)
+            .await?;
+        let signature = self
+            .submit_or_confirm_transaction(&transaction, options)
+            .await?
+            .0
+            .signature;
+
+        info!(
+            token_account = %token_account,
+            token_mint = %token_mint,
+            rent_lamports = account.lamports,
+            signature = %signature,
+            "Closed empty relayer token account"
+        );
+        Ok(Some(signature))
+    }
+
+    fn uses_durable_nonce(&self) -> bool {
+        self.config.nonce_account.is_some()
+    }
+
+    async fn get_bundle_status(&self, signature: &str) -> Result<Option<BundleStatus>, AppError> {
+        match &self.submission_strategy {
+            Some(strategy) => strategy.bundle_status(signature).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn get_proposal_status(
+        &self,
+        proposal: &str,
+    ) -> Result<Option<ProposalStatus>, AppError> {
+        let sdk_client = self.sdk_client.as_ref().ok_or_else(|| {
+            AppError::NotSupported("get_proposal_status requires the SDK client".to_string())
+        })?;
+        let proposal = proposal.parse::<Pubkey>().map_err(|e| {
+            AppError::Blockchain(BlockchainError::RpcError(format!(
+                "Invalid proposal address: {}",
+                e
+            )))
+        })?;
+        let account = sdk_client
+            .get_account_with_commitment(&proposal, CommitmentConfig::confirmed())
+            .await
+            .map_err(map_solana_client_error)?
+            .value;
+        account
+            .map(|account| super::squads::parse_proposal_status(&account.data))
+            .transpose()
+    }
+
+    /// Check if a blockhash is still valid (not expired).
+    /// Blockhashes typically expire after ~150 slots (~1-2 minutes).
+    ///
+    /// In durable nonce mode, a transaction built on the nonce value stays
+    /// valid for as long as the nonce account still stores that value.
+    #[instrument(skip(self))]
+    async fn is_blockhash_valid(&self, blockhash: &str) -> Result<bool, AppError> {
+        // Use the SDK client if available for accurate blockhash validation
+        if let Some(sdk_client) = &self.sdk_client {
+            let hash = solana_sdk::hash::Hash::from_str(blockhash).map_err(|e| {
+                AppError::Validation(crate::domain::ValidationError::InvalidField {
+                    field: "blockhash".to_string(),
+                    message: format!("Invalid blockhash format: {}", e),
+                })
+            })?;
+
+            if let Some(nonce_account) = self.config.nonce_account {
+                let current_nonce = self.fetch_durable_nonce(sdk_client, &nonce_account).await?;
+                if current_nonce == hash {
+                    debug!(blockhash = %blockhash, "Durable nonce not yet advanced");
+                    return Ok(true);
+                }
+            }
+
+            let is_valid = sdk_client
+                .is_blockhash_valid(&hash, CommitmentConfig::confirmed())
+                .await
+                .map_err(map_solana_client_error)?;
+
+            debug!(
+                blockhash = %blockhash,
+                is_valid = %is_valid,
+                "Checked blockhash validity"
+            );
+
+            return Ok(is_valid);
+        }
+
+        // Fallback: Use RPC method directly
+        // The `isBlockhashValid` RPC method returns whether the blockhash is still valid
+        let params = serde_json::json!([blockhash, {"commitment": "confirmed"}]);
+
+        #[derive(Debug, Deserialize)]
+        struct IsValidResult {
+            value: bool,
+        }
+
+        match self
+            .rpc_call::<serde_json::Value, IsValidResult>("isBlockhashValid", params)
+            .await
+        {
+            Ok(result) => {
+                debug!(
+                    blockhash = %blockhash,
+                    is_valid = %result.value,
+                    "Checked blockhash validity via RPC"
+                );
+                Ok(result.value)
+            }
+            Err(e) => {
+                warn!(
+                    blockhash = %blockhash,
+                    error = %e,
+                    "Failed to check blockhash validity, assuming expired"
+                );
+                // On error, assume expired (safe to retry with new blockhash)
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// Map Solana client errors to our AppError types
 fn map_solana_client_error(err: solana_client::client_error::ClientError) -> AppError {
     use solana_client::client_error::ClientErrorKind;
 
     let msg = err.to_string();
+    if let Some(error) = err
+        .get_transaction_error()
+        .and_then(|error| account_already_in_use(&error, &msg))
+    {
+        return error;
+    }
 
     match err.kind() {
         ClientErrorKind::RpcError(_) => {
@@ -2011,1174 +4004,5247 @@ fn map_solana_client_error(err: solana_client::client_error::ClientError) -> App
     }
 }
 
-/// Wrap a blockchain error with the blockhash that was used for the transaction.
-/// This enables "sticky blockhash" logic: on retry, the service layer can reuse the
-/// same blockhash (which will fail safely if already processed) instead of fetching
-/// a new one (which could lead to double-spend).
-fn wrap_error_with_blockhash(error: AppError, blockhash: &str) -> AppError {
-    match error {
-        AppError::Blockchain(BlockchainError::Timeout(msg)) => {
-            AppError::Blockchain(BlockchainError::TimeoutWithBlockhash {
-                message: msg,
-                blockhash: blockhash.to_string(),
-            })
+/// Send-and-confirm primitives of the standard (no strategy) submission path
+#[async_trait]
+trait StandardSender: Send + Sync {
+    /// Latest blockhash to re-sign an expired transaction with
+    async fn latest_blockhash(&self) -> Result<Hash, AppError>;
+
+    /// Send `transaction` and poll until it confirms. Returns `None` once its
+    /// blockhash has expired without the transaction being processed.
+    async fn send_until_expiry(
+        &self,
+        transaction: &VersionedTransaction,
+        poll_interval: Duration,
+        max_poll_interval: Duration,
+    ) -> Result<Option<Signature>, AppError>;
+}
+
+#[async_trait]
+impl StandardSender for SolanaRpcClient {
+    async fn latest_blockhash(&self) -> Result<Hash, AppError> {
+        self.get_latest_blockhash()
+            .await
+            .map_err(map_solana_client_error)
+    }
+
+    async fn send_until_expiry(
+        &self,
+        transaction: &VersionedTransaction,
+        poll_interval: Duration,
+        max_poll_interval: Duration,
+    ) -> Result<Option<Signature>, AppError> {
+        let signature = self
+            .send_transaction(transaction)
+            .await
+            .map_err(map_solana_client_error)?;
+        let blockhash = *transaction.message.recent_blockhash();
+        let max_poll_interval = max_poll_interval.max(poll_interval);
+        let mut poll_interval = poll_interval;
+
+        loop {
+            tokio::time::sleep(poll_interval).await;
+            poll_interval = poll_interval.saturating_mul(2).min(max_poll_interval);
+
+            if let Some(status) = self
+                .get_signature_status(&signature)
+                .await
+                .map_err(map_solana_client_error)?
+            {
+                return match status {
+                    Ok(()) => Ok(Some(signature)),
+                    Err(e) => {
+                        let message = format!("Transaction {} failed: {}", signature, e);
+                        Err(
+                            account_already_in_use(&e, &message).unwrap_or(AppError::Blockchain(
+                                BlockchainError::TransactionFailed(message),
+                            )),
+                        )
+                    }
+                };
+            }
+
+            let blockhash_valid = self
+                .is_blockhash_valid(&blockhash, CommitmentConfig::processed())
+                .await
+                .map_err(map_solana_client_error)?;
+            if blockhash_valid {
+                continue;
+            }
+            // Processed just before the blockhash expired: keep waiting, a
+            // re-signed copy would be a second transfer
+            let processed = self
+                .get_signature_status_with_commitment(&signature, CommitmentConfig::processed())
+                .await
+                .map_err(map_solana_client_error)?;
+            if processed.is_none() {
+                return Ok(None);
+            }
         }
-        AppError::Blockchain(
-            BlockchainError::Connection(ref msg) | BlockchainError::RpcError(ref msg),
-        ) => AppError::Blockchain(BlockchainError::NetworkErrorWithBlockhash {
-            message: msg.clone(),
-            blockhash: blockhash.to_string(),
-        }),
-        // JitoStateUnknown, JitoBundleFailed, etc. pass through — they have
-        // their own retry semantics and the blockhash is already tracked separately.
-        other => other,
     }
 }
 
-/// Parse a base58-encoded private key into a SigningKey
-pub fn signing_key_from_base58(secret: &SecretString) -> Result<SigningKey, AppError> {
-    let key_bytes = bs58::decode(secret.expose_secret())
-        .into_vec()
-        .map_err(|e| AppError::Blockchain(BlockchainError::InvalidSignature(e.to_string())))?;
+/// Send `transaction` on the standard path. Whenever its blockhash expires
+/// before it confirms, it is re-signed by `signers` with a fresh blockhash
+/// and resent, until `deadline` has passed. Returns the signature and the
+/// blockhash of the transaction that confirmed.
+async fn send_with_blockhash_refresh(
+    sender: &dyn StandardSender,
+    transaction: &VersionedTransaction,
+    signers: &[&Keypair],
+    deadline: Duration,
+    poll_interval: Duration,
+    max_poll_interval: Duration,
+) -> Result<(Signature, Hash), AppError> {
+    let deadline = tokio::time::Instant::now() + deadline;
+    let mut transaction = transaction.clone();
+    let mut attempt = 1u32;
+
+    loop {
+        let blockhash = *transaction.message.recent_blockhash();
+        let sent = sender
+            .send_until_expiry(&transaction, poll_interval, max_poll_interval)
+            .await
+            .map_err(|e| wrap_error_with_blockhash(e, &blockhash.to_string()))?;
+        if let Some(signature) = sent {
+            return Ok((signature, blockhash));
+        }
 
-    // Handle both 32-byte (seed) and 64-byte (keypair) formats
-    let key_array: [u8; 32] = if key_bytes.len() == 64 {
-        // Solana keypair format: first 32 bytes are the secret key
-        key_bytes[..32].try_into().map_err(|_| {
-            AppError::Blockchain(BlockchainError::InvalidSignature(
-                "Invalid keypair format".to_string(),
-            ))
-        })?
-    } else if key_bytes.len() == 32 {
-        key_bytes.try_into().map_err(|v: Vec<u8>| {
-            AppError::Blockchain(BlockchainError::InvalidSignature(format!(
-                "Key must be 32 bytes, got {}",
-                v.len()
-            )))
-        })?
-    } else {
-        return Err(AppError::Blockchain(BlockchainError::InvalidSignature(
-            format!("Key must be 32 or 64 bytes, got {}", key_bytes.len()),
-        )));
+        if tokio::time::Instant::now() >= deadline {
+            return Err(wrap_error_with_blockhash(
+                AppError::Blockchain(BlockchainError::Timeout(format!(
+                    "Blockhash expired before confirmation after {} attempt(s)",
+                    attempt
+                ))),
+                &blockhash.to_string(),
+            ));
+        }
+
+        let fresh_blockhash = sender.latest_blockhash().await?;
+        attempt += 1;
+        warn!(
+            attempt,
+            expired_blockhash = %blockhash,
+            blockhash = %fresh_blockhash,
+            "Blockhash expired before confirmation - resending with a fresh blockhash"
+        );
+        transaction = resign_with_blockhash(&transaction, fresh_blockhash, signers)?;
+    }
+}
+
+/// Copy of `transaction` with `blockhash`, signed again by `signers`
+fn resign_with_blockhash(
+    transaction: &VersionedTransaction,
+    blockhash: Hash,
+    signers: &[&Keypair],
+) -> Result<VersionedTransaction, AppError> {
+    let mut message = transaction.message.clone();
+    message.set_recent_blockhash(blockhash);
+    VersionedTransaction::try_new(message, signers).map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to re-sign transaction: {}",
+            e
+        )))
+    })
+}
+
+/// Compile and sign a v0 transaction, resolving accounts through `lookup_tables`
+/// Convert an ed25519-dalek signing key into a Solana SDK keypair
+fn keypair_from_signing_key(signing_key: &SigningKey) -> Result<Keypair, AppError> {
+    let keypair_bytes = signing_key.to_keypair_bytes();
+    Keypair::try_from(keypair_bytes.as_slice()).map_err(|e| {
+        AppError::Blockchain(BlockchainError::InvalidSignature(format!(
+            "Failed to create keypair: {}",
+            e
+        )))
+    })
+}
+
+fn compile_v0_transaction(
+    keypair: &Keypair,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    recent_blockhash: Hash,
+) -> Result<VersionedTransaction, AppError> {
+    let message = v0::Message::try_compile(
+        &keypair.pubkey(),
+        instructions,
+        lookup_tables,
+        recent_blockhash,
+    )
+    .map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to compile v0 message: {}",
+            e
+        )))
+    })?;
+
+    VersionedTransaction::try_new(VersionedMessage::V0(message), &[keypair]).map_err(|e| {
+        AppError::Blockchain(BlockchainError::InvalidSignature(format!(
+            "Failed to sign versioned transaction: {}",
+            e
+        )))
+    })
+}
+
+/// Read the TransferFeeConfig extension of a Token-2022 mint, if present
+fn parse_transfer_fee_config(mint_data: &[u8]) -> Result<Option<TransferFeeConfig>, AppError> {
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(mint_data).map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to unpack Token-2022 mint: {}",
+            e
+        )))
+    })?;
+    Ok(mint.get_extension::<TransferFeeConfig>().ok().copied())
+}
+
+/// Read the auditor ElGamal pubkey of a Token-2022 mint's confidential
+/// transfer extension. `None` when the mint has no auditor or no extension.
+fn parse_confidential_auditor(
+    mint_data: &[u8],
+) -> Result<Option<[u8; ELGAMAL_PUBKEY_LEN]>, AppError> {
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(mint_data).map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to unpack Token-2022 mint: {}",
+            e
+        )))
+    })?;
+    // An all-zero key encodes "no auditor"
+    Ok(mint
+        .get_extension::<ConfidentialTransferMint>()
+        .ok()
+        .map(|extension| {
+            bytemuck::cast::<_, [u8; ELGAMAL_PUBKEY_LEN]>(extension.auditor_elgamal_pubkey)
+        })
+        .filter(|key| *key != [0u8; ELGAMAL_PUBKEY_LEN]))
+}
+
+/// Check the auditor named by a confidential transfer against its mint.
+///
+/// A mint with an auditor requires the request to name that auditor, and the
+/// ciphertext validity proof to encrypt the amount under it (its third
+/// handle). Naming an auditor for a mint without one is rejected as well.
+fn check_confidential_auditor(
+    mint_auditor: Option<[u8; ELGAMAL_PUBKEY_LEN]>,
+    requested_auditor: Option<[u8; ELGAMAL_PUBKEY_LEN]>,
+    proof_auditor: [u8; ELGAMAL_PUBKEY_LEN],
+) -> Result<(), AppError> {
+    let message = match (mint_auditor, requested_auditor) {
+        (None, None) => return Ok(()),
+        (Some(_), None) => "Mint requires an auditor ElGamal pubkey",
+        (None, Some(_)) => "Mint has no auditor",
+        (Some(mint), Some(requested)) if mint != requested => {
+            "Auditor ElGamal pubkey does not match the mint's auditor"
+        }
+        (Some(mint), Some(_)) if mint != proof_auditor => {
+            "Ciphertext validity proof is not encrypted for the mint's auditor"
+        }
+        (Some(_), Some(_)) => return Ok(()),
     };
+    Err(AppError::Validation(
+        crate::domain::ValidationError::InvalidField {
+            field: "auditor_elgamal_pubkey".to_string(),
+            message: message.to_string(),
+        },
+    ))
+}
 
-    Ok(SigningKey::from_bytes(&key_array))
+/// Whether a Token-2022 mint has the confidential transfer fee extension
+fn parse_confidential_transfer_fee(mint_data: &[u8]) -> Result<bool, AppError> {
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(mint_data).map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to unpack Token-2022 mint: {}",
+            e
+        )))
+    })?;
+    Ok(mint
+        .get_extension::<ConfidentialTransferFeeConfig>()
+        .is_ok())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::sync::atomic::{AtomicU8, Ordering};
+/// Fee sigma and fee ciphertext validity proofs of a confidential transfer
+type FeeProofs<'a> = (&'a [u8], &'a [u8]);
 
-    static NEXT_TEST_KEY_SEED: AtomicU8 = AtomicU8::new(1);
+/// Check the fee proofs of a confidential transfer against its mint.
+///
+/// A mint with a confidential transfer fee only accepts `TransferWithFee`,
+/// which needs both fee proofs; a mint without one rejects them. Returns the
+/// fee proofs to verify, if any.
+fn check_confidential_transfer_fee<'a>(
+    mint_has_fee: bool,
+    fee_proofs: Option<FeeProofs<'a>>,
+) -> Result<Option<FeeProofs<'a>>, AppError> {
+    let message = match (mint_has_fee, fee_proofs) {
+        (true, Some(_)) | (false, None) => return Ok(fee_proofs),
+        (true, None) => {
+            "Mint has a confidential transfer fee; fee sigma and fee ciphertext validity \
+             proofs are required"
+        }
+        (false, Some(_)) => "Mint has no confidential transfer fee",
+    };
+    Err(AppError::Validation(
+        crate::domain::ValidationError::InvalidField {
+            field: "fee_sigma_proof".to_string(),
+            message: message.to_string(),
+        },
+    ))
+}
 
-    fn test_signing_key() -> SigningKey {
-        let seed = NEXT_TEST_KEY_SEED.fetch_add(1, Ordering::Relaxed);
-        let mut bytes = [0_u8; 32];
-        bytes[0] = seed;
-        bytes[31] = seed.wrapping_mul(31);
-        SigningKey::from_bytes(&bytes)
+/// Readiness of a token account to receive confidential transfers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfidentialDestinationState {
+    /// No token account exists at the address
+    Missing,
+    /// The account has no confidential transfer extension (`ConfigureAccount` was not run)
+    NotConfigured,
+    /// The owner disabled incoming confidential transfers
+    CreditsDisabled,
+    /// Configured, but awaiting approval by the mint's confidential transfer authority
+    NotApproved,
+    /// Ready to receive confidential transfers
+    Ready,
+}
+
+/// Read the confidential transfer state of a destination token account.
+/// `None` account data means the account does not exist.
+fn parse_confidential_destination(
+    account_data: Option<&[u8]>,
+) -> Result<ConfidentialDestinationState, AppError> {
+    let Some(account_data) = account_data else {
+        return Ok(ConfidentialDestinationState::Missing);
+    };
+    let account = StateWithExtensions::<Token2022Account>::unpack(account_data).map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to unpack Token-2022 account: {}",
+            e
+        )))
+    })?;
+    let Ok(extension) = account.get_extension::<ConfidentialTransferAccount>() else {
+        return Ok(ConfidentialDestinationState::NotConfigured);
+    };
+    Ok(if !bool::from(extension.allow_confidential_credits) {
+        ConfidentialDestinationState::CreditsDisabled
+    } else if !bool::from(extension.approved) {
+        ConfidentialDestinationState::NotApproved
+    } else {
+        ConfidentialDestinationState::Ready
+    })
+}
+
+/// Read the confidential transfer authority of a Token-2022 mint.
+/// `None` when the mint has no authority or no extension.
+fn parse_confidential_transfer_authority(mint_data: &[u8]) -> Result<Option<Pubkey>, AppError> {
+    let mint = StateWithExtensions::<Token2022Mint>::unpack(mint_data).map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to unpack Token-2022 mint: {}",
+            e
+        )))
+    })?;
+    Ok(mint
+        .get_extension::<ConfidentialTransferMint>()
+        .ok()
+        .and_then(|extension| Option::<Pubkey>::from(extension.authority)))
+}
+
+/// Check that a destination can receive a confidential transfer.
+///
+/// Only the account owner can configure the account or enable confidential
+/// credits, so those states fail with the step the recipient has to take. An
+/// account awaiting approval gets an `ApproveAccount` instruction when the
+/// relayer is the mint's confidential transfer authority.
+fn confidential_destination_approval(
+    state: ConfidentialDestinationState,
+    mint_authority: Option<Pubkey>,
+    relayer: &Pubkey,
+    destination: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Option<Instruction>, AppError> {
+    let message = match state {
+        ConfidentialDestinationState::Ready => return Ok(None),
+        ConfidentialDestinationState::NotApproved if mint_authority == Some(*relayer) => {
+            return approve_account(&spl_token_2022::id(), destination, mint, relayer, &[])
+                .map(Some)
+                .map_err(|e| {
+                    AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+                        "Failed to build ApproveAccount instruction: {}",
+                        e
+                    )))
+                });
+        }
+        ConfidentialDestinationState::Missing => {
+            "Recipient has no token account for this mint; the recipient must create it and \
+             configure it for confidential transfers (ConfigureAccount)"
+        }
+        ConfidentialDestinationState::NotConfigured => {
+            "Recipient token account is not configured for confidential transfers; the \
+             recipient must configure it (ConfigureAccount)"
+        }
+        ConfidentialDestinationState::CreditsDisabled => {
+            "Recipient token account does not accept confidential transfers; the recipient \
+             must enable confidential credits (EnableConfidentialCredits)"
+        }
+        ConfidentialDestinationState::NotApproved => {
+            "Recipient token account awaits approval by the mint's confidential transfer \
+             authority (ApproveAccount)"
+        }
+    };
+    Err(AppError::Validation(
+        crate::domain::ValidationError::InvalidField {
+            field: "to_address".to_string(),
+            message: message.to_string(),
+        },
+    ))
+}
+
+/// Auditor ciphertexts of the transfer amount (low bits, then high bits),
+/// taken from the validity proof context. Token-2022 checks them against the
+/// proof whether or not the mint has an auditor.
+fn auditor_ciphertexts(
+    context: &BatchedGroupedCiphertext3HandlesValidityProofContext,
+) -> Result<[PodElGamalCiphertext; 2], AppError> {
+    let extract = |grouped: &PodGroupedElGamalCiphertext3Handles| {
+        grouped
+            .try_extract_ciphertext(AUDITOR_HANDLE_INDEX)
+            .map_err(|e| {
+                AppError::Validation(crate::domain::ValidationError::InvalidField {
+                    field: "ciphertext_validity_proof".to_string(),
+                    message: format!("Invalid transfer amount ciphertext: {}", e),
+                })
+            })
+    };
+    Ok([
+        extract(&context.grouped_ciphertext_lo)?,
+        extract(&context.grouped_ciphertext_hi)?,
+    ])
+}
+
+/// Token-2022 confidential transfer instruction referencing the verified
+/// proof context accounts (equality, ciphertext validity, range).
+///
+/// Built with the interface's [`inner_transfer`], so the data is
+/// `TransferInstructionData`: the new decryptable balance, both auditor
+/// ciphertexts and three proof instruction offsets, all 0 because every
+/// proof is read from its context account. Accounts:
+///   0. `[writable]` Source token account
+///   1. `[]`         Token mint
+///   2. `[writable]` Destination token account
+///   3. `[]`         Equality proof context account
+///   4. `[]`         Ciphertext validity proof context account
+///   5. `[]`         Range proof context account
+///   6. `[signer]`   Authority (owner of source account)
+fn confidential_transfer_instruction(
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    proof_contexts: [Pubkey; 3],
+    authority: &Pubkey,
+    new_decryptable_balance: &DecryptableBalance,
+    auditor_ciphertexts: &[PodElGamalCiphertext; 2],
+) -> Result<Instruction, AppError> {
+    let [equality_context, validity_context, range_context] = proof_contexts;
+    let [auditor_ciphertext_lo, auditor_ciphertext_hi] = auditor_ciphertexts;
+
+    inner_transfer(
+        &spl_token_2022::id(),
+        source,
+        mint,
+        destination,
+        new_decryptable_balance,
+        auditor_ciphertext_lo,
+        auditor_ciphertext_hi,
+        authority,
+        &[],
+        ProofLocation::ContextStateAccount(&equality_context),
+        ProofLocation::ContextStateAccount(&validity_context),
+        ProofLocation::ContextStateAccount(&range_context),
+    )
+    .map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to build confidential Transfer instruction: {}",
+            e
+        )))
+    })
+}
+
+/// Token-2022 confidential transfer-with-fee instruction, for mints with the
+/// confidential transfer fee extension.
+///
+/// Built with the interface's [`inner_transfer_with_fee`], so the data is
+/// `TransferWithFeeInstructionData`: the new decryptable balance, both
+/// auditor ciphertexts and five proof instruction offsets (equality,
+/// ciphertext validity, fee sigma, fee ciphertext validity, range), all 0.
+/// Compared to [`confidential_transfer_instruction`], the fee sigma and fee
+/// ciphertext validity contexts sit between the transfer amount validity and
+/// range contexts:
+///   0. `[writable]` Source token account
+///   1. `[]`         Token mint
+///   2. `[writable]` Destination token account
+///   3. `[]`         Equality proof context account
+///   4. `[]`         Transfer amount ciphertext validity proof context account
+///   5. `[]`         Fee sigma proof context account
+///   6. `[]`         Fee ciphertext validity proof context account
+///   7. `[]`         Range proof context account
+///   8. `[signer]`   Authority (owner of source account)
+fn confidential_transfer_with_fee_instruction(
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    proof_contexts: [Pubkey; 5],
+    authority: &Pubkey,
+    new_decryptable_balance: &DecryptableBalance,
+    auditor_ciphertexts: &[PodElGamalCiphertext; 2],
+) -> Result<Instruction, AppError> {
+    let [
+        equality_context,
+        validity_context,
+        fee_sigma_context,
+        fee_validity_context,
+        range_context,
+    ] = proof_contexts;
+    let [auditor_ciphertext_lo, auditor_ciphertext_hi] = auditor_ciphertexts;
+
+    inner_transfer_with_fee(
+        &spl_token_2022::id(),
+        source,
+        mint,
+        destination,
+        new_decryptable_balance,
+        auditor_ciphertext_lo,
+        auditor_ciphertext_hi,
+        authority,
+        &[],
+        ProofLocation::ContextStateAccount(&equality_context),
+        ProofLocation::ContextStateAccount(&validity_context),
+        ProofLocation::ContextStateAccount(&fee_sigma_context),
+        ProofLocation::ContextStateAccount(&fee_validity_context),
+        ProofLocation::ContextStateAccount(&range_context),
+    )
+    .map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to build confidential TransferWithFee instruction: {}",
+            e
+        )))
+    })
+}
+
+/// Work out the amount to send and the fee withheld by the mint.
+///
+/// With `gross_up`, the sent amount is raised so the recipient nets `amount`;
+/// otherwise `amount` is sent and the recipient receives `amount - fee`.
+/// Returns `(send_amount, fee)`.
+fn plan_fee_transfer(
+    fee: &TransferFee,
+    amount: u64,
+    gross_up: bool,
+) -> Result<(u64, u64), AppError> {
+    let overflow = || {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Transfer fee calculation overflowed for amount {}",
+            amount
+        )))
+    };
+
+    let send_amount = if gross_up {
+        fee.calculate_pre_fee_amount(amount).ok_or_else(overflow)?
+    } else {
+        amount
+    };
+    let withheld = fee.calculate_fee(send_amount).ok_or_else(overflow)?;
+    Ok((send_amount, withheld))
+}
+
+/// Raw amount held by a token account, `None` if the data is too short
+fn token_account_balance(data: &[u8]) -> Option<u64> {
+    data.get(TOKEN_ACCOUNT_AMOUNT_OFFSET..TOKEN_ACCOUNT_AMOUNT_OFFSET + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+}
+
+/// Raw units leaving the source account across every transfer of a batch
+fn batch_send_total<'a>(
+    plans: impl IntoIterator<Item = &'a TokenTransferPlan>,
+) -> Result<u64, AppError> {
+    plans
+        .into_iter()
+        .try_fold(0u64, |total, plan| total.checked_add(plan.send_amount))
+        .ok_or_else(|| {
+            AppError::Blockchain(BlockchainError::TransactionFailed(
+                "Batch transfer total overflowed".to_string(),
+            ))
+        })
+}
+
+/// Record on `outcome` what the planned transfers of a Token-2022 fee mint
+/// debit in total and how much fee they withhold. Left unset for mints
+/// without a transfer fee.
+fn with_token_fee<'a>(
+    outcome: SubmissionOutcome,
+    plans: impl IntoIterator<Item = &'a TokenTransferPlan>,
+) -> SubmissionOutcome {
+    let mut fee_plans = plans
+        .into_iter()
+        .filter_map(|plan| plan.expected_fee.map(|fee| (plan.send_amount, fee)))
+        .peekable();
+    if fee_plans.peek().is_none() {
+        return outcome;
+    }
+    let (sent, fee) = fee_plans.fold((0u64, 0u64), |(sent, fee), (plan_sent, plan_fee)| {
+        (sent.saturating_add(plan_sent), fee.saturating_add(plan_fee))
+    });
+    outcome.with_token_transfer_fee(sent, fee)
+}
+
+/// Compute unit limit of a batch transaction paying `recipients` recipients:
+/// the configured limit, raised to cover every recipient
+fn batch_compute_unit_limit(configured: u32, recipients: usize) -> u32 {
+    let needed = u32::try_from(recipients)
+        .unwrap_or(u32::MAX)
+        .saturating_mul(BATCH_RECIPIENT_COMPUTE_UNITS);
+    configured.max(needed).min(MAX_COMPUTE_UNIT_LIMIT)
+}
+
+/// Serialized size of a legacy transaction carrying `instructions`, with
+/// one signature per required signer
+fn legacy_transaction_size(payer: &Pubkey, instructions: &[Instruction]) -> usize {
+    let transaction = Transaction::new_with_payer(instructions, Some(payer));
+    bincode::serde::encode_to_vec(&transaction, bincode::config::legacy())
+        .map_or(usize::MAX, |bytes| bytes.len())
+}
+
+/// Pack per-recipient instruction `groups` into transactions of at most
+/// `MAX_TRANSACTION_SIZE` bytes, in order and without splitting a group.
+///
+/// `prefix` and `suffix` are the instructions every transaction carries
+/// around the groups; they count towards its size but are not returned.
+/// Sizes are measured as legacy transactions, an upper bound for v0
+/// transactions using a lookup table.
+fn pack_instruction_groups(
+    payer: &Pubkey,
+    prefix: &[Instruction],
+    suffix: &[Instruction],
+    groups: Vec<Vec<Instruction>>,
+) -> Result<Vec<Vec<Vec<Instruction>>>, AppError> {
+    let fits = |chunk: &[Vec<Instruction>]| {
+        let instructions: Vec<Instruction> = prefix
+            .iter()
+            .chain(chunk.iter().flatten())
+            .chain(suffix)
+            .cloned()
+            .collect();
+        legacy_transaction_size(payer, &instructions) <= MAX_TRANSACTION_SIZE
+    };
+
+    let mut transactions: Vec<Vec<Vec<Instruction>>> = Vec::new();
+    let mut current: Vec<Vec<Instruction>> = Vec::new();
+    for group in groups {
+        current.push(group);
+        if fits(&current) {
+            continue;
+        }
+        let group = current.pop().unwrap_or_default();
+        if current.is_empty() || !fits(std::slice::from_ref(&group)) {
+            return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                format!(
+                    "Instructions for a single recipient exceed the {}-byte transaction size limit",
+                    MAX_TRANSACTION_SIZE
+                ),
+            )));
+        }
+        transactions.push(std::mem::take(&mut current));
+        current.push(group);
+    }
+    if !current.is_empty() {
+        transactions.push(current);
+    }
+    Ok(transactions)
+}
+
+/// Network fee of a single-signature transaction requesting
+/// `compute_unit_limit` units at `priority_fee` micro-lamports per unit
+fn estimated_fee_lamports(priority_fee: u64, compute_unit_limit: u32) -> u64 {
+    FeeEstimate::new(LAMPORTS_PER_SIGNATURE, priority_fee, compute_unit_limit, 0).total_lamports
+}
+
+/// Instructions of a SOL transfer from `payer`: the memo, then the transfer
+fn sol_transfer_instructions(
+    payer: &Pubkey,
+    to_pubkey: &Pubkey,
+    amount_lamports: u64,
+    memo: Option<&str>,
+) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    if let Some(memo) = memo {
+        instructions.push(memo_instruction(memo, payer));
+    }
+    instructions.push(system_instruction::transfer(
+        payer,
+        to_pubkey,
+        amount_lamports,
+    ));
+    instructions
+}
+
+/// Instruction closing the empty token account at `address` and returning
+/// its rent to the `relayer`.
+///
+/// Returns `None` unless the account belongs to the SPL Token or Token-2022
+/// program, is owned by the relayer, holds no tokens, is not frozen, and can
+/// be closed by the relayer. Token-2022 accounts with withheld transfer fees or a
+/// confidential balance are kept, as closing them would fail.
+fn close_empty_token_account_instruction(
+    relayer: &Pubkey,
+    address: &Pubkey,
+    account: &solana_sdk::account::Account,
+) -> Option<Instruction> {
+    let program_id = account.owner;
+    if program_id != spl_token_interface::id() && program_id != spl_token_2022_interface::id() {
+        return None;
+    }
+
+    // SPL Token accounts share the base layout, so one unpack covers both programs
+    let state = StateWithExtensions::<Token2022Account>::unpack(&account.data).ok()?;
+    let base = state.base;
+    if base.owner != *relayer || base.amount != 0 || base.is_frozen() {
+        return None;
+    }
+    if Option::<Pubkey>::from(base.close_authority).is_some_and(|authority| authority != *relayer) {
+        return None;
+    }
+    if state
+        .get_extension::<TransferFeeAmount>()
+        .is_ok_and(|fees| u64::from(fees.withheld_amount) > 0)
+        || state.get_extension::<ConfidentialTransferAccount>().is_ok()
+    {
+        return None;
+    }
+
+    spl_token_2022_interface::instruction::close_account(
+        &program_id,
+        address,
+        relayer,
+        relayer,
+        &[],
+    )
+    .ok()
+}
+
+/// Build the token transfer instruction for the mint's token program, using
+/// `transfer_checked_with_fee` when the mint charges a transfer fee
+#[allow(clippy::too_many_arguments)]
+fn build_token_transfer_instruction(
+    token_program_id: &Pubkey,
+    source: &Pubkey,
+    mint: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+    decimals: u8,
+    transfer_fee: Option<u64>,
+) -> Result<Instruction, AppError> {
+    let result = match transfer_fee {
+        Some(fee) => transfer_checked_with_fee(
+            token_program_id,
+            source,
+            mint,
+            destination,
+            authority,
+            &[],
+            amount,
+            decimals,
+            fee,
+        ),
+        None if *token_program_id == spl_token_2022_interface::id() => {
+            spl_token_2022_interface::instruction::transfer_checked(
+                token_program_id,
+                source,
+                mint,
+                destination,
+                authority,
+                &[],
+                amount,
+                decimals,
+            )
+        }
+        None => token_instruction::transfer_checked(
+            token_program_id,
+            source,
+            mint,
+            destination,
+            authority,
+            &[],
+            amount,
+            decimals,
+        ),
+    };
+
+    result.map_err(|e| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Failed to create transfer instruction: {}",
+            e
+        )))
+    })
+}
+
+/// Build an SPL Memo instruction signed by the relayer, so the memo is
+/// attributable to the sending wallet on-chain
+fn memo_instruction(memo: &str, signer: &Pubkey) -> Instruction {
+    spl_memo_interface::instruction::build_memo(
+        &spl_memo_interface::v3::id(),
+        memo.as_bytes(),
+        &[signer],
+    )
+}
+
+/// Proof verification transaction of a confidential transfer, signed once
+/// its blockhash is known
+#[derive(Clone)]
+struct ProofTransaction<'a> {
+    description: &'static str,
+    instructions: Vec<Instruction>,
+    /// Signers besides the fee payer (ephemeral context and record accounts)
+    signers: Vec<&'a Keypair>,
+}
+
+impl ProofTransaction<'_> {
+    /// Sign with the fee payer and the account signers
+    fn sign(&self, payer: &Keypair, recent_blockhash: Hash) -> Transaction {
+        let mut signers = vec![payer];
+        signers.extend(self.signers.iter().copied());
+        Transaction::new_signed_with_payer(
+            &self.instructions,
+            Some(&payer.pubkey()),
+            signers.as_slice(),
+            recent_blockhash,
+        )
+    }
+}
+
+/// Sign proof verification transactions for a single bundle. Order is
+/// preserved and all transactions share one blockhash. The Jito `tip`
+/// instruction, if any, is appended to the last transaction: block engines
+/// drop bundles that do not pay one.
+fn assemble_proof_bundle(
+    proofs: &[ProofTransaction<'_>],
+    payer: &Keypair,
+    recent_blockhash: Hash,
+    tip: Option<Instruction>,
+) -> Vec<Transaction> {
+    let last = proofs.len().saturating_sub(1);
+    proofs
+        .iter()
+        .enumerate()
+        .map(|(index, proof)| match &tip {
+            Some(tip) if index == last => {
+                let mut tipped = proof.clone();
+                tipped.instructions.push(tip.clone());
+                tipped.sign(payer, recent_blockhash)
+            }
+            _ => proof.sign(payer, recent_blockhash),
+        })
+        .collect()
+}
+
+/// Insert the `advance_nonce_account` instruction, which the runtime requires
+/// to be the first instruction of a durable nonce transaction
+fn prepend_advance_nonce(
+    instructions: &mut Vec<Instruction>,
+    nonce_account: &Pubkey,
+    authority: &Pubkey,
+) {
+    instructions.insert(
+        0,
+        system_instruction::advance_nonce_account(nonce_account, authority),
+    );
+}
+
+/// Decode the nonce value stored in an initialized nonce account
+fn parse_durable_nonce(data: &[u8]) -> Result<Hash, AppError> {
+    let invalid =
+        |message: &str| AppError::Blockchain(BlockchainError::RpcError(message.to_string()));
+
+    let state = data
+        .get(4..8)
+        .and_then(|tag| tag.try_into().ok())
+        .map(u32::from_le_bytes)
+        .ok_or_else(|| invalid("Invalid nonce account data"))?;
+    if state != NONCE_STATE_INITIALIZED {
+        return Err(invalid("Nonce account is not initialized"));
+    }
+
+    let nonce: [u8; 32] = data
+        .get(NONCE_VALUE_RANGE)
+        .and_then(|raw| raw.try_into().ok())
+        .ok_or_else(|| invalid("Invalid nonce account data"))?;
+    Ok(Hash::new_from_array(nonce))
+}
+
+/// Decode the addresses stored in an address lookup table account.
+/// The account data is a fixed-size metadata header followed by 32-byte keys.
+fn parse_lookup_table_addresses(data: &[u8]) -> Result<Vec<Pubkey>, AppError> {
+    let raw = data
+        .get(LOOKUP_TABLE_META_SIZE..)
+        .filter(|raw| raw.len() % 32 == 0)
+        .ok_or_else(|| {
+            AppError::Blockchain(BlockchainError::RpcError(
+                "Invalid address lookup table account data".to_string(),
+            ))
+        })?;
+
+    Ok(raw
+        .chunks_exact(32)
+        .map(|chunk| Pubkey::new_from_array(chunk.try_into().expect("chunk is 32 bytes")))
+        .collect())
+}
+
+/// The associated token account creation among `instructions` that `error`
+/// reports failed because the account already exists
+fn failed_account_creation<'a>(
+    error: &AppError,
+    instructions: &'a [Instruction],
+) -> Option<&'a Instruction> {
+    match error {
+        AppError::Blockchain(BlockchainError::AccountAlreadyInUse { instruction, .. }) => {
+            instructions
+                .get(usize::from(*instruction))
+                .filter(|ix| ix.program_id == spl_associated_token_account::id())
+        }
+        _ => None,
+    }
+}
+
+/// Whether `error` means the transaction was rejected or failed, rather
+/// than its outcome being unknown
+fn is_definite_failure(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::Blockchain(
+            BlockchainError::TransactionFailed(_)
+                | BlockchainError::AccountAlreadyInUse { .. }
+                | BlockchainError::JitoBundleFailed(_)
+        )
+    )
+}
+
+/// `AccountAlreadyInUse` for a transaction that failed with the system
+/// program's error of that name, `None` for any other failure
+pub(crate) fn account_already_in_use(error: &TransactionError, message: &str) -> Option<AppError> {
+    match error {
+        TransactionError::InstructionError(instruction, InstructionError::Custom(code))
+            if *code == SystemError::AccountAlreadyInUse as u32 =>
+        {
+            Some(AppError::Blockchain(BlockchainError::AccountAlreadyInUse {
+                instruction: *instruction,
+                message: message.to_string(),
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// [`account_already_in_use`] for a transaction error reported as JSON by
+/// `sendTransaction` or `simulateTransaction`
+pub(crate) fn rpc_account_already_in_use(
+    error: &serde_json::Value,
+    message: &str,
+) -> Option<AppError> {
+    serde_json::from_value::<TransactionError>(error.clone())
+        .ok()
+        .and_then(|error| account_already_in_use(&error, message))
+}
+
+/// Wrap a blockchain error with the blockhash that was used for the transaction.
+/// This enables "sticky blockhash" logic: on retry, the service layer can reuse the
+/// same blockhash (which will fail safely if already processed) instead of fetching
+/// a new one (which could lead to double-spend).
+fn wrap_error_with_blockhash(error: AppError, blockhash: &str) -> AppError {
+    match error {
+        AppError::Blockchain(BlockchainError::Timeout(msg)) => {
+            AppError::Blockchain(BlockchainError::TimeoutWithBlockhash {
+                message: msg,
+                blockhash: blockhash.to_string(),
+            })
+        }
+        AppError::Blockchain(
+            BlockchainError::Connection(ref msg) | BlockchainError::RpcError(ref msg),
+        ) => AppError::Blockchain(BlockchainError::NetworkErrorWithBlockhash {
+            message: msg.clone(),
+            blockhash: blockhash.to_string(),
+        }),
+        // JitoStateUnknown, JitoBundleFailed, etc. pass through — they have
+        // their own retry semantics and the blockhash is already tracked separately.
+        other => other,
+    }
+}
+
+/// Parse a base58-encoded private key into a SigningKey
+pub fn signing_key_from_base58(secret: &SecretString) -> Result<SigningKey, AppError> {
+    let key_bytes = bs58::decode(secret.expose_secret())
+        .into_vec()
+        .map_err(|e| AppError::Blockchain(BlockchainError::InvalidSignature(e.to_string())))?;
+
+    // Handle both 32-byte (seed) and 64-byte (keypair) formats
+    let key_array: [u8; 32] = if key_bytes.len() == 64 {
+        // Solana keypair format: first 32 bytes are the secret key
+        key_bytes[..32].try_into().map_err(|_| {
+            AppError::Blockchain(BlockchainError::InvalidSignature(
+                "Invalid keypair format".to_string(),
+            ))
+        })?
+    } else if key_bytes.len() == 32 {
+        key_bytes.try_into().map_err(|v: Vec<u8>| {
+            AppError::Blockchain(BlockchainError::InvalidSignature(format!(
+                "Key must be 32 bytes, got {}",
+                v.len()
+            )))
+        })?
+    } else {
+        return Err(AppError::Blockchain(BlockchainError::InvalidSignature(
+            format!("Key must be 32 or 64 bytes, got {}", key_bytes.len()),
+        )));
+    };
+
+    Ok(SigningKey::from_bytes(&key_array))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::instruction::AccountMeta;
+    use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+    static NEXT_TEST_KEY_SEED: AtomicU8 = AtomicU8::new(1);
+
+    fn test_signing_key() -> SigningKey {
+        let seed = NEXT_TEST_KEY_SEED.fetch_add(1, Ordering::Relaxed);
+        let mut bytes = [0_u8; 32];
+        bytes[0] = seed;
+        bytes[31] = seed.wrapping_mul(31);
+        SigningKey::from_bytes(&bytes)
+    }
+
+    #[test]
+    fn test_client_creation() {
+        let signing_key = test_signing_key();
+        let client =
+            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key);
+        assert!(client.is_ok());
+    }
+
+    #[test]
+    fn test_public_key_generation() {
+        let signing_key = test_signing_key();
+        let client =
+            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
+                .unwrap();
+        let pubkey = client.public_key();
+        assert!(!pubkey.is_empty());
+        // Verify it decodes to 32 bytes (length can be 43 or 44 chars)
+        let decoded = bs58::decode(&pubkey)
+            .into_vec()
+            .expect("Should be valid base58");
+        assert_eq!(decoded.len(), 32);
+    }
+
+    #[test]
+    fn test_signing() {
+        let signing_key = test_signing_key();
+        let client =
+            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
+                .unwrap();
+        let signature = client.sign(b"test message");
+        assert!(!signature.is_empty());
+    }
+
+    fn multi_key_client(
+        keys: &[SigningKey],
+        policy: crate::infra::blockchain::signers::KeySelectionPolicy,
+    ) -> RpcBlockchainClient {
+        RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", keys[0].clone())
+            .unwrap()
+            .with_additional_signing_keys(&keys[1..], policy)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_signer_selection_is_deterministic_per_request_id() {
+        use crate::infra::blockchain::signers::KeySelectionPolicy;
+
+        let keys = [test_signing_key(), test_signing_key(), test_signing_key()];
+        let client = multi_key_client(&keys, KeySelectionPolicy::RequestId);
+        // A restarted relayer with the same keys makes the same choices
+        let restarted = multi_key_client(&keys, KeySelectionPolicy::RequestId);
+
+        let pubkeys = client.signer_pubkeys();
+        assert_eq!(pubkeys.len(), 3);
+        assert_eq!(pubkeys[0], client.public_key());
+
+        let mut used = std::collections::HashSet::new();
+        for i in 0..30 {
+            let id = format!("transfer-{i}");
+            let signer = client.select_signer(&id).unwrap();
+            assert_eq!(client.select_signer(&id).as_ref(), Some(&signer));
+            assert_eq!(restarted.select_signer(&id).as_ref(), Some(&signer));
+            assert!(pubkeys.contains(&signer));
+            used.insert(signer);
+        }
+        assert_eq!(used.len(), 3);
+    }
+
+    #[test]
+    fn test_round_robin_signer_selection() {
+        use crate::infra::blockchain::signers::KeySelectionPolicy;
+
+        let keys = [test_signing_key(), test_signing_key()];
+        let client = multi_key_client(&keys, KeySelectionPolicy::RoundRobin);
+        let pubkeys = client.signer_pubkeys();
+
+        let picks: Vec<String> = (0..4)
+            .map(|_| client.select_signer("same-id").unwrap())
+            .collect();
+        assert_eq!(
+            picks,
+            vec![
+                pubkeys[0].clone(),
+                pubkeys[1].clone(),
+                pubkeys[0].clone(),
+                pubkeys[1].clone()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_additional_signing_keys_rejected() {
+        use crate::infra::blockchain::signers::KeySelectionPolicy;
+
+        let primary = test_signing_key();
+        let duplicate =
+            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", primary.clone())
+                .unwrap()
+                .with_additional_signing_keys(&[primary], KeySelectionPolicy::RequestId);
+        assert!(duplicate.is_err());
+
+        let config = RpcClientConfig {
+            nonce_account: Some(Pubkey::new_unique()),
+            ..Default::default()
+        };
+        let with_nonce =
+            RpcBlockchainClient::new("https://api.devnet.solana.com", test_signing_key(), config)
+                .unwrap()
+                .with_additional_signing_keys(&[test_signing_key()], KeySelectionPolicy::RequestId);
+        assert!(with_nonce.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_retired_key_signs_pinned_transfers_only() {
+        use crate::infra::blockchain::signers::KeySelectionPolicy;
+
+        let keys = [test_signing_key(), test_signing_key()];
+        let retired = test_signing_key();
+        let client = multi_key_client(&keys, KeySelectionPolicy::RoundRobin)
+            .with_retired_signing_keys(std::slice::from_ref(&retired))
+            .unwrap();
+        let retired_pubkey = bs58::encode(retired.verifying_key().as_bytes()).into_string();
+
+        assert_eq!(client.signer_pubkeys().len(), 2);
+        assert!(!client.signer_pubkeys().contains(&retired_pubkey));
+        for i in 0..4 {
+            assert_ne!(
+                client.select_signer(&format!("t{i}")),
+                Some(retired_pubkey.clone())
+            );
+        }
+
+        assert_eq!(client.payer_pubkey(Some(&retired_pubkey)), retired_pubkey);
+    }
+
+    #[tokio::test]
+    async fn test_pinned_signer_selects_keypair() {
+        use crate::infra::blockchain::signers::KeySelectionPolicy;
+
+        let keys = [test_signing_key(), test_signing_key()];
+        let client = multi_key_client(&keys, KeySelectionPolicy::RequestId);
+        let pubkeys = client.signer_pubkeys();
+
+        assert_eq!(client.payer_pubkey(None), pubkeys[0]);
+        assert_eq!(client.payer_pubkey(Some(&pubkeys[1])), pubkeys[1]);
+        let unknown = Pubkey::new_unique().to_string();
+        assert!(client.keypair(Some(&unknown)).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_submit_rejects_unconfigured_signer() {
+        let client =
+            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", test_signing_key())
+                .unwrap();
+        let request = TransferRequest {
+            id: "pinned_elsewhere".to_string(),
+            signer_pubkey: Some(Pubkey::new_unique().to_string()),
+            ..Default::default()
+        };
+
+        let result = client.submit_transaction(&request).await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::WalletError(_)))
+        ));
+    }
+
+    /// Standard sender whose first `expiries` sends expire unconfirmed
+    struct ExpiringSender {
+        expiries: Mutex<u32>,
+        fresh_blockhash: Hash,
+        sent: Mutex<Vec<VersionedTransaction>>,
+    }
+
+    impl ExpiringSender {
+        fn new(expiries: u32) -> Self {
+            Self {
+                expiries: Mutex::new(expiries),
+                fresh_blockhash: Hash::new_unique(),
+                sent: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl StandardSender for ExpiringSender {
+        async fn latest_blockhash(&self) -> Result<Hash, AppError> {
+            Ok(self.fresh_blockhash)
+        }
+
+        async fn send_until_expiry(
+            &self,
+            transaction: &VersionedTransaction,
+            _poll_interval: Duration,
+            _max_poll_interval: Duration,
+        ) -> Result<Option<Signature>, AppError> {
+            self.sent.lock().unwrap().push(transaction.clone());
+            let mut expiries = self.expiries.lock().unwrap();
+            if *expiries > 0 {
+                *expiries -= 1;
+                return Ok(None);
+            }
+            Ok(Some(transaction.signatures[0]))
+        }
+    }
+
+    fn signed_transfer(payer: &Keypair, blockhash: Hash) -> VersionedTransaction {
+        let instruction = system_instruction::transfer(&payer.pubkey(), &Pubkey::new_unique(), 1);
+        Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&payer.pubkey()),
+            &[payer],
+            blockhash,
+        )
+        .into()
+    }
+
+    #[tokio::test]
+    async fn test_expired_blockhash_is_refreshed_and_resent() {
+        let payer = Keypair::new();
+        let original = signed_transfer(&payer, Hash::new_unique());
+        let sender = ExpiringSender::new(1);
+
+        let (signature, blockhash) = send_with_blockhash_refresh(
+            &sender,
+            &original,
+            &[&payer],
+            Duration::from_secs(60),
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+        )
+        .await
+        .unwrap();
+
+        let sent = sender.sent.lock().unwrap();
+        assert_eq!(sent.len(), 2);
+        assert_eq!(sent[0], original);
+        // The second attempt is the same transfer under the fresh blockhash
+        let resent = &sent[1];
+        assert_eq!(blockhash, sender.fresh_blockhash);
+        assert_eq!(*resent.message.recent_blockhash(), sender.fresh_blockhash);
+        assert_eq!(
+            resent.message.instructions(),
+            original.message.instructions()
+        );
+        assert!(resent.verify_with_results().iter().all(|valid| *valid));
+        assert_eq!(signature, resent.signatures[0]);
+        assert_ne!(signature, original.signatures[0]);
+    }
+
+    #[tokio::test]
+    async fn test_resubmit_stops_at_deadline() {
+        let payer = Keypair::new();
+        let original = signed_transfer(&payer, Hash::new_unique());
+        let sender = ExpiringSender::new(u32::MAX);
+
+        let result = send_with_blockhash_refresh(
+            &sender,
+            &original,
+            &[&payer],
+            Duration::ZERO,
+            Duration::from_millis(1),
+            Duration::from_millis(1),
+        )
+        .await;
+
+        // The only attempt expired, so its blockhash is safe to retry past
+        let expired = original.message.recent_blockhash().to_string();
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::TimeoutWithBlockhash { ref blockhash, .. }))
+                if *blockhash == expired
+        ));
+        assert_eq!(sender.sent.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_resubmit_signers_only_for_refreshable_transactions() {
+        let client =
+            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", test_signing_key())
+                .unwrap();
+        let own = signed_transfer(client.keypair(None).unwrap(), Hash::new_unique());
+        let signers = client.resubmit_signers(&own).unwrap();
+        assert_eq!(signers.len(), 1);
+        assert_eq!(signers[0].pubkey(), client.keypair(None).unwrap().pubkey());
+
+        // Signed by a key the client can't sign with again
+        let foreign = signed_transfer(&Keypair::new(), Hash::new_unique());
+        assert!(client.resubmit_signers(&foreign).is_none());
+
+        // A durable nonce never expires, and zero disables resubmission
+        for config in [
+            RpcClientConfig {
+                nonce_account: Some(Pubkey::new_unique()),
+                ..Default::default()
+            },
+            RpcClientConfig {
+                resubmit_deadline: Duration::ZERO,
+                ..Default::default()
+            },
+        ] {
+            let client = RpcBlockchainClient::new(
+                "https://api.devnet.solana.com",
+                test_signing_key(),
+                config,
+            )
+            .unwrap();
+            let own = signed_transfer(client.keypair(None).unwrap(), Hash::new_unique());
+            assert!(client.resubmit_signers(&own).is_none());
+        }
+    }
+
+    #[test]
+    fn test_signing_key_from_base58_valid_32_bytes() {
+        let original_key = test_signing_key();
+        let encoded = bs58::encode(original_key.to_bytes()).into_string();
+        let secret = SecretString::from(encoded);
+        let result = signing_key_from_base58(&secret);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_signing_key_from_base58_valid_64_bytes() {
+        let original_key = test_signing_key();
+        let mut keypair = original_key.to_bytes().to_vec();
+        keypair.extend_from_slice(original_key.verifying_key().as_bytes());
+        let encoded = bs58::encode(&keypair).into_string();
+        let secret = SecretString::from(encoded);
+        let result = signing_key_from_base58(&secret);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_signing_key_from_base58_invalid() {
+        let secret = SecretString::from("invalid-base58!!!");
+        let result = signing_key_from_base58(&secret);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rpc_client_config_default() {
+        let config = RpcClientConfig::default();
+        assert_eq!(config.max_retries, 3);
+        assert_eq!(config.timeout, Duration::from_secs(30));
+        assert_eq!(config.confirmation_timeout, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_signing_key_from_base58_wrong_length() {
+        // 16 bytes - too short
+        let short_key = bs58::encode(vec![0u8; 16]).into_string();
+        let secret = SecretString::from(short_key);
+        let result = signing_key_from_base58(&secret);
+        assert!(result.is_err());
+
+        // 48 bytes - wrong size (not 32 or 64)
+        let wrong_key = bs58::encode(vec![0u8; 48]).into_string();
+        let secret = SecretString::from(wrong_key);
+        let result = signing_key_from_base58(&secret);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_rpc_client_config_custom() {
+        let config = RpcClientConfig {
+            timeout: Duration::from_secs(60),
+            max_retries: 5,
+            retry_delay: Duration::from_millis(1000),
+            confirmation_timeout: Duration::from_secs(120),
+            ..Default::default()
+        };
+        assert_eq!(config.timeout, Duration::from_secs(60));
+        assert_eq!(config.max_retries, 5);
+        assert_eq!(config.retry_delay, Duration::from_millis(1000));
+        assert_eq!(config.confirmation_timeout, Duration::from_secs(120));
+    }
+
+    #[test]
+    fn test_signing_determinism() {
+        let signing_key = test_signing_key();
+        let client =
+            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
+                .unwrap();
+
+        // Same message should produce same signature
+        let sig1 = client.sign(b"test message");
+        let sig2 = client.sign(b"test message");
+        assert_eq!(sig1, sig2);
+
+        // Different message should produce different signature
+        let sig3 = client.sign(b"different message");
+        assert_ne!(sig1, sig3);
+    }
+
+    // --- MOCK PROVIDER TESTS ---
+    use std::sync::Mutex;
+
+    #[cfg(test)]
+    #[allow(dead_code)]
+    enum BlockchainErrorType {
+        Timeout,
+        Rpc,
+    }
+
+    struct MockState {
+        requests: Vec<String>,
+        should_fail_count: u32,
+        failure_error: Option<BlockchainErrorType>,
+        next_response: Option<serde_json::Value>,
+        /// Per-method result, taking precedence over `next_response`
+        method_responses: std::collections::HashMap<String, Result<serde_json::Value, String>>,
+    }
+
+    struct MockSolanaRpcProvider {
+        state: Mutex<MockState>,
+        signing_key: SigningKey,
+    }
+
+    impl MockSolanaRpcProvider {
+        fn new() -> Self {
+            Self {
+                state: Mutex::new(MockState {
+                    requests: Vec::new(),
+                    should_fail_count: 0,
+                    failure_error: None,
+                    next_response: None,
+                    method_responses: std::collections::HashMap::new(),
+                }),
+                signing_key: test_signing_key(),
+            }
+        }
+
+        fn with_failure(count: u32, error_type: BlockchainErrorType) -> Self {
+            let provider = Self::new(); // removed `mut` since we don’t mutate `provider` itself
+            {
+                let mut state = provider.state.lock().unwrap();
+                state.should_fail_count = count;
+                state.failure_error = Some(error_type);
+            }
+            provider
+        }
+    }
+
+    #[async_trait]
+    impl SolanaRpcProvider for MockSolanaRpcProvider {
+        async fn send_request(
+            &self,
+            method: &str,
+            _params: serde_json::Value,
+        ) -> Result<serde_json::Value, AppError> {
+            let mut state = self.state.lock().unwrap();
+            state.requests.push(method.to_string());
+
+            if state.should_fail_count > 0 {
+                state.should_fail_count -= 1;
+                if let Some(ref err) = state.failure_error {
+                    return match err {
+                        BlockchainErrorType::Timeout => Err(AppError::Blockchain(
+                            BlockchainError::Timeout("Mock timeout".to_string()),
+                        )),
+                        BlockchainErrorType::Rpc => Err(AppError::Blockchain(
+                            BlockchainError::RpcError("Mock RPC error".to_string()),
+                        )),
+                    };
+                }
+            }
+
+            if let Some(result) = state.method_responses.get(method) {
+                return result
+                    .clone()
+                    .map_err(|e| AppError::Blockchain(BlockchainError::RpcError(e)));
+            }
+
+            if let Some(resp) = &state.next_response {
+                return Ok(resp.clone());
+            }
+
+            Ok(serde_json::Value::Null)
+        }
+
+        fn public_key(&self) -> String {
+            bs58::encode(self.signing_key.verifying_key().as_bytes()).into_string()
+        }
+
+        fn sign(&self, message: &[u8]) -> String {
+            let signature = self.signing_key.sign(message);
+            bs58::encode(signature.to_bytes()).into_string()
+        }
+    }
+
+    /// Client over a node reporting `slot`, `getHealth` result `health` and version 2.1.13
+    fn node_health_client(
+        slot: u64,
+        health: Result<&str, &str>,
+        max_slot_lag: u64,
+    ) -> RpcBlockchainClient {
+        let provider = MockSolanaRpcProvider::new();
+        {
+            let mut state = provider.state.lock().unwrap();
+            let responses = &mut state.method_responses;
+            responses.insert("getSlot".to_string(), Ok(serde_json::json!(slot)));
+            responses.insert(
+                "getHealth".to_string(),
+                health
+                    .map(|health| serde_json::json!(health))
+                    .map_err(str::to_string),
+            );
+            responses.insert(
+                "getVersion".to_string(),
+                Ok(serde_json::json!({ "solana-core": "2.1.13", "feature-set": 3_294_202_862u64 })),
+            );
+        }
+        let config = RpcClientConfig {
+            max_retries: 0,
+            max_slot_lag,
+            ..Default::default()
+        };
+        RpcBlockchainClient::with_provider(Box::new(provider), config)
+    }
+
+    #[tokio::test]
+    async fn test_detailed_health_reports_version_and_slot() {
+        let client = node_health_client(1_000, Ok("ok"), DEFAULT_MAX_SLOT_LAG);
+
+        let health = client.detailed_health().await.unwrap();
+        assert_eq!(health.status, HealthStatus::Healthy);
+        assert_eq!(health.slot, 1_000);
+        // No previous observation to project from yet
+        assert_eq!(health.slot_lag, None);
+        assert_eq!(health.node_version.as_deref(), Some("2.1.13"));
+        assert_eq!(health.node_error, None);
+
+        let health = client.detailed_health().await.unwrap();
+        assert_eq!(health.slot_lag, Some(0));
+        assert_eq!(health.status, HealthStatus::Healthy);
+    }
+
+    #[tokio::test]
+    async fn test_detailed_health_flags_stale_slot_as_degraded() {
+        let client = node_health_client(1_000, Ok("ok"), 0);
+        // The node reported the same slot 2 seconds (4+ slots) ago
+        *client.slot_observation.lock().unwrap() =
+            Some((1_000, Instant::now() - Duration::from_secs(2)));
+
+        let health = client.detailed_health().await.unwrap();
+        assert_eq!(health.status, HealthStatus::Degraded);
+        assert!(health.slot_lag.unwrap() >= 4, "{:?}", health.slot_lag);
+
+        // The stalled slot keeps its baseline rather than resetting the lag
+        let health = client.detailed_health().await.unwrap();
+        assert_eq!(health.status, HealthStatus::Degraded);
+        assert!(health.slot_lag.unwrap() >= 4);
+    }
+
+    #[tokio::test]
+    async fn test_detailed_health_unhealthy_node_is_degraded() {
+        let client = node_health_client(1_000, Err("Node is behind by 42 slots"), 150);
+
+        let health = client.detailed_health().await.unwrap();
+        assert_eq!(health.status, HealthStatus::Degraded);
+        assert!(
+            health
+                .node_error
+                .as_deref()
+                .unwrap()
+                .contains("Node is behind by 42 slots")
+        );
+    }
+
+    #[test]
+    fn test_slot_lag_projection() {
+        let start = Instant::now();
+        let later = start + Duration::from_secs(60);
+        assert_eq!(slot_lag(None, 1_000, later), None);
+        // 60s at 500ms per slot: at least 120 slots expected
+        assert_eq!(slot_lag(Some((1_000, start)), 1_000, later), Some(120));
+        assert_eq!(slot_lag(Some((1_000, start)), 1_100, later), Some(20));
+        // Faster than the slowest expected slot time is no lag
+        assert_eq!(slot_lag(Some((1_000, start)), 1_150, later), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_rpc_client_retry_logic_success() {
+        // Setup provider that fails twice then succeeds
+        let provider = MockSolanaRpcProvider::with_failure(2, BlockchainErrorType::Timeout);
+        let config = RpcClientConfig {
+            max_retries: 3,
+            retry_delay: Duration::from_millis(1), // Fast retry
+            ..Default::default()
+        };
+
+        // Set success response
+        {
+            let mut state = provider.state.lock().unwrap();
+            state.next_response = Some(serde_json::json!(12345u64)); // Slot response
+        }
+
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        // Call health_check (uses getSlot)
+        let result = client.health_check().await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rpc_client_retry_logic_failure() {
+        // Setup provider that fails 4 times (max retries is 3)
+        let provider = MockSolanaRpcProvider::with_failure(4, BlockchainErrorType::Timeout);
+        let config = RpcClientConfig {
+            max_retries: 3,
+            retry_delay: Duration::from_millis(1),
+            ..Default::default()
+        };
+
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.health_check().await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::Timeout(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_skip_preflight_override_takes_precedence() {
+        let config = RpcClientConfig {
+            skip_preflight: false,
+            ..Default::default()
+        };
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(MockSolanaRpcProvider::new()), config);
+
+        assert!(!client.skip_preflight(SubmitOptions::default()));
+        let skip = SubmitOptions {
+            skip_preflight: Some(true),
+            ..SubmitOptions::default()
+        };
+        assert!(client.skip_preflight(skip));
+        // An unset override falls back to the configured default
+        let unset = SubmitOptions {
+            skip_preflight: None,
+            ..SubmitOptions::default()
+        };
+        assert!(!client.skip_preflight(unset));
+        assert!(RpcClientConfig::default().skip_preflight);
+    }
+
+    fn rent_client(
+        responses: Vec<Result<serde_json::Value, MockErrorKind>>,
+        rent_cache_ttl: Duration,
+    ) -> (RpcBlockchainClient, std::sync::Arc<Mutex<Vec<String>>>) {
+        let provider = ConfigurableMockProvider::with_responses(responses);
+        let methods = provider.method_log();
+        let config = RpcClientConfig {
+            max_retries: 0,
+            rent_cache_ttl,
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        (client, methods)
+    }
+
+    /// Proof context account sizes looked up by every confidential transfer
+    fn proof_context_sizes() -> [usize; 3] {
+        [
+            std::mem::size_of::<ProofContextState<CiphertextCommitmentEqualityProofContext>>(),
+            std::mem::size_of::<
+                ProofContextState<BatchedGroupedCiphertext3HandlesValidityProofContext>,
+            >(),
+            std::mem::size_of::<ProofContextState<BatchedRangeProofContext>>(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_rent_exemption_fetched_once_per_size() {
+        let (client, methods) = rent_client(
+            vec![
+                Ok(serde_json::json!(1_000u64)),
+                Ok(serde_json::json!(2_000u64)),
+                Ok(serde_json::json!(3_000u64)),
+            ],
+            DEFAULT_RENT_CACHE_TTL,
+        );
+
+        // Two transfers look up the same three sizes
+        for _ in 0..2 {
+            let mut rents = Vec::new();
+            for size in proof_context_sizes() {
+                rents.push(
+                    client
+                        .minimum_balance_for_rent_exemption(size)
+                        .await
+                        .unwrap(),
+                );
+            }
+            assert_eq!(rents, [1_000, 2_000, 3_000]);
+        }
+
+        let calls = methods.lock().unwrap();
+        assert_eq!(calls.len(), 3);
+        assert!(
+            calls
+                .iter()
+                .all(|method| method == "getMinimumBalanceForRentExemption")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rent_exemption_refetched_after_ttl() {
+        let (client, methods) = rent_client(
+            vec![
+                Ok(serde_json::json!(1_000u64)),
+                Ok(serde_json::json!(1_500u64)),
+            ],
+            DEFAULT_RENT_CACHE_TTL,
+        );
+
+        assert_eq!(
+            client
+                .minimum_balance_for_rent_exemption(165)
+                .await
+                .unwrap(),
+            1_000
+        );
+        // Age the entry past the TTL
+        client
+            .rent_cache
+            .lock()
+            .unwrap()
+            .insert(165, (1_000, Instant::now() - DEFAULT_RENT_CACHE_TTL));
+
+        assert_eq!(
+            client
+                .minimum_balance_for_rent_exemption(165)
+                .await
+                .unwrap(),
+            1_500
+        );
+        assert_eq!(methods.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_rent_exemption_cache_disabled_with_zero_ttl() {
+        let (client, methods) = rent_client(
+            vec![
+                Ok(serde_json::json!(1_000u64)),
+                Ok(serde_json::json!(1_000u64)),
+            ],
+            Duration::ZERO,
+        );
+
+        for _ in 0..2 {
+            client
+                .minimum_balance_for_rent_exemption(165)
+                .await
+                .unwrap();
+        }
+        assert_eq!(methods.lock().unwrap().len(), 2);
+        assert!(client.rent_cache.lock().unwrap().is_empty());
+    }
+
+    // --- ENHANCED MOCK FOR ERROR SCENARIOS ---
+
+    #[derive(Clone)]
+    #[allow(dead_code)]
+    enum MockErrorKind {
+        Timeout(String),
+        RpcError(String),
+        InsufficientFunds,
+        TransactionFailed(String),
+        EmptyResponse,
+    }
+
+    struct ConfigurableMockProvider {
+        signing_key: SigningKey,
+        responses: Mutex<Vec<Result<serde_json::Value, MockErrorKind>>>,
+        call_count: Mutex<usize>,
+        methods: std::sync::Arc<Mutex<Vec<String>>>,
+    }
+
+    impl ConfigurableMockProvider {
+        fn new() -> Self {
+            Self {
+                signing_key: test_signing_key(),
+                responses: Mutex::new(Vec::new()),
+                call_count: Mutex::new(0),
+                methods: std::sync::Arc::default(),
+            }
+        }
+
+        /// Shared log of the RPC methods called, readable after boxing the provider
+        fn method_log(&self) -> std::sync::Arc<Mutex<Vec<String>>> {
+            std::sync::Arc::clone(&self.methods)
+        }
+
+        fn with_responses(responses: Vec<Result<serde_json::Value, MockErrorKind>>) -> Self {
+            let provider = Self::new();
+            *provider.responses.lock().unwrap() = responses;
+            provider
+        }
+
+        #[allow(dead_code)]
+        fn get_call_count(&self) -> usize {
+            *self.call_count.lock().unwrap()
+        }
+    }
+
+    #[async_trait]
+    impl SolanaRpcProvider for ConfigurableMockProvider {
+        async fn send_request(
+            &self,
+            method: &str,
+            _params: serde_json::Value,
+        ) -> Result<serde_json::Value, AppError> {
+            self.methods.lock().unwrap().push(method.to_string());
+            let mut count = self.call_count.lock().unwrap();
+            let idx = *count;
+            *count += 1;
+            drop(count);
+
+            let responses = self.responses.lock().unwrap();
+            if idx < responses.len() {
+                match &responses[idx] {
+                    Ok(v) => Ok(v.clone()),
+                    Err(MockErrorKind::Timeout(msg)) => {
+                        Err(AppError::Blockchain(BlockchainError::Timeout(msg.clone())))
+                    }
+                    Err(MockErrorKind::RpcError(msg)) => {
+                        Err(AppError::Blockchain(BlockchainError::RpcError(msg.clone())))
+                    }
+                    Err(MockErrorKind::InsufficientFunds) => {
+                        Err(AppError::Blockchain(BlockchainError::InsufficientFunds))
+                    }
+                    Err(MockErrorKind::TransactionFailed(msg)) => Err(AppError::Blockchain(
+                        BlockchainError::TransactionFailed(msg.clone()),
+                    )),
+                    Err(MockErrorKind::EmptyResponse) => Err(AppError::Blockchain(
+                        BlockchainError::RpcError("Empty response".to_string()),
+                    )),
+                }
+            } else {
+                Ok(serde_json::Value::Null)
+            }
+        }
+
+        fn public_key(&self) -> String {
+            bs58::encode(self.signing_key.verifying_key().as_bytes()).into_string()
+        }
+
+        fn sign(&self, message: &[u8]) -> String {
+            let signature = self.signing_key.sign(message);
+            bs58::encode(signature.to_bytes()).into_string()
+        }
+    }
+
+    // --- ERROR HANDLING TESTS ---
+
+    #[tokio::test]
+    async fn test_rpc_error_insufficient_funds() {
+        let provider =
+            ConfigurableMockProvider::with_responses(vec![Err(MockErrorKind::InsufficientFunds)]);
+        let config = RpcClientConfig {
+            max_retries: 0, // No retries for this test
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.health_check().await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::InsufficientFunds))
+        ));
+    }
+
+    #[test]
+    fn test_estimated_fee_lamports() {
+        assert_eq!(estimated_fee_lamports(0, 200_000), 5_000);
+        // 1,000 micro-lamports x 200,000 CU = 200 lamports
+        assert_eq!(estimated_fee_lamports(1_000, 200_000), 5_200);
+        // Fractional lamports round up
+        assert_eq!(estimated_fee_lamports(1, 1), 5_001);
+        assert_eq!(estimated_fee_lamports(u64::MAX, u32::MAX), u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_fee_sums_components() {
+        let provider = ConfigurableMockProvider::with_responses(vec![
+            Ok(serde_json::json!({"value": {"blockhash": Hash::default().to_string()}})),
+            Ok(serde_json::json!({"context": {"slot": 1}, "value": 10_000})),
+        ]);
+        let methods = provider.method_log();
+        let config = RpcClientConfig {
+            max_retries: 0,
+            compute_unit_limit: 300_000,
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let estimate = client
+            .estimate_fee(
+                &Pubkey::new_unique().to_string(),
+                1_000_000,
+                None,
+                Some("invoice-7"),
+            )
+            .await
+            .unwrap();
+
+        // Fallback strategy: 100 micro-lamports x 300,000 CU = 30 lamports
+        assert_eq!(estimate.base_fee_lamports, 10_000);
+        assert_eq!(estimate.compute_unit_price_micro_lamports, 100);
+        assert_eq!(estimate.compute_unit_limit, 300_000);
+        assert_eq!(estimate.priority_fee_lamports, 30);
+        assert_eq!(estimate.jito_tip_lamports, 0);
+        assert_eq!(estimate.total_lamports, 10_030);
+        assert_eq!(
+            *methods.lock().unwrap(),
+            ["getLatestBlockhash", "getFeeForMessage"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_estimate_fee_expired_blockhash() {
+        let provider = ConfigurableMockProvider::with_responses(vec![
+            Ok(serde_json::json!({"value": {"blockhash": Hash::default().to_string()}})),
+            Ok(serde_json::json!({"context": {"slot": 1}, "value": null})),
+        ]);
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(provider), preflight_config(false));
+
+        let result = client
+            .estimate_fee(&Pubkey::new_unique().to_string(), 1_000_000, None, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::RpcError(_)))
+        ));
+    }
+
+    /// `getTokenAccountsByOwner` response holding `amount` of each mint
+    fn token_accounts_response(holdings: &[(&Pubkey, &str)]) -> serde_json::Value {
+        let value: Vec<serde_json::Value> = holdings
+            .iter()
+            .map(|(mint, amount)| {
+                serde_json::json!({
+                    "pubkey": Pubkey::new_unique().to_string(),
+                    "account": {
+                        "data": {
+                            "program": "spl-token",
+                            "parsed": {
+                                "type": "account",
+                                "info": {
+                                    "mint": mint.to_string(),
+                                    "owner": Pubkey::new_unique().to_string(),
+                                    "tokenAmount": {"amount": amount, "decimals": 0, "uiAmount": 1.0}
+                                }
+                            }
+                        },
+                        "lamports": 2_039_280,
+                        "owner": spl_token_interface::id().to_string()
+                    }
+                })
+            })
+            .collect();
+        serde_json::json!({"context": {"slot": 1}, "value": value})
+    }
+
+    fn sanctioned_config(sanctioned: Pubkey) -> RpcClientConfig {
+        RpcClientConfig {
+            max_retries: 0,
+            sanctioned_mints: vec![sanctioned],
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_token_holdings_fallback_flags_sanctioned_mint() {
+        let sanctioned = Pubkey::new_unique();
+        let clean = Pubkey::new_unique();
+        let provider =
+            ConfigurableMockProvider::with_responses(vec![Ok(token_accounts_response(&[
+                (&clean, "5"),
+                (&sanctioned, "1"),
+            ]))]);
+        let methods = provider.method_log();
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(provider), sanctioned_config(sanctioned));
+
+        let wallet = Pubkey::new_unique().to_string();
+        assert!(!client.check_wallet_assets(&wallet).await.unwrap());
+        // Flagged from the SPL Token accounts, Token-2022 not needed
+        assert_eq!(*methods.lock().unwrap(), ["getTokenAccountsByOwner"]);
+    }
+
+    #[tokio::test]
+    async fn test_token_holdings_fallback_passes_clean_wallet() {
+        let sanctioned = Pubkey::new_unique();
+        let clean = Pubkey::new_unique();
+        let provider = ConfigurableMockProvider::with_responses(vec![
+            // An emptied account of the sanctioned mint is not a holding
+            Ok(token_accounts_response(&[
+                (&clean, "5"),
+                (&sanctioned, "0"),
+            ])),
+            Ok(token_accounts_response(&[])),
+        ]);
+        let methods = provider.method_log();
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(provider), sanctioned_config(sanctioned));
+
+        let wallet = Pubkey::new_unique().to_string();
+        assert!(client.check_wallet_assets(&wallet).await.unwrap());
+        assert_eq!(
+            *methods.lock().unwrap(),
+            ["getTokenAccountsByOwner", "getTokenAccountsByOwner"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_asset_check_skipped_without_sanctioned_mints() {
+        let provider = ConfigurableMockProvider::with_responses(vec![]);
+        let methods = provider.method_log();
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(provider), preflight_config(false));
+
+        let wallet = Pubkey::new_unique().to_string();
+        assert!(client.check_wallet_assets(&wallet).await.unwrap());
+        assert!(methods.lock().unwrap().is_empty());
+    }
+
+    fn preflight_config(enabled: bool) -> RpcClientConfig {
+        RpcClientConfig {
+            max_retries: 0,
+            preflight_balance_check: enabled,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_preflight_rejects_low_balance() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(
+            serde_json::json!({"context": {"slot": 1}, "value": 1_000_000}),
+        )]);
+        let methods = provider.method_log();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), preflight_config(true));
+
+        // Amount alone is covered, amount + fee is not
+        let result = client
+            .transfer_sol(
+                &Pubkey::new_unique().to_string(),
+                1_000_000,
+                None,
+                SubmitOptions::default(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::InsufficientFunds))
+        ));
+        // Only the balance was queried; nothing was built or sent
+        assert_eq!(*methods.lock().unwrap(), ["getBalance"]);
+    }
+
+    #[tokio::test]
+    async fn test_preflight_passes_sufficient_balance() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(
+            serde_json::json!({"context": {"slot": 1}, "value": 10_000_000}),
+        )]);
+        let methods = provider.method_log();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), preflight_config(true));
+
+        // Past the preflight, the mock client has no SDK client to build with
+        let result = client
+            .transfer_sol(
+                &Pubkey::new_unique().to_string(),
+                1_000_000,
+                None,
+                SubmitOptions::default(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
+        ));
+        assert_eq!(*methods.lock().unwrap(), ["getBalance"]);
+    }
+
+    #[tokio::test]
+    async fn test_preflight_disabled_by_default() {
+        let provider = ConfigurableMockProvider::new();
+        let methods = provider.method_log();
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(provider), preflight_config(false));
+
+        let result = client
+            .transfer_sol(
+                &Pubkey::new_unique().to_string(),
+                1_000_000,
+                None,
+                SubmitOptions::default(),
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
+        ));
+        assert!(methods.lock().unwrap().is_empty());
+        assert!(!RpcClientConfig::default().preflight_balance_check);
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error_timeout_mapping() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Err(MockErrorKind::Timeout(
+            "Connection timed out".to_string(),
+        ))]);
+        let config = RpcClientConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.health_check().await;
+        match result {
+            Err(AppError::Blockchain(BlockchainError::Timeout(msg))) => {
+                assert!(msg.contains("timed out"));
+            }
+            _ => panic!("Expected timeout error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rpc_error_generic_rpc_error() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Err(
+            MockErrorKind::RpcError("-32000: Server is busy".to_string()),
+        )]);
+        let config = RpcClientConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.health_check().await;
+        match result {
+            Err(AppError::Blockchain(BlockchainError::RpcError(msg))) => {
+                assert!(msg.contains("Server is busy"));
+            }
+            _ => panic!("Expected RPC error"),
+        }
+    }
+
+    // --- DESERIALIZATION TESTS ---
+
+    #[test]
+    fn test_deserialize_signature_status_confirmed() {
+        let json = serde_json::json!({
+            "err": null,
+            "confirmationStatus": "confirmed"
+        });
+        let status: SignatureStatus = serde_json::from_value(json).unwrap();
+        assert!(status.err.is_none());
+        assert_eq!(status.confirmation_status.as_deref(), Some("confirmed"));
+    }
+
+    #[test]
+    fn test_deserialize_signature_status_finalized() {
+        let json = serde_json::json!({
+            "err": null,
+            "confirmationStatus": "finalized"
+        });
+        let status: SignatureStatus = serde_json::from_value(json).unwrap();
+        assert!(status.err.is_none());
+        assert_eq!(status.confirmation_status.as_deref(), Some("finalized"));
+    }
+
+    #[test]
+    fn test_deserialize_signature_status_with_error() {
+        let json = serde_json::json!({
+            "err": {"InstructionError": [0, "Custom"]},
+            "confirmationStatus": "confirmed"
+        });
+        let status: SignatureStatus = serde_json::from_value(json).unwrap();
+        assert!(status.err.is_some());
+    }
+
+    #[test]
+    fn test_deserialize_signature_status_null_confirmation() {
+        let json = serde_json::json!({
+            "err": null,
+            "confirmationStatus": null
+        });
+        let status: SignatureStatus = serde_json::from_value(json).unwrap();
+        assert!(status.confirmation_status.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_blockhash_result() {
+        let json = serde_json::json!({
+            "value": {
+                "blockhash": "GHtXQBsoZHVnNFa9YevAzFr17DJjgHXk3ycTy5nRhVT3"
+            }
+        });
+        let result: BlockhashResult = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            result.value.blockhash,
+            "GHtXQBsoZHVnNFa9YevAzFr17DJjgHXk3ycTy5nRhVT3"
+        );
+    }
+
+    #[test]
+    fn test_deserialize_signature_status_result() {
+        let json = serde_json::json!({
+            "value": [
+                {
+                    "err": null,
+                    "confirmationStatus": "finalized"
+                }
+            ]
+        });
+        let result: SignatureStatusResult = serde_json::from_value(json).unwrap();
+        assert_eq!(result.value.len(), 1);
+        assert!(result.value[0].is_some());
+    }
+
+    #[test]
+    fn test_deserialize_signature_status_result_null_entry() {
+        let json = serde_json::json!({
+            "value": [null]
+        });
+        let result: SignatureStatusResult = serde_json::from_value(json).unwrap();
+        assert_eq!(result.value.len(), 1);
+        assert!(result.value[0].is_none());
+    }
+
+    // --- TRANSACTION STATUS TESTS ---
+
+    #[tokio::test]
+    async fn test_get_transaction_status_confirmed() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
+            "value": [{
+                "err": null,
+                "confirmationStatus": "confirmed"
+            }]
+        }))]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.get_transaction_status("test_sig").await;
+        assert!(result.is_ok());
+        assert!(result.unwrap()); // Should be confirmed
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_status_finalized() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
+            "value": [{
+                "err": null,
+                "confirmationStatus": "finalized"
+            }]
+        }))]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.get_transaction_status("test_sig").await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_status_not_found() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
+            "value": [null]
+        }))]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.get_transaction_status("unknown_sig").await;
+        assert!(result.is_ok());
+        assert!(!result.unwrap()); // Not found = not confirmed
+    }
+
+    #[tokio::test]
+    async fn test_get_transaction_status_with_error() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
+            "value": [{
+                "err": {"InstructionError": [0, "Custom"]},
+                "confirmationStatus": "confirmed"
+            }]
+        }))]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.get_transaction_status("failed_sig").await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
+        ));
+    }
+
+    // --- BLOCKHASH AND BLOCK HEIGHT TESTS ---
+
+    #[tokio::test]
+    async fn test_get_latest_blockhash() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
+            "value": {
+                "blockhash": "TestBlockhash123"
+            }
+        }))]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.get_latest_blockhash().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), "TestBlockhash123");
+    }
+
+    #[tokio::test]
+    async fn test_get_block_height() {
+        let provider =
+            ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!(123456789u64))]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.get_block_height().await;
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), 123456789);
+    }
+
+    // --- WAIT FOR CONFIRMATION TESTS ---
+
+    #[tokio::test]
+    async fn test_wait_for_confirmation_immediate_success() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
+            "value": [{
+                "err": null,
+                "confirmationStatus": "finalized"
+            }]
+        }))]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.wait_for_confirmation("test_sig", 5).await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_confirmation_eventual_success() {
+        // First call: not confirmed, second call: confirmed
+        let provider = ConfigurableMockProvider::with_responses(vec![
+            Ok(serde_json::json!({"value": [null]})),
+            Ok(serde_json::json!({
+                "value": [{
+                    "err": null,
+                    "confirmationStatus": "confirmed"
+                }]
+            })),
+        ]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        tokio::time::pause();
+        let result = client.wait_for_confirmation("test_sig", 10).await;
+        assert!(result.is_ok());
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_confirmation_timeout() {
+        // Always return not confirmed
+        let provider = ConfigurableMockProvider::with_responses(vec![
+            Ok(serde_json::json!({"value": [null]})),
+            Ok(serde_json::json!({"value": [null]})),
+            Ok(serde_json::json!({"value": [null]})),
+            Ok(serde_json::json!({"value": [null]})),
+            Ok(serde_json::json!({"value": [null]})),
+        ]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        tokio::time::pause();
+        let result = client.wait_for_confirmation("never_confirmed", 1).await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::Timeout(_)))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_confirmation_transaction_failed() {
+        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
+            "value": [{
+                "err": {"InstructionError": [0, "ProgramFailed"]},
+                "confirmationStatus": "confirmed"
+            }]
+        }))]);
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.wait_for_confirmation("failed_tx", 5).await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
+        ));
+    }
+
+    /// Reports every signature as unconfirmed (until `confirmed_at`, if set)
+    /// and records when it was polled
+    struct PollClockProvider {
+        signing_key: SigningKey,
+        polls: std::sync::Arc<Mutex<Vec<tokio::time::Instant>>>,
+        confirmed_at: Option<tokio::time::Instant>,
+    }
+
+    #[async_trait]
+    impl SolanaRpcProvider for PollClockProvider {
+        async fn send_request(
+            &self,
+            _method: &str,
+            _params: serde_json::Value,
+        ) -> Result<serde_json::Value, AppError> {
+            let now = tokio::time::Instant::now();
+            self.polls.lock().unwrap().push(now);
+            if self.confirmed_at.is_some_and(|at| now >= at) {
+                return Ok(serde_json::json!({
+                    "value": [{"slot": 1, "err": null, "confirmationStatus": "confirmed"}]
+                }));
+            }
+            Ok(serde_json::json!({"value": [null]}))
+        }
+
+        fn public_key(&self) -> String {
+            bs58::encode(self.signing_key.verifying_key().as_bytes()).into_string()
+        }
+
+        fn sign(&self, message: &[u8]) -> String {
+            bs58::encode(self.signing_key.sign(message).to_bytes()).into_string()
+        }
+    }
+
+    /// Poll delays observed while waiting `timeout_secs` for a signature that never confirms
+    async fn unconfirmed_poll_delays(timeout_secs: u64) -> (Vec<Duration>, Duration) {
+        let polls = std::sync::Arc::default();
+        let provider = PollClockProvider {
+            signing_key: test_signing_key(),
+            polls: std::sync::Arc::clone(&polls),
+            confirmed_at: None,
+        };
+        let config = RpcClientConfig {
+            confirmation_poll_interval: Duration::from_millis(100),
+            confirmation_poll_max_interval: Duration::from_millis(1_000),
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let start = tokio::time::Instant::now();
+        let result = client.wait_for_confirmation("slow_sig", timeout_secs).await;
+        let elapsed = start.elapsed();
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::Timeout(_)))
+        ));
+
+        let polls = polls.lock().unwrap();
+        let delays = polls.windows(2).map(|w| w[1] - w[0]).collect();
+        (delays, elapsed)
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_confirmation_backs_off_to_cap() {
+        let (delays, _) = unconfirmed_poll_delays(5).await;
+
+        let ms = |ms| Duration::from_millis(ms);
+        assert_eq!(
+            delays[..6],
+            [ms(100), ms(200), ms(400), ms(800), ms(1_000), ms(1_000)]
+        );
+        assert!(delays.iter().all(|d| *d <= ms(1_000)));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_confirmation_backoff_respects_timeout() {
+        let (delays, elapsed) = unconfirmed_poll_delays(2).await;
+
+        // Polls at 0, 0.1, 0.3, 0.7 and 1.5s; the last sleep is cut to the
+        // deadline, where it polls one final time
+        assert_eq!(delays.len(), 5);
+        assert_eq!(delays[4], Duration::from_millis(500));
+        assert_eq!(elapsed, Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_wait_for_confirmation_polls_at_deadline() {
+        let polls = std::sync::Arc::default();
+        // Lands during the last sleep, after the 1.5s poll
+        let provider = PollClockProvider {
+            signing_key: test_signing_key(),
+            polls: std::sync::Arc::clone(&polls),
+            confirmed_at: Some(tokio::time::Instant::now() + Duration::from_millis(1_800)),
+        };
+        let config = RpcClientConfig {
+            confirmation_poll_interval: Duration::from_millis(100),
+            confirmation_poll_max_interval: Duration::from_millis(1_000),
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let confirmed = client.wait_for_confirmation("late_sig", 2).await.unwrap();
+        assert!(confirmed);
+        assert_eq!(polls.lock().unwrap().len(), 6);
+    }
+
+    // --- SUBMIT TRANSACTION TESTS (MOCK MODE) ---
+
+    #[tokio::test]
+    #[cfg(not(feature = "real-blockchain"))]
+    async fn test_submit_transaction_mock_mode() {
+        let provider = ConfigurableMockProvider::new();
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        // In mock mode (no real-blockchain feature), submit_transaction just signs
+        let request = TransferRequest {
+            id: "test_hash_123".to_string(),
+            ..Default::default()
+        };
+        let result = client.submit_transaction(&request).await;
+        assert!(result.is_ok());
+        let (outcome, blockhash) = result.unwrap();
+        assert!(outcome.signature.starts_with("tx_")); // Mock format
+        assert!(outcome.bundle_id.is_none());
+        assert!(!blockhash.is_empty());
+    }
+
+    // --- RETRY LOGIC WITH CALL TRACKING ---
+
+    #[tokio::test]
+    async fn test_retry_counts_attempts_correctly() {
+        let provider = ConfigurableMockProvider::with_responses(vec![
+            Err(MockErrorKind::Timeout("fail 1".to_string())),
+            Err(MockErrorKind::Timeout("fail 2".to_string())),
+            Err(MockErrorKind::Timeout("fail 3".to_string())),
+            Ok(serde_json::json!(999u64)), // Success on 4th attempt
+        ]);
+        let config = RpcClientConfig {
+            max_retries: 3, // Initial + 3 retries = 4 attempts
+            retry_delay: Duration::from_millis(1),
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.health_check().await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_insufficient_funds() {
+        // InsufficientFunds should still trigger retries as per current implementation
+        let provider = ConfigurableMockProvider::with_responses(vec![
+            Err(MockErrorKind::InsufficientFunds),
+            Err(MockErrorKind::InsufficientFunds),
+        ]);
+        let config = RpcClientConfig {
+            max_retries: 1,
+            retry_delay: Duration::from_millis(1),
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        let result = client.health_check().await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::InsufficientFunds))
+        ));
+        // Note: We can't check the provider's state after moving it into Box
+        // The test validates that InsufficientFunds is eventually returned after retries
+    }
+
+    // --- WITH_PROVIDER CONSTRUCTOR TEST ---
+
+    #[test]
+    fn test_with_provider_constructor() {
+        let provider = ConfigurableMockProvider::new();
+        let config = RpcClientConfig {
+            max_retries: 5,
+            timeout: Duration::from_secs(45),
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        // Verify public key is accessible
+        let pubkey = client.public_key();
+        assert!(!pubkey.is_empty());
+
+        // Verify signing works
+        let sig = client.sign(b"test");
+        assert!(!sig.is_empty());
+    }
+
+    // --- HTTP PROVIDER TESTS ---
+
+    #[test]
+    fn test_http_solana_rpc_provider_creation() {
+        let signing_key = test_signing_key();
+        let result = HttpSolanaRpcProvider::new(
+            "https://api.devnet.solana.com",
+            signing_key,
+            Duration::from_secs(30),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_http_solana_rpc_provider_public_key() {
+        let signing_key = test_signing_key();
+        let provider = HttpSolanaRpcProvider::new(
+            "https://api.devnet.solana.com",
+            signing_key.clone(),
+            Duration::from_secs(30),
+        )
+        .unwrap();
+
+        let pubkey = provider.public_key();
+        assert!(!pubkey.is_empty());
+        // Verify it matches the expected public key
+        let expected = bs58::encode(signing_key.verifying_key().as_bytes()).into_string();
+        assert_eq!(pubkey, expected);
+    }
+
+    #[test]
+    fn test_http_solana_rpc_provider_sign() {
+        let signing_key = test_signing_key();
+        let provider = HttpSolanaRpcProvider::new(
+            "https://api.devnet.solana.com",
+            signing_key,
+            Duration::from_secs(30),
+        )
+        .unwrap();
+
+        let signature = provider.sign(b"test message");
+        assert!(!signature.is_empty());
+        // Signature should be base58 encoded
+        let decoded = bs58::decode(&signature).into_vec();
+        assert!(decoded.is_ok());
+        assert_eq!(decoded.unwrap().len(), 64); // Ed25519 signature is 64 bytes
+    }
+
+    // --- JSON-RPC STRUCTURE TESTS ---
+
+    #[test]
+    fn test_json_rpc_response_with_result() {
+        let json = serde_json::json!({
+            "result": 12345,
+            "error": null
+        });
+        let response: JsonRpcResponse<u64> = serde_json::from_value(json).unwrap();
+        assert_eq!(response.result, Some(12345));
+        assert!(response.error.is_none());
+    }
+
+    #[test]
+    fn test_json_rpc_response_with_error() {
+        let json = serde_json::json!({
+            "result": null,
+            "error": {
+                "code": -32600,
+                "message": "Invalid Request"
+            }
+        });
+        let response: JsonRpcResponse<u64> = serde_json::from_value(json).unwrap();
+        assert!(response.result.is_none());
+        assert!(response.error.is_some());
+        let error = response.error.unwrap();
+        assert_eq!(error.code, -32600);
+        assert_eq!(error.message, "Invalid Request");
+    }
+
+    #[test]
+    fn test_json_rpc_error_insufficient_funds_by_message() {
+        let json = serde_json::json!({
+            "result": null,
+            "error": {
+                "code": -32000,
+                "message": "Transaction simulation failed: insufficient lamports"
+            }
+        });
+        let response: JsonRpcResponse<String> = serde_json::from_value(json).unwrap();
+        let error = response.error.unwrap();
+        // The message contains "insufficient" which triggers InsufficientFunds error
+        assert!(error.message.contains("insufficient"));
+    }
+
+    #[test]
+    fn test_json_rpc_error_insufficient_funds_by_code() {
+        let json = serde_json::json!({
+            "result": null,
+            "error": {
+                "code": -32002,
+                "message": "Some other error"
+            }
+        });
+        let response: JsonRpcResponse<String> = serde_json::from_value(json).unwrap();
+        let error = response.error.unwrap();
+        // Error code -32002 triggers InsufficientFunds
+        assert_eq!(error.code, -32002);
+    }
+
+    // --- DESERIALIZATION ERROR TESTS ---
+
+    #[tokio::test]
+    async fn test_rpc_call_deserialization_error() {
+        // Return a value that can't be deserialized to expected type
+        let provider = ConfigurableMockProvider::with_responses(vec![
+            Ok(serde_json::json!("not_a_number")), // String instead of u64
+        ]);
+        let config = RpcClientConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        // get_block_height expects u64, but we return a string
+        let result = client.get_block_height().await;
+        match result {
+            Err(AppError::Blockchain(BlockchainError::RpcError(msg))) => {
+                assert!(msg.contains("Deserialization error"));
+            }
+            _ => panic!("Expected deserialization error, got {:?}", result),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rpc_call_empty_response_after_retries() {
+        // No responses configured - should use fallback null
+        let provider = ConfigurableMockProvider::new();
+        let config = RpcClientConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        // Try to get block height - provider returns null which can't deserialize to u64
+        let result = client.get_block_height().await;
+        assert!(result.is_err());
+    }
+
+    // --- SIGNING KEY ADDITIONAL TESTS ---
+
+    #[test]
+    fn test_signing_key_from_base58_64_bytes_invalid_keypair() {
+        // Create 64 random bytes (not a valid keypair where bytes 32-64 are the public key)
+        let invalid_keypair = vec![42u8; 64];
+        let encoded = bs58::encode(&invalid_keypair).into_string();
+        let secret = SecretString::from(encoded);
+
+        // This should still work since we only use the first 32 bytes
+        let result = signing_key_from_base58(&secret);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_signing_key_from_base58_empty_string() {
+        let secret = SecretString::from("");
+        let result = signing_key_from_base58(&secret);
+        assert!(result.is_err());
+    }
+
+    // --- SDK-BASED TRANSFER TESTS (only with real-blockchain feature) ---
+
+    #[cfg(feature = "real-blockchain")]
+    mod real_blockchain_tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_submit_transaction_real_blockchain_path() {
+            // This test verifies the SDK client is properly initialized
+            // Actual transfer tests require network/mocking
+            let signing_key = test_signing_key();
+            let client =
+                RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
+                    .unwrap();
+
+            // Verify SDK components are initialized
+            assert!(client.sdk_client.is_some());
+            assert!(client.keypair(None).is_some());
+            let _ = client.public_key();
+        }
+    }
+
+    // --- RPC CLIENT NEW CONSTRUCTOR TEST ---
+
+    #[test]
+    fn test_rpc_blockchain_client_new() {
+        let signing_key = test_signing_key();
+        let config = RpcClientConfig {
+            timeout: Duration::from_secs(15),
+            max_retries: 2,
+            retry_delay: Duration::from_millis(250),
+            confirmation_timeout: Duration::from_secs(30),
+            ..Default::default()
+        };
+        let result = RpcBlockchainClient::new("https://api.devnet.solana.com", signing_key, config);
+        assert!(result.is_ok());
+    }
+
+    // --- PROVIDER TRAIT OBJECT TESTS ---
+
+    #[test]
+    fn test_provider_as_trait_object() {
+        let provider: Box<dyn SolanaRpcProvider> = Box::new(ConfigurableMockProvider::new());
+
+        // Test public_key through trait object
+        let pubkey = provider.public_key();
+        assert!(!pubkey.is_empty());
+
+        // Test sign through trait object
+        let sig = provider.sign(b"message");
+        assert!(!sig.is_empty());
+    }
+
+    // --- BLOCKHASH RESPONSE DESERIALIZATION ---
+
+    #[test]
+    fn test_blockhash_response_deserialization() {
+        let json = serde_json::json!({
+            "blockhash": "4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZAMdL4VZHirAn"
+        });
+        let response: BlockhashResponse = serde_json::from_value(json).unwrap();
+        assert_eq!(
+            response.blockhash,
+            "4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZAMdL4VZHirAn"
+        );
+    }
+
+    // --- ADDITIONAL RPC CLIENT CONFIG TESTS ---
+
+    #[test]
+    fn test_rpc_client_config_very_short_timeout() {
+        let config = RpcClientConfig {
+            timeout: Duration::from_millis(1),
+            max_retries: 0,
+            retry_delay: Duration::from_millis(1),
+            confirmation_timeout: Duration::from_millis(1),
+            ..Default::default()
+        };
+        assert_eq!(config.timeout, Duration::from_millis(1));
+    }
+
+    #[test]
+    fn test_rpc_client_config_zero_retries() {
+        let config = RpcClientConfig {
+            max_retries: 0,
+            ..Default::default()
+        };
+        assert_eq!(config.max_retries, 0);
+    }
+
+    // ====================================================================
+    // SUBMISSION STRATEGY INTEGRATION TESTS
+    // ====================================================================
+
+    #[test]
+    fn test_client_without_submission_strategy() {
+        let signing_key = test_signing_key();
+        let client =
+            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
+                .unwrap();
+
+        // Without explicit strategy, should not have one
+        assert!(!client.has_submission_strategy());
+        assert!(!client.supports_private_submission());
+    }
+
+    #[test]
+    fn test_client_with_submission_strategy_constructor() {
+        use super::super::quicknode::{
+            QuickNodePrivateSubmissionStrategy, QuickNodeSubmissionConfig,
+        };
+
+        let signing_key = test_signing_key();
+        let config = QuickNodeSubmissionConfig {
+            rpc_url: "https://test.quiknode.pro/xxx".to_string(),
+            enable_jito_bundles: true,
+            tip_lamports: 10_000,
+            max_bundle_retries: 2,
+            region: None,
+            block_engine_url: None,
+            track_bundle_status: false,
+        };
+        let strategy: Box<dyn super::super::strategies::SubmissionStrategy> =
+            Box::new(QuickNodePrivateSubmissionStrategy::new(config));
+
+        let client = RpcBlockchainClient::with_defaults_and_submission_strategy(
+            "https://test.quiknode.pro/xxx",
+            signing_key,
+            Some(strategy),
+            Some(10_000), // Jito tip
+        )
+        .unwrap();
+
+        assert!(client.has_submission_strategy());
+        assert!(client.supports_private_submission());
     }
 
     #[test]
-    fn test_client_creation() {
+    fn test_client_with_jito_disabled_strategy() {
+        use super::super::quicknode::{
+            QuickNodePrivateSubmissionStrategy, QuickNodeSubmissionConfig,
+        };
+
         let signing_key = test_signing_key();
-        let client =
-            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key);
-        assert!(client.is_ok());
+        let config = QuickNodeSubmissionConfig {
+            rpc_url: "https://test.quiknode.pro/xxx".to_string(),
+            enable_jito_bundles: false, // Jito disabled
+            tip_lamports: 10_000,
+            max_bundle_retries: 2,
+            region: None,
+            block_engine_url: None,
+            track_bundle_status: false,
+        };
+        let strategy: Box<dyn super::super::strategies::SubmissionStrategy> =
+            Box::new(QuickNodePrivateSubmissionStrategy::new(config));
+
+        let client = RpcBlockchainClient::with_defaults_and_submission_strategy(
+            "https://test.quiknode.pro/xxx",
+            signing_key,
+            Some(strategy),
+            None, // No tip when Jito disabled
+        )
+        .unwrap();
+
+        // Has strategy, but private submission is not supported (Jito disabled)
+        assert!(client.has_submission_strategy());
+        assert!(!client.supports_private_submission());
     }
 
     #[test]
-    fn test_public_key_generation() {
-        let signing_key = test_signing_key();
-        let client =
-            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
-                .unwrap();
-        let pubkey = client.public_key();
-        assert!(!pubkey.is_empty());
-        // Verify it decodes to 32 bytes (length can be 43 or 44 chars)
-        let decoded = bs58::decode(&pubkey)
-            .into_vec()
-            .expect("Should be valid base58");
-        assert_eq!(decoded.len(), 32);
+    fn test_with_provider_has_no_strategy() {
+        let provider = ConfigurableMockProvider::new();
+        let config = RpcClientConfig::default();
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+
+        // with_provider doesn't accept a strategy, so it should not have one
+        assert!(!client.has_submission_strategy());
+        assert!(!client.supports_private_submission());
     }
 
     #[test]
-    fn test_signing() {
+    fn test_serialize_transaction_base58() {
+        use solana_sdk::{hash::Hash, transaction::Transaction};
+
         let signing_key = test_signing_key();
         let client =
             RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
                 .unwrap();
-        let signature = client.sign(b"test message");
-        assert!(!signature.is_empty());
-    }
 
-    #[test]
-    fn test_signing_key_from_base58_valid_32_bytes() {
-        let original_key = test_signing_key();
-        let encoded = bs58::encode(original_key.to_bytes()).into_string();
-        let secret = SecretString::from(encoded);
-        let result = signing_key_from_base58(&secret);
-        assert!(result.is_ok());
-    }
+        // Create a minimal unsigned transaction
+        let recent_blockhash = Hash::new_unique();
+        let keypair = client.keypair(None).unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[],
+            Some(&keypair.pubkey()),
+            &[keypair],
+            recent_blockhash,
+        );
 
-    #[test]
-    fn test_signing_key_from_base58_valid_64_bytes() {
-        let original_key = test_signing_key();
-        let mut keypair = original_key.to_bytes().to_vec();
-        keypair.extend_from_slice(original_key.verifying_key().as_bytes());
-        let encoded = bs58::encode(&keypair).into_string();
-        let secret = SecretString::from(encoded);
-        let result = signing_key_from_base58(&secret);
+        // Serialize should work
+        let result = client.serialize_transaction_base58(&VersionedTransaction::from(tx.clone()));
         assert!(result.is_ok());
-    }
 
-    #[test]
-    fn test_signing_key_from_base58_invalid() {
-        let secret = SecretString::from("invalid-base58!!!");
-        let result = signing_key_from_base58(&secret);
-        assert!(result.is_err());
-    }
+        let serialized = result.unwrap();
+        assert!(!serialized.is_empty());
 
-    #[test]
-    fn test_rpc_client_config_default() {
-        let config = RpcClientConfig::default();
-        assert_eq!(config.max_retries, 3);
-        assert_eq!(config.timeout, Duration::from_secs(30));
-        assert_eq!(config.confirmation_timeout, Duration::from_secs(60));
+        // Should be valid Base58
+        let decoded = bs58::decode(&serialized).into_vec();
+        assert!(decoded.is_ok());
+
+        // Legacy transactions keep the legacy wire format
+        let legacy_bytes = bincode::serde::encode_to_vec(&tx, bincode::config::legacy()).unwrap();
+        assert_eq!(decoded.unwrap(), legacy_bytes);
     }
 
-    #[test]
-    fn test_signing_key_from_base58_wrong_length() {
-        // 16 bytes - too short
-        let short_key = bs58::encode(vec![0u8; 16]).into_string();
-        let secret = SecretString::from(short_key);
-        let result = signing_key_from_base58(&secret);
-        assert!(result.is_err());
+    // ====================================================================
+    // VERSIONED TRANSACTION TESTS
+    // ====================================================================
 
-        // 48 bytes - wrong size (not 32 or 64)
-        let wrong_key = bs58::encode(vec![0u8; 48]).into_string();
-        let secret = SecretString::from(wrong_key);
-        let result = signing_key_from_base58(&secret);
-        assert!(result.is_err());
+    fn lookup_table_fixture(addresses: &[Pubkey]) -> AddressLookupTableAccount {
+        AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: addresses.to_vec(),
+        }
     }
 
     #[test]
-    fn test_rpc_client_config_custom() {
-        let config = RpcClientConfig {
-            timeout: Duration::from_secs(60),
-            max_retries: 5,
-            retry_delay: Duration::from_millis(1000),
-            confirmation_timeout: Duration::from_secs(120),
+    fn test_v0_transaction_round_trip() {
+        use solana_sdk::message::MessageHeader;
+
+        let keypair = Keypair::new();
+        let recipients = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let table = lookup_table_fixture(&recipients);
+        let instructions: Vec<Instruction> = recipients
+            .iter()
+            .map(|to| system_instruction::transfer(&keypair.pubkey(), to, 1_000))
+            .collect();
+
+        let tx = compile_v0_transaction(
+            &keypair,
+            &instructions,
+            std::slice::from_ref(&table),
+            Hash::new_unique(),
+        )
+        .unwrap();
+
+        let VersionedMessage::V0(ref message) = tx.message else {
+            panic!("expected a v0 message");
         };
-        assert_eq!(config.timeout, Duration::from_secs(60));
-        assert_eq!(config.max_retries, 5);
-        assert_eq!(config.retry_delay, Duration::from_millis(1000));
-        assert_eq!(config.confirmation_timeout, Duration::from_secs(120));
+        // Recipients are writable non-signers, so they resolve through the table
+        assert_eq!(message.address_table_lookups.len(), 1);
+        assert_eq!(message.address_table_lookups[0].account_key, table.key);
+        assert_eq!(
+            message.address_table_lookups[0].writable_indexes,
+            vec![0, 1]
+        );
+        assert_eq!(
+            message.header,
+            MessageHeader {
+                num_required_signatures: 1,
+                num_readonly_signed_accounts: 0,
+                num_readonly_unsigned_accounts: 1,
+            }
+        );
+
+        let bytes = bincode::serde::encode_to_vec(&tx, bincode::config::legacy()).unwrap();
+        let (decoded, _): (VersionedTransaction, usize) =
+            bincode::serde::decode_from_slice(&bytes, bincode::config::legacy()).unwrap();
+
+        assert_eq!(decoded, tx);
+        assert!(decoded.verify_with_results().iter().all(|ok| *ok));
+        assert_eq!(
+            decoded.message.recent_blockhash(),
+            tx.message.recent_blockhash()
+        );
     }
 
     #[test]
-    fn test_signing_determinism() {
+    fn test_v0_transaction_without_lookup_tables() {
+        let keypair = Keypair::new();
+        let instruction =
+            system_instruction::transfer(&keypair.pubkey(), &Pubkey::new_unique(), 1_000);
+
+        let tx = compile_v0_transaction(&keypair, &[instruction], &[], Hash::new_unique()).unwrap();
+
+        let VersionedMessage::V0(ref message) = tx.message else {
+            panic!("expected a v0 message");
+        };
+        assert!(message.address_table_lookups.is_empty());
+        assert_eq!(message.account_keys.len(), 3);
+
         let signing_key = test_signing_key();
         let client =
             RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
                 .unwrap();
-
-        // Same message should produce same signature
-        let sig1 = client.sign(b"test message");
-        let sig2 = client.sign(b"test message");
-        assert_eq!(sig1, sig2);
-
-        // Different message should produce different signature
-        let sig3 = client.sign(b"different message");
-        assert_ne!(sig1, sig3);
+        let encoded = client.serialize_transaction_base58(&tx).unwrap();
+        let bytes = bs58::decode(&encoded).into_vec().unwrap();
+        let (decoded, _): (VersionedTransaction, usize) =
+            bincode::serde::decode_from_slice(&bytes, bincode::config::legacy()).unwrap();
+        assert_eq!(decoded, tx);
     }
 
-    // --- MOCK PROVIDER TESTS ---
-    use std::sync::Mutex;
+    #[test]
+    fn test_parse_lookup_table_addresses() {
+        let addresses = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let mut data = vec![0u8; LOOKUP_TABLE_META_SIZE];
+        for address in &addresses {
+            data.extend_from_slice(address.as_ref());
+        }
 
-    #[cfg(test)]
-    #[allow(dead_code)]
-    enum BlockchainErrorType {
-        Timeout,
-        Rpc,
+        assert_eq!(parse_lookup_table_addresses(&data).unwrap(), addresses);
+        assert!(
+            parse_lookup_table_addresses(&data[..LOOKUP_TABLE_META_SIZE])
+                .unwrap()
+                .is_empty()
+        );
+        // Truncated header or partial address
+        assert!(parse_lookup_table_addresses(&data[..10]).is_err());
+        assert!(parse_lookup_table_addresses(&data[..data.len() - 1]).is_err());
     }
 
-    struct MockState {
-        requests: Vec<String>,
-        should_fail_count: u32,
-        failure_error: Option<BlockchainErrorType>,
-        next_response: Option<serde_json::Value>,
-    }
+    // ====================================================================
+    // TOKEN-2022 TRANSFER FEE TESTS
+    // ====================================================================
 
-    struct MockSolanaRpcProvider {
-        state: Mutex<MockState>,
-        signing_key: SigningKey,
+    fn transfer_fee(basis_points: u16, maximum_fee: u64) -> TransferFee {
+        TransferFee {
+            epoch: 0.into(),
+            maximum_fee: maximum_fee.into(),
+            transfer_fee_basis_points: basis_points.into(),
+        }
     }
 
-    impl MockSolanaRpcProvider {
-        fn new() -> Self {
-            Self {
-                state: Mutex::new(MockState {
-                    requests: Vec::new(),
-                    should_fail_count: 0,
-                    failure_error: None,
-                    next_response: None,
-                }),
-                signing_key: test_signing_key(),
-            }
-        }
+    fn mint_data(fee: Option<TransferFee>) -> Vec<u8> {
+        use spl_token_2022_interface::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
 
-        fn with_failure(count: u32, error_type: BlockchainErrorType) -> Self {
-            let provider = Self::new(); // removed `mut` since we don’t mutate `provider` itself
-            {
-                let mut state = provider.state.lock().unwrap();
-                state.should_fail_count = count;
-                state.failure_error = Some(error_type);
-            }
-            provider
+        let extensions: &[ExtensionType] = if fee.is_some() {
+            &[ExtensionType::TransferFeeConfig]
+        } else {
+            &[]
+        };
+        let len = ExtensionType::try_calculate_account_len::<Token2022Mint>(extensions).unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<Token2022Mint>::unpack_uninitialized(&mut data).unwrap();
+        if let Some(fee) = fee {
+            let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+            config.older_transfer_fee = fee;
+            config.newer_transfer_fee = fee;
+        }
+        state.base = Token2022Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        if fee.is_some() {
+            state.init_account_type().unwrap();
         }
+        data
     }
 
-    #[async_trait]
-    impl SolanaRpcProvider for MockSolanaRpcProvider {
-        async fn send_request(
-            &self,
-            method: &str,
-            _params: serde_json::Value,
-        ) -> Result<serde_json::Value, AppError> {
-            let mut state = self.state.lock().unwrap();
-            state.requests.push(method.to_string());
-
-            if state.should_fail_count > 0 {
-                state.should_fail_count -= 1;
-                if let Some(ref err) = state.failure_error {
-                    return match err {
-                        BlockchainErrorType::Timeout => Err(AppError::Blockchain(
-                            BlockchainError::Timeout("Mock timeout".to_string()),
-                        )),
-                        BlockchainErrorType::Rpc => Err(AppError::Blockchain(
-                            BlockchainError::RpcError("Mock RPC error".to_string()),
-                        )),
-                    };
-                }
-            }
-
-            if let Some(resp) = &state.next_response {
-                return Ok(resp.clone());
-            }
-
-            Ok(serde_json::Value::Null)
-        }
+    #[test]
+    fn test_parse_transfer_fee_config() {
+        let data = mint_data(Some(transfer_fee(100, 5_000)));
+        let config = parse_transfer_fee_config(&data).unwrap().unwrap();
+        let fee = config.get_epoch_fee(42);
+        assert_eq!(u16::from(fee.transfer_fee_basis_points), 100);
+        assert_eq!(u64::from(fee.maximum_fee), 5_000);
 
-        fn public_key(&self) -> String {
-            bs58::encode(self.signing_key.verifying_key().as_bytes()).into_string()
-        }
+        let plain = mint_data(None);
+        assert!(parse_transfer_fee_config(&plain).unwrap().is_none());
 
-        fn sign(&self, message: &[u8]) -> String {
-            let signature = self.signing_key.sign(message);
-            bs58::encode(signature.to_bytes()).into_string()
-        }
+        assert!(parse_transfer_fee_config(&[0u8; 10]).is_err());
     }
 
-    #[tokio::test]
-    async fn test_rpc_client_retry_logic_success() {
-        // Setup provider that fails twice then succeeds
-        let provider = MockSolanaRpcProvider::with_failure(2, BlockchainErrorType::Timeout);
-        let config = RpcClientConfig {
-            max_retries: 3,
-            retry_delay: Duration::from_millis(1), // Fast retry
-            ..Default::default()
-        };
+    #[test]
+    fn test_plan_fee_transfer() {
+        // 1% fee capped at 5,000
+        let fee = transfer_fee(100, 5_000);
+
+        // Fee deducted from the requested amount
+        assert_eq!(
+            plan_fee_transfer(&fee, 100_000, false).unwrap(),
+            (100_000, 1_000)
+        );
 
-        // Set success response
-        {
-            let mut state = provider.state.lock().unwrap();
-            state.next_response = Some(serde_json::json!(12345u64)); // Slot response
-        }
+        // Grossed up so the recipient nets the requested amount
+        let (send_amount, withheld) = plan_fee_transfer(&fee, 99_000, true).unwrap();
+        assert_eq!((send_amount, withheld), (100_000, 1_000));
+        assert_eq!(send_amount - withheld, 99_000);
 
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        // Maximum fee caps the gross-up
+        let (send_amount, withheld) = plan_fee_transfer(&fee, 10_000_000, true).unwrap();
+        assert_eq!((send_amount, withheld), (10_005_000, 5_000));
 
-        // Call health_check (uses getSlot)
-        let result = client.health_check().await;
-        assert!(result.is_ok());
+        // Zero-fee mint leaves the amount unchanged
+        assert_eq!(
+            plan_fee_transfer(&transfer_fee(0, 0), 1_000, true).unwrap(),
+            (1_000, 0)
+        );
     }
 
-    #[tokio::test]
-    async fn test_rpc_client_retry_logic_failure() {
-        // Setup provider that fails 4 times (max retries is 3)
-        let provider = MockSolanaRpcProvider::with_failure(4, BlockchainErrorType::Timeout);
+    /// Client whose SDK talks to `server`, caching mints for `mint_cache_ttl`
+    fn mint_cache_client(
+        server: &wiremock::MockServer,
+        mint_cache_ttl: Duration,
+    ) -> RpcBlockchainClient {
         let config = RpcClientConfig {
-            max_retries: 3,
-            retry_delay: Duration::from_millis(1),
+            max_retries: 0,
+            mint_cache_ttl,
             ..Default::default()
         };
-
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
-
-        let result = client.health_check().await;
-        assert!(matches!(
-            result,
-            Err(AppError::Blockchain(BlockchainError::Timeout(_)))
-        ));
+        RpcBlockchainClient::new(&server.uri(), test_signing_key(), config).unwrap()
     }
 
-    // --- ENHANCED MOCK FOR ERROR SCENARIOS ---
-
-    #[derive(Clone)]
-    #[allow(dead_code)]
-    enum MockErrorKind {
-        Timeout(String),
-        RpcError(String),
-        InsufficientFunds,
-        TransactionFailed(String),
-        EmptyResponse,
+    /// Mount a `getAccountInfo` response holding a mint owned by `owner`
+    async fn mount_mint_account(
+        server: &wiremock::MockServer,
+        owner: &Pubkey,
+        data: &[u8],
+        expected_fetches: u64,
+    ) {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, ResponseTemplate};
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "getAccountInfo" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": { "slot": 1 },
+                    "value": {
+                        "lamports": 1_461_600u64,
+                        "data": [BASE64_STANDARD.encode(data), "base64"],
+                        "owner": owner.to_string(),
+                        "executable": false,
+                        "rentEpoch": 0,
+                        "space": data.len(),
+                    }
+                }
+            })))
+            .expect(expected_fetches)
+            .mount(server)
+            .await;
     }
 
-    struct ConfigurableMockProvider {
-        signing_key: SigningKey,
-        responses: Mutex<Vec<Result<serde_json::Value, MockErrorKind>>>,
-        call_count: Mutex<usize>,
+    #[tokio::test]
+    async fn test_mint_fetched_once_for_repeated_transfers() {
+        let server = wiremock::MockServer::start().await;
+        let token_program = spl_token_interface::id();
+        mount_mint_account(&server, &token_program, &mint_data(None), 1).await;
+
+        let client = mint_cache_client(&server, DEFAULT_MINT_CACHE_TTL);
+        let sdk_client = client.sdk_client.as_ref().unwrap();
+        let mint = Pubkey::new_unique();
+
+        // Two transfers of the same mint
+        for amount in [1_000, 2_000] {
+            let plan = client
+                .plan_token_transfer(sdk_client, &mint, amount)
+                .await
+                .unwrap();
+            assert_eq!(plan.token_program_id, token_program);
+            assert_eq!(plan.decimals, 6);
+            assert_eq!(plan.send_amount, amount);
+        }
+        assert_eq!(client.mint_cache.lock().unwrap().len(), 1);
+        server.verify().await;
+
+        // An expired entry is refetched
+        client.mint_cache.lock().unwrap().insert(
+            mint,
+            (token_program, 6, Instant::now() - DEFAULT_MINT_CACHE_TTL),
+        );
+        server.reset().await;
+        mount_mint_account(&server, &token_program, &mint_data(None), 1).await;
+        client
+            .plan_token_transfer(sdk_client, &mint, 1_000)
+            .await
+            .unwrap();
     }
 
-    impl ConfigurableMockProvider {
-        fn new() -> Self {
-            Self {
-                signing_key: test_signing_key(),
-                responses: Mutex::new(Vec::new()),
-                call_count: Mutex::new(0),
-            }
+    #[tokio::test]
+    async fn test_fee_mints_and_zero_ttl_bypass_mint_cache() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, ResponseTemplate};
+
+        // A transfer fee can change between epochs, so fee mints are refetched
+        let server = wiremock::MockServer::start().await;
+        let token_2022 = spl_token_2022_interface::id();
+        let fee_mint = mint_data(Some(transfer_fee(100, 5_000)));
+        mount_mint_account(&server, &token_2022, &fee_mint, 2).await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "getEpochInfo" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "absoluteSlot": 1_000,
+                    "blockHeight": 900,
+                    "epoch": 2,
+                    "slotIndex": 100,
+                    "slotsInEpoch": 432_000,
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let client = mint_cache_client(&server, DEFAULT_MINT_CACHE_TTL);
+        let sdk_client = client.sdk_client.as_ref().unwrap();
+        let mint = Pubkey::new_unique();
+        for _ in 0..2 {
+            let plan = client
+                .plan_token_transfer(sdk_client, &mint, 100_000)
+                .await
+                .unwrap();
+            assert_eq!(plan.expected_fee, Some(1_000));
         }
-
-        fn with_responses(responses: Vec<Result<serde_json::Value, MockErrorKind>>) -> Self {
-            let provider = Self::new();
-            *provider.responses.lock().unwrap() = responses;
-            provider
+        assert!(client.mint_cache.lock().unwrap().is_empty());
+
+        // Zero TTL disables the cache
+        let server = wiremock::MockServer::start().await;
+        mount_mint_account(&server, &spl_token_interface::id(), &mint_data(None), 2).await;
+        let client = mint_cache_client(&server, Duration::ZERO);
+        let sdk_client = client.sdk_client.as_ref().unwrap();
+        for _ in 0..2 {
+            client
+                .plan_token_transfer(sdk_client, &mint, 1_000)
+                .await
+                .unwrap();
         }
+        assert!(client.mint_cache.lock().unwrap().is_empty());
+    }
 
-        #[allow(dead_code)]
-        fn get_call_count(&self) -> usize {
-            *self.call_count.lock().unwrap()
-        }
+    /// Instructions paying one batch recipient whose token account is missing
+    fn batch_recipient_group(payer: &Pubkey, mint: &Pubkey, amount: u64) -> Vec<Instruction> {
+        let token_program = spl_token_interface::id();
+        let recipient = Pubkey::new_unique();
+        let source = get_associated_token_address_with_program_id(payer, mint, &token_program);
+        let destination =
+            get_associated_token_address_with_program_id(&recipient, mint, &token_program);
+        vec![
+            create_associated_token_account_idempotent(payer, &recipient, mint, &token_program),
+            build_token_transfer_instruction(
+                &token_program,
+                &source,
+                mint,
+                &destination,
+                payer,
+                amount,
+                6,
+                None,
+            )
+            .unwrap(),
+        ]
     }
 
-    #[async_trait]
-    impl SolanaRpcProvider for ConfigurableMockProvider {
-        async fn send_request(
-            &self,
-            _method: &str,
-            _params: serde_json::Value,
-        ) -> Result<serde_json::Value, AppError> {
-            let mut count = self.call_count.lock().unwrap();
-            let idx = *count;
-            *count += 1;
-            drop(count);
+    fn batch_prefix() -> Vec<Instruction> {
+        vec![
+            ComputeBudgetInstruction::set_compute_unit_price(1_000),
+            ComputeBudgetInstruction::set_compute_unit_limit(DEFAULT_COMPUTE_UNIT_LIMIT),
+        ]
+    }
 
-            let responses = self.responses.lock().unwrap();
-            if idx < responses.len() {
-                match &responses[idx] {
-                    Ok(v) => Ok(v.clone()),
-                    Err(MockErrorKind::Timeout(msg)) => {
-                        Err(AppError::Blockchain(BlockchainError::Timeout(msg.clone())))
-                    }
-                    Err(MockErrorKind::RpcError(msg)) => {
-                        Err(AppError::Blockchain(BlockchainError::RpcError(msg.clone())))
-                    }
-                    Err(MockErrorKind::InsufficientFunds) => {
-                        Err(AppError::Blockchain(BlockchainError::InsufficientFunds))
-                    }
-                    Err(MockErrorKind::TransactionFailed(msg)) => Err(AppError::Blockchain(
-                        BlockchainError::TransactionFailed(msg.clone()),
-                    )),
-                    Err(MockErrorKind::EmptyResponse) => Err(AppError::Blockchain(
-                        BlockchainError::RpcError("Empty response".to_string()),
-                    )),
-                }
-            } else {
-                Ok(serde_json::Value::Null)
-            }
-        }
+    #[test]
+    fn test_pack_instruction_groups_fills_one_transaction() {
+        let (payer, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let groups: Vec<_> = (1..=3)
+            .map(|amount| batch_recipient_group(&payer, &mint, amount))
+            .collect();
 
-        fn public_key(&self) -> String {
-            bs58::encode(self.signing_key.verifying_key().as_bytes()).into_string()
-        }
+        let transactions =
+            pack_instruction_groups(&payer, &batch_prefix(), &[], groups.clone()).unwrap();
 
-        fn sign(&self, message: &[u8]) -> String {
-            let signature = self.signing_key.sign(message);
-            bs58::encode(signature.to_bytes()).into_string()
-        }
+        // Small batches share a single transaction, groups whole and in order
+        assert_eq!(transactions, vec![groups]);
     }
 
-    // --- ERROR HANDLING TESTS ---
+    #[test]
+    fn test_pack_instruction_groups_splits_by_size() {
+        let (payer, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let prefix = batch_prefix();
+        let suffix = vec![system_instruction::transfer(
+            &payer,
+            &Pubkey::new_unique(),
+            10_000,
+        )];
+        let groups: Vec<_> = (1..=30)
+            .map(|amount| batch_recipient_group(&payer, &mint, amount))
+            .collect();
+
+        let transactions =
+            pack_instruction_groups(&payer, &prefix, &suffix, groups.clone()).unwrap();
+
+        assert!(transactions.len() > 1);
+        for chunk in &transactions {
+            let instructions: Vec<Instruction> = prefix
+                .iter()
+                .chain(chunk.iter().flatten())
+                .chain(&suffix)
+                .cloned()
+                .collect();
+            assert!(legacy_transaction_size(&payer, &instructions) <= MAX_TRANSACTION_SIZE);
+        }
+        // Every transaction but the last is full: the next group would not fit
+        for pair in transactions.windows(2) {
+            let mut grown = pair[0].clone();
+            grown.push(pair[1][0].clone());
+            let instructions: Vec<Instruction> = prefix
+                .iter()
+                .chain(grown.iter().flatten())
+                .chain(&suffix)
+                .cloned()
+                .collect();
+            assert!(legacy_transaction_size(&payer, &instructions) > MAX_TRANSACTION_SIZE);
+        }
+        assert_eq!(transactions.concat(), groups);
+    }
 
-    #[tokio::test]
-    async fn test_rpc_error_insufficient_funds() {
-        let provider =
-            ConfigurableMockProvider::with_responses(vec![Err(MockErrorKind::InsufficientFunds)]);
-        let config = RpcClientConfig {
-            max_retries: 0, // No retries for this test
-            ..Default::default()
-        };
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    #[test]
+    fn test_pack_instruction_groups_rejects_oversized_group() {
+        let payer = Pubkey::new_unique();
+        let memo = "m".repeat(MAX_TRANSACTION_SIZE);
+        let groups = vec![vec![memo_instruction(&memo, &payer)]];
 
-        let result = client.health_check().await;
+        let result = pack_instruction_groups(&payer, &batch_prefix(), &[], groups);
         assert!(matches!(
             result,
-            Err(AppError::Blockchain(BlockchainError::InsufficientFunds))
+            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
         ));
     }
 
-    #[tokio::test]
-    async fn test_rpc_error_timeout_mapping() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Err(MockErrorKind::Timeout(
-            "Connection timed out".to_string(),
-        ))]);
-        let config = RpcClientConfig {
-            max_retries: 0,
-            ..Default::default()
-        };
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
-
-        let result = client.health_check().await;
-        match result {
-            Err(AppError::Blockchain(BlockchainError::Timeout(msg))) => {
-                assert!(msg.contains("timed out"));
+    #[test]
+    fn test_serialized_size_flags_oversized_transactions() {
+        let payer = Keypair::new();
+        let transaction_with = |count: usize| {
+            let mut instructions = batch_prefix();
+            for i in 0..count {
+                instructions.push(memo_instruction(&format!("memo {}", i), &payer.pubkey()));
             }
-            _ => panic!("Expected timeout error"),
-        }
-    }
-
-    #[tokio::test]
-    async fn test_rpc_error_generic_rpc_error() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Err(
-            MockErrorKind::RpcError("-32000: Server is busy".to_string()),
-        )]);
-        let config = RpcClientConfig {
-            max_retries: 0,
-            ..Default::default()
+            Transaction::new_signed_with_payer(
+                &instructions,
+                Some(&payer.pubkey()),
+                &[&payer],
+                Hash::new_unique(),
+            )
         };
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
 
-        let result = client.health_check().await;
-        match result {
-            Err(AppError::Blockchain(BlockchainError::RpcError(msg))) => {
-                assert!(msg.contains("Server is busy"));
-            }
-            _ => panic!("Expected RPC error"),
-        }
-    }
+        let small = transaction_with(1);
+        let size = RpcBlockchainClient::serialized_size(&small, "Small").unwrap();
+        assert!(size + TRANSACTION_SIZE_WARNING_MARGIN <= MAX_TRANSACTION_SIZE);
+        assert!(RpcBlockchainClient::ensure_fits_packet(&small, "Small").is_ok());
 
-    // --- DESERIALIZATION TESTS ---
+        // Legacy and versioned wrappers have the same wire size
+        let versioned = VersionedTransaction::from(small.clone());
+        assert_eq!(
+            RpcBlockchainClient::serialized_size(&versioned, "Small").unwrap(),
+            size
+        );
 
-    #[test]
-    fn test_deserialize_signature_status_confirmed() {
-        let json = serde_json::json!({
-            "err": null,
-            "confirmationStatus": "confirmed"
-        });
-        let status: SignatureStatus = serde_json::from_value(json).unwrap();
-        assert!(status.err.is_none());
-        assert_eq!(status.confirmation_status.as_deref(), Some("confirmed"));
+        let large = transaction_with(100);
+        let size = RpcBlockchainClient::serialized_size(&large, "Large").unwrap();
+        assert!(size > MAX_TRANSACTION_SIZE);
+        let err = RpcBlockchainClient::ensure_fits_packet(&large, "Large").unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::Blockchain(BlockchainError::TransactionFailed(ref message))
+                if message.contains("over the 1232-byte size limit")
+        ));
     }
 
     #[test]
-    fn test_deserialize_signature_status_finalized() {
-        let json = serde_json::json!({
-            "err": null,
-            "confirmationStatus": "finalized"
-        });
-        let status: SignatureStatus = serde_json::from_value(json).unwrap();
-        assert!(status.err.is_none());
-        assert_eq!(status.confirmation_status.as_deref(), Some("finalized"));
+    fn test_batch_balance_accounts_for_every_recipient() {
+        // 1% fee capped at 5,000, grossed up per recipient
+        let mint = TokenMintInfo {
+            token_program_id: spl_token_2022_interface::id(),
+            decimals: 6,
+            transfer_fee: Some(transfer_fee(100, 5_000)),
+        };
+        let plans: Vec<_> = [99_000, 198_000, 10_000_000]
+            .into_iter()
+            .map(|amount| mint.plan(amount, true).unwrap())
+            .collect();
+        let sent: Vec<u64> = plans.iter().map(|plan| plan.send_amount).collect();
+        assert_eq!(sent, vec![100_000, 200_000, 10_005_000]);
+
+        let required = batch_send_total(&plans).unwrap();
+        assert_eq!(required, 10_305_000);
+
+        let mut account = vec![0u8; 165];
+        account[64..72].copy_from_slice(&(required - 1).to_le_bytes());
+        assert_eq!(token_account_balance(&account), Some(required - 1));
+        assert!(token_account_balance(&account[..70]).is_none());
+
+        // Overflowing totals are rejected rather than wrapped
+        let huge = TokenMintInfo {
+            transfer_fee: None,
+            ..mint
+        };
+        let plans = [
+            huge.plan(u64::MAX, false).unwrap(),
+            huge.plan(1, false).unwrap(),
+        ];
+        assert!(batch_send_total(&plans).is_err());
     }
 
     #[test]
-    fn test_deserialize_signature_status_with_error() {
-        let json = serde_json::json!({
-            "err": {"InstructionError": [0, "Custom"]},
-            "confirmationStatus": "confirmed"
-        });
-        let status: SignatureStatus = serde_json::from_value(json).unwrap();
-        assert!(status.err.is_some());
+    fn test_outcome_records_token_transfer_fee() {
+        let mint = TokenMintInfo {
+            token_program_id: spl_token_2022_interface::id(),
+            decimals: 6,
+            transfer_fee: Some(transfer_fee(100, 5_000)),
+        };
+        let plans: Vec<_> = [99_000, 198_000, 10_000_000]
+            .into_iter()
+            .map(|amount| mint.plan(amount, true).unwrap())
+            .collect();
+        let outcome = with_token_fee(SubmissionOutcome::new("sig"), &plans);
+        assert_eq!(outcome.token_sent_amount, Some(10_305_000));
+        assert_eq!(outcome.token_transfer_fee, Some(1_000 + 2_000 + 5_000));
+
+        // Without gross-up the fee comes out of the requested amount
+        let plan = mint.plan(100_000, false).unwrap();
+        let outcome = with_token_fee(SubmissionOutcome::new("sig"), [&plan]);
+        assert_eq!(outcome.token_sent_amount, Some(100_000));
+        assert_eq!(outcome.token_transfer_fee, Some(1_000));
+
+        // Nothing is recorded for a mint without a transfer fee
+        let plain = TokenMintInfo {
+            transfer_fee: None,
+            ..mint
+        };
+        let plan = plain.plan(100_000, true).unwrap();
+        let outcome = with_token_fee(SubmissionOutcome::new("sig"), [&plan]);
+        assert_eq!(outcome.token_sent_amount, None);
+        assert_eq!(outcome.token_transfer_fee, None);
     }
 
     #[test]
-    fn test_deserialize_signature_status_null_confirmation() {
-        let json = serde_json::json!({
-            "err": null,
-            "confirmationStatus": null
-        });
-        let status: SignatureStatus = serde_json::from_value(json).unwrap();
-        assert!(status.confirmation_status.is_none());
+    fn test_batch_compute_unit_limit() {
+        assert_eq!(batch_compute_unit_limit(200_000, 1), 200_000);
+        assert_eq!(batch_compute_unit_limit(200_000, 10), 400_000);
+        assert_eq!(
+            batch_compute_unit_limit(200_000, 64),
+            MAX_COMPUTE_UNIT_LIMIT
+        );
     }
 
     #[test]
-    fn test_deserialize_blockhash_result() {
-        let json = serde_json::json!({
-            "value": {
-                "blockhash": "GHtXQBsoZHVnNFa9YevAzFr17DJjgHXk3ycTy5nRhVT3"
-            }
-        });
-        let result: BlockhashResult = serde_json::from_value(json).unwrap();
+    fn test_token_transfer_instruction_selection() {
+        let token_program = spl_token_2022_interface::id();
+        let (source, mint, destination, authority) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let with_fee = build_token_transfer_instruction(
+            &token_program,
+            &source,
+            &mint,
+            &destination,
+            &authority,
+            100_000,
+            6,
+            Some(1_000),
+        )
+        .unwrap();
         assert_eq!(
-            result.value.blockhash,
-            "GHtXQBsoZHVnNFa9YevAzFr17DJjgHXk3ycTy5nRhVT3"
+            with_fee,
+            transfer_checked_with_fee(
+                &token_program,
+                &source,
+                &mint,
+                &destination,
+                &authority,
+                &[],
+                100_000,
+                6,
+                1_000
+            )
+            .unwrap()
+        );
+
+        let without_fee = build_token_transfer_instruction(
+            &token_program,
+            &source,
+            &mint,
+            &destination,
+            &authority,
+            100_000,
+            6,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            without_fee,
+            spl_token_2022_interface::instruction::transfer_checked(
+                &token_program,
+                &source,
+                &mint,
+                &destination,
+                &authority,
+                &[],
+                100_000,
+                6
+            )
+            .unwrap()
         );
+        assert_ne!(with_fee.data, without_fee.data);
+
+        // Legacy SPL Token mints keep the plain transfer_checked instruction
+        let legacy = build_token_transfer_instruction(
+            &spl_token_interface::id(),
+            &source,
+            &mint,
+            &destination,
+            &authority,
+            100_000,
+            6,
+            None,
+        )
+        .unwrap();
+        assert_eq!(legacy.program_id, spl_token_interface::id());
+    }
+
+    /// Token-2022 account owned by `owner`, optionally withholding transfer fees
+    fn token_account(
+        owner: Pubkey,
+        amount: u64,
+        close_authority: Option<Pubkey>,
+        withheld: Option<u64>,
+    ) -> solana_sdk::account::Account {
+        use spl_token_2022_interface::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
+        use spl_token_2022_interface::state::AccountState;
+
+        let extensions: &[ExtensionType] = if withheld.is_some() {
+            &[ExtensionType::TransferFeeAmount]
+        } else {
+            &[]
+        };
+        let len = ExtensionType::try_calculate_account_len::<Token2022Account>(extensions).unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<Token2022Account>::unpack_uninitialized(&mut data).unwrap();
+        if let Some(withheld) = withheld {
+            let fees = state.init_extension::<TransferFeeAmount>(true).unwrap();
+            fees.withheld_amount = withheld.into();
+        }
+        state.base = Token2022Account {
+            mint: Pubkey::new_unique(),
+            owner,
+            amount,
+            state: AccountState::Initialized,
+            close_authority: close_authority.into(),
+            ..Default::default()
+        };
+        state.pack_base();
+        if withheld.is_some() {
+            state.init_account_type().unwrap();
+        }
+
+        solana_sdk::account::Account {
+            lamports: 2_039_280,
+            data,
+            owner: spl_token_2022_interface::id(),
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    fn confidential_mint_data(auditor: Option<[u8; ELGAMAL_PUBKEY_LEN]>) -> Vec<u8> {
+        use spl_token_2022_interface::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
+
+        let len = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+            ExtensionType::ConfidentialTransferMint,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<Token2022Mint>::unpack_uninitialized(&mut data).unwrap();
+        let extension = state
+            .init_extension::<ConfidentialTransferMint>(true)
+            .unwrap();
+        extension.auditor_elgamal_pubkey =
+            bytemuck::cast(auditor.unwrap_or([0u8; ELGAMAL_PUBKEY_LEN]));
+        state.base = Token2022Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
     }
 
     #[test]
-    fn test_deserialize_signature_status_result() {
-        let json = serde_json::json!({
-            "value": [
-                {
-                    "err": null,
-                    "confirmationStatus": "finalized"
-                }
-            ]
-        });
-        let result: SignatureStatusResult = serde_json::from_value(json).unwrap();
-        assert_eq!(result.value.len(), 1);
-        assert!(result.value[0].is_some());
+    fn test_parse_confidential_auditor() {
+        let auditor = [7u8; ELGAMAL_PUBKEY_LEN];
+        assert_eq!(
+            parse_confidential_auditor(&confidential_mint_data(Some(auditor))).unwrap(),
+            Some(auditor)
+        );
+        assert!(
+            parse_confidential_auditor(&confidential_mint_data(None))
+                .unwrap()
+                .is_none()
+        );
+        // Mints without the extension have no auditor
+        assert!(
+            parse_confidential_auditor(&mint_data(None))
+                .unwrap()
+                .is_none()
+        );
     }
 
     #[test]
-    fn test_deserialize_signature_status_result_null_entry() {
-        let json = serde_json::json!({
-            "value": [null]
-        });
-        let result: SignatureStatusResult = serde_json::from_value(json).unwrap();
-        assert_eq!(result.value.len(), 1);
-        assert!(result.value[0].is_none());
+    fn test_check_confidential_auditor() {
+        let auditor = [7u8; ELGAMAL_PUBKEY_LEN];
+        let other = [9u8; ELGAMAL_PUBKEY_LEN];
+        let zero = [0u8; ELGAMAL_PUBKEY_LEN];
+
+        assert!(check_confidential_auditor(None, None, zero).is_ok());
+        assert!(check_confidential_auditor(Some(auditor), Some(auditor), auditor).is_ok());
+
+        for (mint, requested, proof) in [
+            // Mint requires an auditor the request does not name
+            (Some(auditor), None, auditor),
+            // Mint has no auditor
+            (None, Some(auditor), auditor),
+            // Request names a different auditor
+            (Some(auditor), Some(other), other),
+            // Proof is not encrypted for the auditor
+            (Some(auditor), Some(auditor), zero),
+        ] {
+            let err = check_confidential_auditor(mint, requested, proof).unwrap_err();
+            assert!(
+                matches!(
+                    &err,
+                    AppError::Validation(crate::domain::ValidationError::InvalidField { field, .. })
+                        if field == "auditor_elgamal_pubkey"
+                ),
+                "unexpected error: {:?}",
+                err
+            );
+        }
     }
 
-    // --- TRANSACTION STATUS TESTS ---
+    /// Confidential mint data that also carries the confidential transfer fee extension
+    fn confidential_fee_mint_data() -> Vec<u8> {
+        use spl_token_2022_interface::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
 
-    #[tokio::test]
-    async fn test_get_transaction_status_confirmed() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
-            "value": [{
-                "err": null,
-                "confirmationStatus": "confirmed"
-            }]
-        }))]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        let len = ExtensionType::try_calculate_account_len::<Token2022Mint>(&[
+            ExtensionType::ConfidentialTransferMint,
+            ExtensionType::ConfidentialTransferFeeConfig,
+        ])
+        .unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<Token2022Mint>::unpack_uninitialized(&mut data).unwrap();
+        state
+            .init_extension::<ConfidentialTransferMint>(true)
+            .unwrap();
+        state
+            .init_extension::<ConfidentialTransferFeeConfig>(true)
+            .unwrap();
+        state.base = Token2022Mint {
+            decimals: 6,
+            is_initialized: true,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        data
+    }
 
-        let result = client.get_transaction_status("test_sig").await;
-        assert!(result.is_ok());
-        assert!(result.unwrap()); // Should be confirmed
+    #[test]
+    fn test_parse_confidential_transfer_fee() {
+        assert!(parse_confidential_transfer_fee(&confidential_fee_mint_data()).unwrap());
+        assert!(!parse_confidential_transfer_fee(&confidential_mint_data(None)).unwrap());
+        assert!(!parse_confidential_transfer_fee(&mint_data(None)).unwrap());
     }
 
-    #[tokio::test]
-    async fn test_get_transaction_status_finalized() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
-            "value": [{
-                "err": null,
-                "confirmationStatus": "finalized"
-            }]
-        }))]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    #[test]
+    fn test_check_confidential_transfer_fee() {
+        let proofs: (&[u8], &[u8]) = (&[1u8; 4], &[2u8; 4]);
 
-        let result = client.get_transaction_status("test_sig").await;
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+        assert_eq!(check_confidential_transfer_fee(false, None).unwrap(), None);
+        assert_eq!(
+            check_confidential_transfer_fee(true, Some(proofs)).unwrap(),
+            Some(proofs)
+        );
+
+        // Fee mint without fee proofs, and fee proofs for a mint without a fee
+        for (mint_has_fee, fee_proofs) in [(true, None), (false, Some(proofs))] {
+            let err = check_confidential_transfer_fee(mint_has_fee, fee_proofs).unwrap_err();
+            assert!(
+                matches!(
+                    &err,
+                    AppError::Validation(crate::domain::ValidationError::InvalidField { field, .. })
+                        if field == "fee_sigma_proof"
+                ),
+                "unexpected error: {:?}",
+                err
+            );
+        }
     }
 
-    #[tokio::test]
-    async fn test_get_transaction_status_not_found() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
-            "value": [null]
-        }))]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    /// Token-2022 account data, with the confidential transfer extension set to
+    /// `(approved, allow_confidential_credits)` when given
+    fn confidential_account_data(confidential: Option<(bool, bool)>) -> Vec<u8> {
+        use spl_token_2022_interface::extension::{
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        };
+        use spl_token_2022_interface::state::AccountState;
 
-        let result = client.get_transaction_status("unknown_sig").await;
-        assert!(result.is_ok());
-        assert!(!result.unwrap()); // Not found = not confirmed
+        let extensions: &[ExtensionType] = if confidential.is_some() {
+            &[ExtensionType::ConfidentialTransferAccount]
+        } else {
+            &[]
+        };
+        let len = ExtensionType::try_calculate_account_len::<Token2022Account>(extensions).unwrap();
+        let mut data = vec![0u8; len];
+        let mut state =
+            StateWithExtensionsMut::<Token2022Account>::unpack_uninitialized(&mut data).unwrap();
+        if let Some((approved, allow_credits)) = confidential {
+            let extension = state
+                .init_extension::<ConfidentialTransferAccount>(true)
+                .unwrap();
+            extension.approved = approved.into();
+            extension.allow_confidential_credits = allow_credits.into();
+        }
+        state.base = Token2022Account {
+            mint: Pubkey::new_unique(),
+            owner: Pubkey::new_unique(),
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        state.pack_base();
+        if confidential.is_some() {
+            state.init_account_type().unwrap();
+        }
+        data
     }
 
-    #[tokio::test]
-    async fn test_get_transaction_status_with_error() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
-            "value": [{
-                "err": {"InstructionError": [0, "Custom"]},
-                "confirmationStatus": "confirmed"
-            }]
-        }))]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
-
-        let result = client.get_transaction_status("failed_sig").await;
-        assert!(matches!(
-            result,
-            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
-        ));
+    #[test]
+    fn test_parse_confidential_destination() {
+        for (data, expected) in [
+            (None, ConfidentialDestinationState::Missing),
+            (
+                Some(confidential_account_data(None)),
+                ConfidentialDestinationState::NotConfigured,
+            ),
+            (
+                Some(confidential_account_data(Some((true, false)))),
+                ConfidentialDestinationState::CreditsDisabled,
+            ),
+            (
+                Some(confidential_account_data(Some((false, true)))),
+                ConfidentialDestinationState::NotApproved,
+            ),
+            (
+                Some(confidential_account_data(Some((true, true)))),
+                ConfidentialDestinationState::Ready,
+            ),
+        ] {
+            assert_eq!(
+                parse_confidential_destination(data.as_deref()).unwrap(),
+                expected
+            );
+        }
+        assert!(parse_confidential_destination(Some(&[0u8; 10])).is_err());
     }
 
-    // --- BLOCKHASH AND BLOCK HEIGHT TESTS ---
+    #[test]
+    fn test_parse_confidential_transfer_authority() {
+        use spl_token_2022_interface::extension::{
+            BaseStateWithExtensionsMut, StateWithExtensionsMut,
+        };
 
-    #[tokio::test]
-    async fn test_get_latest_blockhash() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
-            "value": {
-                "blockhash": "TestBlockhash123"
-            }
-        }))]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        assert!(
+            parse_confidential_transfer_authority(&confidential_mint_data(None))
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            parse_confidential_transfer_authority(&mint_data(None))
+                .unwrap()
+                .is_none()
+        );
 
-        let result = client.get_latest_blockhash().await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), "TestBlockhash123");
+        let authority = Pubkey::new_unique();
+        let mut data = confidential_mint_data(None);
+        let mut state = StateWithExtensionsMut::<Token2022Mint>::unpack(&mut data).unwrap();
+        state
+            .get_extension_mut::<ConfidentialTransferMint>()
+            .unwrap()
+            .authority = Some(authority).try_into().unwrap();
+        assert_eq!(
+            parse_confidential_transfer_authority(&data).unwrap(),
+            Some(authority)
+        );
     }
 
-    #[tokio::test]
-    async fn test_get_block_height() {
-        let provider =
-            ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!(123456789u64))]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    #[test]
+    fn test_confidential_destination_approval() {
+        let relayer = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let ready = confidential_destination_approval(
+            ConfidentialDestinationState::Ready,
+            None,
+            &relayer,
+            &destination,
+            &mint,
+        );
+        assert!(ready.unwrap().is_none());
+
+        // The relayer approves the account when it is the mint's authority
+        let approve_ix = confidential_destination_approval(
+            ConfidentialDestinationState::NotApproved,
+            Some(relayer),
+            &relayer,
+            &destination,
+            &mint,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            approve_ix,
+            approve_account(&spl_token_2022::id(), &destination, &mint, &relayer, &[]).unwrap()
+        );
 
-        let result = client.get_block_height().await;
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), 123456789);
+        for (state, mint_authority, step) in [
+            (
+                ConfidentialDestinationState::Missing,
+                None,
+                "ConfigureAccount",
+            ),
+            (
+                ConfidentialDestinationState::NotConfigured,
+                Some(relayer),
+                "ConfigureAccount",
+            ),
+            (
+                ConfidentialDestinationState::CreditsDisabled,
+                Some(relayer),
+                "EnableConfidentialCredits",
+            ),
+            (
+                ConfidentialDestinationState::NotApproved,
+                Some(Pubkey::new_unique()),
+                "ApproveAccount",
+            ),
+            (
+                ConfidentialDestinationState::NotApproved,
+                None,
+                "ApproveAccount",
+            ),
+        ] {
+            let err = confidential_destination_approval(
+                state,
+                mint_authority,
+                &relayer,
+                &destination,
+                &mint,
+            )
+            .unwrap_err();
+            assert!(
+                matches!(
+                    &err,
+                    AppError::Validation(crate::domain::ValidationError::InvalidField { field, message })
+                        if field == "to_address" && message.contains(step)
+                ),
+                "unexpected error for {:?}: {:?}",
+                state,
+                err
+            );
+        }
     }
 
-    // --- WAIT FOR CONFIRMATION TESTS ---
+    #[test]
+    fn test_confidential_transfer_instruction_layout() {
+        use spl_token_2022_interface::extension::confidential_transfer::instruction::{
+            TransferInstructionData, transfer,
+        };
+        use spl_token_2022_interface::instruction::decode_instruction_data;
+
+        let source = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let [equality, validity, range] = std::array::from_fn(|_| Pubkey::new_unique());
+        let authority = Pubkey::new_unique();
+        let balance = DecryptableBalance::from([5u8; 36]);
+
+        // The auditor ciphertexts are the commitment and third handle of each half
+        let mut context: BatchedGroupedCiphertext3HandlesValidityProofContext =
+            bytemuck::Zeroable::zeroed();
+        let lo: &mut [u8] = bytemuck::bytes_of_mut(&mut context.grouped_ciphertext_lo);
+        lo[..32].fill(1);
+        lo[96..].fill(2);
+        let hi: &mut [u8] = bytemuck::bytes_of_mut(&mut context.grouped_ciphertext_hi);
+        hi[..32].fill(3);
+        hi[96..].fill(4);
+        let ciphertexts = auditor_ciphertexts(&context).unwrap();
+        let bytes: &[u8] = bytemuck::cast_slice(&ciphertexts);
+        for (i, byte) in [1u8, 2, 3, 4].into_iter().enumerate() {
+            assert!(bytes[i * 32..(i + 1) * 32].iter().all(|b| *b == byte));
+        }
 
-    #[tokio::test]
-    async fn test_wait_for_confirmation_immediate_success() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
-            "value": [{
-                "err": null,
-                "confirmationStatus": "finalized"
-            }]
-        }))]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        let ix = confidential_transfer_instruction(
+            &source,
+            &mint,
+            &destination,
+            [equality, validity, range],
+            &authority,
+            &balance,
+            &ciphertexts,
+        )
+        .unwrap();
 
-        let result = client.wait_for_confirmation("test_sig", 5).await;
-        assert!(result.is_ok());
-        assert!(result.unwrap());
+        // Identical to the interface's Transfer with every proof in a context account
+        let expected = transfer(
+            &spl_token_2022_interface::id(),
+            &source,
+            &mint,
+            &destination,
+            &balance,
+            &ciphertexts[0],
+            &ciphertexts[1],
+            &authority,
+            &[],
+            ProofLocation::ContextStateAccount(&equality),
+            ProofLocation::ContextStateAccount(&validity),
+            ProofLocation::ContextStateAccount(&range),
+        )
+        .unwrap();
+        assert_eq!(vec![ix.clone()], expected);
+
+        // 27 (ConfidentialTransferExtension) | 7 (Transfer)
+        assert_eq!(ix.program_id, spl_token_2022::id());
+        assert_eq!(ix.data[..2], [27, 7]);
+        let data: &TransferInstructionData = decode_instruction_data(&ix.data[1..]).unwrap();
+        assert_eq!(data.new_source_decryptable_available_balance, balance);
+        assert_eq!(data.transfer_amount_auditor_ciphertext_lo, ciphertexts[0]);
+        assert_eq!(data.transfer_amount_auditor_ciphertext_hi, ciphertexts[1]);
+        assert_eq!(data.equality_proof_instruction_offset, 0);
+        assert_eq!(data.ciphertext_validity_proof_instruction_offset, 0);
+        assert_eq!(data.range_proof_instruction_offset, 0);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(equality, false),
+                AccountMeta::new_readonly(validity, false),
+                AccountMeta::new_readonly(range, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
     }
 
-    #[tokio::test]
-    async fn test_wait_for_confirmation_eventual_success() {
-        // First call: not confirmed, second call: confirmed
-        let provider = ConfigurableMockProvider::with_responses(vec![
-            Ok(serde_json::json!({"value": [null]})),
-            Ok(serde_json::json!({
-                "value": [{
-                    "err": null,
-                    "confirmationStatus": "confirmed"
-                }]
-            })),
-        ]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
-
-        tokio::time::pause();
-        let result = client.wait_for_confirmation("test_sig", 10).await;
-        assert!(result.is_ok());
-        assert!(result.unwrap());
-    }
+    #[test]
+    fn test_confidential_transfer_with_fee_instruction_layout() {
+        use spl_token_2022_interface::extension::confidential_transfer::instruction::{
+            TransferWithFeeInstructionData, transfer_with_fee,
+        };
+        use spl_token_2022_interface::instruction::decode_instruction_data;
+
+        let source = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let [equality, validity, fee_sigma, fee_validity, range] =
+            std::array::from_fn(|_| Pubkey::new_unique());
+        let authority = Pubkey::new_unique();
+        let balance = DecryptableBalance::from([5u8; 36]);
+        let ciphertexts = [PodElGamalCiphertext::from([8u8; 64]); 2];
+
+        let plain = confidential_transfer_instruction(
+            &source,
+            &mint,
+            &destination,
+            [equality, validity, range],
+            &authority,
+            &balance,
+            &ciphertexts,
+        )
+        .unwrap();
+        let with_fee = confidential_transfer_with_fee_instruction(
+            &source,
+            &mint,
+            &destination,
+            [equality, validity, fee_sigma, fee_validity, range],
+            &authority,
+            &balance,
+            &ciphertexts,
+        )
+        .unwrap();
 
-    #[tokio::test]
-    async fn test_wait_for_confirmation_timeout() {
-        // Always return not confirmed
-        let provider = ConfigurableMockProvider::with_responses(vec![
-            Ok(serde_json::json!({"value": [null]})),
-            Ok(serde_json::json!({"value": [null]})),
-            Ok(serde_json::json!({"value": [null]})),
-            Ok(serde_json::json!({"value": [null]})),
-            Ok(serde_json::json!({"value": [null]})),
-        ]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        // Identical to the interface's TransferWithFee with every proof in a context account
+        let expected = transfer_with_fee(
+            &spl_token_2022_interface::id(),
+            &source,
+            &mint,
+            &destination,
+            &balance,
+            &ciphertexts[0],
+            &ciphertexts[1],
+            &authority,
+            &[],
+            ProofLocation::ContextStateAccount(&equality),
+            ProofLocation::ContextStateAccount(&validity),
+            ProofLocation::ContextStateAccount(&fee_sigma),
+            ProofLocation::ContextStateAccount(&fee_validity),
+            ProofLocation::ContextStateAccount(&range),
+        )
+        .unwrap();
+        assert_eq!(vec![with_fee.clone()], expected);
+
+        // 27 (ConfidentialTransferExtension) | 13 (TransferWithFee), then five
+        // proof offsets where Transfer has three
+        assert_eq!(with_fee.program_id, plain.program_id);
+        assert_eq!(with_fee.data[..2], [27, 13]);
+        assert_eq!(with_fee.data.len(), plain.data.len() + 2);
+        let data: &TransferWithFeeInstructionData =
+            decode_instruction_data(&with_fee.data[1..]).unwrap();
+        assert_eq!(data.new_source_decryptable_available_balance, balance);
+        assert_eq!(data.transfer_amount_auditor_ciphertext_lo, ciphertexts[0]);
+        assert_eq!(data.transfer_amount_auditor_ciphertext_hi, ciphertexts[1]);
+        assert_eq!(data.equality_proof_instruction_offset, 0);
+        assert_eq!(
+            data.transfer_amount_ciphertext_validity_proof_instruction_offset,
+            0
+        );
+        assert_eq!(data.fee_sigma_proof_instruction_offset, 0);
+        assert_eq!(data.fee_ciphertext_validity_proof_instruction_offset, 0);
+        assert_eq!(data.range_proof_instruction_offset, 0);
 
-        tokio::time::pause();
-        let result = client.wait_for_confirmation("never_confirmed", 1).await;
-        assert!(matches!(
-            result,
-            Err(AppError::Blockchain(BlockchainError::Timeout(_)))
-        ));
+        // The fee contexts sit between the transfer amount validity and range contexts
+        assert_eq!(
+            with_fee.accounts,
+            vec![
+                AccountMeta::new(source, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(equality, false),
+                AccountMeta::new_readonly(validity, false),
+                AccountMeta::new_readonly(fee_sigma, false),
+                AccountMeta::new_readonly(fee_validity, false),
+                AccountMeta::new_readonly(range, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+        assert_eq!(with_fee.accounts[..5], plain.accounts[..5]);
+        assert_eq!(with_fee.accounts[7..], plain.accounts[5..]);
     }
 
-    #[tokio::test]
-    async fn test_wait_for_confirmation_transaction_failed() {
-        let provider = ConfigurableMockProvider::with_responses(vec![Ok(serde_json::json!({
-            "value": [{
-                "err": {"InstructionError": [0, "ProgramFailed"]},
-                "confirmationStatus": "confirmed"
-            }]
-        }))]);
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    #[test]
+    fn test_close_empty_token_account_instruction() {
+        let relayer = Pubkey::new_unique();
+        let address = Pubkey::new_unique();
+
+        for close_authority in [None, Some(relayer)] {
+            let account = token_account(relayer, 0, close_authority, Some(0));
+            let ix = close_empty_token_account_instruction(&relayer, &address, &account).unwrap();
+            assert_eq!(
+                ix,
+                spl_token_2022_interface::instruction::close_account(
+                    &spl_token_2022_interface::id(),
+                    &address,
+                    &relayer,
+                    &relayer,
+                    &[]
+                )
+                .unwrap()
+            );
+            // Rent goes back to the relayer
+            assert_eq!(ix.accounts[1].pubkey, relayer);
+        }
 
-        let result = client.wait_for_confirmation("failed_tx", 5).await;
-        assert!(matches!(
-            result,
-            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
-        ));
+        // Plain SPL Token accounts are closed through their own program
+        let mut account = token_account(relayer, 0, None, None);
+        account.owner = spl_token_interface::id();
+        let ix = close_empty_token_account_instruction(&relayer, &address, &account).unwrap();
+        assert_eq!(ix.program_id, spl_token_interface::id());
     }
 
-    // --- SUBMIT TRANSACTION TESTS (MOCK MODE) ---
+    #[test]
+    fn test_close_empty_token_account_only_targets_relayer_accounts() {
+        let relayer = Pubkey::new_unique();
+        let address = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        let not_closable = [
+            // Someone else's account
+            token_account(other, 0, None, None),
+            // Still holds tokens
+            token_account(relayer, 1, None, None),
+            // Only another authority may close it
+            token_account(relayer, 0, Some(other), None),
+            // Withheld transfer fees must be harvested first
+            token_account(relayer, 0, None, Some(10)),
+            // Not a token account at all
+            solana_sdk::account::Account {
+                owner: solana_system_interface::program::id(),
+                ..token_account(relayer, 0, None, None)
+            },
+        ];
+        for account in &not_closable {
+            assert!(close_empty_token_account_instruction(&relayer, &address, account).is_none());
+        }
+    }
 
     #[tokio::test]
-    #[cfg(not(feature = "real-blockchain"))]
-    async fn test_submit_transaction_mock_mode() {
-        let provider = ConfigurableMockProvider::new();
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    async fn test_close_empty_token_account_disabled_by_default() {
+        let provider = ConfigurableMockProvider::with_responses(vec![]);
+        let methods = provider.method_log();
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(provider), RpcClientConfig::default());
 
-        // In mock mode (no real-blockchain feature), submit_transaction just signs
-        let request = TransferRequest {
-            id: "test_hash_123".to_string(),
-            ..Default::default()
-        };
-        let result = client.submit_transaction(&request).await;
-        assert!(result.is_ok());
-        let (signature, blockhash) = result.unwrap();
-        assert!(signature.starts_with("tx_")); // Mock format
-        assert!(!blockhash.is_empty());
+        assert!(!client.closes_empty_token_accounts());
+        let mint = Pubkey::new_unique().to_string();
+        assert_eq!(
+            client.close_empty_token_account(&mint, None).await.unwrap(),
+            None
+        );
+        assert!(methods.lock().unwrap().is_empty());
     }
 
-    // --- RETRY LOGIC WITH CALL TRACKING ---
+    // ====================================================================
+    // PRIORITY FEE CEILING TESTS
+    // ====================================================================
+
+    fn client_with_fee(fee: u64, config: RpcClientConfig) -> RpcBlockchainClient {
+        let mut client =
+            RpcBlockchainClient::with_provider(Box::new(ConfigurableMockProvider::new()), config);
+        client.fee_strategy =
+            Box::new(super::super::strategies::FallbackFeeStrategy::with_fee(fee));
+        client
+    }
 
     #[tokio::test]
-    async fn test_retry_counts_attempts_correctly() {
-        let provider = ConfigurableMockProvider::with_responses(vec![
-            Err(MockErrorKind::Timeout("fail 1".to_string())),
-            Err(MockErrorKind::Timeout("fail 2".to_string())),
-            Err(MockErrorKind::Timeout("fail 3".to_string())),
-            Ok(serde_json::json!(999u64)), // Success on 4th attempt
-        ]);
+    async fn test_priority_fee_clamped_to_ceiling() {
         let config = RpcClientConfig {
-            max_retries: 3, // Initial + 3 retries = 4 attempts
-            retry_delay: Duration::from_millis(1),
+            max_priority_fee_microlamports: 50_000,
             ..Default::default()
         };
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        let client = client_with_fee(u64::MAX, config);
+        assert_eq!(client.get_priority_fee(None).await, 50_000);
 
-        let result = client.health_check().await;
-        assert!(result.is_ok());
+        let client = client_with_fee(10_000_000_000, RpcClientConfig::default());
+        assert_eq!(
+            client.get_priority_fee(None).await,
+            DEFAULT_MAX_PRIORITY_FEE_MICROLAMPORTS
+        );
     }
 
     #[tokio::test]
-    async fn test_no_retry_on_insufficient_funds() {
-        // InsufficientFunds should still trigger retries as per current implementation
-        let provider = ConfigurableMockProvider::with_responses(vec![
-            Err(MockErrorKind::InsufficientFunds),
-            Err(MockErrorKind::InsufficientFunds),
-        ]);
+    async fn test_priority_fee_below_ceiling_unchanged() {
         let config = RpcClientConfig {
-            max_retries: 1,
-            retry_delay: Duration::from_millis(1),
+            max_priority_fee_microlamports: 50_000,
             ..Default::default()
         };
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        let client = client_with_fee(1_234, config.clone());
+        assert_eq!(client.get_priority_fee(None).await, 1_234);
 
-        let result = client.health_check().await;
-        assert!(matches!(
-            result,
-            Err(AppError::Blockchain(BlockchainError::InsufficientFunds))
-        ));
-        // Note: We can't check the provider's state after moving it into Box
-        // The test validates that InsufficientFunds is eventually returned after retries
+        let client = client_with_fee(50_000, config);
+        assert_eq!(client.get_priority_fee(None).await, 50_000);
     }
 
-    // --- WITH_PROVIDER CONSTRUCTOR TEST ---
-
-    #[test]
-    fn test_with_provider_constructor() {
-        let provider = ConfigurableMockProvider::new();
-        let config = RpcClientConfig {
-            max_retries: 5,
-            timeout: Duration::from_secs(45),
-            ..Default::default()
-        };
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    /// Fee strategy returning a fixed fee and counting its calls
+    struct InjectedFeeStrategy {
+        fee: u64,
+        calls: std::sync::Arc<AtomicUsize>,
+    }
 
-        // Verify public key is accessible
-        let pubkey = client.public_key();
-        assert!(!pubkey.is_empty());
+    #[async_trait]
+    impl super::super::strategies::FeeStrategy for InjectedFeeStrategy {
+        async fn get_priority_fee(&self, _serialized_tx: Option<&str>) -> u64 {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.fee
+        }
 
-        // Verify signing works
-        let sig = client.sign(b"test");
-        assert!(!sig.is_empty());
+        fn name(&self) -> &'static str {
+            "Injected"
+        }
     }
 
-    // --- HTTP PROVIDER TESTS ---
+    #[tokio::test]
+    async fn test_injected_fee_strategy_overrides_detection() {
+        let calls = std::sync::Arc::new(AtomicUsize::new(0));
+        let strategy = InjectedFeeStrategy {
+            fee: 4_321,
+            calls: std::sync::Arc::clone(&calls),
+        };
+        // A Helius URL would otherwise select the Helius fee strategy
+        let client = RpcBlockchainClient::new_with_fee_strategy(
+            "https://mainnet.helius-rpc.com/?api-key=test",
+            test_signing_key(),
+            RpcClientConfig::default(),
+            Box::new(strategy),
+            None,
+            None,
+        )
+        .unwrap();
 
-    #[test]
-    fn test_http_solana_rpc_provider_creation() {
-        let signing_key = test_signing_key();
-        let result = HttpSolanaRpcProvider::new(
-            "https://api.devnet.solana.com",
-            signing_key,
-            Duration::from_secs(30),
+        assert_eq!(client.fee_strategy.name(), "Injected");
+        assert_eq!(client.get_priority_fee(None).await, 4_321);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        // Only fee estimation bypasses detection
+        assert_eq!(
+            client.provider_type,
+            super::super::strategies::RpcProviderType::Helius
         );
-        assert!(result.is_ok());
+        assert!(client.das_client.is_some());
     }
 
     #[test]
-    fn test_http_solana_rpc_provider_public_key() {
-        let signing_key = test_signing_key();
-        let provider = HttpSolanaRpcProvider::new(
-            "https://api.devnet.solana.com",
-            signing_key.clone(),
-            Duration::from_secs(30),
-        )
-        .unwrap();
+    fn test_compute_budget_defaults() {
+        let config = RpcClientConfig::default();
+        assert_eq!(
+            config.max_priority_fee_microlamports,
+            DEFAULT_MAX_PRIORITY_FEE_MICROLAMPORTS
+        );
+        assert_eq!(config.compute_unit_limit, DEFAULT_COMPUTE_UNIT_LIMIT);
+    }
 
-        let pubkey = provider.public_key();
-        assert!(!pubkey.is_empty());
-        // Verify it matches the expected public key
-        let expected = bs58::encode(signing_key.verifying_key().as_bytes()).into_string();
-        assert_eq!(pubkey, expected);
+    // ====================================================================
+    // DURABLE NONCE TESTS
+    // ====================================================================
+
+    fn nonce_account_data(state: u32, authority: &Pubkey, nonce: &Hash) -> Vec<u8> {
+        let mut data = Vec::with_capacity(80);
+        data.extend_from_slice(&1u32.to_le_bytes()); // Versions::Current
+        data.extend_from_slice(&state.to_le_bytes());
+        data.extend_from_slice(authority.as_ref());
+        data.extend_from_slice(nonce.as_ref());
+        data.extend_from_slice(&5000u64.to_le_bytes()); // lamports_per_signature
+        data
     }
 
     #[test]
-    fn test_http_solana_rpc_provider_sign() {
-        let signing_key = test_signing_key();
-        let provider = HttpSolanaRpcProvider::new(
-            "https://api.devnet.solana.com",
-            signing_key,
-            Duration::from_secs(30),
-        )
-        .unwrap();
+    fn test_prepend_advance_nonce_is_first_instruction() {
+        let nonce_account = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let mut instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_price(1_000),
+            system_instruction::transfer(&authority, &Pubkey::new_unique(), 1),
+        ];
 
-        let signature = provider.sign(b"test message");
-        assert!(!signature.is_empty());
-        // Signature should be base58 encoded
-        let decoded = bs58::decode(&signature).into_vec();
-        assert!(decoded.is_ok());
-        assert_eq!(decoded.unwrap().len(), 64); // Ed25519 signature is 64 bytes
-    }
+        prepend_advance_nonce(&mut instructions, &nonce_account, &authority);
 
-    // --- JSON-RPC STRUCTURE TESTS ---
+        assert_eq!(instructions.len(), 3);
+        let advance = &instructions[0];
+        assert_eq!(
+            *advance,
+            system_instruction::advance_nonce_account(&nonce_account, &authority)
+        );
+        assert_eq!(advance.program_id, solana_system_interface::program::ID);
+        assert_eq!(advance.accounts[0].pubkey, nonce_account);
+        assert!(advance.accounts[0].is_writable);
+        let authority_meta = advance
+            .accounts
+            .iter()
+            .find(|meta| meta.pubkey == authority)
+            .unwrap();
+        assert!(authority_meta.is_signer);
+        // Original order is preserved after the nonce instruction
+        assert_eq!(
+            instructions[1],
+            ComputeBudgetInstruction::set_compute_unit_price(1_000)
+        );
+    }
 
     #[test]
-    fn test_json_rpc_response_with_result() {
-        let json = serde_json::json!({
-            "result": 12345,
-            "error": null
-        });
-        let response: JsonRpcResponse<u64> = serde_json::from_value(json).unwrap();
-        assert_eq!(response.result, Some(12345));
-        assert!(response.error.is_none());
+    fn test_parse_durable_nonce() {
+        let authority = Pubkey::new_unique();
+        let nonce = Hash::new_unique();
+
+        let initialized = nonce_account_data(NONCE_STATE_INITIALIZED, &authority, &nonce);
+        assert_eq!(parse_durable_nonce(&initialized).unwrap(), nonce);
+
+        let uninitialized = nonce_account_data(0, &authority, &nonce);
+        assert!(parse_durable_nonce(&uninitialized).is_err());
+        assert!(parse_durable_nonce(&initialized[..50]).is_err());
+        assert!(parse_durable_nonce(&[]).is_err());
     }
 
     #[test]
-    fn test_json_rpc_response_with_error() {
-        let json = serde_json::json!({
-            "result": null,
-            "error": {
-                "code": -32600,
-                "message": "Invalid Request"
-            }
-        });
-        let response: JsonRpcResponse<u64> = serde_json::from_value(json).unwrap();
-        assert!(response.result.is_none());
-        assert!(response.error.is_some());
-        let error = response.error.unwrap();
-        assert_eq!(error.code, -32600);
-        assert_eq!(error.message, "Invalid Request");
+    fn test_durable_nonce_mode_from_config() {
+        let provider = ConfigurableMockProvider::new();
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(provider), RpcClientConfig::default());
+        assert!(!client.uses_durable_nonce());
+
+        let provider = ConfigurableMockProvider::new();
+        let config = RpcClientConfig {
+            nonce_account: Some(Pubkey::new_unique()),
+            ..Default::default()
+        };
+        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        assert!(client.uses_durable_nonce());
     }
 
     #[test]
-    fn test_json_rpc_error_insufficient_funds_by_message() {
-        let json = serde_json::json!({
-            "result": null,
-            "error": {
-                "code": -32000,
-                "message": "Transaction simulation failed: insufficient lamports"
-            }
-        });
-        let response: JsonRpcResponse<String> = serde_json::from_value(json).unwrap();
-        let error = response.error.unwrap();
-        // The message contains "insufficient" which triggers InsufficientFunds error
-        assert!(error.message.contains("insufficient"));
+    fn test_versioned_tx_disabled_by_default() {
+        let config = RpcClientConfig::default();
+        assert!(!config.use_versioned_tx);
+        assert!(config.address_lookup_table.is_none());
     }
 
     #[test]
-    fn test_json_rpc_error_insufficient_funds_by_code() {
-        let json = serde_json::json!({
-            "result": null,
-            "error": {
-                "code": -32002,
-                "message": "Some other error"
-            }
-        });
-        let response: JsonRpcResponse<String> = serde_json::from_value(json).unwrap();
-        let error = response.error.unwrap();
-        // Error code -32002 triggers InsufficientFunds
-        assert_eq!(error.code, -32002);
+    fn test_memo_instruction() {
+        let signer = Pubkey::new_unique();
+        let ix = memo_instruction("INV-2026-0042", &signer);
+
+        assert_eq!(ix.program_id, spl_memo_interface::v3::id());
+        assert_eq!(ix.data, b"INV-2026-0042");
+        assert_eq!(ix.accounts.len(), 1);
+        assert_eq!(ix.accounts[0].pubkey, signer);
+        assert!(ix.accounts[0].is_signer);
     }
 
-    // --- DESERIALIZATION ERROR TESTS ---
+    struct PrivateSubmissionMock;
 
-    #[tokio::test]
-    async fn test_rpc_call_deserialization_error() {
-        // Return a value that can't be deserialized to expected type
-        let provider = ConfigurableMockProvider::with_responses(vec![
-            Ok(serde_json::json!("not_a_number")), // String instead of u64
-        ]);
-        let config = RpcClientConfig {
-            max_retries: 0,
-            ..Default::default()
-        };
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    #[async_trait]
+    impl super::super::strategies::SubmissionStrategy for PrivateSubmissionMock {
+        async fn submit_transaction(
+            &self,
+            _serialized_tx: &str,
+            _skip_preflight: bool,
+            _jito_region: Option<&str>,
+        ) -> Result<SubmissionOutcome, AppError> {
+            Ok(SubmissionOutcome::new("mock_sig"))
+        }
 
-        // get_block_height expects u64, but we return a string
-        let result = client.get_block_height().await;
-        match result {
-            Err(AppError::Blockchain(BlockchainError::RpcError(msg))) => {
-                assert!(msg.contains("Deserialization error"));
-            }
-            _ => panic!("Expected deserialization error, got {:?}", result),
+        fn name(&self) -> &'static str {
+            "Private mock"
+        }
+
+        fn supports_private_submission(&self) -> bool {
+            true
         }
     }
 
     #[tokio::test]
-    async fn test_rpc_call_empty_response_after_retries() {
-        // No responses configured - should use fallback null
+    async fn test_jito_tip_amount_prefers_dynamic_strategy() {
+        use super::super::quicknode::{DynamicTipConfig, DynamicTipStrategy};
+
         let provider = ConfigurableMockProvider::new();
-        let config = RpcClientConfig {
-            max_retries: 0,
+        let mut client =
+            RpcBlockchainClient::with_provider(Box::new(provider), RpcClientConfig::default());
+        client.jito_tip_lamports = Some(1_000);
+        // No Jito-capable submission strategy: never tip
+        assert_eq!(client.jito_tip_amount().await, None);
+
+        client.submission_strategy = Some(Box::new(PrivateSubmissionMock));
+        assert_eq!(client.jito_tip_amount().await, Some(1_000));
+
+        // The tip floor endpoint is unreachable, so the dynamic floor is paid
+        let client = client.with_dynamic_tip(DynamicTipStrategy::new(DynamicTipConfig {
+            tip_floor_url: "http://127.0.0.1:9/tip_floor".to_string(),
+            floor_lamports: 7_500,
             ..Default::default()
-        };
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+        }));
+        assert_eq!(client.jito_tip_amount().await, Some(7_500));
+    }
 
-        // Try to get block height - provider returns null which can't deserialize to u64
-        let result = client.get_block_height().await;
-        assert!(result.is_err());
+    fn proof_transaction<'a>(
+        payer: &Keypair,
+        account: &'a Keypair,
+        description: &'static str,
+    ) -> ProofTransaction<'a> {
+        ProofTransaction {
+            description,
+            instructions: vec![
+                system_instruction::create_account(
+                    &payer.pubkey(),
+                    &account.pubkey(),
+                    1,
+                    0,
+                    &Pubkey::new_unique(),
+                ),
+                memo_instruction(description, &payer.pubkey()),
+            ],
+            signers: vec![account],
+        }
     }
 
-    // --- SIGNING KEY ADDITIONAL TESTS ---
+    #[test]
+    fn test_assemble_proof_bundle_preserves_order() {
+        let payer = Keypair::new();
+        let accounts = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let proofs = [
+            proof_transaction(&payer, &accounts[0], "equality"),
+            proof_transaction(&payer, &accounts[1], "validity"),
+            proof_transaction(&payer, &accounts[2], "range record"),
+        ];
+        let blockhash = Hash::new_unique();
+
+        let bundle = assemble_proof_bundle(&proofs, &payer, blockhash, None);
+
+        assert_eq!(bundle.len(), 3);
+        for ((tx, account), description) in
+            bundle
+                .iter()
+                .zip(&accounts)
+                .zip(["equality", "validity", "range record"])
+        {
+            assert!(tx.verify().is_ok());
+            assert_eq!(tx.message.recent_blockhash, blockhash);
+            // Fee payer first, then the proof's own signer
+            assert_eq!(tx.message.account_keys[0], payer.pubkey());
+            assert_eq!(tx.message.account_keys[1], account.pubkey());
+            assert_eq!(tx.message.instructions[1].data, description.as_bytes());
+        }
+    }
 
     #[test]
-    fn test_signing_key_from_base58_64_bytes_invalid_keypair() {
-        // Create 64 random bytes (not a valid keypair where bytes 32-64 are the public key)
-        let invalid_keypair = vec![42u8; 64];
-        let encoded = bs58::encode(&invalid_keypair).into_string();
-        let secret = SecretString::from(encoded);
+    fn test_assemble_proof_bundle_tips_last_transaction() {
+        let payer = Keypair::new();
+        let accounts = [Keypair::new(), Keypair::new()];
+        let proofs = [
+            proof_transaction(&payer, &accounts[0], "equality"),
+            proof_transaction(&payer, &accounts[1], "range record"),
+        ];
+        let tip_account = Pubkey::new_unique();
+        let tip = system_instruction::transfer(&payer.pubkey(), &tip_account, 1_000);
+
+        let bundle = assemble_proof_bundle(&proofs, &payer, Hash::new_unique(), Some(tip));
+
+        // Only the last transaction pays the tip, as its final instruction
+        assert_eq!(bundle.len(), 2);
+        assert_eq!(bundle[0].message.instructions.len(), 2);
+        let last = &bundle[1];
+        assert!(last.verify().is_ok());
+        assert_eq!(last.message.instructions.len(), 3);
+        let tip_ix = &last.message.instructions[2];
+        assert_eq!(
+            last.message.account_keys[tip_ix.program_id_index as usize],
+            solana_system_interface::program::id()
+        );
+        assert_eq!(
+            last.message.account_keys[tip_ix.accounts[1] as usize],
+            tip_account
+        );
+        assert_eq!(last.message.instructions[1].data, b"range record");
+    }
+
+    struct RecordingBundleStrategy {
+        submitted: std::sync::Arc<Mutex<Vec<String>>>,
+        regions: std::sync::Arc<Mutex<Vec<Option<String>>>>,
+    }
+
+    #[async_trait]
+    impl super::super::strategies::SubmissionStrategy for RecordingBundleStrategy {
+        async fn submit_transaction(
+            &self,
+            serialized_tx: &str,
+            _skip_preflight: bool,
+            jito_region: Option<&str>,
+        ) -> Result<SubmissionOutcome, AppError> {
+            let mut submitted = self.submitted.lock().unwrap();
+            submitted.push(serialized_tx.to_string());
+            self.regions
+                .lock()
+                .unwrap()
+                .push(jito_region.map(String::from));
+            Ok(SubmissionOutcome::new(format!("sig_{}", submitted.len())))
+        }
+
+        fn name(&self) -> &'static str {
+            "Recording mock"
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_and_confirm_bundle_keeps_order() {
+        let confirmed = || {
+            Ok(serde_json::json!({
+                "value": [{"err": null, "confirmationStatus": "confirmed"}]
+            }))
+        };
+        let provider =
+            ConfigurableMockProvider::with_responses(vec![confirmed(), confirmed(), confirmed()]);
+        let mut client =
+            RpcBlockchainClient::with_provider(Box::new(provider), RpcClientConfig::default());
+        let submitted = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let regions = std::sync::Arc::new(Mutex::new(Vec::new()));
+        client.submission_strategy = Some(Box::new(RecordingBundleStrategy {
+            submitted: std::sync::Arc::clone(&submitted),
+            regions: std::sync::Arc::clone(&regions),
+        }));
+
+        let payer = Keypair::new();
+        let accounts = [Keypair::new(), Keypair::new(), Keypair::new()];
+        let proofs = [
+            proof_transaction(&payer, &accounts[0], "equality"),
+            proof_transaction(&payer, &accounts[1], "validity"),
+            proof_transaction(&payer, &accounts[2], "range record"),
+        ];
+        let bundle = assemble_proof_bundle(&proofs, &payer, Hash::new_unique(), None);
+
+        let options = SubmitOptions {
+            jito_region: Some("tokyo"),
+            ..SubmitOptions::default()
+        };
+        let signatures = client
+            .submit_and_confirm_bundle(&bundle, "Proof verification bundle", options)
+            .await
+            .unwrap();
+
+        // Strategies without bundle support submit one transaction at a time, in order
+        assert_eq!(signatures, vec!["sig_1", "sig_2", "sig_3"]);
+        let expected: Vec<String> = bundle
+            .iter()
+            .map(|tx| {
+                client
+                    .serialize_transaction_base58(&VersionedTransaction::from(tx.clone()))
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(*submitted.lock().unwrap(), expected);
+        // The transfer's region goes with every transaction of the bundle
+        assert_eq!(*regions.lock().unwrap(), vec![Some("tokyo".to_string()); 3]);
+    }
+
+    #[tokio::test]
+    async fn test_submit_and_confirm_bundle_requires_strategy() {
+        let provider = ConfigurableMockProvider::new();
+        let client =
+            RpcBlockchainClient::with_provider(Box::new(provider), RpcClientConfig::default());
+        let payer = Keypair::new();
+        let account = Keypair::new();
+        let bundle = assemble_proof_bundle(
+            &[proof_transaction(&payer, &account, "equality")],
+            &payer,
+            Hash::new_unique(),
+            None,
+        );
 
-        // This should still work since we only use the first 32 bytes
-        let result = signing_key_from_base58(&secret);
-        assert!(result.is_ok());
+        let result = client
+            .submit_and_confirm_bundle(&bundle, "Proof bundle", SubmitOptions::default())
+            .await;
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(BlockchainError::TransactionFailed(_)))
+        ));
     }
 
-    #[test]
-    fn test_signing_key_from_base58_empty_string() {
-        let secret = SecretString::from("");
-        let result = signing_key_from_base58(&secret);
-        assert!(result.is_err());
+    /// Strategy rejecting its first submission because an account the
+    /// transaction creates already exists. The failing instruction is the
+    /// ATA creation when there is one, else the token transfer.
+    struct AccountRaceStrategy {
+        submitted: std::sync::Arc<Mutex<Vec<String>>>,
     }
 
-    // --- SDK-BASED TRANSFER TESTS (only with real-blockchain feature) ---
-
-    #[cfg(feature = "real-blockchain")]
-    mod real_blockchain_tests {
-        use super::*;
-
-        #[tokio::test]
-        async fn test_submit_transaction_real_blockchain_path() {
-            // This test verifies the SDK client is properly initialized
-            // Actual transfer tests require network/mocking
-            let signing_key = test_signing_key();
-            let client =
-                RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
-                    .unwrap();
+    #[async_trait]
+    impl super::super::strategies::SubmissionStrategy for AccountRaceStrategy {
+        async fn submit_transaction(
+            &self,
+            serialized_tx: &str,
+            _skip_preflight: bool,
+            _jito_region: Option<&str>,
+        ) -> Result<SubmissionOutcome, AppError> {
+            let mut submitted = self.submitted.lock().unwrap();
+            submitted.push(serialized_tx.to_string());
+            if submitted.len() == 1 {
+                let instruction = ata_creation_indexes(serialized_tx)
+                    .first()
+                    .copied()
+                    .unwrap_or(2);
+                return Err(AppError::Blockchain(BlockchainError::AccountAlreadyInUse {
+                    instruction,
+                    message: "Allocate: account Address { address: Dest111, base: None } \
+                              already in use"
+                        .to_string(),
+                }));
+            }
+            Ok(SubmissionOutcome::new("sig_resent"))
+        }
 
-            // Verify SDK components are initialized
-            assert!(client.sdk_client.is_some());
-            assert!(client.keypair.is_some());
-            let _ = client.public_key();
+        fn name(&self) -> &'static str {
+            "Account race mock"
         }
     }
 
-    // --- RPC CLIENT NEW CONSTRUCTOR TEST ---
+    /// Mount a `getAccountInfo` response for `address`, `None` when it does
+    /// not exist
+    async fn mount_account(
+        server: &wiremock::MockServer,
+        address: &Pubkey,
+        account: Option<&solana_sdk::account::Account>,
+    ) {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let value = account.map(|account| {
+            serde_json::json!({
+                "lamports": account.lamports,
+                "data": [BASE64_STANDARD.encode(&account.data), "base64"],
+                "owner": account.owner.to_string(),
+                "executable": false,
+                "rentEpoch": 0,
+                "space": account.data.len(),
+            })
+        });
+        Mock::given(method("POST"))
+            .and(body_partial_json(serde_json::json!({
+                "method": "getAccountInfo",
+                "params": [address.to_string()],
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "context": { "slot": 1 }, "value": value }
+            })))
+            .mount(server)
+            .await;
+    }
 
-    #[test]
-    fn test_rpc_blockchain_client_new() {
+    /// Client paying a token transfer from a funded account through
+    /// `AccountRaceStrategy`, with the recipient's account optionally present
+    async fn account_race_client(
+        server: &wiremock::MockServer,
+        mint: &Pubkey,
+        recipient: &Pubkey,
+        recipient_account_exists: bool,
+    ) -> (RpcBlockchainClient, std::sync::Arc<Mutex<Vec<String>>>) {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let token_program = spl_token_2022_interface::id();
         let signing_key = test_signing_key();
+        let payer = keypair_from_signing_key(&signing_key).unwrap().pubkey();
+        let mint_account = solana_sdk::account::Account {
+            lamports: 1_461_600,
+            data: mint_data(None),
+            owner: token_program,
+            executable: false,
+            rent_epoch: 0,
+        };
+        mount_account(server, mint, Some(&mint_account)).await;
+        let source = get_associated_token_address_with_program_id(&payer, mint, &token_program);
+        mount_account(
+            server,
+            &source,
+            Some(&token_account(payer, 1_000_000, None, None)),
+        )
+        .await;
+        let destination =
+            get_associated_token_address_with_program_id(recipient, mint, &token_program);
+        let destination_account = token_account(*recipient, 0, None, None);
+        mount_account(
+            server,
+            &destination,
+            recipient_account_exists.then_some(&destination_account),
+        )
+        .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "getLatestBlockhash" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": { "slot": 1 },
+                    "value": {
+                        "blockhash": Hash::new_unique().to_string(),
+                        "lastValidBlockHeight": 100,
+                    }
+                }
+            })))
+            .mount(server)
+            .await;
+
+        let submitted = std::sync::Arc::new(Mutex::new(Vec::new()));
         let config = RpcClientConfig {
-            timeout: Duration::from_secs(15),
-            max_retries: 2,
-            retry_delay: Duration::from_millis(250),
-            confirmation_timeout: Duration::from_secs(30),
+            max_retries: 0,
+            ..Default::default()
         };
-        let result = RpcBlockchainClient::new("https://api.devnet.solana.com", signing_key, config);
-        assert!(result.is_ok());
+        let client = RpcBlockchainClient::new_with_fee_strategy(
+            &server.uri(),
+            signing_key,
+            config,
+            Box::new(InjectedFeeStrategy {
+                fee: 1_000,
+                calls: std::sync::Arc::new(AtomicUsize::new(0)),
+            }),
+            Some(Box::new(AccountRaceStrategy {
+                submitted: std::sync::Arc::clone(&submitted),
+            })),
+            None,
+        )
+        .unwrap();
+        (client, submitted)
     }
 
-    // --- PROVIDER TRAIT OBJECT TESTS ---
-
-    #[test]
-    fn test_provider_as_trait_object() {
-        let provider: Box<dyn SolanaRpcProvider> = Box::new(ConfigurableMockProvider::new());
-
-        // Test public_key through trait object
-        let pubkey = provider.public_key();
-        assert!(!pubkey.is_empty());
+    /// Indexes of the ATA creations in a submitted (base58) transaction
+    fn ata_creation_indexes(serialized_tx: &str) -> Vec<u8> {
+        let bytes = bs58::decode(serialized_tx).into_vec().unwrap();
+        let (tx, _): (VersionedTransaction, usize) =
+            bincode::serde::decode_from_slice(&bytes, bincode::config::legacy()).unwrap();
+        let keys = tx.message.static_account_keys();
+        (0u8..)
+            .zip(tx.message.instructions())
+            .filter(|(_, ix)| {
+                keys[ix.program_id_index as usize] == spl_associated_token_account::id()
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
 
-        // Test sign through trait object
-        let sig = provider.sign(b"message");
-        assert!(!sig.is_empty());
+    /// Number of ATA creations in a submitted (base58) transaction
+    fn ata_creations(serialized_tx: &str) -> usize {
+        ata_creation_indexes(serialized_tx).len()
     }
 
-    // --- BLOCKHASH RESPONSE DESERIALIZATION ---
+    #[tokio::test]
+    async fn test_token_transfer_resent_without_ata_after_creation_race() {
+        let server = wiremock::MockServer::start().await;
+        let mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let (client, submitted) = account_race_client(&server, &mint, &recipient, false).await;
+
+        let (outcome, _) = client
+            .transfer_token(
+                &recipient.to_string(),
+                &mint.to_string(),
+                1_000,
+                None,
+                SubmitOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        // The account was created between the check and execution: the
+        // transfer is resent once, without creating it again
+        assert_eq!(outcome.signature, "sig_resent");
+        let submitted = submitted.lock().unwrap();
+        assert_eq!(submitted.len(), 2);
+        assert_eq!(ata_creations(&submitted[0]), 1);
+        assert_eq!(ata_creations(&submitted[1]), 0);
+    }
 
-    #[test]
-    fn test_blockhash_response_deserialization() {
-        let json = serde_json::json!({
-            "blockhash": "4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZAMdL4VZHirAn"
-        });
-        let response: BlockhashResponse = serde_json::from_value(json).unwrap();
-        assert_eq!(
-            response.blockhash,
-            "4sGjMW1sUnHzSxGspuhpqLDx6wiyjNtZAMdL4VZHirAn"
+    #[tokio::test]
+    async fn test_token_batch_resent_without_the_ata_that_raced() {
+        let server = wiremock::MockServer::start().await;
+        let mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let (client, submitted) = account_race_client(&server, &mint, &recipient, false).await;
+        let other_destination = get_associated_token_address_with_program_id(
+            &other,
+            &mint,
+            &spl_token_2022_interface::id(),
         );
+        mount_account(&server, &other_destination, None).await;
+
+        let (outcome, _) = client
+            .transfer_token_batch(
+                &mint.to_string(),
+                &[(recipient.to_string(), 1_000), (other.to_string(), 2_000)],
+                None,
+                SubmitOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        // Only the account that already exists is left out of the resend;
+        // the other recipient's account is still created
+        assert_eq!(outcome.signature, "sig_resent");
+        let submitted = submitted.lock().unwrap();
+        assert_eq!(submitted.len(), 2);
+        assert_eq!(ata_creations(&submitted[0]), 2);
+        assert_eq!(ata_creations(&submitted[1]), 1);
     }
 
-    // --- ADDITIONAL RPC CLIENT CONFIG TESTS ---
+    #[tokio::test]
+    async fn test_token_batch_rejected_when_it_needs_several_transactions() {
+        let server = wiremock::MockServer::start().await;
+        let mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let (client, submitted) = account_race_client(&server, &mint, &recipient, false).await;
+        let mut recipients = vec![(recipient.to_string(), 1_000)];
+        for _ in 1..crate::domain::MAX_BATCH_RECIPIENTS {
+            let other = Pubkey::new_unique();
+            let destination = get_associated_token_address_with_program_id(
+                &other,
+                &mint,
+                &spl_token_2022_interface::id(),
+            );
+            mount_account(&server, &destination, None).await;
+            recipients.push((other.to_string(), 1_000));
+        }
 
-    #[test]
-    fn test_rpc_client_config_very_short_timeout() {
-        let config = RpcClientConfig {
-            timeout: Duration::from_millis(1),
-            max_retries: 0,
-            retry_delay: Duration::from_millis(1),
-            confirmation_timeout: Duration::from_millis(1),
-        };
-        assert_eq!(config.timeout, Duration::from_millis(1));
+        let result = client
+            .transfer_token_batch(
+                &mint.to_string(),
+                &recipients,
+                None,
+                SubmitOptions::default(),
+            )
+            .await;
+
+        // Creating every recipient's account does not fit one transaction:
+        // nothing is sent, so no recipient can be paid twice on retry
+        match result {
+            Err(AppError::Validation(crate::domain::ValidationError::InvalidField {
+                field,
+                message,
+            })) => {
+                assert_eq!(field, "recipients");
+                assert!(message.contains("split it into smaller batches"));
+            }
+            other => panic!("expected a recipients validation error, got {:?}", other),
+        }
+        assert!(submitted.lock().unwrap().is_empty());
     }
 
-    #[test]
-    fn test_rpc_client_config_zero_retries() {
-        let config = RpcClientConfig {
-            max_retries: 0,
-            ..Default::default()
-        };
-        assert_eq!(config.max_retries, 0);
+    #[tokio::test]
+    async fn test_account_in_use_not_retried_without_ata_creation() {
+        let server = wiremock::MockServer::start().await;
+        let mint = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        let (client, submitted) = account_race_client(&server, &mint, &recipient, true).await;
+
+        let result = client
+            .transfer_token(
+                &recipient.to_string(),
+                &mint.to_string(),
+                1_000,
+                None,
+                SubmitOptions::default(),
+            )
+            .await;
+
+        // Nothing was being created, so the error is not a lost race
+        assert!(matches!(
+            result,
+            Err(AppError::Blockchain(
+                BlockchainError::AccountAlreadyInUse { .. }
+            ))
+        ));
+        let submitted = submitted.lock().unwrap();
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(ata_creations(&submitted[0]), 0);
     }
 
-    // ====================================================================
-    // SUBMISSION STRATEGY INTEGRATION TESTS
-    // ====================================================================
-
-    #[test]
-    fn test_client_without_submission_strategy() {
-        let signing_key = test_signing_key();
-        let client =
-            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
-                .unwrap();
-
-        // Without explicit strategy, should not have one
-        assert!(!client.has_submission_strategy());
-        assert!(!client.supports_private_submission());
+    /// Strategy failing the first proposal creation as if another proposer
+    /// had taken its transaction index
+    struct IndexRaceStrategy {
+        submitted: std::sync::Arc<Mutex<Vec<String>>>,
     }
 
-    #[test]
-    fn test_client_with_submission_strategy_constructor() {
-        use super::super::quicknode::{
-            QuickNodePrivateSubmissionStrategy, QuickNodeSubmissionConfig,
-        };
+    #[async_trait]
+    impl super::super::strategies::SubmissionStrategy for IndexRaceStrategy {
+        async fn submit_transaction(
+            &self,
+            serialized_tx: &str,
+            _skip_preflight: bool,
+            _jito_region: Option<&str>,
+        ) -> Result<SubmissionOutcome, AppError> {
+            let mut submitted = self.submitted.lock().unwrap();
+            submitted.push(serialized_tx.to_string());
+            if submitted.len() == 1 {
+                return Err(AppError::Blockchain(BlockchainError::TransactionFailed(
+                    "custom program error: 0x7d6".to_string(),
+                )));
+            }
+            Ok(SubmissionOutcome::new("sig_proposal"))
+        }
 
-        let signing_key = test_signing_key();
-        let config = QuickNodeSubmissionConfig {
-            rpc_url: "https://test.quiknode.pro/xxx".to_string(),
-            enable_jito_bundles: true,
-            tip_lamports: 10_000,
-            max_bundle_retries: 2,
-            region: None,
-        };
-        let strategy: Box<dyn super::super::strategies::SubmissionStrategy> =
-            Box::new(QuickNodePrivateSubmissionStrategy::new(config));
+        fn name(&self) -> &'static str {
+            "Index race mock"
+        }
+    }
 
-        let client = RpcBlockchainClient::with_defaults_and_submission_strategy(
-            "https://test.quiknode.pro/xxx",
-            signing_key,
-            Some(strategy),
-            Some(10_000), // Jito tip
+    #[tokio::test]
+    async fn test_pinned_signer_pays_for_transfer() {
+        use crate::infra::blockchain::signers::KeySelectionPolicy;
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = wiremock::MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "getLatestBlockhash" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": { "slot": 1 },
+                    "value": {
+                        "blockhash": Hash::new_unique().to_string(),
+                        "lastValidBlockHeight": 100,
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+
+        let submitted = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let client = RpcBlockchainClient::new_with_fee_strategy(
+            &server.uri(),
+            test_signing_key(),
+            RpcClientConfig::default(),
+            Box::new(InjectedFeeStrategy {
+                fee: 1_000,
+                calls: std::sync::Arc::new(AtomicUsize::new(0)),
+            }),
+            Some(Box::new(RecordingBundleStrategy {
+                submitted: std::sync::Arc::clone(&submitted),
+                regions: std::sync::Arc::new(Mutex::new(Vec::new())),
+            })),
+            None,
         )
+        .unwrap()
+        .with_additional_signing_keys(&[test_signing_key()], KeySelectionPolicy::RequestId)
         .unwrap();
+        let pinned = client.signer_pubkeys()[1].clone();
 
-        assert!(client.has_submission_strategy());
-        assert!(client.supports_private_submission());
+        let options = SubmitOptions {
+            signer: Some(&pinned),
+            ..SubmitOptions::default()
+        };
+        client
+            .transfer_sol(&Pubkey::new_unique().to_string(), 1_000, None, options)
+            .await
+            .unwrap();
+
+        let serialized = bs58::decode(&submitted.lock().unwrap()[0])
+            .into_vec()
+            .unwrap();
+        let (transaction, _): (VersionedTransaction, _) =
+            bincode::serde::decode_from_slice(&serialized, bincode::config::legacy()).unwrap();
+        assert_eq!(
+            transaction.message.static_account_keys()[0].to_string(),
+            pinned
+        );
     }
 
-    #[test]
-    fn test_client_with_jito_disabled_strategy() {
-        use super::super::quicknode::{
-            QuickNodePrivateSubmissionStrategy, QuickNodeSubmissionConfig,
+    #[tokio::test]
+    async fn test_multisig_proposal_retried_at_next_index_when_taken() {
+        use sha2::{Digest, Sha256};
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let server = wiremock::MockServer::start().await;
+        let squads = super::super::squads::SquadsConfig::new(Pubkey::new_unique());
+        // Another proposer takes index 6 between the first read and the creation
+        let multisig_account = |transaction_index: u64| {
+            let mut data = Sha256::digest(b"account:Multisig")[..8].to_vec();
+            data.resize(8 + 32 + 32 + 2 + 4, 0);
+            data.extend_from_slice(&transaction_index.to_le_bytes());
+            data.extend_from_slice(&[0; 16]);
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": { "slot": 1 },
+                    "value": {
+                        "lamports": 1_000_000,
+                        "data": [BASE64_STANDARD.encode(&data), "base64"],
+                        "owner": squads.program_id.to_string(),
+                        "executable": false,
+                        "rentEpoch": 0,
+                        "space": data.len(),
+                    }
+                }
+            })
         };
+        for (transaction_index, times) in [(5, Some(1)), (6, None)] {
+            let mock = Mock::given(method("POST"))
+                .and(body_partial_json(serde_json::json!({
+                    "method": "getAccountInfo",
+                    "params": [squads.multisig.to_string()],
+                })))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(multisig_account(transaction_index)),
+                );
+            match times {
+                Some(times) => mock.up_to_n_times(times).mount(&server).await,
+                None => mock.mount(&server).await,
+            }
+        }
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "getLatestBlockhash" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": { "slot": 1 },
+                    "value": {
+                        "blockhash": Hash::new_unique().to_string(),
+                        "lastValidBlockHeight": 100,
+                    }
+                }
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "getSignatureStatuses" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": { "slot": 1 },
+                    "value": [{
+                        "slot": 1,
+                        "confirmations": null,
+                        "err": null,
+                        "confirmationStatus": "confirmed",
+                    }]
+                }
+            })))
+            .mount(&server)
+            .await;
 
-        let signing_key = test_signing_key();
-        let config = QuickNodeSubmissionConfig {
-            rpc_url: "https://test.quiknode.pro/xxx".to_string(),
-            enable_jito_bundles: false, // Jito disabled
-            tip_lamports: 10_000,
-            max_bundle_retries: 2,
-            region: None,
+        let submitted = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let config = RpcClientConfig {
+            max_retries: 0,
+            ..Default::default()
         };
-        let strategy: Box<dyn super::super::strategies::SubmissionStrategy> =
-            Box::new(QuickNodePrivateSubmissionStrategy::new(config));
-
-        let client = RpcBlockchainClient::with_defaults_and_submission_strategy(
-            "https://test.quiknode.pro/xxx",
-            signing_key,
-            Some(strategy),
-            None, // No tip when Jito disabled
+        let client = RpcBlockchainClient::new_with_fee_strategy(
+            &server.uri(),
+            test_signing_key(),
+            config,
+            Box::new(InjectedFeeStrategy {
+                fee: 1_000,
+                calls: std::sync::Arc::new(AtomicUsize::new(0)),
+            }),
+            Some(Box::new(IndexRaceStrategy {
+                submitted: std::sync::Arc::clone(&submitted),
+            })),
+            None,
         )
-        .unwrap();
+        .unwrap()
+        .with_multisig(squads.clone());
+
+        let (outcome, _) = client
+            .transfer_sol(
+                &Pubkey::new_unique().to_string(),
+                1_000,
+                None,
+                SubmitOptions::default(),
+            )
+            .await
+            .unwrap();
 
-        // Has strategy, but private submission is not supported (Jito disabled)
-        assert!(client.has_submission_strategy());
-        assert!(!client.supports_private_submission());
+        assert!(outcome.awaiting_approval);
+        assert_eq!(outcome.signature, squads.proposal(7).to_string());
+        assert_eq!(submitted.lock().unwrap().len(), 2);
     }
 
     #[test]
-    fn test_with_provider_has_no_strategy() {
-        let provider = ConfigurableMockProvider::new();
-        let config = RpcClientConfig::default();
-        let client = RpcBlockchainClient::with_provider(Box::new(provider), config);
+    fn test_failed_account_creation() {
+        let payer = Pubkey::new_unique();
+        let instructions = vec![
+            ComputeBudgetInstruction::set_compute_unit_price(1),
+            create_associated_token_account_idempotent(
+                &payer,
+                &Pubkey::new_unique(),
+                &Pubkey::new_unique(),
+                &spl_token_interface::id(),
+            ),
+            system_instruction::transfer(&payer, &Pubkey::new_unique(), 1),
+        ];
+        let in_use = |instruction| {
+            AppError::Blockchain(BlockchainError::AccountAlreadyInUse {
+                instruction,
+                message: "already in use".to_string(),
+            })
+        };
 
-        // with_provider doesn't accept a strategy, so it should not have one
-        assert!(!client.has_submission_strategy());
-        assert!(!client.supports_private_submission());
+        assert_eq!(
+            failed_account_creation(&in_use(1), &instructions),
+            Some(&instructions[1])
+        );
+        // Raised by an instruction that creates no token account
+        assert!(failed_account_creation(&in_use(2), &instructions).is_none());
+        assert!(failed_account_creation(&in_use(7), &instructions).is_none());
+        // The message alone is not enough
+        assert!(
+            failed_account_creation(
+                &AppError::Blockchain(BlockchainError::TransactionFailed(
+                    "Allocate: account Address { address: X, base: None } already in use"
+                        .to_string()
+                )),
+                &instructions
+            )
+            .is_none()
+        );
     }
 
     #[test]
-    fn test_serialize_transaction_base58() {
-        use solana_sdk::{hash::Hash, transaction::Transaction};
-
-        let signing_key = test_signing_key();
-        let client =
-            RpcBlockchainClient::with_defaults("https://api.devnet.solana.com", signing_key)
-                .unwrap();
-
-        // Create a minimal unsigned transaction
-        let recent_blockhash = Hash::new_unique();
-        let keypair = client.keypair.as_ref().unwrap();
-        let tx = Transaction::new_signed_with_payer(
-            &[],
-            Some(&keypair.pubkey()),
-            &[keypair],
-            recent_blockhash,
+    fn test_account_already_in_use_from_transaction_error() {
+        let in_use = TransactionError::InstructionError(
+            3,
+            InstructionError::Custom(SystemError::AccountAlreadyInUse as u32),
         );
+        assert!(matches!(
+            account_already_in_use(&in_use, "failed"),
+            Some(AppError::Blockchain(BlockchainError::AccountAlreadyInUse {
+                instruction: 3,
+                ..
+            }))
+        ));
+        assert!(
+            account_already_in_use(
+                &TransactionError::InstructionError(3, InstructionError::Custom(1)),
+                "failed"
+            )
+            .is_none()
+        );
+        assert!(account_already_in_use(&TransactionError::AccountInUse, "failed").is_none());
 
-        // Serialize should work
-        let result = client.serialize_transaction_base58(&tx);
-        assert!(result.is_ok());
-
-        let serialized = result.unwrap();
-        assert!(!serialized.is_empty());
-
-        // Should be valid Base58
-        let decoded = bs58::decode(&serialized).into_vec();
-        assert!(decoded.is_ok());
+        // As reported by sendTransaction and simulateTransaction
+        assert!(matches!(
+            rpc_account_already_in_use(
+                &serde_json::json!({"InstructionError": [2, {"Custom": 0}]}),
+                "failed"
+            ),
+            Some(AppError::Blockchain(BlockchainError::AccountAlreadyInUse {
+                instruction: 2,
+                ..
+            }))
+        ));
+        assert!(
+            rpc_account_already_in_use(
+                &serde_json::json!({"InstructionError": [2, "InvalidAccountData"]}),
+                "failed"
+            )
+            .is_none()
+        );
     }
 }
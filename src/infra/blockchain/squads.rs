@@ -0,0 +1,468 @@
+//! Squads v4 multisig as the transfer authority.
+//!
+//! With a multisig configured, transfers move funds out of a Squads vault
+//! instead of the issuer wallet. The relayer never signs for the vault: it
+//! wraps the transfer instructions in a vault transaction and opens a
+//! proposal for it, which the members approve and execute with their own
+//! tooling. The issuer key only creates and pays for the proposal, so it
+//! must be a member of the multisig with the Initiate permission.
+//!
+//! Each proposal takes the multisig's next transaction index. The client
+//! creates its proposals one at a time, waiting for each to confirm before
+//! reading the index again, and retries at the new index when another
+//! proposer took it first.
+//!
+//! The proposal address is tracked in place of a signature while the
+//! transfer awaits approval; the crank reads the proposal account to settle
+//! the transfer once the members have decided. A proposal still undecided
+//! after [`SquadsConfig::approval_timeout`] marks the transfer `expired`;
+//! the members should then reject it so it can never execute.
+
+use std::time::Duration;
+
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+};
+
+use crate::domain::{AppError, BlockchainError, ProposalStatus};
+
+/// Squads v4 program, deployed at the same address on mainnet and devnet
+pub const SQUADS_V4_PROGRAM_ID: &str = "SQDS4ep65T869zMMBKyuUq6SqQhpMyRSoTCT5HMzHHS";
+
+/// How long a proposal may await approval before its transfer expires
+pub const DEFAULT_APPROVAL_TIMEOUT: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+const SEED_PREFIX: &[u8] = b"multisig";
+const SEED_VAULT: &[u8] = b"vault";
+const SEED_TRANSACTION: &[u8] = b"transaction";
+const SEED_PROPOSAL: &[u8] = b"proposal";
+
+/// Anchor discriminator of `vault_transaction_create`: `sha256("global:vault_transaction_create")[..8]`
+const VAULT_TRANSACTION_CREATE_DISCRIMINATOR: [u8; 8] = [48, 250, 78, 168, 208, 226, 218, 211];
+
+/// Anchor discriminator of `proposal_create`: `sha256("global:proposal_create")[..8]`
+const PROPOSAL_CREATE_DISCRIMINATOR: [u8; 8] = [220, 60, 73, 224, 30, 108, 79, 159];
+
+/// Anchor discriminator of the `Multisig` account: `sha256("account:Multisig")[..8]`
+const MULTISIG_ACCOUNT_DISCRIMINATOR: [u8; 8] = [224, 116, 121, 186, 68, 161, 79, 236];
+
+/// Anchor discriminator of the `Proposal` account: `sha256("account:Proposal")[..8]`
+const PROPOSAL_ACCOUNT_DISCRIMINATOR: [u8; 8] = [26, 94, 189, 187, 116, 136, 53, 33];
+
+/// Offset of `transaction_index` in a `Multisig` account: after the
+/// discriminator, `create_key`, `config_authority`, `threshold` and `time_lock`
+const MULTISIG_TRANSACTION_INDEX_OFFSET: usize = 8 + 32 + 32 + 2 + 4;
+
+/// Offset of the status tag in a `Proposal` account: after the
+/// discriminator, `multisig` and `transaction_index`
+const PROPOSAL_STATUS_OFFSET: usize = 8 + 32 + 8;
+
+/// The Squads multisig transfers are proposed to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SquadsConfig {
+    /// Squads program the multisig belongs to
+    pub program_id: Pubkey,
+    /// Multisig account address
+    pub multisig: Pubkey,
+    /// Index of the vault funds are sent from
+    pub vault_index: u8,
+    /// How long a proposal may await approval before its transfer expires
+    pub approval_timeout: Duration,
+}
+
+impl SquadsConfig {
+    /// Vault 0 of `multisig` on the Squads v4 program
+    #[must_use]
+    pub fn new(multisig: Pubkey) -> Self {
+        Self {
+            program_id: SQUADS_V4_PROGRAM_ID
+                .parse()
+                .expect("Squads program ID is a valid pubkey"),
+            multisig,
+            vault_index: 0,
+            approval_timeout: DEFAULT_APPROVAL_TIMEOUT,
+        }
+    }
+
+    /// Send funds from vault `vault_index` instead of vault 0
+    #[must_use]
+    pub fn with_vault_index(mut self, vault_index: u8) -> Self {
+        self.vault_index = vault_index;
+        self
+    }
+
+    /// Expire transfers whose proposal is still undecided after `timeout`
+    #[must_use]
+    pub fn with_approval_timeout(mut self, timeout: Duration) -> Self {
+        self.approval_timeout = timeout;
+        self
+    }
+
+    /// Use a Squads deployment other than the public v4 program
+    #[must_use]
+    pub fn with_program_id(mut self, program_id: Pubkey) -> Self {
+        self.program_id = program_id;
+        self
+    }
+
+    /// Vault PDA that holds the funds and authorizes the transfers
+    #[must_use]
+    pub fn vault(&self) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                self.multisig.as_ref(),
+                SEED_VAULT,
+                &[self.vault_index],
+            ],
+            &self.program_id,
+        )
+        .0
+    }
+
+    /// PDA of the vault transaction at `transaction_index`
+    #[must_use]
+    pub fn transaction(&self, transaction_index: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                self.multisig.as_ref(),
+                SEED_TRANSACTION,
+                &transaction_index.to_le_bytes(),
+            ],
+            &self.program_id,
+        )
+        .0
+    }
+
+    /// PDA of the proposal for the transaction at `transaction_index`
+    #[must_use]
+    pub fn proposal(&self, transaction_index: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                SEED_PREFIX,
+                self.multisig.as_ref(),
+                SEED_TRANSACTION,
+                &transaction_index.to_le_bytes(),
+                SEED_PROPOSAL,
+            ],
+            &self.program_id,
+        )
+        .0
+    }
+
+    /// `vault_transaction_create` storing `transaction_message` (see
+    /// [`transaction_message_bytes`]) at `transaction_index`, created and
+    /// paid for by `creator`
+    #[must_use]
+    pub fn vault_transaction_create(
+        &self,
+        creator: &Pubkey,
+        transaction_index: u64,
+        transaction_message: &[u8],
+    ) -> Instruction {
+        // VaultTransactionCreateArgs { vault_index, ephemeral_signers, transaction_message, memo }
+        let mut data = Vec::with_capacity(8 + 2 + 4 + transaction_message.len() + 1);
+        data.extend_from_slice(&VAULT_TRANSACTION_CREATE_DISCRIMINATOR);
+        data.push(self.vault_index);
+        data.push(0);
+        data.extend_from_slice(&(transaction_message.len() as u32).to_le_bytes());
+        data.extend_from_slice(transaction_message);
+        data.push(0);
+
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new(self.multisig, false),
+                AccountMeta::new(self.transaction(transaction_index), false),
+                AccountMeta::new_readonly(*creator, true),
+                AccountMeta::new(*creator, true),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+            data,
+        }
+    }
+
+    /// `proposal_create` opening the transaction at `transaction_index` for
+    /// voting, created and paid for by `creator`
+    #[must_use]
+    pub fn proposal_create(&self, creator: &Pubkey, transaction_index: u64) -> Instruction {
+        // ProposalCreateArgs { transaction_index, draft }
+        let mut data = Vec::with_capacity(8 + 8 + 1);
+        data.extend_from_slice(&PROPOSAL_CREATE_DISCRIMINATOR);
+        data.extend_from_slice(&transaction_index.to_le_bytes());
+        data.push(0);
+
+        Instruction {
+            program_id: self.program_id,
+            accounts: vec![
+                AccountMeta::new_readonly(self.multisig, false),
+                AccountMeta::new(self.proposal(transaction_index), false),
+                AccountMeta::new_readonly(*creator, true),
+                AccountMeta::new(*creator, true),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ],
+            data,
+        }
+    }
+}
+
+/// Serialize `instructions` as the Squads `TransactionMessage` executed by
+/// `vault`: account keys ordered as in a legacy message, with `u8` length
+/// prefixes everywhere except instruction data, which takes a `u16`.
+/// Address lookup tables are not used.
+pub fn transaction_message_bytes(
+    vault: &Pubkey,
+    instructions: &[Instruction],
+) -> Result<Vec<u8>, AppError> {
+    let too_large = |what: &str| {
+        AppError::Blockchain(BlockchainError::TransactionFailed(format!(
+            "Too many {} for a multisig vault transaction",
+            what
+        )))
+    };
+
+    let message = Message::new(instructions, Some(vault));
+    let header = message.header;
+    let num_keys = message.account_keys.len();
+    let num_writable_signers = header.num_required_signatures - header.num_readonly_signed_accounts;
+    let num_writable_non_signers = num_keys
+        - usize::from(header.num_required_signatures)
+        - usize::from(header.num_readonly_unsigned_accounts);
+
+    let mut bytes = vec![
+        header.num_required_signatures,
+        num_writable_signers,
+        u8::try_from(num_writable_non_signers).map_err(|_| too_large("accounts"))?,
+        u8::try_from(num_keys).map_err(|_| too_large("accounts"))?,
+    ];
+    for key in &message.account_keys {
+        bytes.extend_from_slice(key.as_ref());
+    }
+
+    bytes.push(u8::try_from(message.instructions.len()).map_err(|_| too_large("instructions"))?);
+    for instruction in &message.instructions {
+        bytes.push(instruction.program_id_index);
+        bytes.push(
+            u8::try_from(instruction.accounts.len())
+                .map_err(|_| too_large("instruction accounts"))?,
+        );
+        bytes.extend_from_slice(&instruction.accounts);
+        let data_len =
+            u16::try_from(instruction.data.len()).map_err(|_| too_large("instruction data"))?;
+        bytes.extend_from_slice(&data_len.to_le_bytes());
+        bytes.extend_from_slice(&instruction.data);
+    }
+
+    // No address table lookups
+    bytes.push(0);
+    Ok(bytes)
+}
+
+/// Index of the last transaction created on a multisig, from its account data
+pub fn parse_transaction_index(data: &[u8]) -> Result<u64, AppError> {
+    if data.get(..8) != Some(&MULTISIG_ACCOUNT_DISCRIMINATOR[..]) {
+        return Err(invalid_account("Account is not a Squads multisig"));
+    }
+    data.get(MULTISIG_TRANSACTION_INDEX_OFFSET..MULTISIG_TRANSACTION_INDEX_OFFSET + 8)
+        .and_then(|raw| raw.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or_else(|| invalid_account("Invalid Squads multisig account data"))
+}
+
+/// Status of a proposal, from its account data
+pub fn parse_proposal_status(data: &[u8]) -> Result<ProposalStatus, AppError> {
+    if data.get(..8) != Some(&PROPOSAL_ACCOUNT_DISCRIMINATOR[..]) {
+        return Err(invalid_account("Account is not a Squads proposal"));
+    }
+    let status = match data.get(PROPOSAL_STATUS_OFFSET) {
+        Some(0) => ProposalStatus::Draft,
+        Some(1) => ProposalStatus::Active,
+        Some(2) => ProposalStatus::Rejected,
+        Some(3) => ProposalStatus::Approved,
+        Some(4) => ProposalStatus::Executing,
+        Some(5) => ProposalStatus::Executed,
+        Some(6) => ProposalStatus::Cancelled,
+        _ => return Err(invalid_account("Invalid Squads proposal account data")),
+    };
+    Ok(status)
+}
+
+fn invalid_account(message: &str) -> AppError {
+    AppError::Blockchain(BlockchainError::RpcError(message.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use sha2::{Digest, Sha256};
+    use solana_system_interface::instruction as system_instruction;
+
+    use super::*;
+
+    fn anchor_discriminator(preimage: &str) -> [u8; 8] {
+        Sha256::digest(preimage.as_bytes())[..8].try_into().unwrap()
+    }
+
+    fn config() -> SquadsConfig {
+        SquadsConfig::new(Pubkey::new_from_array([7; 32]))
+    }
+
+    #[test]
+    fn test_discriminators_match_anchor() {
+        assert_eq!(
+            VAULT_TRANSACTION_CREATE_DISCRIMINATOR,
+            anchor_discriminator("global:vault_transaction_create")
+        );
+        assert_eq!(
+            PROPOSAL_CREATE_DISCRIMINATOR,
+            anchor_discriminator("global:proposal_create")
+        );
+        assert_eq!(
+            MULTISIG_ACCOUNT_DISCRIMINATOR,
+            anchor_discriminator("account:Multisig")
+        );
+        assert_eq!(
+            PROPOSAL_ACCOUNT_DISCRIMINATOR,
+            anchor_discriminator("account:Proposal")
+        );
+    }
+
+    #[test]
+    fn test_pdas_follow_squads_seeds() {
+        let config = config();
+        let program_id = config.program_id;
+        let multisig = config.multisig;
+
+        let (vault, _) = Pubkey::find_program_address(
+            &[b"multisig", multisig.as_ref(), b"vault", &[0]],
+            &program_id,
+        );
+        assert_eq!(config.vault(), vault);
+        assert_ne!(config.clone().with_vault_index(1).vault(), vault);
+
+        let index = 5u64.to_le_bytes();
+        let (transaction, _) = Pubkey::find_program_address(
+            &[b"multisig", multisig.as_ref(), b"transaction", &index],
+            &program_id,
+        );
+        let (proposal, _) = Pubkey::find_program_address(
+            &[
+                b"multisig",
+                multisig.as_ref(),
+                b"transaction",
+                &index,
+                b"proposal",
+            ],
+            &program_id,
+        );
+        assert_eq!(config.transaction(5), transaction);
+        assert_eq!(config.proposal(5), proposal);
+        assert_ne!(config.proposal(6), proposal);
+    }
+
+    #[test]
+    fn test_vault_transaction_create_instruction() {
+        let config = config().with_vault_index(2);
+        let creator = Pubkey::new_unique();
+        let message = [1, 2, 3];
+        let ix = config.vault_transaction_create(&creator, 9, &message);
+
+        assert_eq!(ix.program_id, config.program_id);
+        assert_eq!(
+            ix.accounts,
+            vec![
+                AccountMeta::new(config.multisig, false),
+                AccountMeta::new(config.transaction(9), false),
+                AccountMeta::new_readonly(creator, true),
+                AccountMeta::new(creator, true),
+                AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            ]
+        );
+        let mut expected = VAULT_TRANSACTION_CREATE_DISCRIMINATOR.to_vec();
+        // vault index, no ephemeral signers, the message, no memo
+        expected.extend_from_slice(&[2, 0, 3, 0, 0, 0, 1, 2, 3, 0]);
+        assert_eq!(ix.data, expected);
+    }
+
+    #[test]
+    fn test_proposal_create_instruction() {
+        let config = config();
+        let creator = Pubkey::new_unique();
+        let ix = config.proposal_create(&creator, 9);
+
+        assert_eq!(ix.program_id, config.program_id);
+        assert_eq!(
+            ix.accounts[0],
+            AccountMeta::new_readonly(config.multisig, false)
+        );
+        assert_eq!(ix.accounts[1], AccountMeta::new(config.proposal(9), false));
+        assert!(ix.accounts[2].is_signer && ix.accounts[3].is_signer);
+        let mut expected = PROPOSAL_CREATE_DISCRIMINATOR.to_vec();
+        expected.extend_from_slice(&9u64.to_le_bytes());
+        // Opened for voting right away, not as a draft
+        expected.push(0);
+        assert_eq!(ix.data, expected);
+    }
+
+    #[test]
+    fn test_transaction_message_of_vault_transfer() {
+        let vault = config().vault();
+        let recipient = Pubkey::new_unique();
+        let transfer = system_instruction::transfer(&vault, &recipient, 1_000);
+        let bytes = transaction_message_bytes(&vault, &[transfer]).unwrap();
+
+        // Vault signs and is written, the recipient is written, the system program is read
+        assert_eq!(&bytes[..4], &[1, 1, 1, 3]);
+        assert_eq!(&bytes[4..36], vault.as_ref());
+        assert_eq!(&bytes[36..68], recipient.as_ref());
+        assert_eq!(
+            &bytes[68..100],
+            solana_system_interface::program::ID.as_ref()
+        );
+
+        // One instruction: program 2, accounts [vault, recipient], 12 bytes of data
+        assert_eq!(&bytes[100..106], &[1, 2, 2, 0, 1, 12]);
+        assert_eq!(bytes[106], 0);
+        let data_end = 107 + 12;
+        assert_eq!(&bytes[107..111], &2u32.to_le_bytes());
+        assert_eq!(&bytes[111..data_end], &1_000u64.to_le_bytes());
+        // No address table lookups
+        assert_eq!(&bytes[data_end..], &[0]);
+    }
+
+    #[test]
+    fn test_parse_transaction_index() {
+        let mut data = MULTISIG_ACCOUNT_DISCRIMINATOR.to_vec();
+        data.resize(MULTISIG_TRANSACTION_INDEX_OFFSET, 0);
+        data.extend_from_slice(&41u64.to_le_bytes());
+        data.extend_from_slice(&[0; 16]);
+        assert_eq!(parse_transaction_index(&data).unwrap(), 41);
+
+        assert!(parse_transaction_index(&data[..MULTISIG_TRANSACTION_INDEX_OFFSET + 4]).is_err());
+        data[0] ^= 1;
+        assert!(parse_transaction_index(&data).is_err());
+    }
+
+    #[test]
+    fn test_parse_proposal_status() {
+        let mut data = PROPOSAL_ACCOUNT_DISCRIMINATOR.to_vec();
+        data.resize(PROPOSAL_STATUS_OFFSET, 0);
+        data.push(5);
+        data.extend_from_slice(&[0; 8]);
+        assert_eq!(
+            parse_proposal_status(&data).unwrap(),
+            ProposalStatus::Executed
+        );
+
+        data[PROPOSAL_STATUS_OFFSET] = 2;
+        assert_eq!(
+            parse_proposal_status(&data).unwrap(),
+            ProposalStatus::Rejected
+        );
+
+        data[PROPOSAL_STATUS_OFFSET] = 7;
+        assert!(parse_proposal_status(&data).is_err());
+    }
+}
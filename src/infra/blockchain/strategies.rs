@@ -8,7 +8,7 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
-use crate::domain::AppError;
+use crate::domain::{AppError, BundleStatus, SubmissionOutcome};
 
 // ============================================================================
 // PROVIDER TYPE DETECTION
@@ -103,15 +103,45 @@ pub trait SubmissionStrategy: Send + Sync {
     ///
     /// # Arguments
     /// * `serialized_tx` - Base58-encoded serialized transaction
-    /// * `skip_preflight` - Whether to skip preflight simulation
+    /// * `skip_preflight` - Whether to skip preflight simulation. Strategies
+    ///   that send Jito bundles simulate the transaction themselves when `false`,
+    ///   as `sendBundle` has no preflight
+    /// * `jito_region` - Jito region to send the bundle to instead of the
+    ///   configured one. Ignored by strategies that don't send bundles
     ///
     /// # Returns
-    /// Transaction signature on success
+    /// Transaction signature on success, with the bundle ID when the
+    /// transaction was sent as a Jito bundle
     async fn submit_transaction(
         &self,
         serialized_tx: &str,
         skip_preflight: bool,
-    ) -> Result<String, AppError>;
+        jito_region: Option<&str>,
+    ) -> Result<SubmissionOutcome, AppError>;
+
+    /// Submit several serialized transactions as one bundle, to `jito_region`
+    /// instead of the configured region when given
+    ///
+    /// Bundled transactions execute in order and land together or not at all.
+    /// Strategies without bundle support submit them one at a time, in order.
+    ///
+    /// # Returns
+    /// Transaction signatures, in submission order
+    async fn submit_bundle(
+        &self,
+        serialized_txs: &[String],
+        jito_region: Option<&str>,
+    ) -> Result<Vec<String>, AppError> {
+        let mut signatures = Vec::with_capacity(serialized_txs.len());
+        for serialized_tx in serialized_txs {
+            signatures.push(
+                self.submit_transaction(serialized_tx, true, jito_region)
+                    .await?
+                    .signature,
+            );
+        }
+        Ok(signatures)
+    }
 
     /// Human-readable strategy name for logging
     fn name(&self) -> &'static str;
@@ -123,6 +153,15 @@ pub trait SubmissionStrategy: Send + Sync {
     fn supports_private_submission(&self) -> bool {
         false // Default: standard submission
     }
+
+    /// Status of the bundle that carried `tx_signature`.
+    ///
+    /// Returns `Ok(None)` for strategies that don't submit bundles, or when
+    /// the bundle ID for the transaction is not tracked.
+    async fn bundle_status(&self, tx_signature: &str) -> Result<Option<BundleStatus>, AppError> {
+        let _ = tx_signature;
+        Ok(None)
+    }
 }
 
 // ============================================================================
@@ -0,0 +1,270 @@
+//! Live Jito tip account set, refreshed from `getTipAccounts`.
+//!
+//! Until the first successful refresh, tips go to the hardcoded
+//! [`JITO_TIP_ACCOUNTS`]. A failed refresh keeps the previous set, so a
+//! flaky endpoint never leaves the relayer without tip accounts.
+
+use std::sync::RwLock;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use crate::domain::{AppError, BlockchainError};
+
+use super::JITO_TIP_ACCOUNTS;
+
+/// Default interval between tip account refreshes
+pub const DEFAULT_TIP_ACCOUNT_REFRESH_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Tip accounts used by [`super::random_jito_tip_account_dynamic`]
+pub(crate) static LIVE_TIP_ACCOUNTS: TipAccountSet = TipAccountSet::new();
+
+/// Set of Jito tip accounts to choose from
+#[derive(Debug, Default)]
+pub struct TipAccountSet {
+    /// Accounts from the last successful refresh (empty = use the hardcoded list)
+    accounts: RwLock<Vec<Pubkey>>,
+}
+
+impl TipAccountSet {
+    /// Create an empty set, which selects from the hardcoded accounts
+    pub const fn new() -> Self {
+        Self {
+            accounts: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Replace the live accounts
+    pub fn replace(&self, accounts: Vec<Pubkey>) {
+        *self.accounts.write().expect("tip account lock poisoned") = accounts;
+    }
+
+    /// Current accounts, or the hardcoded list if none were fetched yet
+    pub fn accounts(&self) -> Vec<Pubkey> {
+        let accounts = self.accounts.read().expect("tip account lock poisoned");
+        if accounts.is_empty() {
+            hardcoded_tip_accounts()
+        } else {
+            accounts.clone()
+        }
+    }
+
+    /// Whether the set holds fetched accounts rather than the hardcoded fallback
+    pub fn is_live(&self) -> bool {
+        !self
+            .accounts
+            .read()
+            .expect("tip account lock poisoned")
+            .is_empty()
+    }
+
+    /// Select a random account to reduce contention
+    pub fn random(&self) -> Pubkey {
+        let accounts = self.accounts.read().expect("tip account lock poisoned");
+        if accounts.is_empty() {
+            return super::random_jito_tip_account()
+                .parse()
+                .expect("Hardcoded Jito tip account should be valid");
+        }
+        accounts[rand::random_range(0..accounts.len())]
+    }
+}
+
+fn hardcoded_tip_accounts() -> Vec<Pubkey> {
+    JITO_TIP_ACCOUNTS
+        .iter()
+        .map(|account| {
+            account
+                .parse()
+                .expect("Hardcoded Jito tip account should be valid")
+        })
+        .collect()
+}
+
+#[derive(Debug, Serialize)]
+struct TipAccountsRequest {
+    jsonrpc: &'static str,
+    id: u64,
+    method: &'static str,
+    params: Vec<()>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TipAccountsResponse {
+    result: Option<Vec<String>>,
+    error: Option<serde_json::Value>,
+}
+
+/// Fetch the current tip accounts with `getTipAccounts`.
+///
+/// # API Reference
+/// <https://www.quicknode.com/docs/solana/getTipAccounts>
+pub async fn fetch_tip_accounts(
+    http_client: &reqwest::Client,
+    rpc_url: &str,
+) -> Result<Vec<Pubkey>, AppError> {
+    let request = TipAccountsRequest {
+        jsonrpc: "2.0",
+        id: 1,
+        method: "getTipAccounts",
+        params: Vec::new(),
+    };
+
+    let response = http_client
+        .post(rpc_url)
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| {
+            AppError::Blockchain(BlockchainError::RpcError(format!(
+                "getTipAccounts failed: {}",
+                e
+            )))
+        })?;
+
+    let body: TipAccountsResponse = response.json().await.map_err(|e| {
+        AppError::Blockchain(BlockchainError::RpcError(format!(
+            "Failed to parse getTipAccounts response: {}",
+            e
+        )))
+    })?;
+
+    parse_tip_accounts(body)
+}
+
+fn parse_tip_accounts(response: TipAccountsResponse) -> Result<Vec<Pubkey>, AppError> {
+    if let Some(error) = response.error {
+        return Err(AppError::Blockchain(BlockchainError::RpcError(format!(
+            "getTipAccounts error: {}",
+            error
+        ))));
+    }
+
+    let accounts = response
+        .result
+        .unwrap_or_default()
+        .iter()
+        .map(|account| {
+            account.parse::<Pubkey>().map_err(|e| {
+                AppError::Blockchain(BlockchainError::RpcError(format!(
+                    "Invalid tip account {}: {}",
+                    account, e
+                )))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Never swap in an empty set; keep tipping the previous accounts instead
+    if accounts.is_empty() {
+        return Err(AppError::Blockchain(BlockchainError::RpcError(
+            "getTipAccounts returned no accounts".to_string(),
+        )));
+    }
+    Ok(accounts)
+}
+
+/// Fetch the tip accounts and replace the contents of `set`.
+/// Returns the number of accounts now in the set.
+pub async fn refresh_tip_accounts(
+    set: &TipAccountSet,
+    http_client: &reqwest::Client,
+    rpc_url: &str,
+) -> Result<usize, AppError> {
+    let accounts = fetch_tip_accounts(http_client, rpc_url).await?;
+    let count = accounts.len();
+    set.replace(accounts);
+    Ok(count)
+}
+
+/// Spawn a task refreshing the live tip accounts now and every `interval`.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_tip_account_refresh(
+    rpc_url: String,
+    interval: Duration,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+    let handle = tokio::spawn(async move {
+        let http_client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .expect("Failed to create HTTP client");
+
+        loop {
+            match refresh_tip_accounts(&LIVE_TIP_ACCOUNTS, &http_client, &rpc_url).await {
+                Ok(count) => info!(count, "Refreshed Jito tip accounts"),
+                Err(e) => warn!(
+                    error = %e,
+                    live = LIVE_TIP_ACCOUNTS.is_live(),
+                    "Failed to refresh Jito tip accounts, keeping current set"
+                ),
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                result = shutdown_rx.changed() => {
+                    if result.is_ok() && *shutdown_rx.borrow() {
+                        debug!("Jito tip account refresh shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    (handle, shutdown_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_set_uses_hardcoded_accounts() {
+        let set = TipAccountSet::new();
+        assert!(!set.is_live());
+        assert_eq!(set.accounts().len(), JITO_TIP_ACCOUNTS.len());
+
+        let hardcoded = hardcoded_tip_accounts();
+        for _ in 0..20 {
+            assert!(hardcoded.contains(&set.random()));
+        }
+    }
+
+    #[test]
+    fn test_replace_changes_selection() {
+        let set = TipAccountSet::new();
+        let live = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+        set.replace(live.clone());
+
+        assert!(set.is_live());
+        assert_eq!(set.accounts(), live);
+        for _ in 0..20 {
+            assert!(live.contains(&set.random()));
+        }
+    }
+
+    #[test]
+    fn test_parse_tip_accounts() {
+        let account = Pubkey::new_unique();
+        let parsed = parse_tip_accounts(TipAccountsResponse {
+            result: Some(vec![account.to_string()]),
+            error: None,
+        })
+        .unwrap();
+        assert_eq!(parsed, vec![account]);
+
+        let empty = TipAccountsResponse {
+            result: Some(Vec::new()),
+            error: None,
+        };
+        assert!(parse_tip_accounts(empty).is_err());
+
+        let invalid = TipAccountsResponse {
+            result: Some(vec!["not-a-pubkey".to_string()]),
+            error: None,
+        };
+        assert!(parse_tip_accounts(invalid).is_err());
+    }
+}
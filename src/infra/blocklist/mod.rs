@@ -3,28 +3,115 @@
 //! Provides a high-performance, thread-safe "hot cache" for blocking
 //! malicious addresses before querying external compliance providers.
 //! The blocklist is persisted to the database for durability across restarts.
+//!
+//! Entries may carry an optional expiry. Expired entries stop blocking
+//! immediately and are purged lazily on lookup and by a periodic sweep.
+//!
+//! Every match found by [`BlocklistManager::check_address`] is counted in
+//! memory together with the time of the last hit. Counters are written back
+//! to the database in batches by a periodic flush rather than on each hit.
+//!
+//! Addresses are canonicalized on every write and lookup (see
+//! [`normalize_solana_address`]), so a pasted address with stray whitespace
+//! still resolves to the same entry. Writes reject anything that is not a
+//! valid public key.
 
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
 use dashmap::DashMap;
 use sqlx::PgPool;
-use tracing::{info, warn};
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+use crate::domain::{
+    AppError, BlocklistCategory, DatabaseError, ValidationError, normalize_solana_address,
+};
+
+pub mod ofac;
 
-use crate::domain::{AppError, DatabaseError};
+pub use ofac::{
+    OFAC_REASON_PREFIX, OfacSyncConfig, OfacSyncService, OfacSyncSummary, spawn_ofac_sync,
+};
 
-/// Blocklist entry with address and reason
+/// Default interval between expired-entry sweeps
+pub const DEFAULT_EXPIRY_SWEEP_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Default interval between hit counter flushes
+pub const DEFAULT_HIT_FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Blocklist entry with address, reason, classification and optional expiry
 #[derive(Debug, Clone)]
 pub struct BlocklistEntry {
     pub address: String,
     pub reason: String,
+    /// Why the address was blocked
+    pub category: BlocklistCategory,
+    /// Severity from 0 (informational) to 10 (sanctioned)
+    pub severity: u8,
+    /// When the entry stops blocking (`None` = blocked indefinitely)
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl BlocklistEntry {
+    /// Whether the entry has passed its expiry time
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now())
+    }
+}
+
+/// How often a blocklist entry has matched a screened address
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlocklistHits {
+    /// Total matches, including ones not yet flushed to the database
+    pub hit_count: u64,
+    /// When the entry last matched (`None` = never)
+    pub last_hit_at: Option<DateTime<Utc>>,
+}
+
+/// `(address, reason, category, severity, expires_at, hit_count, last_hit_at)`
+type BlocklistRow = (
+    String,
+    String,
+    String,
+    i16,
+    Option<DateTime<Utc>>,
+    i64,
+    Option<DateTime<Utc>>,
+);
+
+/// In-memory hit counter with the part not yet written to the database
+#[derive(Debug, Clone, Copy, Default)]
+struct HitCounter {
+    hits: BlocklistHits,
+    unflushed: u64,
+}
+
+/// Outcome of a bulk blocklist upsert
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BatchUpsertSummary {
+    /// Addresses that were not previously in the blocklist
+    pub inserted: usize,
+    /// Addresses whose existing entry was overwritten
+    pub updated: usize,
+    /// Entries dropped because the same address appeared later in the batch
+    pub duplicates: usize,
 }
 
 /// Thread-safe internal blocklist manager using DashMap for high-concurrency access.
 ///
-/// Maps wallet addresses (String) to rejection reasons (String).
+/// Maps wallet addresses (String) to blocklist entries.
 /// All changes are persisted to the database for durability.
 #[derive(Debug)]
 pub struct BlocklistManager {
     /// In-memory cache for O(1) lookups
-    store: DashMap<String, String>,
+    store: DashMap<String, BlocklistEntry>,
+    /// Per-address hit counters, flushed to the database by [`Self::flush_hits`]
+    hits: DashMap<String, HitCounter>,
     /// Database pool for persistence
     pool: PgPool,
 }
@@ -40,6 +127,7 @@ impl BlocklistManager {
     pub async fn new(pool: PgPool) -> Result<Self, AppError> {
         let manager = Self {
             store: DashMap::new(),
+            hits: DashMap::new(),
             pool,
         };
 
@@ -54,17 +142,47 @@ impl BlocklistManager {
         Ok(manager)
     }
 
-    /// Load all blocklist entries from the database into memory.
+    /// Load all unexpired blocklist entries and their hit counters from the database into memory.
     async fn load_from_database(&self) -> Result<(), AppError> {
-        let rows = sqlx::query_as::<_, (String, String)>(
-            "SELECT address, reason FROM blocklist ORDER BY created_at",
+        let rows = sqlx::query_as::<_, BlocklistRow>(
+            r#"
+            SELECT address, reason, category, severity, expires_at, hit_count, last_hit_at
+            FROM blocklist
+            WHERE expires_at IS NULL OR expires_at > NOW()
+            ORDER BY created_at
+            "#,
         )
         .fetch_all(&self.pool)
         .await
         .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
 
-        for (address, reason) in rows {
-            self.store.insert(address, reason);
+        for (address, reason, category, severity, expires_at, hit_count, last_hit_at) in rows {
+            let category = category.parse().unwrap_or_else(|e| {
+                warn!(address = %address, error = %e, "Unknown blocklist category, treating as manual");
+                BlocklistCategory::Manual
+            });
+            if hit_count > 0 || last_hit_at.is_some() {
+                self.hits.insert(
+                    address.clone(),
+                    HitCounter {
+                        hits: BlocklistHits {
+                            hit_count: u64::try_from(hit_count).unwrap_or(0),
+                            last_hit_at,
+                        },
+                        unflushed: 0,
+                    },
+                );
+            }
+            self.store.insert(
+                address.clone(),
+                BlocklistEntry {
+                    address,
+                    reason,
+                    category,
+                    severity: u8::try_from(severity).unwrap_or(0),
+                    expires_at,
+                },
+            );
         }
 
         Ok(())
@@ -72,49 +190,308 @@ impl BlocklistManager {
 
     /// Check if an address is in the blocklist.
     /// Returns `Some(reason)` if blocked, `None` if not blocked.
+    ///
+    /// A match counts as a hit on the entry. Expired entries are treated
+    /// as not blocked and evicted from both the cache and the database.
     #[must_use]
     pub fn check_address(&self, address: &str) -> Option<String> {
-        self.store.get(address).map(|entry| entry.value().clone())
+        self.check_address_detailed(address)
+            .map(|entry| entry.reason)
+    }
+
+    /// Check if an address is in the blocklist, returning the full entry.
+    /// Hits and expiry are handled the same way as in [`Self::check_address`].
+    #[must_use]
+    pub fn check_address_detailed(&self, address: &str) -> Option<BlocklistEntry> {
+        let key = lookup_key(address);
+        let entry = self.peek_key(&key)?;
+        self.record_hit(&key);
+        Some(entry)
+    }
+
+    /// Look up an address without counting a hit.
+    /// Used for bookkeeping lookups (admin checks, list syncs) that do not screen a transfer.
+    #[must_use]
+    pub fn peek_address(&self, address: &str) -> Option<BlocklistEntry> {
+        self.peek_key(&lookup_key(address))
+    }
+
+    /// Look up an already normalized key, evicting it if expired.
+    fn peek_key(&self, key: &str) -> Option<BlocklistEntry> {
+        let entry = self.store.get(key)?;
+        if !entry.is_expired() {
+            return Some(entry.value().clone());
+        }
+        // Release the shard read lock before evicting
+        drop(entry);
+        self.evict_expired(key);
+        None
+    }
+
+    /// Count a match on `address`.
+    fn record_hit(&self, address: &str) {
+        let mut counter = self.hits.entry(address.to_string()).or_default();
+        counter.hits.hit_count += 1;
+        counter.hits.last_hit_at = Some(Utc::now());
+        counter.unflushed += 1;
+    }
+
+    /// Hit statistics for an address (zero if it never matched).
+    #[must_use]
+    pub fn hits(&self, address: &str) -> BlocklistHits {
+        self.hits
+            .get(&lookup_key(address))
+            .map(|counter| counter.hits)
+            .unwrap_or_default()
+    }
+
+    /// Write hits counted since the last flush to the database in one statement.
+    /// Returns the number of entries updated.
+    ///
+    /// If the write fails the counts are kept in memory for the next flush.
+    pub async fn flush_hits(&self) -> Result<usize, AppError> {
+        let mut addresses = Vec::new();
+        let mut deltas = Vec::new();
+        let mut last_hits = Vec::new();
+        for mut counter in self.hits.iter_mut() {
+            if counter.unflushed == 0 {
+                continue;
+            }
+            addresses.push(counter.key().clone());
+            deltas.push(i64::try_from(counter.unflushed).unwrap_or(i64::MAX));
+            last_hits.push(counter.hits.last_hit_at.unwrap_or_else(Utc::now));
+            counter.unflushed = 0;
+        }
+
+        if addresses.is_empty() {
+            return Ok(0);
+        }
+
+        let result = sqlx::query(
+            r#"
+            UPDATE blocklist AS b SET
+                hit_count = b.hit_count + h.delta,
+                last_hit_at = GREATEST(b.last_hit_at, h.last_hit_at)
+            FROM UNNEST($1::text[], $2::bigint[], $3::timestamptz[]) AS h(address, delta, last_hit_at)
+            WHERE b.address = h.address
+            "#,
+        )
+        .bind(&addresses)
+        .bind(&deltas)
+        .bind(&last_hits)
+        .execute(&self.pool)
+        .await;
+
+        if let Err(e) = result {
+            // Put the counts back so the next flush retries them
+            for (address, delta) in addresses.iter().zip(&deltas) {
+                if let Some(mut counter) = self.hits.get_mut(address) {
+                    counter.unflushed += u64::try_from(*delta).unwrap_or(0);
+                }
+            }
+            return Err(AppError::Database(DatabaseError::Query(e.to_string())));
+        }
+
+        debug!(entries = addresses.len(), "Flushed blocklist hit counters");
+        Ok(addresses.len())
+    }
+
+    /// Remove an expired entry from the cache and schedule its database deletion.
+    fn evict_expired(&self, address: &str) {
+        if self
+            .store
+            .remove_if(address, |_, entry| entry.is_expired())
+            .is_none()
+        {
+            return;
+        }
+        self.hits.remove(address);
+
+        info!(address = %address, "Blocklist entry expired");
+
+        // check_address is synchronous, so the row is deleted in the background.
+        // The periodic sweep picks it up if no runtime is available.
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+        let pool = self.pool.clone();
+        let address = address.to_string();
+        handle.spawn(async move {
+            if let Err(e) =
+                sqlx::query("DELETE FROM blocklist WHERE address = $1 AND expires_at <= NOW()")
+                    .bind(&address)
+                    .execute(&pool)
+                    .await
+            {
+                warn!(address = %address, error = %e, "Failed to delete expired blocklist entry");
+            }
+        });
     }
 
-    /// Add or update an address in the blocklist.
+    /// Add or update an address in the blocklist without expiry.
     /// The change is persisted to the database.
-    pub async fn add_address(&self, address: String, reason: String) -> Result<(), AppError> {
-        let is_update = self.store.contains_key(&address);
+    ///
+    /// Returns a validation error if `address` is not a valid public key.
+    pub async fn add_address(
+        &self,
+        address: String,
+        reason: String,
+        category: BlocklistCategory,
+        severity: u8,
+    ) -> Result<(), AppError> {
+        self.upsert(BlocklistEntry {
+            address,
+            reason,
+            category,
+            severity,
+            expires_at: None,
+        })
+        .await
+    }
+
+    /// Add or update an address in the blocklist that stops blocking after `ttl`.
+    /// The change is persisted to the database.
+    pub async fn add_address_with_ttl(
+        &self,
+        address: String,
+        reason: String,
+        category: BlocklistCategory,
+        severity: u8,
+        ttl: Duration,
+    ) -> Result<(), AppError> {
+        let ttl = chrono::Duration::from_std(ttl)
+            .map_err(|e| AppError::Internal(format!("Invalid blocklist TTL: {e}")))?;
+        self.upsert(BlocklistEntry {
+            address,
+            reason,
+            category,
+            severity,
+            expires_at: Some(Utc::now() + ttl),
+        })
+        .await
+    }
+
+    /// Add or update many addresses in a single database transaction.
+    ///
+    /// When an address appears more than once (after normalization), the
+    /// last occurrence wins. An invalid address rejects the whole batch.
+    /// If any write fails the transaction is rolled back and the cache is
+    /// left untouched.
+    pub async fn add_addresses_batch(
+        &self,
+        entries: Vec<BlocklistEntry>,
+    ) -> Result<BatchUpsertSummary, AppError> {
+        let (entries, duplicates) = normalize_batch(entries)?;
+        let mut summary = BatchUpsertSummary {
+            duplicates,
+            ..Default::default()
+        };
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        for entry in &entries {
+            // xmax = 0 only for freshly inserted rows
+            let inserted = sqlx::query_scalar::<_, bool>(
+                r#"
+                INSERT INTO blocklist (address, reason, category, severity, expires_at, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, NOW(), NOW())
+                ON CONFLICT (address) DO UPDATE SET
+                    reason = EXCLUDED.reason,
+                    category = EXCLUDED.category,
+                    severity = EXCLUDED.severity,
+                    expires_at = EXCLUDED.expires_at,
+                    updated_at = NOW()
+                RETURNING (xmax = 0)
+                "#,
+            )
+            .bind(&entry.address)
+            .bind(&entry.reason)
+            .bind(entry.category.as_str())
+            .bind(i16::from(entry.severity))
+            .bind(entry.expires_at)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+            if inserted {
+                summary.inserted += 1;
+            } else {
+                summary.updated += 1;
+            }
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        for entry in entries {
+            self.store.insert(entry.address.clone(), entry);
+        }
+
+        warn!(
+            inserted = summary.inserted,
+            updated = summary.updated,
+            duplicates = summary.duplicates,
+            "Bulk blocklist import committed"
+        );
+
+        Ok(summary)
+    }
+
+    /// Persist an entry to the database and update the cache.
+    async fn upsert(&self, mut entry: BlocklistEntry) -> Result<(), AppError> {
+        entry.address = canonical_address(&entry.address)?;
+        let is_update = self.store.contains_key(&entry.address);
 
         // Persist to database first (upsert)
         sqlx::query(
             r#"
-            INSERT INTO blocklist (address, reason, created_at, updated_at)
-            VALUES ($1, $2, NOW(), NOW())
+            INSERT INTO blocklist (address, reason, category, severity, expires_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, NOW(), NOW())
             ON CONFLICT (address) DO UPDATE SET
                 reason = EXCLUDED.reason,
+                category = EXCLUDED.category,
+                severity = EXCLUDED.severity,
+                expires_at = EXCLUDED.expires_at,
                 updated_at = NOW()
             "#,
         )
-        .bind(&address)
-        .bind(&reason)
+        .bind(&entry.address)
+        .bind(&entry.reason)
+        .bind(entry.category.as_str())
+        .bind(i16::from(entry.severity))
+        .bind(entry.expires_at)
         .execute(&self.pool)
         .await
         .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
 
-        // Update in-memory cache
-        self.store.insert(address.clone(), reason.clone());
-
         if is_update {
             info!(
-                address = %address,
-                reason = %reason,
+                address = %entry.address,
+                reason = %entry.reason,
+                category = %entry.category,
+                severity = entry.severity,
+                expires_at = ?entry.expires_at,
                 "Blocklist entry updated"
             );
         } else {
             warn!(
-                address = %address,
-                reason = %reason,
+                address = %entry.address,
+                reason = %entry.reason,
+                category = %entry.category,
+                severity = entry.severity,
+                expires_at = ?entry.expires_at,
                 "Address added to blocklist"
             );
         }
 
+        // Update in-memory cache
+        self.store.insert(entry.address.clone(), entry);
+
         Ok(())
     }
 
@@ -122,6 +499,9 @@ impl BlocklistManager {
     /// The change is persisted to the database.
     /// Returns `true` if the address was present and removed.
     pub async fn remove_address(&self, address: &str) -> Result<bool, AppError> {
+        let key = lookup_key(address);
+        let address = key.as_str();
+
         // Remove from database first
         let result = sqlx::query("DELETE FROM blocklist WHERE address = $1")
             .bind(address)
@@ -130,6 +510,7 @@ impl BlocklistManager {
             .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
 
         // Remove from in-memory cache
+        self.hits.remove(address);
         if let Some((addr, _reason)) = self.store.remove(address) {
             warn!(
                 address = %addr,
@@ -148,46 +529,383 @@ impl BlocklistManager {
         }
     }
 
-    /// Get the current number of blocklisted addresses.
+    /// Purge expired entries from the database and the in-memory cache.
+    /// Returns the number of database rows removed.
+    pub async fn purge_expired(&self) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            "DELETE FROM blocklist WHERE expires_at IS NOT NULL AND expires_at <= NOW()",
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        self.store.retain(|_, entry| !entry.is_expired());
+        self.hits
+            .retain(|address, _| self.store.contains_key(address));
+
+        let purged = result.rows_affected();
+        if purged > 0 {
+            info!(purged, "Purged expired blocklist entries");
+        }
+        Ok(purged)
+    }
+
+    /// Get the current number of active (unexpired) blocklisted addresses.
     #[must_use]
     pub fn len(&self) -> usize {
-        self.store.len()
+        self.store
+            .iter()
+            .filter(|entry| !entry.is_expired())
+            .count()
     }
 
-    /// Check if the blocklist is empty.
+    /// Check if the blocklist has no active entries.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.store.is_empty()
+        self.len() == 0
     }
 
-    /// List all blocklisted addresses (for admin purposes).
+    /// List all active blocklisted addresses (for admin purposes).
     #[must_use]
     pub fn list_all(&self) -> Vec<BlocklistEntry> {
         self.store
             .iter()
-            .map(|entry| BlocklistEntry {
-                address: entry.key().clone(),
-                reason: entry.value().clone(),
-            })
+            .filter(|entry| !entry.is_expired())
+            .map(|entry| entry.value().clone())
             .collect()
     }
+
+    /// Build an in-memory blocklist backed by a pool that is never connected.
+    /// Must be called from within a Tokio runtime.
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn in_memory(addresses: &[&str]) -> Self {
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .expect("lazy pool");
+        let store = addresses
+            .iter()
+            .map(|address| {
+                let address = lookup_key(address);
+                let entry = BlocklistEntry {
+                    address: address.clone(),
+                    reason: "test".to_string(),
+                    category: BlocklistCategory::Manual,
+                    severity: BlocklistCategory::Manual.default_severity(),
+                    expires_at: None,
+                };
+                (address, entry)
+            })
+            .collect();
+        Self {
+            store,
+            hits: DashMap::new(),
+            pool,
+        }
+    }
+}
+
+/// Canonical storage key for an address being written.
+fn canonical_address(address: &str) -> Result<String, AppError> {
+    normalize_solana_address(address).ok_or_else(|| {
+        AppError::Validation(ValidationError::InvalidAddress(address.trim().to_string()))
+    })
+}
+
+/// Key an address is looked up under: its canonical form, or the trimmed
+/// input when it is not a valid public key (such entries can only predate
+/// normalized writes).
+fn lookup_key(address: &str) -> String {
+    normalize_solana_address(address).unwrap_or_else(|| address.trim().to_string())
+}
+
+/// Canonicalize every address in a batch, then collapse repeats with
+/// [`dedup_batch`]. Fails on the first invalid address.
+fn normalize_batch(entries: Vec<BlocklistEntry>) -> Result<(Vec<BlocklistEntry>, usize), AppError> {
+    let entries = entries
+        .into_iter()
+        .map(|mut entry| {
+            entry.address = canonical_address(&entry.address)?;
+            Ok(entry)
+        })
+        .collect::<Result<Vec<_>, AppError>>()?;
+    Ok(dedup_batch(entries))
+}
+
+/// Collapse repeated addresses in a batch, keeping the last occurrence
+/// at the position of the first. Returns the unique entries and the
+/// number of entries dropped.
+fn dedup_batch(entries: Vec<BlocklistEntry>) -> (Vec<BlocklistEntry>, usize) {
+    let total = entries.len();
+    let mut positions: HashMap<String, usize> = HashMap::with_capacity(total);
+    let mut unique: Vec<BlocklistEntry> = Vec::with_capacity(total);
+
+    for entry in entries {
+        if let Some(&pos) = positions.get(&entry.address) {
+            unique[pos] = entry;
+        } else {
+            positions.insert(entry.address.clone(), unique.len());
+            unique.push(entry);
+        }
+    }
+
+    let duplicates = total - unique.len();
+    (unique, duplicates)
+}
+
+/// Spawn the periodic hit counter flush.
+/// Pending hits are flushed once more on shutdown.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_hit_flush(
+    manager: Arc<BlocklistManager>,
+    interval: Duration,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+    let handle = tokio::spawn(async move {
+        debug!(interval = ?interval, "Starting blocklist hit flush");
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {
+                    if let Err(e) = manager.flush_hits().await {
+                        error!(error = %e, "Blocklist hit flush failed");
+                    }
+                }
+                result = shutdown_rx.changed() => {
+                    if result.is_ok() && *shutdown_rx.borrow() {
+                        if let Err(e) = manager.flush_hits().await {
+                            error!(error = %e, "Final blocklist hit flush failed");
+                        }
+                        info!("Blocklist hit flush shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    (handle, shutdown_tx)
+}
+
+/// Spawn the periodic expired-entry sweep.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_expiry_sweep(
+    manager: Arc<BlocklistManager>,
+    interval: Duration,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+    let handle = tokio::spawn(async move {
+        debug!(interval = ?interval, "Starting blocklist expiry sweep");
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {
+                    if let Err(e) = manager.purge_expired().await {
+                        error!(error = %e, "Blocklist expiry sweep failed");
+                    }
+                }
+                result = shutdown_rx.changed() => {
+                    if result.is_ok() && *shutdown_rx.borrow() {
+                        info!("Blocklist expiry sweep shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    (handle, shutdown_tx)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use solana_sdk::pubkey::Pubkey;
+    use sqlx::postgres::PgPoolOptions;
+
     // Note: Full integration tests require a PostgreSQL database.
-    // Unit tests for the in-memory operations can be done with mocks.
+    // Unit tests for the in-memory operations use a lazy (never connected) pool.
+
+    fn lazy_manager() -> BlocklistManager {
+        let pool = PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .expect("lazy pool");
+        BlocklistManager {
+            store: DashMap::new(),
+            hits: DashMap::new(),
+            pool,
+        }
+    }
+
+    fn entry(address: &str, expires_at: Option<DateTime<Utc>>) -> BlocklistEntry {
+        BlocklistEntry {
+            address: address.to_string(),
+            reason: "test_reason".to_string(),
+            category: BlocklistCategory::Manual,
+            severity: 5,
+            expires_at,
+        }
+    }
 
     #[test]
     fn test_blocklist_entry_debug() {
-        let entry = BlocklistEntry {
-            address: "test_addr".to_string(),
-            reason: "test_reason".to_string(),
-        };
+        let entry = entry("test_addr", None);
         let debug_str = format!("{:?}", entry);
         assert!(debug_str.contains("test_addr"));
         assert!(debug_str.contains("test_reason"));
     }
+
+    #[test]
+    fn test_dedup_batch_last_occurrence_wins() {
+        let mut first = entry("dup", None);
+        first.reason = "first".to_string();
+        let mut last = entry("dup", None);
+        last.reason = "last".to_string();
+
+        let (unique, duplicates) = dedup_batch(vec![first, entry("other", None), last]);
+
+        assert_eq!(duplicates, 1);
+        assert_eq!(unique.len(), 2);
+        assert_eq!(unique[0].address, "dup");
+        assert_eq!(unique[0].reason, "last");
+        assert_eq!(unique[1].address, "other");
+    }
+
+    #[test]
+    fn test_blocklist_entry_expiry() {
+        assert!(!entry("a", None).is_expired());
+        assert!(!entry("a", Some(Utc::now() + chrono::Duration::minutes(5))).is_expired());
+        assert!(entry("a", Some(Utc::now() - chrono::Duration::seconds(1))).is_expired());
+    }
+
+    #[tokio::test]
+    async fn test_expired_entries_not_blocked() {
+        let manager = lazy_manager();
+        let past = Some(Utc::now() - chrono::Duration::seconds(1));
+        let future = Some(Utc::now() + chrono::Duration::hours(1));
+        manager
+            .store
+            .insert("expired".to_string(), entry("expired", past));
+        manager
+            .store
+            .insert("active".to_string(), entry("active", future));
+        manager
+            .store
+            .insert("permanent".to_string(), entry("permanent", None));
+
+        assert_eq!(manager.len(), 2);
+        assert_eq!(manager.list_all().len(), 2);
+        assert!(manager.list_all().iter().all(|e| e.address != "expired"));
+
+        assert!(manager.check_address("active").is_some());
+        assert!(manager.check_address("permanent").is_some());
+        assert_eq!(
+            manager
+                .check_address_detailed("permanent")
+                .map(|e| e.category),
+            Some(BlocklistCategory::Manual)
+        );
+        assert!(manager.check_address("expired").is_none());
+        // Lazily evicted from the cache on lookup
+        assert!(!manager.store.contains_key("expired"));
+    }
+
+    #[tokio::test]
+    async fn test_repeated_matches_increment_hit_counter() {
+        let manager = lazy_manager();
+        manager.store.insert("bad".to_string(), entry("bad", None));
+
+        assert_eq!(manager.hits("bad"), BlocklistHits::default());
+
+        for _ in 0..3 {
+            assert!(manager.check_address("bad").is_some());
+        }
+        assert!(manager.check_address_detailed("bad").is_some());
+
+        let hits = manager.hits("bad");
+        assert_eq!(hits.hit_count, 4);
+        assert!(hits.last_hit_at.is_some());
+        assert_eq!(manager.hits.get("bad").map(|c| c.unflushed), Some(4));
+
+        // Misses and bookkeeping lookups are not hits
+        assert!(manager.check_address("clean").is_none());
+        assert_eq!(manager.hits("clean").hit_count, 0);
+        assert!(manager.peek_address("bad").is_some());
+        assert_eq!(manager.hits("bad").hit_count, 4);
+    }
+
+    #[test]
+    fn test_canonical_address_trims_whitespace() {
+        let address = Pubkey::new_unique().to_string();
+        assert_eq!(
+            canonical_address(&format!("  {address}\n")).unwrap(),
+            address
+        );
+    }
+
+    #[test]
+    fn test_canonical_address_rejects_invalid() {
+        // Not base58 (0, O, I and l are outside the alphabet), too short, empty
+        for invalid in ["0OIl0OIl0OIl0OIl0OIl0OIl0OIl0OIl", "abc", "   "] {
+            assert!(matches!(
+                canonical_address(invalid),
+                Err(AppError::Validation(ValidationError::InvalidAddress(_)))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_invalid_address_rejected_before_database() {
+        let manager = lazy_manager();
+
+        // The lazy pool is never connected, so these only pass without a query
+        let result = manager
+            .add_address(
+                "not a wallet".to_string(),
+                "test".to_string(),
+                BlocklistCategory::Manual,
+                5,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(AppError::Validation(ValidationError::InvalidAddress(_)))
+        ));
+        assert!(
+            manager
+                .add_addresses_batch(vec![entry("not a wallet", None)])
+                .await
+                .is_err()
+        );
+        assert!(manager.is_empty());
+    }
+
+    #[test]
+    fn test_normalize_batch_collapses_whitespace_variants() {
+        let address = Pubkey::new_unique().to_string();
+        let mut padded = entry(&format!(" {address} "), None);
+        padded.reason = "padded".to_string();
+
+        let (unique, duplicates) = normalize_batch(vec![entry(&address, None), padded]).unwrap();
+
+        assert_eq!(duplicates, 1);
+        assert_eq!(unique[0].address, address);
+        assert_eq!(unique[0].reason, "padded");
+    }
+
+    #[tokio::test]
+    async fn test_canonical_entry_matches_raw_pubkey() {
+        let pubkey = Pubkey::new_unique();
+        let manager = BlocklistManager::in_memory(&[&format!("\t{pubkey} ")]);
+
+        assert!(manager.check_address(&pubkey.to_string()).is_some());
+        assert!(manager.check_address(&format!(" {pubkey}")).is_some());
+        // Both lookups count against the one canonical entry
+        assert_eq!(manager.hits(&pubkey.to_string()).hit_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_flush_without_hits_skips_database() {
+        let manager = lazy_manager();
+        manager.store.insert("bad".to_string(), entry("bad", None));
+
+        // The lazy pool is never connected, so this only passes without a query
+        assert_eq!(manager.flush_hits().await.unwrap(), 0);
+    }
 }
@@ -0,0 +1,306 @@
+//! Periodic OFAC SDN synchronization into the internal blocklist.
+//!
+//! Fetches a published list of sanctioned Solana addresses and converges the
+//! blocklist towards it. Entries owned by the sync are tagged with the
+//! [`OFAC_REASON_PREFIX`] reason prefix; every other entry (manual, fraud,
+//! Range auto-blocks) is left alone.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::Deserialize;
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+use crate::domain::{AppError, BlocklistCategory, ExternalServiceError};
+
+use super::BlocklistManager;
+
+/// Reason prefix marking entries managed by the OFAC sync
+pub const OFAC_REASON_PREFIX: &str = "OFAC-SDN:";
+
+/// Configuration for the OFAC sync task
+#[derive(Debug, Clone)]
+pub struct OfacSyncConfig {
+    /// URL of the sanctioned address list (JSON or CSV)
+    pub source_url: String,
+    /// Interval between synchronizations
+    pub interval: Duration,
+}
+
+impl Default for OfacSyncConfig {
+    fn default() -> Self {
+        Self {
+            source_url: String::new(),
+            interval: Duration::from_secs(3600),
+        }
+    }
+}
+
+/// Result of a single synchronization pass
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OfacSyncSummary {
+    /// Sanctioned addresses newly added to the blocklist
+    pub added: usize,
+    /// Previously synced addresses no longer on the list
+    pub removed: usize,
+    /// Sanctioned addresses already blocked by a non-sync entry
+    pub skipped: usize,
+}
+
+/// JSON list formats accepted from the source URL
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum SanctionedList {
+    /// `["addr1", "addr2"]`
+    Plain(Vec<String>),
+    /// `[{"address": "addr1"}, ...]`
+    Objects(Vec<SanctionedAddress>),
+    /// `{"addresses": [...]}`
+    Wrapped { addresses: Vec<String> },
+}
+
+#[derive(Debug, Deserialize)]
+struct SanctionedAddress {
+    address: String,
+}
+
+/// Keeps the blocklist in sync with the OFAC SDN sanctioned-address list
+pub struct OfacSyncService {
+    blocklist: Arc<BlocklistManager>,
+    http_client: Client,
+    config: OfacSyncConfig,
+}
+
+impl OfacSyncService {
+    /// Create a new sync service
+    pub fn new(blocklist: Arc<BlocklistManager>, config: OfacSyncConfig) -> Self {
+        let http_client = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            blocklist,
+            http_client,
+            config,
+        }
+    }
+
+    /// Run the sync loop until shutdown is signalled.
+    /// The first sync runs immediately so a fresh deployment is protected at startup.
+    pub async fn run(self, mut shutdown_rx: watch::Receiver<bool>) {
+        info!(
+            source_url = %self.config.source_url,
+            interval = ?self.config.interval,
+            "Starting OFAC SDN sync"
+        );
+
+        loop {
+            if let Err(e) = self.sync_once().await {
+                error!(error = %e, "OFAC SDN sync failed");
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(self.config.interval) => {}
+                result = shutdown_rx.changed() => {
+                    if result.is_ok() && *shutdown_rx.borrow() {
+                        info!("OFAC SDN sync shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fetch the sanctioned list once and converge the blocklist towards it.
+    pub async fn sync_once(&self) -> Result<OfacSyncSummary, AppError> {
+        let sanctioned = self.fetch_sanctioned_addresses().await?;
+
+        // An empty feed is far more likely to be an upstream error than
+        // every sanction being lifted at once.
+        if sanctioned.is_empty() {
+            warn!("OFAC SDN source returned no addresses, skipping sync");
+            return Ok(OfacSyncSummary::default());
+        }
+
+        let mut summary = OfacSyncSummary::default();
+        let mut synced = HashSet::new();
+        let mut other = HashSet::new();
+        for entry in self.blocklist.list_all() {
+            if is_sync_owned(&entry.reason) {
+                synced.insert(entry.address);
+            } else {
+                other.insert(entry.address);
+            }
+        }
+
+        for address in &sanctioned {
+            if synced.contains(address) {
+                continue;
+            }
+            if other.contains(address) {
+                // Never overwrite an operator's entry
+                summary.skipped += 1;
+                continue;
+            }
+            let added = self
+                .blocklist
+                .add_address(
+                    address.clone(),
+                    format!("{} sanctioned address", OFAC_REASON_PREFIX),
+                    BlocklistCategory::OfacSanction,
+                    BlocklistCategory::OfacSanction.default_severity(),
+                )
+                .await;
+            match added {
+                Ok(()) => summary.added += 1,
+                // The feed may carry addresses of other chains
+                Err(AppError::Validation(e)) => {
+                    warn!(address = %address, error = %e, "Skipping invalid OFAC SDN address");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        for address in synced.difference(&sanctioned) {
+            // Re-check ownership in case an operator replaced the entry since the snapshot
+            let still_owned = self
+                .blocklist
+                .peek_address(address)
+                .is_some_and(|entry| is_sync_owned(&entry.reason));
+            if still_owned && self.blocklist.remove_address(address).await? {
+                summary.removed += 1;
+            }
+        }
+
+        info!(
+            sanctioned = sanctioned.len(),
+            added = summary.added,
+            removed = summary.removed,
+            skipped = summary.skipped,
+            "OFAC SDN sync complete"
+        );
+
+        Ok(summary)
+    }
+
+    /// Download and parse the sanctioned address list.
+    async fn fetch_sanctioned_addresses(&self) -> Result<HashSet<String>, AppError> {
+        debug!(url = %self.config.source_url, "Fetching OFAC SDN list");
+
+        let response = self
+            .http_client
+            .get(&self.config.source_url)
+            .send()
+            .await
+            .map_err(|e| AppError::ExternalService(ExternalServiceError::Network(e.to_string())))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(AppError::ExternalService(ExternalServiceError::ApiError {
+                status_code: status.as_u16(),
+                message: body,
+            }));
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| AppError::ExternalService(ExternalServiceError::Network(e.to_string())))?;
+
+        parse_sanctioned_addresses(&body)
+    }
+}
+
+/// Whether a blocklist reason marks an entry managed by the sync
+fn is_sync_owned(reason: &str) -> bool {
+    reason.starts_with(OFAC_REASON_PREFIX)
+}
+
+/// Parse a JSON list (see [`SanctionedList`]) or a CSV/plain-text list with
+/// the address in the first column. A leading `address` header is skipped.
+fn parse_sanctioned_addresses(body: &str) -> Result<HashSet<String>, AppError> {
+    let trimmed = body.trim_start();
+    let addresses = if trimmed.starts_with('[') || trimmed.starts_with('{') {
+        let list: SanctionedList = serde_json::from_str(trimmed).map_err(|e| {
+            AppError::ExternalService(ExternalServiceError::ParseError(format!(
+                "Invalid OFAC SDN JSON list: {}",
+                e
+            )))
+        })?;
+        match list {
+            SanctionedList::Plain(addresses) | SanctionedList::Wrapped { addresses } => addresses,
+            SanctionedList::Objects(entries) => entries.into_iter().map(|e| e.address).collect(),
+        }
+    } else {
+        body.lines()
+            .map(|line| line.split_once(',').map_or(line, |(first, _)| first))
+            .map(str::to_string)
+            .filter(|address| !address.trim().eq_ignore_ascii_case("address"))
+            .collect()
+    };
+
+    Ok(addresses
+        .into_iter()
+        .map(|address| address.trim().to_string())
+        .filter(|address| !address.is_empty())
+        .collect())
+}
+
+/// Spawn the OFAC sync task.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_ofac_sync(
+    blocklist: Arc<BlocklistManager>,
+    config: OfacSyncConfig,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let service = OfacSyncService::new(blocklist, config);
+    let handle = tokio::spawn(service.run(shutdown_rx));
+    (handle, shutdown_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_json_list() {
+        let parsed = parse_sanctioned_addresses(r#"["AddrA", " AddrB ", ""]"#).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert!(parsed.contains("AddrB"));
+    }
+
+    #[test]
+    fn test_parse_object_and_wrapped_json() {
+        let objects = parse_sanctioned_addresses(r#"[{"address":"AddrA","name":"x"}]"#).unwrap();
+        assert!(objects.contains("AddrA"));
+
+        let wrapped = parse_sanctioned_addresses(r#"{"addresses":["AddrB"]}"#).unwrap();
+        assert!(wrapped.contains("AddrB"));
+    }
+
+    #[test]
+    fn test_parse_csv_list() {
+        let parsed =
+            parse_sanctioned_addresses("address,program\nAddrA,SDN\nAddrB,SDN\n\nAddrA,SDN\n")
+                .unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert!(parsed.contains("AddrA"));
+        assert!(!parsed.contains("address"));
+    }
+
+    #[test]
+    fn test_parse_invalid_json() {
+        assert!(parse_sanctioned_addresses("[1, 2").is_err());
+    }
+
+    #[test]
+    fn test_sync_ownership_by_reason_prefix() {
+        assert!(is_sync_owned("OFAC-SDN: sanctioned address"));
+        assert!(!is_sync_owned("Manual: OFAC-SDN lookalike"));
+    }
+}
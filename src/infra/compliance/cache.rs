@@ -0,0 +1,233 @@
+//! Decision cache shared by the external compliance providers.
+//!
+//! Decisions are keyed by sender, recipient, transfer kind and mint, and
+//! expire after a status-dependent TTL: rejections live longer than approvals.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+
+use crate::domain::{ComplianceStatus, ComplianceVerdict, SubmitTransferRequest, TransferType};
+
+/// Default TTL for cached compliance decisions (seconds)
+pub const DEFAULT_CACHE_TTL_SECS: u64 = 300;
+
+/// Rejected decisions are cached this many times longer than approvals,
+/// so repeat attempts by a bad actor don't translate into API calls.
+pub const REJECTED_CACHE_TTL_MULTIPLIER: u32 = 4;
+
+/// Expired cache entries are swept once the cache grows past this size
+const CACHE_SWEEP_THRESHOLD: usize = 10_000;
+
+/// Cache key for a compliance decision
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ComplianceCacheKey {
+    from_address: String,
+    to_address: String,
+    transfer_kind: &'static str,
+    /// Part of the key because per-mint thresholds can change the decision
+    token_mint: Option<String>,
+}
+
+impl ComplianceCacheKey {
+    pub(crate) fn from_request(request: &SubmitTransferRequest) -> Self {
+        let transfer_kind = match request.transfer_details {
+            TransferType::Public { .. } => "public",
+            TransferType::PublicBatch { .. } => "public_batch",
+            TransferType::Confidential { .. } => "confidential",
+        };
+        Self {
+            from_address: request.from_address.clone(),
+            to_address: request.to_address.clone(),
+            transfer_kind,
+            token_mint: request.token_mint.clone(),
+        }
+    }
+
+    fn involves(&self, address: &str) -> bool {
+        self.from_address == address || self.to_address == address
+    }
+}
+
+/// Cached compliance decision
+#[derive(Debug, Clone)]
+struct CachedDecision {
+    verdict: ComplianceVerdict,
+    expires_at: Instant,
+}
+
+/// TTL-bound cache of compliance decisions, shared between clones
+#[derive(Debug, Clone)]
+pub(crate) struct DecisionCache {
+    entries: Arc<DashMap<ComplianceCacheKey, CachedDecision>>,
+    /// TTL for cached `Approved` decisions (zero disables caching)
+    approved_ttl: Duration,
+    /// TTL for cached `Rejected` decisions
+    rejected_ttl: Duration,
+}
+
+impl Default for DecisionCache {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(DEFAULT_CACHE_TTL_SECS))
+    }
+}
+
+impl DecisionCache {
+    /// Create a cache holding approvals for `ttl` and rejections for
+    /// `ttl * REJECTED_CACHE_TTL_MULTIPLIER`. A zero TTL disables caching.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            entries: Arc::new(DashMap::new()),
+            approved_ttl: ttl,
+            rejected_ttl: ttl * REJECTED_CACHE_TTL_MULTIPLIER,
+        }
+    }
+
+    /// Number of cached decisions (including not-yet-swept expired ones)
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Look up an unexpired cached decision
+    pub(crate) fn get(&self, key: &ComplianceCacheKey) -> Option<ComplianceVerdict> {
+        let cached = self.entries.get(key)?.clone();
+        if cached.expires_at > Instant::now() {
+            return Some(cached.verdict);
+        }
+        self.entries
+            .remove_if(key, |_, cached| cached.expires_at <= Instant::now());
+        None
+    }
+
+    /// Store a decision with the TTL for its status
+    pub(crate) fn insert(&self, key: ComplianceCacheKey, verdict: ComplianceVerdict) {
+        if self.approved_ttl.is_zero() {
+            return;
+        }
+        let ttl = match verdict.status {
+            ComplianceStatus::Rejected => self.rejected_ttl,
+            _ => self.approved_ttl,
+        };
+
+        if self.entries.len() >= CACHE_SWEEP_THRESHOLD {
+            let now = Instant::now();
+            self.entries.retain(|_, cached| cached.expires_at > now);
+        }
+
+        self.entries.insert(
+            key,
+            CachedDecision {
+                verdict,
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+
+    /// Drop every decision involving `address` as sender or recipient.
+    /// Returns the number of removed entries.
+    pub(crate) fn invalidate(&self, address: &str) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|key, _| !key.involves(address));
+        before.saturating_sub(self.entries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cache_key(from: &str, to: &str) -> ComplianceCacheKey {
+        ComplianceCacheKey {
+            from_address: from.to_string(),
+            to_address: to.to_string(),
+            transfer_kind: "public",
+            token_mint: None,
+        }
+    }
+
+    #[test]
+    fn test_cache_hit_and_rejected_ttl_longer() {
+        let cache = DecisionCache::new(Duration::from_secs(60));
+        assert_eq!(cache.rejected_ttl, Duration::from_secs(240));
+
+        cache.insert(cache_key("a", "b"), ComplianceVerdict::approved());
+        cache.insert(
+            cache_key("a", "bad"),
+            ComplianceVerdict::rejected("sanctions"),
+        );
+
+        assert_eq!(
+            cache.get(&cache_key("a", "b")),
+            Some(ComplianceVerdict::approved())
+        );
+        assert_eq!(
+            cache.get(&cache_key("a", "bad")),
+            Some(ComplianceVerdict::rejected("sanctions"))
+        );
+        assert_eq!(cache.get(&cache_key("b", "a")), None);
+
+        let approved = cache.entries.get(&cache_key("a", "b")).unwrap().clone();
+        let rejected = cache.entries.get(&cache_key("a", "bad")).unwrap().clone();
+        assert!(rejected.expires_at > approved.expires_at);
+    }
+
+    #[test]
+    fn test_cache_expired_entry_evicted() {
+        let cache = DecisionCache::default();
+        let key = cache_key("a", "b");
+        cache.entries.insert(
+            key.clone(),
+            CachedDecision {
+                verdict: ComplianceVerdict::approved(),
+                expires_at: Instant::now(),
+            },
+        );
+
+        assert_eq!(cache.get(&key), None);
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_zero_ttl_disables_cache() {
+        let cache = DecisionCache::new(Duration::ZERO);
+        cache.insert(cache_key("a", "b"), ComplianceStatus::Rejected.into());
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_invalidate_address() {
+        let cache = DecisionCache::default();
+        cache.insert(cache_key("a", "b"), ComplianceVerdict::approved());
+        cache.insert(cache_key("b", "c"), ComplianceVerdict::approved());
+        cache.insert(cache_key("c", "d"), ComplianceVerdict::approved());
+
+        assert_eq!(cache.invalidate("b"), 2);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.invalidate("unknown"), 0);
+    }
+
+    #[test]
+    fn test_cache_key_separates_mints() {
+        let mut request = SubmitTransferRequest {
+            from_address: "sender".to_string(),
+            to_address: "receiver".to_string(),
+            transfer_details: TransferType::Public { amount: 1 },
+            token_mint: None,
+            signature: "dummy_sig".to_string(),
+            nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7c".to_string(),
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        };
+        let sol_key = ComplianceCacheKey::from_request(&request);
+        request.token_mint = Some("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v".to_string());
+        let mint_key = ComplianceCacheKey::from_request(&request);
+        assert_ne!(sol_key, mint_key);
+    }
+}
@@ -0,0 +1,413 @@
+//! Composite compliance provider.
+//!
+//! Chains several compliance providers (e.g. Range Protocol plus a second
+//! screening vendor) behind a single [`ComplianceProvider`] and combines
+//! their decisions according to a [`CompositionPolicy`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tracing::{debug, instrument, warn};
+
+use crate::domain::{
+    AppError, ComplianceProvider, ComplianceStatus, ComplianceVerdict, SubmitTransferRequest,
+};
+
+use super::UNAVAILABLE_CATEGORY;
+
+/// How the decisions of the chained providers are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompositionPolicy {
+    /// Reject as soon as any provider rejects; provider errors are propagated
+    #[default]
+    FirstRejection,
+    /// Approve only if every provider explicitly approves; errors count as rejection
+    AllMustApprove,
+    /// Approve as soon as any provider approves; errors are skipped
+    AnyApproves,
+}
+
+impl CompositionPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::FirstRejection => "first_rejection",
+            Self::AllMustApprove => "all_must_approve",
+            Self::AnyApproves => "any_approves",
+        }
+    }
+}
+
+impl std::str::FromStr for CompositionPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "first_rejection" => Ok(Self::FirstRejection),
+            "all_must_approve" => Ok(Self::AllMustApprove),
+            "any_approves" => Ok(Self::AnyApproves),
+            _ => Err(format!("Invalid compliance policy: {}", s)),
+        }
+    }
+}
+
+impl std::fmt::Display for CompositionPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Compliance provider that consults an ordered list of providers
+pub struct CompositeComplianceProvider {
+    providers: Vec<Arc<dyn ComplianceProvider>>,
+    policy: CompositionPolicy,
+}
+
+impl CompositeComplianceProvider {
+    /// Create a composite over `providers`, consulted in order
+    pub fn new(providers: Vec<Arc<dyn ComplianceProvider>>, policy: CompositionPolicy) -> Self {
+        Self { providers, policy }
+    }
+
+    /// Get the configured policy
+    #[must_use]
+    pub fn policy(&self) -> CompositionPolicy {
+        self.policy
+    }
+
+    /// Number of chained providers
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.providers.len()
+    }
+
+    /// Check if no providers are chained
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.providers.is_empty()
+    }
+
+    async fn first_rejection(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<ComplianceVerdict, AppError> {
+        for (index, provider) in self.providers.iter().enumerate() {
+            let verdict = provider.screen(request).await?;
+            debug!(provider = provider.name(), index, status = %verdict.status, "Compliance provider decision");
+            if verdict.status == ComplianceStatus::Rejected {
+                warn!(
+                    provider = provider.name(),
+                    index, "Transfer rejected by compliance provider"
+                );
+                return Ok(verdict);
+            }
+        }
+        Ok(ComplianceVerdict::approved())
+    }
+
+    async fn all_must_approve(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<ComplianceVerdict, AppError> {
+        // Category of the first provider that did not approve
+        let mut rejection: Option<Option<String>> = None;
+        for (index, provider) in self.providers.iter().enumerate() {
+            match provider.screen(request).await {
+                Ok(verdict) if verdict.status == ComplianceStatus::Approved => {
+                    debug!(
+                        provider = provider.name(),
+                        index, "Compliance provider approved"
+                    );
+                }
+                Ok(verdict) => {
+                    warn!(provider = provider.name(), index, status = %verdict.status, "Compliance provider did not approve");
+                    rejection.get_or_insert(verdict.category);
+                }
+                Err(e) => {
+                    warn!(provider = provider.name(), index, error = %e, "Compliance provider failed, treating as rejection");
+                    rejection.get_or_insert(Some(UNAVAILABLE_CATEGORY.to_string()));
+                }
+            }
+        }
+        Ok(match rejection {
+            None => ComplianceVerdict::approved(),
+            Some(category) => ComplianceVerdict {
+                status: ComplianceStatus::Rejected,
+                category,
+            },
+        })
+    }
+
+    async fn any_approves(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<ComplianceVerdict, AppError> {
+        // Category of the first provider that did not approve
+        let mut category: Option<Option<String>> = None;
+        for (index, provider) in self.providers.iter().enumerate() {
+            match provider.screen(request).await {
+                Ok(verdict) if verdict.status == ComplianceStatus::Approved => {
+                    debug!(
+                        provider = provider.name(),
+                        index, "Transfer approved by compliance provider"
+                    );
+                    return Ok(verdict);
+                }
+                Ok(verdict) => {
+                    warn!(provider = provider.name(), index, status = %verdict.status, "Compliance provider did not approve");
+                    category.get_or_insert(verdict.category);
+                }
+                Err(e) => {
+                    warn!(provider = provider.name(), index, error = %e, "Compliance provider failed, trying next");
+                    category.get_or_insert(Some(UNAVAILABLE_CATEGORY.to_string()));
+                }
+            }
+        }
+        Ok(ComplianceVerdict {
+            status: ComplianceStatus::Rejected,
+            category: category.flatten(),
+        })
+    }
+}
+
+#[async_trait]
+impl ComplianceProvider for CompositeComplianceProvider {
+    async fn check_compliance(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<ComplianceStatus, AppError> {
+        self.screen(request).await.map(|verdict| verdict.status)
+    }
+
+    #[instrument(skip(self, request), fields(policy = %self.policy, providers = self.providers.len()))]
+    async fn screen(&self, request: &SubmitTransferRequest) -> Result<ComplianceVerdict, AppError> {
+        if self.providers.is_empty() {
+            // Fail closed: a misconfigured chain must not approve everything
+            warn!("Composite compliance provider has no providers - rejecting");
+            return Ok(ComplianceStatus::Rejected.into());
+        }
+
+        match self.policy {
+            CompositionPolicy::FirstRejection => self.first_rejection(request).await,
+            CompositionPolicy::AllMustApprove => self.all_must_approve(request).await,
+            CompositionPolicy::AnyApproves => self.any_approves(request).await,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "composite"
+    }
+
+    /// Healthy if every chained provider is reachable
+    async fn health_check(&self) -> Result<(), AppError> {
+        for provider in &self.providers {
+            if let Err(e) = provider.health_check().await {
+                warn!(provider = provider.name(), error = %e, "Compliance provider health check failed");
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    fn invalidate_address(&self, address: &str) -> usize {
+        self.providers
+            .iter()
+            .map(|provider| provider.invalidate_address(address))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::TransferType;
+    use crate::test_utils::MockComplianceProvider;
+    use std::str::FromStr;
+
+    fn request() -> SubmitTransferRequest {
+        SubmitTransferRequest {
+            from_address: "sender".to_string(),
+            to_address: "receiver".to_string(),
+            transfer_details: TransferType::Public { amount: 1_000 },
+            token_mint: None,
+            signature: "dummy_sig".to_string(),
+            nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string(),
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        }
+    }
+
+    fn composite(
+        providers: &[Arc<MockComplianceProvider>],
+        policy: CompositionPolicy,
+    ) -> CompositeComplianceProvider {
+        CompositeComplianceProvider::new(
+            providers
+                .iter()
+                .map(|p| Arc::clone(p) as Arc<dyn ComplianceProvider>)
+                .collect(),
+            policy,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_first_rejection_short_circuits() {
+        let approve = Arc::new(MockComplianceProvider::new());
+        let reject = Arc::new(MockComplianceProvider::rejecting());
+        let never = Arc::new(MockComplianceProvider::new());
+        let provider = composite(
+            &[approve.clone(), reject.clone(), never.clone()],
+            CompositionPolicy::default(),
+        );
+
+        let status = provider.check_compliance(&request()).await.unwrap();
+
+        assert_eq!(status, ComplianceStatus::Rejected);
+        assert_eq!(approve.call_count(), 1);
+        assert_eq!(reject.call_count(), 1);
+        assert_eq!(never.call_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_first_rejection_approves_and_propagates_errors() {
+        let provider = composite(
+            &[
+                Arc::new(MockComplianceProvider::new()),
+                Arc::new(MockComplianceProvider::new()),
+            ],
+            CompositionPolicy::FirstRejection,
+        );
+        assert_eq!(
+            provider.check_compliance(&request()).await.unwrap(),
+            ComplianceStatus::Approved
+        );
+
+        let failing = composite(
+            &[Arc::new(MockComplianceProvider::failing("down"))],
+            CompositionPolicy::FirstRejection,
+        );
+        assert!(failing.check_compliance(&request()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_all_must_approve() {
+        let approve = Arc::new(MockComplianceProvider::new());
+        let pending = Arc::new(MockComplianceProvider::with_status(
+            ComplianceStatus::Pending,
+        ));
+        let provider = composite(
+            &[approve.clone(), pending.clone()],
+            CompositionPolicy::AllMustApprove,
+        );
+        assert_eq!(
+            provider.check_compliance(&request()).await.unwrap(),
+            ComplianceStatus::Rejected
+        );
+
+        let failing = composite(
+            &[
+                approve.clone(),
+                Arc::new(MockComplianceProvider::failing("down")),
+            ],
+            CompositionPolicy::AllMustApprove,
+        );
+        assert_eq!(
+            failing.check_compliance(&request()).await.unwrap(),
+            ComplianceStatus::Rejected
+        );
+
+        let all_ok = composite(
+            &[approve.clone(), Arc::new(MockComplianceProvider::new())],
+            CompositionPolicy::AllMustApprove,
+        );
+        assert_eq!(
+            all_ok.check_compliance(&request()).await.unwrap(),
+            ComplianceStatus::Approved
+        );
+    }
+
+    #[tokio::test]
+    async fn test_any_approves() {
+        let reject = Arc::new(MockComplianceProvider::rejecting());
+        let failing = Arc::new(MockComplianceProvider::failing("down"));
+        let approve = Arc::new(MockComplianceProvider::new());
+        let never = Arc::new(MockComplianceProvider::new());
+        let provider = composite(
+            &[
+                reject.clone(),
+                failing.clone(),
+                approve.clone(),
+                never.clone(),
+            ],
+            CompositionPolicy::AnyApproves,
+        );
+
+        assert_eq!(
+            provider.check_compliance(&request()).await.unwrap(),
+            ComplianceStatus::Approved
+        );
+        assert_eq!(never.call_count(), 0);
+
+        let none = composite(&[reject, failing], CompositionPolicy::AnyApproves);
+        assert_eq!(
+            none.check_compliance(&request()).await.unwrap(),
+            ComplianceStatus::Rejected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejection_category_comes_from_the_rejecting_provider() {
+        let approve = Arc::new(MockComplianceProvider::new());
+        let sanctions = Arc::new(MockComplianceProvider::rejecting_with("sanctions"));
+        let scam = Arc::new(MockComplianceProvider::rejecting_with("scam"));
+        let failing = Arc::new(MockComplianceProvider::failing("down"));
+
+        for policy in [
+            CompositionPolicy::FirstRejection,
+            CompositionPolicy::AllMustApprove,
+            CompositionPolicy::AnyApproves,
+        ] {
+            let provider = composite(&[approve.clone(), sanctions.clone(), scam.clone()], policy);
+            let verdict = provider.screen(&request()).await.unwrap();
+            if policy == CompositionPolicy::AnyApproves {
+                assert_eq!(verdict, ComplianceVerdict::approved());
+            } else {
+                assert_eq!(verdict, ComplianceVerdict::rejected("sanctions"));
+            }
+        }
+
+        let failing_first = composite(&[failing, scam], CompositionPolicy::AnyApproves);
+        assert_eq!(
+            failing_first.screen(&request()).await.unwrap(),
+            ComplianceVerdict::rejected(UNAVAILABLE_CATEGORY)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_empty_composite_rejects() {
+        let provider = CompositeComplianceProvider::new(vec![], CompositionPolicy::AnyApproves);
+        assert!(provider.is_empty());
+        assert_eq!(
+            provider.check_compliance(&request()).await.unwrap(),
+            ComplianceStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn test_policy_display_and_parsing() {
+        for policy in [
+            CompositionPolicy::FirstRejection,
+            CompositionPolicy::AllMustApprove,
+            CompositionPolicy::AnyApproves,
+        ] {
+            assert_eq!(CompositionPolicy::from_str(policy.as_str()), Ok(policy));
+            assert_eq!(policy.to_string(), policy.as_str());
+        }
+        assert!(CompositionPolicy::from_str("majority").is_err());
+    }
+}
@@ -1,3 +1,15 @@
+mod cache;
+pub mod composite;
+mod probe;
 pub mod range;
+mod retry;
+pub mod trm;
 
+pub use composite::{CompositeComplianceProvider, CompositionPolicy};
 pub use range::{RangeComplianceProvider, RiskResponse};
+pub use retry::ComplianceRetryConfig;
+pub use trm::TrmComplianceProvider;
+
+/// Rejection category of a provider that failed closed because its API
+/// could not be queried
+const UNAVAILABLE_CATEGORY: &str = "provider_unavailable";
@@ -0,0 +1,31 @@
+//! Lightweight reachability probe for compliance provider APIs.
+
+use reqwest::Client;
+use tracing::debug;
+
+use crate::domain::{AppError, ExternalServiceError};
+
+/// Send a `HEAD` request to `url` without credentials.
+///
+/// Any response below 500 counts as reachable: an unauthenticated probe of
+/// an API root commonly returns 401 or 404, which still proves the service
+/// is up. Network errors, timeouts and 5xx responses are failures.
+pub(super) async fn ping(client: &Client, url: &str) -> Result<(), AppError> {
+    debug!(url = %url, "Probing compliance provider");
+    let response = client.head(url).send().await.map_err(|e| {
+        if e.is_timeout() {
+            AppError::ExternalService(ExternalServiceError::Timeout(e.to_string()))
+        } else {
+            AppError::ExternalService(ExternalServiceError::Network(e.to_string()))
+        }
+    })?;
+
+    let status = response.status();
+    if status.is_server_error() {
+        return Err(AppError::ExternalService(ExternalServiceError::ApiError {
+            status_code: status.as_u16(),
+            message: format!("Health probe returned {}", status),
+        }));
+    }
+    Ok(())
+}
@@ -3,12 +3,24 @@
 //! This module provides integration with Range Protocol's Risk API
 //! for wallet address screening and compliance checks.
 
+use std::collections::HashMap;
+use std::time::Duration;
+
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::Deserialize;
 use tracing::{debug, error, info, instrument, warn};
 
-use crate::domain::{AppError, ComplianceProvider, ComplianceStatus, SubmitTransferRequest};
+use crate::domain::{
+    AppError, ComplianceProvider, ComplianceStatus, ComplianceVerdict, ExternalServiceError,
+    SubmitTransferRequest,
+};
+
+use super::UNAVAILABLE_CATEGORY;
+use super::cache::{ComplianceCacheKey, DecisionCache};
+pub use super::cache::{DEFAULT_CACHE_TTL_SECS, REJECTED_CACHE_TTL_MULTIPLIER};
+use super::probe;
+use super::retry::{ComplianceRetryConfig, retry_transient};
 
 /// Default Range Protocol API base URL
 pub const DEFAULT_RANGE_API_URL: &str = "https://api.range.org/v1";
@@ -17,6 +29,43 @@ pub const DEFAULT_RANGE_API_URL: &str = "https://api.range.org/v1";
 /// Addresses with risk_score >= this threshold will be rejected.
 pub const DEFAULT_RISK_THRESHOLD: i32 = 6;
 
+/// Rejection category of the highest Range risk level
+const CRITICAL_RISK_CATEGORY: &str = "critical_risk";
+
+/// Parse per-mint threshold overrides in the form `mint1:8,mint2:4`.
+/// Whitespace around entries is ignored; thresholds must be within 1-10.
+pub fn parse_threshold_overrides(raw: &str) -> Result<HashMap<String, i32>, String> {
+    let mut overrides = HashMap::new();
+    for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (mint, threshold) = entry.rsplit_once(':').ok_or_else(|| {
+            format!(
+                "Invalid threshold override '{}': expected mint:threshold",
+                entry
+            )
+        })?;
+        let mint = mint.trim();
+        if mint.is_empty() {
+            return Err(format!(
+                "Invalid threshold override '{}': missing mint",
+                entry
+            ));
+        }
+        let threshold = threshold
+            .trim()
+            .parse::<i32>()
+            .ok()
+            .filter(|t| (1..=10).contains(t))
+            .ok_or_else(|| {
+                format!(
+                    "Invalid threshold override '{}': threshold must be between 1 and 10",
+                    entry
+                )
+            })?;
+        overrides.insert(mint.to_string(), threshold);
+    }
+    Ok(overrides)
+}
+
 /// Detailed malicious address info
 #[derive(Debug, Deserialize, Clone)]
 pub struct MaliciousAddress {
@@ -69,6 +118,53 @@ pub struct RiskResponse {
     pub attribution: Option<Attribution>,
 }
 
+impl RiskResponse {
+    /// Human-readable factors contributing to the risk score.
+    ///
+    /// Built from the flagged addresses found near the wallet and the risk
+    /// categories of its own attribution. Duplicates are dropped, order is kept.
+    #[must_use]
+    pub fn risk_factors(&self) -> Vec<String> {
+        let mut factors: Vec<String> = Vec::new();
+        let mut push = |factor: String| {
+            if !factors.contains(&factor) {
+                factors.push(factor);
+            }
+        };
+
+        if let Some(attribution) = &self.attribution {
+            for category in &attribution.risk_categories {
+                push(format!("Attributed risk category: {}", category));
+            }
+        }
+
+        for flagged in &self.malicious_addresses_found {
+            let label = [
+                flagged.name_tag.as_str(),
+                flagged.entity.as_deref().unwrap_or_default(),
+                flagged.address.as_str(),
+            ]
+            .into_iter()
+            .find(|label| !label.is_empty())
+            .unwrap_or_default();
+            let category = if flagged.category.is_empty() {
+                "Flagged address"
+            } else {
+                flagged.category.as_str()
+            };
+            push(format!(
+                "{} ({}) at {} hop(s)",
+                category, label, flagged.distance
+            ));
+            for risk_category in &flagged.risk_categories {
+                push(format!("Linked risk category: {}", risk_category));
+            }
+        }
+
+        factors
+    }
+}
+
 /// Compliance provider that screens addresses via Range Protocol API
 #[derive(Debug, Clone)]
 pub struct RangeComplianceProvider {
@@ -76,6 +172,13 @@ pub struct RangeComplianceProvider {
     api_key: Option<String>,
     base_url: String,
     risk_threshold: i32,
+    /// Per-mint threshold overrides (mint address -> threshold)
+    threshold_overrides: HashMap<String, i32>,
+    /// Decision cache, shared between clones
+    cache: DecisionCache,
+    retry_config: ComplianceRetryConfig,
+    /// Approve (with a warning) instead of rejecting when retries are exhausted
+    fail_open: bool,
 }
 
 impl Default for RangeComplianceProvider {
@@ -106,9 +209,61 @@ impl RangeComplianceProvider {
             api_key,
             base_url: base_url.unwrap_or_else(|| DEFAULT_RANGE_API_URL.to_string()),
             risk_threshold: risk_threshold.unwrap_or(DEFAULT_RISK_THRESHOLD),
+            threshold_overrides: HashMap::new(),
+            cache: DecisionCache::default(),
+            retry_config: ComplianceRetryConfig::default(),
+            fail_open: false,
         }
     }
 
+    /// Set the retry policy for transient API failures
+    #[must_use]
+    pub fn with_retry_config(mut self, retry_config: ComplianceRetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// Treat a check whose retries were exhausted as `Approved` instead of `Rejected`.
+    /// Other API errors (auth, malformed responses) still reject.
+    #[must_use]
+    pub fn with_fail_open(mut self, fail_open: bool) -> Self {
+        self.fail_open = fail_open;
+        self
+    }
+
+    /// Set the cache TTL for approved decisions.
+    /// Rejected decisions are cached for `ttl * REJECTED_CACHE_TTL_MULTIPLIER`.
+    /// A zero TTL disables caching.
+    #[must_use]
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache = DecisionCache::new(ttl);
+        self
+    }
+
+    /// Set per-mint risk threshold overrides.
+    /// Transfers of a listed mint are evaluated against its threshold instead
+    /// of the global one; native SOL and unlisted mints use the global threshold.
+    #[must_use]
+    pub fn with_threshold_overrides(mut self, overrides: HashMap<String, i32>) -> Self {
+        self.threshold_overrides = overrides;
+        self
+    }
+
+    /// Risk threshold applied to a transfer of the given mint
+    #[must_use]
+    pub fn threshold_for(&self, token_mint: Option<&str>) -> i32 {
+        token_mint
+            .and_then(|mint| self.threshold_overrides.get(mint))
+            .copied()
+            .unwrap_or(self.risk_threshold)
+    }
+
+    /// Number of cached decisions (including not-yet-swept expired ones)
+    #[must_use]
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+
     /// Check if running in mock mode (no API key configured)
     fn is_mock_mode(&self) -> bool {
         self.api_key.is_none()
@@ -129,10 +284,22 @@ impl RangeComplianceProvider {
         ComplianceStatus::Approved
     }
 
-    /// Call Range Protocol Risk API
+    /// Call Range Protocol Risk API.
+    ///
+    /// Timeouts and 5xx responses are retried with exponential backoff and
+    /// jitter; once retries are exhausted, returns
+    /// [`ExternalServiceError::RetriesExhausted`]. Other errors return immediately.
     pub async fn check_address_risk(&self, address: &str) -> Result<RiskResponse, AppError> {
+        retry_transient(&self.retry_config, "range", || {
+            self.request_address_risk(address)
+        })
+        .await
+    }
+
+    /// Single Range Protocol Risk API request
+    async fn request_address_risk(&self, address: &str) -> Result<RiskResponse, AppError> {
         let api_key = self.api_key.as_ref().ok_or_else(|| {
-            AppError::ExternalService(crate::domain::ExternalServiceError::Configuration(
+            AppError::ExternalService(ExternalServiceError::Configuration(
                 "RANGE_API_KEY not configured".to_string(),
             ))
         })?;
@@ -151,27 +318,27 @@ impl RangeComplianceProvider {
             .await
             .map_err(|e| {
                 error!(error = %e, "Range Protocol API request failed");
-                AppError::ExternalService(crate::domain::ExternalServiceError::Network(
-                    e.to_string(),
-                ))
+                if e.is_timeout() {
+                    AppError::ExternalService(ExternalServiceError::Timeout(e.to_string()))
+                } else {
+                    AppError::ExternalService(ExternalServiceError::Network(e.to_string()))
+                }
             })?;
 
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().await.unwrap_or_default();
             error!(status = %status, body = %body, "Range Protocol API returned error");
-            return Err(AppError::ExternalService(
-                crate::domain::ExternalServiceError::ApiError {
-                    status_code: status.as_u16(),
-                    message: body,
-                },
-            ));
+            return Err(AppError::ExternalService(ExternalServiceError::ApiError {
+                status_code: status.as_u16(),
+                message: body,
+            }));
         }
 
         // Get raw body text first for debugging if parsing fails
         let body_text = response.text().await.map_err(|e| {
             error!(error = %e, "Failed to read Range Protocol response body");
-            AppError::ExternalService(crate::domain::ExternalServiceError::Network(e.to_string()))
+            AppError::ExternalService(ExternalServiceError::Network(e.to_string()))
         })?;
 
         let risk_response: RiskResponse = serde_json::from_str(&body_text).map_err(|e| {
@@ -180,7 +347,7 @@ impl RangeComplianceProvider {
                 raw_body = %body_text,
                 "Failed to parse Range Protocol response - logging raw body for debugging"
             );
-            AppError::ExternalService(crate::domain::ExternalServiceError::ParseError(format!(
+            AppError::ExternalService(ExternalServiceError::ParseError(format!(
                 "JSON parse error: {}. Raw body: {}",
                 e, body_text
             )))
@@ -205,21 +372,23 @@ impl RangeComplianceProvider {
     ///   3-2 = Low risk
     ///   1 = Very low risk
     ///
-    /// Rule: Reject if riskScore >= self.risk_threshold (configurable)
+    /// Rule: Reject if riskScore >= the threshold for `token_mint` (configurable,
+    /// see [`Self::threshold_for`]).
     /// Text-based checks are conditional on the threshold to ensure
     /// the numeric threshold is the single source of truth.
-    fn evaluate_risk(&self, response: &RiskResponse) -> ComplianceStatus {
+    fn evaluate_risk(&self, response: &RiskResponse, token_mint: Option<&str>) -> ComplianceStatus {
         let risk_level_lower = response.risk_level.to_lowercase();
+        let threshold = self.threshold_for(token_mint);
 
         // Primary check: numeric risk score against configured threshold
-        let exceeds_threshold = response.risk_score >= self.risk_threshold;
+        let exceeds_threshold = response.risk_score >= threshold;
 
         // Text-based checks are conditional on the threshold level:
         // - "high" text triggers rejection only if threshold <= 6 (High risk level)
         // - "severe"/"extremely" text triggers only if threshold <= 8 (Extremely high)
         // - "critical" text triggers only if threshold <= 10 (always, since max is 10)
-        let text_indicates_risk = (self.risk_threshold <= 6 && risk_level_lower.contains("high"))
-            || (self.risk_threshold <= 8
+        let text_indicates_risk = (threshold <= 6 && risk_level_lower.contains("high"))
+            || (threshold <= 8
                 && (risk_level_lower.contains("severe") || risk_level_lower.contains("extremely")))
             || risk_level_lower.contains("critical");
 
@@ -229,7 +398,8 @@ impl RangeComplianceProvider {
             info!(
                 risk_score = %response.risk_score,
                 risk_level = %response.risk_level,
-                threshold = %self.risk_threshold,
+                threshold = %threshold,
+                token_mint = token_mint.unwrap_or("SOL"),
                 "Address rejected: risk threshold exceeded"
             );
             ComplianceStatus::Rejected
@@ -237,41 +407,118 @@ impl RangeComplianceProvider {
             debug!(
                 risk_score = %response.risk_score,
                 risk_level = %response.risk_level,
-                threshold = %self.risk_threshold,
+                threshold = %threshold,
+                token_mint = token_mint.unwrap_or("SOL"),
                 "Address approved"
             );
             ComplianceStatus::Approved
         }
     }
+
+    /// Risk band of a response, named after the Range risk levels. The
+    /// stronger of the numeric score and the risk level text wins.
+    fn risk_category(response: &RiskResponse) -> &'static str {
+        let level = response.risk_level.to_lowercase();
+        if response.risk_score >= 10 || level.contains("critical") {
+            CRITICAL_RISK_CATEGORY
+        } else if response.risk_score >= 8
+            || level.contains("severe")
+            || level.contains("extremely")
+        {
+            "extremely_high_risk"
+        } else if response.risk_score >= 6 || level.contains("high") {
+            "high_risk"
+        } else if response.risk_score >= 4 {
+            "medium_risk"
+        } else {
+            "low_risk"
+        }
+    }
 }
 
 #[async_trait]
 impl ComplianceProvider for RangeComplianceProvider {
-    #[instrument(skip(self, request), fields(from = %request.from_address, to = %request.to_address))]
     async fn check_compliance(
         &self,
         request: &SubmitTransferRequest,
     ) -> Result<ComplianceStatus, AppError> {
+        self.screen(request).await.map(|verdict| verdict.status)
+    }
+
+    #[instrument(skip(self, request), fields(from = %request.from_address, to = %request.to_address))]
+    async fn screen(&self, request: &SubmitTransferRequest) -> Result<ComplianceVerdict, AppError> {
         // Use mock mode if no API key is configured
         if self.is_mock_mode() {
             warn!("Running in mock compliance mode - no RANGE_API_KEY configured");
-            return Ok(self.mock_check(&request.to_address));
+            return Ok(match self.mock_check(&request.to_address) {
+                ComplianceStatus::Rejected => ComplianceVerdict::rejected(CRITICAL_RISK_CATEGORY),
+                status => status.into(),
+            });
+        }
+
+        let cache_key = ComplianceCacheKey::from_request(request);
+        if let Some(verdict) = self.cache.get(&cache_key) {
+            debug!(status = %verdict.status, "Range compliance cache hit");
+            return Ok(verdict);
         }
 
         // Check destination address against Range Protocol
         match self.check_address_risk(&request.to_address).await {
-            Ok(response) => Ok(self.evaluate_risk(&response)),
+            Ok(response) => {
+                let verdict = match self.evaluate_risk(&response, request.token_mint.as_deref()) {
+                    ComplianceStatus::Rejected => {
+                        ComplianceVerdict::rejected(Self::risk_category(&response))
+                    }
+                    status => status.into(),
+                };
+                self.cache.insert(cache_key, verdict.clone());
+                Ok(verdict)
+            }
+            Err(AppError::ExternalService(ExternalServiceError::RetriesExhausted {
+                attempts,
+                message,
+            })) if self.fail_open => {
+                // Not cached: the next check should reach Range again
+                warn!(
+                    attempts,
+                    error = %message,
+                    to_address = %request.to_address,
+                    "Range Protocol unavailable after retries - approving (RANGE_FAIL_OPEN)"
+                );
+                Ok(ComplianceVerdict::approved())
+            }
             Err(e) => {
+                // Errors are not cached so the next attempt queries again
                 // On API error, default to rejection for safety
                 error!(
                     error = ?e,
                     to_address = %request.to_address,
                     "Range Protocol API error - defaulting to rejection for safety"
                 );
-                Ok(ComplianceStatus::Rejected)
+                Ok(ComplianceVerdict::rejected(UNAVAILABLE_CATEGORY))
             }
         }
     }
+
+    fn name(&self) -> &'static str {
+        "range"
+    }
+
+    async fn health_check(&self) -> Result<(), AppError> {
+        // Mock mode never calls the API
+        if self.is_mock_mode() {
+            return Ok(());
+        }
+        probe::ping(&self.http_client, &self.base_url).await
+    }
+
+    fn invalidate_address(&self, address: &str) -> usize {
+        let removed = self.cache.invalidate(address);
+        if removed > 0 {
+            info!(address = %address, removed, "Invalidated cached Range compliance decisions");
+        }
+        removed
+    }
 }
 
 #[cfg(test)]
@@ -314,7 +561,7 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&response),
+            provider.evaluate_risk(&response, None),
             ComplianceStatus::Rejected
         );
     }
@@ -331,7 +578,7 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&response),
+            provider.evaluate_risk(&response, None),
             ComplianceStatus::Approved
         );
     }
@@ -348,11 +595,35 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&response),
+            provider.evaluate_risk(&response, None),
             ComplianceStatus::Rejected
         );
     }
 
+    #[test]
+    fn test_risk_category_follows_range_risk_levels() {
+        let response = |risk_score: i32, risk_level: &str| RiskResponse {
+            risk_score,
+            risk_level: risk_level.to_string(),
+            num_hops: None,
+            malicious_addresses_found: vec![],
+            reasoning: String::new(),
+            attribution: None,
+        };
+        let category =
+            |score, level| RangeComplianceProvider::risk_category(&response(score, level));
+
+        assert_eq!(category(10, "Critical risk"), "critical_risk");
+        assert_eq!(
+            category(3, "CRITICAL RISK (Directly malicious)"),
+            "critical_risk"
+        );
+        assert_eq!(category(9, "Extremely high risk"), "extremely_high_risk");
+        assert_eq!(category(7, "High risk"), "high_risk");
+        assert_eq!(category(5, "Medium risk"), "medium_risk");
+        assert_eq!(category(2, "Low risk"), "low_risk");
+    }
+
     #[test]
     fn test_risk_evaluation_low_risk_approved() {
         let provider = RangeComplianceProvider::new(Some("test_key".to_string()), None, None);
@@ -365,7 +636,7 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&response),
+            provider.evaluate_risk(&response, None),
             ComplianceStatus::Approved
         );
     }
@@ -382,6 +653,14 @@ mod tests {
             token_mint: None,
             signature: "dummy_sig".to_string(),
             nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string(),
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
         };
         let result = provider.check_compliance(&request).await;
         assert_eq!(result.unwrap(), ComplianceStatus::Approved);
@@ -399,6 +678,14 @@ mod tests {
             token_mint: None,
             signature: "dummy_sig".to_string(),
             nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7b".to_string(),
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
         };
         let result = provider.check_compliance(&request).await;
         assert_eq!(result.unwrap(), ComplianceStatus::Rejected);
@@ -426,7 +713,7 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&response),
+            provider.evaluate_risk(&response, None),
             ComplianceStatus::Rejected
         );
 
@@ -440,7 +727,7 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&safe_response),
+            provider.evaluate_risk(&safe_response, None),
             ComplianceStatus::Approved
         );
     }
@@ -462,7 +749,7 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&high_risk_response),
+            provider.evaluate_risk(&high_risk_response, None),
             ComplianceStatus::Approved
         );
 
@@ -476,7 +763,7 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&extremely_high_response),
+            provider.evaluate_risk(&extremely_high_response, None),
             ComplianceStatus::Rejected
         );
 
@@ -490,8 +777,166 @@ mod tests {
             attribution: None,
         };
         assert_eq!(
-            provider.evaluate_risk(&critical_response),
+            provider.evaluate_risk(&critical_response, None),
+            ComplianceStatus::Rejected
+        );
+    }
+
+    #[test]
+    fn test_risk_factors_from_response() {
+        let response: RiskResponse = serde_json::from_value(serde_json::json!({
+            "riskScore": 9,
+            "riskLevel": "Extremely high risk",
+            "maliciousAddressesFound": [
+                {
+                    "address": "MixerAddr",
+                    "distance": 1,
+                    "name_tag": "Tornado Mixer",
+                    "category": "Mixer",
+                    "risk_categories": ["money_laundering"]
+                },
+                {
+                    "address": "UnlabelledAddr",
+                    "distance": 2,
+                    "risk_categories": ["money_laundering"]
+                }
+            ],
+            "attribution": {
+                "risk_categories": ["phishing"]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            response.risk_factors(),
+            vec![
+                "Attributed risk category: phishing".to_string(),
+                "Mixer (Tornado Mixer) at 1 hop(s)".to_string(),
+                "Linked risk category: money_laundering".to_string(),
+                "Flagged address (UnlabelledAddr) at 2 hop(s)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_risk_factors_empty_for_clean_wallet() {
+        let response = RiskResponse {
+            risk_score: 1,
+            risk_level: "Very low risk".to_string(),
+            num_hops: None,
+            malicious_addresses_found: vec![],
+            reasoning: "Safe".to_string(),
+            attribution: None,
+        };
+        assert!(response.risk_factors().is_empty());
+    }
+
+    const STRICT_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+
+    #[test]
+    fn test_parse_threshold_overrides() {
+        let overrides = parse_threshold_overrides(" mintA:8, mintB:4 ,").unwrap();
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides["mintA"], 8);
+        assert_eq!(overrides["mintB"], 4);
+
+        assert!(parse_threshold_overrides("").unwrap().is_empty());
+        assert!(parse_threshold_overrides("mintA").is_err());
+        assert!(parse_threshold_overrides(":5").is_err());
+        assert!(parse_threshold_overrides("mintA:high").is_err());
+        assert!(parse_threshold_overrides("mintA:11").is_err());
+    }
+
+    /// A score that passes the global threshold is rejected for a mint
+    /// configured with a stricter override.
+    #[test]
+    fn test_per_mint_threshold_override() {
+        let provider = RangeComplianceProvider::new(Some("test_key".to_string()), None, None)
+            .with_threshold_overrides(HashMap::from([(STRICT_MINT.to_string(), 3)]));
+        let response = RiskResponse {
+            risk_score: 4,
+            risk_level: "Medium risk".to_string(),
+            num_hops: Some(3),
+            malicious_addresses_found: vec![],
+            reasoning: "3 hops from malicious address".to_string(),
+            attribution: None,
+        };
+
+        assert_eq!(provider.threshold_for(None), DEFAULT_RISK_THRESHOLD);
+        assert_eq!(
+            provider.threshold_for(Some("OtherMint")),
+            DEFAULT_RISK_THRESHOLD
+        );
+        assert_eq!(provider.threshold_for(Some(STRICT_MINT)), 3);
+
+        assert_eq!(
+            provider.evaluate_risk(&response, None),
+            ComplianceStatus::Approved
+        );
+        assert_eq!(
+            provider.evaluate_risk(&response, Some("OtherMint")),
+            ComplianceStatus::Approved
+        );
+        assert_eq!(
+            provider.evaluate_risk(&response, Some(STRICT_MINT)),
             ComplianceStatus::Rejected
         );
     }
+
+    fn cache_key(from: &str, to: &str) -> ComplianceCacheKey {
+        ComplianceCacheKey::from_request(&SubmitTransferRequest {
+            from_address: from.to_string(),
+            to_address: to.to_string(),
+            transfer_details: TransferType::Public { amount: 1 },
+            token_mint: None,
+            signature: "dummy_sig".to_string(),
+            nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7d".to_string(),
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        })
+    }
+
+    #[test]
+    fn test_zero_ttl_disables_cache() {
+        let provider = RangeComplianceProvider::new(Some("test_key".to_string()), None, None)
+            .with_cache_ttl(Duration::ZERO);
+        provider
+            .cache
+            .insert(cache_key("a", "b"), ComplianceStatus::Rejected.into());
+        assert_eq!(provider.cache_len(), 0);
+    }
+
+    #[test]
+    fn test_invalidate_address() {
+        let provider = RangeComplianceProvider::new(Some("test_key".to_string()), None, None);
+        provider
+            .cache
+            .insert(cache_key("a", "b"), ComplianceVerdict::approved());
+        provider
+            .cache
+            .insert(cache_key("b", "c"), ComplianceVerdict::approved());
+        provider
+            .cache
+            .insert(cache_key("c", "d"), ComplianceVerdict::approved());
+
+        assert_eq!(provider.invalidate_address("b"), 2);
+        assert_eq!(provider.cache_len(), 1);
+        assert_eq!(provider.invalidate_address("unknown"), 0);
+    }
+
+    #[test]
+    fn test_clones_share_cache() {
+        let provider = RangeComplianceProvider::new(Some("test_key".to_string()), None, None);
+        let clone = provider.clone();
+        provider
+            .cache
+            .insert(cache_key("a", "b"), ComplianceVerdict::approved());
+        assert_eq!(clone.cache_len(), 1);
+    }
 }
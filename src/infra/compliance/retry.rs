@@ -0,0 +1,172 @@
+//! Bounded retry with exponential backoff for compliance API calls.
+//!
+//! Only transient failures (timeouts and 5xx responses) are retried. When
+//! every attempt fails, the caller gets [`ExternalServiceError::RetriesExhausted`]
+//! so it can apply its fail-open / fail-closed policy.
+
+use std::future::Future;
+use std::time::Duration;
+
+use tracing::warn;
+
+use crate::domain::{AppError, ExternalServiceError};
+
+/// Retry configuration for transient compliance API failures (timeouts and 5xx)
+#[derive(Debug, Clone)]
+pub struct ComplianceRetryConfig {
+    /// Retries after the first attempt (0 disables retrying)
+    pub max_retries: u32,
+    /// Base delay, doubled on every retry and jittered by up to 50%
+    pub retry_delay: Duration,
+}
+
+impl Default for ComplianceRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            retry_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+impl ComplianceRetryConfig {
+    /// Backoff before the given retry (1-based)
+    fn backoff(&self, retry: u32) -> Duration {
+        let base = self
+            .retry_delay
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)));
+        let max_jitter_ms = u64::try_from(base.as_millis() / 2).unwrap_or(u64::MAX);
+        let jitter = if max_jitter_ms == 0 {
+            0
+        } else {
+            rand::random_range(0..=max_jitter_ms)
+        };
+        base + Duration::from_millis(jitter)
+    }
+}
+
+/// Whether an error is worth retrying (timeouts and server-side failures)
+fn is_transient(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::ExternalService(
+            ExternalServiceError::Timeout(_)
+                | ExternalServiceError::ApiError {
+                    status_code: 500..=599,
+                    ..
+                }
+        )
+    )
+}
+
+/// Run `operation`, retrying transient failures according to `config`.
+/// Non-transient errors are returned immediately.
+pub(crate) async fn retry_transient<T, F, Fut>(
+    config: &ComplianceRetryConfig,
+    service: &'static str,
+    mut operation: F,
+) -> Result<T, AppError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, AppError>>,
+{
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        match operation().await {
+            Ok(value) => return Ok(value),
+            Err(e) if is_transient(&e) => {
+                if attempt > config.max_retries {
+                    return Err(AppError::ExternalService(
+                        ExternalServiceError::RetriesExhausted {
+                            attempts: attempt,
+                            message: e.to_string(),
+                        },
+                    ));
+                }
+                let delay = config.backoff(attempt);
+                warn!(
+                    service,
+                    attempt,
+                    delay_ms = delay.as_millis() as u64,
+                    error = %e,
+                    "Transient compliance API failure, retrying"
+                );
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_transient_error_classification() {
+        assert!(is_transient(&AppError::ExternalService(
+            ExternalServiceError::Timeout("30s".to_string())
+        )));
+        assert!(is_transient(&AppError::ExternalService(
+            ExternalServiceError::ApiError {
+                status_code: 503,
+                message: String::new(),
+            }
+        )));
+        assert!(!is_transient(&AppError::ExternalService(
+            ExternalServiceError::ApiError {
+                status_code: 403,
+                message: String::new(),
+            }
+        )));
+        assert!(!is_transient(&AppError::ExternalService(
+            ExternalServiceError::ParseError("bad json".to_string())
+        )));
+    }
+
+    #[test]
+    fn test_retry_backoff_grows_with_bounded_jitter() {
+        let config = ComplianceRetryConfig {
+            max_retries: 3,
+            retry_delay: Duration::from_millis(100),
+        };
+        for _ in 0..20 {
+            let first = config.backoff(1);
+            let third = config.backoff(3);
+            assert!(first >= Duration::from_millis(100) && first <= Duration::from_millis(150));
+            assert!(third >= Duration::from_millis(400) && third <= Duration::from_millis(600));
+        }
+        let no_delay = ComplianceRetryConfig {
+            max_retries: 1,
+            retry_delay: Duration::ZERO,
+        };
+        assert_eq!(no_delay.backoff(1), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn test_non_transient_error_not_retried() {
+        let config = ComplianceRetryConfig {
+            max_retries: 3,
+            retry_delay: Duration::ZERO,
+        };
+        let calls = AtomicU32::new(0);
+        let counter = &calls;
+        let result: Result<(), AppError> = retry_transient(&config, "test", || async move {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Err(AppError::ExternalService(
+                ExternalServiceError::Configuration("no key".to_string()),
+            ))
+        })
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(AppError::ExternalService(
+                ExternalServiceError::Configuration(_)
+            ))
+        ));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,453 @@
+//! TRM Labs compliance provider implementation.
+//!
+//! Screens the sender and recipient of a transfer with TRM's address
+//! screening API and maps the reported risk indicators to a
+//! [`ComplianceStatus`].
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, error, info, instrument, warn};
+
+use crate::domain::{
+    AppError, ComplianceProvider, ComplianceStatus, ComplianceVerdict, ExternalServiceError,
+    SubmitTransferRequest,
+};
+
+use super::UNAVAILABLE_CATEGORY;
+use super::cache::{ComplianceCacheKey, DecisionCache};
+use super::probe;
+use super::retry::{ComplianceRetryConfig, retry_transient};
+
+/// Default TRM Labs API base URL
+pub const DEFAULT_TRM_API_URL: &str = "https://api.trmlabs.com/public/v2";
+
+/// Default risk level threshold (High = 10).
+/// TRM risk levels: 0 = Unknown, 1 = Low, 5 = Medium, 10 = High, 15 = Severe.
+pub const DEFAULT_TRM_RISK_THRESHOLD: i32 = 10;
+
+/// Risk category that always rejects, regardless of the threshold
+const SANCTIONS_CATEGORY: &str = "sanctions";
+
+/// Rejection category when TRM names no category for a risky address
+const DEFAULT_REJECTION_CATEGORY: &str = "high_risk";
+
+/// Chain identifier used by TRM for Solana
+const TRM_CHAIN: &str = "solana";
+
+/// Address screening request item
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ScreeningRequest<'a> {
+    address: &'a str,
+    chain: &'static str,
+}
+
+/// Risk indicator attached to a screened address
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TrmRiskIndicator {
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub category_risk_score_level: i32,
+    #[serde(default)]
+    pub category_risk_score_level_label: String,
+    #[serde(default)]
+    pub risk_type: String,
+}
+
+/// Entity attributed to a screened address
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TrmEntity {
+    #[serde(default)]
+    pub category: String,
+    #[serde(default)]
+    pub entity: String,
+    #[serde(default)]
+    pub risk_score_level: i32,
+    #[serde(default)]
+    pub risk_score_level_label: String,
+}
+
+/// Screening result for a single address
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TrmScreeningResult {
+    pub address: String,
+    #[serde(default)]
+    pub address_risk_indicators: Vec<TrmRiskIndicator>,
+    #[serde(default)]
+    pub entities: Vec<TrmEntity>,
+}
+
+impl TrmScreeningResult {
+    /// Highest risk level reported for the address (0 if none)
+    #[must_use]
+    pub fn max_risk_level(&self) -> i32 {
+        let indicators = self
+            .address_risk_indicators
+            .iter()
+            .map(|i| i.category_risk_score_level);
+        let entities = self.entities.iter().map(|e| e.risk_score_level);
+        indicators.chain(entities).max().unwrap_or(0)
+    }
+
+    /// Whether any indicator or entity falls in the sanctions category
+    #[must_use]
+    pub fn is_sanctioned(&self) -> bool {
+        self.address_risk_indicators
+            .iter()
+            .map(|i| i.category.as_str())
+            .chain(self.entities.iter().map(|e| e.category.as_str()))
+            .any(|category| category.eq_ignore_ascii_case(SANCTIONS_CATEGORY))
+    }
+
+    /// Rejection category for the address: `sanctions` when sanctioned,
+    /// otherwise the category of its highest-risk indicator or entity in
+    /// snake case (`Stolen Funds` becomes `stolen_funds`)
+    #[must_use]
+    pub fn rejection_category(&self) -> String {
+        if self.is_sanctioned() {
+            return SANCTIONS_CATEGORY.to_string();
+        }
+        let indicators = self
+            .address_risk_indicators
+            .iter()
+            .map(|i| (i.category_risk_score_level, i.category.as_str()));
+        let entities = self
+            .entities
+            .iter()
+            .map(|e| (e.risk_score_level, e.category.as_str()));
+        let category = indicators
+            .chain(entities)
+            .filter(|(_, category)| !category.trim().is_empty())
+            .max_by_key(|(level, _)| *level)
+            .map_or(DEFAULT_REJECTION_CATEGORY, |(_, category)| category.trim());
+        category
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect()
+    }
+}
+
+/// Compliance provider that screens addresses via the TRM Labs API
+#[derive(Debug, Clone)]
+pub struct TrmComplianceProvider {
+    http_client: Client,
+    api_key: String,
+    base_url: String,
+    risk_threshold: i32,
+    /// Decision cache, shared between clones
+    cache: DecisionCache,
+    retry_config: ComplianceRetryConfig,
+    /// Approve (with a warning) instead of rejecting when retries are exhausted
+    fail_open: bool,
+}
+
+impl TrmComplianceProvider {
+    /// Create a new TRM compliance provider
+    ///
+    /// # Arguments
+    /// * `api_key` - TRM Labs API key
+    /// * `base_url` - Optional custom API base URL. Defaults to TRM production.
+    /// * `risk_threshold` - Optional risk level threshold. Defaults to DEFAULT_TRM_RISK_THRESHOLD (10).
+    pub fn new(api_key: String, base_url: Option<String>, risk_threshold: Option<i32>) -> Self {
+        let http_client = Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .expect("Failed to create HTTP client");
+
+        Self {
+            http_client,
+            api_key,
+            base_url: base_url.unwrap_or_else(|| DEFAULT_TRM_API_URL.to_string()),
+            risk_threshold: risk_threshold.unwrap_or(DEFAULT_TRM_RISK_THRESHOLD),
+            cache: DecisionCache::default(),
+            retry_config: ComplianceRetryConfig::default(),
+            fail_open: false,
+        }
+    }
+
+    /// Set the cache TTL for approved decisions.
+    /// Rejected decisions are cached longer; a zero TTL disables caching.
+    #[must_use]
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache = DecisionCache::new(ttl);
+        self
+    }
+
+    /// Set the retry policy for transient API failures
+    #[must_use]
+    pub fn with_retry_config(mut self, retry_config: ComplianceRetryConfig) -> Self {
+        self.retry_config = retry_config;
+        self
+    }
+
+    /// Treat a check whose retries were exhausted as `Approved` instead of `Rejected`
+    #[must_use]
+    pub fn with_fail_open(mut self, fail_open: bool) -> Self {
+        self.fail_open = fail_open;
+        self
+    }
+
+    /// Number of cached decisions (including not-yet-swept expired ones)
+    #[must_use]
+    pub fn cache_len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Screen addresses with TRM, retrying timeouts and 5xx responses.
+    pub async fn screen_addresses(
+        &self,
+        addresses: &[&str],
+    ) -> Result<Vec<TrmScreeningResult>, AppError> {
+        retry_transient(&self.retry_config, "trm", || {
+            self.request_screening(addresses)
+        })
+        .await
+    }
+
+    /// Single TRM screening request
+    async fn request_screening(
+        &self,
+        addresses: &[&str],
+    ) -> Result<Vec<TrmScreeningResult>, AppError> {
+        let url = format!("{}/screening/addresses", self.base_url);
+        let body: Vec<ScreeningRequest<'_>> = addresses
+            .iter()
+            .map(|&address| ScreeningRequest {
+                address,
+                chain: TRM_CHAIN,
+            })
+            .collect();
+
+        debug!(url = %url, count = addresses.len(), "Calling TRM screening API");
+
+        let response = self
+            .http_client
+            .post(&url)
+            .basic_auth(&self.api_key, Some(&self.api_key))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "TRM API request failed");
+                if e.is_timeout() {
+                    AppError::ExternalService(ExternalServiceError::Timeout(e.to_string()))
+                } else {
+                    AppError::ExternalService(ExternalServiceError::Network(e.to_string()))
+                }
+            })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            error!(status = %status, body = %body, "TRM API returned error");
+            return Err(AppError::ExternalService(ExternalServiceError::ApiError {
+                status_code: status.as_u16(),
+                message: body,
+            }));
+        }
+
+        response
+            .json::<Vec<TrmScreeningResult>>()
+            .await
+            .map_err(|e| {
+                error!(error = %e, "Failed to parse TRM screening response");
+                AppError::ExternalService(ExternalServiceError::ParseError(e.to_string()))
+            })
+    }
+
+    /// Determine compliance status from screening results.
+    ///
+    /// Rule: Reject if any address is sanctioned or has a risk level
+    /// >= self.risk_threshold (configurable).
+    fn evaluate(&self, results: &[TrmScreeningResult]) -> ComplianceVerdict {
+        for result in results {
+            let level = result.max_risk_level();
+            if result.is_sanctioned() || level >= self.risk_threshold {
+                info!(
+                    address = %result.address,
+                    risk_level = level,
+                    threshold = self.risk_threshold,
+                    sanctioned = result.is_sanctioned(),
+                    "Address rejected by TRM screening"
+                );
+                return ComplianceVerdict::rejected(result.rejection_category());
+            }
+        }
+        ComplianceVerdict::approved()
+    }
+}
+
+#[async_trait]
+impl ComplianceProvider for TrmComplianceProvider {
+    async fn check_compliance(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<ComplianceStatus, AppError> {
+        self.screen(request).await.map(|verdict| verdict.status)
+    }
+
+    #[instrument(skip(self, request), fields(from = %request.from_address, to = %request.to_address))]
+    async fn screen(&self, request: &SubmitTransferRequest) -> Result<ComplianceVerdict, AppError> {
+        let cache_key = ComplianceCacheKey::from_request(request);
+        if let Some(verdict) = self.cache.get(&cache_key) {
+            debug!(status = %verdict.status, "TRM compliance cache hit");
+            return Ok(verdict);
+        }
+
+        let addresses = [request.from_address.as_str(), request.to_address.as_str()];
+        match self.screen_addresses(&addresses).await {
+            Ok(results) => {
+                let verdict = self.evaluate(&results);
+                self.cache.insert(cache_key, verdict.clone());
+                Ok(verdict)
+            }
+            Err(AppError::ExternalService(ExternalServiceError::RetriesExhausted {
+                attempts,
+                message,
+            })) if self.fail_open => {
+                warn!(
+                    attempts,
+                    error = %message,
+                    "TRM unavailable after retries - approving (TRM_FAIL_OPEN)"
+                );
+                Ok(ComplianceVerdict::approved())
+            }
+            Err(e) => {
+                error!(error = ?e, "TRM API error - defaulting to rejection for safety");
+                Ok(ComplianceVerdict::rejected(UNAVAILABLE_CATEGORY))
+            }
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        "trm"
+    }
+
+    async fn health_check(&self) -> Result<(), AppError> {
+        probe::ping(&self.http_client, &self.base_url).await
+    }
+
+    fn invalidate_address(&self, address: &str) -> usize {
+        let removed = self.cache.invalidate(address);
+        if removed > 0 {
+            info!(address = %address, removed, "Invalidated cached TRM compliance decisions");
+        }
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(json: serde_json::Value) -> TrmScreeningResult {
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn provider() -> TrmComplianceProvider {
+        TrmComplianceProvider::new("test_key".to_string(), None, None)
+    }
+
+    #[test]
+    fn test_clean_address_approved() {
+        let clean = result(serde_json::json!({
+            "address": "CleanAddr",
+            "addressRiskIndicators": [],
+            "entities": []
+        }));
+        assert_eq!(clean.max_risk_level(), 0);
+        assert_eq!(provider().evaluate(&[clean]), ComplianceVerdict::approved());
+    }
+
+    #[test]
+    fn test_high_risk_indicator_rejected() {
+        let risky = result(serde_json::json!({
+            "address": "RiskyAddr",
+            "addressRiskIndicators": [{
+                "category": "Scam",
+                "categoryRiskScoreLevel": 10,
+                "categoryRiskScoreLevelLabel": "High",
+                "riskType": "COUNTERPARTY"
+            }]
+        }));
+        assert_eq!(risky.max_risk_level(), 10);
+        assert_eq!(
+            provider().evaluate(&[risky]),
+            ComplianceVerdict::rejected("scam")
+        );
+    }
+
+    #[test]
+    fn test_medium_risk_approved_under_default_threshold() {
+        let medium = result(serde_json::json!({
+            "address": "MediumAddr",
+            "entities": [{
+                "category": "Gambling",
+                "entity": "Some Casino",
+                "riskScoreLevel": 5,
+                "riskScoreLevelLabel": "Medium"
+            }]
+        }));
+        assert_eq!(
+            provider().evaluate(std::slice::from_ref(&medium)),
+            ComplianceVerdict::approved()
+        );
+
+        let strict = TrmComplianceProvider::new("test_key".to_string(), None, Some(5));
+        assert_eq!(
+            strict.evaluate(&[medium]),
+            ComplianceVerdict::rejected("gambling")
+        );
+    }
+
+    #[test]
+    fn test_sanctions_always_rejected() {
+        let sanctioned = result(serde_json::json!({
+            "address": "SanctionedAddr",
+            "entities": [{
+                "category": "Sanctions",
+                "entity": "OFAC SDN",
+                "riskScoreLevel": 1
+            }]
+        }));
+        assert!(sanctioned.is_sanctioned());
+
+        let relaxed = TrmComplianceProvider::new("test_key".to_string(), None, Some(15));
+        assert_eq!(
+            relaxed.evaluate(&[sanctioned]),
+            ComplianceVerdict::rejected("sanctions")
+        );
+    }
+
+    #[test]
+    fn test_rejection_category_uses_riskiest_indicator() {
+        let risky = result(serde_json::json!({
+            "address": "RiskyAddr",
+            "addressRiskIndicators": [
+                { "category": "Gambling", "categoryRiskScoreLevel": 5 },
+                { "category": "Stolen Funds", "categoryRiskScoreLevel": 15 }
+            ],
+            "entities": [{ "category": "Mixer", "riskScoreLevel": 10 }]
+        }));
+        assert_eq!(risky.rejection_category(), "stolen_funds");
+
+        let unnamed = result(serde_json::json!({
+            "address": "UnnamedAddr",
+            "addressRiskIndicators": [{ "categoryRiskScoreLevel": 15 }]
+        }));
+        assert_eq!(unnamed.rejection_category(), "high_risk");
+    }
+}
@@ -2,15 +2,17 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::{PgPool, Row, postgres::PgPoolOptions};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row, postgres::PgPoolOptions};
 use std::time::Duration;
 use tracing::{info, instrument};
 
 use crate::domain::types::TransferType;
 use crate::domain::{
-    AppError, BlockchainStatus, CheckoutSession, CheckoutSessionStatus, ComplianceStatus,
-    CreateCheckoutSessionRequest, DatabaseClient, DatabaseError, LastErrorType, PaginatedResponse,
-    SubmitTransferRequest, TransferRequest, WalletRiskProfile,
+    AppError, BlockchainStatus, CheckoutSession, CheckoutSessionStatus, ComplianceDecision,
+    ComplianceDecisionFilter, ComplianceStatus, ConfigError, CreateCheckoutSessionRequest,
+    DatabaseClient, DatabaseError, IdempotencyRecord, LastErrorType, PaginatedResponse,
+    RejectionReason, SenderLimit, StatusTransition, SubmissionOutcome, SubmitTransferRequest,
+    TransferFilter, TransferRequest, TransferStatusEvent, WalletRiskProfile, WebhookDelivery,
 };
 
 /// PostgreSQL connection pool configuration
@@ -35,16 +37,113 @@ impl Default for PostgresConfig {
     }
 }
 
+impl PostgresConfig {
+    /// Create config from `DB_MAX_CONNECTIONS`, `DB_MIN_CONNECTIONS`,
+    /// `DB_ACQUIRE_TIMEOUT_SECS` and `DB_IDLE_TIMEOUT_SECS`.
+    /// Unset variables keep their defaults.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let parse = |key: &str| -> Result<Option<u64>, ConfigError> {
+            lookup(key)
+                .map(|v| {
+                    v.trim()
+                        .parse::<u64>()
+                        .map_err(|_| ConfigError::InvalidValue {
+                            key: key.to_string(),
+                            message: format!("expected a non-negative integer, got '{}'", v),
+                        })
+                })
+                .transpose()
+        };
+        let parse_count = |key: &str| -> Result<Option<u32>, ConfigError> {
+            parse(key)?
+                .map(|v| {
+                    u32::try_from(v).map_err(|_| ConfigError::InvalidValue {
+                        key: key.to_string(),
+                        message: format!("{} is too large", v),
+                    })
+                })
+                .transpose()
+        };
+
+        let default = Self::default();
+        let max_connections = parse_count("DB_MAX_CONNECTIONS")?.unwrap_or(default.max_connections);
+        let min_connections = parse_count("DB_MIN_CONNECTIONS")?.unwrap_or(default.min_connections);
+
+        if max_connections == 0 {
+            return Err(ConfigError::InvalidValue {
+                key: "DB_MAX_CONNECTIONS".to_string(),
+                message: "must be at least 1".to_string(),
+            });
+        }
+        if min_connections > max_connections {
+            return Err(ConfigError::InvalidValue {
+                key: "DB_MIN_CONNECTIONS".to_string(),
+                message: format!(
+                    "{} exceeds DB_MAX_CONNECTIONS ({})",
+                    min_connections, max_connections
+                ),
+            });
+        }
+
+        Ok(Self {
+            max_connections,
+            min_connections,
+            acquire_timeout: parse("DB_ACQUIRE_TIMEOUT_SECS")?
+                .map(Duration::from_secs)
+                .unwrap_or(default.acquire_timeout),
+            idle_timeout: parse("DB_IDLE_TIMEOUT_SECS")?
+                .map(Duration::from_secs)
+                .unwrap_or(default.idle_timeout),
+            max_lifetime: default.max_lifetime,
+        })
+    }
+}
+
 /// PostgreSQL database client with connection pooling
 pub struct PostgresClient {
     pool: PgPool,
+    /// Optional read replica for list and report queries. Writes and
+    /// single-row lookups that must see them always use `pool`.
+    read_pool: Option<PgPool>,
 }
 
 impl PostgresClient {
     /// Create a new PostgreSQL client with custom configuration
     pub async fn new(database_url: &str, config: PostgresConfig) -> Result<Self, AppError> {
         info!("Connecting to PostgreSQL...");
-        let pool = PgPoolOptions::new()
+        let pool = Self::connect_pool(database_url, &config).await?;
+        info!("Connected to PostgreSQL");
+        Ok(Self {
+            pool,
+            read_pool: None,
+        })
+    }
+
+    /// Connect a read replica and route list and report queries to it
+    pub async fn with_read_replica(
+        self,
+        replica_url: &str,
+        config: PostgresConfig,
+    ) -> Result<Self, AppError> {
+        info!("Connecting to PostgreSQL read replica...");
+        let read_pool = Self::connect_pool(replica_url, &config).await?;
+        info!("Connected to PostgreSQL read replica");
+        Ok(self.with_read_pool(read_pool))
+    }
+
+    /// Route list and report queries to an existing pool
+    #[must_use]
+    pub fn with_read_pool(mut self, read_pool: PgPool) -> Self {
+        self.read_pool = Some(read_pool);
+        self
+    }
+
+    async fn connect_pool(database_url: &str, config: &PostgresConfig) -> Result<PgPool, AppError> {
+        PgPoolOptions::new()
             .max_connections(config.max_connections)
             .min_connections(config.min_connections)
             .acquire_timeout(config.acquire_timeout)
@@ -52,9 +151,7 @@ impl PostgresClient {
             .max_lifetime(config.max_lifetime)
             .connect(database_url)
             .await
-            .map_err(|e| AppError::Database(DatabaseError::Connection(e.to_string())))?;
-        info!("Connected to PostgreSQL");
-        Ok(Self { pool })
+            .map_err(|e| AppError::Database(DatabaseError::Connection(e.to_string())))
     }
 
     /// Create a new PostgreSQL client with default configuration
@@ -79,6 +176,177 @@ impl PostgresClient {
         &self.pool
     }
 
+    /// Pool serving list and report queries: the replica if configured, else
+    /// the primary. Lag is tolerable there; lookups by id, signature or nonce
+    /// feed status polling, webhooks and replay checks, so they use the primary.
+    #[must_use]
+    pub fn read_pool(&self) -> &PgPool {
+        self.read_pool.as_ref().unwrap_or(&self.pool)
+    }
+
+    /// Insert a transfer with status `Received`, or return the existing row
+    /// if its nonce was already used
+    async fn insert_transfer<'e, E>(
+        executor: E,
+        data: &SubmitTransferRequest,
+        correlation_id: Option<&str>,
+    ) -> Result<TransferRequest, AppError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = Utc::now();
+
+        let (
+            transfer_type_str,
+            amount,
+            new_decryptable_available_balance,
+            equality_proof,
+            ciphertext_validity_proof,
+            range_proof,
+            auditor_elgamal_pubkey,
+        ) = match &data.transfer_details {
+            TransferType::Public { amount } => {
+                ("public", Some(*amount as i64), None, None, None, None, None)
+            }
+            TransferType::PublicBatch { .. } => (
+                "public_batch",
+                data.batch_total().map(|total| total as i64),
+                None,
+                None,
+                None,
+                None,
+                None,
+            ),
+            TransferType::Confidential {
+                new_decryptable_available_balance,
+                equality_proof,
+                ciphertext_validity_proof,
+                range_proof,
+                auditor_elgamal_pubkey,
+                ..
+            } => (
+                "confidential",
+                None,
+                Some(new_decryptable_available_balance.clone()),
+                Some(equality_proof.clone()),
+                Some(ciphertext_validity_proof.clone()),
+                Some(range_proof.clone()),
+                auditor_elgamal_pubkey.clone(),
+            ),
+        };
+
+        let batch_recipients = match &data.transfer_details {
+            TransferType::PublicBatch { recipients } => Some(sqlx::types::Json(recipients)),
+            _ => None,
+        };
+
+        let (fee_sigma_proof, fee_ciphertext_validity_proof) = match &data.transfer_details {
+            TransferType::Confidential {
+                fee_sigma_proof,
+                fee_ciphertext_validity_proof,
+                ..
+            } => (
+                fee_sigma_proof.as_deref(),
+                fee_ciphertext_validity_proof.as_deref(),
+            ),
+            _ => (None, None),
+        };
+
+        // Insert with nonce - uses UNIQUE constraint for idempotency
+        // ON CONFLICT handles race condition: if another request with same nonce
+        // was inserted between our check and insert, return the existing row
+        let row = sqlx::query(
+            r#"
+            INSERT INTO transfer_requests (
+                id, from_address, to_address, amount, token_mint,
+                compliance_status, blockchain_status, blockchain_retry_count,
+                created_at, updated_at,
+                transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
+                auditor_elgamal_pubkey, nonce, client_signature, memo, priority, correlation_id, callback_url, jito_region,
+                batch_recipients, skip_preflight, valid_until, fee_sigma_proof, fee_ciphertext_validity_proof
+            ) 
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28)
+            ON CONFLICT (nonce) WHERE nonce IS NOT NULL
+            DO UPDATE SET id = transfer_requests.id
+            RETURNING id, from_address, to_address, amount, token_mint,
+                      compliance_status, blockchain_status, blockchain_signature,
+                      blockchain_retry_count, blockchain_last_error, blockchain_next_retry_at,
+                      created_at, updated_at,
+                      transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                      original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                      nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
+            "#,
+        )
+        .bind(&id)
+        .bind(&data.from_address)
+        .bind(&data.to_address)
+        .bind(amount)
+        .bind(data.token_mint.as_deref())
+        .bind(ComplianceStatus::Pending.as_str())
+        .bind(BlockchainStatus::Received.as_str())  // Receive→Persist→Process: persist BEFORE compliance
+        .bind(0i32)
+        .bind(now)
+        .bind(now)
+        .bind(transfer_type_str)
+        .bind(new_decryptable_available_balance)
+        .bind(equality_proof)
+        .bind(ciphertext_validity_proof)
+        .bind(range_proof)
+        .bind(auditor_elgamal_pubkey)
+        .bind(&data.nonce)
+        .bind(&data.signature)
+        .bind(data.memo.as_deref())
+        .bind(data.priority)
+        .bind(correlation_id)
+        .bind(data.callback_url.as_deref())
+        .bind(data.jito_region.as_deref())
+        .bind(batch_recipients)
+        .bind(data.skip_preflight)
+        .bind(data.valid_until)
+        .bind(fee_sigma_proof)
+        .bind(fee_ciphertext_validity_proof)
+        .fetch_one(executor)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::from(e)))?;
+
+        // Parse the returned row (handles both new insert and existing row on conflict)
+        Self::row_to_transfer_request(&row)
+    }
+
+    /// Lamports of the sender's native SOL transfers (single and batch)
+    /// created at or after `since` that count toward the daily limit: all
+    /// but those compliance rejected and those that expired or were
+    /// cancelled without moving funds
+    async fn sum_recent_sol_transfers<'e, E>(
+        executor: E,
+        from_address: &str,
+        since: DateTime<Utc>,
+    ) -> Result<u64, AppError>
+    where
+        E: sqlx::PgExecutor<'e>,
+    {
+        let total = sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COALESCE(SUM(amount), 0)::BIGINT
+            FROM transfer_requests
+            WHERE from_address = $1
+              AND created_at >= $2
+              AND token_mint IS NULL
+              AND transfer_type IN ('public', 'public_batch')
+              AND compliance_status <> 'rejected'
+              AND blockchain_status NOT IN ('expired', 'cancelled')
+            "#,
+        )
+        .bind(from_address)
+        .bind(since)
+        .fetch_one(executor)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        Ok(total.max(0) as u64)
+    }
+
     /// Parse a database row into a TransferRequest
     fn row_to_transfer_request(row: &sqlx::postgres::PgRow) -> Result<TransferRequest, AppError> {
         let compliance_status_str: String = row.get("compliance_status");
@@ -93,14 +361,27 @@ impl PostgresClient {
         let ciphertext_validity_proof: Option<String> =
             row.try_get("ciphertext_validity_proof").ok();
         let range_proof: Option<String> = row.try_get("range_proof").ok();
+        let auditor_elgamal_pubkey: Option<String> =
+            row.try_get("auditor_elgamal_pubkey").ok().flatten();
+        let fee_sigma_proof: Option<String> = row.try_get("fee_sigma_proof").ok().flatten();
+        let fee_ciphertext_validity_proof: Option<String> =
+            row.try_get("fee_ciphertext_validity_proof").ok().flatten();
+        let batch_recipients: Option<sqlx::types::Json<Vec<(String, u64)>>> =
+            row.try_get("batch_recipients").ok().flatten();
 
         let transfer_details = match transfer_type_str.as_deref() {
+            Some("public_batch") => TransferType::PublicBatch {
+                recipients: batch_recipients.map(|json| json.0).unwrap_or_default(),
+            },
             Some("confidential") => TransferType::Confidential {
                 new_decryptable_available_balance: new_decryptable_available_balance
                     .unwrap_or_default(),
                 equality_proof: equality_proof.unwrap_or_default(),
                 ciphertext_validity_proof: ciphertext_validity_proof.unwrap_or_default(),
                 range_proof: range_proof.unwrap_or_default(),
+                auditor_elgamal_pubkey,
+                fee_sigma_proof,
+                fee_ciphertext_validity_proof,
             },
             // Default to Public if "public" or unknown/null (backward compatibility)
             _ => TransferType::Public {
@@ -113,6 +394,10 @@ impl PostgresClient {
             row.try_get("original_tx_signature").ok().flatten();
         let last_error_type_str: Option<String> = row.try_get("last_error_type").ok().flatten();
         let blockhash_used: Option<String> = row.try_get("blockhash_used").ok().flatten();
+        let bundle_id: Option<String> = row.try_get("bundle_id").ok().flatten();
+        let proof_signatures: Vec<String> = row.try_get("proof_signatures").unwrap_or_default();
+        let token_sent_amount: Option<i64> = row.try_get("token_sent_amount").ok().flatten();
+        let token_transfer_fee: Option<i64> = row.try_get("token_transfer_fee").ok().flatten();
 
         let last_error_type = last_error_type_str
             .as_deref()
@@ -122,6 +407,17 @@ impl PostgresClient {
         // Request Uniqueness fields (Replay Protection & Idempotency)
         let nonce: Option<String> = row.try_get("nonce").ok().flatten();
         let client_signature: Option<String> = row.try_get("client_signature").ok().flatten();
+        let memo: Option<String> = row.try_get("memo").ok().flatten();
+        let priority: i16 = row.try_get("priority").unwrap_or(0);
+        let archived_at: Option<DateTime<Utc>> = row.try_get("archived_at").ok().flatten();
+        let correlation_id: Option<String> = row.try_get("correlation_id").ok().flatten();
+        let callback_url: Option<String> = row.try_get("callback_url").ok().flatten();
+        let jito_region: Option<String> = row.try_get("jito_region").ok().flatten();
+        let signer_pubkey: Option<String> = row.try_get("signer_pubkey").ok().flatten();
+        let skip_preflight: Option<bool> = row.try_get("skip_preflight").ok().flatten();
+        let valid_until: Option<DateTime<Utc>> = row.try_get("valid_until").ok().flatten();
+        let rejection_reason: Option<sqlx::types::Json<RejectionReason>> =
+            row.try_get("rejection_reason").ok().flatten();
 
         Ok(TransferRequest {
             id: row.get("id"),
@@ -132,6 +428,7 @@ impl PostgresClient {
             compliance_status: compliance_status_str
                 .parse()
                 .unwrap_or(ComplianceStatus::Pending),
+            rejection_reason: rejection_reason.map(|json| json.0),
             blockchain_status: blockchain_status_str
                 .parse()
                 .unwrap_or(BlockchainStatus::Pending),
@@ -143,9 +440,100 @@ impl PostgresClient {
             original_tx_signature,
             last_error_type,
             blockhash_used,
+            bundle_id,
+            proof_signatures,
+            token_sent_amount: token_sent_amount.map(|amount| amount as u64),
+            token_transfer_fee: token_transfer_fee.map(|fee| fee as u64),
             // Request Uniqueness fields
             nonce,
             client_signature,
+            valid_until,
+            memo,
+            priority,
+            archived_at,
+            correlation_id,
+            callback_url,
+            jito_region,
+            skip_preflight,
+            signer_pubkey,
+            explorer_url: None,
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        })
+    }
+
+    /// Record a status transition after the status update was applied.
+    /// The update already succeeded, so a failure here is logged rather than
+    /// returned: reporting it would make callers retry a completed update.
+    async fn record_transition_or_warn(&self, transition: StatusTransition) {
+        if let Err(e) = self.record_status_transition(&transition).await {
+            tracing::error!(
+                transfer_id = %transition.transfer_id,
+                kind = transition.kind.as_str(),
+                new_status = %transition.new_status,
+                error = %e,
+                "Failed to record status transition"
+            );
+        }
+    }
+
+    /// Parse a database row into a StatusTransition
+    fn row_to_status_transition(row: &sqlx::postgres::PgRow) -> Result<StatusTransition, AppError> {
+        let kind: String = row.get("kind");
+        Ok(StatusTransition {
+            transfer_id: row.get("transfer_id"),
+            kind: kind
+                .parse()
+                .map_err(|e: String| AppError::Database(DatabaseError::Query(e)))?,
+            old_status: row.get("old_status"),
+            new_status: row.get("new_status"),
+            error: row.get("error"),
+            rejection_reason: row
+                .try_get::<Option<sqlx::types::Json<RejectionReason>>, _>("rejection_reason")
+                .ok()
+                .flatten()
+                .map(|json| json.0),
+            created_at: row.get("created_at"),
+        })
+    }
+
+    /// Parse a database row into a ComplianceDecision
+    fn row_to_compliance_decision(
+        row: &sqlx::postgres::PgRow,
+    ) -> Result<ComplianceDecision, AppError> {
+        let decision: String = row.get("decision");
+        Ok(ComplianceDecision {
+            id: row.get("id"),
+            transfer_id: row.get("transfer_id"),
+            address: row.get("address"),
+            decision: decision
+                .parse()
+                .map_err(|e: String| AppError::Database(DatabaseError::Query(e)))?,
+            provider: row.get("provider"),
+            risk_score: row.get("risk_score"),
+            reason: row.get("reason"),
+            created_at: row.get("created_at"),
+        })
+    }
+
+    /// Parse a database row into a WebhookDelivery
+    fn row_to_webhook_delivery(row: &sqlx::postgres::PgRow) -> Result<WebhookDelivery, AppError> {
+        let status: String = row.get("status");
+        let payload: sqlx::types::Json<TransferStatusEvent> = row
+            .try_get("payload")
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+        Ok(WebhookDelivery {
+            id: row.get("id"),
+            transfer_id: row.get("transfer_id"),
+            event_key: row.get("event_key"),
+            url: row.get("url"),
+            event: payload.0,
+            status: status
+                .parse()
+                .map_err(|e: String| AppError::Database(DatabaseError::Query(e)))?,
+            attempts: row.get("attempts"),
+            next_attempt_at: row.get("next_attempt_at"),
+            last_error: row.get("last_error"),
             created_at: row.get("created_at"),
             updated_at: row.get("updated_at"),
         })
@@ -185,6 +573,16 @@ impl DatabaseClient for PostgresClient {
         Ok(())
     }
 
+    async fn read_replica_health_check(&self) -> Option<Result<(), AppError>> {
+        let read_pool = self.read_pool.as_ref()?;
+        let result = sqlx::query("SELECT 1")
+            .execute(read_pool)
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::Database(DatabaseError::Connection(e.to_string())));
+        Some(result)
+    }
+
     #[instrument(skip(self))]
     async fn get_transfer_request(&self, id: &str) -> Result<Option<TransferRequest>, AppError> {
         let row = sqlx::query(
@@ -193,9 +591,9 @@ impl DatabaseClient for PostgresClient {
                    blockchain_status, blockchain_signature, blockchain_retry_count,
                    blockchain_last_error, blockchain_next_retry_at,
                    created_at, updated_at,
-                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                   original_tx_signature, last_error_type, blockhash_used,
-                   nonce, client_signature
+                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                   original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                   nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
             FROM transfer_requests 
             WHERE id = $1
             "#,
@@ -211,87 +609,53 @@ impl DatabaseClient for PostgresClient {
         }
     }
 
-    #[instrument(skip(self, data), fields(from = %data.from_address, to = %data.to_address, nonce = %data.nonce))]
     async fn submit_transfer(
         &self,
         data: &SubmitTransferRequest,
     ) -> Result<TransferRequest, AppError> {
-        let id = uuid::Uuid::new_v4().to_string();
-        let now = Utc::now();
+        self.submit_transfer_with_correlation(data, None).await
+    }
 
-        let (
-            transfer_type_str,
-            amount,
-            new_decryptable_available_balance,
-            equality_proof,
-            ciphertext_validity_proof,
-            range_proof,
-        ) = match &data.transfer_details {
-            TransferType::Public { amount } => {
-                ("public", Some(*amount as i64), None, None, None, None)
-            }
-            TransferType::Confidential {
-                new_decryptable_available_balance,
-                equality_proof,
-                ciphertext_validity_proof,
-                range_proof,
-            } => (
-                "confidential",
-                None,
-                Some(new_decryptable_available_balance.clone()),
-                Some(equality_proof.clone()),
-                Some(ciphertext_validity_proof.clone()),
-                Some(range_proof.clone()),
-            ),
-        };
+    #[instrument(skip(self, data), fields(from = %data.from_address, to = %data.to_address, nonce = %data.nonce))]
+    async fn submit_transfer_with_correlation(
+        &self,
+        data: &SubmitTransferRequest,
+        correlation_id: Option<&str>,
+    ) -> Result<TransferRequest, AppError> {
+        Self::insert_transfer(&self.pool, data, correlation_id).await
+    }
 
-        // Insert with nonce - uses UNIQUE constraint for idempotency
-        // ON CONFLICT handles race condition: if another request with same nonce
-        // was inserted between our check and insert, return the existing row
-        let row = sqlx::query(
-            r#"
-            INSERT INTO transfer_requests (
-                id, from_address, to_address, amount, token_mint,
-                compliance_status, blockchain_status, blockchain_retry_count,
-                created_at, updated_at,
-                transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                nonce, client_signature
-            ) 
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
-            ON CONFLICT (nonce) WHERE nonce IS NOT NULL
-            DO UPDATE SET id = transfer_requests.id
-            RETURNING id, from_address, to_address, amount, token_mint,
-                      compliance_status, blockchain_status, blockchain_signature,
-                      blockchain_retry_count, blockchain_last_error, blockchain_next_retry_at,
-                      created_at, updated_at,
-                      transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                      original_tx_signature, last_error_type, blockhash_used,
-                      nonce, client_signature
-            "#,
-        )
-        .bind(&id)
-        .bind(&data.from_address)
-        .bind(&data.to_address)
-        .bind(amount)
-        .bind(data.token_mint.as_deref())
-        .bind(ComplianceStatus::Pending.as_str())
-        .bind(BlockchainStatus::Received.as_str())  // Receive→Persist→Process: persist BEFORE compliance
-        .bind(0i32)
-        .bind(now)
-        .bind(now)
-        .bind(transfer_type_str)
-        .bind(new_decryptable_available_balance)
-        .bind(equality_proof)
-        .bind(ciphertext_validity_proof)
-        .bind(range_proof)
-        .bind(&data.nonce)
-        .bind(&data.signature)
-        .fetch_one(&self.pool)
-        .await
-        .map_err(|e| AppError::Database(DatabaseError::from(e)))?;
+    #[instrument(skip(self, data), fields(from = %data.from_address, nonce = %data.nonce))]
+    async fn submit_transfer_within_limit(
+        &self,
+        data: &SubmitTransferRequest,
+        correlation_id: Option<&str>,
+        limit: SenderLimit,
+    ) -> Result<TransferRequest, AppError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Connection(e.to_string())))?;
 
-        // Parse the returned row (handles both new insert and existing row on conflict)
-        Self::row_to_transfer_request(&row)
+        // Held until the transaction ends, so a concurrent submission from the
+        // same sender sums only after this one is inserted
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind(&data.from_address)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        let sent =
+            Self::sum_recent_sol_transfers(&mut *tx, &data.from_address, limit.since).await?;
+        // Dropping the transaction on rejection releases the lock
+        limit.check(sent)?;
+
+        let transfer = Self::insert_transfer(&mut *tx, data, correlation_id).await?;
+        tx.commit()
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+        Ok(transfer)
     }
 
     #[instrument(skip(self))]
@@ -299,19 +663,30 @@ impl DatabaseClient for PostgresClient {
         &self,
         limit: i64,
         cursor: Option<&str>,
+    ) -> Result<PaginatedResponse<TransferRequest>, AppError> {
+        self.list_transfer_requests_filtered(&TransferFilter::default(), limit, cursor)
+            .await
+    }
+
+    #[instrument(skip(self))]
+    async fn list_transfer_requests_filtered(
+        &self,
+        filter: &TransferFilter,
+        limit: i64,
+        cursor: Option<&str>,
     ) -> Result<PaginatedResponse<TransferRequest>, AppError> {
         // Clamp limit to valid range
         let limit = limit.clamp(1, 100);
         // Fetch one extra to determine if there are more items
         let fetch_limit = limit + 1;
 
-        let rows = match cursor {
+        // Get the created_at of the cursor item for proper pagination
+        let cursor = match cursor {
             Some(cursor_id) => {
-                // Get the created_at of the cursor item for proper pagination
                 let cursor_row =
                     sqlx::query("SELECT created_at FROM transfer_requests WHERE id = $1")
                         .bind(cursor_id)
-                        .fetch_optional(&self.pool)
+                        .fetch_optional(self.read_pool())
                         .await
                         .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
 
@@ -326,49 +701,64 @@ impl DatabaseClient for PostgresClient {
                         ));
                     }
                 };
-
-                sqlx::query(
-                    r#"
-                    SELECT id, from_address, to_address, amount, token_mint, compliance_status,
-                           blockchain_status, blockchain_signature, blockchain_retry_count,
-                           blockchain_last_error, blockchain_next_retry_at,
-                           created_at, updated_at,
-                           transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                           original_tx_signature, last_error_type, blockhash_used,
-                           nonce, client_signature
-                    FROM transfer_requests
-                    WHERE (created_at, id) < ($1, $2)
-                    ORDER BY created_at DESC, id DESC
-                    LIMIT $3
-                    "#,
-                )
-                .bind(cursor_created_at)
-                .bind(cursor_id)
-                .bind(fetch_limit)
-                .fetch_all(&self.pool)
-                .await
-                .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?
+                Some((cursor_created_at, cursor_id))
             }
-            None => sqlx::query(
-                r#"
-                    SELECT id, from_address, to_address, amount, token_mint, compliance_status,
-                           blockchain_status, blockchain_signature, blockchain_retry_count,
-                           blockchain_last_error, blockchain_next_retry_at,
-                           created_at, updated_at,
-                           transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                           original_tx_signature, last_error_type, blockhash_used,
-                           nonce, client_signature
-                    FROM transfer_requests
-                    ORDER BY created_at DESC, id DESC
-                    LIMIT $1
-                    "#,
-            )
-            .bind(fetch_limit)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?,
+            None => None,
         };
 
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT id, from_address, to_address, amount, token_mint, compliance_status,
+                   blockchain_status, blockchain_signature, blockchain_retry_count,
+                   blockchain_last_error, blockchain_next_retry_at,
+                   created_at, updated_at,
+                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                   original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                   nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
+            FROM transfer_requests
+            WHERE archived_at IS NULL
+            "#,
+        );
+        if let Some(from_address) = &filter.from_address {
+            query.push(" AND from_address = ").push_bind(from_address);
+        }
+        if let Some(to_address) = &filter.to_address {
+            query.push(" AND to_address = ").push_bind(to_address);
+        }
+        if let Some(status) = filter.blockchain_status {
+            query
+                .push(" AND blockchain_status = ")
+                .push_bind(status.as_str());
+        }
+        if let Some(status) = filter.compliance_status {
+            query
+                .push(" AND compliance_status = ")
+                .push_bind(status.as_str());
+        }
+        if let Some(created_after) = filter.created_after {
+            query.push(" AND created_at >= ").push_bind(created_after);
+        }
+        if let Some(created_before) = filter.created_before {
+            query.push(" AND created_at < ").push_bind(created_before);
+        }
+        if let Some((cursor_created_at, cursor_id)) = cursor {
+            query
+                .push(" AND (created_at, id) < (")
+                .push_bind(cursor_created_at)
+                .push(", ")
+                .push_bind(cursor_id)
+                .push(")");
+        }
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(fetch_limit);
+
+        let rows = query
+            .build()
+            .fetch_all(self.read_pool())
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
         let has_more = rows.len() > limit as usize;
         let requests: Vec<TransferRequest> = rows
             .iter()
@@ -385,7 +775,7 @@ impl DatabaseClient for PostgresClient {
         Ok(PaginatedResponse::new(requests, next_cursor, has_more))
     }
 
-    #[instrument(skip(self), fields(id = %id, status = %status.as_str()))]
+    #[instrument(skip(self, id), fields(transfer_id = %id, status = %status.as_str()))]
     async fn update_blockchain_status(
         &self,
         id: &str,
@@ -397,16 +787,21 @@ impl DatabaseClient for PostgresClient {
     ) -> Result<(), AppError> {
         let now = Utc::now();
 
-        let result = sqlx::query(
+        // The row is locked in `prev` so the returned old status is the one replaced
+        let row = sqlx::query(
             r#"
-            UPDATE transfer_requests 
+            UPDATE transfer_requests AS t
             SET blockchain_status = $1,
-                blockchain_signature = COALESCE($2, blockchain_signature),
+                blockchain_signature = COALESCE($2, t.blockchain_signature),
                 blockchain_last_error = $3,
                 blockchain_next_retry_at = $4,
-                blockhash_used = COALESCE($5, blockhash_used),
+                blockhash_used = COALESCE($5, t.blockhash_used),
                 updated_at = $6
-            WHERE id = $7
+            FROM (
+                SELECT id, blockchain_status FROM transfer_requests WHERE id = $7 FOR UPDATE
+            ) AS prev
+            WHERE t.id = prev.id
+            RETURNING prev.blockchain_status AS old_status
             "#,
         )
         .bind(status.as_str())
@@ -416,50 +811,293 @@ impl DatabaseClient for PostgresClient {
         .bind(blockhash_used)
         .bind(now)
         .bind(id)
-        .execute(&self.pool)
+        .fetch_optional(&self.pool)
         .await
         .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
 
         // Verify the update actually affected a row
+        let Some(row) = row else {
+            tracing::warn!(transfer_id = %id, "update_blockchain_status: no rows affected (record may not exist)");
+            return Err(AppError::Database(DatabaseError::NotFound(id.to_string())));
+        };
+
+        let old_status = row.get::<String, _>("old_status").parse().ok();
+        self.record_transition_or_warn(StatusTransition::blockchain(id, old_status, status, error))
+            .await;
+
+        tracing::debug!(transfer_id = %id, status = %status.as_str(), "Blockchain status updated");
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn cancel_transfer_request(&self, id: &str) -> Result<bool, AppError> {
+        // Conditional on the current status so a row the worker has already
+        // claimed (processing) or submitted is never cancelled
+        let row = sqlx::query(
+            r#"
+            UPDATE transfer_requests AS t
+            SET blockchain_status = $1,
+                blockchain_next_retry_at = NULL,
+                updated_at = $2
+            FROM (
+                SELECT id, blockchain_status FROM transfer_requests WHERE id = $3 FOR UPDATE
+            ) AS prev
+            WHERE t.id = prev.id
+              AND prev.blockchain_status IN ($4, $5)
+            RETURNING prev.blockchain_status AS old_status
+            "#,
+        )
+        .bind(BlockchainStatus::Cancelled.as_str())
+        .bind(Utc::now())
+        .bind(id)
+        .bind(BlockchainStatus::Pending.as_str())
+        .bind(BlockchainStatus::PendingSubmission.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        let Some(row) = row else {
+            return Ok(false);
+        };
+        let old_status = row.get::<String, _>("old_status").parse().ok();
+        self.record_transition_or_warn(StatusTransition::blockchain(
+            id,
+            old_status,
+            BlockchainStatus::Cancelled,
+            None,
+        ))
+        .await;
+        Ok(true)
+    }
+
+    #[instrument(skip(self, reason))]
+    async fn update_rejection_reason(
+        &self,
+        id: &str,
+        reason: Option<&RejectionReason>,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE transfer_requests
+            SET rejection_reason = $1,
+                updated_at = $2
+            WHERE id = $3
+            "#,
+        )
+        .bind(reason.map(sqlx::types::Json))
+        .bind(Utc::now())
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
         if result.rows_affected() == 0 {
-            tracing::warn!(id = %id, "update_blockchain_status: no rows affected (record may not exist)");
             return Err(AppError::Database(DatabaseError::NotFound(id.to_string())));
         }
-
-        tracing::debug!(id = %id, status = %status.as_str(), "Blockchain status updated");
         Ok(())
     }
 
-    #[instrument(skip(self), fields(id = %id, status = %status.as_str()))]
+    #[instrument(skip(self, id), fields(transfer_id = %id, status = %status.as_str()))]
     async fn update_compliance_status(
         &self,
         id: &str,
         status: ComplianceStatus,
     ) -> Result<(), AppError> {
         let now = Utc::now();
-        let result = sqlx::query(
+        let row = sqlx::query(
             r#"
-            UPDATE transfer_requests 
+            UPDATE transfer_requests AS t
             SET compliance_status = $1,
                 updated_at = $2
-            WHERE id = $3
+            FROM (
+                SELECT id, compliance_status FROM transfer_requests WHERE id = $3 FOR UPDATE
+            ) AS prev
+            WHERE t.id = prev.id
+            RETURNING prev.compliance_status AS old_status, t.rejection_reason
             "#,
         )
         .bind(status.as_str())
         .bind(now)
         .bind(id)
-        .execute(&self.pool)
+        .fetch_optional(&self.pool)
         .await
         .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
 
         // Verify the update actually affected a row
-        if result.rows_affected() == 0 {
-            tracing::warn!(id = %id, "update_compliance_status: no rows affected (record may not exist)");
+        let Some(row) = row else {
+            tracing::warn!(transfer_id = %id, "update_compliance_status: no rows affected (record may not exist)");
             return Err(AppError::Database(DatabaseError::NotFound(id.to_string())));
+        };
+
+        let old_status = row.get::<String, _>("old_status").parse().ok();
+        // A rejection carries the reason stored just before it
+        let rejection_reason = (status == ComplianceStatus::Rejected)
+            .then(|| {
+                row.try_get::<Option<sqlx::types::Json<RejectionReason>>, _>("rejection_reason")
+                    .ok()
+                    .flatten()
+                    .map(|json| json.0)
+            })
+            .flatten();
+        self.record_transition_or_warn(
+            StatusTransition::compliance(id, old_status, status)
+                .with_rejection_reason(rejection_reason),
+        )
+        .await;
+
+        tracing::debug!(transfer_id = %id, status = %status.as_str(), "Compliance status updated");
+        Ok(())
+    }
+
+    #[instrument(skip(self, transition), fields(transfer_id = %transition.transfer_id))]
+    async fn record_status_transition(
+        &self,
+        transition: &StatusTransition,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            INSERT INTO transfer_status_history
+                (transfer_id, kind, old_status, new_status, error, rejection_reason, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+        )
+        .bind(&transition.transfer_id)
+        .bind(transition.kind.as_str())
+        .bind(&transition.old_status)
+        .bind(&transition.new_status)
+        .bind(&transition.error)
+        .bind(transition.rejection_reason.as_ref().map(sqlx::types::Json))
+        .bind(transition.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_status_history(&self, id: &str) -> Result<Vec<StatusTransition>, AppError> {
+        // Served from the primary: history rows are written right after the
+        // status update and a lagging replica would show a truncated timeline
+        let rows = sqlx::query(
+            r#"
+            SELECT transfer_id, kind, old_status, new_status, error, rejection_reason, created_at
+            FROM transfer_status_history
+            WHERE transfer_id = $1
+            ORDER BY id ASC
+            "#,
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        rows.iter().map(Self::row_to_status_transition).collect()
+    }
+
+    #[instrument(skip(self, decision), fields(transfer_id = %decision.transfer_id, decision = %decision.decision.as_str()))]
+    async fn record_compliance_decision(
+        &self,
+        decision: &ComplianceDecision,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            INSERT INTO compliance_decisions
+                (id, transfer_id, address, decision, provider, risk_score, reason, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+        )
+        .bind(&decision.id)
+        .bind(&decision.transfer_id)
+        .bind(&decision.address)
+        .bind(decision.decision.as_str())
+        .bind(&decision.provider)
+        .bind(decision.risk_score)
+        .bind(&decision.reason)
+        .bind(decision.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+        Ok(())
+    }
+
+    #[instrument(skip(self, filter))]
+    async fn list_compliance_decisions(
+        &self,
+        filter: &ComplianceDecisionFilter,
+        limit: i64,
+        cursor: Option<&str>,
+    ) -> Result<PaginatedResponse<ComplianceDecision>, AppError> {
+        let limit = limit.clamp(1, 100);
+        let fetch_limit = limit + 1;
+
+        let cursor = match cursor {
+            Some(cursor_id) => {
+                let cursor_created_at: Option<DateTime<Utc>> =
+                    sqlx::query_scalar("SELECT created_at FROM compliance_decisions WHERE id = $1")
+                        .bind(cursor_id)
+                        .fetch_optional(self.read_pool())
+                        .await
+                        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+                let Some(cursor_created_at) = cursor_created_at else {
+                    return Err(AppError::Validation(
+                        crate::domain::ValidationError::InvalidField {
+                            field: "cursor".to_string(),
+                            message: "Invalid cursor".to_string(),
+                        },
+                    ));
+                };
+                Some((cursor_created_at, cursor_id))
+            }
+            None => None,
+        };
+
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT id, transfer_id, address, decision, provider, risk_score, reason, created_at
+            FROM compliance_decisions
+            WHERE TRUE
+            "#,
+        );
+        if let Some(decision) = filter.decision {
+            query.push(" AND decision = ").push_bind(decision.as_str());
+        }
+        if let Some(created_after) = filter.created_after {
+            query.push(" AND created_at >= ").push_bind(created_after);
+        }
+        if let Some(created_before) = filter.created_before {
+            query.push(" AND created_at < ").push_bind(created_before);
         }
+        if let Some((cursor_created_at, cursor_id)) = cursor {
+            query
+                .push(" AND (created_at, id) < (")
+                .push_bind(cursor_created_at)
+                .push(", ")
+                .push_bind(cursor_id)
+                .push(")");
+        }
+        query
+            .push(" ORDER BY created_at DESC, id DESC LIMIT ")
+            .push_bind(fetch_limit);
+
+        let rows = query
+            .build()
+            .fetch_all(self.read_pool())
+            .await
+            .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        let has_more = rows.len() > limit as usize;
+        let decisions: Vec<ComplianceDecision> = rows
+            .iter()
+            .take(limit as usize)
+            .map(Self::row_to_compliance_decision)
+            .collect::<Result<Vec<_>, _>>()?;
+        let next_cursor = if has_more {
+            decisions.last().map(|decision| decision.id.clone())
+        } else {
+            None
+        };
 
-        tracing::debug!(id = %id, status = %status.as_str(), "Compliance status updated");
-        Ok(())
+        Ok(PaginatedResponse::new(decisions, next_cursor, has_more))
     }
 
     /// Get pending blockchain requests and atomically claim them for processing.
@@ -479,28 +1117,33 @@ impl DatabaseClient for PostgresClient {
         // Atomic claim: SELECT eligible rows with FOR UPDATE SKIP LOCKED,
         // UPDATE them to 'processing', and RETURN them in one operation.
         // This prevents race conditions when multiple worker replicas are running.
+        // Higher priority rows are claimed first; RETURNING has no defined
+        // order, so the claimed rows are re-sorted the same way.
         let rows = sqlx::query(
             r#"
-            UPDATE transfer_requests
-            SET blockchain_status = 'processing',
-                updated_at = NOW()
-            WHERE id IN (
-                SELECT id FROM transfer_requests
-                WHERE (blockchain_status = 'pending_submission'
-                       OR (blockchain_status = 'processing' AND updated_at < NOW() - INTERVAL '5 minutes'))
-                  AND compliance_status = 'approved'
-                  AND (blockchain_next_retry_at IS NULL OR blockchain_next_retry_at <= $1)
-                  AND blockchain_retry_count < 10
-                ORDER BY blockchain_next_retry_at ASC NULLS FIRST, created_at ASC
-                LIMIT $2
-                FOR UPDATE SKIP LOCKED
+            WITH claimed AS (
+                UPDATE transfer_requests
+                SET blockchain_status = 'processing',
+                    updated_at = NOW()
+                WHERE id IN (
+                    SELECT id FROM transfer_requests
+                    WHERE blockchain_status = 'pending_submission'
+                      AND compliance_status = 'approved'
+                      AND archived_at IS NULL
+                      AND (blockchain_next_retry_at IS NULL OR blockchain_next_retry_at <= $1)
+                    ORDER BY priority DESC, created_at ASC
+                    LIMIT $2
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING id, from_address, to_address, amount, token_mint, compliance_status,
+                          blockchain_status, blockchain_signature, blockchain_retry_count,
+                          blockchain_last_error, blockchain_next_retry_at, created_at, updated_at,
+                          transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                          original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                          nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
             )
-            RETURNING id, from_address, to_address, amount, token_mint, compliance_status,
-                      blockchain_status, blockchain_signature, blockchain_retry_count,
-                      blockchain_last_error, blockchain_next_retry_at, created_at, updated_at,
-                      transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                      original_tx_signature, last_error_type, blockhash_used,
-                      nonce, client_signature
+            SELECT * FROM claimed
+            ORDER BY priority DESC, created_at ASC
             "#,
         )
         .bind(now)
@@ -512,6 +1155,17 @@ impl DatabaseClient for PostgresClient {
         rows.iter().map(Self::row_to_transfer_request).collect()
     }
 
+    #[instrument(skip(self))]
+    async fn count_pending_blockchain_requests(&self) -> Result<i64, AppError> {
+        sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM transfer_requests WHERE blockchain_status = $1 AND archived_at IS NULL",
+        )
+        .bind(BlockchainStatus::PendingSubmission.as_str())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))
+    }
+
     #[instrument(skip(self))]
     async fn increment_retry_count(&self, id: &str) -> Result<i32, AppError> {
         let row = sqlx::query(
@@ -542,11 +1196,11 @@ impl DatabaseClient for PostgresClient {
                    blockchain_status, blockchain_signature, blockchain_retry_count,
                    blockchain_last_error, blockchain_next_retry_at,
                    created_at, updated_at,
-                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                   original_tx_signature, last_error_type, blockhash_used,
-                   nonce, client_signature
+                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                   original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                   nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
             FROM transfer_requests 
-            WHERE blockchain_signature = $1
+            WHERE blockchain_signature = $1 OR proof_signatures @> ARRAY[$1]::text[]
             "#,
         )
         .bind(signature)
@@ -679,9 +1333,9 @@ impl DatabaseClient for PostgresClient {
                    blockchain_status, blockchain_signature, blockchain_retry_count,
                    blockchain_last_error, blockchain_next_retry_at,
                    created_at, updated_at,
-                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                   original_tx_signature, last_error_type, blockhash_used,
-                   nonce, client_signature
+                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                   original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                   nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
             FROM transfer_requests 
             WHERE from_address = $1 AND nonce = $2
             "#,
@@ -698,6 +1352,104 @@ impl DatabaseClient for PostgresClient {
         }
     }
 
+    async fn find_idempotency_record(
+        &self,
+        scope: &str,
+        key: &str,
+    ) -> Result<Option<IdempotencyRecord>, AppError> {
+        // Primary pool: a retry right after the first response must see the key
+        let row = sqlx::query(
+            r#"
+            SELECT scope, key, request_hash, status_code, response_body, transfer_id,
+                   created_at, expires_at
+            FROM idempotency_keys
+            WHERE scope = $1 AND key = $2 AND expires_at > NOW()
+            "#,
+        )
+        .bind(scope)
+        .bind(key)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        row.map(|row| {
+            let status_code: i32 = row.get("status_code");
+            Ok(IdempotencyRecord {
+                scope: row.get("scope"),
+                key: row.get("key"),
+                request_hash: row.get("request_hash"),
+                status_code: u16::try_from(status_code).map_err(|_| {
+                    AppError::Database(DatabaseError::Query(format!(
+                        "Invalid stored status code: {}",
+                        status_code
+                    )))
+                })?,
+                response_body: row.get("response_body"),
+                transfer_id: row.get("transfer_id"),
+                created_at: row.get("created_at"),
+                expires_at: row.get("expires_at"),
+            })
+        })
+        .transpose()
+    }
+
+    async fn save_idempotency_record(&self, record: &IdempotencyRecord) -> Result<(), AppError> {
+        // An expired record not purged yet is replaced
+        sqlx::query(
+            r#"
+            INSERT INTO idempotency_keys
+                (scope, key, request_hash, status_code, response_body, transfer_id,
+                 created_at, expires_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (scope, key) DO UPDATE SET
+                request_hash = EXCLUDED.request_hash,
+                status_code = EXCLUDED.status_code,
+                response_body = EXCLUDED.response_body,
+                transfer_id = EXCLUDED.transfer_id,
+                created_at = EXCLUDED.created_at,
+                expires_at = EXCLUDED.expires_at
+            WHERE idempotency_keys.expires_at <= NOW()
+            "#,
+        )
+        .bind(&record.scope)
+        .bind(&record.key)
+        .bind(&record.request_hash)
+        .bind(i32::from(record.status_code))
+        .bind(&record.response_body)
+        .bind(&record.transfer_id)
+        .bind(record.created_at)
+        .bind(record.expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+        Ok(())
+    }
+
+    async fn purge_expired_idempotency_records(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+            DELETE FROM idempotency_keys
+            WHERE (scope, key) IN (
+                SELECT scope, key
+                FROM idempotency_keys
+                WHERE expires_at <= $1
+                ORDER BY expires_at
+                LIMIT $2
+            )
+            "#,
+        )
+        .bind(now)
+        .bind(limit)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+        Ok(result.rows_affected())
+    }
+
     // =========================================================================
     // Jito Double Spend Protection Methods
     // =========================================================================
@@ -736,6 +1488,83 @@ impl DatabaseClient for PostgresClient {
         Ok(())
     }
 
+    /// Pin the signing key, keeping any key pinned by an earlier attempt.
+    #[instrument(skip(self))]
+    async fn pin_transfer_signer(&self, id: &str, signer_pubkey: &str) -> Result<String, AppError> {
+        let pinned = sqlx::query_scalar::<_, Option<String>>(
+            r#"
+            UPDATE transfer_requests
+            SET signer_pubkey = COALESCE(signer_pubkey, $1),
+                updated_at = NOW()
+            WHERE id = $2
+            RETURNING signer_pubkey
+            "#,
+        )
+        .bind(signer_pubkey)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?
+        .flatten();
+
+        pinned.ok_or_else(|| AppError::Database(DatabaseError::NotFound(id.to_string())))
+    }
+
+    #[instrument(skip(self, outcome), fields(signature = %outcome.signature))]
+    async fn record_submission(
+        &self,
+        id: &str,
+        outcome: &SubmissionOutcome,
+        blockhash_used: Option<&str>,
+    ) -> Result<(), AppError> {
+        let status = outcome.submitted_status();
+
+        // The row is locked in `prev` so the returned old status is the one replaced
+        let row = sqlx::query(
+            r#"
+            UPDATE transfer_requests AS t
+            SET blockchain_status = $1,
+                blockchain_signature = $2,
+                blockchain_last_error = NULL,
+                blockchain_next_retry_at = $8,
+                blockhash_used = COALESCE($3, t.blockhash_used),
+                bundle_id = $4,
+                proof_signatures = $5,
+                token_sent_amount = $6,
+                token_transfer_fee = $7,
+                updated_at = $9
+            FROM (
+                SELECT id, blockchain_status FROM transfer_requests WHERE id = $10 FOR UPDATE
+            ) AS prev
+            WHERE t.id = prev.id
+            RETURNING prev.blockchain_status AS old_status
+            "#,
+        )
+        .bind(status.as_str())
+        .bind(&outcome.signature)
+        .bind(blockhash_used)
+        .bind(outcome.bundle_id.as_deref())
+        .bind(&outcome.proof_signatures)
+        .bind(outcome.token_sent_amount.map(|amount| amount as i64))
+        .bind(outcome.token_transfer_fee.map(|fee| fee as i64))
+        .bind(outcome.approval_expires_at)
+        .bind(Utc::now())
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        let Some(row) = row else {
+            tracing::warn!(transfer_id = %id, "record_submission: no rows affected (record may not exist)");
+            return Err(AppError::Database(DatabaseError::NotFound(id.to_string())));
+        };
+
+        let old_status = row.get::<String, _>("old_status").parse().ok();
+        self.record_transition_or_warn(StatusTransition::blockchain(id, old_status, status, None))
+            .await;
+        Ok(())
+    }
+
     // =========================================================================
     // Active Polling Fallback (Crank) Methods
     // =========================================================================
@@ -754,11 +1583,12 @@ impl DatabaseClient for PostgresClient {
                    blockchain_status, blockchain_signature, blockchain_retry_count,
                    blockchain_last_error, blockchain_next_retry_at,
                    created_at, updated_at,
-                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof,
-                   original_tx_signature, last_error_type, blockhash_used,
-                   nonce, client_signature
+                   transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                   original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                   nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
             FROM transfer_requests
             WHERE blockchain_status = 'submitted'
+              AND archived_at IS NULL
               AND updated_at < NOW() - make_interval(secs => $1)
             ORDER BY updated_at ASC
             LIMIT $2
@@ -773,6 +1603,238 @@ impl DatabaseClient for PostgresClient {
         rows.iter().map(Self::row_to_transfer_request).collect()
     }
 
+    /// Claim abandoned `processing` rows by renewing their `updated_at`.
+    /// Uses FOR UPDATE SKIP LOCKED so concurrent cranks claim disjoint rows.
+    #[instrument(skip(self))]
+    async fn claim_stale_processing_transactions(
+        &self,
+        older_than_secs: i64,
+        limit: i64,
+    ) -> Result<Vec<TransferRequest>, AppError> {
+        let rows = sqlx::query(
+            r#"
+            UPDATE transfer_requests
+            SET updated_at = NOW()
+            WHERE id IN (
+                SELECT id FROM transfer_requests
+                WHERE blockchain_status = 'processing'
+                  AND archived_at IS NULL
+                  AND updated_at < NOW() - make_interval(secs => $1)
+                ORDER BY updated_at ASC
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING id, from_address, to_address, amount, token_mint, compliance_status,
+                      blockchain_status, blockchain_signature, blockchain_retry_count,
+                      blockchain_last_error, blockchain_next_retry_at,
+                      created_at, updated_at,
+                      transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                      original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                      nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
+            "#,
+        )
+        .bind(older_than_secs as f64)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        rows.iter().map(Self::row_to_transfer_request).collect()
+    }
+
+    /// Claim `awaiting_approval` rows by renewing their `updated_at`.
+    /// Uses FOR UPDATE SKIP LOCKED so concurrent cranks claim disjoint rows.
+    #[instrument(skip(self))]
+    async fn claim_awaiting_approval_transfers(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<TransferRequest>, AppError> {
+        let rows = sqlx::query(
+            r#"
+            UPDATE transfer_requests
+            SET updated_at = NOW()
+            WHERE id IN (
+                SELECT id FROM transfer_requests
+                WHERE blockchain_status = 'awaiting_approval'
+                  AND archived_at IS NULL
+                ORDER BY updated_at ASC
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING id, from_address, to_address, amount, token_mint, compliance_status,
+                      blockchain_status, blockchain_signature, blockchain_retry_count,
+                      blockchain_last_error, blockchain_next_retry_at,
+                      created_at, updated_at,
+                      transfer_type, new_decryptable_available_balance, equality_proof, ciphertext_validity_proof, range_proof, auditor_elgamal_pubkey, fee_sigma_proof, fee_ciphertext_validity_proof, batch_recipients,
+                      original_tx_signature, last_error_type, blockhash_used, bundle_id, proof_signatures, token_sent_amount, token_transfer_fee,
+                      nonce, client_signature, memo, priority, archived_at, correlation_id, callback_url, jito_region, signer_pubkey, skip_preflight, valid_until, rejection_reason
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        rows.iter().map(Self::row_to_transfer_request).collect()
+    }
+
+    // =========================================================================
+    // Archival Methods
+    // =========================================================================
+
+    #[instrument(skip(self))]
+    async fn archive_terminal_transfers(
+        &self,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE transfer_requests
+            SET archived_at = NOW()
+            WHERE id IN (
+                SELECT id FROM transfer_requests
+                WHERE archived_at IS NULL
+                  AND blockchain_status IN ('confirmed', 'failed', 'rejected', 'unreconciled', 'expired', 'cancelled')
+                  AND updated_at < $1
+                ORDER BY updated_at ASC
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            "#,
+        )
+        .bind(older_than)
+        .bind(limit)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip(self))]
+    async fn purge_expired_nonces(
+        &self,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE transfer_requests
+            SET nonce = NULL
+            WHERE id IN (
+                SELECT id FROM transfer_requests
+                WHERE nonce IS NOT NULL
+                  AND valid_until IS NOT NULL
+                  AND valid_until < $1
+                  AND blockchain_status IN ('confirmed', 'failed', 'rejected', 'unreconciled', 'expired', 'cancelled')
+                  AND updated_at < $1
+                ORDER BY updated_at ASC
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            "#,
+        )
+        .bind(older_than)
+        .bind(limit)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        Ok(result.rows_affected())
+    }
+
+    // =========================================================================
+    // Outbound Webhook Methods
+    // =========================================================================
+
+    #[instrument(skip(self, delivery), fields(transfer_id = %delivery.transfer_id, event_key = %delivery.event_key))]
+    async fn enqueue_webhook_delivery(&self, delivery: &WebhookDelivery) -> Result<bool, AppError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO webhook_deliveries (
+                id, transfer_id, event_key, url, payload, status, attempts,
+                next_attempt_at, last_error, created_at, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (event_key) DO NOTHING
+            "#,
+        )
+        .bind(&delivery.id)
+        .bind(&delivery.transfer_id)
+        .bind(&delivery.event_key)
+        .bind(&delivery.url)
+        .bind(sqlx::types::Json(&delivery.event))
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(&delivery.last_error)
+        .bind(delivery.created_at)
+        .bind(delivery.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Claims with FOR UPDATE SKIP LOCKED and pushes `next_attempt_at` out
+    /// by the lease, so replicas running the dispatcher never share a delivery.
+    #[instrument(skip(self))]
+    async fn claim_due_webhook_deliveries(
+        &self,
+        limit: i64,
+        lease_secs: i64,
+    ) -> Result<Vec<WebhookDelivery>, AppError> {
+        let rows = sqlx::query(
+            r#"
+            WITH claimed AS (
+                UPDATE webhook_deliveries
+                SET next_attempt_at = NOW() + make_interval(secs => $2)
+                WHERE id IN (
+                    SELECT id FROM webhook_deliveries
+                    WHERE status = 'pending'
+                      AND next_attempt_at <= NOW()
+                    ORDER BY next_attempt_at ASC
+                    LIMIT $1
+                    FOR UPDATE SKIP LOCKED
+                )
+                RETURNING id, transfer_id, event_key, url, payload, status, attempts,
+                          next_attempt_at, last_error, created_at, updated_at
+            )
+            SELECT * FROM claimed
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(limit)
+        .bind(lease_secs as f64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+
+        rows.iter().map(Self::row_to_webhook_delivery).collect()
+    }
+
+    #[instrument(skip(self, delivery), fields(delivery_id = %delivery.id, status = %delivery.status.as_str()))]
+    async fn update_webhook_delivery(&self, delivery: &WebhookDelivery) -> Result<(), AppError> {
+        sqlx::query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = $2, attempts = $3, next_attempt_at = $4, last_error = $5, updated_at = NOW()
+            WHERE id = $1
+            "#,
+        )
+        .bind(&delivery.id)
+        .bind(delivery.status.as_str())
+        .bind(delivery.attempts)
+        .bind(delivery.next_attempt_at)
+        .bind(&delivery.last_error)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AppError::Database(DatabaseError::Query(e.to_string())))?;
+        Ok(())
+    }
+
     // =========================================================================
     // Risk Profile Methods (for pre-flight compliance screening cache)
     // =========================================================================
@@ -785,7 +1847,7 @@ impl DatabaseClient for PostgresClient {
     ) -> Result<Option<WalletRiskProfile>, AppError> {
         let row = sqlx::query(
             r#"
-            SELECT address, risk_score, risk_level, reasoning,
+            SELECT address, risk_score, risk_level, reasoning, risk_factors,
                    has_sanctioned_assets, helius_assets_checked, created_at, updated_at
             FROM wallet_risk_profiles
             WHERE address = $1
@@ -804,6 +1866,7 @@ impl DatabaseClient for PostgresClient {
                 risk_score: row.get("risk_score"),
                 risk_level: row.get("risk_level"),
                 reasoning: row.get("reasoning"),
+                risk_factors: row.get("risk_factors"),
                 has_sanctioned_assets: row.get("has_sanctioned_assets"),
                 helius_assets_checked: row.get("helius_assets_checked"),
                 created_at: row.get("created_at"),
@@ -818,13 +1881,14 @@ impl DatabaseClient for PostgresClient {
         sqlx::query(
             r#"
             INSERT INTO wallet_risk_profiles 
-                (address, risk_score, risk_level, reasoning, 
+                (address, risk_score, risk_level, reasoning, risk_factors,
                  has_sanctioned_assets, helius_assets_checked, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, NOW(), NOW())
+            VALUES ($1, $2, $3, $4, $5, $6, $7, NOW(), NOW())
             ON CONFLICT (address) DO UPDATE SET
                 risk_score = EXCLUDED.risk_score,
                 risk_level = EXCLUDED.risk_level,
                 reasoning = EXCLUDED.reasoning,
+                risk_factors = EXCLUDED.risk_factors,
                 has_sanctioned_assets = EXCLUDED.has_sanctioned_assets,
                 helius_assets_checked = EXCLUDED.helius_assets_checked,
                 updated_at = NOW()
@@ -834,6 +1898,7 @@ impl DatabaseClient for PostgresClient {
         .bind(profile.risk_score)
         .bind(&profile.risk_level)
         .bind(&profile.reasoning)
+        .bind(&profile.risk_factors)
         .bind(profile.has_sanctioned_assets)
         .bind(profile.helius_assets_checked)
         .execute(&self.pool)
@@ -873,4 +1938,104 @@ mod tests {
         assert_eq!(config.idle_timeout, Duration::from_secs(300));
         assert_eq!(config.max_lifetime, Duration::from_secs(3600));
     }
+
+    fn lazy_pool(database: &str) -> PgPool {
+        PgPoolOptions::new()
+            .connect_lazy(&format!("postgres://localhost/{}", database))
+            .expect("lazy pool")
+    }
+
+    #[tokio::test]
+    async fn test_reads_use_primary_without_replica() {
+        let client = PostgresClient {
+            pool: lazy_pool("primary"),
+            read_pool: None,
+        };
+        assert_eq!(
+            client.read_pool().connect_options().get_database(),
+            Some("primary")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reads_use_replica_when_configured() {
+        let client = PostgresClient {
+            pool: lazy_pool("primary"),
+            read_pool: None,
+        }
+        .with_read_pool(lazy_pool("replica"));
+        assert_eq!(
+            client.read_pool().connect_options().get_database(),
+            Some("replica")
+        );
+        assert_eq!(
+            client.pool().connect_options().get_database(),
+            Some("primary")
+        );
+    }
+
+    fn config_from(vars: &[(&str, &str)]) -> Result<PostgresConfig, ConfigError> {
+        PostgresConfig::from_lookup(|key| {
+            vars.iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_string())
+        })
+    }
+
+    #[test]
+    fn test_postgres_config_from_env_defaults() {
+        let config = config_from(&[]).unwrap();
+        let default = PostgresConfig::default();
+        assert_eq!(config.max_connections, default.max_connections);
+        assert_eq!(config.min_connections, default.min_connections);
+        assert_eq!(config.acquire_timeout, default.acquire_timeout);
+        assert_eq!(config.idle_timeout, default.idle_timeout);
+    }
+
+    #[test]
+    fn test_postgres_config_from_env_overrides() {
+        let config = config_from(&[
+            ("DB_MAX_CONNECTIONS", "50"),
+            ("DB_MIN_CONNECTIONS", "5"),
+            ("DB_ACQUIRE_TIMEOUT_SECS", "8"),
+            ("DB_IDLE_TIMEOUT_SECS", "120"),
+        ])
+        .unwrap();
+        assert_eq!(config.max_connections, 50);
+        assert_eq!(config.min_connections, 5);
+        assert_eq!(config.acquire_timeout, Duration::from_secs(8));
+        assert_eq!(config.idle_timeout, Duration::from_secs(120));
+        assert_eq!(config.max_lifetime, Duration::from_secs(1800));
+    }
+
+    #[test]
+    fn test_postgres_config_from_env_invalid() {
+        for vars in [
+            [("DB_MAX_CONNECTIONS", "many")],
+            [("DB_MAX_CONNECTIONS", "-1")],
+            [("DB_MAX_CONNECTIONS", "0")],
+            [("DB_MIN_CONNECTIONS", "99999999999")],
+            [("DB_ACQUIRE_TIMEOUT_SECS", "3s")],
+            [("DB_IDLE_TIMEOUT_SECS", "")],
+        ] {
+            assert!(
+                matches!(config_from(&vars), Err(ConfigError::InvalidValue { .. })),
+                "{:?} should be rejected",
+                vars
+            );
+        }
+    }
+
+    #[test]
+    fn test_postgres_config_min_exceeds_max() {
+        let result = config_from(&[("DB_MAX_CONNECTIONS", "4"), ("DB_MIN_CONNECTIONS", "5")]);
+        match result {
+            Err(ConfigError::InvalidValue { key, .. }) => assert_eq!(key, "DB_MIN_CONNECTIONS"),
+            other => panic!("expected InvalidValue, got {:?}", other),
+        }
+
+        // Raising only the minimum above the default maximum is rejected too
+        assert!(config_from(&[("DB_MIN_CONNECTIONS", "11")]).is_err());
+        assert!(config_from(&[("DB_MIN_CONNECTIONS", "10")]).is_ok());
+    }
 }
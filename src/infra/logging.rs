@@ -0,0 +1,261 @@
+//! Tracing subscriber setup.
+//!
+//! Logs are human-readable by default. With `LOG_FORMAT=json` every event is
+//! written as one JSON object per line: event fields (`transfer_id`,
+//! `signature`, `error`, ...) sit at the top level next to `timestamp`,
+//! `level`, `target` and `message`, and the fields of the enclosing spans are
+//! listed under `spans`.
+//!
+//! With `OTEL_EXPORTER_OTLP_ENDPOINT` set, spans are also exported over OTLP
+//! (HTTP/protobuf) to that collector, and W3C `traceparent` headers of
+//! incoming requests are honoured (see [`crate::api::request_id_middleware`]).
+//! Worker and crank spans are new traces; they carry the `correlation_id` of
+//! the request that created the transfer, which is the `request_id` of that
+//! request's `correlation` span.
+
+use std::str::FromStr;
+
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use tracing::Subscriber;
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{EnvFilter, Layer, fmt, layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Environment variable selecting the log format
+pub const LOG_FORMAT_ENV: &str = "LOG_FORMAT";
+
+/// Environment variable enabling OTLP trace export, e.g. `http://otel-collector:4318`
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+
+/// Service name reported with exported spans
+const OTEL_SERVICE_NAME: &str = "solana-compliance-relayer";
+
+/// Filter used when `RUST_LOG` is not set
+const DEFAULT_LOG_FILTER: &str = "info,tower_http=debug,sqlx=warn";
+
+/// Output format of the tracing subscriber
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Pretty,
+    /// One JSON object per line
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "pretty" | "text" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            other => Err(format!("Unknown log format: {}", other)),
+        }
+    }
+}
+
+impl LogFormat {
+    /// Read the format from `LOG_FORMAT`, defaulting to pretty
+    #[must_use]
+    pub fn from_env() -> Self {
+        std::env::var(LOG_FORMAT_ENV)
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Formatting layer for `format`, writing to `writer`
+pub fn fmt_layer<S, W>(format: LogFormat, writer: W) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    W: for<'w> MakeWriter<'w> + Send + Sync + 'static,
+{
+    match format {
+        LogFormat::Pretty => fmt::layer().with_writer(writer).boxed(),
+        LogFormat::Json => fmt::layer()
+            .json()
+            .flatten_event(true)
+            .with_current_span(false)
+            .with_span_list(true)
+            .with_writer(writer)
+            .boxed(),
+    }
+}
+
+/// Tracer provider exporting spans in batches to the OTLP collector at
+/// `endpoint` (the base URL; traces are posted to `/v1/traces`)
+pub fn otlp_tracer_provider(endpoint: &str) -> Result<SdkTracerProvider, String> {
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()
+        .map_err(|e| format!("Failed to create OTLP span exporter: {}", e))?;
+
+    Ok(SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(
+            Resource::builder()
+                .with_service_name(OTEL_SERVICE_NAME)
+                .build(),
+        )
+        .build())
+}
+
+/// Layer exporting spans through `provider`
+pub fn otel_layer<S>(provider: &SdkTracerProvider) -> Box<dyn Layer<S> + Send + Sync>
+where
+    S: Subscriber + for<'a> LookupSpan<'a> + Send + Sync,
+{
+    tracing_opentelemetry::layer()
+        .with_tracer(provider.tracer(OTEL_SERVICE_NAME))
+        .boxed()
+}
+
+/// Keeps OTLP export running; dropping it flushes the spans still buffered.
+/// Hold it until shutdown.
+#[must_use = "dropping the guard stops trace export"]
+pub struct TracingGuard {
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take()
+            && let Err(e) = provider.shutdown()
+        {
+            tracing::warn!(error = %e, "Failed to flush exported spans");
+        }
+    }
+}
+
+/// Install the global tracing subscriber, filtered by `RUST_LOG`.
+/// Spans are also exported over OTLP when `OTEL_EXPORTER_OTLP_ENDPOINT` is set;
+/// if the exporter cannot be created, the relayer only logs locally.
+///
+/// # Panics
+/// Panics if a global subscriber was already installed.
+pub fn init_tracing(format: LogFormat) -> TracingGuard {
+    let env_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_LOG_FILTER));
+
+    let endpoint = std::env::var(OTLP_ENDPOINT_ENV)
+        .ok()
+        .filter(|endpoint| !endpoint.trim().is_empty());
+    let provider = endpoint.as_deref().map(otlp_tracer_provider);
+    let (provider, otlp_error) = match provider {
+        Some(Ok(provider)) => (Some(provider), None),
+        Some(Err(e)) => (None, Some(e)),
+        None => (None, None),
+    };
+    if provider.is_some() {
+        opentelemetry::global::set_text_map_propagator(TraceContextPropagator::new());
+    }
+
+    tracing_subscriber::registry()
+        .with(env_filter)
+        .with(fmt_layer(format, std::io::stdout))
+        .with(provider.as_ref().map(otel_layer))
+        .init();
+
+    // Reported once the subscriber exists
+    if let Some(endpoint) = endpoint {
+        match otlp_error {
+            None => tracing::info!(endpoint = %endpoint, "Exporting traces over OTLP"),
+            Some(e) => tracing::warn!(error = %e, "OTLP trace export disabled"),
+        }
+    }
+
+    TracingGuard { provider }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use tracing::{info, info_span};
+
+    use super::*;
+
+    /// Writer collecting everything written into a shared buffer
+    #[derive(Clone, Default)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'w> MakeWriter<'w> for BufferWriter {
+        type Writer = Self;
+
+        fn make_writer(&'w self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_otlp_tracer_initializes_with_dummy_endpoint() {
+        // Nothing listens there: export fails in the background, never panics
+        let provider = otlp_tracer_provider("http://127.0.0.1:4318/").unwrap();
+        let subscriber = tracing_subscriber::registry().with(otel_layer(&provider));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("submit_transfer", transfer_id = "tr_123");
+            let _guard = span.enter();
+            info!("Transfer received");
+        });
+
+        drop(TracingGuard {
+            provider: Some(provider),
+        });
+    }
+
+    #[test]
+    fn test_log_format_parse() {
+        assert_eq!("json".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert_eq!(" JSON ".parse::<LogFormat>(), Ok(LogFormat::Json));
+        assert_eq!("pretty".parse::<LogFormat>(), Ok(LogFormat::Pretty));
+        assert!("xml".parse::<LogFormat>().is_err());
+    }
+
+    #[test]
+    fn test_json_output_has_structured_fields() {
+        let buffer = BufferWriter::default();
+        let subscriber =
+            tracing_subscriber::registry().with(fmt_layer(LogFormat::Json, buffer.clone()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = info_span!("process_submission", transfer_id = "tr_123");
+            let _guard = span.enter();
+            info!(
+                signature = "sig_abc",
+                retry_count = 2,
+                "Transfer successful"
+            );
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let line = output.lines().next().expect("one log line");
+        let event: serde_json::Value = serde_json::from_str(line).unwrap();
+
+        assert_eq!(event["level"], "INFO");
+        assert_eq!(event["message"], "Transfer successful");
+        assert_eq!(event["signature"], "sig_abc");
+        assert_eq!(event["retry_count"], 2);
+        assert!(event["timestamp"].is_string());
+        assert_eq!(event["spans"][0]["name"], "process_submission");
+        assert_eq!(event["spans"][0]["transfer_id"], "tr_123");
+    }
+}
@@ -0,0 +1,261 @@
+//! Prometheus metrics for transfers, compliance, privacy checks and submission.
+//!
+//! Metrics are recorded through the `metrics` facade and rendered in the
+//! Prometheus text format by the recorder installed with
+//! [`install_prometheus_recorder`]. Until a recorder is installed, recording
+//! is a no-op.
+
+use std::collections::HashSet;
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::time::Duration;
+
+use metrics::{counter, describe_counter, describe_gauge, describe_histogram, gauge, histogram};
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use tokio::sync::watch;
+use tracing::{debug, info, warn};
+
+use crate::domain::{AppError, BlockchainError, BlockchainStatus, DatabaseClient};
+use crate::infra::privacy::PrivacyAction;
+
+/// Transfers that reached a terminal status, labelled by `status`
+pub const TRANSFERS_TOTAL: &str = "relayer_transfers_total";
+
+/// Latency of compliance provider checks, labelled by `provider`
+pub const COMPLIANCE_CHECK_DURATION_SECONDS: &str = "relayer_compliance_check_duration_seconds";
+
+/// Transfers blocked by the internal blocklist, labelled by `party`
+pub const BLOCKLIST_HITS_TOTAL: &str = "relayer_blocklist_hits_total";
+
+/// Jito bundle submissions, labelled by `outcome`
+pub const JITO_BUNDLES_TOTAL: &str = "relayer_jito_bundles_total";
+
+/// Anonymity set health checks, labelled by `outcome` and `mint`
+pub const PRIVACY_CHECKS_TOTAL: &str = "relayer_privacy_checks_total";
+
+/// Recent activity observed by anonymity set health checks, labelled by `mint`
+pub const PRIVACY_RECENT_ACTIVITY: &str = "relayer_privacy_recent_activity";
+
+/// Transfers waiting for the background worker
+pub const PENDING_QUEUE_DEPTH: &str = "relayer_pending_queue_depth";
+
+/// Default interval between queue depth refreshes
+pub const DEFAULT_METRICS_REFRESH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Buckets for compliance check latency (seconds)
+const COMPLIANCE_LATENCY_BUCKETS: &[f64] =
+    &[0.005, 0.01, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0];
+
+/// Buckets for recent activity counts (transactions)
+const PRIVACY_ACTIVITY_BUCKETS: &[f64] =
+    &[0.0, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 250.0, 500.0];
+
+/// Distinct mints labelled individually; further mints share the `other`
+/// label so arbitrary mints cannot grow the series without bound
+const MAX_MINT_LABELS: usize = 20;
+
+static MINT_LABELS: LazyLock<Mutex<HashSet<String>>> = LazyLock::new(Default::default);
+
+static PROMETHEUS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Install the Prometheus recorder as the global `metrics` recorder.
+///
+/// Idempotent: later calls return a handle to the already installed recorder.
+///
+/// # Panics
+/// Panics if a different global recorder was installed first.
+pub fn install_prometheus_recorder() -> PrometheusHandle {
+    PROMETHEUS_HANDLE
+        .get_or_init(|| {
+            let recorder = PrometheusBuilder::new()
+                .set_buckets_for_metric(
+                    Matcher::Full(COMPLIANCE_CHECK_DURATION_SECONDS.to_string()),
+                    COMPLIANCE_LATENCY_BUCKETS,
+                )
+                .expect("Compliance latency buckets should not be empty")
+                .set_buckets_for_metric(
+                    Matcher::Full(PRIVACY_RECENT_ACTIVITY.to_string()),
+                    PRIVACY_ACTIVITY_BUCKETS,
+                )
+                .expect("Privacy activity buckets should not be empty")
+                .build_recorder();
+            let handle = recorder.handle();
+            if metrics::set_global_recorder(recorder).is_err() {
+                panic!("Another global metrics recorder is already installed");
+            }
+            describe_metrics();
+            handle
+        })
+        .clone()
+}
+
+fn describe_metrics() {
+    describe_counter!(
+        TRANSFERS_TOTAL,
+        "Transfers that reached a terminal blockchain status"
+    );
+    describe_histogram!(
+        COMPLIANCE_CHECK_DURATION_SECONDS,
+        metrics::Unit::Seconds,
+        "Latency of compliance provider checks"
+    );
+    describe_counter!(
+        BLOCKLIST_HITS_TOTAL,
+        "Transfers blocked by the internal blocklist"
+    );
+    describe_counter!(JITO_BUNDLES_TOTAL, "Jito bundle submissions by outcome");
+    describe_counter!(
+        PRIVACY_CHECKS_TOTAL,
+        "Anonymity set health checks by outcome"
+    );
+    describe_histogram!(
+        PRIVACY_RECENT_ACTIVITY,
+        "Recent transactions observed by anonymity set health checks"
+    );
+    describe_gauge!(
+        PENDING_QUEUE_DEPTH,
+        "Transfers waiting for the background worker"
+    );
+}
+
+/// Count a transfer reaching `status`. Non-terminal statuses are ignored.
+pub fn record_transfer_status(status: BlockchainStatus) {
+    if status.is_terminal() {
+        counter!(TRANSFERS_TOTAL, "status" => status.as_str()).increment(1);
+    }
+}
+
+/// Record the latency of one compliance check
+pub fn record_compliance_check(provider: &'static str, elapsed: Duration) {
+    histogram!(COMPLIANCE_CHECK_DURATION_SECONDS, "provider" => provider)
+        .record(elapsed.as_secs_f64());
+}
+
+/// Count a blocklist hit for the `sender` or `recipient` of a transfer
+pub fn record_blocklist_hit(party: &'static str) {
+    counter!(BLOCKLIST_HITS_TOTAL, "party" => party).increment(1);
+}
+
+/// Count the outcome of a Jito bundle submission
+pub fn record_jito_bundle_outcome<T>(result: &Result<T, AppError>) {
+    counter!(JITO_BUNDLES_TOTAL, "outcome" => jito_bundle_outcome(result)).increment(1);
+}
+
+/// Record the outcome of an anonymity set health check and the activity
+/// it observed for `token_mint`
+pub fn record_privacy_check(token_mint: &str, action: PrivacyAction, recent_tx_count: u64) {
+    let mint = mint_label(token_mint);
+    counter!(PRIVACY_CHECKS_TOTAL, "outcome" => action.as_str(), "mint" => mint.clone())
+        .increment(1);
+    histogram!(PRIVACY_RECENT_ACTIVITY, "mint" => mint).record(recent_tx_count as f64);
+}
+
+/// Label for `token_mint`: the mint itself while fewer than
+/// [`MAX_MINT_LABELS`] mints have been seen, `other` afterwards
+fn mint_label(token_mint: &str) -> String {
+    let mut seen = MINT_LABELS.lock().unwrap_or_else(|e| e.into_inner());
+    if seen.contains(token_mint) {
+        return token_mint.to_string();
+    }
+    if seen.len() < MAX_MINT_LABELS {
+        seen.insert(token_mint.to_string());
+        return token_mint.to_string();
+    }
+    "other".to_string()
+}
+
+fn jito_bundle_outcome<T>(result: &Result<T, AppError>) -> &'static str {
+    match result {
+        Ok(_) => "accepted",
+        Err(AppError::Blockchain(BlockchainError::JitoBundleFailed(_))) => "rejected",
+        Err(AppError::Blockchain(BlockchainError::JitoStateUnknown(_))) => "state_unknown",
+        Err(AppError::Blockchain(BlockchainError::PrivateSubmissionFallback(_))) => "unavailable",
+        Err(_) => "error",
+    }
+}
+
+/// Spawn a task that refreshes the pending-queue gauge from the database
+/// and runs recorder upkeep every `interval`.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_metrics_refresh(
+    db_client: Arc<dyn DatabaseClient>,
+    handle: PrometheusHandle,
+    interval: Duration,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+    let task = tokio::spawn(async move {
+        debug!(interval = ?interval, "Starting metrics refresh");
+        loop {
+            match db_client.count_pending_blockchain_requests().await {
+                Ok(depth) => gauge!(PENDING_QUEUE_DEPTH).set(depth as f64),
+                Err(e) => warn!(error = %e, "Failed to count pending transfers for metrics"),
+            }
+            // Drains histogram buckets; required when the recorder is installed manually
+            handle.run_upkeep();
+
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                result = shutdown_rx.changed() => {
+                    if result.is_ok() && *shutdown_rx.borrow() {
+                        info!("Metrics refresh shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    (task, shutdown_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jito_bundle_outcome_labels() {
+        let cases: [(Result<(), AppError>, &str); 5] = [
+            (Ok(()), "accepted"),
+            (
+                Err(AppError::Blockchain(BlockchainError::JitoBundleFailed(
+                    "dropped".to_string(),
+                ))),
+                "rejected",
+            ),
+            (
+                Err(AppError::Blockchain(BlockchainError::JitoStateUnknown(
+                    "timeout".to_string(),
+                ))),
+                "state_unknown",
+            ),
+            (
+                Err(AppError::Blockchain(
+                    BlockchainError::PrivateSubmissionFallback("no jito".to_string()),
+                )),
+                "unavailable",
+            ),
+            (Err(AppError::Internal("other".to_string())), "error"),
+        ];
+        for (result, expected) in cases {
+            assert_eq!(jito_bundle_outcome(&result), expected);
+        }
+    }
+
+    #[test]
+    fn test_recorder_renders_recorded_metrics() {
+        let handle = install_prometheus_recorder();
+        record_transfer_status(BlockchainStatus::Confirmed);
+        record_transfer_status(BlockchainStatus::Submitted);
+        record_compliance_check("test", Duration::from_millis(20));
+
+        let rendered = handle.render();
+        assert!(rendered.contains(r#"relayer_transfers_total{status="confirmed"}"#));
+        assert!(!rendered.contains(r#"status="submitted""#));
+        assert!(rendered.contains(COMPLIANCE_CHECK_DURATION_SECONDS));
+
+        // A second install returns the same recorder
+        assert!(
+            install_prometheus_recorder()
+                .render()
+                .contains(TRANSFERS_TOTAL)
+        );
+    }
+}
@@ -1,13 +1,27 @@
 //! Infrastructure layer implementations.
 
+pub mod alerting;
+pub mod allowlist;
 pub mod blockchain;
 pub mod blocklist;
 pub mod compliance;
 pub mod database;
+pub mod logging;
+pub mod metrics;
+pub mod outbound_webhooks;
 pub mod privacy;
 
+pub use alerting::{AlertDispatcher, DiscordWebhookSink, RejectionRateDetector, SlackWebhookSink};
+pub use allowlist::{AllowlistEntry, AllowlistManager};
 pub use blockchain::{RpcBlockchainClient, RpcClientConfig, signing_key_from_base58};
-pub use blocklist::{BlocklistEntry, BlocklistManager};
-pub use compliance::RangeComplianceProvider;
+pub use blocklist::{BatchUpsertSummary, BlocklistEntry, BlocklistHits, BlocklistManager};
+pub use compliance::{
+    CompositeComplianceProvider, CompositionPolicy, RangeComplianceProvider, TrmComplianceProvider,
+};
 pub use database::{PostgresClient, PostgresConfig};
-pub use privacy::{AnonymitySetHealth, PrivacyHealthCheckConfig, PrivacyHealthCheckService};
+pub use outbound_webhooks::{
+    OutboundWebhookConfig, WebhookDispatcher, is_notified_status, spawn_webhook_dispatcher,
+};
+pub use privacy::{
+    AnonymitySetHealth, PrivacyAction, PrivacyHealthCheckConfig, PrivacyHealthCheckService,
+};
@@ -0,0 +1,751 @@
+//! Outbound webhook notifications of transfer status changes.
+//!
+//! When a transfer becomes `submitted`, `confirmed`, `failed` or `expired`,
+//! the service queues a [`WebhookDelivery`] for the transfer's callback URL
+//! (or the global one). [`WebhookDispatcher`] POSTs due deliveries as JSON,
+//! signed with HMAC-SHA256 of the body under the shared secret
+//! (`X-Relayer-Signature: sha256=<hex>`), and retries non-2xx responses with
+//! exponential backoff. Delivery state lives in the database, so a delivered
+//! event is not sent again after a restart.
+//!
+//! A transfer's own callback URL is chosen by whoever submits it, so it must
+//! not reach internal services: it is refused at submission if its host is
+//! a loopback, private or link-local address, and again at delivery if the
+//! host resolves to one. Redirects are not followed. The global URL is set
+//! by the operator and trusted.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use futures_util::{StreamExt, stream};
+use hmac::{Hmac, KeyInit, Mac};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::redirect::Policy;
+use reqwest::{Client, Url};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+use crate::domain::{
+    AppError, BlockchainStatus, DatabaseClient, TransferStatusEvent, WebhookDelivery,
+    WebhookDeliveryStatus,
+};
+
+/// Header carrying `sha256=<hex HMAC-SHA256 of the body>`
+pub const SIGNATURE_HEADER: &str = "X-Relayer-Signature";
+
+/// Header carrying the delivery ID, stable across attempts
+pub const DELIVERY_ID_HEADER: &str = "X-Relayer-Delivery-Id";
+
+/// Header carrying the attempt number, starting at 1
+pub const DELIVERY_ATTEMPT_HEADER: &str = "X-Relayer-Delivery-Attempt";
+
+/// Default interval between dispatcher polls
+pub const DEFAULT_OUTBOUND_WEBHOOK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Default number of attempts before a delivery is given up
+pub const DEFAULT_OUTBOUND_WEBHOOK_MAX_ATTEMPTS: i32 = 8;
+
+/// Default delay before the first retry; doubles with every further attempt
+pub const DEFAULT_OUTBOUND_WEBHOOK_BASE_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Longest delay between two attempts
+const MAX_BACKOFF: Duration = Duration::from_secs(3600);
+
+/// Deliveries claimed per poll
+const DISPATCH_BATCH_SIZE: i64 = 50;
+
+/// Deliveries of a claimed batch attempted at once
+const DISPATCH_CONCURRENCY: usize = 10;
+
+/// Timeout of one delivery attempt, resolving the host included
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a claimed delivery is hidden from other dispatchers
+const CLAIM_LEASE_SECS: i64 = 60;
+
+// A full batch of timed-out attempts must still finish within the lease
+const _: () = assert!(
+    (DISPATCH_BATCH_SIZE as u64).div_ceil(DISPATCH_CONCURRENCY as u64) * DELIVERY_TIMEOUT.as_secs()
+        < CLAIM_LEASE_SECS as u64
+);
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Configuration for outbound webhooks
+#[derive(Clone)]
+pub struct OutboundWebhookConfig {
+    /// Shared secret signing every payload
+    pub secret: String,
+    /// Callback URL for transfers submitted without their own
+    pub default_url: Option<String>,
+    /// Interval between dispatcher polls
+    pub poll_interval: Duration,
+    /// Attempts before a delivery is marked failed
+    pub max_attempts: i32,
+    /// Delay before the first retry
+    pub base_backoff: Duration,
+    /// Deliver to per-transfer callback URLs on loopback and private
+    /// networks (local development only)
+    pub allow_private_callback_urls: bool,
+}
+
+impl OutboundWebhookConfig {
+    /// Configuration signing with `secret`, with default timings
+    #[must_use]
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: secret.into(),
+            default_url: None,
+            poll_interval: DEFAULT_OUTBOUND_WEBHOOK_POLL_INTERVAL,
+            max_attempts: DEFAULT_OUTBOUND_WEBHOOK_MAX_ATTEMPTS,
+            base_backoff: DEFAULT_OUTBOUND_WEBHOOK_BASE_BACKOFF,
+            allow_private_callback_urls: false,
+        }
+    }
+
+    /// Set the global callback URL (builder pattern)
+    #[must_use]
+    pub fn with_default_url(mut self, default_url: Option<String>) -> Self {
+        self.default_url = default_url;
+        self
+    }
+}
+
+/// JSON body POSTed to the callback URL
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OutboundWebhookPayload {
+    /// Delivery ID, identical across retries (use it to deduplicate)
+    pub delivery_id: String,
+    /// Attempt number, starting at 1
+    pub attempt: i32,
+    /// The status change: transfer `id`, `blockchain_status`, signature,
+    /// last error and `updated_at`
+    #[serde(flatten)]
+    pub event: TransferStatusEvent,
+}
+
+/// Whether reaching `status` notifies the callback URL
+#[must_use]
+pub fn is_notified_status(status: BlockchainStatus) -> bool {
+    matches!(
+        status,
+        BlockchainStatus::Submitted
+            | BlockchainStatus::AwaitingApproval
+            | BlockchainStatus::Confirmed
+            | BlockchainStatus::Failed
+            | BlockchainStatus::Rejected
+            | BlockchainStatus::Unreconciled
+            | BlockchainStatus::Expired
+    )
+}
+
+/// Hex HMAC-SHA256 of `body` under `secret`
+#[must_use]
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Check that a per-transfer callback URL is an http(s) URL whose host is
+/// not a loopback, private, link-local or otherwise non-public address.
+/// Host names are checked against `localhost` only; where they resolve is
+/// checked at delivery.
+///
+/// # Errors
+/// Returns why the URL is refused.
+pub fn validate_callback_url(url: &str) -> Result<(), String> {
+    let parsed = Url::parse(url).map_err(|e| format!("Invalid callback URL: {}", e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err("Callback URL must be an http(s) URL".to_string());
+    }
+    let host = callback_host(&parsed)?;
+    let refused = match host.parse::<IpAddr>() {
+        Ok(ip) => !is_public_ip(ip),
+        Err(_) => {
+            let host = host.trim_end_matches('.').to_ascii_lowercase();
+            host == "localhost" || host.ends_with(".localhost")
+        }
+    };
+    if refused {
+        return Err(format!(
+            "Callback URL host {} is not a public address",
+            host
+        ));
+    }
+    Ok(())
+}
+
+/// Host of a callback URL, without the brackets of an IPv6 literal
+fn callback_host(url: &Url) -> Result<&str, String> {
+    url.host_str()
+        .map(|host| host.trim_start_matches('[').trim_end_matches(']'))
+        .filter(|host| !host.is_empty())
+        .ok_or_else(|| "Callback URL has no host".to_string())
+}
+
+/// Whether `ip` is routable on the public internet
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            // 0.0.0.0/8 and the 100.64.0.0/10 carrier-grade NAT range
+            let reserved = first == 0 || (first == 100 && second & 0xc0 == 64);
+            !(reserved
+                || ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_broadcast()
+                || ip.is_documentation())
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_ip(IpAddr::V4(mapped)),
+            None => {
+                let first = ip.segments()[0];
+                // fc00::/7 unique local and fe80::/10 link-local
+                let local = first & 0xfe00 == 0xfc00 || first & 0xffc0 == 0xfe80;
+                !(local || ip.is_loopback() || ip.is_unspecified())
+            }
+        },
+    }
+}
+
+/// Delay before the attempt following attempt number `attempt`
+fn retry_backoff(base: Duration, attempt: i32) -> Duration {
+    let exponent = attempt.saturating_sub(1).clamp(0, 16) as u32;
+    base.saturating_mul(2u32.pow(exponent)).min(MAX_BACKOFF)
+}
+
+/// Background service delivering queued webhook notifications
+pub struct WebhookDispatcher {
+    db_client: Arc<dyn DatabaseClient>,
+    http_client: Client,
+    /// Client for per-transfer callback URLs; only connects to public
+    /// addresses unless private callback URLs are allowed
+    callback_client: Client,
+    config: OutboundWebhookConfig,
+}
+
+/// Resolver that drops non-public addresses, so a callback host cannot be
+/// pointed at an internal service after it was validated
+struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_ip(addr.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to a public address", host).into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+impl WebhookDispatcher {
+    /// Create a new dispatcher
+    pub fn new(db_client: Arc<dyn DatabaseClient>, config: OutboundWebhookConfig) -> Self {
+        let builder = || {
+            Client::builder()
+                .timeout(DELIVERY_TIMEOUT)
+                .redirect(Policy::none())
+        };
+        let http_client = builder().build().expect("Failed to create HTTP client");
+        let callback_client = if config.allow_private_callback_urls {
+            http_client.clone()
+        } else {
+            builder()
+                .dns_resolver(PublicAddressResolver)
+                .build()
+                .expect("Failed to create HTTP client")
+        };
+
+        Self {
+            db_client,
+            http_client,
+            callback_client,
+            config,
+        }
+    }
+
+    /// Run the dispatch loop until shutdown is signalled
+    pub async fn run(self, mut shutdown_rx: watch::Receiver<bool>) {
+        info!(
+            poll_interval = ?self.config.poll_interval,
+            max_attempts = self.config.max_attempts,
+            "Starting outbound webhook dispatcher"
+        );
+
+        loop {
+            match self.dispatch_due().await {
+                Ok(0) => {}
+                Ok(count) => debug!(count, "Dispatched outbound webhooks"),
+                Err(e) => error!(error = ?e, "Error dispatching outbound webhooks"),
+            }
+
+            tokio::select! {
+                _ = tokio::time::sleep(self.config.poll_interval) => {}
+                result = shutdown_rx.changed() => {
+                    if result.is_ok() && *shutdown_rx.borrow() {
+                        info!("Outbound webhook dispatcher shutting down");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Attempt every due delivery once.
+    /// Returns the number of deliveries attempted.
+    pub async fn dispatch_due(&self) -> Result<usize, AppError> {
+        let deliveries = self
+            .db_client
+            .claim_due_webhook_deliveries(DISPATCH_BATCH_SIZE, CLAIM_LEASE_SECS)
+            .await?;
+        let count = deliveries.len();
+
+        stream::iter(deliveries)
+            .for_each_concurrent(DISPATCH_CONCURRENCY, |mut delivery| async move {
+                self.attempt(&mut delivery).await;
+                if let Err(e) = self.db_client.update_webhook_delivery(&delivery).await {
+                    error!(
+                        delivery_id = %delivery.id,
+                        error = ?e,
+                        "Failed to record outbound webhook attempt"
+                    );
+                }
+            })
+            .await;
+
+        Ok(count)
+    }
+
+    /// POST the delivery once and update its state from the outcome
+    async fn attempt(&self, delivery: &mut WebhookDelivery) {
+        delivery.attempts += 1;
+        let payload = OutboundWebhookPayload {
+            delivery_id: delivery.id.clone(),
+            attempt: delivery.attempts,
+            event: delivery.event.clone(),
+        };
+
+        if let Err(reason) = self.check_callback_url(&delivery.url) {
+            warn!(
+                transfer_id = %delivery.transfer_id,
+                delivery_id = %delivery.id,
+                error = %reason,
+                "Outbound webhook refused"
+            );
+            delivery.status = WebhookDeliveryStatus::Failed;
+            delivery.last_error = Some(reason);
+            return;
+        }
+
+        match self.post(delivery, &payload).await {
+            Ok(()) => {
+                info!(
+                    transfer_id = %delivery.transfer_id,
+                    delivery_id = %delivery.id,
+                    status = %delivery.event.blockchain_status,
+                    attempt = delivery.attempts,
+                    "Outbound webhook delivered"
+                );
+                delivery.status = WebhookDeliveryStatus::Delivered;
+                delivery.last_error = None;
+            }
+            Err(reason) if delivery.attempts >= self.config.max_attempts => {
+                error!(
+                    transfer_id = %delivery.transfer_id,
+                    delivery_id = %delivery.id,
+                    attempt = delivery.attempts,
+                    error = %reason,
+                    "Outbound webhook failed, giving up"
+                );
+                delivery.status = WebhookDeliveryStatus::Failed;
+                delivery.last_error = Some(reason);
+            }
+            Err(reason) => {
+                let backoff = retry_backoff(self.config.base_backoff, delivery.attempts);
+                warn!(
+                    transfer_id = %delivery.transfer_id,
+                    delivery_id = %delivery.id,
+                    attempt = delivery.attempts,
+                    retry_in = ?backoff,
+                    error = %reason,
+                    "Outbound webhook failed, will retry"
+                );
+                delivery.next_attempt_at =
+                    Utc::now() + chrono::Duration::milliseconds(backoff.as_millis() as i64);
+                delivery.last_error = Some(reason);
+            }
+        }
+    }
+
+    /// Refuse a per-transfer callback URL on a non-public host. Deliveries
+    /// queued before submissions were validated are checked here too.
+    fn check_callback_url(&self, url: &str) -> Result<(), String> {
+        if self.config.allow_private_callback_urls || self.is_default_url(url) {
+            return Ok(());
+        }
+        validate_callback_url(url)
+    }
+
+    fn is_default_url(&self, url: &str) -> bool {
+        self.config.default_url.as_deref() == Some(url)
+    }
+
+    /// Send one signed request; any non-2xx response is an error
+    async fn post(
+        &self,
+        delivery: &WebhookDelivery,
+        payload: &OutboundWebhookPayload,
+    ) -> Result<(), String> {
+        let client = if self.is_default_url(&delivery.url) {
+            &self.http_client
+        } else {
+            &self.callback_client
+        };
+        let request = signed_request(client, &self.config.secret, &delivery.url, payload)?
+            .header(DELIVERY_ID_HEADER, &delivery.id)
+            .header(DELIVERY_ATTEMPT_HEADER, payload.attempt.to_string());
+        send(request).await
+    }
+}
+
+/// POST request carrying `payload` as JSON, signed under `secret`
+fn signed_request(
+    http_client: &Client,
+    secret: &str,
+    url: &str,
+    payload: &impl Serialize,
+) -> Result<reqwest::RequestBuilder, String> {
+    let body = serde_json::to_vec(payload).map_err(|e| e.to_string())?;
+    let signature = sign_payload(secret, &body);
+    Ok(http_client
+        .post(url)
+        .header("Content-Type", "application/json")
+        .header(SIGNATURE_HEADER, format!("sha256={}", signature))
+        .body(body))
+}
+
+/// Send `request`; any non-2xx response is an error
+async fn send(request: reqwest::RequestBuilder) -> Result<(), String> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Request failed: {}", e))?;
+
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else {
+        Err(format!("Receiver responded with HTTP {}", status.as_u16()))
+    }
+}
+
+/// POST a one-off operational alert to the global callback URL, signed like
+/// status notifications. Alerts are not queued or retried.
+///
+/// # Errors
+/// Returns a description of the failure if no global URL is configured, the
+/// request fails or the receiver responds with a non-2xx status.
+pub async fn send_alert(
+    http_client: &Client,
+    config: &OutboundWebhookConfig,
+    payload: &impl Serialize,
+) -> Result<(), String> {
+    let url = config
+        .default_url
+        .as_deref()
+        .ok_or_else(|| "No global callback URL configured".to_string())?;
+    send(signed_request(http_client, &config.secret, url, payload)?).await
+}
+
+/// Spawn the webhook dispatcher as a tokio task.
+/// Returns the task handle and a shutdown sender.
+pub fn spawn_webhook_dispatcher(
+    db_client: Arc<dyn DatabaseClient>,
+    config: OutboundWebhookConfig,
+) -> (tokio::task::JoinHandle<()>, watch::Sender<bool>) {
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let dispatcher = WebhookDispatcher::new(db_client, config);
+    let handle = tokio::spawn(dispatcher.run(shutdown_rx));
+    (handle, shutdown_tx)
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::test_utils::MockDatabaseClient;
+
+    const SECRET: &str = "whsec_test_secret";
+
+    fn event(status: BlockchainStatus) -> TransferStatusEvent {
+        TransferStatusEvent {
+            id: "tr_123".to_string(),
+            blockchain_status: status,
+            blockchain_signature: Some("sig_abc".to_string()),
+            blockchain_last_error: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn dispatcher(db: &Arc<MockDatabaseClient>, max_attempts: i32) -> WebhookDispatcher {
+        let config = OutboundWebhookConfig {
+            max_attempts,
+            base_backoff: Duration::ZERO,
+            // Receivers are mock servers on loopback
+            allow_private_callback_urls: true,
+            ..OutboundWebhookConfig::new(SECRET)
+        };
+        WebhookDispatcher::new(Arc::clone(db) as _, config)
+    }
+
+    async fn queue(db: &MockDatabaseClient, url: String) -> WebhookDelivery {
+        let delivery = WebhookDelivery::new(url, event(BlockchainStatus::Confirmed));
+        assert!(db.enqueue_webhook_delivery(&delivery).await.unwrap());
+        delivery
+    }
+
+    #[test]
+    fn test_sign_payload_known_vector() {
+        // Same vector as the inbound webhook verifier
+        assert_eq!(
+            sign_payload(SECRET, br#"{"signature":"sig_123"}"#),
+            "397fe083fdac422d50c084d544d78fa848b7ff7f5d67d287e45eb6efb4a0c8b4"
+        );
+    }
+
+    #[test]
+    fn test_retry_backoff_doubles_up_to_cap() {
+        let base = Duration::from_secs(10);
+        assert_eq!(retry_backoff(base, 1), Duration::from_secs(10));
+        assert_eq!(retry_backoff(base, 2), Duration::from_secs(20));
+        assert_eq!(retry_backoff(base, 4), Duration::from_secs(80));
+        assert_eq!(retry_backoff(base, 30), MAX_BACKOFF);
+    }
+
+    #[test]
+    fn test_validate_callback_url() {
+        for url in [
+            "https://example.com/hook",
+            "http://93.184.216.34:8080/hook",
+            "https://[2606:2800:220:1::1]/hook",
+        ] {
+            assert!(
+                validate_callback_url(url).is_ok(),
+                "{} should be allowed",
+                url
+            );
+        }
+        for url in [
+            "ftp://example.com/hook",
+            "not a url",
+            "http://localhost:8080/hook",
+            "http://api.localhost/hook",
+            "http://127.0.0.1/hook",
+            "http://10.0.0.5/hook",
+            "http://172.16.0.1/hook",
+            "http://192.168.1.1/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://100.64.0.1/hook",
+            "http://0.0.0.0/hook",
+            "http://[::1]/hook",
+            "http://[fd00::1]/hook",
+            "http://[fe80::1]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+        ] {
+            assert!(
+                validate_callback_url(url).is_err(),
+                "{} should be refused",
+                url
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_private_callback_refused_unless_global() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let db = Arc::new(MockDatabaseClient::new());
+        let global = queue(&db, server.uri()).await;
+        let callback = WebhookDelivery::new(
+            format!("{}/hook", server.uri()),
+            event(BlockchainStatus::Submitted),
+        );
+        assert!(db.enqueue_webhook_delivery(&callback).await.unwrap());
+        let config = OutboundWebhookConfig::new(SECRET).with_default_url(Some(server.uri()));
+        let dispatcher = WebhookDispatcher::new(Arc::clone(&db) as _, config);
+
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 2);
+
+        let deliveries = db.webhook_deliveries();
+        let stored = |id: &str| deliveries.iter().find(|d| d.id == id).unwrap();
+        // The operator's global URL is trusted
+        assert_eq!(stored(&global.id).status, WebhookDeliveryStatus::Delivered);
+        // A per-transfer callback on loopback fails without a request or retry
+        let refused = stored(&callback.id);
+        assert_eq!(refused.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(refused.attempts, 1);
+        assert!(
+            refused
+                .last_error
+                .as_deref()
+                .unwrap()
+                .contains("not a public address")
+        );
+    }
+
+    #[test]
+    fn test_notified_statuses() {
+        assert!(is_notified_status(BlockchainStatus::Submitted));
+        assert!(is_notified_status(BlockchainStatus::AwaitingApproval));
+        assert!(is_notified_status(BlockchainStatus::Confirmed));
+        assert!(is_notified_status(BlockchainStatus::Failed));
+        assert!(is_notified_status(BlockchainStatus::Rejected));
+        assert!(is_notified_status(BlockchainStatus::Unreconciled));
+        assert!(is_notified_status(BlockchainStatus::Expired));
+        assert!(!is_notified_status(BlockchainStatus::PendingSubmission));
+        assert!(!is_notified_status(BlockchainStatus::Cancelled));
+    }
+
+    #[tokio::test]
+    async fn test_delivers_signed_payload_once() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/hook"))
+            .and(header(DELIVERY_ATTEMPT_HEADER, "1"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let db = Arc::new(MockDatabaseClient::new());
+        let delivery = queue(&db, format!("{}/hook", server.uri())).await;
+        let dispatcher = dispatcher(&db, 3);
+
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 1);
+        // Delivered events are not sent again
+        assert_eq!(dispatcher.dispatch_due().await.unwrap(), 0);
+
+        let requests = server.received_requests().await.unwrap();
+        let request = &requests[0];
+        let signature = request.headers[SIGNATURE_HEADER].to_str().unwrap();
+        assert_eq!(
+            signature,
+            format!("sha256={}", sign_payload(SECRET, &request.body))
+        );
+        assert_eq!(request.headers[DELIVERY_ID_HEADER], delivery.id.as_str());
+
+        let payload: OutboundWebhookPayload = serde_json::from_slice(&request.body).unwrap();
+        assert_eq!(payload.delivery_id, delivery.id);
+        assert_eq!(payload.attempt, 1);
+        assert_eq!(payload.event.id, "tr_123");
+        assert_eq!(payload.event.blockchain_status, BlockchainStatus::Confirmed);
+
+        let stored = &db.webhook_deliveries()[0];
+        assert_eq!(stored.status, WebhookDeliveryStatus::Delivered);
+        assert_eq!(stored.attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_retries_on_non_2xx() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&server)
+            .await;
+
+        let db = Arc::new(MockDatabaseClient::new());
+        queue(&db, server.uri()).await;
+        let dispatcher = dispatcher(&db, 5);
+
+        for _ in 0..2 {
+            dispatcher.dispatch_due().await.unwrap();
+            let stored = &db.webhook_deliveries()[0];
+            assert_eq!(stored.status, WebhookDeliveryStatus::Pending);
+            assert_eq!(
+                stored.last_error.as_deref(),
+                Some("Receiver responded with HTTP 503")
+            );
+        }
+        dispatcher.dispatch_due().await.unwrap();
+
+        let stored = &db.webhook_deliveries()[0];
+        assert_eq!(stored.status, WebhookDeliveryStatus::Delivered);
+        assert_eq!(stored.attempts, 3);
+        assert_eq!(stored.last_error, None);
+
+        // The attempt counter increases with every request
+        let attempts: Vec<String> = server
+            .received_requests()
+            .await
+            .unwrap()
+            .iter()
+            .map(|r| {
+                r.headers[DELIVERY_ATTEMPT_HEADER]
+                    .to_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(attempts, ["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let db = Arc::new(MockDatabaseClient::new());
+        queue(&db, server.uri()).await;
+        let dispatcher = dispatcher(&db, 2);
+
+        for _ in 0..3 {
+            dispatcher.dispatch_due().await.unwrap();
+        }
+
+        let stored = &db.webhook_deliveries()[0];
+        assert_eq!(stored.status, WebhookDeliveryStatus::Failed);
+        assert_eq!(stored.attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_same_event_queued_once() {
+        let db = MockDatabaseClient::new();
+        queue(&db, "https://example.com/hook".to_string()).await;
+
+        let duplicate = WebhookDelivery::new(
+            "https://example.com/hook",
+            event(BlockchainStatus::Confirmed),
+        );
+        assert!(!db.enqueue_webhook_delivery(&duplicate).await.unwrap());
+        assert_eq!(db.webhook_deliveries().len(), 1);
+    }
+}
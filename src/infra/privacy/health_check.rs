@@ -7,8 +7,15 @@
 //! # How It Works
 //! 1. Before submitting a confidential transfer, the worker queries this service
 //! 2. The service fetches recent transaction activity for the token mint
-//! 3. If activity is below the threshold, a randomized delay is recommended
+//! 3. Activity and, when reported, holder count are combined into an
+//!    anonymity score; below a full score a randomized delay is recommended
 //! 4. The transaction is postponed to blend with future network activity
+//! 5. If activity is below the minimum acceptable count, the transfer is rejected
+//! 6. Every confidential transfer additionally waits a random jitter within
+//!    the configured window, so submission time does not reveal dequeue time
+//!
+//! Each completed check is recorded in the `relayer_privacy_checks_total`
+//! counter and the `relayer_privacy_recent_activity` histogram.
 //!
 //! # Graceful Degradation
 //! If the Token API is unavailable or returns an error, the check is skipped
@@ -16,9 +23,12 @@
 
 use chrono::{DateTime, Utc};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing::{debug, info, warn};
 
+use crate::domain::AppError;
 use crate::infra::blockchain::quicknode::QuickNodeTokenApiClient;
+use crate::infra::metrics;
 
 // ============================================================================
 // CONFIGURATION
@@ -29,12 +39,23 @@ use crate::infra::blockchain::quicknode::QuickNodeTokenApiClient;
 pub struct PrivacyHealthCheckConfig {
     /// Minimum number of recent transactions to consider "healthy"
     pub min_tx_threshold: u64,
+    /// Transfers are rejected below this many recent transactions (0 = never reject)
+    pub min_acceptable_tx_count: u64,
+    /// Number of token holders considered "healthy"
+    pub min_holder_count: u64,
+    /// Share of the anonymity score taken from the holder count (0.0 - 1.0);
+    /// the rest comes from recent activity
+    pub holder_weight: f64,
     /// Lookback window in minutes for activity assessment
     pub lookback_minutes: u64,
     /// Maximum delay in seconds when activity is low
     pub max_delay_secs: u64,
     /// Minimum delay in seconds when activity is low
     pub min_delay_secs: u64,
+    /// Lower bound of the jitter applied before every confidential transfer (ms)
+    pub jitter_min_ms: u64,
+    /// Upper bound of the jitter applied before every confidential transfer (ms)
+    pub jitter_max_ms: u64,
     /// Whether the health check is enabled
     pub enabled: bool,
 }
@@ -42,10 +63,15 @@ pub struct PrivacyHealthCheckConfig {
 impl Default for PrivacyHealthCheckConfig {
     fn default() -> Self {
         Self {
-            min_tx_threshold: 5,  // Require 5+ transactions
-            lookback_minutes: 10, // In the last 10 minutes
-            max_delay_secs: 120,  // Max 2 minute delay
-            min_delay_secs: 10,   // Min 10 second delay
+            min_tx_threshold: 5,        // Require 5+ transactions
+            min_acceptable_tx_count: 0, // Delay, never reject
+            min_holder_count: 100,      // Require 100+ holders
+            holder_weight: 0.5,         // Holders and activity weigh equally
+            lookback_minutes: 10,       // In the last 10 minutes
+            max_delay_secs: 120,        // Max 2 minute delay
+            min_delay_secs: 10,         // Min 10 second delay
+            jitter_min_ms: 500,         // Jitter window: 0.5s
+            jitter_max_ms: 3_000,       // ... to 3s
             enabled: true,
         }
     }
@@ -69,6 +95,22 @@ impl PrivacyHealthCheckConfig {
             .and_then(|v| v.parse().ok())
             .unwrap_or(5);
 
+        let min_acceptable_tx_count = env::var("PRIVACY_MIN_ACCEPTABLE_TX_COUNT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+
+        let min_holder_count = env::var("PRIVACY_MIN_HOLDER_COUNT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(100);
+
+        let holder_weight = env::var("PRIVACY_HOLDER_WEIGHT")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .filter(|weight| (0.0..=1.0).contains(weight))
+            .unwrap_or(0.5);
+
         let lookback_minutes = env::var("PRIVACY_LOOKBACK_MINUTES")
             .ok()
             .and_then(|v| v.parse().ok())
@@ -84,15 +126,30 @@ impl PrivacyHealthCheckConfig {
             .and_then(|v| v.parse().ok())
             .unwrap_or(10);
 
+        let jitter_min_ms = env::var("PRIVACY_DELAY_MIN_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(500);
+
+        let jitter_max_ms = env::var("PRIVACY_DELAY_MAX_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(3_000);
+
         let enabled = env::var("ENABLE_PRIVACY_CHECKS")
             .map(|v| v == "true" || v == "1")
             .unwrap_or(true);
 
         Self {
             min_tx_threshold,
+            min_acceptable_tx_count,
+            min_holder_count,
+            holder_weight,
             lookback_minutes,
             max_delay_secs,
             min_delay_secs,
+            jitter_min_ms,
+            jitter_max_ms,
             enabled,
         }
     }
@@ -102,6 +159,28 @@ impl PrivacyHealthCheckConfig {
 // ANONYMITY SET HEALTH RESULT
 // ============================================================================
 
+/// What the worker should do with a confidential transfer after a health check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrivacyAction {
+    /// Submit immediately
+    Proceed,
+    /// Wait for the recommended (jittered) delay, then submit
+    Delay,
+    /// Do not submit; the anonymity set is too thin
+    Reject,
+}
+
+impl PrivacyAction {
+    /// Label used in metrics
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Proceed => "proceed",
+            Self::Delay => "delay",
+            Self::Reject => "reject",
+        }
+    }
+}
+
 /// Result of an anonymity set health check
 #[derive(Debug, Clone)]
 pub struct AnonymitySetHealth {
@@ -109,10 +188,16 @@ pub struct AnonymitySetHealth {
     pub token_mint: String,
     /// Number of recent transactions observed
     pub recent_tx_count: u64,
+    /// Number of token holders, when the Token API reports it
+    pub holder_count: Option<u64>,
+    /// Combined activity and holder score (0.0 - 1.0; 1.0 is healthy)
+    pub anonymity_score: f64,
     /// Whether the anonymity set is healthy (sufficient activity)
     pub is_healthy: bool,
     /// Recommended delay in seconds (if unhealthy)
     pub recommended_delay_secs: Option<u64>,
+    /// Action recommended for the transfer
+    pub action: PrivacyAction,
     /// Timestamp of the check
     pub checked_at: DateTime<Utc>,
 }
@@ -123,8 +208,11 @@ impl AnonymitySetHealth {
         Self {
             token_mint,
             recent_tx_count,
+            holder_count: None,
+            anonymity_score: 1.0,
             is_healthy: true,
             recommended_delay_secs: None,
+            action: PrivacyAction::Proceed,
             checked_at: Utc::now(),
         }
     }
@@ -134,20 +222,48 @@ impl AnonymitySetHealth {
         Self {
             token_mint,
             recent_tx_count,
+            holder_count: None,
+            anonymity_score: 0.0,
             is_healthy: false,
             recommended_delay_secs: Some(delay_secs),
+            action: PrivacyAction::Delay,
             checked_at: Utc::now(),
         }
     }
 
+    /// Create an unhealthy result that rejects the transfer
+    pub fn rejected(token_mint: String, recent_tx_count: u64) -> Self {
+        Self {
+            token_mint,
+            recent_tx_count,
+            holder_count: None,
+            anonymity_score: 0.0,
+            is_healthy: false,
+            recommended_delay_secs: None,
+            action: PrivacyAction::Reject,
+            checked_at: Utc::now(),
+        }
+    }
+
+    /// Attach the observed holder count and the score the action was based on
+    #[must_use]
+    pub fn with_score(mut self, holder_count: Option<u64>, anonymity_score: f64) -> Self {
+        self.holder_count = holder_count;
+        self.anonymity_score = anonymity_score;
+        self
+    }
+
     /// Skip the check result (e.g., API unavailable)
     /// Assumes healthy to prioritize liveness
     pub fn skipped(token_mint: String) -> Self {
         Self {
             token_mint,
             recent_tx_count: 0,
+            holder_count: None,
+            anonymity_score: 1.0,
             is_healthy: true, // Assume healthy to prioritize liveness
             recommended_delay_secs: None,
+            action: PrivacyAction::Proceed,
             checked_at: Utc::now(),
         }
     }
@@ -175,6 +291,7 @@ impl PrivacyHealthCheckService {
         if config.enabled && token_api_client.is_some() {
             info!(
                 threshold = config.min_tx_threshold,
+                min_acceptable = config.min_acceptable_tx_count,
                 lookback_minutes = config.lookback_minutes,
                 max_delay_secs = config.max_delay_secs,
                 "🛡️ Privacy Health Check service initialized"
@@ -209,6 +326,16 @@ impl PrivacyHealthCheckService {
         &self.config
     }
 
+    /// Check that the Token API is reachable.
+    /// Returns `None` when the service is not operational.
+    pub async fn ping(&self) -> Option<Result<(), AppError>> {
+        if !self.config.enabled {
+            return None;
+        }
+        let client = self.token_api_client.as_ref()?;
+        Some(client.ping().await)
+    }
+
     /// Check the anonymity set health for a token mint
     ///
     /// # Arguments
@@ -243,28 +370,52 @@ impl PrivacyHealthCheckService {
         {
             Ok(activity) => {
                 let recent_tx_count = activity.recent_tx_count;
-
-                if recent_tx_count >= self.config.min_tx_threshold {
-                    info!(
-                        token_mint = %token_mint,
-                        recent_tx_count = recent_tx_count,
-                        threshold = self.config.min_tx_threshold,
-                        "✅ Anonymity set HEALTHY - proceeding with submission"
-                    );
-                    AnonymitySetHealth::healthy(token_mint.to_string(), recent_tx_count)
-                } else {
-                    // Calculate randomized delay
-                    let delay = self.calculate_delay(recent_tx_count);
-
-                    warn!(
-                        token_mint = %token_mint,
-                        recent_tx_count = recent_tx_count,
-                        threshold = self.config.min_tx_threshold,
-                        delay_secs = delay,
-                        "⚠️ Anonymity set UNHEALTHY - recommending delay"
-                    );
-                    AnonymitySetHealth::unhealthy(token_mint.to_string(), recent_tx_count, delay)
-                }
+                let holder_count = activity.holder_count;
+                let score = self.anonymity_score(recent_tx_count, holder_count);
+                let action = self.action_for_score(recent_tx_count, score);
+                metrics::record_privacy_check(token_mint, action, recent_tx_count);
+
+                let health = match action {
+                    PrivacyAction::Proceed => {
+                        info!(
+                            token_mint = %token_mint,
+                            recent_tx_count = recent_tx_count,
+                            holder_count = ?holder_count,
+                            threshold = self.config.min_tx_threshold,
+                            "✅ Anonymity set HEALTHY - proceeding with submission"
+                        );
+                        AnonymitySetHealth::healthy(token_mint.to_string(), recent_tx_count)
+                    }
+                    PrivacyAction::Delay => {
+                        // Calculate randomized delay
+                        let delay = self.calculate_delay(score);
+
+                        warn!(
+                            token_mint = %token_mint,
+                            recent_tx_count = recent_tx_count,
+                            holder_count = ?holder_count,
+                            anonymity_score = score,
+                            threshold = self.config.min_tx_threshold,
+                            delay_secs = delay,
+                            "⚠️ Anonymity set UNHEALTHY - recommending delay"
+                        );
+                        AnonymitySetHealth::unhealthy(
+                            token_mint.to_string(),
+                            recent_tx_count,
+                            delay,
+                        )
+                    }
+                    PrivacyAction::Reject => {
+                        warn!(
+                            token_mint = %token_mint,
+                            recent_tx_count = recent_tx_count,
+                            min_acceptable = self.config.min_acceptable_tx_count,
+                            "⛔ Anonymity set TOO THIN - recommending rejection"
+                        );
+                        AnonymitySetHealth::rejected(token_mint.to_string(), recent_tx_count)
+                    }
+                };
+                health.with_score(holder_count, score)
             }
             Err(e) => {
                 // Graceful degradation: log warning and proceed
@@ -278,17 +429,60 @@ impl PrivacyHealthCheckService {
         }
     }
 
-    /// Calculate a randomized delay based on activity level
-    fn calculate_delay(&self, recent_tx_count: u64) -> u64 {
-        // Lower activity = longer delay (inverse relationship)
-        let activity_factor = if self.config.min_tx_threshold > 0 {
-            1.0 - (recent_tx_count as f64 / self.config.min_tx_threshold as f64)
+    /// Action for a given recent activity count when the holder count is
+    /// unknown: reject below the minimum acceptable count, delay below the
+    /// healthy threshold, otherwise proceed
+    pub fn action_for(&self, recent_tx_count: u64) -> PrivacyAction {
+        self.action_for_score(recent_tx_count, self.anonymity_score(recent_tx_count, None))
+    }
+
+    /// Action for a recent activity count and its anonymity score: reject
+    /// below the minimum acceptable count, delay below a full score,
+    /// otherwise proceed
+    pub fn action_for_score(&self, recent_tx_count: u64, anonymity_score: f64) -> PrivacyAction {
+        if recent_tx_count < self.config.min_acceptable_tx_count {
+            PrivacyAction::Reject
+        } else if anonymity_score < 1.0 {
+            PrivacyAction::Delay
         } else {
-            1.0
-        };
+            PrivacyAction::Proceed
+        }
+    }
+
+    /// Combined anonymity score between 0.0 and 1.0.
+    ///
+    /// Activity and holder count each score their fraction of the healthy
+    /// threshold, capped at 1.0, and are blended by `holder_weight`. A token
+    /// with bursty activity but few holders therefore scores low. Without a
+    /// holder count the score is the activity score alone.
+    pub fn anonymity_score(&self, recent_tx_count: u64, holder_count: Option<u64>) -> f64 {
+        let activity = threshold_fraction(recent_tx_count, self.config.min_tx_threshold);
+        match holder_count {
+            Some(holder_count) => {
+                let holders = threshold_fraction(holder_count, self.config.min_holder_count);
+                let weight = self.config.holder_weight.clamp(0.0, 1.0);
+                activity + weight * (holders - activity)
+            }
+            None => activity,
+        }
+    }
+
+    /// Uniformly random jitter within the configured window, applied before
+    /// every confidential transfer regardless of anonymity set health.
+    /// An inverted window is treated as a fixed delay of `jitter_min_ms`.
+    pub fn submission_jitter(&self) -> Duration {
+        let min = self.config.jitter_min_ms;
+        let max = self.config.jitter_max_ms.max(min);
+        Duration::from_millis(rand::random_range(min..=max))
+    }
+
+    /// Calculate a randomized delay based on the anonymity score
+    fn calculate_delay(&self, anonymity_score: f64) -> u64 {
+        // Lower score = longer delay (inverse relationship)
+        let deficit = 1.0 - anonymity_score.clamp(0.0, 1.0);
 
         let base_delay = self.config.min_delay_secs as f64
-            + (activity_factor * (self.config.max_delay_secs - self.config.min_delay_secs) as f64);
+            + (deficit * (self.config.max_delay_secs - self.config.min_delay_secs) as f64);
 
         // Add randomization (±30%)
         let jitter = rand::random_range(0.7..1.3);
@@ -298,6 +492,14 @@ impl PrivacyHealthCheckService {
     }
 }
 
+/// `count` as a fraction of `threshold`, capped at 1.0 (a zero threshold is always met)
+fn threshold_fraction(count: u64, threshold: u64) -> f64 {
+    if threshold == 0 {
+        return 1.0;
+    }
+    (count as f64 / threshold as f64).min(1.0)
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -310,9 +512,14 @@ mod tests {
     fn test_config_default() {
         let config = PrivacyHealthCheckConfig::default();
         assert_eq!(config.min_tx_threshold, 5);
+        assert_eq!(config.min_acceptable_tx_count, 0);
+        assert_eq!(config.min_holder_count, 100);
+        assert_eq!(config.holder_weight, 0.5);
         assert_eq!(config.lookback_minutes, 10);
         assert_eq!(config.max_delay_secs, 120);
         assert_eq!(config.min_delay_secs, 10);
+        assert_eq!(config.jitter_min_ms, 500);
+        assert_eq!(config.jitter_max_ms, 3_000);
         assert!(config.enabled);
     }
 
@@ -388,7 +595,7 @@ mod tests {
         let service = PrivacyHealthCheckService::new(config, None);
 
         // With 0 activity, delay should be close to max
-        let delay = service.calculate_delay(0);
+        let delay = service.calculate_delay(service.anonymity_score(0, None));
         assert!(delay >= 10);
         assert!(delay <= 100);
     }
@@ -404,8 +611,223 @@ mod tests {
         let service = PrivacyHealthCheckService::new(config, None);
 
         // With activity at threshold, delay should be close to min
-        let delay = service.calculate_delay(9);
+        let delay = service.calculate_delay(service.anonymity_score(9, None));
         assert!(delay >= 10);
         assert!(delay <= 100);
     }
+
+    #[test]
+    fn test_action_for_activity_counts() {
+        let config = PrivacyHealthCheckConfig {
+            min_tx_threshold: 10,
+            min_acceptable_tx_count: 3,
+            ..Default::default()
+        };
+        let service = PrivacyHealthCheckService::new(config, None);
+
+        assert_eq!(service.action_for(0), PrivacyAction::Reject);
+        assert_eq!(service.action_for(2), PrivacyAction::Reject);
+        assert_eq!(service.action_for(3), PrivacyAction::Delay);
+        assert_eq!(service.action_for(9), PrivacyAction::Delay);
+        assert_eq!(service.action_for(10), PrivacyAction::Proceed);
+        assert_eq!(service.action_for(500), PrivacyAction::Proceed);
+    }
+
+    #[test]
+    fn test_action_for_never_rejects_by_default() {
+        let service = PrivacyHealthCheckService::new(PrivacyHealthCheckConfig::default(), None);
+        assert_eq!(service.action_for(0), PrivacyAction::Delay);
+        assert_eq!(service.action_for(5), PrivacyAction::Proceed);
+    }
+
+    #[test]
+    fn test_thin_holder_token_scores_worse_than_balanced() {
+        let config = PrivacyHealthCheckConfig {
+            min_tx_threshold: 10,
+            min_holder_count: 100,
+            holder_weight: 0.5,
+            ..Default::default()
+        };
+        let service = PrivacyHealthCheckService::new(config, None);
+
+        let bursty_thin = service.anonymity_score(500, Some(3));
+        let balanced = service.anonymity_score(8, Some(80));
+        assert!(bursty_thin < balanced);
+        assert!((bursty_thin - 0.515).abs() < 1e-9);
+        assert!((balanced - 0.8).abs() < 1e-9);
+
+        // High activity alone no longer proceeds when holders are few
+        assert_eq!(
+            service.action_for_score(500, bursty_thin),
+            PrivacyAction::Delay
+        );
+        let healthy = service.anonymity_score(500, Some(1_000));
+        assert_eq!(healthy, 1.0);
+        assert_eq!(
+            service.action_for_score(500, healthy),
+            PrivacyAction::Proceed
+        );
+
+        // Without a holder count only activity is scored
+        assert_eq!(service.anonymity_score(500, None), 1.0);
+        assert!((service.anonymity_score(5, None) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_holder_weight_shifts_score() {
+        let score = |holder_weight| {
+            let config = PrivacyHealthCheckConfig {
+                min_tx_threshold: 10,
+                min_holder_count: 100,
+                holder_weight,
+                ..Default::default()
+            };
+            PrivacyHealthCheckService::new(config, None).anonymity_score(10, Some(20))
+        };
+        assert_eq!(score(0.0), 1.0);
+        assert!((score(0.25) - 0.8).abs() < 1e-9);
+        assert!((score(1.0) - 0.2).abs() < 1e-9);
+        // Out-of-range weights are clamped
+        assert!((score(3.0) - 0.2).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_check_health_uses_reported_holder_count() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let server = MockServer::start().await;
+        // 14,400 transfers a day scale to 100 in the 10 minute window
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "qn_getTokenMetadata" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "holderCount": 4, "transferCount24h": 14_400 },
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Arc::new(QuickNodeTokenApiClient::new(&server.uri()));
+        let service =
+            PrivacyHealthCheckService::new(PrivacyHealthCheckConfig::default(), Some(client));
+        let health = service.check_health("ThinHolderMint").await;
+
+        assert_eq!(health.recent_tx_count, 100);
+        assert_eq!(health.holder_count, Some(4));
+        assert!((health.anonymity_score - 0.52).abs() < 1e-9);
+        assert_eq!(health.action, PrivacyAction::Delay);
+        assert!(health.recommended_delay_secs.is_some());
+    }
+
+    #[test]
+    fn test_health_result_actions() {
+        let healthy = AnonymitySetHealth::healthy("token123".to_string(), 10);
+        assert_eq!(healthy.action, PrivacyAction::Proceed);
+
+        let delayed = AnonymitySetHealth::unhealthy("token123".to_string(), 2, 60);
+        assert_eq!(delayed.action, PrivacyAction::Delay);
+
+        let rejected = AnonymitySetHealth::rejected("token123".to_string(), 0);
+        assert_eq!(rejected.action, PrivacyAction::Reject);
+        assert!(!rejected.is_healthy);
+        assert!(rejected.recommended_delay_secs.is_none());
+
+        // Skipped checks never block liveness
+        let skipped = AnonymitySetHealth::skipped("token123".to_string());
+        assert_eq!(skipped.action, PrivacyAction::Proceed);
+    }
+
+    #[tokio::test]
+    async fn test_check_health_records_outcome_metric() {
+        use wiremock::matchers::{body_partial_json, method};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let handle = metrics::install_prometheus_recorder();
+        let server = MockServer::start().await;
+        // 288 transfers a day scale to 2 in the 10 minute window
+        Mock::given(method("POST"))
+            .and(body_partial_json(
+                serde_json::json!({ "method": "qn_getTokenMetadata" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": { "transferCount24h": 288 },
+            })))
+            .mount(&server)
+            .await;
+
+        let client = Arc::new(QuickNodeTokenApiClient::new(&server.uri()));
+        let service =
+            PrivacyHealthCheckService::new(PrivacyHealthCheckConfig::default(), Some(client));
+        let mint = "PrivacyMetricsTestMint1111111111111111111111";
+        let health = service.check_health(mint).await;
+        assert_eq!(health.action, PrivacyAction::Delay);
+        assert_eq!(health.recent_tx_count, 2);
+
+        let rendered = handle.render();
+        let checks: Vec<&str> = rendered
+            .lines()
+            .filter(|line| {
+                line.starts_with(metrics::PRIVACY_CHECKS_TOTAL)
+                    && line.contains(&format!(r#"mint="{}""#, mint))
+            })
+            .collect();
+        assert_eq!(checks.len(), 1);
+        assert!(checks[0].contains(r#"outcome="delay""#));
+        assert!(checks[0].ends_with(" 1"));
+        assert!(rendered.contains(metrics::PRIVACY_RECENT_ACTIVITY));
+    }
+
+    #[test]
+    fn test_submission_jitter_within_bounds() {
+        let config = PrivacyHealthCheckConfig {
+            jitter_min_ms: 200,
+            jitter_max_ms: 800,
+            ..Default::default()
+        };
+        let service = PrivacyHealthCheckService::new(config, None);
+
+        for _ in 0..200 {
+            let jitter = service.submission_jitter();
+            assert!(jitter >= Duration::from_millis(200));
+            assert!(jitter <= Duration::from_millis(800));
+        }
+    }
+
+    #[test]
+    fn test_submission_jitter_degenerate_windows() {
+        let fixed = PrivacyHealthCheckService::new(
+            PrivacyHealthCheckConfig {
+                jitter_min_ms: 250,
+                jitter_max_ms: 250,
+                ..Default::default()
+            },
+            None,
+        );
+        assert_eq!(fixed.submission_jitter(), Duration::from_millis(250));
+
+        let inverted = PrivacyHealthCheckService::new(
+            PrivacyHealthCheckConfig {
+                jitter_min_ms: 400,
+                jitter_max_ms: 100,
+                ..Default::default()
+            },
+            None,
+        );
+        assert_eq!(inverted.submission_jitter(), Duration::from_millis(400));
+
+        let disabled = PrivacyHealthCheckService::new(
+            PrivacyHealthCheckConfig {
+                jitter_min_ms: 0,
+                jitter_max_ms: 0,
+                ..Default::default()
+            },
+            None,
+        );
+        assert_eq!(disabled.submission_jitter(), Duration::ZERO);
+    }
 }
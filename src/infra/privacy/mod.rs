@@ -10,4 +10,6 @@
 
 pub mod health_check;
 
-pub use health_check::{AnonymitySetHealth, PrivacyHealthCheckConfig, PrivacyHealthCheckService};
+pub use health_check::{
+    AnonymitySetHealth, PrivacyAction, PrivacyHealthCheckConfig, PrivacyHealthCheckService,
+};
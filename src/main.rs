@@ -1,5 +1,6 @@
 //! Application entry point.
 
+use std::collections::HashMap;
 use std::env;
 use std::sync::Arc;
 
@@ -9,36 +10,97 @@ use ed25519_dalek::SigningKey;
 use secrecy::SecretString;
 use tokio::signal;
 use tracing::{info, warn};
-use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
+
+use solana_sdk::pubkey::Pubkey;
 
 use solana_compliance_relayer::api::{
     RateLimitConfig, create_router, create_router_with_rate_limit,
 };
 use solana_compliance_relayer::app::{
-    AppState, CrankConfig, RiskService, WorkerConfig, spawn_crank, spawn_worker,
-    spawn_worker_with_privacy,
+    AppState, ArchivalConfig, BalanceMonitorConfig, BlockchainRetryConfig, CrankConfig,
+    DEFAULT_ARCHIVAL_BATCH_SIZE, DEFAULT_ARCHIVAL_INTERVAL, DEFAULT_ARCHIVAL_RETENTION,
+    DEFAULT_BALANCE_CHECK_INTERVAL, DEFAULT_CLOCK_SKEW_TOLERANCE_SECS,
+    DEFAULT_IDEMPOTENCY_SWEEP_BATCH_SIZE, DEFAULT_IDEMPOTENCY_SWEEP_INTERVAL,
+    DEFAULT_MAX_BACKOFF_SECS, DEFAULT_MAX_BULK_TRANSFERS, DEFAULT_MAX_RETRY_ATTEMPTS,
+    DEFAULT_MIN_FEE_PAYER_LAMPORTS, DEFAULT_NONCE_RETENTION, DEFAULT_NONCE_SWEEP_BATCH_SIZE,
+    DEFAULT_NONCE_SWEEP_INTERVAL, DEFAULT_PROCESSING_LEASE_SECS, DEFAULT_WORKER_CONCURRENCY,
+    DEFAULT_WORKER_DRAIN_TIMEOUT, DEFAULT_WORKER_MAX_RPS, FeePayerBalanceTracker,
+    IdempotencySweepConfig, MAX_CLOCK_SKEW_TOLERANCE_SECS, NonceSweepConfig, RiskService,
+    RpcRateLimiter, ScreeningOrder, ScreeningPolicy, ShutdownSummary, TransferLimits, WorkerConfig,
+    parse_screening_order_overrides, spawn_archival, spawn_balance_monitor, spawn_crank,
+    spawn_idempotency_sweep, spawn_nonce_sweep, spawn_worker, spawn_worker_with_privacy,
+};
+use solana_compliance_relayer::domain::{
+    AlertSink, ApiKeyScope, ApiKeyStore, ComplianceProvider, normalize_solana_address,
 };
 use solana_compliance_relayer::infra::RpcBlockchainClient;
+use solana_compliance_relayer::infra::alerting::{
+    AlertDispatcher, DEFAULT_ALERT_COOLDOWN, DEFAULT_REJECTION_ALERT_THRESHOLD,
+    DEFAULT_REJECTION_ALERT_WINDOW, DiscordWebhookSink, RejectionRateDetector, SlackWebhookSink,
+};
 use solana_compliance_relayer::infra::blockchain::{
+    CircuitBreakerClient, CircuitBreakerConfig, DEFAULT_CIRCUIT_COOLDOWN,
+    DEFAULT_CIRCUIT_FAILURE_THRESHOLD, DEFAULT_COMPUTE_UNIT_LIMIT,
+    DEFAULT_CONFIRMATION_POLL_INTERVAL, DEFAULT_CONFIRMATION_POLL_MAX_INTERVAL,
+    DEFAULT_EXPLORER_BASE_URL, DEFAULT_JITO_TIP_CEILING_LAMPORTS, DEFAULT_JITO_TIP_FLOOR_URL,
+    DEFAULT_JITO_TIP_PERCENTILE, DEFAULT_MAX_PRIORITY_FEE_MICROLAMPORTS, DEFAULT_MINT_CACHE_TTL,
+    DEFAULT_RENT_CACHE_TTL, DEFAULT_RESUBMIT_DEADLINE, DEFAULT_TIP_ACCOUNT_REFRESH_INTERVAL,
+    DynamicTipConfig, DynamicTipStrategy, ExplorerLinks, KeySelectionPolicy,
     QuickNodePrivateSubmissionStrategy, QuickNodeSubmissionConfig, QuickNodeTokenApiClient,
-    RpcProviderType,
+    RpcClientConfig, RpcProviderType, SquadsConfig, spawn_tip_account_refresh,
+};
+use solana_compliance_relayer::infra::blocklist::{
+    DEFAULT_HIT_FLUSH_INTERVAL, OfacSyncConfig, spawn_expiry_sweep, spawn_hit_flush,
+    spawn_ofac_sync,
+};
+use solana_compliance_relayer::infra::compliance::ComplianceRetryConfig;
+use solana_compliance_relayer::infra::compliance::range::{
+    DEFAULT_CACHE_TTL_SECS, DEFAULT_RISK_THRESHOLD, REJECTED_CACHE_TTL_MULTIPLIER,
+    parse_threshold_overrides,
+};
+use solana_compliance_relayer::infra::compliance::trm::DEFAULT_TRM_RISK_THRESHOLD;
+use solana_compliance_relayer::infra::logging::{LogFormat, init_tracing};
+use solana_compliance_relayer::infra::metrics::{
+    DEFAULT_METRICS_REFRESH_INTERVAL, install_prometheus_recorder, spawn_metrics_refresh,
+};
+use solana_compliance_relayer::infra::outbound_webhooks::{
+    DEFAULT_OUTBOUND_WEBHOOK_BASE_BACKOFF, DEFAULT_OUTBOUND_WEBHOOK_MAX_ATTEMPTS,
+    DEFAULT_OUTBOUND_WEBHOOK_POLL_INTERVAL, OutboundWebhookConfig, spawn_webhook_dispatcher,
 };
-use solana_compliance_relayer::infra::compliance::range::DEFAULT_RISK_THRESHOLD;
 use solana_compliance_relayer::infra::{
-    BlocklistManager, PostgresClient, PostgresConfig, PrivacyHealthCheckConfig,
-    PrivacyHealthCheckService, signing_key_from_base58,
+    AllowlistManager, BlocklistManager, CompositeComplianceProvider, CompositionPolicy,
+    PostgresClient, PostgresConfig, PrivacyHealthCheckConfig, PrivacyHealthCheckService,
+    TrmComplianceProvider, signing_key_from_base58,
 };
 
 /// Application configuration
 struct Config {
     database_url: String,
+    /// Read replica for read-only lookups (optional - primary serves reads if not set)
+    database_replica_url: Option<String>,
+    /// Start without applying migrations (schema managed elsewhere)
+    skip_migrations: bool,
     blockchain_rpc_url: String,
     signing_key: SigningKey,
+    /// Issuer keys beyond `signing_key`, from `ISSUER_PRIVATE_KEYS`
+    additional_signing_keys: Vec<SigningKey>,
+    /// Keys that only sign transfers already pinned to them (rotation)
+    retired_signing_keys: Vec<SigningKey>,
+    /// How new transfers are assigned to an issuer key (default: request_id)
+    key_selection_policy: KeySelectionPolicy,
     host: String,
     port: u16,
     enable_rate_limiting: bool,
     rate_limit_config: RateLimitConfig,
     enable_background_worker: bool,
+    /// Retry limit and backoff cap of failed blockchain submissions
+    blockchain_retry_config: BlockchainRetryConfig,
+    /// Permits per second shared by worker submissions and crank status checks
+    worker_max_rps: u32,
+    /// Seconds of disagreement tolerated between our clock and others'
+    clock_skew_tolerance_secs: i64,
+    /// Per-transfer and rolling per-sender caps on native SOL amounts
+    transfer_limits: TransferLimits,
     worker_config: WorkerConfig,
     /// Range Protocol API key (optional - uses mock mode if not set)
     range_api_key: Option<String>,
@@ -46,12 +108,42 @@ struct Config {
     range_api_url: Option<String>,
     /// Risk threshold for Range compliance (default: 6 = High Risk)
     range_risk_threshold: i32,
+    /// Per-mint risk thresholds overriding `range_risk_threshold`
+    range_threshold_overrides: HashMap<String, i32>,
+    /// How decisions are combined when several compliance providers are chained
+    compliance_policy: CompositionPolicy,
+    /// Order of the blocklist and compliance provider checks, per mint
+    screening_policy: ScreeningPolicy,
+    /// Mint of public transfers that omit `token_mint` (default: SOL)
+    default_token_mint: Option<String>,
+    /// Retry policy for transient Range API failures
+    range_retry_config: ComplianceRetryConfig,
+    /// Approve transfers when Range stays unavailable after retries (default: false)
+    range_fail_open: bool,
+    /// TTL for cached Range approvals in seconds; rejections are cached longer (default: 300)
+    range_cache_ttl_secs: u64,
+    /// Ordered compliance providers from COMPLIANCE_PROVIDER (default: range)
+    compliance_providers: Vec<String>,
+    /// TRM Labs API key (required when `trm` is selected)
+    trm_api_key: Option<String>,
+    /// TRM Labs API base URL (optional - uses default if not set)
+    trm_api_url: Option<String>,
+    /// Risk level threshold for TRM screening (default: 10 = High)
+    trm_risk_threshold: i32,
+    /// Retry policy for transient TRM API failures
+    trm_retry_config: ComplianceRetryConfig,
+    /// Approve transfers when TRM stays unavailable after retries (default: false)
+    trm_fail_open: bool,
+    /// TTL for cached TRM approvals in seconds (default: 300)
+    trm_cache_ttl_secs: u64,
     /// Helius webhook secret for authentication (optional)
     helius_webhook_secret: Option<String>,
     /// QuickNode webhook secret for authentication (optional)
     quicknode_webhook_secret: Option<String>,
     /// Admin API key for /admin route authentication (optional for local development)
     admin_api_key: Option<String>,
+    /// Scoped API keys for submit and admin routes (empty = open for local development)
+    api_keys: ApiKeyStore,
     /// Enable privacy health checks for confidential transfers
     enable_privacy_checks: bool,
     /// Enable Jito bundle submission for MEV-protected transactions (QuickNode only)
@@ -60,6 +152,44 @@ struct Config {
     use_jito_bundles: bool,
     /// Jito tip amount in lamports (default: 1000 = 0.000001 SOL)
     jito_tip_lamports: u64,
+    /// Size Jito tips from recent tip-floor data (JITO_TIP_LAMPORTS becomes the floor)
+    jito_dynamic_tip: bool,
+    /// Percentile, ceiling and endpoint for dynamic tips
+    jito_dynamic_tip_config: DynamicTipConfig,
+    /// Interval for refreshing Jito tip accounts via getTipAccounts in seconds (0 = hardcoded list)
+    jito_tip_account_refresh_secs: u64,
+    /// Build v0 (versioned) transactions instead of legacy ones
+    use_versioned_tx: bool,
+    /// Address lookup table resolved into v0 messages (optional)
+    address_lookup_table: Option<Pubkey>,
+    /// Durable nonce account used instead of a recent blockhash (optional)
+    nonce_account: Option<Pubkey>,
+    /// Squads multisig whose vault sends transfers as proposals (optional)
+    squads_multisig: Option<SquadsConfig>,
+    /// Priority fee ceiling in micro-lamports per compute unit (default: 1,000,000)
+    max_priority_fee: u64,
+    /// Compute unit limit for SOL and token transfers (default: 200,000)
+    compute_unit_limit: u32,
+    /// Gross up Token-2022 transfers so the recipient nets the requested amount
+    gross_up_transfer_fees: bool,
+    /// Check the fee payer's balance before building SOL transfers
+    preflight_balance_check: bool,
+    /// Close the relayer's emptied token accounts after confirmed transfers
+    close_empty_token_accounts: bool,
+    /// Skip preflight simulation on strategy submissions (per-transfer overridable)
+    skip_preflight: bool,
+    /// Lifetime of cached rent-exempt minimums (zero disables the cache)
+    rent_cache_ttl: std::time::Duration,
+    /// Lifetime of cached mint token programs and decimals (zero disables the cache)
+    mint_cache_ttl: std::time::Duration,
+    /// First delay between confirmation polls (doubles up to the maximum)
+    confirmation_poll_interval: std::time::Duration,
+    /// Longest delay between confirmation polls
+    confirmation_poll_max_interval: std::time::Duration,
+    /// How long the standard path resends with fresh blockhashes (zero sends once)
+    resubmit_deadline: std::time::Duration,
+    /// Mints screened by the token-account asset check on providers without DAS
+    sanctioned_mints: Vec<Pubkey>,
     /// Enable stale transaction crank (active polling fallback for webhook failures)
     enable_stale_crank: bool,
     /// Crank poll interval in seconds (default: 60)
@@ -68,14 +198,62 @@ struct Config {
     crank_stale_after_secs: i64,
     /// Number of stale transactions to process per crank cycle (default: 20)
     crank_batch_size: i64,
+    /// Reclaim transfers left in `processing` after this many seconds (default: 300)
+    processing_lease_secs: i64,
+    /// Interval between expired blocklist entry sweeps in seconds (default: 300)
+    blocklist_sweep_interval_secs: u64,
+    /// Interval between blocklist hit counter flushes in seconds (default: 60)
+    blocklist_hit_flush_interval_secs: u64,
+    /// Interval between pending-queue metric refreshes in seconds (default: 15)
+    metrics_refresh_interval_secs: u64,
+    /// Maximum number of transfers in one bulk submission (default: 100)
+    max_bulk_transfers: usize,
+    /// Explorer linked from transfer responses (default: Solana Explorer)
+    explorer_base_url: String,
+    /// Enable periodic OFAC SDN list synchronization into the blocklist
+    enable_ofac_sync: bool,
+    /// URL of the sanctioned address list (JSON or CSV)
+    ofac_sync_url: Option<String>,
+    /// OFAC sync interval in seconds (default: 3600)
+    ofac_sync_interval_secs: u64,
+    /// Soft-delete of old terminal transfers (disabled by default)
+    archival_config: ArchivalConfig,
+    /// Clearing of nonces that can no longer be replayed (disabled by default)
+    nonce_sweep_config: NonceSweepConfig,
+    /// Deletion of expired Idempotency-Key records (enabled by default)
+    idempotency_sweep_config: IdempotencySweepConfig,
+    /// Circuit breaker around the blockchain RPC client
+    circuit_breaker_config: CircuitBreakerConfig,
+    /// Outbound status webhooks (enabled when OUTBOUND_WEBHOOK_SECRET is set)
+    outbound_webhook_config: Option<OutboundWebhookConfig>,
+    /// Fee payer balance monitoring (enabled by default)
+    balance_monitor_config: Option<BalanceMonitorConfig>,
+    /// Slack/Discord alerting (enabled when a chat webhook URL is set)
+    alert_dispatcher: Option<Arc<AlertDispatcher>>,
+    /// Compliance rejections within `rejection_alert_window` that raise an alert
+    rejection_alert_threshold: usize,
+    rejection_alert_window: std::time::Duration,
 }
 
 impl Config {
     fn from_env() -> Result<Self> {
         let database_url = env::var("DATABASE_URL").context("DATABASE_URL not set")?;
+        let database_replica_url = env::var("DATABASE_REPLICA_URL")
+            .ok()
+            .filter(|v| !v.trim().is_empty());
+        let skip_migrations = env::var("SKIP_MIGRATIONS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
         let blockchain_rpc_url = env::var("SOLANA_RPC_URL")
             .unwrap_or_else(|_| "https://api.devnet.solana.com".to_string());
-        let signing_key = Self::load_signing_key()?;
+        let (signing_key, additional_signing_keys) = Self::load_signing_keys()?;
+        let retired_signing_keys = Self::parse_signing_keys("ISSUER_RETIRED_PRIVATE_KEYS")?;
+        let key_selection_policy = match env::var("ISSUER_KEY_SELECTION") {
+            Ok(v) => v
+                .parse::<KeySelectionPolicy>()
+                .map_err(|e| anyhow::anyhow!(e))?,
+            Err(_) => KeySelectionPolicy::default(),
+        };
         let host = env::var("HOST").unwrap_or_else(|_| "0.0.0.0".to_string());
         let port = env::var("PORT")
             .ok()
@@ -104,13 +282,108 @@ impl Config {
 
         let admin_api_key = env::var("ADMIN_API_KEY").ok().filter(|s| !s.is_empty());
 
+        // Scoped API keys, e.g. "key1:submit,key2:admin"
+        let api_keys = match env::var("API_KEYS") {
+            Ok(v) => v
+                .parse::<ApiKeyStore>()
+                .map_err(|e| anyhow::anyhow!("Invalid API_KEYS: {}", e))?,
+            Err(_) => ApiKeyStore::default(),
+        };
+
         // Range risk threshold configuration
         let range_risk_threshold = env::var("RANGE_RISK_THRESHOLD")
             .ok()
             .and_then(|v| v.parse::<i32>().ok())
             .unwrap_or(DEFAULT_RISK_THRESHOLD);
 
-        let rate_limit_config = RateLimitConfig::from_env();
+        // Per-mint overrides, e.g. "mint1:8,mint2:4"
+        let range_threshold_overrides = match env::var("RANGE_RISK_THRESHOLD_OVERRIDES") {
+            Ok(v) => parse_threshold_overrides(&v).map_err(|e| anyhow::anyhow!(e))?,
+            Err(_) => HashMap::new(),
+        };
+
+        let compliance_policy = match env::var("COMPLIANCE_POLICY") {
+            Ok(v) => v
+                .parse::<CompositionPolicy>()
+                .map_err(|e| anyhow::anyhow!(e))?,
+            Err(_) => CompositionPolicy::default(),
+        };
+
+        // Blocklist before the provider unless overridden, e.g. per high-value mint
+        let screening_policy = ScreeningPolicy {
+            default_order: match env::var("COMPLIANCE_CHECK_ORDER") {
+                Ok(v) => v
+                    .parse::<ScreeningOrder>()
+                    .map_err(|e| anyhow::anyhow!(e))?,
+                Err(_) => ScreeningOrder::default(),
+            },
+            mint_orders: match env::var("COMPLIANCE_CHECK_ORDER_OVERRIDES") {
+                Ok(v) => parse_screening_order_overrides(&v).map_err(|e| anyhow::anyhow!(e))?,
+                Err(_) => HashMap::new(),
+            },
+        };
+
+        // Deployments moving a single stablecoin can let requests omit the mint
+        let default_token_mint = match env::var("DEFAULT_TOKEN_MINT") {
+            Ok(v) if !v.trim().is_empty() => {
+                Some(normalize_solana_address(&v).ok_or_else(|| {
+                    anyhow::anyhow!("DEFAULT_TOKEN_MINT is not a valid mint address: {}", v)
+                })?)
+            }
+            _ => None,
+        };
+
+        let range_cache_ttl_secs = env::var("RANGE_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_CACHE_TTL_SECS);
+
+        let range_retry_config = Self::retry_config_from_env("RANGE");
+
+        let range_fail_open = env::var("RANGE_FAIL_OPEN")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false); // Fail closed by default
+
+        // Compliance provider selection, e.g. "range", "trm" or "range,trm"
+        let compliance_providers: Vec<String> = env::var("COMPLIANCE_PROVIDER")
+            .unwrap_or_else(|_| "range".to_string())
+            .split(',')
+            .map(|p| p.trim().to_lowercase())
+            .filter(|p| !p.is_empty())
+            .collect();
+        if compliance_providers.is_empty() {
+            anyhow::bail!("COMPLIANCE_PROVIDER must name at least one provider (range, trm)");
+        }
+        if let Some(unknown) = compliance_providers
+            .iter()
+            .find(|p| !matches!(p.as_str(), "range" | "trm"))
+        {
+            anyhow::bail!(
+                "Invalid COMPLIANCE_PROVIDER '{}': expected range or trm",
+                unknown
+            );
+        }
+
+        // TRM Labs configuration (required when selected)
+        let trm_api_key = env::var("TRM_API_KEY").ok().filter(|k| !k.is_empty());
+        if compliance_providers.iter().any(|p| p == "trm") && trm_api_key.is_none() {
+            anyhow::bail!("COMPLIANCE_PROVIDER includes trm but TRM_API_KEY is not set");
+        }
+        let trm_api_url = env::var("TRM_API_URL").ok().filter(|u| !u.is_empty());
+        let trm_risk_threshold = env::var("TRM_RISK_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse::<i32>().ok())
+            .unwrap_or(DEFAULT_TRM_RISK_THRESHOLD);
+        let trm_retry_config = Self::retry_config_from_env("TRM");
+        let trm_fail_open = env::var("TRM_FAIL_OPEN")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false); // Fail closed by default
+        let trm_cache_ttl_secs = env::var("TRM_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_CACHE_TTL_SECS);
+
+        let rate_limit_config = RateLimitConfig::from_env()?;
 
         // Privacy health check configuration
         let enable_privacy_checks = env::var("ENABLE_PRIVACY_CHECKS")
@@ -128,6 +401,171 @@ impl Config {
             .and_then(|v| v.parse::<u64>().ok())
             .unwrap_or(1_000); // Default: 0.000001 SOL
 
+        // Dynamic Jito tips (tip-floor percentile, clamped to [JITO_TIP_LAMPORTS, ceiling])
+        // Default: false - flat JITO_TIP_LAMPORTS
+        let jito_dynamic_tip = env::var("JITO_DYNAMIC_TIP")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        // Refresh Jito tip accounts via getTipAccounts (0 = hardcoded list only)
+        let jito_tip_account_refresh_secs = env::var("JITO_TIP_ACCOUNT_REFRESH_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_TIP_ACCOUNT_REFRESH_INTERVAL.as_secs()); // Default: 1 hour
+
+        let jito_dynamic_tip_config = DynamicTipConfig {
+            tip_floor_url: env::var("JITO_TIP_FLOOR_URL")
+                .ok()
+                .filter(|v| !v.is_empty())
+                .unwrap_or_else(|| DEFAULT_JITO_TIP_FLOOR_URL.to_string()),
+            percentile: env::var("JITO_TIP_PERCENTILE")
+                .ok()
+                .and_then(|v| v.parse::<u8>().ok())
+                .filter(|p| *p <= 100)
+                .unwrap_or(DEFAULT_JITO_TIP_PERCENTILE), // Default: 75th percentile
+            floor_lamports: jito_tip_lamports,
+            ceiling_lamports: env::var("JITO_TIP_MAX_LAMPORTS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(DEFAULT_JITO_TIP_CEILING_LAMPORTS), // Default: 0.001 SOL
+        };
+
+        // Versioned transactions (v0 messages with address lookup tables)
+        // Default: false - legacy transactions
+        let use_versioned_tx = env::var("USE_VERSIONED_TX")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        let address_lookup_table = match env::var("ADDRESS_LOOKUP_TABLE") {
+            Ok(v) if !v.is_empty() => Some(
+                v.parse::<Pubkey>()
+                    .with_context(|| format!("Invalid ADDRESS_LOOKUP_TABLE '{}'", v))?,
+            ),
+            _ => None,
+        };
+
+        // Durable nonce mode: transactions never expire, so the crank resubmits
+        // them instead of marking them expired
+        let nonce_account = match env::var("NONCE_ACCOUNT") {
+            Ok(v) if !v.is_empty() => Some(
+                v.parse::<Pubkey>()
+                    .with_context(|| format!("Invalid NONCE_ACCOUNT '{}'", v))?,
+            ),
+            _ => None,
+        };
+
+        // Multisig mode: transfers leave a Squads vault once its members approve
+        let squads_multisig = match env::var("SQUADS_MULTISIG_ADDRESS") {
+            Ok(v) if !v.is_empty() => {
+                let multisig = v
+                    .parse::<Pubkey>()
+                    .with_context(|| format!("Invalid SQUADS_MULTISIG_ADDRESS '{}'", v))?;
+                let mut squads = SquadsConfig::new(multisig);
+                if let Ok(index) = env::var("SQUADS_VAULT_INDEX") {
+                    squads = squads.with_vault_index(
+                        index
+                            .parse::<u8>()
+                            .with_context(|| format!("Invalid SQUADS_VAULT_INDEX '{}'", index))?,
+                    );
+                }
+                match env::var("SQUADS_PROGRAM_ID") {
+                    Ok(id) if !id.is_empty() => {
+                        squads = squads.with_program_id(
+                            id.parse::<Pubkey>()
+                                .with_context(|| format!("Invalid SQUADS_PROGRAM_ID '{}'", id))?,
+                        );
+                    }
+                    _ => {}
+                }
+                if let Ok(secs) = env::var("SQUADS_APPROVAL_TIMEOUT_SECS") {
+                    squads = squads.with_approval_timeout(std::time::Duration::from_secs(
+                        secs.parse::<u64>().with_context(|| {
+                            format!("Invalid SQUADS_APPROVAL_TIMEOUT_SECS '{}'", secs)
+                        })?,
+                    ));
+                }
+                Some(squads)
+            }
+            _ => None,
+        };
+
+        let max_priority_fee = env::var("MAX_PRIORITY_FEE")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_MAX_PRIORITY_FEE_MICROLAMPORTS); // Default: 1,000,000 micro-lamports
+
+        let compute_unit_limit = env::var("COMPUTE_UNIT_LIMIT")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(DEFAULT_COMPUTE_UNIT_LIMIT); // Default: 200,000 CU
+
+        // Default: false - the mint's transfer fee is deducted from the requested amount
+        let gross_up_transfer_fees = env::var("GROSS_UP_TRANSFER_FEES")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        // Default: false - insufficient funds surface as an RPC error on submission
+        let preflight_balance_check = env::var("PREFLIGHT_BALANCE_CHECK")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        // Default: false - emptied relayer token accounts keep their rent
+        let close_empty_token_accounts = env::var("CLOSE_EMPTY_TOKEN_ACCOUNTS")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        // Default: true - submit without simulating; transfers may override it
+        let skip_preflight = env::var("SKIP_PREFLIGHT")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(true);
+
+        // Default: 10 minutes - 0 disables the rent-exemption cache
+        let rent_cache_ttl = env::var("RENT_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_RENT_CACHE_TTL);
+
+        // Default: 24 hours - 0 disables the mint cache
+        let mint_cache_ttl = env::var("MINT_CACHE_TTL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_MINT_CACHE_TTL);
+
+        // Default: 200ms, doubling after each poll up to 2s
+        let confirmation_poll_interval = env::var("CONFIRMATION_POLL_INTERVAL_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|ms| *ms > 0)
+            .map(std::time::Duration::from_millis)
+            .unwrap_or(DEFAULT_CONFIRMATION_POLL_INTERVAL);
+        let confirmation_poll_max_interval = env::var("CONFIRMATION_POLL_MAX_INTERVAL_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|ms| *ms > 0)
+            .map(std::time::Duration::from_millis)
+            .unwrap_or(DEFAULT_CONFIRMATION_POLL_MAX_INTERVAL);
+
+        // Default: 120s - 0 sends standard-path transactions once
+        let resubmit_deadline = env::var("RESUBMIT_DEADLINE_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_RESUBMIT_DEADLINE);
+
+        // Asset screen for QuickNode/standard RPC (Helius uses DAS instead)
+        let sanctioned_mints = env::var("SANCTIONED_MINTS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|m| !m.is_empty())
+            .map(|m| {
+                m.parse::<Pubkey>()
+                    .with_context(|| format!("Invalid SANCTIONED_MINTS entry '{}'", m))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
         // Stale transaction crank configuration (active polling fallback)
         let enable_stale_crank = env::var("ENABLE_STALE_CRANK")
             .map(|v| v == "true" || v == "1")
@@ -148,38 +586,464 @@ impl Config {
             .and_then(|v| v.parse::<i64>().ok())
             .unwrap_or(20); // Default: 20 transactions per cycle
 
+        let processing_lease_secs = env::var("PROCESSING_LEASE_SECS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_PROCESSING_LEASE_SECS); // Default: 5 minutes
+
+        let blocklist_sweep_interval_secs = env::var("BLOCKLIST_SWEEP_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(300); // Default: 5 minutes
+
+        let blocklist_hit_flush_interval_secs = env::var("BLOCKLIST_HIT_FLUSH_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_HIT_FLUSH_INTERVAL.as_secs()); // Default: 60 seconds
+
+        let metrics_refresh_interval_secs = env::var("METRICS_REFRESH_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_METRICS_REFRESH_INTERVAL.as_secs()); // Default: 15 seconds
+
+        let max_bulk_transfers = env::var("MAX_BULK_TRANSFERS")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|max| *max > 0)
+            .unwrap_or(DEFAULT_MAX_BULK_TRANSFERS); // Default: 100
+
+        let explorer_base_url = env::var("EXPLORER_BASE_URL")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_EXPLORER_BASE_URL.to_string()); // Default: Solana Explorer
+
+        // OFAC SDN sync configuration
+        let enable_ofac_sync = env::var("ENABLE_OFAC_SYNC")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        let ofac_sync_url = env::var("OFAC_SYNC_URL").ok().filter(|s| !s.is_empty());
+
+        let ofac_sync_interval_secs = env::var("OFAC_SYNC_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(3600); // Default: 1 hour
+
+        let worker_concurrency = env::var("WORKER_CONCURRENCY")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_WORKER_CONCURRENCY); // Default: 4
+
+        let worker_drain_timeout = env::var("WORKER_DRAIN_TIMEOUT_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_WORKER_DRAIN_TIMEOUT); // Default: 30s
+
+        let max_retry_attempts = env::var("MAX_RETRY_ATTEMPTS")
+            .ok()
+            .and_then(|v| v.parse::<i32>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_MAX_RETRY_ATTEMPTS); // Default: 10 attempts
+
+        let max_backoff_secs = env::var("MAX_BACKOFF_SECS")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|secs| *secs > 0)
+            .unwrap_or(DEFAULT_MAX_BACKOFF_SECS); // Default: 5 minutes
+
+        let blockchain_retry_config = BlockchainRetryConfig {
+            max_attempts: max_retry_attempts,
+            max_backoff_secs,
+        };
+
+        let worker_max_rps = env::var("WORKER_MAX_RPS")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_WORKER_MAX_RPS); // Default: 20 calls per second
+
+        // VALID_UNTIL_SKEW_SECS is the older name of the same setting
+        let clock_skew_tolerance_secs = match env::var("CLOCK_SKEW_TOLERANCE_SECS")
+            .or_else(|_| env::var("VALID_UNTIL_SKEW_SECS"))
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|secs| *secs >= 0)
+        {
+            Some(secs) if secs <= MAX_CLOCK_SKEW_TOLERANCE_SECS => secs,
+            Some(secs) => {
+                warn!(
+                    secs,
+                    max = MAX_CLOCK_SKEW_TOLERANCE_SECS,
+                    "CLOCK_SKEW_TOLERANCE_SECS is above the maximum - using the default"
+                );
+                DEFAULT_CLOCK_SKEW_TOLERANCE_SECS
+            }
+            None => DEFAULT_CLOCK_SKEW_TOLERANCE_SECS, // Default: 30s
+        };
+
+        // Amount caps on native SOL transfers
+        let transfer_limits = TransferLimits {
+            max_transfer_lamports: env::var("MAX_TRANSFER_LAMPORTS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|v| *v > 0), // Default: no per-transfer cap
+            daily_limit_lamports: env::var("DAILY_TRANSFER_LIMIT_LAMPORTS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|v| *v > 0), // Default: no rolling per-sender cap
+        };
+
+        // Blockchain RPC circuit breaker configuration
+        let circuit_failure_threshold = env::var("BLOCKCHAIN_CIRCUIT_FAILURE_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_CIRCUIT_FAILURE_THRESHOLD); // Default: 5 consecutive failures
+
+        let circuit_cooldown = env::var("BLOCKCHAIN_CIRCUIT_COOLDOWN_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_CIRCUIT_COOLDOWN); // Default: 30s
+
+        let circuit_breaker_config = CircuitBreakerConfig {
+            failure_threshold: circuit_failure_threshold,
+            cooldown: circuit_cooldown,
+        };
+
+        // Transfer archival configuration (soft-delete of old terminal transfers)
+        let enable_archival = env::var("ENABLE_ARCHIVAL")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        let archival_retention = env::var("ARCHIVAL_RETENTION_DAYS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|days| *days > 0)
+            .map(|days| std::time::Duration::from_secs(days * 24 * 60 * 60))
+            .unwrap_or(DEFAULT_ARCHIVAL_RETENTION); // Default: 90 days
+
+        let archival_poll_interval = env::var("ARCHIVAL_POLL_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_ARCHIVAL_INTERVAL); // Default: 1 hour
+
+        let archival_batch_size = env::var("ARCHIVAL_BATCH_SIZE")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|size| *size > 0)
+            .unwrap_or(DEFAULT_ARCHIVAL_BATCH_SIZE); // Default: 500 transfers per batch
+
+        let archival_config = ArchivalConfig {
+            poll_interval: archival_poll_interval,
+            retention: archival_retention,
+            batch_size: archival_batch_size,
+            enabled: enable_archival,
+        };
+
+        // Nonce sweep configuration (clears nonces of old, expired terminal transfers)
+        let enable_nonce_sweep = env::var("ENABLE_NONCE_SWEEP")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        let nonce_retention = env::var("NONCE_RETENTION_DAYS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|days| *days > 0)
+            .map(|days| std::time::Duration::from_secs(days * 24 * 60 * 60))
+            .unwrap_or(DEFAULT_NONCE_RETENTION); // Default: 30 days
+
+        let nonce_sweep_interval = env::var("NONCE_SWEEP_INTERVAL_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_NONCE_SWEEP_INTERVAL); // Default: 1 hour
+
+        let nonce_sweep_batch_size = env::var("NONCE_SWEEP_BATCH_SIZE")
+            .ok()
+            .and_then(|v| v.parse::<i64>().ok())
+            .filter(|size| *size > 0)
+            .unwrap_or(DEFAULT_NONCE_SWEEP_BATCH_SIZE); // Default: 500 nonces per batch
+
+        let nonce_sweep_config = NonceSweepConfig {
+            poll_interval: nonce_sweep_interval,
+            retention: nonce_retention,
+            batch_size: nonce_sweep_batch_size,
+            enabled: enable_nonce_sweep,
+        };
+
+        // Idempotency sweep configuration (deletes expired Idempotency-Key records)
+        let idempotency_sweep_config = IdempotencySweepConfig {
+            poll_interval: env::var("IDEMPOTENCY_SWEEP_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|secs| *secs > 0)
+                .map(std::time::Duration::from_secs)
+                .unwrap_or(DEFAULT_IDEMPOTENCY_SWEEP_INTERVAL), // Default: 1 hour
+            batch_size: env::var("IDEMPOTENCY_SWEEP_BATCH_SIZE")
+                .ok()
+                .and_then(|v| v.parse::<i64>().ok())
+                .filter(|size| *size > 0)
+                .unwrap_or(DEFAULT_IDEMPOTENCY_SWEEP_BATCH_SIZE), // Default: 500 records per batch
+            enabled: env::var("ENABLE_IDEMPOTENCY_SWEEP")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(true), // Enabled by default
+        };
+
+        // Slack/Discord alerting (enabled by setting a chat webhook URL)
+        let mut alert_sinks: Vec<Arc<dyn AlertSink>> = Vec::new();
+        if let Some(url) = env::var("ALERT_SLACK_WEBHOOK_URL")
+            .ok()
+            .filter(|u| !u.is_empty())
+        {
+            alert_sinks.push(Arc::new(SlackWebhookSink::new(url)));
+        }
+        if let Some(url) = env::var("ALERT_DISCORD_WEBHOOK_URL")
+            .ok()
+            .filter(|u| !u.is_empty())
+        {
+            alert_sinks.push(Arc::new(DiscordWebhookSink::new(url)));
+        }
+        let alert_cooldown = env::var("ALERT_COOLDOWN_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_ALERT_COOLDOWN); // Default: 600s
+        let alert_dispatcher = (!alert_sinks.is_empty())
+            .then(|| Arc::new(AlertDispatcher::new(alert_sinks, alert_cooldown)));
+        let rejection_alert_threshold = env::var("ALERT_REJECTION_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .filter(|v| *v > 0)
+            .unwrap_or(DEFAULT_REJECTION_ALERT_THRESHOLD); // Default: 20 rejections
+        let rejection_alert_window = env::var("ALERT_REJECTION_WINDOW_SECS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|secs| *secs > 0)
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(DEFAULT_REJECTION_ALERT_WINDOW); // Default: 300s
+
+        // Outbound status webhooks (enabled by setting the signing secret)
+        let outbound_webhook_config = env::var("OUTBOUND_WEBHOOK_SECRET")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .map(|secret| {
+                let max_attempts = env::var("OUTBOUND_WEBHOOK_MAX_ATTEMPTS")
+                    .ok()
+                    .and_then(|v| v.parse::<i32>().ok())
+                    .filter(|n| *n > 0)
+                    .unwrap_or(DEFAULT_OUTBOUND_WEBHOOK_MAX_ATTEMPTS); // Default: 8
+                let poll_interval = env::var("OUTBOUND_WEBHOOK_POLL_INTERVAL_SECS")
+                    .ok()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .filter(|secs| *secs > 0)
+                    .map(std::time::Duration::from_secs)
+                    .unwrap_or(DEFAULT_OUTBOUND_WEBHOOK_POLL_INTERVAL); // Default: 5s
+                let base_backoff = env::var("OUTBOUND_WEBHOOK_BASE_BACKOFF_SECS")
+                    .ok()
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .filter(|secs| *secs > 0)
+                    .map(std::time::Duration::from_secs)
+                    .unwrap_or(DEFAULT_OUTBOUND_WEBHOOK_BASE_BACKOFF); // Default: 10s
+                let allow_private_callback_urls = env::var("OUTBOUND_WEBHOOK_ALLOW_PRIVATE_URLS")
+                    .map(|v| v == "true" || v == "1")
+                    .unwrap_or(false); // Local development only
+                OutboundWebhookConfig {
+                    max_attempts,
+                    poll_interval,
+                    base_backoff,
+                    allow_private_callback_urls,
+                    ..OutboundWebhookConfig::new(secret)
+                }
+                .with_default_url(
+                    env::var("OUTBOUND_WEBHOOK_URL")
+                        .ok()
+                        .filter(|u| !u.is_empty()),
+                )
+            });
+
+        // Fee payer balance monitor; alerts go to the global outbound webhook URL
+        let enable_balance_monitor = env::var("ENABLE_BALANCE_MONITOR")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(true); // Enabled by default
+        let balance_monitor_config = enable_balance_monitor.then(|| BalanceMonitorConfig {
+            poll_interval: env::var("BALANCE_CHECK_INTERVAL_SECS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|secs| *secs > 0)
+                .map(std::time::Duration::from_secs)
+                .unwrap_or(DEFAULT_BALANCE_CHECK_INTERVAL), // Default: 60s
+            min_lamports: env::var("MIN_FEE_PAYER_LAMPORTS")
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(DEFAULT_MIN_FEE_PAYER_LAMPORTS), // Default: 0.1 SOL
+            alert: outbound_webhook_config
+                .clone()
+                .filter(|webhooks| webhooks.default_url.is_some()),
+            alert_dispatcher: alert_dispatcher.clone(),
+        });
+
         let worker_config = WorkerConfig {
             enabled: enable_background_worker,
             enable_privacy_checks,
+            concurrency: worker_concurrency,
+            drain_timeout: worker_drain_timeout,
+            // Pause the worker for as long as the circuit stays open
+            circuit_open_backoff: circuit_cooldown,
             ..Default::default()
         };
 
         Ok(Self {
             database_url,
+            database_replica_url,
+            skip_migrations,
             blockchain_rpc_url,
             signing_key,
+            additional_signing_keys,
+            retired_signing_keys,
+            key_selection_policy,
             host,
             port,
             enable_rate_limiting,
             rate_limit_config,
             enable_background_worker,
+            blockchain_retry_config,
+            worker_max_rps,
+            clock_skew_tolerance_secs,
+            transfer_limits,
             worker_config,
             range_api_key,
             range_api_url,
             range_risk_threshold,
+            range_threshold_overrides,
+            compliance_policy,
+            screening_policy,
+            default_token_mint,
+            range_cache_ttl_secs,
+            range_retry_config,
+            range_fail_open,
+            compliance_providers,
+            trm_api_key,
+            trm_api_url,
+            trm_risk_threshold,
+            trm_retry_config,
+            trm_fail_open,
+            trm_cache_ttl_secs,
             helius_webhook_secret,
             quicknode_webhook_secret,
             admin_api_key,
+            api_keys,
             enable_privacy_checks,
             use_jito_bundles,
             jito_tip_lamports,
+            jito_dynamic_tip,
+            jito_dynamic_tip_config,
+            jito_tip_account_refresh_secs,
+            use_versioned_tx,
+            address_lookup_table,
+            nonce_account,
+            squads_multisig,
+            max_priority_fee,
+            compute_unit_limit,
+            gross_up_transfer_fees,
+            preflight_balance_check,
+            close_empty_token_accounts,
+            skip_preflight,
+            rent_cache_ttl,
+            mint_cache_ttl,
+            confirmation_poll_interval,
+            confirmation_poll_max_interval,
+            resubmit_deadline,
+            sanctioned_mints,
             enable_stale_crank,
             crank_poll_interval_secs,
             crank_stale_after_secs,
             crank_batch_size,
+            processing_lease_secs,
+            blocklist_sweep_interval_secs,
+            blocklist_hit_flush_interval_secs,
+            metrics_refresh_interval_secs,
+            max_bulk_transfers,
+            explorer_base_url,
+            enable_ofac_sync,
+            ofac_sync_url,
+            ofac_sync_interval_secs,
+            archival_config,
+            nonce_sweep_config,
+            idempotency_sweep_config,
+            circuit_breaker_config,
+            outbound_webhook_config,
+            balance_monitor_config,
+            alert_dispatcher,
+            rejection_alert_threshold,
+            rejection_alert_window,
         })
     }
 
+    /// Read `{prefix}_MAX_RETRIES` and `{prefix}_RETRY_DELAY_MS`
+    fn retry_config_from_env(prefix: &str) -> ComplianceRetryConfig {
+        let default_retry = ComplianceRetryConfig::default();
+        ComplianceRetryConfig {
+            max_retries: env::var(format!("{}_MAX_RETRIES", prefix))
+                .ok()
+                .and_then(|v| v.parse::<u32>().ok())
+                .unwrap_or(default_retry.max_retries), // Default: 2
+            retry_delay: env::var(format!("{}_RETRY_DELAY_MS", prefix))
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .map(std::time::Duration::from_millis)
+                .unwrap_or(default_retry.retry_delay), // Default: 200ms
+        }
+    }
+
+    /// Load the issuer keys: `ISSUER_PRIVATE_KEYS` (comma-separated, first is
+    /// primary) if set, otherwise the single `ISSUER_PRIVATE_KEY`.
+    fn load_signing_keys() -> Result<(SigningKey, Vec<SigningKey>)> {
+        if !env::var("ISSUER_PRIVATE_KEYS").is_ok_and(|v| !v.trim().is_empty()) {
+            return Ok((Self::load_signing_key()?, Vec::new()));
+        }
+
+        if env::var("ISSUER_PRIVATE_KEY").is_ok_and(|v| !v.trim().is_empty()) {
+            warn!(
+                "Both ISSUER_PRIVATE_KEYS and ISSUER_PRIVATE_KEY are set - using ISSUER_PRIVATE_KEYS"
+            );
+        }
+
+        info!("Loading signing keys from environment");
+        let mut keys = Self::parse_signing_keys("ISSUER_PRIVATE_KEYS")?.into_iter();
+        let primary = keys
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("ISSUER_PRIVATE_KEYS contains no keys"))?;
+        Ok((primary, keys.collect()))
+    }
+
+    /// Parse a comma-separated list of Base58 private keys (empty if unset)
+    fn parse_signing_keys(var: &str) -> Result<Vec<SigningKey>> {
+        let Ok(keys_str) = env::var(var) else {
+            return Ok(Vec::new());
+        };
+        keys_str
+            .split(',')
+            .map(str::trim)
+            .filter(|key| !key.is_empty())
+            .enumerate()
+            .map(|(index, key)| {
+                signing_key_from_base58(&SecretString::from(key.to_string())).with_context(|| {
+                    format!("Failed to parse key #{} of {} as Base58", index + 1, var)
+                })
+            })
+            .collect()
+    }
+
     fn load_signing_key() -> Result<SigningKey> {
         let key_str = env::var("ISSUER_PRIVATE_KEY").map_err(|_| {
             anyhow::anyhow!(
@@ -210,16 +1074,6 @@ impl Config {
     }
 }
 
-fn init_tracing() {
-    let env_filter = EnvFilter::try_from_default_env()
-        .unwrap_or_else(|_| EnvFilter::new("info,tower_http=debug,sqlx=warn"));
-
-    tracing_subscriber::registry()
-        .with(env_filter)
-        .with(tracing_subscriber::fmt::layer())
-        .init();
-}
-
 async fn shutdown_signal() {
     let ctrl_c = async {
         signal::ctrl_c()
@@ -247,7 +1101,8 @@ async fn shutdown_signal() {
 #[tokio::main]
 async fn main() -> Result<()> {
     dotenv().ok();
-    init_tracing();
+    // Held until main returns so buffered spans are flushed on shutdown
+    let tracing_guard = init_tracing(LogFormat::from_env());
 
     info!(
         "🏗️  Solana Compliance Relayer v{}",
@@ -256,16 +1111,38 @@ async fn main() -> Result<()> {
 
     let config = Config::from_env()?;
 
+    // Install the metrics recorder before anything records
+    let metrics_handle = install_prometheus_recorder();
+
     let public_key = bs58::encode(config.signing_key.verifying_key().as_bytes()).into_string();
     info!("🔑 Public key: {}", public_key);
+    for key in &config.additional_signing_keys {
+        let public_key = bs58::encode(key.verifying_key().as_bytes()).into_string();
+        info!("🔑 Additional public key: {}", public_key);
+    }
+    for key in &config.retired_signing_keys {
+        let public_key = bs58::encode(key.verifying_key().as_bytes()).into_string();
+        info!("🔑 Retired public key: {}", public_key);
+    }
 
     info!("📦 Initializing infrastructure...");
 
     // Initialize database
-    let db_config = PostgresConfig::default();
-    let postgres_client = PostgresClient::new(&config.database_url, db_config).await?;
-    postgres_client.run_migrations().await?;
-    info!("   ✓ Database connected and migrations applied");
+    let db_config = PostgresConfig::from_env()?;
+    let mut postgres_client = PostgresClient::new(&config.database_url, db_config.clone()).await?;
+    let migrations_skipped = config.skip_migrations;
+    if migrations_skipped {
+        warn!("   ⚠ Database connected, migrations skipped (SKIP_MIGRATIONS)");
+    } else {
+        postgres_client.run_migrations().await?;
+        info!("   ✓ Database connected and migrations applied");
+    }
+    if let Some(replica_url) = &config.database_replica_url {
+        postgres_client = postgres_client
+            .with_read_replica(replica_url, db_config)
+            .await?;
+        info!("   ✓ Read replica connected");
+    }
 
     // Get pool reference for blocklist manager (before moving postgres_client into Arc)
     let db_pool = postgres_client.pool().clone();
@@ -282,6 +1159,16 @@ async fn main() -> Result<()> {
         if matches!(provider_type, RpcProviderType::QuickNode) {
             // Read optional Jito region (e.g., "ny", "amsterdam", "frankfurt", "tokyo")
             let jito_region = env::var("JITO_REGION").ok();
+            // Optional endpoint bundles are sent to instead of the RPC URL
+            let block_engine_url = env::var("JITO_BLOCK_ENGINE_URL")
+                .ok()
+                .filter(|url| !url.is_empty());
+
+            // Track bundle IDs so the crank can retry dropped bundles early
+            // Default: false
+            let track_bundle_status = env::var("JITO_TRACK_BUNDLES")
+                .map(|v| v == "true" || v == "1")
+                .unwrap_or(false);
 
             let jito_config = QuickNodeSubmissionConfig {
                 rpc_url: config.blockchain_rpc_url.clone(),
@@ -289,7 +1176,15 @@ async fn main() -> Result<()> {
                 tip_lamports: config.jito_tip_lamports,
                 max_bundle_retries: 2,
                 region: jito_region.clone(),
+                block_engine_url: block_engine_url.clone(),
+                track_bundle_status,
             };
+            if let Some(url) = &block_engine_url {
+                info!("   ✓ Jito bundles sent to block engine {}", url);
+            }
+            if track_bundle_status {
+                info!("   ✓ Jito bundle status tracking enabled");
+            }
             info!(
                 "   ✓ Jito bundle submission enabled (tip: {} lamports, region: {:?})",
                 config.jito_tip_lamports,
@@ -313,43 +1208,250 @@ async fn main() -> Result<()> {
         (None, None)
     };
 
-    let blockchain_client = RpcBlockchainClient::with_defaults_and_submission_strategy(
+    if config.use_versioned_tx {
+        match config.address_lookup_table {
+            Some(table) => info!(
+                "   ✓ Versioned transactions enabled (lookup table: {})",
+                table
+            ),
+            None => info!("   ✓ Versioned transactions enabled (no lookup table)"),
+        }
+    } else if config.address_lookup_table.is_some() {
+        warn!("   ⚠ ADDRESS_LOOKUP_TABLE is set but USE_VERSIONED_TX is disabled - ignoring");
+    }
+
+    match config.nonce_account {
+        Some(nonce_account) => info!(
+            "   ✓ Durable nonce mode enabled (account: {})",
+            nonce_account
+        ),
+        None => info!("   ○ Durable nonce mode disabled (recent blockhash)"),
+    }
+
+    let rpc_config = RpcClientConfig {
+        use_versioned_tx: config.use_versioned_tx,
+        address_lookup_table: config.address_lookup_table,
+        nonce_account: config.nonce_account,
+        max_priority_fee_microlamports: config.max_priority_fee,
+        compute_unit_limit: config.compute_unit_limit,
+        gross_up_transfer_fees: config.gross_up_transfer_fees,
+        preflight_balance_check: config.preflight_balance_check,
+        close_empty_token_accounts: config.close_empty_token_accounts,
+        skip_preflight: config.skip_preflight,
+        rent_cache_ttl: config.rent_cache_ttl,
+        mint_cache_ttl: config.mint_cache_ttl,
+        confirmation_poll_interval: config.confirmation_poll_interval,
+        confirmation_poll_max_interval: config.confirmation_poll_max_interval,
+        resubmit_deadline: config.resubmit_deadline,
+        sanctioned_mints: config.sanctioned_mints.clone(),
+        ..Default::default()
+    };
+    info!(
+        "   ✓ Compute budget: limit {} CU, priority fee ceiling {} micro-lamports",
+        config.compute_unit_limit, config.max_priority_fee
+    );
+    let jito_enabled = jito_tip_for_client.is_some();
+    let mut blockchain_client = RpcBlockchainClient::new_with_submission_strategy(
         &config.blockchain_rpc_url,
         config.signing_key,
+        rpc_config,
         submission_strategy,
         jito_tip_for_client,
     )?;
+    if !config.additional_signing_keys.is_empty() {
+        blockchain_client = blockchain_client.with_additional_signing_keys(
+            &config.additional_signing_keys,
+            config.key_selection_policy,
+        )?;
+        info!(
+            "   ✓ {} signing keys (selection: {})",
+            config.additional_signing_keys.len() + 1,
+            config.key_selection_policy
+        );
+    }
+    if !config.retired_signing_keys.is_empty() {
+        blockchain_client =
+            blockchain_client.with_retired_signing_keys(&config.retired_signing_keys)?;
+        info!(
+            "   ✓ {} retired signing key(s) kept for pinned transfers",
+            config.retired_signing_keys.len()
+        );
+    }
+    if let Some(squads) = config.squads_multisig.clone() {
+        info!(
+            "   ✓ Transfers proposed to Squads multisig {} (vault {})",
+            squads.multisig,
+            squads.vault()
+        );
+        blockchain_client = blockchain_client.with_multisig(squads);
+    }
+    if config.jito_dynamic_tip {
+        if jito_enabled {
+            let tip_config = config.jito_dynamic_tip_config.clone();
+            info!(
+                "   ✓ Dynamic Jito tips enabled (p{}, {}-{} lamports)",
+                tip_config.percentile, tip_config.floor_lamports, tip_config.ceiling_lamports
+            );
+            blockchain_client =
+                blockchain_client.with_dynamic_tip(DynamicTipStrategy::new(tip_config));
+        } else {
+            warn!("   ⚠ JITO_DYNAMIC_TIP=true but Jito bundles are disabled - ignoring");
+        }
+    }
     info!("   ✓ Blockchain client created");
+    let blockchain_client = CircuitBreakerClient::new(
+        Arc::new(blockchain_client),
+        config.circuit_breaker_config.clone(),
+    );
+    let blockchain_client = match &config.alert_dispatcher {
+        Some(alerts) => blockchain_client.with_alerts(Arc::clone(alerts)),
+        None => blockchain_client,
+    };
+    info!(
+        "   ✓ Blockchain circuit breaker enabled (opens after {} failures, cooldown {}s)",
+        config.circuit_breaker_config.failure_threshold,
+        config.circuit_breaker_config.cooldown.as_secs()
+    );
+
+    // Keep Jito tip accounts current (falls back to the hardcoded list)
+    let tip_account_shutdown_tx = if jito_enabled && config.jito_tip_account_refresh_secs > 0 {
+        let (_tip_handle, shutdown_tx) = spawn_tip_account_refresh(
+            config.blockchain_rpc_url.clone(),
+            std::time::Duration::from_secs(config.jito_tip_account_refresh_secs),
+        );
+        info!(
+            "   ✓ Jito tip account refresh started (interval: {}s)",
+            config.jito_tip_account_refresh_secs
+        );
+        Some(shutdown_tx)
+    } else {
+        None
+    };
 
-    let compliance_provider = solana_compliance_relayer::infra::RangeComplianceProvider::new(
+    let range_provider = solana_compliance_relayer::infra::RangeComplianceProvider::new(
         config.range_api_key.clone(),
         config.range_api_url.clone(),
         Some(config.range_risk_threshold),
-    );
+    )
+    .with_threshold_overrides(config.range_threshold_overrides.clone())
+    .with_retry_config(config.range_retry_config.clone())
+    .with_fail_open(config.range_fail_open)
+    .with_cache_ttl(std::time::Duration::from_secs(config.range_cache_ttl_secs));
     if config.range_api_key.is_some() {
         info!("   ✓ Compliance provider created (Range Protocol API)");
         info!("   ✓ Risk threshold: {}", config.range_risk_threshold);
+        if !config.range_threshold_overrides.is_empty() {
+            info!(
+                "   ✓ Per-mint risk threshold overrides: {}",
+                config.range_threshold_overrides.len()
+            );
+        }
+        info!(
+            "   ✓ Compliance cache TTL: {}s (rejections: {}s)",
+            config.range_cache_ttl_secs,
+            config.range_cache_ttl_secs * u64::from(REJECTED_CACHE_TTL_MULTIPLIER)
+        );
+        info!(
+            "   ✓ Range retries: {} (base delay {}ms)",
+            config.range_retry_config.max_retries,
+            config.range_retry_config.retry_delay.as_millis()
+        );
+        if config.range_fail_open {
+            warn!(
+                "   ⚠ RANGE_FAIL_OPEN enabled - transfers are approved when Range is unavailable"
+            );
+        }
     } else {
         warn!("   ⚠ Compliance provider created (MOCK MODE - no RANGE_API_KEY)");
     }
 
+    // Ordered compliance chain, as listed in COMPLIANCE_PROVIDER
+    let mut compliance_chain: Vec<Arc<dyn ComplianceProvider>> = Vec::new();
+    for provider in &config.compliance_providers {
+        match provider.as_str() {
+            "range" => compliance_chain.push(Arc::new(range_provider.clone())),
+            "trm" => {
+                let trm_provider = TrmComplianceProvider::new(
+                    config
+                        .trm_api_key
+                        .clone()
+                        .expect("TRM_API_KEY validated in Config::from_env"),
+                    config.trm_api_url.clone(),
+                    Some(config.trm_risk_threshold),
+                )
+                .with_retry_config(config.trm_retry_config.clone())
+                .with_fail_open(config.trm_fail_open)
+                .with_cache_ttl(std::time::Duration::from_secs(config.trm_cache_ttl_secs));
+                info!(
+                    "   ✓ Compliance provider created (TRM Labs API, threshold: {})",
+                    config.trm_risk_threshold
+                );
+                if config.trm_fail_open {
+                    warn!(
+                        "   ⚠ TRM_FAIL_OPEN enabled - transfers are approved when TRM is unavailable"
+                    );
+                }
+                compliance_chain.push(Arc::new(trm_provider));
+            }
+            _ => unreachable!("COMPLIANCE_PROVIDER validated in Config::from_env"),
+        }
+    }
+    let compliance_provider: Arc<dyn ComplianceProvider> = if compliance_chain.len() > 1 {
+        info!(
+            "   ✓ Composite compliance provider ({} providers, policy: {})",
+            compliance_chain.len(),
+            config.compliance_policy
+        );
+        Arc::new(CompositeComplianceProvider::new(
+            compliance_chain,
+            config.compliance_policy,
+        ))
+    } else {
+        compliance_chain
+            .into_iter()
+            .next()
+            .expect("COMPLIANCE_PROVIDER names at least one provider")
+    };
+
     // Initialize internal blocklist manager (uses db_pool directly)
-    let blocklist = BlocklistManager::new(db_pool).await?;
+    let blocklist = BlocklistManager::new(db_pool.clone()).await?;
     info!(
         "   ✓ Blocklist manager initialized ({} entries loaded)",
         blocklist.len()
     );
     let blocklist = Arc::new(blocklist);
+    let (_sweep_handle, blocklist_sweep_shutdown_tx) = spawn_expiry_sweep(
+        Arc::clone(&blocklist),
+        std::time::Duration::from_secs(config.blocklist_sweep_interval_secs),
+    );
+    info!(
+        "   ✓ Blocklist expiry sweep started (interval: {}s)",
+        config.blocklist_sweep_interval_secs
+    );
+    let (blocklist_hit_flush_handle, blocklist_hit_flush_shutdown_tx) = spawn_hit_flush(
+        Arc::clone(&blocklist),
+        std::time::Duration::from_secs(config.blocklist_hit_flush_interval_secs),
+    );
+    info!(
+        "   ✓ Blocklist hit flush started (interval: {}s)",
+        config.blocklist_hit_flush_interval_secs
+    );
 
     // Create application state
     let app_state = AppState::with_webhook_secrets(
         Arc::new(postgres_client),
         Arc::new(blockchain_client),
-        Arc::new(compliance_provider),
+        compliance_provider,
         config.helius_webhook_secret.clone(),
         config.quicknode_webhook_secret.clone(),
     )
-    .with_admin_api_key(config.admin_api_key.clone());
+    .with_admin_api_key(config.admin_api_key.clone())
+    .with_api_keys(config.api_keys.clone())
+    .with_max_bulk_transfers(config.max_bulk_transfers)
+    .with_explorer(ExplorerLinks::for_rpc_url(
+        config.explorer_base_url.clone(),
+        &config.blockchain_rpc_url,
+    ));
 
     if config.helius_webhook_secret.is_some() {
         info!("   ✓ Helius webhook secret configured");
@@ -369,6 +1471,12 @@ async fn main() -> Result<()> {
         warn!("   ⚠ ADMIN_API_KEY not configured (/admin auth disabled for local development)");
     }
 
+    if config.api_keys.enforces(ApiKeyScope::Submit) {
+        info!("   ✓ API keys configured (submit routes require the submit scope)");
+    } else {
+        warn!("   ⚠ No API key with the submit scope (submit routes open for local development)");
+    }
+
     // Initialize privacy health check service (QuickNode only)
     let privacy_service = if config.enable_privacy_checks {
         let provider_type = RpcProviderType::detect(&config.blockchain_rpc_url);
@@ -400,28 +1508,95 @@ async fn main() -> Result<()> {
     };
     let app_state = app_state.with_blocklist(Arc::clone(&blocklist));
 
+    // Initialize allowlist of known-good addresses (uses db_pool directly)
+    let allowlist = AllowlistManager::new(db_pool).await?;
+    info!(
+        "   ✓ Allowlist manager initialized ({} entries loaded)",
+        allowlist.len()
+    );
+    let app_state = app_state.with_allowlist(Arc::new(allowlist));
+
+    let app_state = match config.outbound_webhook_config.clone() {
+        Some(webhook_config) => app_state.with_outbound_webhooks(webhook_config),
+        None => app_state,
+    };
+
+    let app_state = app_state
+        .with_retry_config(config.blockchain_retry_config)
+        .with_rpc_limiter(Arc::new(RpcRateLimiter::new(config.worker_max_rps)))
+        .with_clock_skew_tolerance(chrono::Duration::seconds(config.clock_skew_tolerance_secs))
+        .with_transfer_limits(config.transfer_limits)
+        .with_screening_policy(config.screening_policy.clone());
+
+    let app_state = match config.default_token_mint.clone() {
+        Some(mint) => {
+            info!("   ✓ Default token mint: {}", mint);
+            app_state.with_default_token_mint(mint)
+        }
+        None => app_state,
+    };
+
+    let app_state = match &config.alert_dispatcher {
+        Some(alerts) => {
+            info!(
+                "   ✓ Chat alerting enabled ({}; rejection spike: {} in {}s)",
+                alerts.sink_names().join(", "),
+                config.rejection_alert_threshold,
+                config.rejection_alert_window.as_secs()
+            );
+            app_state.with_rejection_alerts(Arc::new(RejectionRateDetector::new(
+                Arc::clone(alerts),
+                config.rejection_alert_threshold,
+                config.rejection_alert_window,
+            )))
+        }
+        None => {
+            info!("   ○ Chat alerting disabled (no ALERT_*_WEBHOOK_URL set)");
+            app_state
+        }
+    };
+
+    let fee_payer_balance = FeePayerBalanceTracker::default();
+    let app_state = if config.balance_monitor_config.is_some() {
+        app_state.with_fee_payer_balance(fee_payer_balance.clone())
+    } else {
+        app_state
+    };
+
     // Initialize risk service for pre-flight compliance checks
     let range_provider_arc = Arc::new(
         solana_compliance_relayer::infra::RangeComplianceProvider::new(
             config.range_api_key.clone(),
             config.range_api_url.clone(),
             Some(config.range_risk_threshold),
-        ),
+        )
+        .with_threshold_overrides(config.range_threshold_overrides.clone())
+        .with_retry_config(config.range_retry_config.clone()),
     );
     let risk_service = Arc::new(RiskService::new(
         Arc::clone(&app_state.db_client),
         Arc::clone(&app_state.blockchain_client),
         range_provider_arc,
-        Some(blocklist),
+        Some(Arc::clone(&blocklist)),
     ));
     let app_state = app_state.with_risk_service(risk_service);
     info!("   ✓ Risk check service initialized");
 
-    let app_state = Arc::new(app_state);
+    let app_state = Arc::new(app_state.with_metrics_handle(metrics_handle.clone()));
+
+    let (_metrics_task, metrics_shutdown_tx) = spawn_metrics_refresh(
+        Arc::clone(&app_state.db_client),
+        metrics_handle,
+        std::time::Duration::from_secs(config.metrics_refresh_interval_secs),
+    );
+    info!(
+        "   ✓ Prometheus metrics enabled at /metrics (queue depth refresh: {}s)",
+        config.metrics_refresh_interval_secs
+    );
 
     // Start background worker if enabled
-    let worker_shutdown_tx = if config.enable_background_worker {
-        let (_worker_handle, shutdown_tx) = if let Some(ref privacy_svc) = privacy_service {
+    let worker = if config.enable_background_worker {
+        let (worker_handle, shutdown_tx) = if let Some(ref privacy_svc) = privacy_service {
             spawn_worker_with_privacy(
                 Arc::clone(&app_state.service),
                 config.worker_config.clone(),
@@ -431,27 +1606,31 @@ async fn main() -> Result<()> {
             spawn_worker(Arc::clone(&app_state.service), config.worker_config.clone())
         };
         info!("   ✓ Background worker started");
-        Some(shutdown_tx)
+        Some((worker_handle, shutdown_tx))
     } else {
         info!("   ○ Background worker disabled");
         None
     };
 
     // Start stale transaction crank (active polling fallback for webhook failures)
-    let crank_shutdown_tx = if config.enable_stale_crank && config.enable_background_worker {
+    let crank = if config.enable_stale_crank && config.enable_background_worker {
         let crank_config = CrankConfig {
             poll_interval: std::time::Duration::from_secs(config.crank_poll_interval_secs),
             stale_after_secs: config.crank_stale_after_secs,
             batch_size: config.crank_batch_size,
+            processing_lease_secs: config.processing_lease_secs,
+            // Shares the worker's budget (WORKER_DRAIN_TIMEOUT_SECS)
+            drain_timeout: config.worker_config.drain_timeout,
             enabled: true,
         };
-        let (_crank_handle, shutdown_tx) =
-            spawn_crank(Arc::clone(&app_state.service), crank_config);
+        let (crank_handle, shutdown_tx) = spawn_crank(Arc::clone(&app_state.service), crank_config);
         info!(
-            "   ✓ Stale transaction crank started (poll: {}s, stale_after: {}s)",
-            config.crank_poll_interval_secs, config.crank_stale_after_secs
+            "   ✓ Stale transaction crank started (poll: {}s, stale_after: {}s, processing lease: {}s)",
+            config.crank_poll_interval_secs,
+            config.crank_stale_after_secs,
+            config.processing_lease_secs
         );
-        Some(shutdown_tx)
+        Some((crank_handle, shutdown_tx))
     } else if !config.enable_stale_crank {
         info!("   ○ Stale transaction crank disabled");
         None
@@ -460,6 +1639,128 @@ async fn main() -> Result<()> {
         None
     };
 
+    // Start transfer archival (soft-deletes old terminal transfers)
+    let archival_shutdown_tx = if config.archival_config.enabled {
+        let (_archival_handle, shutdown_tx) = spawn_archival(
+            Arc::clone(&app_state.db_client),
+            config.archival_config.clone(),
+        );
+        info!(
+            "   ✓ Transfer archival started (retention: {}d, interval: {}s)",
+            config.archival_config.retention.as_secs() / 86_400,
+            config.archival_config.poll_interval.as_secs()
+        );
+        Some(shutdown_tx)
+    } else {
+        info!("   ○ Transfer archival disabled");
+        None
+    };
+
+    // Start nonce sweep (clears nonces that can no longer be replayed)
+    let nonce_sweep_shutdown_tx = if config.nonce_sweep_config.enabled {
+        let (_nonce_sweep_handle, shutdown_tx) = spawn_nonce_sweep(
+            Arc::clone(&app_state.db_client),
+            config.nonce_sweep_config.clone(),
+        );
+        info!(
+            "   ✓ Nonce sweep started (retention: {}d, interval: {}s)",
+            config.nonce_sweep_config.retention.as_secs() / 86_400,
+            config.nonce_sweep_config.poll_interval.as_secs()
+        );
+        Some(shutdown_tx)
+    } else {
+        info!("   ○ Nonce sweep disabled");
+        None
+    };
+
+    // Start idempotency sweep (deletes expired Idempotency-Key records)
+    let idempotency_sweep_shutdown_tx = if config.idempotency_sweep_config.enabled {
+        let (_idempotency_sweep_handle, shutdown_tx) = spawn_idempotency_sweep(
+            Arc::clone(&app_state.db_client),
+            config.idempotency_sweep_config.clone(),
+        );
+        info!(
+            "   ✓ Idempotency sweep started (interval: {}s)",
+            config.idempotency_sweep_config.poll_interval.as_secs()
+        );
+        Some(shutdown_tx)
+    } else {
+        info!("   ○ Idempotency sweep disabled");
+        None
+    };
+
+    // Start outbound webhook dispatcher (delivers queued status notifications)
+    let webhook_dispatcher_shutdown_tx = match config.outbound_webhook_config.clone() {
+        Some(webhook_config) => {
+            let global_url = webhook_config.default_url.is_some();
+            let (_dispatcher_handle, shutdown_tx) =
+                spawn_webhook_dispatcher(Arc::clone(&app_state.db_client), webhook_config);
+            info!(
+                "   ✓ Outbound webhook dispatcher started (global callback URL: {})",
+                if global_url { "set" } else { "not set" }
+            );
+            Some(shutdown_tx)
+        }
+        None => {
+            info!("   ○ Outbound webhooks disabled (OUTBOUND_WEBHOOK_SECRET not set)");
+            None
+        }
+    };
+
+    // Start fee payer balance monitor (warns before a drained payer fails transfers)
+    let balance_monitor_shutdown_tx = match config.balance_monitor_config.clone() {
+        Some(monitor_config) => {
+            info!(
+                "   ✓ Fee payer balance monitor started (min: {} lamports, interval: {}s, alert: {})",
+                monitor_config.min_lamports,
+                monitor_config.poll_interval.as_secs(),
+                match (
+                    monitor_config.alert.is_some(),
+                    monitor_config.alert_dispatcher.is_some()
+                ) {
+                    (true, true) => "webhook + chat",
+                    (true, false) => "webhook",
+                    (false, true) => "chat",
+                    (false, false) => "log only",
+                }
+            );
+            let (_monitor_handle, shutdown_tx) = spawn_balance_monitor(
+                Arc::clone(&app_state.blockchain_client),
+                monitor_config,
+                fee_payer_balance,
+            );
+            Some(shutdown_tx)
+        }
+        None => {
+            info!("   ○ Fee payer balance monitor disabled");
+            None
+        }
+    };
+
+    // Start OFAC SDN sync (keeps sanctioned addresses in the blocklist)
+    let ofac_shutdown_tx = match (config.enable_ofac_sync, config.ofac_sync_url.clone()) {
+        (true, Some(source_url)) => {
+            let ofac_config = OfacSyncConfig {
+                source_url,
+                interval: std::time::Duration::from_secs(config.ofac_sync_interval_secs),
+            };
+            let (_ofac_handle, shutdown_tx) = spawn_ofac_sync(Arc::clone(&blocklist), ofac_config);
+            info!(
+                "   ✓ OFAC SDN sync started (interval: {}s)",
+                config.ofac_sync_interval_secs
+            );
+            Some(shutdown_tx)
+        }
+        (true, None) => {
+            warn!("   ⚠ ENABLE_OFAC_SYNC is set but OFAC_SYNC_URL is missing - sync disabled");
+            None
+        }
+        (false, _) => {
+            info!("   ○ OFAC SDN sync disabled");
+            None
+        }
+    };
+
     // Create router
     let router = if config.enable_rate_limiting {
         info!("   ✓ Rate limiting enabled");
@@ -480,14 +1781,77 @@ async fn main() -> Result<()> {
         .with_graceful_shutdown(shutdown_signal())
         .await?;
 
-    // Signal worker and crank to shutdown
-    if let Some(tx) = worker_shutdown_tx {
+    // Signal background tasks to shutdown
+    let worker_handle = worker.map(|(handle, tx)| {
+        let _ = tx.send(true);
+        handle
+    });
+    let crank_handle = crank.map(|(handle, tx)| {
+        let _ = tx.send(true);
+        handle
+    });
+    if let Some(tx) = archival_shutdown_tx {
+        let _ = tx.send(true);
+    }
+    if let Some(tx) = nonce_sweep_shutdown_tx {
+        let _ = tx.send(true);
+    }
+    if let Some(tx) = idempotency_sweep_shutdown_tx {
         let _ = tx.send(true);
     }
-    if let Some(tx) = crank_shutdown_tx {
+    if let Some(tx) = webhook_dispatcher_shutdown_tx {
         let _ = tx.send(true);
     }
+    if let Some(tx) = balance_monitor_shutdown_tx {
+        let _ = tx.send(true);
+    }
+    let _ = blocklist_sweep_shutdown_tx.send(true);
+    let _ = blocklist_hit_flush_shutdown_tx.send(true);
+    if let Some(tx) = ofac_shutdown_tx {
+        let _ = tx.send(true);
+    }
+    if let Some(tx) = tip_account_shutdown_tx {
+        let _ = tx.send(true);
+    }
+    let _ = metrics_shutdown_tx.send(true);
+
+    let mut summary = ShutdownSummary {
+        migrations_skipped,
+        ..ShutdownSummary::default()
+    };
+    // Let the worker finish in-flight submissions (bounded by its drain timeout)
+    if let Some(handle) = worker_handle {
+        match handle.await {
+            Ok(outcome) => summary.worker = Some(outcome),
+            Err(e) => {
+                warn!(error = %e, "Background worker task failed during shutdown");
+                summary.failed_tasks.push("worker");
+            }
+        }
+    }
+    // Let the crank finish the status checks of its current batch (bounded by
+    // the same drain timeout)
+    if let Some(handle) = crank_handle {
+        match handle.await {
+            Ok(outcome) => summary.crank = Some(outcome),
+            Err(e) => {
+                warn!(error = %e, "Stale transaction crank task failed during shutdown");
+                summary.failed_tasks.push("crank");
+            }
+        }
+    }
+    // Persist hits counted since the last flush
+    if let Err(e) = blocklist_hit_flush_handle.await {
+        warn!(error = %e, "Blocklist hit flush task failed during shutdown");
+        summary.failed_tasks.push("blocklist_hit_flush");
+    }
 
     info!("Server shutdown complete");
+    summary.log();
+    if !summary.is_clean() {
+        // process::exit skips destructors, so flush buffered spans first
+        drop(tracing_guard);
+        std::process::exit(summary.exit_code());
+    }
     Ok(())
 }
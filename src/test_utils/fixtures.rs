@@ -0,0 +1,36 @@
+//! Shared test fixtures.
+
+use crate::domain::{BlockchainStatus, ComplianceStatus, DatabaseClient, SubmitTransferRequest};
+
+/// Public transfer of 1,000 lamports from `AddressA` to `AddressB`. `seq`
+/// makes the nonce unique among the transfers of a test.
+#[must_use]
+pub fn transfer_request(seq: usize) -> SubmitTransferRequest {
+    SubmitTransferRequest::new(
+        "AddressA".to_string(),
+        "AddressB".to_string(),
+        1_000,
+        "dummy_sig".to_string(),
+        format!("019470a4-7e7c-7d3e-8f1a-{:012}", seq),
+    )
+}
+
+/// Store `request` as a compliance-approved transfer waiting for the worker
+/// (`pending_submission`) and return its id
+pub async fn queue_transfer(db: &dyn DatabaseClient, request: &SubmitTransferRequest) -> String {
+    let tr = db.submit_transfer(request).await.unwrap();
+    db.update_compliance_status(&tr.id, ComplianceStatus::Approved)
+        .await
+        .unwrap();
+    db.update_blockchain_status(
+        &tr.id,
+        BlockchainStatus::PendingSubmission,
+        None,
+        None,
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    tr.id
+}
@@ -3,14 +3,19 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use uuid::Uuid;
 
 use crate::domain::{
-    AppError, BlockchainClient, BlockchainError, BlockchainStatus, CheckoutSession,
-    CheckoutSessionStatus, ComplianceStatus, CreateCheckoutSessionRequest, DatabaseClient,
-    DatabaseError, PaginatedResponse, SubmitTransferRequest, TransferRequest,
+    AppError, BlockchainClient, BlockchainError, BlockchainNodeHealth, BlockchainStatus,
+    CheckoutSession, CheckoutSessionStatus, ComplianceDecision, ComplianceDecisionFilter,
+    ComplianceStatus, CreateCheckoutSessionRequest, DatabaseClient, DatabaseError, FeeEstimate,
+    IdempotencyRecord, PaginatedResponse, ProposalStatus, RejectionReason, SenderLimit,
+    StatusTransition, SubmissionOutcome, SubmitOptions, SubmitTransferRequest, TransactionStatus,
+    TransferFilter, TransferRequest, TransferType, WalletRiskProfile, WebhookDelivery,
+    WebhookDeliveryStatus,
 };
 
 /// Configuration for mock behavior
@@ -39,6 +44,18 @@ impl MockConfig {
 pub struct MockDatabaseClient {
     storage: Arc<Mutex<HashMap<String, TransferRequest>>>,
     checkout_storage: Arc<Mutex<HashMap<String, CheckoutSession>>>,
+    /// Recorded status transitions, oldest first
+    status_history: Arc<Mutex<Vec<StatusTransition>>>,
+    /// Recorded compliance decisions, oldest first
+    compliance_decisions: Arc<Mutex<Vec<ComplianceDecision>>>,
+    /// Queued outbound webhook deliveries, in enqueue order
+    webhook_deliveries: Arc<Mutex<Vec<WebhookDelivery>>>,
+    /// Recorded `Idempotency-Key` responses, by key
+    idempotency_records: Arc<Mutex<HashMap<(String, String), IdempotencyRecord>>>,
+    /// Cached wallet risk profiles, by address
+    risk_profiles: Arc<Mutex<HashMap<String, WalletRiskProfile>>>,
+    /// Serializes sender limit checks, like the advisory lock in Postgres
+    sender_limit_lock: tokio::sync::Mutex<()>,
     config: MockConfig,
     is_healthy: AtomicBool,
 }
@@ -54,6 +71,12 @@ impl MockDatabaseClient {
         Self {
             storage: Arc::new(Mutex::new(HashMap::new())),
             checkout_storage: Arc::new(Mutex::new(HashMap::new())),
+            status_history: Arc::new(Mutex::new(Vec::new())),
+            compliance_decisions: Arc::new(Mutex::new(Vec::new())),
+            webhook_deliveries: Arc::new(Mutex::new(Vec::new())),
+            idempotency_records: Arc::new(Mutex::new(HashMap::new())),
+            risk_profiles: Arc::new(Mutex::new(HashMap::new())),
+            sender_limit_lock: tokio::sync::Mutex::new(()),
             config,
             is_healthy: AtomicBool::new(true),
         }
@@ -73,6 +96,25 @@ impl MockDatabaseClient {
         self.storage.lock().unwrap().values().cloned().collect()
     }
 
+    /// Overwrite a stored item's `updated_at` (for testing)
+    pub fn set_updated_at(&self, id: &str, updated_at: DateTime<Utc>) {
+        if let Some(item) = self.storage.lock().unwrap().get_mut(id) {
+            item.updated_at = updated_at;
+        }
+    }
+
+    /// Overwrite a stored item's `created_at` (for testing)
+    pub fn set_created_at(&self, id: &str, created_at: DateTime<Utc>) {
+        if let Some(item) = self.storage.lock().unwrap().get_mut(id) {
+            item.created_at = created_at;
+        }
+    }
+
+    /// Get all queued webhook deliveries (for testing)
+    pub fn webhook_deliveries(&self) -> Vec<WebhookDelivery> {
+        self.webhook_deliveries.lock().unwrap().clone()
+    }
+
     fn check_should_fail(&self) -> Result<(), AppError> {
         if self.config.should_fail {
             let msg = self
@@ -109,9 +151,30 @@ impl DatabaseClient for MockDatabaseClient {
         Ok(storage.get(id).cloned())
     }
 
+    async fn find_by_nonce(
+        &self,
+        from_address: &str,
+        nonce: &str,
+    ) -> Result<Option<TransferRequest>, AppError> {
+        self.check_should_fail()?;
+        let storage = self.storage.lock().unwrap();
+        Ok(storage
+            .values()
+            .find(|r| r.from_address == from_address && r.nonce.as_deref() == Some(nonce))
+            .cloned())
+    }
+
     async fn submit_transfer(
         &self,
         data: &SubmitTransferRequest,
+    ) -> Result<TransferRequest, AppError> {
+        self.submit_transfer_with_correlation(data, None).await
+    }
+
+    async fn submit_transfer_with_correlation(
+        &self,
+        data: &SubmitTransferRequest,
+        correlation_id: Option<&str>,
     ) -> Result<TransferRequest, AppError> {
         self.check_should_fail()?;
         let id = Uuid::new_v4().to_string();
@@ -127,7 +190,9 @@ impl DatabaseClient for MockDatabaseClient {
             transfer_details: data.transfer_details.clone(),
             token_mint: data.token_mint.clone(),
             compliance_status,
-            blockchain_status: BlockchainStatus::Pending,
+            rejection_reason: None,
+            // Persisted before compliance, like the Postgres client
+            blockchain_status: BlockchainStatus::Received,
             blockchain_signature: None,
             blockchain_retry_count: 0,
             blockchain_last_error: None,
@@ -136,9 +201,23 @@ impl DatabaseClient for MockDatabaseClient {
             original_tx_signature: None,
             last_error_type: crate::domain::LastErrorType::None,
             blockhash_used: None,
+            bundle_id: None,
+            proof_signatures: Vec::new(),
+            token_sent_amount: None,
+            token_transfer_fee: None,
             // Request Uniqueness fields
             nonce: Some(data.nonce.clone()),
             client_signature: Some(data.signature.clone()),
+            valid_until: data.valid_until,
+            memo: data.memo.clone(),
+            priority: data.priority,
+            archived_at: None,
+            correlation_id: correlation_id.map(String::from),
+            callback_url: data.callback_url.clone(),
+            jito_region: data.jito_region.clone(),
+            skip_preflight: data.skip_preflight,
+            signer_pubkey: None,
+            explorer_url: None,
             created_at: now,
             updated_at: now,
         };
@@ -151,10 +230,24 @@ impl DatabaseClient for MockDatabaseClient {
         &self,
         limit: i64,
         cursor: Option<&str>,
+    ) -> Result<PaginatedResponse<TransferRequest>, AppError> {
+        self.list_transfer_requests_filtered(&TransferFilter::default(), limit, cursor)
+            .await
+    }
+
+    async fn list_transfer_requests_filtered(
+        &self,
+        filter: &TransferFilter,
+        limit: i64,
+        cursor: Option<&str>,
     ) -> Result<PaginatedResponse<TransferRequest>, AppError> {
         self.check_should_fail()?;
         let storage = self.storage.lock().unwrap();
-        let mut items: Vec<TransferRequest> = storage.values().cloned().collect();
+        let mut items: Vec<TransferRequest> = storage
+            .values()
+            .filter(|item| item.archived_at.is_none() && filter.matches(item))
+            .cloned()
+            .collect();
         items.sort_by_key(|item| std::cmp::Reverse(item.created_at));
 
         // Apply cursor
@@ -197,8 +290,12 @@ impl DatabaseClient for MockDatabaseClient {
         blockhash_used: Option<&str>,
     ) -> Result<(), AppError> {
         self.check_should_fail()?;
-        let mut storage = self.storage.lock().unwrap();
-        if let Some(item) = storage.get_mut(id) {
+        let old_status = {
+            let mut storage = self.storage.lock().unwrap();
+            let Some(item) = storage.get_mut(id) else {
+                return Ok(());
+            };
+            let old_status = item.blockchain_status;
             item.blockchain_status = status;
             if let Some(sig) = signature {
                 item.blockchain_signature = Some(sig.to_string());
@@ -209,8 +306,45 @@ impl DatabaseClient for MockDatabaseClient {
                 item.blockhash_used = Some(bh.to_string());
             }
             item.updated_at = Utc::now();
-        }
-        Ok(())
+            old_status
+        };
+        self.record_status_transition(&StatusTransition::blockchain(
+            id,
+            Some(old_status),
+            status,
+            error,
+        ))
+        .await
+    }
+
+    async fn cancel_transfer_request(&self, id: &str) -> Result<bool, AppError> {
+        self.check_should_fail()?;
+        let old_status = {
+            let mut storage = self.storage.lock().unwrap();
+            match storage.get_mut(id) {
+                Some(item)
+                    if matches!(
+                        item.blockchain_status,
+                        BlockchainStatus::Pending | BlockchainStatus::PendingSubmission
+                    ) =>
+                {
+                    let old_status = item.blockchain_status;
+                    item.blockchain_status = BlockchainStatus::Cancelled;
+                    item.blockchain_next_retry_at = None;
+                    item.updated_at = Utc::now();
+                    old_status
+                }
+                _ => return Ok(false),
+            }
+        };
+        self.record_status_transition(&StatusTransition::blockchain(
+            id,
+            Some(old_status),
+            BlockchainStatus::Cancelled,
+            None,
+        ))
+        .await?;
+        Ok(true)
     }
 
     async fn update_compliance_status(
@@ -219,14 +353,118 @@ impl DatabaseClient for MockDatabaseClient {
         status: ComplianceStatus,
     ) -> Result<(), AppError> {
         self.check_should_fail()?;
-        let mut storage = self.storage.lock().unwrap();
-        if let Some(item) = storage.get_mut(id) {
+        let (old_status, rejection_reason) = {
+            let mut storage = self.storage.lock().unwrap();
+            let Some(item) = storage.get_mut(id) else {
+                return Ok(());
+            };
+            let old_status = item.compliance_status;
             item.compliance_status = status;
             item.updated_at = Utc::now();
-        }
+            let rejection_reason = item
+                .rejection_reason
+                .clone()
+                .filter(|_| status == ComplianceStatus::Rejected);
+            (old_status, rejection_reason)
+        };
+        self.record_status_transition(
+            &StatusTransition::compliance(id, Some(old_status), status)
+                .with_rejection_reason(rejection_reason),
+        )
+        .await
+    }
+
+    async fn update_rejection_reason(
+        &self,
+        id: &str,
+        reason: Option<&RejectionReason>,
+    ) -> Result<(), AppError> {
+        self.check_should_fail()?;
+        let mut storage = self.storage.lock().unwrap();
+        let item = storage
+            .get_mut(id)
+            .ok_or_else(|| AppError::Database(DatabaseError::NotFound(id.to_string())))?;
+        item.rejection_reason = reason.cloned();
+        item.updated_at = Utc::now();
+        Ok(())
+    }
+
+    async fn record_status_transition(
+        &self,
+        transition: &StatusTransition,
+    ) -> Result<(), AppError> {
+        self.check_should_fail()?;
+        self.status_history.lock().unwrap().push(transition.clone());
+        Ok(())
+    }
+
+    async fn get_status_history(&self, id: &str) -> Result<Vec<StatusTransition>, AppError> {
+        self.check_should_fail()?;
+        Ok(self
+            .status_history
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|transition| transition.transfer_id == id)
+            .cloned()
+            .collect())
+    }
+
+    async fn record_compliance_decision(
+        &self,
+        decision: &ComplianceDecision,
+    ) -> Result<(), AppError> {
+        self.check_should_fail()?;
+        self.compliance_decisions
+            .lock()
+            .unwrap()
+            .push(decision.clone());
         Ok(())
     }
 
+    async fn list_compliance_decisions(
+        &self,
+        filter: &ComplianceDecisionFilter,
+        limit: i64,
+        cursor: Option<&str>,
+    ) -> Result<PaginatedResponse<ComplianceDecision>, AppError> {
+        self.check_should_fail()?;
+        // Newest first; recording order breaks ties between equal timestamps
+        let mut items: Vec<ComplianceDecision> = self
+            .compliance_decisions
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|decision| filter.matches(decision))
+            .cloned()
+            .collect();
+        items.sort_by_key(|decision| std::cmp::Reverse(decision.created_at));
+
+        if let Some(cursor_id) = cursor {
+            let Some(pos) = items.iter().position(|d| d.id == cursor_id) else {
+                return Err(AppError::Validation(
+                    crate::domain::ValidationError::InvalidField {
+                        field: "cursor".to_string(),
+                        message: "Invalid cursor".to_string(),
+                    },
+                ));
+            };
+            items.drain(..=pos);
+        }
+
+        let limit = limit.clamp(1, 100) as usize;
+        let has_more = items.len() > limit;
+        items.truncate(limit);
+        let next_cursor = if has_more {
+            items.last().map(|d| d.id.clone())
+        } else {
+            None
+        };
+
+        Ok(PaginatedResponse::new(items, next_cursor, has_more))
+    }
+
     /// Mock atomic claim: returns items with Processing status (like the real implementation)
     async fn get_pending_blockchain_requests(
         &self,
@@ -236,15 +474,19 @@ impl DatabaseClient for MockDatabaseClient {
         let mut storage = self.storage.lock().unwrap();
         let now = Utc::now();
 
-        // Find eligible items
-        let eligible_ids: Vec<String> = storage
+        // Find eligible items, highest priority first, then oldest first
+        let mut eligible: Vec<&TransferRequest> = storage
             .values()
             .filter(|i| {
                 i.blockchain_status == BlockchainStatus::PendingSubmission
                     && i.compliance_status == ComplianceStatus::Approved
-                    && i.blockchain_retry_count < 10
+                    && i.archived_at.is_none()
                     && i.blockchain_next_retry_at.map(|t| t <= now).unwrap_or(true)
             })
+            .collect();
+        eligible.sort_by_key(|i| (std::cmp::Reverse(i.priority), i.created_at));
+        let eligible_ids: Vec<String> = eligible
+            .into_iter()
             .take(limit as usize)
             .map(|i| i.id.clone())
             .collect();
@@ -259,10 +501,57 @@ impl DatabaseClient for MockDatabaseClient {
             }
         }
 
-        claimed_items.sort_by_key(|item| item.created_at);
         Ok(claimed_items)
     }
 
+    async fn count_pending_blockchain_requests(&self) -> Result<i64, AppError> {
+        self.check_should_fail()?;
+        let storage = self.storage.lock().unwrap();
+        Ok(storage
+            .values()
+            .filter(|i| {
+                i.blockchain_status == BlockchainStatus::PendingSubmission
+                    && i.archived_at.is_none()
+            })
+            .count() as i64)
+    }
+
+    async fn submit_transfer_within_limit(
+        &self,
+        data: &SubmitTransferRequest,
+        correlation_id: Option<&str>,
+        limit: SenderLimit,
+    ) -> Result<TransferRequest, AppError> {
+        self.check_should_fail()?;
+        let _guard = self.sender_limit_lock.lock().await;
+        let sent = {
+            let storage = self.storage.lock().unwrap();
+            storage
+                .values()
+                .filter(|i| {
+                    i.from_address == data.from_address
+                        && i.created_at >= limit.since
+                        && i.token_mint.is_none()
+                        && i.compliance_status != ComplianceStatus::Rejected
+                        && !matches!(
+                            i.blockchain_status,
+                            BlockchainStatus::Expired | BlockchainStatus::Cancelled
+                        )
+                })
+                .map(|i| match &i.transfer_details {
+                    TransferType::Public { amount } => *amount,
+                    TransferType::PublicBatch { recipients } => {
+                        recipients.iter().map(|(_, amount)| *amount).sum()
+                    }
+                    TransferType::Confidential { .. } => 0,
+                })
+                .sum()
+        };
+        limit.check(sent)?;
+        self.submit_transfer_with_correlation(data, correlation_id)
+            .await
+    }
+
     async fn increment_retry_count(&self, id: &str) -> Result<i32, AppError> {
         self.check_should_fail()?;
         let mut storage = self.storage.lock().unwrap();
@@ -275,6 +564,56 @@ impl DatabaseClient for MockDatabaseClient {
         }
     }
 
+    async fn record_submission(
+        &self,
+        id: &str,
+        outcome: &SubmissionOutcome,
+        blockhash_used: Option<&str>,
+    ) -> Result<(), AppError> {
+        self.check_should_fail()?;
+        let status = outcome.submitted_status();
+        let old_status = {
+            let mut storage = self.storage.lock().unwrap();
+            let Some(item) = storage.get_mut(id) else {
+                return Ok(());
+            };
+            let old_status = item.blockchain_status;
+            item.blockchain_status = status;
+            item.blockchain_signature = Some(outcome.signature.clone());
+            item.blockchain_last_error = None;
+            item.blockchain_next_retry_at = outcome.approval_expires_at;
+            if let Some(bh) = blockhash_used {
+                item.blockhash_used = Some(bh.to_string());
+            }
+            item.bundle_id = outcome.bundle_id.clone();
+            item.proof_signatures = outcome.proof_signatures.clone();
+            item.token_sent_amount = outcome.token_sent_amount;
+            item.token_transfer_fee = outcome.token_transfer_fee;
+            item.updated_at = Utc::now();
+            old_status
+        };
+        self.record_status_transition(&StatusTransition::blockchain(
+            id,
+            Some(old_status),
+            status,
+            None,
+        ))
+        .await
+    }
+
+    async fn pin_transfer_signer(&self, id: &str, signer_pubkey: &str) -> Result<String, AppError> {
+        self.check_should_fail()?;
+        let mut storage = self.storage.lock().unwrap();
+        let item = storage
+            .get_mut(id)
+            .ok_or_else(|| AppError::Database(DatabaseError::NotFound(id.to_string())))?;
+        item.updated_at = Utc::now();
+        Ok(item
+            .signer_pubkey
+            .get_or_insert_with(|| signer_pubkey.to_string())
+            .clone())
+    }
+
     async fn get_transfer_by_signature(
         &self,
         signature: &str,
@@ -283,7 +622,10 @@ impl DatabaseClient for MockDatabaseClient {
         let storage = self.storage.lock().unwrap();
         Ok(storage
             .values()
-            .find(|req| req.blockchain_signature.as_deref() == Some(signature))
+            .find(|req| {
+                req.blockchain_signature.as_deref() == Some(signature)
+                    || req.proof_signatures.iter().any(|proof| proof == signature)
+            })
             .cloned())
     }
 
@@ -341,6 +683,254 @@ impl DatabaseClient for MockDatabaseClient {
         session.updated_at = Utc::now();
         Ok(session.clone())
     }
+
+    async fn get_stale_submitted_transactions(
+        &self,
+        older_than_secs: i64,
+        limit: i64,
+    ) -> Result<Vec<TransferRequest>, AppError> {
+        self.check_should_fail()?;
+        let cutoff = Utc::now() - chrono::Duration::seconds(older_than_secs);
+        let storage = self.storage.lock().unwrap();
+        let mut stale: Vec<TransferRequest> = storage
+            .values()
+            .filter(|i| {
+                i.blockchain_status == BlockchainStatus::Submitted
+                    && i.archived_at.is_none()
+                    && i.updated_at < cutoff
+            })
+            .cloned()
+            .collect();
+        stale.sort_by_key(|i| i.updated_at);
+        stale.truncate(limit.max(0) as usize);
+        Ok(stale)
+    }
+
+    async fn claim_stale_processing_transactions(
+        &self,
+        older_than_secs: i64,
+        limit: i64,
+    ) -> Result<Vec<TransferRequest>, AppError> {
+        self.check_should_fail()?;
+        let now = Utc::now();
+        let cutoff = now - chrono::Duration::seconds(older_than_secs);
+        let mut storage = self.storage.lock().unwrap();
+        let mut stale: Vec<&mut TransferRequest> = storage
+            .values_mut()
+            .filter(|i| {
+                i.blockchain_status == BlockchainStatus::Processing
+                    && i.archived_at.is_none()
+                    && i.updated_at < cutoff
+            })
+            .collect();
+        stale.sort_by_key(|i| i.updated_at);
+        stale.truncate(limit.max(0) as usize);
+        Ok(stale
+            .into_iter()
+            .map(|item| {
+                item.updated_at = now;
+                item.clone()
+            })
+            .collect())
+    }
+
+    async fn claim_awaiting_approval_transfers(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<TransferRequest>, AppError> {
+        self.check_should_fail()?;
+        let now = Utc::now();
+        let mut storage = self.storage.lock().unwrap();
+        let mut pending: Vec<&mut TransferRequest> = storage
+            .values_mut()
+            .filter(|i| {
+                i.blockchain_status == BlockchainStatus::AwaitingApproval && i.archived_at.is_none()
+            })
+            .collect();
+        pending.sort_by_key(|i| i.updated_at);
+        pending.truncate(limit.max(0) as usize);
+        Ok(pending
+            .into_iter()
+            .map(|item| {
+                item.updated_at = now;
+                item.clone()
+            })
+            .collect())
+    }
+
+    async fn archive_terminal_transfers(
+        &self,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        self.check_should_fail()?;
+        let mut storage = self.storage.lock().unwrap();
+        let mut candidates: Vec<&mut TransferRequest> = storage
+            .values_mut()
+            .filter(|i| {
+                i.archived_at.is_none()
+                    && i.blockchain_status.is_terminal()
+                    && i.updated_at < older_than
+            })
+            .collect();
+        candidates.sort_by_key(|i| i.updated_at);
+
+        let now = Utc::now();
+        let mut archived = 0;
+        for item in candidates.into_iter().take(limit.max(0) as usize) {
+            item.archived_at = Some(now);
+            archived += 1;
+        }
+        Ok(archived)
+    }
+
+    async fn purge_expired_nonces(
+        &self,
+        older_than: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        self.check_should_fail()?;
+        let mut storage = self.storage.lock().unwrap();
+        let mut candidates: Vec<&mut TransferRequest> = storage
+            .values_mut()
+            .filter(|i| {
+                i.nonce.is_some()
+                    && i.valid_until
+                        .is_some_and(|valid_until| valid_until < older_than)
+                    && i.blockchain_status.is_terminal()
+                    && i.updated_at < older_than
+            })
+            .collect();
+        candidates.sort_by_key(|i| i.updated_at);
+
+        let mut purged = 0;
+        for item in candidates.into_iter().take(limit.max(0) as usize) {
+            item.nonce = None;
+            purged += 1;
+        }
+        Ok(purged)
+    }
+
+    async fn find_idempotency_record(
+        &self,
+        scope: &str,
+        key: &str,
+    ) -> Result<Option<IdempotencyRecord>, AppError> {
+        self.check_should_fail()?;
+        Ok(self
+            .idempotency_records
+            .lock()
+            .unwrap()
+            .get(&(scope.to_string(), key.to_string()))
+            .filter(|record| record.expires_at > Utc::now())
+            .cloned())
+    }
+
+    async fn save_idempotency_record(&self, record: &IdempotencyRecord) -> Result<(), AppError> {
+        self.check_should_fail()?;
+        let mut records = self.idempotency_records.lock().unwrap();
+        let id = (record.scope.clone(), record.key.clone());
+        if records
+            .get(&id)
+            .is_none_or(|existing| existing.expires_at <= Utc::now())
+        {
+            records.insert(id, record.clone());
+        }
+        Ok(())
+    }
+
+    async fn purge_expired_idempotency_records(
+        &self,
+        now: DateTime<Utc>,
+        limit: i64,
+    ) -> Result<u64, AppError> {
+        self.check_should_fail()?;
+        let mut records = self.idempotency_records.lock().unwrap();
+        let expired: Vec<_> = records
+            .iter()
+            .filter(|(_, record)| record.expires_at <= now)
+            .map(|(id, _)| id.clone())
+            .take(limit.max(0) as usize)
+            .collect();
+        for id in &expired {
+            records.remove(id);
+        }
+        Ok(expired.len() as u64)
+    }
+
+    async fn get_risk_profile(
+        &self,
+        address: &str,
+        max_age_secs: i64,
+    ) -> Result<Option<WalletRiskProfile>, AppError> {
+        self.check_should_fail()?;
+        let cutoff = Utc::now() - chrono::Duration::seconds(max_age_secs);
+        Ok(self
+            .risk_profiles
+            .lock()
+            .unwrap()
+            .get(address)
+            .filter(|profile| profile.updated_at > cutoff)
+            .cloned())
+    }
+
+    async fn upsert_risk_profile(&self, profile: &WalletRiskProfile) -> Result<(), AppError> {
+        self.check_should_fail()?;
+        self.risk_profiles
+            .lock()
+            .unwrap()
+            .insert(profile.address.clone(), profile.clone());
+        Ok(())
+    }
+
+    async fn enqueue_webhook_delivery(&self, delivery: &WebhookDelivery) -> Result<bool, AppError> {
+        self.check_should_fail()?;
+        let mut deliveries = self.webhook_deliveries.lock().unwrap();
+        if deliveries.iter().any(|d| d.event_key == delivery.event_key) {
+            return Ok(false);
+        }
+        deliveries.push(delivery.clone());
+        Ok(true)
+    }
+
+    async fn claim_due_webhook_deliveries(
+        &self,
+        limit: i64,
+        lease_secs: i64,
+    ) -> Result<Vec<WebhookDelivery>, AppError> {
+        self.check_should_fail()?;
+        let now = Utc::now();
+        let mut deliveries = self.webhook_deliveries.lock().unwrap();
+        let mut claimed = Vec::new();
+        for delivery in deliveries
+            .iter_mut()
+            .filter(|d| d.status == WebhookDeliveryStatus::Pending && d.next_attempt_at <= now)
+        {
+            if claimed.len() >= limit.max(0) as usize {
+                break;
+            }
+            claimed.push(delivery.clone());
+            delivery.next_attempt_at = now + chrono::Duration::seconds(lease_secs);
+        }
+        Ok(claimed)
+    }
+
+    async fn update_webhook_delivery(&self, delivery: &WebhookDelivery) -> Result<(), AppError> {
+        self.check_should_fail()?;
+        let mut deliveries = self.webhook_deliveries.lock().unwrap();
+        match deliveries.iter_mut().find(|d| d.id == delivery.id) {
+            Some(existing) => {
+                *existing = WebhookDelivery {
+                    updated_at: Utc::now(),
+                    ..delivery.clone()
+                };
+                Ok(())
+            }
+            None => Err(AppError::Database(DatabaseError::NotFound(
+                delivery.id.clone(),
+            ))),
+        }
+    }
 }
 
 /// Mock blockchain client for testing
@@ -348,8 +938,50 @@ pub struct MockBlockchainClient {
     transactions: Arc<Mutex<Vec<String>>>,
     config: MockConfig,
     is_healthy: AtomicBool,
+    /// Fail every call with a connection error, as if the RPC were down
+    unreachable: AtomicBool,
+    rpc_calls: AtomicUsize,
+    /// How long each `submit_transaction` call takes
+    submit_delay: Duration,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+    /// Balance reported by `get_fee_payer_balance`
+    fee_payer_balance: AtomicU64,
+    /// Status reported by `get_signature_status` (`None`: not found) and
+    /// how long each call takes
+    signature_status: Option<(Option<TransactionStatus>, Duration)>,
+    /// Bundle ID reported by `submit_transaction`, as if sent via Jito
+    bundle_id: Option<String>,
+    /// Issuer keys handed out round-robin by `select_signer`
+    signers: Vec<String>,
+    next_signer: AtomicUsize,
+    /// `signer_pubkey` of every successful `submit_transaction` call
+    submitted_signers: Mutex<Vec<Option<String>>>,
+    /// `token_mint` of every `estimate_fee` call
+    estimated_mints: Mutex<Vec<Option<String>>>,
+    /// Reported by `detailed_health`; not supported when unset
+    node_health: Mutex<Option<BlockchainNodeHealth>>,
+    /// Reported by `is_blockhash_valid`; not supported when unset
+    blockhash_valid: Option<bool>,
+    /// When set, submissions are multisig proposals and this is what
+    /// `get_proposal_status` reports for them
+    proposal_status: Mutex<Option<ProposalStatus>>,
+    /// How long proposals may await approval, if they expire
+    approval_window: Option<chrono::Duration>,
 }
 
+/// Fee payer balance reported by a fresh mock: 10 SOL
+const MOCK_FEE_PAYER_BALANCE: u64 = 10_000_000_000;
+
+/// Base fee reported by `estimate_fee`: one signature
+pub const MOCK_BASE_FEE_LAMPORTS: u64 = 5_000;
+
+/// Compute unit price reported by `estimate_fee`, in micro-lamports
+pub const MOCK_COMPUTE_UNIT_PRICE: u64 = 100;
+
+/// Compute unit limit reported by `estimate_fee`
+pub const MOCK_COMPUTE_UNIT_LIMIT: u32 = 200_000;
+
 impl MockBlockchainClient {
     #[must_use]
     pub fn new() -> Self {
@@ -362,9 +994,111 @@ impl MockBlockchainClient {
             transactions: Arc::new(Mutex::new(Vec::new())),
             config,
             is_healthy: AtomicBool::new(true),
+            unreachable: AtomicBool::new(false),
+            rpc_calls: AtomicUsize::new(0),
+            submit_delay: Duration::ZERO,
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+            fee_payer_balance: AtomicU64::new(MOCK_FEE_PAYER_BALANCE),
+            signature_status: None,
+            bundle_id: None,
+            signers: Vec::new(),
+            next_signer: AtomicUsize::new(0),
+            submitted_signers: Mutex::new(Vec::new()),
+            estimated_mints: Mutex::new(Vec::new()),
+            node_health: Mutex::new(None),
+            blockhash_valid: None,
+            proposal_status: Mutex::new(None),
+            approval_window: None,
         }
     }
 
+    /// Make each `submit_transaction` call take `delay`, so overlapping
+    /// submissions can be observed with [`Self::max_in_flight`]
+    #[must_use]
+    pub fn with_submit_delay(mut self, delay: Duration) -> Self {
+        self.submit_delay = delay;
+        self
+    }
+
+    /// Report `status` from `get_signature_status`, each call taking `delay`.
+    /// Without this, `get_signature_status` is not supported.
+    #[must_use]
+    pub fn with_signature_status(mut self, status: TransactionStatus, delay: Duration) -> Self {
+        self.signature_status = Some((Some(status), delay));
+        self
+    }
+
+    /// Report every signature as not found from `get_signature_status`
+    #[must_use]
+    pub fn with_signature_not_found(mut self) -> Self {
+        self.signature_status = Some((None, Duration::ZERO));
+        self
+    }
+
+    /// Report every blockhash as still valid (`true`) or expired (`false`)
+    /// from `is_blockhash_valid`. Without this, it is not supported.
+    #[must_use]
+    pub fn with_blockhash_valid(mut self, valid: bool) -> Self {
+        self.blockhash_valid = Some(valid);
+        self
+    }
+
+    /// Report every `submit_transaction` call as carried by Jito bundle `bundle_id`
+    #[must_use]
+    pub fn with_bundle_id(mut self, bundle_id: impl Into<String>) -> Self {
+        self.bundle_id = Some(bundle_id.into());
+        self
+    }
+
+    /// Submit every transfer as a multisig proposal whose status is
+    /// reported as `status` by `get_proposal_status`
+    #[must_use]
+    pub fn with_proposal_status(self, status: ProposalStatus) -> Self {
+        self.set_proposal_status(status);
+        self
+    }
+
+    /// Set the status reported for every proposal
+    pub fn set_proposal_status(&self, status: ProposalStatus) {
+        *self.proposal_status.lock().unwrap() = Some(status);
+    }
+
+    /// Expire proposals that are still undecided `window` after submission
+    #[must_use]
+    pub fn with_approval_window(mut self, window: chrono::Duration) -> Self {
+        self.approval_window = Some(window);
+        self
+    }
+
+    /// Hand out `signers` round-robin from `select_signer`, so a transfer
+    /// that was not pinned would get a different key on every attempt
+    #[must_use]
+    pub fn with_signers(mut self, signers: &[&str]) -> Self {
+        self.signers = signers.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// `signer_pubkey` of each successful `submit_transaction` call, in order
+    pub fn submitted_signers(&self) -> Vec<Option<String>> {
+        self.submitted_signers.lock().unwrap().clone()
+    }
+
+    /// `token_mint` of each `estimate_fee` call, in order
+    pub fn estimated_mints(&self) -> Vec<Option<String>> {
+        self.estimated_mints.lock().unwrap().clone()
+    }
+
+    /// Number of `submit_transaction` calls currently in flight
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Highest number of `submit_transaction` calls that were in flight at once
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
     #[must_use]
     pub fn failing(message: impl Into<String>) -> Self {
         Self::with_config(MockConfig::failure(message))
@@ -374,11 +1108,37 @@ impl MockBlockchainClient {
         self.is_healthy.store(healthy, Ordering::Relaxed);
     }
 
+    /// Set the node diagnostics reported by `detailed_health`
+    pub fn set_node_health(&self, health: BlockchainNodeHealth) {
+        *self.node_health.lock().unwrap() = Some(health);
+    }
+
+    /// Set the balance reported by `get_fee_payer_balance`
+    pub fn set_fee_payer_balance(&self, lamports: u64) {
+        self.fee_payer_balance.store(lamports, Ordering::SeqCst);
+    }
+
+    /// Make every call fail with a connection error until reset
+    pub fn set_unreachable(&self, unreachable: bool) {
+        self.unreachable.store(unreachable, Ordering::SeqCst);
+    }
+
+    /// Number of calls that reached the mock, including failed ones
+    pub fn rpc_calls(&self) -> usize {
+        self.rpc_calls.load(Ordering::SeqCst)
+    }
+
     pub fn get_transactions(&self) -> Vec<String> {
         self.transactions.lock().unwrap().clone()
     }
 
     fn check_should_fail(&self) -> Result<(), AppError> {
+        self.rpc_calls.fetch_add(1, Ordering::SeqCst);
+        if self.unreachable.load(Ordering::SeqCst) {
+            return Err(AppError::Blockchain(BlockchainError::Connection(
+                "RPC unreachable".to_string(),
+            )));
+        }
         if self.config.should_fail {
             let msg = self
                 .config
@@ -410,17 +1170,58 @@ impl BlockchainClient for MockBlockchainClient {
         self.check_should_fail()
     }
 
+    async fn detailed_health(&self) -> Result<BlockchainNodeHealth, AppError> {
+        self.check_should_fail()?;
+        self.node_health
+            .lock()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| AppError::NotSupported("detailed_health not configured".to_string()))
+    }
+
     async fn submit_transaction(
         &self,
         request: &TransferRequest,
-    ) -> Result<(String, String), AppError> {
+    ) -> Result<(SubmissionOutcome, String), AppError> {
         self.check_should_fail()?;
+        let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+        if !self.submit_delay.is_zero() {
+            tokio::time::sleep(self.submit_delay).await;
+        }
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
         // Mock signature generation (e.g., hash of ID)
         let signature = format!("sig_{}", request.id);
         let blockhash = "mock_blockhash_abc123".to_string();
         let mut transactions = self.transactions.lock().unwrap();
         transactions.push(request.id.clone());
-        Ok((signature, blockhash))
+        self.submitted_signers
+            .lock()
+            .unwrap()
+            .push(request.signer_pubkey.clone());
+        let outcome = if self.proposal_status.lock().unwrap().is_some() {
+            let proposal = SubmissionOutcome::proposal(format!("proposal_{}", request.id));
+            match self.approval_window {
+                Some(window) => proposal.with_approval_expiry(Utc::now() + window),
+                None => proposal,
+            }
+        } else {
+            // A confidential transfer verifies its proofs in earlier transactions
+            let proof_signatures = match request.transfer_details {
+                TransferType::Confidential { .. } => (1..=3)
+                    .map(|n| format!("proof{}_sig_{}", n, request.id))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            SubmissionOutcome {
+                signature,
+                bundle_id: self.bundle_id.clone(),
+                proof_signatures,
+                ..SubmissionOutcome::new("")
+            }
+        };
+        Ok((outcome, blockhash))
     }
 
     async fn get_transaction_status(&self, _signature: &str) -> Result<bool, AppError> {
@@ -436,11 +1237,47 @@ impl BlockchainClient for MockBlockchainClient {
         Ok("mock_blockhash_abc123".to_string())
     }
 
+    async fn is_blockhash_valid(&self, _blockhash: &str) -> Result<bool, AppError> {
+        let Some(valid) = self.blockhash_valid else {
+            return Err(AppError::NotSupported(
+                "is_blockhash_valid not implemented".to_string(),
+            ));
+        };
+        self.check_should_fail()?;
+        Ok(valid)
+    }
+
+    async fn get_signature_status(
+        &self,
+        _signature: &str,
+    ) -> Result<Option<TransactionStatus>, AppError> {
+        let Some((status, delay)) = &self.signature_status else {
+            return Err(AppError::NotSupported(
+                "get_signature_status not implemented".to_string(),
+            ));
+        };
+        self.check_should_fail()?;
+        if !delay.is_zero() {
+            tokio::time::sleep(*delay).await;
+        }
+        Ok(status.clone())
+    }
+
+    async fn get_proposal_status(
+        &self,
+        _proposal: &str,
+    ) -> Result<Option<ProposalStatus>, AppError> {
+        self.check_should_fail()?;
+        Ok(*self.proposal_status.lock().unwrap())
+    }
+
     async fn transfer_sol(
         &self,
         to_address: &str,
         amount_lamports: u64,
-    ) -> Result<(String, String), AppError> {
+        _memo: Option<&str>,
+        _options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
         self.check_should_fail()?;
         let signature = format!(
             "transfer_sig_{}_{}",
@@ -450,7 +1287,7 @@ impl BlockchainClient for MockBlockchainClient {
         let blockhash = "mock_blockhash_sol_transfer".to_string();
         let mut transactions = self.transactions.lock().unwrap();
         transactions.push(format!("transfer:{}:{}", to_address, amount_lamports));
-        Ok((signature, blockhash))
+        Ok((SubmissionOutcome::new(signature), blockhash))
     }
 
     async fn transfer_token(
@@ -458,7 +1295,9 @@ impl BlockchainClient for MockBlockchainClient {
         to_address: &str,
         token_mint: &str,
         amount: u64,
-    ) -> Result<(String, String), AppError> {
+        _memo: Option<&str>,
+        _options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
         self.check_should_fail()?;
         let mint_prefix = &token_mint[..8.min(token_mint.len())];
         let signature = format!("token_sig_{}_{}", mint_prefix, amount);
@@ -468,7 +1307,28 @@ impl BlockchainClient for MockBlockchainClient {
             "token_transfer:{}:{}:{}",
             to_address, token_mint, amount
         ));
-        Ok((signature, blockhash))
+        Ok((SubmissionOutcome::new(signature), blockhash))
+    }
+
+    async fn transfer_token_batch(
+        &self,
+        token_mint: &str,
+        recipients: &[(String, u64)],
+        _memo: Option<&str>,
+        _options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
+        self.check_should_fail()?;
+        let mint_prefix = &token_mint[..8.min(token_mint.len())];
+        let signature = format!("token_batch_sig_{}_{}", mint_prefix, recipients.len());
+        let blockhash = "mock_blockhash_token_batch".to_string();
+        let mut transactions = self.transactions.lock().unwrap();
+        for (to_address, amount) in recipients {
+            transactions.push(format!(
+                "token_transfer:{}:{}:{}",
+                to_address, token_mint, amount
+            ));
+        }
+        Ok((SubmissionOutcome::new(signature), blockhash))
     }
 
     async fn transfer_confidential(
@@ -479,7 +1339,12 @@ impl BlockchainClient for MockBlockchainClient {
         equality_proof: &str,
         ciphertext_validity_proof: &str,
         range_proof: &str,
-    ) -> Result<(String, String), AppError> {
+        _auditor_elgamal_pubkey: Option<&str>,
+        _fee_sigma_proof: Option<&str>,
+        _fee_ciphertext_validity_proof: Option<&str>,
+        _memo: Option<&str>,
+        _options: SubmitOptions<'_>,
+    ) -> Result<(SubmissionOutcome, String), AppError> {
         self.check_should_fail()?;
         let mint_prefix = &token_mint[..8.min(token_mint.len())];
         let signature = format!("confidential_sig_{}", mint_prefix);
@@ -494,27 +1359,97 @@ impl BlockchainClient for MockBlockchainClient {
             ciphertext_validity_proof.len(),
             range_proof.len()
         ));
-        Ok((signature, blockhash))
+        Ok((SubmissionOutcome::new(signature), blockhash))
+    }
+
+    fn signer_pubkeys(&self) -> Vec<String> {
+        self.signers.clone()
+    }
+
+    fn select_signer(&self, _transfer_id: &str) -> Option<String> {
+        if self.signers.is_empty() {
+            return None;
+        }
+        let next = self.next_signer.fetch_add(1, Ordering::SeqCst);
+        Some(self.signers[next % self.signers.len()].clone())
+    }
+
+    async fn get_fee_payer_balance(&self) -> Result<u64, AppError> {
+        self.check_should_fail()?;
+        Ok(self.fee_payer_balance.load(Ordering::SeqCst))
+    }
+
+    async fn estimate_fee(
+        &self,
+        _to_address: &str,
+        _amount: u64,
+        token_mint: Option<&str>,
+        _memo: Option<&str>,
+    ) -> Result<FeeEstimate, AppError> {
+        self.check_should_fail()?;
+        self.estimated_mints
+            .lock()
+            .unwrap()
+            .push(token_mint.map(String::from));
+        Ok(FeeEstimate::new(
+            MOCK_BASE_FEE_LAMPORTS,
+            MOCK_COMPUTE_UNIT_PRICE,
+            MOCK_COMPUTE_UNIT_LIMIT,
+            0,
+        ))
     }
 }
 
 /// Mock compliance provider for testing
 pub struct MockComplianceProvider {
     config: MockConfig,
+    status: ComplianceStatus,
+    /// Category reported with rejections
+    category: Option<String>,
+    calls: AtomicUsize,
 }
 
 impl MockComplianceProvider {
     pub fn new() -> Self {
+        Self::with_status(ComplianceStatus::Approved)
+    }
+
+    pub fn failing(message: impl Into<String>) -> Self {
+        Self {
+            config: MockConfig::failure(message),
+            status: ComplianceStatus::Approved,
+            category: None,
+            calls: AtomicUsize::new(0),
+        }
+    }
+
+    /// Mock that returns `status` for every check
+    pub fn with_status(status: ComplianceStatus) -> Self {
         Self {
             config: MockConfig::success(),
+            status,
+            category: None,
+            calls: AtomicUsize::new(0),
         }
     }
 
-    pub fn failing(message: impl Into<String>) -> Self {
+    /// Mock that rejects every check
+    pub fn rejecting() -> Self {
+        Self::with_status(ComplianceStatus::Rejected)
+    }
+
+    /// Mock that rejects every check in `category`
+    pub fn rejecting_with(category: impl Into<String>) -> Self {
         Self {
-            config: MockConfig::failure(message),
+            category: Some(category.into()),
+            ..Self::rejecting()
         }
     }
+
+    /// Number of compliance checks performed
+    pub fn call_count(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
 }
 
 impl Default for MockComplianceProvider {
@@ -529,6 +1464,7 @@ impl crate::domain::ComplianceProvider for MockComplianceProvider {
         &self,
         _request: &SubmitTransferRequest,
     ) -> Result<ComplianceStatus, AppError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
         if self.config.should_fail {
             return Err(AppError::ExternalService(
                 crate::domain::ExternalServiceError::HttpError(
@@ -536,8 +1472,33 @@ impl crate::domain::ComplianceProvider for MockComplianceProvider {
                 ),
             ));
         }
-        // Default to approved for mocks unless we specifically want to test rejection logic
-        // We can extend this mock later if needed for specific test cases
-        Ok(ComplianceStatus::Approved)
+        // Approved unless constructed with a specific status
+        Ok(self.status)
+    }
+
+    async fn screen(
+        &self,
+        request: &SubmitTransferRequest,
+    ) -> Result<crate::domain::ComplianceVerdict, AppError> {
+        let status = self.check_compliance(request).await?;
+        Ok(crate::domain::ComplianceVerdict {
+            status,
+            category: self.category.clone(),
+        })
+    }
+
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    async fn health_check(&self) -> Result<(), AppError> {
+        if self.config.should_fail {
+            return Err(AppError::ExternalService(
+                crate::domain::ExternalServiceError::Unavailable(
+                    self.config.error_message.clone().unwrap_or_default(),
+                ),
+            ));
+        }
+        Ok(())
     }
 }
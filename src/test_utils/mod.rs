@@ -1,5 +1,7 @@
 //! Test utilities and mock implementations.
 
+pub mod fixtures;
 pub mod mocks;
 
+pub use fixtures::{queue_transfer, transfer_request};
 pub use mocks::{MockBlockchainClient, MockComplianceProvider, MockConfig, MockDatabaseClient};
@@ -52,6 +52,14 @@ fn create_signed_transfer_request(to_idx: u32, amount: u64) -> SubmitTransferReq
         token_mint: None,
         signature: signature_b58,
         nonce,
+        memo: None,
+        priority: 0,
+        callback_url: None,
+        signature_version: None,
+        valid_until: None,
+        jito_region: None,
+        skip_preflight: None,
+        amount_sol: None,
     }
 }
 
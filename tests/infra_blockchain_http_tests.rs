@@ -688,3 +688,412 @@ mod quicknode_token_api_tests {
         assert_eq!(response.status(), 503);
     }
 }
+
+// ============================================================================
+// JITO MULTI-TRANSACTION BUNDLE TESTS
+// ============================================================================
+
+mod jito_multi_bundle_tests {
+    use super::*;
+    use serde_json::json;
+    use solana_compliance_relayer::infra::blockchain::{
+        QuickNodePrivateSubmissionStrategy, QuickNodeSubmissionConfig, SubmissionStrategy,
+    };
+    use solana_sdk::{
+        hash::Hash,
+        signer::{Signer, keypair::Keypair},
+        transaction::Transaction,
+    };
+    use wiremock::matchers::body_partial_json;
+
+    fn signed_tx() -> (String, String) {
+        let payer = Keypair::new();
+        let tx = Transaction::new_signed_with_payer(
+            &[],
+            Some(&payer.pubkey()),
+            &[&payer],
+            Hash::new_unique(),
+        );
+        let bytes = bincode::serde::encode_to_vec(&tx, bincode::config::legacy()).unwrap();
+        (
+            bs58::encode(bytes).into_string(),
+            tx.signatures[0].to_string(),
+        )
+    }
+
+    fn strategy(rpc_url: String, enable_jito_bundles: bool) -> QuickNodePrivateSubmissionStrategy {
+        QuickNodePrivateSubmissionStrategy::new(QuickNodeSubmissionConfig {
+            rpc_url,
+            enable_jito_bundles,
+            track_bundle_status: true,
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_bundle_sent_in_one_request_in_order() {
+        let mock_server = MockServer::start().await;
+        let txs = [signed_tx(), signed_tx(), signed_tx()];
+        let serialized: Vec<String> = txs.iter().map(|(tx, _)| tx.clone()).collect();
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({
+                "method": "sendBundle",
+                "params": [serialized]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "bundle_proofs"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let strategy = strategy(mock_server.uri(), true);
+        let signatures = strategy.submit_bundle(&serialized, None).await.unwrap();
+
+        let expected: Vec<String> = txs.iter().map(|(_, sig)| sig.clone()).collect();
+        assert_eq!(signatures, expected);
+        assert_eq!(strategy.last_bundle_id().as_deref(), Some("bundle_proofs"));
+    }
+
+    async fn bundle_region(region: Option<&str>, per_request: Option<&str>) -> Option<String> {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "sendBundle"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "bundle_region"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let strategy = QuickNodePrivateSubmissionStrategy::new(QuickNodeSubmissionConfig {
+            rpc_url: mock_server.uri(),
+            region: region.map(String::from),
+            ..Default::default()
+        });
+        let serialized = vec![signed_tx().0];
+        strategy
+            .submit_bundle(&serialized, per_request)
+            .await
+            .unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        body["params"]
+            .get(1)
+            .map(|region| region.as_str().unwrap().to_string())
+    }
+
+    #[tokio::test]
+    async fn test_per_request_region_sent_with_bundle() {
+        assert_eq!(
+            bundle_region(Some("ny"), Some("frankfurt"))
+                .await
+                .as_deref(),
+            Some("frankfurt")
+        );
+        assert_eq!(
+            bundle_region(None, Some("tokyo")).await.as_deref(),
+            Some("tokyo")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_configured_region_used_without_override() {
+        assert_eq!(bundle_region(Some("ny"), None).await.as_deref(), Some("ny"));
+        // Neither set: Jito picks the region
+        assert_eq!(bundle_region(None, None).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_bundle_sent_to_configured_block_engine() {
+        let rpc_server = MockServer::start().await;
+        let block_engine = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "sendBundle"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "bundle_relay"
+            })))
+            .expect(1)
+            .mount(&block_engine)
+            .await;
+
+        let strategy = QuickNodePrivateSubmissionStrategy::new(QuickNodeSubmissionConfig {
+            rpc_url: rpc_server.uri(),
+            block_engine_url: Some(block_engine.uri()),
+            ..Default::default()
+        });
+        let (serialized, signature) = signed_tx();
+        let outcome = strategy
+            .submit_transaction(&serialized, true, Some("tokyo"))
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.signature, signature);
+        assert_eq!(outcome.bundle_id.as_deref(), Some("bundle_relay"));
+        assert!(rpc_server.received_requests().await.unwrap().is_empty());
+        let requests = block_engine.received_requests().await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(body["params"][1], "tokyo");
+    }
+
+    #[tokio::test]
+    async fn test_bundle_without_jito_submits_one_at_a_time() {
+        let mock_server = MockServer::start().await;
+        let serialized: Vec<String> = [signed_tx(), signed_tx()]
+            .into_iter()
+            .map(|(tx, _)| tx)
+            .collect();
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "sendTransaction"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "standard_sig"
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let strategy = strategy(mock_server.uri(), false);
+        let signatures = strategy.submit_bundle(&serialized, None).await.unwrap();
+
+        assert_eq!(signatures.len(), 2);
+        assert!(strategy.last_bundle_id().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_jito_submission_outcome_carries_bundle_id() {
+        let mock_server = MockServer::start().await;
+        let (serialized, signature) = signed_tx();
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "sendBundle"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "bundle_single"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let strategy = strategy(mock_server.uri(), true);
+        let outcome = strategy
+            .submit_transaction(&serialized, true, None)
+            .await
+            .unwrap();
+
+        // The transfer is identified by its signature, not the bundle ID
+        assert_eq!(outcome.signature, signature);
+        assert_eq!(outcome.bundle_id.as_deref(), Some("bundle_single"));
+    }
+
+    #[tokio::test]
+    async fn test_standard_submission_outcome_has_no_bundle_id() {
+        let mock_server = MockServer::start().await;
+        let (serialized, _) = signed_tx();
+
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "sendTransaction"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "standard_sig"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let strategy = strategy(mock_server.uri(), false);
+        let outcome = strategy
+            .submit_transaction(&serialized, true, None)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.signature, "standard_sig");
+        assert!(outcome.bundle_id.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_standard_submission_forwards_skip_preflight() {
+        for skip_preflight in [true, false] {
+            let mock_server = MockServer::start().await;
+            let (serialized, _) = signed_tx();
+
+            Mock::given(method("POST"))
+                .and(body_partial_json(json!({
+                    "method": "sendTransaction",
+                    "params": [serialized, {"skipPreflight": skip_preflight}]
+                })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "jsonrpc": "2.0",
+                    "id": 1,
+                    "result": "standard_sig"
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+
+            let strategy = strategy(mock_server.uri(), false);
+            strategy
+                .submit_transaction(&serialized, skip_preflight, None)
+                .await
+                .unwrap();
+        }
+    }
+
+    /// Mounts a `simulateTransaction` mock returning `err` and a `sendBundle`
+    /// mock, each expected the given number of times.
+    async fn mount_preflight_mocks(
+        mock_server: &MockServer,
+        err: serde_json::Value,
+        simulations: u64,
+        bundles: u64,
+    ) {
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "simulateTransaction"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": {
+                    "context": {"slot": 1},
+                    "value": {"err": err, "logs": []}
+                }
+            })))
+            .expect(simulations)
+            .mount(mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(body_partial_json(json!({"method": "sendBundle"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": "bundle_preflight"
+            })))
+            .expect(bundles)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_jito_skip_preflight_sends_bundle_without_simulation() {
+        let mock_server = MockServer::start().await;
+        mount_preflight_mocks(&mock_server, json!(null), 0, 1).await;
+
+        let (serialized, _) = signed_tx();
+        let strategy = strategy(mock_server.uri(), true);
+        let outcome = strategy
+            .submit_transaction(&serialized, true, None)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.bundle_id.as_deref(), Some("bundle_preflight"));
+    }
+
+    #[tokio::test]
+    async fn test_jito_preflight_simulates_before_bundle() {
+        let mock_server = MockServer::start().await;
+        mount_preflight_mocks(&mock_server, json!(null), 1, 1).await;
+
+        let (serialized, _) = signed_tx();
+        let strategy = strategy(mock_server.uri(), true);
+        let outcome = strategy
+            .submit_transaction(&serialized, false, None)
+            .await
+            .unwrap();
+
+        assert_eq!(outcome.bundle_id.as_deref(), Some("bundle_preflight"));
+        let requests = mock_server.received_requests().await.unwrap();
+        let first: serde_json::Value = serde_json::from_slice(&requests[0].body).unwrap();
+        assert_eq!(first["method"], "simulateTransaction");
+    }
+
+    #[tokio::test]
+    async fn test_jito_preflight_failure_skips_bundle() {
+        let mock_server = MockServer::start().await;
+        mount_preflight_mocks(
+            &mock_server,
+            json!({"InstructionError": [0, {"Custom": 1}]}),
+            1,
+            0,
+        )
+        .await;
+
+        let (serialized, _) = signed_tx();
+        let strategy = strategy(mock_server.uri(), true);
+        let err = strategy
+            .submit_transaction(&serialized, false, None)
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("Preflight simulation failed"));
+    }
+}
+
+// ============================================================================
+// JITO TIP ACCOUNT REFRESH TESTS
+// ============================================================================
+
+mod jito_tip_account_tests {
+    use super::*;
+    use serde_json::json;
+    use solana_compliance_relayer::infra::blockchain::{TipAccountSet, refresh_tip_accounts};
+    use solana_sdk::pubkey::Pubkey;
+
+    #[tokio::test]
+    async fn test_refresh_replaces_tip_accounts() {
+        let mock_server = MockServer::start().await;
+        let live = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "result": live.iter().map(ToString::to_string).collect::<Vec<_>>()
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let set = TipAccountSet::new();
+        assert!(!set.is_live());
+
+        let count = refresh_tip_accounts(&set, &reqwest::Client::new(), &mock_server.uri())
+            .await
+            .unwrap();
+
+        assert_eq!(count, 2);
+        assert!(set.is_live());
+        assert_eq!(set.accounts(), live);
+        for _ in 0..20 {
+            assert!(live.contains(&set.random()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_refresh_keeps_previous_set() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service Unavailable"))
+            .mount(&mock_server)
+            .await;
+
+        let set = TipAccountSet::new();
+        let previous = vec![Pubkey::new_unique()];
+        set.replace(previous.clone());
+
+        let result = refresh_tip_accounts(&set, &reqwest::Client::new(), &mock_server.uri()).await;
+
+        assert!(result.is_err());
+        assert_eq!(set.accounts(), previous);
+    }
+}
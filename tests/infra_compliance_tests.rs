@@ -511,3 +511,465 @@ mod range_deserialization_tests {
         assert!(addr.risk_categories.is_empty());
     }
 }
+
+// ============================================================================
+// RANGE COMPLIANCE CACHE TESTS
+// ============================================================================
+
+mod range_cache_tests {
+    use super::*;
+    use solana_compliance_relayer::domain::{
+        ComplianceProvider, ComplianceStatus, SubmitTransferRequest, TransferType,
+    };
+    use solana_compliance_relayer::infra::RangeComplianceProvider;
+    use solana_compliance_relayer::infra::compliance::ComplianceRetryConfig;
+
+    fn transfer(to_address: &str) -> SubmitTransferRequest {
+        SubmitTransferRequest {
+            from_address: "SenderWallet".to_string(),
+            to_address: to_address.to_string(),
+            transfer_details: TransferType::Public { amount: 1_000_000 },
+            token_mint: None,
+            signature: "dummy_sig".to_string(),
+            nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string(),
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        }
+    }
+
+    async fn mount_risk(mock_server: &MockServer, address: &str, score: i32, level: &str) {
+        Mock::given(method("GET"))
+            .and(query_param("address", address))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "riskScore": score,
+                "riskLevel": level,
+                "numHops": 2,
+                "maliciousAddressesFound": [],
+                "reasoning": "Test response"
+            })))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_repeat_checks_served_from_cache() {
+        let mock_server = MockServer::start().await;
+        mount_risk(&mock_server, "SafeWallet", 1, "Very low risk").await;
+        mount_risk(&mock_server, "BadWallet", 10, "CRITICAL RISK").await;
+
+        let provider = RangeComplianceProvider::new(
+            Some("test_api_key".to_string()),
+            Some(mock_server.uri()),
+            None,
+        );
+
+        for _ in 0..3 {
+            assert_eq!(
+                provider
+                    .check_compliance(&transfer("SafeWallet"))
+                    .await
+                    .unwrap(),
+                ComplianceStatus::Approved
+            );
+            assert_eq!(
+                provider
+                    .check_compliance(&transfer("BadWallet"))
+                    .await
+                    .unwrap(),
+                ComplianceStatus::Rejected
+            );
+        }
+        // Mock expectations (one call per address) are verified on drop
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_forces_recheck() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(query_param("address", "RecheckWallet"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "riskScore": 1,
+                "riskLevel": "Very low risk"
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let provider = RangeComplianceProvider::new(
+            Some("test_api_key".to_string()),
+            Some(mock_server.uri()),
+            None,
+        );
+
+        provider
+            .check_compliance(&transfer("RecheckWallet"))
+            .await
+            .unwrap();
+        assert_eq!(provider.invalidate_address("RecheckWallet"), 1);
+        provider
+            .check_compliance(&transfer("RecheckWallet"))
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_not_cached() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let provider = RangeComplianceProvider::new(
+            Some("test_api_key".to_string()),
+            Some(mock_server.uri()),
+            None,
+        )
+        .with_retry_config(ComplianceRetryConfig {
+            max_retries: 0,
+            retry_delay: std::time::Duration::ZERO,
+        });
+
+        for _ in 0..2 {
+            assert_eq!(
+                provider
+                    .check_compliance(&transfer("AnyWallet"))
+                    .await
+                    .unwrap(),
+                ComplianceStatus::Rejected
+            );
+        }
+        assert_eq!(provider.cache_len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_per_mint_threshold_is_applied_and_cached_separately() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(query_param("address", "MediumWallet"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "riskScore": 4,
+                "riskLevel": "Medium risk"
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let strict_mint = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        let provider = RangeComplianceProvider::new(
+            Some("test_api_key".to_string()),
+            Some(mock_server.uri()),
+            None,
+        )
+        .with_threshold_overrides(std::collections::HashMap::from([(
+            strict_mint.to_string(),
+            3,
+        )]));
+
+        let mut request = transfer("MediumWallet");
+        assert_eq!(
+            provider.check_compliance(&request).await.unwrap(),
+            ComplianceStatus::Approved
+        );
+
+        request.token_mint = Some(strict_mint.to_string());
+        assert_eq!(
+            provider.check_compliance(&request).await.unwrap(),
+            ComplianceStatus::Rejected
+        );
+        assert_eq!(provider.cache_len(), 2);
+    }
+}
+
+// ============================================================================
+// RANGE RETRY / FAIL-OPEN TESTS
+// ============================================================================
+
+mod range_retry_tests {
+    use super::*;
+    use std::time::Duration;
+
+    use solana_compliance_relayer::domain::{
+        AppError, ComplianceProvider, ComplianceStatus, ExternalServiceError,
+        SubmitTransferRequest, TransferType,
+    };
+    use solana_compliance_relayer::infra::RangeComplianceProvider;
+    use solana_compliance_relayer::infra::compliance::ComplianceRetryConfig;
+
+    fn transfer() -> SubmitTransferRequest {
+        SubmitTransferRequest {
+            from_address: "SenderWallet".to_string(),
+            to_address: "FlakyWallet".to_string(),
+            transfer_details: TransferType::Public { amount: 1_000_000 },
+            token_mint: None,
+            signature: "dummy_sig".to_string(),
+            nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string(),
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        }
+    }
+
+    fn provider(mock_server: &MockServer, fail_open: bool) -> RangeComplianceProvider {
+        RangeComplianceProvider::new(
+            Some("test_api_key".to_string()),
+            Some(mock_server.uri()),
+            None,
+        )
+        .with_retry_config(ComplianceRetryConfig {
+            max_retries: 2,
+            retry_delay: Duration::from_millis(1),
+        })
+        .with_fail_open(fail_open)
+    }
+
+    async fn mount_unavailable(mock_server: &MockServer, expected_calls: u64) {
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(expected_calls)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_retries_exhausted_returns_distinct_error() {
+        let mock_server = MockServer::start().await;
+        mount_unavailable(&mock_server, 3).await;
+
+        let err = provider(&mock_server, false)
+            .check_address_risk("FlakyWallet")
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::ExternalService(ExternalServiceError::RetriesExhausted { attempts: 3, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_fail_closed_rejects_after_retries() {
+        let mock_server = MockServer::start().await;
+        mount_unavailable(&mock_server, 3).await;
+
+        let status = provider(&mock_server, false)
+            .check_compliance(&transfer())
+            .await
+            .unwrap();
+        assert_eq!(status, ComplianceStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_fail_open_approves_after_retries() {
+        let mock_server = MockServer::start().await;
+        mount_unavailable(&mock_server, 3).await;
+
+        let provider = provider(&mock_server, true);
+        let status = provider.check_compliance(&transfer()).await.unwrap();
+        assert_eq!(status, ComplianceStatus::Approved);
+        // Fail-open approvals must not be cached
+        assert_eq!(provider.cache_len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fail_open_does_not_cover_client_errors() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(403))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let status = provider(&mock_server, true)
+            .check_compliance(&transfer())
+            .await
+            .unwrap();
+        assert_eq!(status, ComplianceStatus::Rejected);
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_recovers_on_retry() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "riskScore": 1,
+                "riskLevel": "Very low risk"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let status = provider(&mock_server, false)
+            .check_compliance(&transfer())
+            .await
+            .unwrap();
+        assert_eq!(status, ComplianceStatus::Approved);
+    }
+}
+
+// ============================================================================
+// TRM LABS PROVIDER TESTS
+// ============================================================================
+
+mod trm_provider_tests {
+    use super::*;
+    use std::time::Duration;
+
+    use solana_compliance_relayer::domain::{
+        ComplianceProvider, ComplianceStatus, SubmitTransferRequest, TransferType,
+    };
+    use solana_compliance_relayer::infra::TrmComplianceProvider;
+    use solana_compliance_relayer::infra::compliance::ComplianceRetryConfig;
+    use wiremock::matchers::{body_json, header_exists, path};
+
+    fn transfer(to_address: &str) -> SubmitTransferRequest {
+        SubmitTransferRequest {
+            from_address: "SenderWallet".to_string(),
+            to_address: to_address.to_string(),
+            transfer_details: TransferType::Public { amount: 1_000_000 },
+            token_mint: None,
+            signature: "dummy_sig".to_string(),
+            nonce: "019470a4-7e7c-7d3e-8f1a-2b3c4d5e6f7a".to_string(),
+            memo: None,
+            priority: 0,
+            callback_url: None,
+            signature_version: None,
+            valid_until: None,
+            jito_region: None,
+            skip_preflight: None,
+            amount_sol: None,
+        }
+    }
+
+    fn provider(mock_server: &MockServer) -> TrmComplianceProvider {
+        TrmComplianceProvider::new("test_api_key".to_string(), Some(mock_server.uri()), None)
+            .with_retry_config(ComplianceRetryConfig {
+                max_retries: 1,
+                retry_delay: Duration::from_millis(1),
+            })
+    }
+
+    fn screening(address: &str, level: i32, category: &str) -> serde_json::Value {
+        json!({
+            "address": address,
+            "chain": "solana",
+            "addressRiskIndicators": [{
+                "category": category,
+                "categoryRiskScoreLevel": level,
+                "categoryRiskScoreLevelLabel": "label",
+                "riskType": "COUNTERPARTY"
+            }],
+            "entities": []
+        })
+    }
+
+    #[tokio::test]
+    async fn test_trm_screens_both_addresses_and_approves_clean_transfer() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/screening/addresses"))
+            .and(header_exists("authorization"))
+            .and(body_json(json!([
+                {"address": "SenderWallet", "chain": "solana"},
+                {"address": "CleanWallet", "chain": "solana"}
+            ])))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                screening("SenderWallet", 1, "Exchange"),
+                screening("CleanWallet", 5, "Gambling")
+            ])))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let provider = provider(&mock_server);
+        for _ in 0..2 {
+            assert_eq!(
+                provider
+                    .check_compliance(&transfer("CleanWallet"))
+                    .await
+                    .unwrap(),
+                ComplianceStatus::Approved
+            );
+        }
+        // Second check is served from the cache (expect(1) verified on drop)
+        assert_eq!(provider.cache_len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_trm_rejects_high_risk_recipient() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                screening("SenderWallet", 1, "Exchange"),
+                screening("ScamWallet", 10, "Scam")
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        assert_eq!(
+            provider(&mock_server)
+                .check_compliance(&transfer("ScamWallet"))
+                .await
+                .unwrap(),
+            ComplianceStatus::Rejected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trm_rejects_sanctioned_sender() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                screening("SenderWallet", 1, "Sanctions"),
+                screening("CleanWallet", 0, "Exchange")
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        assert_eq!(
+            provider(&mock_server)
+                .check_compliance(&transfer("CleanWallet"))
+                .await
+                .unwrap(),
+            ComplianceStatus::Rejected
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trm_retries_then_fails_closed() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let provider = provider(&mock_server);
+        assert_eq!(
+            provider
+                .check_compliance(&transfer("AnyWallet"))
+                .await
+                .unwrap(),
+            ComplianceStatus::Rejected
+        );
+        assert_eq!(provider.cache_len(), 0);
+    }
+}